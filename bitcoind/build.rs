@@ -1,76 +1,72 @@
-fn main() { download::start().unwrap(); }
+/// The env vars read by the build script, it runs again if one of them changes.
+const ENV_VARS: &[&str] = &[
+    "BITCOIND_DOWNLOAD_CACHE",
+    "BITCOIND_DOWNLOAD_DIR",
+    "BITCOIND_DOWNLOAD_ENDPOINT",
+    "BITCOIND_SIGNATURES_FILE",
+    "BITCOIND_SKIP_DOWNLOAD",
+    "BITCOIND_TARBALL_FILE",
+];
+
+fn main() {
+    for var in ENV_VARS {
+        println!("cargo:rerun-if-env-changed={}", var);
+    }
+    if let Err(e) = download::start() {
+        println!("cargo:warning=failed to download bitcoind: {:#}", e);
+        std::process::exit(1);
+    }
+}
 
 #[cfg(any(docsrs, not(feature = "download")))]
 mod download {
-    pub(crate) fn start() -> Result<(), ()> { Ok(()) }
+    pub(crate) fn start() -> Result<(), std::convert::Infallible> { Ok(()) }
 }
 
+/// The variant of `bitcoind::Error` used by `src/download.rs`.
 #[cfg(feature = "download")]
-mod download {
-    use std::fs::File;
-    use std::io::{self, BufRead, BufReader, Cursor, Read};
-    use std::path::{Path, PathBuf};
-    use std::str::FromStr;
-
-    use anyhow::Context;
-    use bitcoin_hashes::{sha256, Hash};
-    use flate2::read::GzDecoder;
-    use tar::Archive;
-
-    include!("src/versions.rs");
+#[derive(Debug)]
+enum Error {
+    DownloadVerification(String),
+}
 
-    fn download_filename() -> String {
-        if cfg!(all(target_os = "macos", target_arch = "x86_64")) {
-            if cfg!(not(feature = "23_2")) {
-                return format!("bitcoin-{}-osx64.tar.gz", &VERSION);
-            }
-            return format!("bitcoin-{}-x86_64-apple-darwin.tar.gz", &VERSION);
+#[cfg(feature = "download")]
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::DownloadVerification(msg) => write!(f, "download verification failed: {}", msg),
         }
+    }
+}
 
-        if cfg!(all(target_os = "macos", target_arch = "aarch64")) {
-            return format!("bitcoin-{}-arm64-apple-darwin.tar.gz", &VERSION);
-        }
+#[cfg(feature = "download")]
+impl std::error::Error for Error {}
 
-        if cfg!(all(target_os = "linux", target_arch = "x86_64")) {
-            return format!("bitcoin-{}-x86_64-linux-gnu.tar.gz", &VERSION);
-        }
+#[cfg(feature = "download")]
+#[allow(dead_code)] // The runtime API is not used by the build script.
+#[path = "src/download.rs"]
+mod fetch;
 
-        if cfg!(all(target_os = "linux", target_arch = "aarch64")) {
-            return format!("bitcoin-{}-aarch64-linux-gnu.tar.gz", &VERSION);
-        }
+#[cfg(feature = "download")]
+mod download {
+    use std::fs::File;
+    use std::io::{BufReader, Read};
+    use std::path::{Path, PathBuf};
 
-        if cfg!(all(target_os = "windows", target_arch = "x86_64")) {
-            return format!("bitcoin-{}-win64.zip", &VERSION);
-        }
+    use anyhow::Context;
 
-        panic!("No download file for this os/arch");
-    }
+    use crate::fetch;
 
-    #[allow(clippy::lines_filter_map_ok)] // clippy doesn't like the `lines` call below and the suggested fix is incorrect.
-    fn get_expected_sha256(filename: &str) -> anyhow::Result<sha256::Hash> {
-        let sha256sums_filename = format!("sha256/bitcoin-core-{}-SHA256SUMS", &VERSION);
-        #[cfg(not(feature = "22_1"))]
-        let sha256sums_filename = format!("{}.asc", sha256sums_filename);
-        let file = File::open(&sha256sums_filename)
-            .with_context(|| format!("cannot find {:?}", sha256sums_filename))?;
-        for line in BufReader::new(file).lines().flatten() {
-            let tokens: Vec<_> = line.split("  ").collect();
-            if tokens.len() == 2 && filename == tokens[1] {
-                return Ok(sha256::Hash::from_str(tokens[0]).unwrap());
-            }
-        }
-        panic!(
-            "Couldn't find hash for `{}` in `{}`:\n{}",
-            filename,
-            sha256sums_filename,
-            std::fs::read_to_string(&sha256sums_filename).unwrap()
-        );
-    }
+    include!("src/versions.rs");
 
+    /// Returns the directory the release is downloaded to.
     fn download_dir(out_dir: &Path) -> PathBuf {
         if let Some(path) = std::env::var_os("BITCOIND_DOWNLOAD_DIR") {
             return PathBuf::from(path);
         }
+        if let Some(path) = std::env::var_os("BITCOIND_DOWNLOAD_CACHE") {
+            return PathBuf::from(path);
+        }
         out_dir.join("bitcoin")
     }
 
@@ -84,139 +80,24 @@ mod download {
         std::fs::create_dir_all(&bitcoin_exe_home)
             .with_context(|| format!("cannot create dir {:?}", bitcoin_exe_home))?;
 
-        let version_dir = bitcoin_exe_home.join(format!("bitcoin-{}", VERSION));
-        let mut existing_filename = version_dir.join("bin");
-        if cfg!(target_os = "windows") {
-            existing_filename.push("bitcoind.exe");
-        } else {
-            existing_filename.push("bitcoind");
+        if fetch::is_cached(VERSION, &bitcoin_exe_home) {
+            return Ok(());
         }
 
-        #[cfg(not(target_os = "windows"))]
-        let cache_complete = existing_filename.exists()
-            && version_dir.join("bin").join("bitcoin-cli").exists()
-            && (!cfg!(feature = "30_2")
-                || version_dir.join("libexec").join("bitcoin-node").exists());
-        #[cfg(target_os = "windows")]
-        let cache_complete = existing_filename.exists();
-
-        if !cache_complete {
-            let download_filename = download_filename();
-            println!("download_filename: {}", download_filename);
-            let expected_hash = get_expected_sha256(&download_filename)?;
-
-            println!("filename:{} version:{} hash:{}", download_filename, VERSION, expected_hash);
-
-            let (file_or_url, tarball_bytes) = match std::env::var("BITCOIND_TARBALL_FILE") {
-                Err(_) => {
-                    let download_endpoint = std::env::var("BITCOIND_DOWNLOAD_ENDPOINT")
-                        .unwrap_or("https://bitcoincore.org/bin".to_owned());
-
-                    let url = format!(
-                        "{}/bitcoin-core-{}/{}",
-                        download_endpoint, VERSION, download_filename
-                    );
-                    let resp = bitreq::get(&url)
-                        .send()
-                        .with_context(|| format!("cannot reach url {}", url))?;
-                    assert_eq!(resp.status_code, 200, "url {} didn't return 200", url);
-
-                    (url, resp.as_bytes().to_vec())
-                }
-                Ok(path) => {
-                    let f = File::open(&path).with_context(|| {
-                        format!(
-                            "Cannot find {:?} specified with env var BITCOIND_TARBALL_FILE",
-                            &path
-                        )
-                    })?;
-                    let mut reader = BufReader::new(f);
-                    let mut buffer = Vec::new();
-                    reader.read_to_end(&mut buffer)?;
-                    (path, buffer)
-                }
-            };
-
-            let tarball_hash = sha256::Hash::hash(&tarball_bytes);
-            assert_eq!(
-                expected_hash, tarball_hash,
-                "expected hash of {} is not matching",
-                file_or_url
-            );
-
-            if download_filename.ends_with(".tar.gz") {
-                let d = GzDecoder::new(&tarball_bytes[..]);
-
-                let targets: &[&Path] = &[
-                    Path::new("bin/bitcoind"),
-                    Path::new("bin/bitcoin-cli"),
-                    Path::new("libexec/bitcoin-node"),
-                ];
-                let mut archive = Archive::new(d);
-                for mut entry in archive.entries().unwrap().flatten() {
-                    if let Ok(file) = entry.path() {
-                        if targets.iter().any(|t| file.ends_with(t)) {
-                            entry.unpack_in(&bitcoin_exe_home).unwrap();
-                        }
-                    }
-                }
-            } else if download_filename.ends_with(".zip") {
-                let cursor = Cursor::new(tarball_bytes);
-                let mut archive = zip::ZipArchive::new(cursor).unwrap();
-                for i in 0..zip::ZipArchive::len(&archive) {
-                    let mut file = archive.by_index(i).unwrap();
-                    let outpath = match file.enclosed_name() {
-                        Some(path) => path.to_owned(),
-                        None => continue,
-                    };
-
-                    if outpath.file_name().map(|s| s.to_str()) == Some(Some("bitcoind.exe")) {
-                        let dest = bitcoin_exe_home.join(&outpath);
-                        let parent = dest.parent().unwrap();
-                        std::fs::create_dir_all(parent)
-                            .with_context(|| format!("cannot create dir {:?}", parent))?;
-                        let mut outfile = std::fs::File::create(&dest)
-                            .with_context(|| format!("cannot create file {:?}", dest))?;
-                        io::copy(&mut file, &mut outfile).unwrap();
-                        break;
-                    }
-                }
+        let (file_or_url, tarball_bytes) = match std::env::var("BITCOIND_TARBALL_FILE") {
+            Err(_) => fetch::download(VERSION)?,
+            Ok(path) => {
+                let f = File::open(&path).with_context(|| {
+                    format!("Cannot find {:?} specified with env var BITCOIND_TARBALL_FILE", &path)
+                })?;
+                let mut reader = BufReader::new(f);
+                let mut buffer = Vec::new();
+                reader.read_to_end(&mut buffer)?;
+                (path, buffer)
             }
+        };
+        println!("file_or_url:{} version:{}", file_or_url, VERSION);
 
-            // Code signing for arm64 macOS:
-            #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
-            {
-                use std::process::Command;
-
-                let to_sign = [
-                    version_dir.join("bin").join("bitcoind"),
-                    version_dir.join("bin").join("bitcoin-cli"),
-                    version_dir.join("libexec").join("bitcoin-node"),
-                ];
-                for binary in to_sign.iter().filter(|p| p.exists()) {
-                    let signing_status =
-                        Command::new("codesign").arg("-v").arg(binary).status().with_context(
-                            || format!("failed to verify code signature on {:?}", binary),
-                        )?;
-
-                    if !signing_status.success() {
-                        let status = Command::new("codesign")
-                            .arg("-s")
-                            .arg("-")
-                            .arg(binary)
-                            .status()
-                            .with_context(|| format!("failed to sign {:?}", binary))?;
-                        if !status.success() {
-                            return Err(anyhow::anyhow!(
-                                "codesign failed for {:?} with exit code {}",
-                                binary,
-                                status.code().unwrap_or(-1)
-                            ));
-                        }
-                    }
-                }
-            }
-        }
-        Ok(())
+        fetch::install(VERSION, &bitcoin_exe_home, &file_or_url, &tarball_bytes)
     }
 }
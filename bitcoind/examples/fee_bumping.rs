@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Replaces an unconfirmed transaction with one paying a higher fee (BIP-125).
+//!
+//! Run with `cargo run --example fee_bumping --features=download,28_2`, or without `download` if
+//! `bitcoind` is in the `PATH` or set with `BITCOIND_EXE`.
+
+use std::time::Duration;
+
+use bitcoind::client::bitcoin::Amount;
+use bitcoind::BitcoinD;
+
+fn main() -> anyhow::Result<()> {
+    let node = BitcoinD::new(bitcoind::exe_path()?)?;
+    node.fund_node_wallet()?;
+
+    let address = node.client.new_address()?;
+    let txid = node.client.send_to_address_rbf(&address, Amount::from_btc(0.1)?)?.txid()?;
+    println!("sent {}, signaling replaceability", txid);
+
+    let bumped = node.client.bump_fee(txid)?.into_model()?;
+    println!("replaced by {}, fee {} -> {}", bumped.txid, bumped.original_fee, bumped.fee);
+    assert!(bumped.fee > bumped.original_fee);
+
+    node.wait_for_tx_in_mempool(bumped.txid, Duration::from_secs(10))?;
+    node.mine_blocks(1)?;
+    let confirmations = node.client.get_transaction(bumped.txid)?.into_model()?.confirmations;
+    assert_eq!(confirmations, 1);
+    Ok(())
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Funds the node wallet by mining, then sends coins to a second wallet.
+//!
+//! Run with `cargo run --example fund_and_send --features=download,28_2`, or without `download`
+//! if `bitcoind` is in the `PATH` or set with `BITCOIND_EXE`.
+
+use bitcoind::client::bitcoin::Amount;
+use bitcoind::BitcoinD;
+
+fn main() -> anyhow::Result<()> {
+    let node = BitcoinD::new(bitcoind::exe_path()?)?;
+
+    // Coinbase outputs need 100 confirmations before they can be spent.
+    let balance = node.fund_node_wallet()?;
+    println!("node wallet balance: {}", balance);
+
+    let alice = node.create_wallet("alice")?;
+    let address = alice.new_address()?;
+    let amount = Amount::from_btc(1.5)?;
+    let (outpoint, _) = node.fund_address(&address, amount)?;
+    println!("sent {} to {} in {}", amount, address, outpoint);
+
+    let received = alice.get_balance()?.into_model()?.0;
+    assert_eq!(received, amount);
+    println!("alice balance: {}", received);
+    Ok(())
+}
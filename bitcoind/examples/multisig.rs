@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Funds a 2-of-3 P2WSH multisig with keys held outside the node, then spends from it.
+//!
+//! Run with `cargo run --example multisig --features=download,28_2`, or without `download` if
+//! `bitcoind` is in the `PATH` or set with `BITCOIND_EXE`.
+
+use bitcoind::client::bitcoin::hashes::Hash;
+use bitcoind::client::bitcoin::opcodes::all::OP_CHECKMULTISIG;
+use bitcoind::client::bitcoin::script::Builder;
+use bitcoind::client::bitcoin::secp256k1::{Message, Secp256k1};
+use bitcoind::client::bitcoin::sighash::{EcdsaSighashType, SighashCache};
+use bitcoind::client::bitcoin::{
+    absolute, ecdsa, transaction, Address, Amount, Network, PrivateKey, ScriptBuf, Sequence,
+    Transaction, TxIn, TxOut, Witness,
+};
+use bitcoind::BitcoinD;
+
+fn main() -> anyhow::Result<()> {
+    let node = BitcoinD::new(bitcoind::exe_path()?)?;
+    node.fund_node_wallet()?;
+
+    let secp = Secp256k1::new();
+    let keys = [1u8, 2, 3]
+        .iter()
+        .map(|i| PrivateKey::from_slice(&[*i; 32], Network::Regtest))
+        .collect::<Result<Vec<_>, _>>()?;
+    let pubkeys = keys.iter().map(|key| key.public_key(&secp)).collect::<Vec<_>>();
+
+    let mut builder = Builder::new().push_int(2);
+    for pubkey in &pubkeys {
+        builder = builder.push_key(pubkey);
+    }
+    let witness_script = builder.push_int(3).push_opcode(OP_CHECKMULTISIG).into_script();
+    // Core builds the same script, it returns a P2SH address by default.
+    let multisig = node.client.create_multisig(2, pubkeys)?.into_model()?;
+    assert_eq!(multisig.redeem_script, witness_script);
+
+    let address = Address::p2wsh(&witness_script, Network::Regtest);
+    let (outpoint, funded) = node.fund_address(&address, Amount::from_btc(1.0)?)?;
+    println!("funded {} with {} in {}", address, funded.value, outpoint);
+
+    // Spend back to the node wallet, signing with the first two keys.
+    let destination = node.client.new_address()?;
+    let mut tx = Transaction {
+        version: transaction::Version::TWO,
+        lock_time: absolute::LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: outpoint,
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: funded.value - Amount::from_sat(1_000),
+            script_pubkey: destination.script_pubkey(),
+        }],
+    };
+    let sighash_type = EcdsaSighashType::All;
+    let sighash = SighashCache::new(&tx).p2wsh_signature_hash(
+        0,
+        &witness_script,
+        funded.value,
+        sighash_type,
+    )?;
+    let message = Message::from_digest(sighash.to_byte_array());
+    // `CHECKMULTISIG` pops an extra element, then the signatures in the order of the keys.
+    let mut witness = vec![vec![]];
+    for key in &keys[..2] {
+        let signature = secp.sign_ecdsa(&message, &key.inner);
+        witness.push(ecdsa::Signature { signature, sighash_type }.serialize().to_vec());
+    }
+    witness.push(witness_script.to_bytes());
+    tx.input[0].witness = Witness::from_slice(&witness);
+
+    let txid = node.client.send_raw_transaction(&tx)?.into_model()?.0;
+    node.mine_blocks(1)?;
+    println!("spent the multisig to {} in {}", destination, txid);
+    Ok(())
+}
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A PSBT round: create and fund, sign, finalize, extract and broadcast.
+//!
+//! Run with `cargo run --example psbt --features=download,28_2`, or without `download` if
+//! `bitcoind` is in the `PATH` or set with `BITCOIND_EXE`.
+
+use std::collections::BTreeMap;
+
+use bitcoind::client::bitcoin::Amount;
+use bitcoind::BitcoinD;
+
+fn main() -> anyhow::Result<()> {
+    let node = BitcoinD::new(bitcoind::exe_path()?)?;
+    node.fund_node_wallet()?;
+    let bob = node.create_wallet("bob")?;
+
+    // Creator and updater: the node wallet picks the inputs and adds a change output.
+    let amount = Amount::from_btc(0.5)?;
+    let outputs = vec![BTreeMap::from([(bob.new_address()?, amount)])];
+    let funded = node.client.wallet_create_funded_psbt(vec![], outputs)?.into_model()?;
+    println!("created a PSBT paying a fee of {}", funded.fee);
+
+    // Signer.
+    let signed = node.client.wallet_process_psbt(&funded.psbt)?.into_model()?;
+    anyhow::ensure!(signed.complete, "the node wallet owns all the inputs");
+
+    // Finalizer and extractor.
+    let finalized = node.client.finalize_psbt(&signed.psbt)?.into_model()?;
+    let psbt = finalized.psbt.ok_or_else(|| anyhow::anyhow!("finalizepsbt returned no PSBT"))?;
+    let tx = psbt.extract_tx()?;
+
+    let txid = node.client.send_raw_transaction(&tx)?.into_model()?.0;
+    node.mine_blocks(1)?;
+    assert_eq!(bob.get_balance()?.into_model()?.0, amount);
+    println!("broadcast {}, bob received {}", txid, amount);
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Replaces the block confirming a transaction, the transaction is mined again in a new block.
+//!
+//! Run with `cargo run --example reorg --features=download,28_2`, or without `download` if
+//! `bitcoind` is in the `PATH` or set with `BITCOIND_EXE`.
+
+use bitcoind::client::bitcoin::Amount;
+use bitcoind::BitcoinD;
+
+fn main() -> anyhow::Result<()> {
+    let node = BitcoinD::new(bitcoind::exe_path()?)?;
+    node.fund_node_wallet()?;
+
+    let address = node.client.new_address()?;
+    let (outpoint, _) = node.fund_address(&address, Amount::from_btc(0.2)?)?;
+    let before = node.client.get_transaction(outpoint.txid)?.into_model()?;
+    println!("{} confirmed in {:?}", outpoint.txid, before.block_hash);
+
+    let replaced = node.reorg(1)?;
+    assert_eq!(Some(replaced[0]), before.block_hash);
+    println!("replaced {} with 2 new blocks", replaced[0]);
+
+    // The wallet follows the reorg, the transaction is in one of the new blocks.
+    let after = node.client.get_transaction(outpoint.txid)?.into_model()?;
+    assert_ne!(after.block_hash, before.block_hash);
+    println!("{} now confirmed in {:?}", outpoint.txid, after.block_hash);
+    Ok(())
+}
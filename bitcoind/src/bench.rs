@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Benchmarking the startup and block sync of the node across configurations.
+//!
+//! [`run`] starts a node per configuration and measures how long it takes to answer RPC calls, to
+//! sync the blocks of a source node over p2p, and each step of flushing the chain state, as
+//! parsed from `debug.log`. Useful to compare e.g., `-dbcache` or `-par` settings.
+//!
+//! ```no_run
+//! # fn main() -> anyhow::Result<()> {
+//! use std::time::Duration;
+//!
+//! use bitcoind::{bench, Conf};
+//!
+//! let mut small_cache = Conf::default();
+//! small_cache.args.push("-dbcache=4");
+//! let variants = [("default", Conf::default()), ("dbcache=4", small_cache)];
+//!
+//! let reports = bench::run(bitcoind::exe_path()?, 1_000, &variants, Duration::from_secs(60))?;
+//! for report in reports {
+//!     println!("{}: {:?} to sync", report.label, report.sync);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::ffi::OsStr;
+use std::time::{Duration, Instant};
+
+use crate::logparse::Event;
+use crate::{BitcoinD, Conf, P2P};
+
+/// The number of blocks mined per RPC call on the source node.
+const MINE_BATCH: usize = 1_000;
+
+/// The debug categories logging the flush timings.
+const FLUSH_CATEGORIES: [&str; 2] = ["-debug=bench", "-debug=coindb"];
+
+/// The measurements of one configuration, see [`run`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchReport {
+    /// The label the configuration was given.
+    pub label: String,
+    /// The time from spawning the node until it answered RPC calls.
+    pub rpc_ready: Duration,
+    /// The time from the node answering RPC calls until it synced all the blocks.
+    pub sync: Duration,
+    /// The number of blocks synced.
+    pub blocks: u64,
+    /// The flush steps completed while the node ran, including at shutdown.
+    ///
+    /// Core logs the timings from v25, earlier versions report none.
+    pub flushes: Vec<FlushTiming>,
+}
+
+impl BenchReport {
+    /// Returns the total time spent flushing the chain state.
+    pub fn flush_time(&self) -> Duration { self.flushes.iter().map(|flush| flush.duration).sum() }
+}
+
+/// A completed step of flushing the chain state to disk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlushTiming {
+    /// The step e.g., `write coins cache to disk (101 coins, 13.84kB)`.
+    pub step: String,
+    /// How long the step took.
+    pub duration: Duration,
+}
+
+/// Benchmarks a node running `exe` with each of the `variants`, labelled configurations.
+///
+/// A source node with `blocks` blocks is started first, each variant then syncs from it over p2p,
+/// `p2p` of the variants is replaced to connect to it. `timeout` bounds the sync of each variant.
+/// The nodes are run one after the other and stopped once measured.
+pub fn run<S: AsRef<OsStr>>(
+    exe: S,
+    blocks: usize,
+    variants: &[(&str, Conf)],
+    timeout: Duration,
+) -> anyhow::Result<Vec<BenchReport>> {
+    let source = BitcoinD::with_conf(&exe, &Conf { p2p: P2P::Yes, ..Default::default() })?;
+    let mut remaining = blocks;
+    while remaining > 0 {
+        let batch = remaining.min(MINE_BATCH);
+        source.mine_blocks(batch)?;
+        remaining -= batch;
+    }
+    let connect = source.p2p_connect(false).expect("source node listens");
+
+    let mut reports = Vec::with_capacity(variants.len());
+    for (label, conf) in variants {
+        let mut conf = conf.clone();
+        conf.p2p = connect.clone();
+        conf.args.extend(FLUSH_CATEGORIES);
+
+        let start = Instant::now();
+        let mut node = BitcoinD::with_conf(&exe, &conf)?;
+        let rpc_ready = start.elapsed();
+        node.wait_for_block_height(blocks as u64, timeout)?;
+        let sync = start.elapsed() - rpc_ready;
+        // Stopping flushes the chain state.
+        node.stop()?;
+
+        let flushes = node
+            .parse_debug_log()?
+            .into_iter()
+            .filter_map(|line| match line.event {
+                Event::Flush { step, duration } => Some(FlushTiming { step, duration }),
+                _ => None,
+            })
+            .collect();
+        reports.push(BenchReport {
+            label: label.to_string(),
+            rpc_ready,
+            sync,
+            blocks: blocks as u64,
+            flushes,
+        });
+    }
+    Ok(reports)
+}
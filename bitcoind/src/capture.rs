@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Capture of the `bitcoind` process output.
+
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Read};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+/// Number of lines kept by an [`OutputCapture`].
+pub(crate) const CAPTURE_LINES: usize = 20;
+
+/// Keeps the last lines written to a process output stream.
+///
+/// A background thread drains the stream until EOF so the child never blocks on a full pipe.
+#[derive(Debug)]
+pub(crate) struct OutputCapture {
+    /// Ring buffer of the most recent lines.
+    lines: Arc<Mutex<VecDeque<String>>>,
+    /// The draining thread, finishes once the stream is closed.
+    handle: Option<JoinHandle<()>>,
+}
+
+impl OutputCapture {
+    /// Starts draining `reader`, keeping the last `capacity` lines.
+    ///
+    /// If `echo` is `true` every line is also written to the stderr of the current process.
+    pub(crate) fn spawn<R: Read + Send + 'static>(reader: R, capacity: usize, echo: bool) -> Self {
+        let lines = Arc::new(Mutex::new(VecDeque::with_capacity(capacity)));
+        let buffer = Arc::clone(&lines);
+        let handle = thread::spawn(move || {
+            for line in BufReader::new(reader).lines() {
+                let Ok(line) = line else { break };
+                if echo {
+                    eprintln!("{}", line);
+                }
+                let mut buffer = buffer.lock().expect("poisoned lock");
+                if buffer.len() == capacity {
+                    buffer.pop_front();
+                }
+                buffer.push_back(line);
+            }
+        });
+        OutputCapture { lines, handle: Some(handle) }
+    }
+
+    /// Waits for the stream to be closed then returns the captured lines.
+    ///
+    /// Only call this once the process has exited otherwise it blocks until it does.
+    pub(crate) fn finish(&mut self) -> String {
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+        self.tail()
+    }
+
+    /// Returns the lines captured so far, joined by newlines.
+    pub(crate) fn tail(&self) -> String {
+        let lines = self.lines.lock().expect("poisoned lock");
+        lines.iter().map(String::as_str).collect::<Vec<_>>().join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_last_lines() {
+        let input: String = (0..30).map(|i| format!("line {}\n", i)).collect();
+        let mut capture = OutputCapture::spawn(std::io::Cursor::new(input), 3, false);
+        assert_eq!(capture.finish(), "line 27\nline 28\nline 29");
+    }
+}
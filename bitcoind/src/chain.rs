@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Moving the chain of a regtest node forward and back, and funding addresses from its wallet.
+//!
+//! These need the node to have a wallet loaded, see [`crate::Conf::wallet`]. Funding an address
+//! mines coins to the node wallet first if it cannot pay, the node acts as a faucet.
+
+use corepc_client::bitcoin::{Address, Amount, BlockHash, OutPoint, TxOut, Txid};
+
+use crate::BitcoinD;
+
+/// The number of confirmations before a coinbase output can be spent.
+const COINBASE_MATURITY: usize = 100;
+
+/// The fee the node wallet is expected to pay at most when funding an address.
+const MAX_FEE: Amount = Amount::from_sat(100_000);
+
+impl BitcoinD {
+    /// Mines `n` blocks to a new address of the node wallet, returns their hashes.
+    pub fn mine_blocks(&self, n: usize) -> anyhow::Result<Vec<BlockHash>> {
+        let address = self.client.new_address()?;
+        Ok(self.client.generate_to_address(n, &address)?.into_model()?.0)
+    }
+
+    /// Mines enough blocks for the node wallet to spend a coinbase, returns its trusted balance.
+    pub fn fund_node_wallet(&self) -> anyhow::Result<Amount> {
+        self.mine_blocks(COINBASE_MATURITY + 1)?;
+        Ok(self.client.get_balance()?.into_model()?.0)
+    }
+
+    /// Sends `amount` from the node wallet to `address` and mines the transaction.
+    ///
+    /// Returns the funded output, to spend it without the node wallet e.g., from a multisig. The
+    /// node wallet is funded first if needed, see [`BitcoinD::fund_address_unconfirmed`].
+    pub fn fund_address(
+        &self,
+        address: &Address,
+        amount: Amount,
+    ) -> anyhow::Result<(OutPoint, TxOut)> {
+        let txid = self.fund_address_unconfirmed(address, amount)?;
+        // Fetched while in the mempool, confirmed transactions need `-txindex`.
+        let tx = self.client.get_raw_transaction(txid)?.transaction()?;
+        self.mine_blocks(1)?;
+
+        let script_pubkey = address.script_pubkey();
+        let (vout, txout) = tx
+            .output
+            .into_iter()
+            .enumerate()
+            .find(|(_, txout)| txout.script_pubkey == script_pubkey && txout.value == amount)
+            .ok_or_else(|| anyhow::anyhow!("no output paying {} in {}", address, txid))?;
+        Ok((OutPoint::new(txid, vout as u32), txout))
+    }
+
+    /// Sends `amount` from the node wallet to `address`, returns the transaction left in the
+    /// mempool.
+    ///
+    /// If the node wallet cannot pay for it, enough blocks are mined for it to spend a coinbase
+    /// first. Returns an error if the wallet still cannot pay, the subsidy on regtest halves every
+    /// 150 blocks.
+    pub fn fund_address_unconfirmed(
+        &self,
+        address: &Address,
+        amount: Amount,
+    ) -> anyhow::Result<Txid> {
+        let needed = amount + MAX_FEE;
+        let mut balance = self.client.get_balance()?.into_model()?.0;
+        if balance < needed {
+            balance = self.fund_node_wallet()?;
+        }
+        anyhow::ensure!(
+            balance >= needed,
+            "node wallet balance {} is too low to send {} to {}",
+            balance,
+            amount,
+            address
+        );
+        Ok(self.client.send_to_address(address, amount)?.txid()?)
+    }
+
+    /// Replaces the last `depth` blocks with `depth + 1` new blocks, returns the hashes of the
+    /// replaced blocks starting from the old tip.
+    ///
+    /// The transactions of the replaced blocks go back to the mempool, those that are still valid
+    /// are mined again in the new blocks.
+    pub fn reorg(&self, depth: usize) -> anyhow::Result<Vec<BlockHash>> {
+        let height = self.client.get_block_count()?.0;
+        anyhow::ensure!(
+            depth > 0 && depth as u64 <= height,
+            "cannot reorg {} blocks at height {}",
+            depth,
+            height
+        );
+        let mut replaced = Vec::with_capacity(depth);
+        for h in (height + 1 - depth as u64..=height).rev() {
+            replaced.push(self.client.get_block_hash(h)?.block_hash()?);
+        }
+        let fork = *replaced.last().expect("depth is not zero");
+        self.client.invalidate_block(fork)?;
+        self.mine_blocks(depth + 1)?;
+        Ok(replaced)
+    }
+}
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The version of the running node, see [`BitcoinD::version`].
+
+use std::fmt;
+
+use crate::{BitcoinD, Client};
+
+/// The version of Bitcoin Core a node runs, parsed from the `version` of `getnetworkinfo`.
+///
+/// Versions before v22 were numbered `0.x.y`, the leading zero is dropped so that all versions
+/// compare on one scale e.g., `0.21.2` is major `21` and minor `2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CoreVersion {
+    /// The major version e.g., `28` for v28.2.0 and `21` for v0.21.2.
+    pub major: u32,
+    /// The minor version e.g., `2` for v28.2.0 and v0.21.2.
+    pub minor: u32,
+    /// The patch version e.g., `0` for v28.2.0.
+    pub patch: u32,
+}
+
+impl CoreVersion {
+    /// Parses the integer version reported by the node e.g., `280200` for v28.2.0.
+    pub fn from_server_version(version: usize) -> Self {
+        let version = version as u32;
+        CoreVersion { major: version / 10_000, minor: version / 100 % 100, patch: version % 100 }
+    }
+}
+
+impl fmt::Display for CoreVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.major < 22 {
+            write!(f, "0.{}.{}", self.major, self.minor)
+        } else {
+            write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+        }
+    }
+}
+
+/// Returns the version of the node `client` is for.
+pub(crate) fn core_version(client: &Client) -> anyhow::Result<CoreVersion> {
+    Ok(CoreVersion::from_server_version(client.server_version()?))
+}
+
+impl BitcoinD {
+    /// Returns the version of the node, read once at startup.
+    pub fn version(&self) -> CoreVersion { self.version }
+
+    /// Returns true if the node runs at least major version `major` e.g., `at_least(21)` for
+    /// v0.21.0 and later.
+    pub fn at_least(&self, major: u32) -> bool { self.version.major >= major }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_server_version() {
+        let v28 = CoreVersion::from_server_version(280200);
+        assert_eq!(v28, CoreVersion { major: 28, minor: 2, patch: 0 });
+        assert_eq!(v28.to_string(), "28.2.0");
+
+        let v21 = CoreVersion::from_server_version(210200);
+        assert_eq!(v21, CoreVersion { major: 21, minor: 2, patch: 0 });
+        assert_eq!(v21.to_string(), "0.21.2");
+
+        assert!(v21 < v28);
+        assert!(CoreVersion::from_server_version(170200) < v21);
+    }
+}
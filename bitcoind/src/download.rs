@@ -0,0 +1,408 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Downloading Bitcoin Core releases.
+//!
+//! The build script uses this module to download the version selected by the version feature, it
+//! is also available at runtime to download any supported version e.g., to run the same test
+//! against several versions.
+//!
+//! ```no_run
+//! # fn main() -> anyhow::Result<()> {
+//! let exe = bitcoind::download::fetch("26.2")?;
+//! let node = bitcoind::BitcoinD::new(exe)?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Releases are verified against the vendored `SHA256SUMS` before they are unpacked.
+//!
+//! This file is included in `build.rs` so must not depend on the rest of the crate, other than
+//! [`Error::DownloadVerification`] which `build.rs` also defines.
+
+use std::io::{self, Cursor};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::{env, fs};
+
+use anyhow::Context;
+use bitcoin_hashes::{sha256, Hash};
+use flate2::read::GzDecoder;
+use tar::Archive;
+
+use crate::Error;
+
+/// The companion tools extracted next to `bitcoind` (`bitcoin-util` only exists from v22).
+const TOOLS: &[&str] = &["bitcoin-wallet", "bitcoin-tx", "bitcoin-util"];
+
+/// Downloads Bitcoin Core `version` (e.g. "26.2") into the [`cache_dir`], returns the path of the
+/// `bitcoind` executable.
+///
+/// Nothing is downloaded if the version is already in the cache.
+pub fn fetch(version: &str) -> anyhow::Result<PathBuf> { fetch_into(version, &cache_dir()) }
+
+/// Downloads Bitcoin Core `version` into `dir`, returns the path of the `bitcoind` executable.
+///
+/// Nothing is downloaded if the version is already in `dir`.
+pub fn fetch_into(version: &str, dir: &Path) -> anyhow::Result<PathBuf> {
+    if !is_cached(version, dir) {
+        // Fail early for versions that cannot be verified.
+        expected_sha256(version, &download_filename(version))?;
+        let (url, bytes) = download(version)?;
+        install(version, dir, &url, &bytes)?;
+    }
+    Ok(exe_path_in(version, dir))
+}
+
+/// Returns the directory releases are downloaded to by [`fetch`].
+///
+/// This is `BITCOIND_DOWNLOAD_CACHE` if set, otherwise a `bitcoind` directory in the system temp
+/// directory. Use the same directory across projects to only download each release once.
+pub fn cache_dir() -> PathBuf {
+    match env::var_os("BITCOIND_DOWNLOAD_CACHE") {
+        Some(dir) => PathBuf::from(dir),
+        None => env::temp_dir().join("bitcoind"),
+    }
+}
+
+/// Returns the path of the `bitcoind` executable of `version` in `dir`.
+pub fn exe_path_in(version: &str, dir: &Path) -> PathBuf {
+    let mut path = dir.join(format!("bitcoin-{}", version)).join("bin");
+    if cfg!(target_os = "windows") {
+        path.push("bitcoind.exe");
+    } else {
+        path.push("bitcoind");
+    }
+    path
+}
+
+/// Returns `true` if all the executables of `version` are in `dir`.
+pub(crate) fn is_cached(version: &str, dir: &Path) -> bool {
+    if cfg!(target_os = "windows") {
+        return exe_path_in(version, dir).exists();
+    }
+    let version_dir = dir.join(format!("bitcoin-{}", version));
+    exe_path_in(version, dir).exists()
+        && version_dir.join("bin").join("bitcoin-cli").exists()
+        && version_dir.join("bin").join("bitcoin-wallet").exists()
+        && (major(version) < 30 || version_dir.join("libexec").join("bitcoin-node").exists())
+}
+
+/// Downloads the release archive of `version` for this os/arch, returns the url and the bytes.
+pub(crate) fn download(version: &str) -> anyhow::Result<(String, Vec<u8>)> {
+    let url = format!("{}/bitcoin-core-{}/{}", endpoint(), version, download_filename(version));
+    let resp = bitreq::get(&url).send().with_context(|| format!("cannot reach url {}", url))?;
+    if resp.status_code != 200 {
+        return Err(anyhow::anyhow!("url {} didn't return 200", url));
+    }
+    Ok((url, resp.as_bytes().to_vec()))
+}
+
+/// Verifies the release archive `bytes` of `version` read from `source` and unpacks the
+/// executables into `dir`.
+pub(crate) fn install(version: &str, dir: &Path, source: &str, bytes: &[u8]) -> anyhow::Result<()> {
+    let download_filename = download_filename(version);
+    #[cfg(feature = "download-gpg")]
+    verify_signatures(version)?;
+    let expected_hash = expected_sha256(version, &download_filename)?;
+    let hash = sha256::Hash::hash(bytes);
+    if expected_hash != hash {
+        return Err(Error::DownloadVerification(format!(
+            "hash of {} is {} but `SHA256SUMS` expects {}, the download is truncated or corrupt",
+            source, hash, expected_hash
+        ))
+        .into());
+    }
+
+    // Unpack into a staging directory first so that concurrent downloads of the same version
+    // never see a partially unpacked release.
+    let staging = dir.join(format!(".bitcoin-{}-{}", version, std::process::id()));
+    fs::create_dir_all(&staging).with_context(|| format!("cannot create dir {:?}", staging))?;
+    let res = unpack(&download_filename, bytes, &staging).and_then(|()| {
+        let unpacked = staging.join(format!("bitcoin-{}", version));
+        let version_dir = dir.join(format!("bitcoin-{}", version));
+        #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+        codesign(&unpacked)?;
+        if version_dir.exists() && !is_cached(version, dir) {
+            // An incomplete release left behind by an earlier version of this crate.
+            fs::remove_dir_all(&version_dir)
+                .with_context(|| format!("cannot remove dir {:?}", version_dir))?;
+        }
+        // If the rename fails because another process won the race the release is cached.
+        if fs::rename(&unpacked, &version_dir).is_err() && !is_cached(version, dir) {
+            return Err(anyhow::anyhow!("cannot move {:?} to {:?}", unpacked, version_dir));
+        }
+        Ok(())
+    });
+    let _ = fs::remove_dir_all(&staging);
+    res
+}
+
+/// Returns the file name of the release archive of `version` for this os/arch.
+fn download_filename(version: &str) -> String {
+    if cfg!(all(target_os = "macos", target_arch = "x86_64")) {
+        if major(version) < 23 {
+            return format!("bitcoin-{}-osx64.tar.gz", version);
+        }
+        return format!("bitcoin-{}-x86_64-apple-darwin.tar.gz", version);
+    }
+
+    if cfg!(all(target_os = "macos", target_arch = "aarch64")) {
+        return format!("bitcoin-{}-arm64-apple-darwin.tar.gz", version);
+    }
+
+    if cfg!(all(target_os = "linux", target_arch = "x86_64")) {
+        return format!("bitcoin-{}-x86_64-linux-gnu.tar.gz", version);
+    }
+
+    if cfg!(all(target_os = "linux", target_arch = "aarch64")) {
+        return format!("bitcoin-{}-aarch64-linux-gnu.tar.gz", version);
+    }
+
+    if cfg!(all(target_os = "windows", target_arch = "x86_64")) {
+        return format!("bitcoin-{}-win64.zip", version);
+    }
+
+    panic!("No download file for this os/arch");
+}
+
+/// Returns the major version number e.g., `0` for "0.21.2" and `26` for "26.2".
+fn major(version: &str) -> u32 {
+    version.split('.').next().and_then(|major| major.parse().ok()).unwrap_or(0)
+}
+
+fn endpoint() -> String {
+    env::var("BITCOIND_DOWNLOAD_ENDPOINT").unwrap_or("https://bitcoincore.org/bin".to_owned())
+}
+
+/// The vendored `SHA256SUMS` of each supported version.
+///
+/// Releases before v22 vendor the clearsigned `SHA256SUMS.asc`.
+const SHA256SUMS: &[(&str, &str)] = &[
+    ("0.17.2", include_str!("../sha256/bitcoin-core-0.17.2-SHA256SUMS.asc")),
+    ("0.18.1", include_str!("../sha256/bitcoin-core-0.18.1-SHA256SUMS.asc")),
+    ("0.19.1", include_str!("../sha256/bitcoin-core-0.19.1-SHA256SUMS.asc")),
+    ("0.20.2", include_str!("../sha256/bitcoin-core-0.20.2-SHA256SUMS.asc")),
+    ("0.21.2", include_str!("../sha256/bitcoin-core-0.21.2-SHA256SUMS.asc")),
+    ("22.0", include_str!("../sha256/bitcoin-core-22.0-SHA256SUMS")),
+    ("22.1", include_str!("../sha256/bitcoin-core-22.1-SHA256SUMS")),
+    ("23.0", include_str!("../sha256/bitcoin-core-23.0-SHA256SUMS")),
+    ("23.1", include_str!("../sha256/bitcoin-core-23.1-SHA256SUMS")),
+    ("23.2", include_str!("../sha256/bitcoin-core-23.2-SHA256SUMS")),
+    ("24.0.1", include_str!("../sha256/bitcoin-core-24.0.1-SHA256SUMS")),
+    ("24.1", include_str!("../sha256/bitcoin-core-24.1-SHA256SUMS")),
+    ("24.2", include_str!("../sha256/bitcoin-core-24.2-SHA256SUMS")),
+    ("25.0", include_str!("../sha256/bitcoin-core-25.0-SHA256SUMS")),
+    ("25.1", include_str!("../sha256/bitcoin-core-25.1-SHA256SUMS")),
+    ("25.2", include_str!("../sha256/bitcoin-core-25.2-SHA256SUMS")),
+    ("26.0", include_str!("../sha256/bitcoin-core-26.0-SHA256SUMS")),
+    ("26.1", include_str!("../sha256/bitcoin-core-26.1-SHA256SUMS")),
+    ("26.2", include_str!("../sha256/bitcoin-core-26.2-SHA256SUMS")),
+    ("27.0", include_str!("../sha256/bitcoin-core-27.0-SHA256SUMS")),
+    ("27.1", include_str!("../sha256/bitcoin-core-27.1-SHA256SUMS")),
+    ("27.2", include_str!("../sha256/bitcoin-core-27.2-SHA256SUMS")),
+    ("28.0", include_str!("../sha256/bitcoin-core-28.0-SHA256SUMS")),
+    ("28.1", include_str!("../sha256/bitcoin-core-28.1-SHA256SUMS")),
+    ("28.2", include_str!("../sha256/bitcoin-core-28.2-SHA256SUMS")),
+    ("29.0", include_str!("../sha256/bitcoin-core-29.0-SHA256SUMS")),
+    ("30.0", include_str!("../sha256/bitcoin-core-30.0-SHA256SUMS")),
+    ("30.2", include_str!("../sha256/bitcoin-core-30.2-SHA256SUMS")),
+    ("31.0", include_str!("../sha256/bitcoin-core-31.0-SHA256SUMS")),
+];
+
+/// Returns the vendored `SHA256SUMS` of `version`, `None` if the version is not supported.
+fn sha256sums(version: &str) -> Option<&'static str> {
+    SHA256SUMS.iter().find(|(v, _)| *v == version).map(|(_, sums)| *sums)
+}
+
+/// Returns the hash of the release archive `filename` from the vendored `SHA256SUMS`.
+fn expected_sha256(version: &str, filename: &str) -> anyhow::Result<sha256::Hash> {
+    let sums = sha256sums(version).ok_or_else(|| {
+        Error::DownloadVerification(format!("no `SHA256SUMS` for unsupported version {}", version))
+    })?;
+    for line in sums.lines() {
+        let tokens: Vec<_> = line.split("  ").collect();
+        if tokens.len() == 2 && filename == tokens[1] {
+            return sha256::Hash::from_str(tokens[0]).map_err(|_| {
+                Error::DownloadVerification(format!("invalid hash for `{}`", filename)).into()
+            });
+        }
+    }
+    Err(Error::DownloadVerification(format!(
+        "couldn't find hash for `{}` in `SHA256SUMS` of {}",
+        filename, version
+    ))
+    .into())
+}
+
+/// Verifies the signatures on the vendored `SHA256SUMS` using the keys in the `gpg` keyring,
+/// `dir` is used for temporary files.
+///
+/// At least one signature must be valid and none may be bad, signatures made with keys that are
+/// not in the keyring are ignored. Import the builder keys from
+/// <https://github.com/bitcoin-core/guix.sigs/tree/main/builder-keys> first.
+#[cfg(feature = "download-gpg")]
+fn verify_signatures(version: &str) -> anyhow::Result<()> {
+    use std::process::Command;
+
+    let sums = sha256sums(version).ok_or_else(|| {
+        Error::DownloadVerification(format!("no `SHA256SUMS` for unsupported version {}", version))
+    })?;
+    // Not in the download directory, which can be shared by concurrent builds.
+    let tmp = tempfile::tempdir().context("cannot create a temporary directory")?;
+    let dir = tmp.path();
+    let sums_filename = dir.join(format!("bitcoin-core-{}-SHA256SUMS", version));
+    fs::write(&sums_filename, sums).with_context(|| format!("cannot write {:?}", sums_filename))?;
+
+    let mut gpg = Command::new("gpg");
+    gpg.args(["--status-fd", "1", "--verify"]);
+    // Releases before v22 vendor a clearsigned `SHA256SUMS.asc`, later releases publish the
+    // signatures in a separate file.
+    if major(version) >= 22 {
+        let signatures = match env::var("BITCOIND_SIGNATURES_FILE") {
+            Ok(path) => fs::read(&path).with_context(|| {
+                format!("Cannot read {:?} specified with env var BITCOIND_SIGNATURES_FILE", path)
+            })?,
+            Err(_) => {
+                let url = format!("{}/bitcoin-core-{}/SHA256SUMS.asc", endpoint(), version);
+                let resp = bitreq::get(&url)
+                    .send()
+                    .with_context(|| format!("cannot reach url {}", url))?;
+                if resp.status_code != 200 {
+                    return Err(anyhow::anyhow!("url {} returned {}", url, resp.status_code));
+                }
+                resp.as_bytes().to_vec()
+            }
+        };
+        let signatures_filename = dir.join(format!("bitcoin-core-{}-SHA256SUMS.asc", version));
+        fs::write(&signatures_filename, signatures)
+            .with_context(|| format!("cannot write {:?}", signatures_filename))?;
+        gpg.arg(signatures_filename);
+    }
+    let output = gpg
+        .arg(&sums_filename)
+        .output()
+        .context("cannot run gpg, it is required by the download-gpg feature")?;
+
+    let status = String::from_utf8_lossy(&output.stdout);
+    let valid = status.lines().filter(|line| line.starts_with("[GNUPG:] VALIDSIG")).count();
+    let bad = status.lines().any(|line| line.starts_with("[GNUPG:] BADSIG"));
+    if bad {
+        return Err(Error::DownloadVerification(format!(
+            "bad signature on `SHA256SUMS` of {}",
+            version
+        ))
+        .into());
+    }
+    if valid == 0 {
+        return Err(Error::DownloadVerification(format!(
+            "no valid signature on `SHA256SUMS` of {}, are the builder keys imported?",
+            version
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// Unpacks the executables from the release archive `bytes` into `dir`.
+fn unpack(download_filename: &str, bytes: &[u8], dir: &Path) -> anyhow::Result<()> {
+    if download_filename.ends_with(".tar.gz") {
+        let d = GzDecoder::new(bytes);
+
+        let mut targets: Vec<PathBuf> = vec![
+            PathBuf::from("bin/bitcoind"),
+            PathBuf::from("bin/bitcoin-cli"),
+            PathBuf::from("libexec/bitcoin-node"),
+        ];
+        targets.extend(TOOLS.iter().map(|tool| Path::new("bin").join(tool)));
+        let mut archive = Archive::new(d);
+        for mut entry in archive.entries()?.flatten() {
+            if let Ok(file) = entry.path() {
+                if targets.iter().any(|t| file.ends_with(t)) {
+                    entry.unpack_in(dir)?;
+                }
+            }
+        }
+    } else if download_filename.ends_with(".zip") {
+        let cursor = Cursor::new(bytes);
+        let mut archive = zip::ZipArchive::new(cursor)?;
+        for i in 0..zip::ZipArchive::len(&archive) {
+            let mut file = archive.by_index(i)?;
+            let outpath = match file.enclosed_name() {
+                Some(path) => path.to_owned(),
+                None => continue,
+            };
+
+            let name =
+                outpath.file_name().and_then(|s| s.to_str()).and_then(|s| s.strip_suffix(".exe"));
+            if name.is_some_and(|name| name == "bitcoind" || TOOLS.contains(&name)) {
+                let dest = dir.join(&outpath);
+                let parent = dest.parent().context("unpacked file has no parent")?;
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("cannot create dir {:?}", parent))?;
+                let mut outfile = fs::File::create(&dest)
+                    .with_context(|| format!("cannot create file {:?}", dest))?;
+                io::copy(&mut file, &mut outfile)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Code signs the executables in `version_dir`, required on arm64 macOS.
+#[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+fn codesign(version_dir: &Path) -> anyhow::Result<()> {
+    use std::process::Command;
+
+    let to_sign = [
+        version_dir.join("bin").join("bitcoind"),
+        version_dir.join("bin").join("bitcoin-cli"),
+        version_dir.join("libexec").join("bitcoin-node"),
+        version_dir.join("bin").join("bitcoin-wallet"),
+        version_dir.join("bin").join("bitcoin-tx"),
+        version_dir.join("bin").join("bitcoin-util"),
+    ];
+    for binary in to_sign.iter().filter(|p| p.exists()) {
+        let signing_status = Command::new("codesign")
+            .arg("-v")
+            .arg(binary)
+            .status()
+            .with_context(|| format!("failed to verify code signature on {:?}", binary))?;
+
+        if !signing_status.success() {
+            let status = Command::new("codesign")
+                .arg("-s")
+                .arg("-")
+                .arg(binary)
+                .status()
+                .with_context(|| format!("failed to sign {:?}", binary))?;
+            if !status.success() {
+                return Err(anyhow::anyhow!(
+                    "codesign failed for {:?} with exit code {}",
+                    binary,
+                    status.code().unwrap_or(-1)
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_version_has_sha256sums() {
+        for (version, _) in SHA256SUMS {
+            assert!(expected_sha256(version, &download_filename(version)).is_ok(), "{}", version);
+        }
+        assert!(sha256sums("99.0").is_none());
+    }
+
+    #[test]
+    fn major_version() {
+        assert_eq!(major("0.21.2"), 0);
+        assert_eq!(major("24.0.1"), 24);
+        assert_eq!(major("31.0"), 31);
+    }
+}
@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Encrypting the wallet of the node at startup, see [`Conf::wallet_passphrase`].
+//!
+//! [`Conf::wallet_passphrase`]: crate::Conf::wallet_passphrase
+
+use std::time::Duration;
+
+use anyhow::Context;
+use serde_json::Value;
+
+use crate::{BitcoinD, Client, Conf};
+
+/// Encrypts the wallet `client` is for with the [`Conf::wallet_passphrase`] of `conf`, if set.
+///
+/// A wallet loaded from [`Conf::staticdir`] may already be encrypted, it is then left as is.
+pub(crate) fn encrypt_wallet(client: &Client, conf: &Conf) -> anyhow::Result<()> {
+    let Some(passphrase) = &conf.wallet_passphrase else { return Ok(()) };
+    anyhow::ensure!(conf.wallet.is_some(), "encrypting the wallet requires a wallet");
+
+    let info: Value = client.call("getwalletinfo", &[])?;
+    // Only encrypted wallets have the field.
+    if info.get("unlocked_until").is_none() {
+        client.encrypt_wallet(passphrase)?;
+    }
+    Ok(())
+}
+
+impl BitcoinD {
+    /// Unlocks the wallet of [`BitcoinD::client`] for `duration`, rounded down to whole seconds.
+    ///
+    /// The wallet must have been encrypted with [`Conf::wallet_passphrase`]. It locks itself once
+    /// `duration` passes, or when [`BitcoinD::lock_wallet`] is called.
+    pub fn unlock_wallet(&self, duration: Duration) -> anyhow::Result<()> {
+        let passphrase = self
+            .launch
+            .wallet_passphrase
+            .as_deref()
+            .context("the wallet is not encrypted, set `Conf::wallet_passphrase`")?;
+        self.client.wallet_passphrase(passphrase, duration.as_secs())?;
+        Ok(())
+    }
+
+    /// Locks the wallet of [`BitcoinD::client`], unlocked with [`BitcoinD::unlock_wallet`].
+    pub fn lock_wallet(&self) -> anyhow::Result<()> {
+        self.client.wallet_lock()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,338 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! New blocks and transactions of a node as a channel, see [`BitcoinD::events`].
+//!
+//! With [`Conf::enable_zmq`] the events are read from the `rawblock` and `rawtx` ZMQ publishers of
+//! the node, otherwise the node is polled over RPC. The ZMQ subscriber speaks ZMTP 3.0 directly,
+//! it only supports what Core publishes: the `NULL` security mechanism and multipart messages.
+//!
+//! [`Conf::enable_zmq`]: crate::Conf::enable_zmq
+
+use std::collections::HashSet;
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::str::FromStr;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use corepc_client::bitcoin::block::Header;
+use corepc_client::bitcoin::consensus::encode;
+use corepc_client::bitcoin::{BlockHash, Transaction, Txid};
+use corepc_client::client_sync::Auth;
+use serde_json::Value;
+
+use crate::{BitcoinD, Client};
+
+/// How often the node is polled when ZMQ is not enabled.
+const EVENTS_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How many blocks back from a new tip are reported at most, after a reorg.
+const MAX_NEW_BLOCKS: usize = 100;
+
+/// How long to wait to connect to a publisher and for the handshake to complete.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long to give the publisher to process the subscription before returning.
+///
+/// ZMQ has no acknowledgement of subscriptions, messages published before the publisher processed
+/// it are not sent to this subscriber.
+const SUBSCRIBE_DELAY: Duration = Duration::from_millis(100);
+
+/// Largest frame accepted, blocks are at most 4 MB.
+const MAX_FRAME_LEN: u64 = 32 * 1024 * 1024;
+
+/// Frame flag: more frames of the same message follow.
+const MORE: u8 = 0x01;
+
+/// Frame flag: the size is 8 bytes instead of 1.
+const LONG: u8 = 0x02;
+
+/// Frame flag: the frame is a command, not part of a message.
+const COMMAND: u8 = 0x04;
+
+/// A new block or transaction of the node, see [`BitcoinD::events`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeEvent {
+    /// A block was connected to the chain, it may not be the tip after a reorg.
+    Block(BlockHash),
+    /// A transaction was added to the mempool, or is in a block that was connected.
+    Tx(Txid),
+}
+
+impl BitcoinD {
+    /// Returns a channel receiving the blocks and transactions of the node from now on.
+    ///
+    /// Uses the ZMQ publishers of the node if [`Conf::enable_zmq`] is set, otherwise polls the
+    /// node every 100 ms. The transactions of a connected block are sent before the block, though
+    /// with ZMQ blocks and transactions come from different publishers and may be interleaved.
+    /// Polling only reports the transactions seen in the mempool, not those added and removed in
+    /// between two polls.
+    ///
+    /// Each call returns a new channel. Events are sent until the channel is dropped or the node
+    /// stops, a restarted node needs a new channel.
+    ///
+    /// [`Conf::enable_zmq`]: crate::Conf::enable_zmq
+    pub fn events(&self) -> anyhow::Result<Receiver<NodeEvent>> {
+        let (sender, events) = mpsc::channel();
+        let params = &self.params;
+        match (params.zmq_pub_raw_block_socket, params.zmq_pub_raw_tx_socket) {
+            (Some(block_socket), Some(tx_socket)) => {
+                for (socket, topic) in [(tx_socket, "rawtx"), (block_socket, "rawblock")] {
+                    let stream = subscribe(socket, topic.as_bytes())?;
+                    let sender = sender.clone();
+                    thread::spawn(move || read_messages(stream, &sender));
+                }
+                thread::sleep(SUBSCRIBE_DELAY);
+            }
+            _ => {
+                let auth = Auth::CookieFile(params.cookie_file.clone());
+                let client = Client::new_with_auth(&self.rpc_url(), auth)?;
+                let mut poller = Poller::new(client)?;
+                thread::spawn(move || {
+                    while let Ok(true) = poller.poll(&sender) {
+                        thread::sleep(EVENTS_POLL_INTERVAL);
+                    }
+                });
+            }
+        }
+        Ok(events)
+    }
+}
+
+/// Polls a node for the blocks and transactions it did not have at the last poll.
+struct Poller {
+    client: Client,
+    /// The blocks already seen, new blocks are looked for from the tip back to one of them.
+    blocks: HashSet<BlockHash>,
+    /// The transactions in the mempool at the last poll.
+    mempool: HashSet<Txid>,
+}
+
+impl Poller {
+    /// Creates a poller reporting the changes from the current state of the node.
+    fn new(client: Client) -> anyhow::Result<Self> {
+        let tip: String = client.call("getbestblockhash", &[])?;
+        let mut poller = Poller { client, blocks: HashSet::new(), mempool: HashSet::new() };
+        poller.blocks.insert(BlockHash::from_str(&tip)?);
+        poller.mempool = poller.fetch_mempool()?;
+        Ok(poller)
+    }
+
+    /// Sends the changes since the last poll, returns `false` if the channel was dropped.
+    fn poll(&mut self, events: &Sender<NodeEvent>) -> anyhow::Result<bool> {
+        let mempool = self.fetch_mempool()?;
+        for txid in mempool.difference(&self.mempool) {
+            if events.send(NodeEvent::Tx(*txid)).is_err() {
+                return Ok(false);
+            }
+        }
+        self.mempool = mempool;
+
+        for hash in self.new_blocks()? {
+            let block: Value =
+                self.client.call("getblock", &[hash.to_string().into(), 1.into()])?;
+            let txids = block["tx"].as_array().map(Vec::as_slice).unwrap_or_default();
+            for txid in txids.iter().filter_map(Value::as_str) {
+                if events.send(NodeEvent::Tx(Txid::from_str(txid)?)).is_err() {
+                    return Ok(false);
+                }
+            }
+            self.blocks.insert(hash);
+            if events.send(NodeEvent::Block(hash)).is_err() {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Returns the txids of the transactions in the mempool.
+    fn fetch_mempool(&self) -> anyhow::Result<HashSet<Txid>> {
+        let txids: Vec<String> = self.client.call("getrawmempool", &[])?;
+        Ok(txids.iter().map(|txid| Txid::from_str(txid)).collect::<Result<_, _>>()?)
+    }
+
+    /// Returns the blocks from the last one seen to the tip, oldest first.
+    fn new_blocks(&self) -> anyhow::Result<Vec<BlockHash>> {
+        let tip: String = self.client.call("getbestblockhash", &[])?;
+        let mut hash = BlockHash::from_str(&tip)?;
+        let mut blocks = vec![];
+        while !self.blocks.contains(&hash) && blocks.len() < MAX_NEW_BLOCKS {
+            blocks.push(hash);
+            let header: Value = self.client.call("getblockheader", &[hash.to_string().into()])?;
+            match header["previousblockhash"].as_str() {
+                Some(previous) => hash = BlockHash::from_str(previous)?,
+                None => break,
+            }
+        }
+        blocks.reverse();
+        Ok(blocks)
+    }
+}
+
+/// Connects to the ZMQ publisher at `socket` and subscribes to `topic`.
+fn subscribe(socket: SocketAddr, topic: &[u8]) -> io::Result<TcpStream> {
+    let mut stream = TcpStream::connect_timeout(&socket, HANDSHAKE_TIMEOUT)?;
+    stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT))?;
+
+    stream.write_all(&greeting())?;
+    let mut greeting = [0; 64];
+    stream.read_exact(&mut greeting)?;
+    if greeting[0] != 0xff || greeting[9] != 0x7f || greeting[10] < 3 {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "not a ZMTP 3 publisher"));
+    }
+
+    write_frame(&mut stream, COMMAND, &ready())?;
+    let (flags, _) = read_frame(&mut stream)?;
+    if flags & COMMAND == 0 {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "expected the READY command"));
+    }
+
+    // A ZMTP 3.0 subscription is a message starting with 1.
+    let mut subscription = vec![1];
+    subscription.extend_from_slice(topic);
+    write_frame(&mut stream, 0, &subscription)?;
+    stream.set_read_timeout(None)?;
+    Ok(stream)
+}
+
+/// Returns the ZMTP 3.0 greeting of a client using the `NULL` mechanism.
+fn greeting() -> [u8; 64] {
+    let mut greeting = [0; 64];
+    greeting[0] = 0xff;
+    greeting[9] = 0x7f;
+    greeting[10] = 3;
+    greeting[12..16].copy_from_slice(b"NULL");
+    greeting
+}
+
+/// Returns the body of the `READY` command of a `SUB` socket.
+fn ready() -> Vec<u8> {
+    let mut ready = vec![5];
+    ready.extend_from_slice(b"READY");
+    ready.push(11);
+    ready.extend_from_slice(b"Socket-Type");
+    ready.extend_from_slice(&3_u32.to_be_bytes());
+    ready.extend_from_slice(b"SUB");
+    ready
+}
+
+/// Writes a frame with `flags` and `body`.
+fn write_frame<W: Write>(writer: &mut W, flags: u8, body: &[u8]) -> io::Result<()> {
+    match u8::try_from(body.len()) {
+        Ok(len) => writer.write_all(&[flags, len])?,
+        Err(_) => {
+            writer.write_all(&[flags | LONG])?;
+            writer.write_all(&(body.len() as u64).to_be_bytes())?;
+        }
+    }
+    writer.write_all(body)
+}
+
+/// Reads a frame, returns its flags and body.
+fn read_frame<R: Read>(reader: &mut R) -> io::Result<(u8, Vec<u8>)> {
+    let mut flags = [0; 1];
+    reader.read_exact(&mut flags)?;
+    let len = if flags[0] & LONG == 0 {
+        let mut len = [0; 1];
+        reader.read_exact(&mut len)?;
+        u64::from(len[0])
+    } else {
+        let mut len = [0; 8];
+        reader.read_exact(&mut len)?;
+        u64::from_be_bytes(len)
+    };
+    if len > MAX_FRAME_LEN {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "frame too large"));
+    }
+    let mut body = vec![0; len as usize];
+    reader.read_exact(&mut body)?;
+    Ok((flags[0], body))
+}
+
+/// Reads the next message, skipping commands.
+fn read_message<R: Read>(reader: &mut R) -> io::Result<Vec<Vec<u8>>> {
+    let mut parts = vec![];
+    loop {
+        let (flags, body) = read_frame(reader)?;
+        if flags & COMMAND != 0 {
+            continue;
+        }
+        parts.push(body);
+        if flags & MORE == 0 {
+            return Ok(parts);
+        }
+    }
+}
+
+/// Sends the events read from `stream` until the channel is dropped or the node disconnects.
+fn read_messages(mut stream: TcpStream, events: &Sender<NodeEvent>) {
+    while let Ok(parts) = read_message(&mut stream) {
+        // The parts are the topic, the body and a sequence number.
+        let event = match parts.as_slice() {
+            [topic, body, ..] => event(topic, body),
+            _ => None,
+        };
+        if let Some(event) = event {
+            if events.send(event).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+/// Returns the event of a message published on `topic`, `None` if it cannot be parsed.
+fn event(topic: &[u8], body: &[u8]) -> Option<NodeEvent> {
+    match topic {
+        b"rawblock" => {
+            let header: Header = encode::deserialize(body.get(..80)?).ok()?;
+            Some(NodeEvent::Block(header.block_hash()))
+        }
+        b"rawtx" => {
+            let tx: Transaction = encode::deserialize(body).ok()?;
+            Some(NodeEvent::Tx(tx.compute_txid()))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use corepc_client::bitcoin::blockdata::constants::genesis_block;
+    use corepc_client::bitcoin::Network;
+
+    use super::*;
+
+    #[test]
+    fn frames_round_trip() {
+        let mut bytes = vec![];
+        write_frame(&mut bytes, COMMAND, &ready()).unwrap();
+        write_frame(&mut bytes, MORE, b"rawtx").unwrap();
+        write_frame(&mut bytes, MORE, &[7; 300]).unwrap();
+        write_frame(&mut bytes, 0, &[0; 4]).unwrap();
+        assert_eq!(bytes[ready().len() + 9], MORE | LONG);
+
+        let mut reader = Cursor::new(bytes);
+        let parts = read_message(&mut reader).unwrap();
+        assert_eq!(parts, [b"rawtx".to_vec(), vec![7; 300], vec![0; 4]]);
+        assert!(read_frame(&mut reader).is_err());
+
+        let mut reader = Cursor::new([LONG, 0xff, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(read_frame(&mut reader).unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn events_from_messages() {
+        let block = genesis_block(Network::Regtest);
+        let tx = &block.txdata[0];
+        assert_eq!(
+            event(b"rawblock", &encode::serialize(&block)),
+            Some(NodeEvent::Block(block.block_hash()))
+        );
+        assert_eq!(event(b"rawtx", &encode::serialize(tx)), Some(NodeEvent::Tx(tx.compute_txid())));
+        assert_eq!(event(b"rawblock", &[0; 79]), None);
+        assert_eq!(event(b"hashtx", &[0; 32]), None);
+    }
+}
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The version of a `bitcoind` executable, from `bitcoind -version`.
+//!
+//! Read before launching the node, so that the args passed to it match the executable run and
+//! not the version selected by the version feature.
+
+use std::ffi::OsStr;
+use std::process::Command;
+
+/// Returns the version of the `bitcoind` at `exe` e.g., `28.2.0`.
+///
+/// `None` if `exe` cannot be run or does not print a version e.g., a script wrapping `bitcoind`.
+pub(crate) fn exe_version(exe: &OsStr) -> Option<String> {
+    let output = Command::new(exe).arg("-version").output().ok()?;
+    parse_version(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Returns the version in the first line printed by `bitcoind -version` e.g., `28.2.0` for
+/// `Bitcoin Core version v28.2.0`.
+fn parse_version(stdout: &str) -> Option<String> {
+    let (_, version) = stdout.lines().next()?.split_once(" version v")?;
+    let version = version.split_whitespace().next()?;
+    major(version).map(|_| version.to_owned())
+}
+
+/// Returns the major version of the Core version `version` e.g., `21` for `0.21.2`.
+pub(crate) fn major(version: &str) -> Option<u32> {
+    let mut parts = version.split('.');
+    let first = parts.next()?;
+    let major = if first == "0" { parts.next()? } else { first };
+    major.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn major_version() {
+        assert_eq!(major("0.17.2"), Some(17));
+        assert_eq!(major("0.21.2"), Some(21));
+        assert_eq!(major("28.2"), Some(28));
+        assert_eq!(major("29.99.0-abcdef"), Some(29));
+        assert_eq!(major("master"), None);
+        assert!(major(crate::VERSION).is_some_and(|major| major >= 17));
+    }
+
+    #[test]
+    fn parse_version_output() {
+        let v17 = "Bitcoin Core Daemon version v0.17.2\nCopyright (C) 2009-2018\n";
+        assert_eq!(parse_version(v17).as_deref(), Some("0.17.2"));
+        let v28 = "Bitcoin Core version v28.2.0\nCopyright (C) 2009-2025\n";
+        assert_eq!(parse_version(v28).as_deref(), Some("28.2.0"));
+        assert_eq!(parse_version("usage: wrapper.sh <args>\n"), None);
+    }
+}
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Feeding the fee estimator of a regtest node until `estimatesmartfee` returns estimates.
+//!
+//! A fresh node answers `estimatesmartfee` with "Insufficient data or no feerate found" until it
+//! saw enough transactions confirm, see [`BitcoinD::bootstrap_fee_estimates`].
+
+use corepc_client::bitcoin::Amount;
+
+use crate::BitcoinD;
+
+/// The feerates, in sat/vB, of the transactions sent before each block.
+const FEE_RATES: [u64; 9] = [1, 2, 3, 5, 8, 13, 21, 34, 55];
+
+/// The amount of each transaction, small enough for the wallet to keep many coins.
+const PAYMENT: Amount = Amount::from_sat(100_000);
+
+/// The confirmation target estimates are asked for, the lowest Core accepts.
+const CONF_TARGET: u32 = 2;
+
+impl BitcoinD {
+    /// Sends transactions at a spread of feerates and mines them until `estimatesmartfee` returns
+    /// an estimate, returns the number of blocks mined.
+    ///
+    /// Mines at most `blocks` blocks, after which the error of `estimatesmartfee` is returned.
+    /// The node estimates fees after a handful of blocks, about ten is enough. The node wallet is
+    /// funded first if it has no spendable coins.
+    pub fn bootstrap_fee_estimates(&self, blocks: usize) -> anyhow::Result<usize> {
+        if self.client.get_balance()?.into_model()?.0 < PAYMENT * FEE_RATES.len() as u64 {
+            self.fund_node_wallet()?;
+        }
+
+        let mut errors = Vec::new();
+        for mined in 0..blocks {
+            // The estimator ignores transactions spending unconfirmed outputs, the payments and
+            // change of a block are spent after it.
+            for sat_per_vb in FEE_RATES {
+                self.send_at_fee_rate(sat_per_vb)?;
+            }
+            self.mine_blocks(1)?;
+
+            let estimate =
+                self.client.call::<serde_json::Value>("estimatesmartfee", &[CONF_TARGET.into()])?;
+            if estimate["feerate"].is_number() {
+                return Ok(mined + 1);
+            }
+            errors = estimate["errors"].as_array().cloned().unwrap_or_default();
+        }
+        anyhow::bail!("no fee estimate after {} blocks: {:?}", blocks, errors)
+    }
+
+    /// Sends [`PAYMENT`] to the node wallet at `sat_per_vb`.
+    ///
+    /// Uses `fundrawtransaction` since `settxfee` and the feerate argument of `sendtoaddress` are
+    /// not available in all versions.
+    fn send_at_fee_rate(&self, sat_per_vb: u64) -> anyhow::Result<()> {
+        let address = self.client.new_address()?;
+        let outputs = serde_json::json!({ address.to_string(): PAYMENT.to_btc() });
+        let tx = self
+            .client
+            .call::<serde_json::Value>("createrawtransaction", &[serde_json::json!([]), outputs])?;
+
+        // `feeRate` is in BTC/kvB.
+        let fee_rate = Amount::from_sat(sat_per_vb * 1_000).to_btc();
+        let options = serde_json::json!({ "feeRate": fee_rate });
+        let funded = self.client.call::<serde_json::Value>("fundrawtransaction", &[tx, options])?;
+        let signed = self
+            .client
+            .call::<serde_json::Value>("signrawtransactionwithwallet", &[funded["hex"].clone()])?;
+        anyhow::ensure!(signed["complete"] == true, "cannot sign: {}", signed["errors"]);
+        let _ = self
+            .client
+            .call::<serde_json::Value>("sendrawtransaction", &[signed["hex"].clone()])?;
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A cheap health check of a running node e.g., for long running soak tests.
+
+use std::time::Duration;
+
+use crate::BitcoinD;
+
+/// The health of a node, see [`BitcoinD::health`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Health {
+    /// The `bitcoind` process is still running.
+    pub alive: bool,
+    /// How long the node has been running, `None` if the process exited.
+    pub uptime: Option<Duration>,
+    /// The warnings from `getblockchaininfo`.
+    pub blockchain_warnings: Vec<String>,
+    /// The warnings from `getnetworkinfo`.
+    pub network_warnings: Vec<String>,
+    /// The number of peers, `None` if the process exited.
+    pub connections: Option<usize>,
+    /// The number of inbound peers, Core v21 and later only.
+    pub connections_in: Option<usize>,
+    /// The number of outbound peers, Core v21 and later only.
+    pub connections_out: Option<usize>,
+}
+
+impl Health {
+    /// Returns `true` if the node is running and reports no warnings.
+    pub fn is_healthy(&self) -> bool {
+        self.alive && self.blockchain_warnings.is_empty() && self.network_warnings.is_empty()
+    }
+
+    /// The health of a node whose process exited.
+    fn exited() -> Self {
+        Health {
+            alive: false,
+            uptime: None,
+            blockchain_warnings: vec![],
+            network_warnings: vec![],
+            connections: None,
+            connections_in: None,
+            connections_out: None,
+        }
+    }
+}
+
+impl BitcoinD {
+    /// Checks that the process is running and queries `uptime`, `getblockchaininfo` and
+    /// `getnetworkinfo`.
+    ///
+    /// Returns an error if the process is running but one of the RPC calls fails.
+    pub fn health(&mut self) -> anyhow::Result<Health> {
+        if self.process.try_wait()?.is_some() {
+            return Ok(Health::exited());
+        }
+        let uptime = Duration::from_secs(self.client.uptime()?.into());
+        let blockchain = self.client.get_blockchain_info()?.into_model()?;
+        let network = self.client.get_network_info()?.into_model()?;
+        Ok(Health {
+            alive: true,
+            uptime: Some(uptime),
+            blockchain_warnings: non_empty(blockchain.warnings),
+            network_warnings: non_empty(network.warnings),
+            connections: Some(network.connections),
+            connections_in: network.connections_in,
+            connections_out: network.connections_out,
+        })
+    }
+}
+
+/// Returns the non-empty `warnings`, Core before v28 returns a single, possibly empty, string.
+fn non_empty(warnings: Vec<String>) -> Vec<String> {
+    warnings.into_iter().filter(|warning| !warning.is_empty()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_warnings_removed() {
+        assert!(non_empty(vec![String::new()]).is_empty());
+        assert_eq!(non_empty(vec!["warning".to_string()]), vec!["warning"]);
+    }
+}
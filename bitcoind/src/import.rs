@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Importing descriptors into the wallet at startup, see [`Conf::import_descriptors`] and
+//! [`Conf::deterministic_seed`].
+//!
+//! [`Conf::import_descriptors`]: crate::Conf::import_descriptors
+//! [`Conf::deterministic_seed`]: crate::Conf::deterministic_seed
+
+use corepc_client::bitcoin::bip32::Xpriv;
+use corepc_client::bitcoin::Network;
+use serde_json::{json, Value};
+
+use crate::{Client, Conf};
+
+/// A descriptor imported into the wallet of the node at startup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DescriptorImport {
+    /// The output descriptor, its checksum is added if missing.
+    pub descriptor: String,
+    /// The UNIX time from which the chain is rescanned for the descriptor, `None` for "now".
+    pub timestamp: Option<u64>,
+    /// The range of indexes imported, for a ranged descriptor.
+    pub range: Option<(u32, u32)>,
+    /// The descriptor is used for change.
+    pub internal: bool,
+    /// The descriptor is used for new addresses, descriptor wallets only (Core v23 and later).
+    pub active: bool,
+}
+
+impl DescriptorImport {
+    /// Imports `descriptor` with no rescan, not ranged, as non-change and not active.
+    pub fn new<S: Into<String>>(descriptor: S) -> Self {
+        DescriptorImport {
+            descriptor: descriptor.into(),
+            timestamp: None,
+            range: None,
+            internal: false,
+            active: false,
+        }
+    }
+
+    /// Returns the request importing the descriptor `descriptor`, with its checksum.
+    fn request(&self, descriptor: String) -> Value {
+        let mut request = json!({
+            "desc": descriptor,
+            "timestamp": self.timestamp.map_or(json!("now"), Value::from),
+        });
+        if let Some((start, end)) = self.range {
+            request["range"] = json!([start, end]);
+        }
+        if self.internal {
+            request["internal"] = true.into();
+        }
+        if self.active && cfg!(feature = "23_2") {
+            request["active"] = true.into();
+        }
+        request
+    }
+}
+
+/// The last index of the ranged descriptors derived from a seed, the size of the Core keypool.
+const SEED_RANGE_END: u32 = 999;
+
+/// Returns the active descriptors derived from `seed`, for each address type and for receiving and
+/// change, with the BIP44, BIP49, BIP84 and BIP86 test network paths.
+fn seed_descriptors(seed: &[u8; 32]) -> Vec<DescriptorImport> {
+    // Test network keys, used on all networks the node may run on.
+    let xprv = Xpriv::new_master(Network::Regtest, seed).expect("a 32 byte seed is valid");
+    let templates =
+        [("pkh(", 44, ")"), ("sh(wpkh(", 49, "))"), ("wpkh(", 84, ")"), ("tr(", 86, ")")];
+    templates
+        .iter()
+        .flat_map(|(prefix, purpose, suffix)| {
+            [false, true].map(|internal| DescriptorImport {
+                timestamp: Some(0),
+                range: Some((0, SEED_RANGE_END)),
+                internal,
+                active: true,
+                ..DescriptorImport::new(format!(
+                    "{}{}/{}h/1h/0h/{}/*{}",
+                    prefix,
+                    xprv,
+                    purpose,
+                    u8::from(internal),
+                    suffix
+                ))
+            })
+        })
+        .collect()
+}
+
+/// Imports the [`Conf::import_descriptors`] of `conf`, and the descriptors derived from its
+/// [`Conf::deterministic_seed`], into the wallet `client` is for.
+///
+/// Uses `importdescriptors` if the wallet is a descriptor wallet, the default from Core v23, and
+/// `importmulti` otherwise. Core v0.17 cannot import descriptors.
+pub(crate) fn import_descriptors(client: &Client, conf: &Conf) -> anyhow::Result<()> {
+    let mut imports = conf.import_descriptors.clone();
+    if let Some(ref seed) = conf.deterministic_seed {
+        anyhow::ensure!(
+            cfg!(feature = "23_2"),
+            "a deterministic seed requires a descriptor wallet, Core v23 or later"
+        );
+        imports.extend(seed_descriptors(seed));
+    }
+    if imports.is_empty() {
+        return Ok(());
+    }
+    anyhow::ensure!(cfg!(feature = "0_18_1"), "importing descriptors requires Core v0.18 or later");
+    anyhow::ensure!(conf.wallet.is_some(), "importing descriptors requires a wallet");
+
+    let requests = imports
+        .iter()
+        .map(|import| {
+            let descriptor = if import.descriptor.contains('#') {
+                import.descriptor.clone()
+            } else {
+                let info: Value =
+                    client.call("getdescriptorinfo", &[import.descriptor.clone().into()])?;
+                let checksum = info["checksum"].as_str().unwrap_or_default();
+                format!("{}#{}", import.descriptor, checksum)
+            };
+            Ok(import.request(descriptor))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let method = if cfg!(feature = "23_2") { "importdescriptors" } else { "importmulti" };
+    // The results of both methods have the same `success` and `error` fields.
+    let results: Vec<Value> = client.call(method, &[requests.into()])?;
+    for (import, result) in imports.iter().zip(results) {
+        anyhow::ensure!(
+            result["success"] == true,
+            "{} failed for {}: {}",
+            method,
+            import.descriptor,
+            result["error"]
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_fields() {
+        let import = DescriptorImport::new("addr(bcrt1qxyz)");
+        assert_eq!(
+            import.request("addr(bcrt1qxyz)#abc".to_string()),
+            json!({ "desc": "addr(bcrt1qxyz)#abc", "timestamp": "now" })
+        );
+
+        let import = DescriptorImport {
+            timestamp: Some(0),
+            range: Some((0, 9)),
+            internal: true,
+            ..DescriptorImport::new("wpkh(tpub/0/*)")
+        };
+        let request = import.request("wpkh(tpub/0/*)#abc".to_string());
+        assert_eq!(request["timestamp"], json!(0));
+        assert_eq!(request["range"], json!([0, 9]));
+        assert_eq!(request["internal"], json!(true));
+    }
+
+    #[test]
+    fn seed_descriptors_are_deterministic() {
+        let imports = seed_descriptors(&[0; 32]);
+        assert_eq!(imports, seed_descriptors(&[0; 32]));
+        assert_ne!(imports, seed_descriptors(&[1; 32]));
+        assert_eq!(imports.len(), 8);
+        assert!(imports.iter().all(|import| import.active && import.descriptor.contains("tprv")));
+
+        let wpkh_change = &imports[5];
+        assert!(wpkh_change.internal);
+        assert!(wpkh_change.descriptor.starts_with("wpkh(tprv"));
+        assert!(wpkh_change.descriptor.ends_with("/84h/1h/0h/1/*)"));
+    }
+}
@@ -0,0 +1,86 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Optional indexes, see [`Conf::indexes`](crate::Conf::indexes).
+
+#[cfg(feature = "0_21_2")]
+use std::time::Duration;
+
+#[cfg(feature = "0_21_2")]
+use crate::wait::{poll, Poll};
+#[cfg(feature = "0_21_2")]
+use crate::BitcoinD;
+
+/// An optional index the node builds in the background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndexKind {
+    /// Transaction index, `-txindex`.
+    Tx,
+    /// Basic block filter index, `-blockfilterindex` (Core v0.19 and later).
+    BlockFilter,
+    /// UTXO set statistics index, `-coinstatsindex` (Core v22 and later).
+    CoinStats,
+}
+
+impl IndexKind {
+    /// Returns the name of the index in the result of `getindexinfo`.
+    pub fn name(self) -> &'static str {
+        match self {
+            IndexKind::Tx => "txindex",
+            IndexKind::BlockFilter => "basic block filter index",
+            IndexKind::CoinStats => "coinstatsindex",
+        }
+    }
+
+    /// Returns the arg enabling the index.
+    fn arg(self) -> &'static str {
+        match self {
+            IndexKind::Tx => "-txindex",
+            IndexKind::BlockFilter => "-blockfilterindex",
+            IndexKind::CoinStats => "-coinstatsindex",
+        }
+    }
+}
+
+/// Returns the args enabling `indexes`, without the transaction index if the node is `pruned`.
+pub(crate) fn index_args(indexes: &[IndexKind], pruned: bool) -> Vec<String> {
+    indexes
+        .iter()
+        .filter(|index| !(pruned && **index == IndexKind::Tx))
+        .map(|index| index.arg().to_string())
+        .collect()
+}
+
+#[cfg(feature = "0_21_2")]
+impl BitcoinD {
+    /// Waits until `getindexinfo` reports the `index` as synced.
+    ///
+    /// Calls using the index fail, or return incomplete results, until it is synced with the
+    /// chain. Returns a [`WaitTimeout`](crate::WaitTimeout) including the height the index got to
+    /// if it is not synced in `timeout`, or if the index is not enabled.
+    pub fn wait_for_index(&self, index: IndexKind, timeout: Duration) -> anyhow::Result<()> {
+        poll(format!("{} synced", index.name()), timeout, || {
+            let info = self.client.get_index_info()?.0;
+            Ok(match info.get(index.name()) {
+                Some(info) if info.synced => Poll::Ready,
+                Some(info) => Poll::Pending(format!("synced to height {}", info.best_block_height)),
+                None => Poll::Pending("index not enabled".to_string()),
+            })
+        })?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn args_skip_txindex_when_pruned() {
+        let indexes = [IndexKind::Tx, IndexKind::BlockFilter, IndexKind::CoinStats];
+        assert_eq!(
+            index_args(&indexes, false),
+            vec!["-txindex", "-blockfilterindex", "-coinstatsindex"]
+        );
+        assert_eq!(index_args(&indexes, true), vec!["-blockfilterindex", "-coinstatsindex"]);
+    }
+}
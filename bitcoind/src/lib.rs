@@ -8,21 +8,81 @@
 
 pub extern crate corepc_client as client;
 
+pub mod bench;
+mod capture;
+mod chain;
 #[rustfmt::skip]
 mod client_versions;
+mod core_version;
+#[cfg(feature = "download")]
+pub mod download;
+mod encryption;
+mod events;
+mod exe_version;
+mod fee_estimates;
+mod health;
+mod import;
+mod index;
+mod limit;
+mod log_events;
+pub mod logparse;
+pub mod mempool_dat;
+mod miner;
+mod mocktime;
+mod net;
+mod notify;
+mod offline;
+mod options;
+pub mod p2p;
+pub mod p2p_probe;
+mod peers;
+mod permissions;
+mod policy;
+mod ports;
+mod prune;
+mod reaper;
+mod reindex;
+mod resources;
+mod rpcauth;
+mod shutdown;
+pub mod signer;
+mod signet;
+#[cfg(feature = "26_2")]
+mod snapshot;
+mod template;
+mod tools;
+mod tor;
+pub mod utxo_snapshot;
 mod versions;
+mod wait;
+mod wrapper;
+mod zmq_check;
 
 use std::ffi::OsStr;
-use std::net::{Ipv4Addr, SocketAddrV4, TcpListener};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 use std::path::{Path, PathBuf};
-use std::process::{Child, Command, ExitStatus, Stdio};
+use std::process::{Child, ExitStatus, Stdio};
+use std::sync::atomic::AtomicU64;
+use std::sync::Mutex;
 use std::time::Duration;
 use std::{env, fmt, fs, thread};
 
+pub use anyhow;
 use anyhow::Context;
+use capture::{OutputCapture, CAPTURE_LINES};
+use corepc_client::bitcoin::ScriptBuf;
 use corepc_client::client_sync::{self, Auth};
+use limit::Permit;
+use log_events::LogEvents;
+use miner::Miner;
+use notify::Notifier;
+use ports::Reservation;
+use reaper::Reaper;
+use reindex::Launch;
+pub use serde_json;
+pub use tempfile;
 use tempfile::TempDir;
-pub use {anyhow, serde_json, tempfile, which};
+pub use which;
 
 #[rustfmt::skip]                // Keep pubic re-exports separate.
 #[doc(inline)]
@@ -31,6 +91,24 @@ pub use self::{
     client_versions::*,
     // Re-export the version string e.g., "28.0".
     versions::VERSION,
+    core_version::CoreVersion,
+    health::Health,
+    events::NodeEvent,
+    import::DescriptorImport,
+    index::IndexKind,
+    log_events::LogEvent,
+    net::Reachability,
+    notify::NotifyEvent,
+    permissions::Permission,
+    policy::PolicySnapshot,
+    resources::ResourceUsage,
+    rpcauth::RpcAuth,
+    shutdown::{ShutdownMode, StopReport},
+    signet::signet_challenge,
+    template::NodeTemplate,
+    tools::{exe_path_for, Tool},
+    tor::OnlyNet,
+    wait::WaitTimeout,
     // Re-export the model types as `mtype` to differentiate it from `vtype`.
     client::types::model as mtype, // `types` is the `corepc-types` crate.
 };
@@ -40,10 +118,39 @@ pub use self::{
 pub struct BitcoinD {
     /// Process child handle, used to terminate the process when this struct is dropped.
     process: Child,
+    /// The pid of the node, differs from the pid of `process` if a wrapper runs it as a child.
+    pid: u32,
+    /// The pid file of the node if it runs under [`Conf::wrapper`], read to find its pid.
+    pid_file: Option<PathBuf>,
     /// Rpc client linked to this bitcoind process.
     pub client: Client,
     /// Work directory, where the node store blocks and other stuff.
     work_dir: DataDir,
+    /// The log file of the node, in the network directory.
+    debug_log: PathBuf,
+    /// The `Conf` args the process was launched with.
+    args: Vec<String>,
+    /// How to shut the node down when dropped, `None` to use the default for the data directory.
+    shutdown_mode: Option<ShutdownMode>,
+    /// Leave the process running when dropped, see [`Conf::leave_running_on_drop`].
+    leave_running: bool,
+    /// The mock time last set on the node, `0` if it uses the system clock.
+    mocktime: AtomicU64,
+    /// The background miner, if running.
+    miner: Mutex<Option<Miner>>,
+    /// How the process was launched, used to restart it.
+    launch: Launch,
+    /// The version of the node, read at startup.
+    version: CoreVersion,
+    /// Reads the block and wallet notifications, if enabled.
+    notifier: Option<Notifier>,
+    /// Sends the events parsed from the node log, if enabled.
+    log_events: Option<LogEvents>,
+    /// Kills the process if this process dies without dropping this struct.
+    reaper: Option<Reaper>,
+    /// Counts the node against `CORPC_NODE_MAX_NODES`, held for its drop which releases it once
+    /// the process is shut down.
+    _permit: Option<Permit<'static>>,
 
     /// Contains information to connect to this node.
     pub params: ConnectParams,
@@ -75,13 +182,13 @@ pub struct ConnectParams {
     /// Path to the node cookie file, useful for other client to connect to the node.
     pub cookie_file: PathBuf,
     /// Url of the rpc of the node, useful for other client to connect to the node.
-    pub rpc_socket: SocketAddrV4,
+    pub rpc_socket: SocketAddr,
     /// p2p connection url, is some if the node started with p2p enabled.
-    pub p2p_socket: Option<SocketAddrV4>,
+    pub p2p_socket: Option<SocketAddr>,
     /// zmq pub raw block connection url.
-    pub zmq_pub_raw_block_socket: Option<SocketAddrV4>,
+    pub zmq_pub_raw_block_socket: Option<SocketAddr>,
     /// zmq pub raw tx connection Url.
-    pub zmq_pub_raw_tx_socket: Option<SocketAddrV4>,
+    pub zmq_pub_raw_tx_socket: Option<SocketAddr>,
 }
 
 pub struct CookieValues {
@@ -115,7 +222,7 @@ pub enum P2P {
     /// The node open a p2p port and also connects to the url given as parameter, it's handy to
     /// initialize this with [BitcoinD::p2p_connect] of another node. The `bool` parameter indicates
     /// if the node can accept connection too.
-    Connect(SocketAddrV4, bool),
+    Connect(SocketAddr, bool),
 }
 
 /// All the possible error in this crate.
@@ -131,8 +238,8 @@ pub enum Error {
     /// Returned when calling methods requiring the bitcoind executable but none is found
     /// (no feature, no `BITCOIND_EXE`, no `bitcoind` in `PATH` ).
     NoBitcoindExecutableFound,
-    /// Wrapper of early exit status.
-    EarlyExit(ExitStatus),
+    /// Wrapper of early exit status, includes the last lines the process wrote to stderr.
+    EarlyExit(ExitStatus, String),
     /// Returned when both tmpdir and staticdir is specified in `Conf` options.
     BothDirsSpecified,
     /// Returned when -rpcuser and/or -rpcpassword is used in `Conf` args.
@@ -143,6 +250,34 @@ pub enum Error {
     /// Returned when bitcoind could not be reached after multiple attempts.
     /// The attached string, if present, contains the error encountered when trying to connect.
     NoBitcoindInstance(String),
+    /// Returned when calling methods requiring a companion tool executable but none is found.
+    NoToolExecutableFound(Tool),
+    /// Returned when connecting to a node that was not launched with p2p enabled.
+    NoP2p,
+    /// Returned when a node launched with [`Conf::enable_zmq`] does not advertise a ZMQ
+    /// notification on the port allocated for it.
+    ZmqEndpointMismatch {
+        /// The notification e.g., `pubrawtx`.
+        notification: String,
+        /// The port allocated for the notification.
+        expected_port: u16,
+        /// The addresses advertised by `getzmqnotifications` for the notification.
+        advertised: Vec<String>,
+    },
+    /// Returned when an option in `Conf` args does not exist in the version of Core launched.
+    UnsupportedArg {
+        /// The arg e.g., `-mempoolfullrbf=1`.
+        arg: String,
+        /// The version of Core launched e.g., `28.2.0`.
+        version: String,
+        /// The versions having the option e.g., `added in v24`.
+        supported: String,
+    },
+    /// Returned when an arg in `Conf` args cannot be used with [`Conf::prune`] e.g., `-txindex`.
+    PruneConflict(String),
+    /// Returned when a downloaded release does not match the published `SHA256SUMS`, or the
+    /// signatures on `SHA256SUMS` could not be verified.
+    DownloadVerification(String),
 }
 
 impl fmt::Debug for Error {
@@ -155,11 +290,17 @@ impl fmt::Debug for Error {
             NoFeature => write!(f, "Called a method requiring a feature to be set, but it's not"),
             NoEnvVar => write!(f, "Called a method requiring env var `BITCOIND_EXE` to be set, but it's not"),
             NoBitcoindExecutableFound =>  write!(f, "`bitcoind` executable is required, provide it with one of the following: set env var `BITCOIND_EXE` or use a feature like \"22_1\" or have `bitcoind` executable in the `PATH`"),
-            EarlyExit(e) => write!(f, "The bitcoind process terminated early with exit code {}", e),
+            EarlyExit(e, stderr) => write!(f, "The bitcoind process terminated early with exit code {}, stderr:\n{}", e, stderr),
             BothDirsSpecified => write!(f, "tempdir and staticdir cannot be enabled at same time in configuration options"),
             RpcUserAndPasswordUsed => write!(f, "`-rpcuser` and `-rpcpassword` cannot be used, it will be deprecated soon and it's recommended to use `-rpcauth` instead which works alongside with the default cookie authentication"),
             SkipDownload => write!(f, "expecting an auto-downloaded executable but `BITCOIND_SKIP_DOWNLOAD` env var is set"),
             NoBitcoindInstance(msg) => write!(f, "it appears that bitcoind is not reachable: {}", msg),
+            NoToolExecutableFound(tool) => write!(f, "`{}` executable is required, provide it with one of the following: set env var `{}` or use the \"download\" feature or have `{}` next to `BITCOIND_EXE` or in the `PATH`", tool, tool.env_var(), tool),
+            NoP2p => write!(f, "the node does not listen for p2p connections, launch it with `P2P::Yes` or `P2P::Connect(_, true)`"),
+            ZmqEndpointMismatch { notification, expected_port, advertised } => write!(f, "the node does not publish `{}` on the allocated port {}, `getzmqnotifications` advertises {:?}", notification, expected_port, advertised),
+            UnsupportedArg { arg, version, supported } => write!(f, "`{}` is not supported by Core v{}, the option was {}", arg, version, supported),
+            PruneConflict(arg) => write!(f, "`{}` cannot be used with `Conf::prune`, Core does not start a pruned node with it", arg),
+            DownloadVerification(msg) => write!(f, "download verification failed: {}", msg),
         }
     }
 }
@@ -178,11 +319,17 @@ impl std::error::Error for Error {
             NoFeature
             | NoEnvVar
             | NoBitcoindExecutableFound
-            | EarlyExit(_)
+            | EarlyExit(..)
             | BothDirsSpecified
             | RpcUserAndPasswordUsed
             | SkipDownload
-            | NoBitcoindInstance(_) => None,
+            | NoBitcoindInstance(_)
+            | NoToolExecutableFound(_)
+            | NoP2p
+            | ZmqEndpointMismatch { .. }
+            | UnsupportedArg { .. }
+            | PruneConflict(_)
+            | DownloadVerification(_) => None,
         }
     }
 }
@@ -191,6 +338,9 @@ const LOCAL_IP: Ipv4Addr = Ipv4Addr::new(127, 0, 0, 1);
 
 const INVALID_ARGS: [&str; 2] = ["-rpcuser", "-rpcpassword"];
 
+/// Env var leaving all nodes running when dropped, see [`Conf::leave_running_on_drop`].
+const LEAVE_RUNNING_ENV_VAR: &str = "CORPC_NODE_LEAVE_RUNNING";
+
 /// The node configuration parameters, implements a convenient [Default] for most common use.
 ///
 /// `#[non_exhaustive]` allows adding new parameters without breaking downstream users.
@@ -207,6 +357,41 @@ const INVALID_ARGS: [&str; 2] = ["-rpcuser", "-rpcpassword"];
 /// conf.tmpdir = None;
 /// conf.staticdir = None;
 /// conf.attempts = 5;
+/// conf.startup_timeout = std::time::Duration::from_secs(5);
+/// conf.rpc_ready_timeout = std::time::Duration::from_secs(5);
+/// conf.poll_interval = std::time::Duration::from_millis(200);
+/// conf.shutdown = None;
+/// conf.leave_running_on_drop = false;
+/// conf.mocktime = None;
+/// conf.prune = None;
+/// conf.rpc_bind = std::net::Ipv4Addr::LOCALHOST.into();
+/// conf.p2p_bind = std::net::Ipv4Addr::LOCALHOST.into();
+/// conf.proxy = None;
+/// conf.onlynet = vec![];
+/// conf.tor_control = None;
+/// conf.tor_password = None;
+/// conf.i2p_sam = None;
+/// conf.cjdns_reachable = false;
+/// conf.external_signer = None;
+/// conf.block_notify = false;
+/// conf.wallet_notify = false;
+/// conf.indexes = vec![];
+/// conf.envs = vec![];
+/// conf.cwd = None;
+/// conf.whitelist = vec![];
+/// conf.whitebind = vec![];
+/// conf.rpc_auth = None;
+/// conf.cookie_file = None;
+/// conf.signet_challenge = None;
+/// conf.log_events = false;
+/// conf.wrapper = None;
+/// conf.offline = false;
+/// conf.import_descriptors = vec![];
+/// conf.wallet_passphrase = None;
+/// conf.deterministic_seed = None;
+/// conf.onion_proxy = None;
+/// conf.onion_bind = None;
+/// conf.i2p_accept_incoming = true;
 /// assert_eq!(conf, bitcoind::Conf::default());
 /// ```
 ///
@@ -254,10 +439,211 @@ pub struct Conf<'a> {
     pub attempts: u8,
 
     /// Enable the ZMQ interface to be accessible.
+    ///
+    /// Once started, the node must advertise the raw transaction and block publishers on the
+    /// allocated ports with `getzmqnotifications`, or launching it fails with
+    /// [`Error::ZmqEndpointMismatch`].
     pub enable_zmq: bool,
 
     /// Load `wallet` after initialization.
     pub wallet: Option<String>,
+
+    /// How long to wait for the node to write its cookie file before retrying.
+    pub startup_timeout: Duration,
+
+    /// How long to wait for the RPC server to answer calls before retrying.
+    ///
+    /// Increase this for slow machines or nodes doing a long `-reindex` on startup.
+    pub rpc_ready_timeout: Duration,
+
+    /// How often to check whether the node is ready while waiting.
+    pub poll_interval: Duration,
+
+    /// How long to wait for the node to get back to its tip when it is restarted e.g., by
+    /// [`BitcoinD::restart_with_reindex`].
+    ///
+    /// Increase this for nodes with a long chain, a reindex validates every block again.
+    pub reindex_timeout: Duration,
+
+    /// How to shut the node down when it is dropped.
+    ///
+    /// If `None` nodes with a persistent directory are shut down gracefully using
+    /// [`ShutdownMode::default`] and nodes with a temporary directory are killed.
+    pub shutdown: Option<ShutdownMode>,
+
+    /// Leave the node running when it is dropped, to inspect it after a test failed.
+    ///
+    /// The data directory is kept, even if temporary, and its path, the RPC URL and the cookie
+    /// file are printed to stderr. Also enabled by setting the `CORPC_NODE_LEAVE_RUNNING` env
+    /// var, which needs no code change. Stop the node with `bitcoin-cli stop` when done.
+    pub leave_running_on_drop: bool,
+
+    /// Start the node with `-mocktime` set to this UNIX timestamp (in seconds).
+    ///
+    /// Only supported on regtest, see [`BitcoinD::set_mocktime`] to change it once running.
+    pub mocktime: Option<u64>,
+
+    /// Start the node with `-prune` set to this target size (in MiB).
+    ///
+    /// Use `1` to only prune manually with [`BitcoinD::prune_to_height`], otherwise the value
+    /// must be at least `550`. Launching the node fails with [`Error::PruneConflict`] if
+    /// [`Conf::args`] has an arg that Core rejects in pruned mode i.e., `-txindex`.
+    pub prune: Option<u64>,
+
+    /// Address the RPC server listens on.
+    ///
+    /// Use `::1` for IPv6, or an unspecified address (`0.0.0.0` or `::`) to listen on all
+    /// interfaces e.g., to reach the node from another container. Connections are allowed from
+    /// this address only, or from anywhere if it is unspecified.
+    pub rpc_bind: IpAddr,
+
+    /// Address the p2p port listens on if [`Conf::p2p`] opens one.
+    pub p2p_bind: IpAddr,
+
+    /// SOCKS5 proxy used for outbound connections e.g., the Tor SOCKS port.
+    pub proxy: Option<SocketAddr>,
+
+    /// Only make outbound connections to these networks, all networks if empty.
+    pub onlynet: Vec<OnlyNet>,
+
+    /// Tor control port used to publish an onion service for the p2p port.
+    ///
+    /// See [`Conf::tor_only`] to only connect via Tor and [`BitcoinD::onion_address`] to get the
+    /// address of the published service.
+    pub tor_control: Option<SocketAddr>,
+
+    /// Password for the Tor control port, the Tor cookie file is used if `None`.
+    pub tor_password: Option<String>,
+
+    /// I2P SAM proxy used to connect to I2P peers, and to accept connections unless
+    /// [`Conf::i2p_accept_incoming`] is false. Supported since Core v22.
+    ///
+    /// See [`BitcoinD::reachability`] to check which networks the node can reach.
+    pub i2p_sam: Option<SocketAddr>,
+
+    /// Treat CJDNS addresses as reachable, the host must be running CJDNS. Supported since Core
+    /// v23.
+    pub cjdns_reachable: bool,
+
+    /// External signer command passed as `-signer`, enables the signer RPCs e.g.,
+    /// `enumeratesigners`.
+    ///
+    /// Use [`signer::install_mock`] to test without a hardware wallet. Supported since Core v22.
+    pub external_signer: Option<PathBuf>,
+
+    /// Report a [`NotifyEvent::Block`] each time the best block changes, using `-blocknotify`.
+    ///
+    /// See [`BitcoinD::take_notifications`] to receive the events.
+    pub block_notify: bool,
+
+    /// Report a [`NotifyEvent::Wallet`] each time a wallet transaction changes, using
+    /// `-walletnotify`.
+    ///
+    /// See [`BitcoinD::take_notifications`] to receive the events.
+    pub wallet_notify: bool,
+
+    /// Optional indexes to build, the transaction index is not built if [`Conf::prune`] is set.
+    ///
+    /// Indexes are built in the background, see `BitcoinD::wait_for_index` (Core v0.21 and
+    /// later) to wait until one can be used.
+    pub indexes: Vec<IndexKind>,
+
+    /// Env vars set for the `bitcoind` process e.g., `LD_PRELOAD` or sanitizer options, on top of
+    /// the env of this process.
+    pub envs: Vec<(String, String)>,
+
+    /// Working directory of the `bitcoind` process, the working directory of this process if
+    /// `None`.
+    pub cwd: Option<PathBuf>,
+
+    /// Permissions granted to peers connecting from the loopback addresses, using `-whitelist`.
+    ///
+    /// All the peers launched by this crate connect from a loopback address. Supported since Core
+    /// v0.19.
+    pub whitelist: Vec<Permission>,
+
+    /// Permissions granted to peers connecting to the p2p port, using `-whitebind` instead of
+    /// `-bind`.
+    ///
+    /// Only used if [`Conf::p2p`] opens a p2p port. Supported since Core v0.19.
+    pub whitebind: Vec<Permission>,
+
+    /// A user allowed to call the RPC interface with a password, alongside the cookie file.
+    ///
+    /// Use [`RpcAuth::generate`] to get the credentials and the password, `-rpcuser` and
+    /// `-rpcpassword` are rejected.
+    pub rpc_auth: Option<RpcAuth>,
+
+    /// Where the node writes the RPC cookie file, passed with `-rpccookiefile`.
+    ///
+    /// As in Core, a relative path is relative to the network directory of the data directory,
+    /// `<datadir>/<network>/.cookie` if `None`. [`ConnectParams::cookie_file`] holds the resolved
+    /// path.
+    pub cookie_file: Option<PathBuf>,
+
+    /// The challenge of a private signet, passed with `-signetchallenge`.
+    ///
+    /// Only used if `args` contains `-signet`, see [`Conf::custom_signet`]. Supported since Core
+    /// v0.21.
+    pub signet_challenge: Option<ScriptBuf>,
+
+    /// Parse the log the node prints to stdout into [`LogEvent`]s instead of discarding it.
+    ///
+    /// See [`BitcoinD::take_log_events`] to receive the events. The lines are still printed if
+    /// [`Conf::view_stdout`] is set. Enable debug categories with `args` e.g., `-debug=mempool`.
+    pub log_events: bool,
+
+    /// A command to run `bitcoind` under e.g., `vec!["valgrind", "--tool=massif"]`, the path to
+    /// the executable and the args are appended to it.
+    ///
+    /// The node is found by its pid file so that signals reach it even if the wrapper runs it as
+    /// a child (e.g., `perf record`). Nodes with a wrapper shut down gracefully by default, for the
+    /// wrapper to write its output. Wrappers slow the node down, raise the timeouts as needed.
+    pub wrapper: Option<Vec<String>>,
+
+    /// Make no network connections at all, for nodes on non-regtest networks that must not reach
+    /// real peers.
+    ///
+    /// Disables connecting to peers, DNS and fixed seeds, and port mapping (NAT-PMP and UPnP).
+    /// Requires [`Conf::p2p`] to be [`P2P::No`], which disables listening. See [`Conf::isolated`].
+    pub offline: bool,
+
+    /// Descriptors imported into [`Conf::wallet`] once it is created or loaded.
+    ///
+    /// Uses `importdescriptors` from Core v23, where wallets are descriptor wallets by default, and
+    /// `importmulti` before. Not supported by Core v0.17, requires a wallet. A descriptor wallet
+    /// with private keys only accepts descriptors with private keys.
+    pub import_descriptors: Vec<DescriptorImport>,
+
+    /// Encrypt [`Conf::wallet`] with this passphrase once it is created or loaded.
+    ///
+    /// The wallet is locked once encrypted, see [`BitcoinD::unlock_wallet`]. Descriptors in
+    /// [`Conf::import_descriptors`] are imported before the wallet is encrypted.
+    pub wallet_passphrase: Option<String>,
+
+    /// Make the addresses of [`Conf::wallet`] reproducible across runs by deriving them from this
+    /// seed.
+    ///
+    /// Imports BIP44, BIP49, BIP84 and BIP86 descriptors of the BIP32 master key of the seed as
+    /// the active descriptors of the wallet, for receiving and change. Requires a descriptor
+    /// wallet, Core v23 or later.
+    pub deterministic_seed: Option<[u8; 32]>,
+
+    /// SOCKS5 proxy used to connect to onion peers, instead of [`Conf::proxy`] (`-onion`).
+    pub onion_proxy: Option<SocketAddr>,
+
+    /// Address the p2p port for connections from the onion service listens on, in addition to the
+    /// port opened by [`Conf::p2p`] (`-bind=<addr>=onion`).
+    ///
+    /// Tor forwards the connections to the onion service published with [`Conf::tor_control`] to
+    /// this address. Requires [`Conf::p2p`] to listen. Supported since Core v0.21.
+    pub onion_bind: Option<SocketAddr>,
+
+    /// Accept connections from I2P peers when [`Conf::i2p_sam`] is set (`-i2pacceptincoming`).
+    ///
+    /// See [`Conf::i2p_only`] to only connect via I2P and [`BitcoinD::i2p_address`] to get the
+    /// address of the node.
+    pub i2p_accept_incoming: bool,
 }
 
 impl Default for Conf<'_> {
@@ -272,6 +658,42 @@ impl Default for Conf<'_> {
             attempts: 5,
             enable_zmq: false,
             wallet: Some("default".to_string()),
+            startup_timeout: Duration::from_secs(5),
+            rpc_ready_timeout: Duration::from_secs(5),
+            poll_interval: Duration::from_millis(200),
+            reindex_timeout: Duration::from_secs(120),
+            shutdown: None,
+            leave_running_on_drop: false,
+            mocktime: None,
+            prune: None,
+            rpc_bind: LOCAL_IP.into(),
+            p2p_bind: LOCAL_IP.into(),
+            proxy: None,
+            onlynet: vec![],
+            tor_control: None,
+            tor_password: None,
+            i2p_sam: None,
+            cjdns_reachable: false,
+            external_signer: None,
+            block_notify: false,
+            wallet_notify: false,
+            indexes: vec![],
+            envs: vec![],
+            cwd: None,
+            whitelist: vec![],
+            whitebind: vec![],
+            rpc_auth: None,
+            cookie_file: None,
+            signet_challenge: None,
+            log_events: false,
+            wrapper: None,
+            offline: false,
+            import_descriptors: vec![],
+            wallet_passphrase: None,
+            deterministic_seed: None,
+            onion_proxy: None,
+            onion_bind: None,
+            i2p_accept_incoming: true,
         }
     }
 }
@@ -302,36 +724,108 @@ impl BitcoinD {
     ///
     /// If the node fails to start after the specified number of attempts.
     pub fn with_conf<S: AsRef<OsStr>>(exe: S, conf: &Conf) -> anyhow::Result<BitcoinD> {
-        for attempt in 0..conf.attempts {
-            let work_dir = Self::init_work_dir(conf)?;
-            let cookie_file = work_dir.path().join(conf.network).join(".cookie");
+        Self::launch(exe, conf, Self::init_work_dir)
+    }
 
-            let rpc_port = get_available_port()?;
-            let rpc_socket = SocketAddrV4::new(LOCAL_IP, rpc_port);
+    /// Launches the node like [`BitcoinD::with_conf`], in the data directory returned by
+    /// `init_work_dir` for each attempt.
+    pub(crate) fn launch<S, F>(exe: S, conf: &Conf, init_work_dir: F) -> anyhow::Result<BitcoinD>
+    where
+        S: AsRef<OsStr>,
+        F: Fn(&Conf) -> anyhow::Result<DataDir>,
+    {
+        // Held across the attempts, a failed attempt kills its process before the next one.
+        let permit = limit::acquire()?;
+        // The args are checked against the version of `exe`, not the one of the version feature.
+        let version = exe_version::exe_version(exe.as_ref());
+        let mut last_error = None;
+        for attempt in 0..conf.attempts {
+            let work_dir = init_work_dir(conf)?;
+            let net_dir = work_dir.path().join(conf.network);
+            let (cookie_args, cookie_file) =
+                rpcauth::cookie_file_args(conf.cookie_file.as_deref(), &net_dir);
+            let pid_file = wrapper::pid_file(conf.wrapper.as_deref(), &net_dir);
+
+            // Dropped when this attempt ends, by then the node has bound the ports or failed.
+            let mut ports = Reservation::new();
+            let rpc_port = ports.port(conf.rpc_bind)?;
+            let rpc_socket = SocketAddr::new(connect_ip(conf.rpc_bind), rpc_port);
             let rpc_url = format!("http://{}", rpc_socket);
-
-            let (p2p_args, p2p_socket) = Self::p2p_args(&conf.p2p)?;
+            let rpc_bind_args = Self::rpc_bind_args(conf.rpc_bind, rpc_port);
+            let rpc_auth_args = rpcauth::rpc_auth_args(conf.rpc_auth.as_ref());
+
+            let (p2p_args, p2p_socket) =
+                Self::p2p_args(&conf.p2p, conf.p2p_bind, &conf.whitebind, &mut ports)?;
+            let whitelist_args = permissions::whitelist_args(conf);
+            let tor_args = tor::tor_args(conf);
+            let net_args = net::net_args(conf);
+            let offline_args = offline::offline_args(conf, version.as_deref())?;
+            let signet_args = signet::signet_args(conf.signet_challenge.as_ref());
+            let signer_args = signer::signer_args(conf.external_signer.as_deref());
+            let notifier = Notifier::start(conf, &work_dir.path())?;
+            let notify_args = notifier.as_ref().map(|n| n.args(conf)).unwrap_or_default();
             let (zmq_args, zmq_pub_raw_tx_socket, zmq_pub_raw_block_socket) =
-                Self::zmq_args(conf.enable_zmq)?;
+                Self::zmq_args(conf.enable_zmq, &mut ports)?;
 
-            let stdout = if conf.view_stdout { Stdio::inherit() } else { Stdio::null() };
+            let stdout = log_events::stdout(conf.log_events, conf.view_stdout);
+            let log_events = LogEvents::new(conf.log_events);
 
             let datadir_arg = format!("-datadir={}", work_dir.path().display());
             let rpc_arg = format!("-rpcport={}", rpc_port);
             let default_args = [&datadir_arg, &rpc_arg];
-            let conf_args = validate_args(conf.args.clone())?;
-
-            let mut process = Command::new(exe.as_ref())
+            let conf_args = validate_args_for(conf.args.clone(), version.as_deref())?;
+            let prune_args = prune::prune_args(conf.prune, &conf_args)?;
+            let index_args = index::index_args(&conf.indexes, conf.prune.is_some());
+            let mocktime_args: Vec<String> =
+                conf.mocktime.iter().map(|timestamp| format!("-mocktime={}", timestamp)).collect();
+
+            let mut command = wrapper::command(exe.as_ref(), conf.wrapper.as_deref());
+            command
                 .args(default_args)
+                .args(&rpc_bind_args)
+                .args(&rpc_auth_args)
+                .args(&cookie_args)
                 .args(&p2p_args)
+                .args(&whitelist_args)
+                .args(&tor_args)
+                .args(&net_args)
+                .args(&offline_args)
+                .args(&signet_args)
+                .args(&signer_args)
+                .args(&notify_args)
                 .args(&conf_args)
+                .args(&mocktime_args)
+                .args(&prune_args)
+                .args(&index_args)
                 .args(&zmq_args)
+                .envs(conf.envs.iter().map(|(key, value)| (key, value)))
                 .stdout(stdout)
+                .stderr(Stdio::piped());
+            if let Some(ref cwd) = conf.cwd {
+                command.current_dir(cwd);
+            }
+            let mut process = command
                 .spawn()
                 .with_context(|| format!("Error while executing {:?}", exe.as_ref()))?;
+            let reaper = Reaper::spawn(process.id());
+            // Always drain stderr, echo it if the user asked to see the node output.
+            let mut stderr = OutputCapture::spawn(
+                process.stderr.take().expect("stderr is piped"),
+                CAPTURE_LINES,
+                conf.view_stdout,
+            );
+            if let Some(ref log_events) = log_events {
+                log_events
+                    .forward(process.stdout.take().expect("stdout is piped"), conf.view_stdout);
+            }
             match process.try_wait() {
-                Ok(Some(_)) | Err(_) => {
-                    // Process has exited or an error occurred, kill and retry
+                Ok(Some(status)) => {
+                    // Process has exited, record why and retry.
+                    last_error = Some(Error::EarlyExit(status, stderr.finish()));
+                    continue;
+                }
+                Err(_) => {
+                    // An error occurred, kill and retry
                     let _ = process.kill();
                     continue;
                 }
@@ -340,12 +834,18 @@ impl BitcoinD {
                 }
             }
 
-            if Self::wait_for_cookie_file(cookie_file.as_path(), Duration::from_secs(5)).is_err() {
+            if Self::wait_for_cookie_file(&cookie_file, conf.startup_timeout, conf.poll_interval)
+                .is_err()
+            {
                 // If the cookie file is not accessible a new work_dir is needed and therefore a new
                 // process. Kill the process and retry.
-                let _ = process.kill();
+                last_error =
+                    Some(Self::kill_after_failure(&mut process, pid_file.as_deref(), &mut stderr));
                 continue;
             }
+            let pid = wrapper::node_pid(&process, pid_file.as_deref());
+            // The watchdog would otherwise kill a wrapper running the node as a child.
+            let reaper = if pid == process.id() { reaper } else { Reaper::spawn(pid) };
             let auth = Auth::CookieFile(cookie_file.clone());
 
             let client_base = Self::create_client_base(&rpc_url, &auth)?;
@@ -359,23 +859,65 @@ impl BitcoinD {
                             } else {
                                 // If the wallet cannot be created or loaded, there might be an issue
                                 // with the work_dir or process. Kill the process and retry.
-                                let _ = process.kill();
+                                last_error = Some(Self::kill_after_failure(
+                                    &mut process,
+                                    pid_file.as_deref(),
+                                    &mut stderr,
+                                ));
                                 continue;
                             },
                     },
                 None => client_base,
             };
-            if Self::wait_for_client(&client, Duration::from_secs(5)).is_err() {
+            if Self::wait_for_client(&client, conf.rpc_ready_timeout, conf.poll_interval).is_err() {
                 // If the client times out there might be an issue with the work_dir or process. Kill
                 // the process and retry.
-                let _ = process.kill();
+                last_error =
+                    Some(Self::kill_after_failure(&mut process, pid_file.as_deref(), &mut stderr));
                 continue;
             }
+            // A misconfiguration, launching the node again would not help.
+            if let Err(e) =
+                zmq_check::check_endpoints(&client, zmq_pub_raw_tx_socket, zmq_pub_raw_block_socket)
+            {
+                Self::kill_after_failure(&mut process, pid_file.as_deref(), &mut stderr);
+                return Err(e.into());
+            }
+            if let Err(e) = import::import_descriptors(&client, conf) {
+                Self::kill_after_failure(&mut process, pid_file.as_deref(), &mut stderr);
+                return Err(e);
+            }
+            if let Err(e) = encryption::encrypt_wallet(&client, conf) {
+                Self::kill_after_failure(&mut process, pid_file.as_deref(), &mut stderr);
+                return Err(e);
+            }
+            let version = match core_version::core_version(&client) {
+                Ok(version) => version,
+                Err(e) => {
+                    Self::kill_after_failure(&mut process, pid_file.as_deref(), &mut stderr);
+                    return Err(e);
+                }
+            };
 
             return Ok(BitcoinD {
                 process,
+                pid,
+                pid_file,
                 client,
+                debug_log: work_dir.path().join(conf.network).join("debug.log"),
                 work_dir,
+                args: conf_args.iter().map(|arg| arg.to_string()).collect(),
+                shutdown_mode: conf.shutdown,
+                leave_running: conf.leave_running_on_drop
+                    || env::var_os(LEAVE_RUNNING_ENV_VAR).is_some(),
+                mocktime: AtomicU64::new(conf.mocktime.unwrap_or(0)),
+                miner: Mutex::new(None),
+                launch: Launch::new(&command, exe.as_ref(), conf),
+                version,
+                notifier,
+                log_events,
+                reaper,
+                _permit: permit,
                 params: ConnectParams {
                     cookie_file,
                     rpc_socket,
@@ -385,7 +927,36 @@ impl BitcoinD {
                 },
             });
         }
-        Err(anyhow::anyhow!("Failed to start the node after {} attempts", conf.attempts))
+        let msg = format!("Failed to start the node after {} attempts", conf.attempts);
+        match last_error {
+            Some(e) => Err(anyhow::Error::from(e).context(msg)),
+            None => Err(anyhow::anyhow!(msg)),
+        }
+    }
+
+    /// Kills a process that failed to become ready, and the node it runs if it is a wrapper with a
+    /// `pid_file`.
+    ///
+    /// Returns an [`Error::EarlyExit`] with the captured stderr if the process had already exited
+    /// on its own, otherwise an [`Error::NoBitcoindInstance`] containing the captured stderr.
+    fn kill_after_failure(
+        process: &mut Child,
+        pid_file: Option<&Path>,
+        stderr: &mut OutputCapture,
+    ) -> Error {
+        let pid = wrapper::node_pid(process, pid_file);
+        if pid != process.id() {
+            shutdown::kill(pid);
+        }
+        if let Ok(Some(status)) = process.try_wait() {
+            return Error::EarlyExit(status, stderr.finish());
+        }
+        let _ = process.kill();
+        let _ = process.wait();
+        Error::NoBitcoindInstance(format!(
+            "node did not become ready, stderr:\n{}",
+            stderr.finish()
+        ))
     }
 
     /// Initialize the work directory based on the provided configuration in [`Conf`].
@@ -395,7 +966,7 @@ impl BitcoinD {
     /// * `conf` - Contains the paths for temporary (`tmpdir`) and static (`staticdir`)
     ///   directories. If neither is specified, a temporary directory will be created in the
     ///   system's default temporary directory.
-    fn init_work_dir(conf: &Conf) -> anyhow::Result<DataDir> {
+    pub(crate) fn init_work_dir(conf: &Conf) -> anyhow::Result<DataDir> {
         let tmpdir =
             conf.tmpdir.clone().or_else(|| env::var("TEMPDIR_ROOT").map(PathBuf::from).ok());
         let work_dir = match (&tmpdir, &conf.staticdir) {
@@ -410,21 +981,41 @@ impl BitcoinD {
         Ok(work_dir)
     }
 
+    /// Returns the args to listen for RPC connections on `bind`.
+    ///
+    /// No args are needed for `127.0.0.1` because Core listens on localhost by default.
+    fn rpc_bind_args(bind: IpAddr, port: u16) -> Vec<String> {
+        if bind == IpAddr::V4(LOCAL_IP) {
+            return vec![];
+        }
+        let allow = match bind {
+            IpAddr::V4(ip) if ip.is_unspecified() => "0.0.0.0/0".to_string(),
+            IpAddr::V6(ip) if ip.is_unspecified() => "::/0".to_string(),
+            ip => ip.to_string(),
+        };
+        vec![format!("-rpcbind={}", SocketAddr::new(bind, port)), format!("-rpcallowip={}", allow)]
+    }
+
     /// Returns the p2p args and the p2p socket address if any.
-    fn p2p_args(p2p: &P2P) -> anyhow::Result<(Vec<String>, Option<SocketAddrV4>)> {
+    fn p2p_args(
+        p2p: &P2P,
+        bind: IpAddr,
+        whitebind: &[Permission],
+        ports: &mut Reservation,
+    ) -> anyhow::Result<(Vec<String>, Option<SocketAddr>)> {
         match p2p {
             P2P::No => Ok((vec!["-listen=0".to_string()], None)),
             P2P::Yes => {
-                let p2p_port = get_available_port()?;
-                let p2p_socket = SocketAddrV4::new(LOCAL_IP, p2p_port);
-                let bind_arg = format!("-bind={}", p2p_socket);
+                let p2p_port = ports.port(bind)?;
+                let p2p_socket = SocketAddr::new(connect_ip(bind), p2p_port);
+                let bind_arg = permissions::bind_arg(whitebind, SocketAddr::new(bind, p2p_port));
                 let args = vec![bind_arg];
                 Ok((args, Some(p2p_socket)))
             }
             P2P::Connect(other_node_url, listen) => {
-                let p2p_port = get_available_port()?;
-                let p2p_socket = SocketAddrV4::new(LOCAL_IP, p2p_port);
-                let bind_arg = format!("-bind={}", p2p_socket);
+                let p2p_port = ports.port(bind)?;
+                let p2p_socket = SocketAddr::new(connect_ip(bind), p2p_port);
+                let bind_arg = permissions::bind_arg(whitebind, SocketAddr::new(bind, p2p_port));
                 let connect = format!("-connect={}", other_node_url);
                 let mut args = vec![bind_arg, connect];
                 if *listen {
@@ -443,12 +1034,13 @@ impl BitcoinD {
     ///     - `zmq_pub_raw_block_socket`: for raw block publishing.
     fn zmq_args(
         enable_zmq: bool,
-    ) -> anyhow::Result<(Vec<String>, Option<SocketAddrV4>, Option<SocketAddrV4>)> {
+        ports: &mut Reservation,
+    ) -> anyhow::Result<(Vec<String>, Option<SocketAddr>, Option<SocketAddr>)> {
         if enable_zmq {
-            let zmq_pub_raw_tx_port = get_available_port()?;
-            let zmq_pub_raw_tx_socket = SocketAddrV4::new(LOCAL_IP, zmq_pub_raw_tx_port);
-            let zmq_pub_raw_block_port = get_available_port()?;
-            let zmq_pub_raw_block_socket = SocketAddrV4::new(LOCAL_IP, zmq_pub_raw_block_port);
+            let zmq_pub_raw_tx_port = ports.port(LOCAL_IP.into())?;
+            let zmq_pub_raw_tx_socket = SocketAddr::new(LOCAL_IP.into(), zmq_pub_raw_tx_port);
+            let zmq_pub_raw_block_port = ports.port(LOCAL_IP.into())?;
+            let zmq_pub_raw_block_socket = SocketAddr::new(LOCAL_IP.into(), zmq_pub_raw_block_port);
             let zmqpubrawblock_arg =
                 format!("-zmqpubrawblock=tcp://0.0.0.0:{}", zmq_pub_raw_block_port);
             let zmqpubrawtx_arg = format!("-zmqpubrawtx=tcp://0.0.0.0:{}", zmq_pub_raw_tx_port);
@@ -463,26 +1055,34 @@ impl BitcoinD {
     }
 
     /// Returns `Ok` once the cookie file is accessible, or an error if it times out.
-    fn wait_for_cookie_file(cookie_file: &Path, timeout: Duration) -> anyhow::Result<()> {
+    fn wait_for_cookie_file(
+        cookie_file: &Path,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> anyhow::Result<()> {
         let start = std::time::Instant::now();
         while start.elapsed() < timeout {
             if cookie_file.exists() {
                 return Ok(());
             }
-            thread::sleep(Duration::from_millis(200));
+            thread::sleep(poll_interval);
         }
         Err(anyhow::anyhow!("timeout waiting for cookie file: {}", cookie_file.display()))
     }
 
     /// Returns `Ok` once the client can successfully call, or an error if it times out.
-    fn wait_for_client(client: &Client, timeout: Duration) -> anyhow::Result<()> {
+    fn wait_for_client(
+        client: &Client,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> anyhow::Result<()> {
         let start = std::time::Instant::now();
         while start.elapsed() < timeout {
             // Test calling GetBlockchainInfo. Use serde value to be resilient to upstream changes.
             if client.call::<serde_json::Value>("getblockchaininfo", &[]).is_ok() {
                 return Ok(());
             }
-            thread::sleep(Duration::from_millis(200));
+            thread::sleep(poll_interval);
         }
         Err(anyhow::anyhow!("timeout waiting for client to be ready"))
     }
@@ -544,9 +1144,14 @@ impl BitcoinD {
     }
 
     /// Stop the node, waiting correct process termination.
-    pub fn stop(&mut self) -> anyhow::Result<ExitStatus> {
+    ///
+    /// Returns the exit status and what the node logged about the shutdown, use
+    /// [`StopReport::is_clean`] to assert that it shut down cleanly.
+    pub fn stop(&mut self) -> anyhow::Result<StopReport> {
+        let _ = self.stop_miner();
         self.client.stop()?;
-        Ok(self.process.wait()?)
+        let status = self.process.wait()?;
+        Ok(StopReport::new(status, &self.debug_log))
     }
 
     /// Create a new wallet in the running node, and return an RPC client connected to the just
@@ -573,23 +1178,40 @@ impl BitcoinD {
 
 impl Drop for BitcoinD {
     fn drop(&mut self) {
-        // Frist attempt graceful shutdown for persistent directories,
-        // always fallback to force kill and wait for process to be reaped.
-        if let DataDir::Persistent(_) = self.work_dir {
-            let _ = self.stop();
+        if self.leave_running {
+            self.leave_running();
+            return;
         }
-        let _ = self.process.kill();
-        let _ = self.process.wait();
+        // Unless configured otherwise attempt graceful shutdown for persistent directories, either
+        // way the process is reaped before returning.
+        let mode = match (self.shutdown_mode, &self.work_dir) {
+            (Some(mode), _) => mode,
+            (None, _) if self.pid_file.is_some() => ShutdownMode::default(),
+            (None, DataDir::Persistent(_)) => ShutdownMode::default(),
+            (None, DataDir::Temporary(_)) => ShutdownMode::Kill,
+        };
+        let _ = self.shutdown(mode);
     }
 }
 
 /// Returns a non-used local port if available.
 ///
-/// Note there is a race condition during the time the method check availability and the caller.
+/// The port is not returned again for a minute, by this function or to nodes launched in this
+/// process, nor in other processes if they set the same `BITCOIND_PORT_LOCK_DIR` env var. Use the
+/// port before then, nothing stops programs not using this crate from binding it.
 pub fn get_available_port() -> anyhow::Result<u16> {
-    // using 0 as port let the system assign a port available
-    let t = TcpListener::bind(("127.0.0.1", 0))?; // 0 means the OS choose a free port
-    Ok(t.local_addr().map(|s| s.port())?)
+    Ok(ports::reserve_until_timeout(LOCAL_IP.into())?)
+}
+
+/// Returns the address to connect to a socket listening on `bind`.
+///
+/// A socket bound to the unspecified address is reached on the loopback address.
+fn connect_ip(bind: IpAddr) -> IpAddr {
+    match bind {
+        IpAddr::V4(ip) if ip.is_unspecified() => Ipv4Addr::LOCALHOST.into(),
+        IpAddr::V6(ip) if ip.is_unspecified() => Ipv6Addr::LOCALHOST.into(),
+        ip => ip,
+    }
 }
 
 impl From<std::io::Error> for Error {
@@ -607,12 +1229,24 @@ pub fn downloaded_exe_path() -> anyhow::Result<String> { Err(Error::NoFeature.in
 /// Provide the bitcoind executable path if a version feature has been specified.
 #[cfg(feature = "download")]
 pub fn downloaded_exe_path() -> anyhow::Result<String> {
+    if cfg!(target_os = "windows") {
+        downloaded_bin_path("bitcoind.exe")
+    } else {
+        downloaded_bin_path("bitcoind")
+    }
+}
+
+/// Provide the path of the executable `file_name` from the downloaded release.
+#[cfg(feature = "download")]
+fn downloaded_bin_path(file_name: &str) -> anyhow::Result<String> {
     if std::env::var_os("BITCOIND_SKIP_DOWNLOAD").is_some() {
         return Err(Error::SkipDownload.into());
     }
 
     let mut path: PathBuf = if let Ok(dir) = std::env::var("BITCOIND_DOWNLOAD_DIR") {
         dir.into()
+    } else if let Ok(dir) = std::env::var("BITCOIND_DOWNLOAD_CACHE") {
+        dir.into()
     } else {
         let mut p: PathBuf = env!("OUT_DIR").into();
         p.push("bitcoin");
@@ -620,12 +1254,7 @@ pub fn downloaded_exe_path() -> anyhow::Result<String> {
     };
     path.push(format!("bitcoin-{}", VERSION));
     path.push("bin");
-
-    if cfg!(target_os = "windows") {
-        path.push("bitcoind.exe");
-    } else {
-        path.push("bitcoind");
-    }
+    path.push(file_name);
 
     let path = format!("{}", path.display());
     Ok(path)
@@ -650,18 +1279,53 @@ pub fn exe_path() -> anyhow::Result<String> {
 }
 
 /// Validate the specified arg if there is any unavailable or deprecated one.
+///
+/// Options that were added after, or removed before, the version of Core selected by the version
+/// feature are rejected with [`Error::UnsupportedArg`].
 pub fn validate_args(args: Vec<&str>) -> anyhow::Result<Vec<&str>> {
+    validate_args_for(args, Some(VERSION))
+}
+
+/// Like [`validate_args`], checking the options against Core `version` e.g., `28.2.0`.
+///
+/// The options are not checked if `version` is `None`, the version of the `bitcoind` launched is
+/// not known.
+fn validate_args_for<'a>(
+    args: Vec<&'a str>,
+    version: Option<&str>,
+) -> anyhow::Result<Vec<&'a str>> {
     args.iter().try_for_each(|arg| {
         // other kind of invalid arguments can be added into the list if needed
         if INVALID_ARGS.iter().any(|x| arg.starts_with(x)) {
             return Err(Error::RpcUserAndPasswordUsed);
         }
-        Ok(())
+        version.map_or(Ok(()), |version| options::check(arg, version))
     })?;
 
     Ok(args)
 }
 
+/// Returns the value of the last `-name=value` arg in `args`, if any.
+fn launch_arg<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    let prefix = format!("-{}=", name);
+    args.iter().rev().find_map(|arg| arg.strip_prefix(&prefix))
+}
+
+/// Returns the value of the last boolean arg `-name`, `-name=<0|1>` or `-noname` in `args`, if any.
+fn launch_flag(args: &[String], name: &str) -> Option<bool> {
+    let set = format!("-{}", name);
+    let negated = format!("-no{}", name);
+    args.iter().rev().find_map(|arg| {
+        if *arg == set {
+            Some(true)
+        } else if *arg == negated {
+            Some(false)
+        } else {
+            arg.strip_prefix(&set).and_then(|rest| rest.strip_prefix('=')).map(|v| v != "0")
+        }
+    })
+}
+
 #[cfg(test)]
 mod test {
     use std::net::SocketAddrV4;
@@ -679,6 +1343,33 @@ mod test {
         assert_eq!(format!("127.0.0.1:{}", port), format!("{}", socket));
     }
 
+    #[test]
+    fn test_launch_args() {
+        let args: Vec<String> =
+            ["-regtest", "-datacarrier=0", "-datacarriersize=42", "-nopermitbaremultisig"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect();
+        assert_eq!(launch_arg(&args, "datacarriersize"), Some("42"));
+        assert_eq!(launch_arg(&args, "dustrelayfee"), None);
+        assert_eq!(launch_flag(&args, "regtest"), Some(true));
+        assert_eq!(launch_flag(&args, "datacarrier"), Some(false));
+        assert_eq!(launch_flag(&args, "permitbaremultisig"), Some(false));
+        assert_eq!(launch_flag(&args, "mempoolfullrbf"), None);
+    }
+
+    #[test]
+    fn test_policy() {
+        let exe = init();
+        let mut conf = Conf::default();
+        conf.args.push("-datacarriersize=42");
+        let node = BitcoinD::with_conf(exe, &conf).unwrap();
+        let policy = node.policy().unwrap();
+        assert_eq!(policy.data_carrier_size, 42);
+        assert!(policy.data_carrier);
+        assert_eq!(policy.max_standard_tx_weight.to_wu(), 400_000);
+    }
+
     #[test]
     fn test_node_get_blockchain_info() {
         let exe = init();
@@ -700,6 +1391,22 @@ mod test {
         assert_eq!(1, info.blocks);
     }
 
+    #[test]
+    fn test_restart_with_reindex() {
+        let exe = init();
+        let mut node = BitcoinD::new(exe).unwrap();
+        let address = node.client.new_address().unwrap();
+        node.client.generate_to_address(101, &address).unwrap();
+        let best_block = node.client.get_best_block_hash().unwrap();
+        let balance = node.client.get_balance().unwrap();
+
+        for full in [false, true] {
+            node.restart_with_reindex(full).unwrap();
+            assert_eq!(node.client.get_best_block_hash().unwrap(), best_block);
+            assert_eq!(node.client.get_balance().unwrap(), balance);
+        }
+    }
+
     #[test]
     #[cfg(feature = "0_21_2")]
     fn test_getindexinfo() {
@@ -707,14 +1414,27 @@ mod test {
         let mut conf = Conf::default();
         conf.args.push("-txindex");
         let node = BitcoinD::with_conf(&exe, &conf).unwrap();
-        assert!(
-            node.client.server_version().unwrap() >= 210_000,
-            "getindexinfo requires bitcoin >0.21"
-        );
+        assert!(node.at_least(21), "getindexinfo requires bitcoin >0.21");
         let info: std::collections::HashMap<String, serde_json::Value> =
             node.client.call("getindexinfo", &[]).unwrap();
         assert!(info.contains_key("txindex"));
-        assert!(node.client.server_version().unwrap() >= 210_000);
+    }
+
+    #[test]
+    #[cfg(feature = "22_1")]
+    fn test_wait_for_index() {
+        let exe = init();
+        let conf = Conf {
+            indexes: vec![IndexKind::Tx, IndexKind::BlockFilter, IndexKind::CoinStats],
+            ..Default::default()
+        };
+        let node = BitcoinD::with_conf(&exe, &conf).unwrap();
+        let address = node.client.new_address().unwrap();
+        node.client.generate_to_address(101, &address).unwrap();
+
+        for index in conf.indexes {
+            node.wait_for_index(index, Duration::from_secs(30)).unwrap();
+        }
     }
 
     #[test]
@@ -732,6 +1452,84 @@ mod test {
         assert_eq!(peers_connected(&other_node.client), 1);
     }
 
+    #[test]
+    fn test_ipv6() {
+        let exe = init();
+
+        let localhost = IpAddr::V6(Ipv6Addr::LOCALHOST);
+        let conf = Conf::<'_> {
+            p2p: P2P::Yes,
+            rpc_bind: localhost,
+            p2p_bind: localhost,
+            ..Default::default()
+        };
+        let node = BitcoinD::with_conf(&exe, &conf).unwrap();
+        assert!(node.rpc_url().starts_with("http://[::1]:"));
+        assert_eq!(node.params.p2p_socket.unwrap().ip(), localhost);
+
+        let other_conf = Conf::<'_> { p2p: node.p2p_connect(false).unwrap(), ..Default::default() };
+        let other_node = BitcoinD::with_conf(&exe, &other_conf).unwrap();
+        assert_eq!(peers_connected(&node.client), 1);
+        assert_eq!(peers_connected(&other_node.client), 1);
+    }
+
+    #[test]
+    fn test_proxy_onlynet() {
+        let exe = init();
+
+        // Nothing listens on the proxy, the node only uses it for outbound connections.
+        let proxy = SocketAddr::new(LOCAL_IP.into(), get_available_port().unwrap());
+        let conf = Conf { proxy: Some(proxy), onlynet: vec![OnlyNet::Ipv4], ..Default::default() };
+        let node = BitcoinD::with_conf(&exe, &conf).unwrap();
+
+        let info = node.client.call::<serde_json::Value>("getnetworkinfo", &[]).unwrap();
+        let networks = info["networks"].as_array().unwrap();
+        let ipv4 = networks.iter().find(|n| n["name"] == "ipv4").unwrap();
+        assert_eq!(ipv4["proxy"], proxy.to_string());
+        assert_eq!(ipv4["limited"], false);
+        let ipv6 = networks.iter().find(|n| n["name"] == "ipv6").unwrap();
+        assert_eq!(ipv6["limited"], true);
+        assert_eq!(node.onion_address().unwrap(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "23_2")]
+    fn test_i2p_cjdns_reachability() {
+        use std::net::TcpListener;
+
+        let exe = init();
+
+        // A mock SAM proxy, it accepts connections but never answers.
+        let sam = TcpListener::bind((LOCAL_IP, 0)).unwrap();
+        let conf = Conf {
+            i2p_sam: Some(sam.local_addr().unwrap()),
+            cjdns_reachable: true,
+            onlynet: vec![OnlyNet::I2p, OnlyNet::Cjdns],
+            ..Default::default()
+        };
+        let node = BitcoinD::with_conf(&exe, &conf).unwrap();
+
+        let networks = node.reachability().unwrap();
+        assert!(networks[&OnlyNet::I2p].reachable);
+        assert!(networks[&OnlyNet::Cjdns].reachable);
+        assert!(networks[&OnlyNet::Ipv4].limited);
+        assert!(!networks[&OnlyNet::Ipv4].reachable);
+    }
+
+    #[test]
+    fn test_rpc_bind_args() {
+        assert!(BitcoinD::rpc_bind_args(LOCAL_IP.into(), 18443).is_empty());
+        assert_eq!(
+            BitcoinD::rpc_bind_args(Ipv6Addr::LOCALHOST.into(), 18443),
+            vec!["-rpcbind=[::1]:18443", "-rpcallowip=::1"]
+        );
+        assert_eq!(
+            BitcoinD::rpc_bind_args(Ipv4Addr::UNSPECIFIED.into(), 18443),
+            vec!["-rpcbind=0.0.0.0:18443", "-rpcallowip=0.0.0.0/0"]
+        );
+        assert_eq!(connect_ip(Ipv6Addr::UNSPECIFIED.into()), IpAddr::V6(Ipv6Addr::LOCALHOST));
+    }
+
     #[cfg(not(target_os = "windows"))] // TODO: investigate why it doesn't work in windows
     #[test]
     fn test_data_persistence() {
@@ -792,6 +1590,116 @@ mod test {
         assert_eq!(node3_peers, 1, "listen false but more than 1 peer");
     }
 
+    #[test]
+    fn test_connect_disconnect() {
+        let exe = init();
+        let conf = Conf::<'_> { p2p: P2P::Yes, ..Default::default() };
+        let node1 = BitcoinD::with_conf(&exe, &conf).unwrap();
+        let node2 = BitcoinD::new(&exe).unwrap();
+
+        node2.connect_to(&node1).unwrap();
+        assert_eq!(peers_connected(&node2.client), 1);
+
+        node2.disconnect_from(&node1).unwrap();
+        assert_eq!(peers_connected(&node2.client), 0);
+
+        let err = node1.connect_to(&node2).unwrap_err();
+        assert!(matches!(err.downcast_ref::<Error>(), Some(Error::NoP2p)));
+    }
+
+    #[test]
+    fn test_spawn_peer() {
+        let exe = init();
+        let conf = Conf::<'_> { p2p: P2P::Yes, ..Default::default() };
+        let node = BitcoinD::with_conf(&exe, &conf).unwrap();
+        let tip = *node.mine_blocks(10).unwrap().last().unwrap();
+
+        let peer = node.spawn_peer(&Conf::default()).unwrap();
+        assert_eq!(peer.client.get_block_count().unwrap().0, 10);
+        let best: String = peer.client.call("getbestblockhash", &[]).unwrap();
+        assert_eq!(best, tip.to_string());
+        assert_eq!(peers_connected(&peer.client), 1);
+
+        // The new node does not listen, it cannot have peers spawned.
+        let err = peer.spawn_peer(&Conf::default()).unwrap_err();
+        assert!(matches!(err.downcast_ref::<Error>(), Some(Error::NoP2p)));
+    }
+
+    #[test]
+    fn test_connect_raw_peers() {
+        let exe = init();
+        let conf = Conf::<'_> { p2p: P2P::Yes, ..Default::default() };
+        let node = BitcoinD::with_conf(&exe, &conf).unwrap();
+
+        let peers = node.connect_raw_peers(3).unwrap();
+        assert_eq!(peers_connected(&node.client), 3);
+        assert!(peers.iter().all(|peer| peer.is_connected()));
+    }
+
+    #[test]
+    fn test_p2p_probe() {
+        use corepc_client::bitcoin::constants::genesis_block;
+        use corepc_client::bitcoin::hashes::Hash as _;
+        use corepc_client::bitcoin::{BlockHash, Network};
+        use p2p_probe::Announcement;
+
+        let exe = init();
+        let conf = Conf::<'_> { p2p: P2P::Yes, ..Default::default() };
+        let node = BitcoinD::with_conf(&exe, &conf).unwrap();
+        let mut probe = node.p2p_probe().unwrap();
+        probe.ping(Duration::from_secs(10)).unwrap();
+
+        let address = node.client.new_address().unwrap();
+        let hashes = node.client.generate_to_address(2, &address).unwrap().0;
+        let tip: BlockHash = hashes.last().unwrap().parse().unwrap();
+        assert!(probe
+            .wait_for_announcement(Announcement::Block(tip), Duration::from_secs(10))
+            .unwrap());
+
+        let genesis = genesis_block(Network::Regtest).block_hash();
+        let headers = probe
+            .get_headers(vec![genesis], BlockHash::all_zeros(), Duration::from_secs(10))
+            .unwrap();
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers[1].block_hash(), tip);
+    }
+
+    #[cfg(feature = "0_19_1")]
+    #[test]
+    fn test_assert_relayed() {
+        use std::str::FromStr;
+
+        use corepc_client::bitcoin::hashes::Hash as _;
+        use corepc_client::bitcoin::{Amount, BlockHash, Txid};
+
+        let exe = init();
+        // No announcement delay for whitelisted peers.
+        let conf =
+            Conf::<'_> { p2p: P2P::Yes, whitelist: vec![Permission::NoBan], ..Default::default() };
+        let node = BitcoinD::with_conf(&exe, &conf).unwrap();
+        let mut probe = node.p2p_probe().unwrap();
+        probe.ping(Duration::from_secs(10)).unwrap();
+
+        let address = node.client.new_address().unwrap();
+        let blocks = node.client.generate_to_address(101, &address).unwrap();
+        let tip = BlockHash::from_str(blocks.0.last().unwrap()).unwrap();
+        node.assert_block_announced(&mut probe, tip, Duration::from_secs(10)).unwrap();
+
+        let txid = node
+            .client
+            .send_to_address(&address, Amount::from_sat(10_000))
+            .unwrap()
+            .txid()
+            .unwrap();
+        node.assert_tx_relayed(&mut probe, txid, Duration::from_secs(10)).unwrap();
+
+        let unknown = Txid::from_byte_array([1; 32]);
+        let err =
+            node.assert_tx_relayed(&mut probe, unknown, Duration::from_millis(300)).unwrap_err();
+        let timeout = err.downcast_ref::<WaitTimeout>().expect("WaitTimeout");
+        assert_eq!(timeout.last_observed, "in the mempool: false");
+    }
+
     #[cfg(feature = "0_19_1")]
     #[test]
     fn test_multi_wallet() {
@@ -870,42 +1778,787 @@ mod test {
     }
 
     #[test]
-    fn test_node_rpcauth() {
+    fn test_shutdown_graceful() {
         let exe = init();
+        let mut node = BitcoinD::new(exe).unwrap();
+        let mode = ShutdownMode::Graceful {
+            stop_timeout: Duration::from_secs(10),
+            term_timeout: Duration::from_secs(5),
+        };
+        let status = node.shutdown(mode).unwrap();
+        assert!(status.success());
+    }
 
-        let mut conf = Conf::default();
-        // rpcauth generated with [rpcauth.py](https://github.com/bitcoin/bitcoin/blob/master/share/rpcauth/rpcauth.py)
-        // this could be also added to node, example: [RpcAuth](https://github.com/testcontainers/testcontainers-rs/blob/dev/testcontainers/src/images/coblox_bitcoincore.rs#L39-L91)
-        conf.args.push("-rpcauth=bitcoind:cccd5d7fd36e55c1b8576b8077dc1b83$60b5676a09f8518dcb4574838fb86f37700cd690d99bd2fdc2ea2bf2ab80ead6");
+    #[test]
+    fn test_mocktime() {
+        let exe = init();
+        let start = 1_700_000_000;
 
+        let conf = Conf { mocktime: Some(start), ..Default::default() };
         let node = BitcoinD::with_conf(exe, &conf).unwrap();
+        assert_eq!(node.mocktime(), Some(start));
 
-        let auth = Auth::UserPass("bitcoind".to_string(), "bitcoind".to_string());
-        let client = Client::new_with_auth(
-            format!("{}/wallet/default", node.rpc_url().as_str()).as_str(),
-            auth,
-        )
-        .unwrap();
-        let info = client.get_blockchain_info().unwrap();
-        assert_eq!(0, info.blocks);
+        let now = node.advance_time(Duration::from_secs(3600)).unwrap();
+        assert_eq!(now, start + 3600);
+        assert_eq!(node.mocktime(), Some(start + 3600));
 
-        let address = client.new_address().unwrap();
-        let _ = client.generate_to_address(1, &address).unwrap();
-        let info = node.client.get_blockchain_info().unwrap();
-        assert_eq!(1, info.blocks);
+        // Blocks mined under mock time carry the mocked timestamp.
+        let address = node.client.new_address().unwrap();
+        node.client.generate_to_address(1, &address).unwrap();
+        let hash = node.client.best_block_hash().unwrap();
+        let header = node.client.get_block_header_verbose(&hash).unwrap();
+        assert_eq!(header.time as u64, now);
+
+        node.set_mocktime(0).unwrap();
+        assert_eq!(node.mocktime(), None);
     }
 
     #[test]
-    fn test_get_cookie_user_and_pass() {
+    fn test_node_template() {
+        use corepc_client::bitcoin::Amount;
+
         let exe = init();
-        let node = BitcoinD::new(exe).unwrap();
+        let template = NodeTemplate::build(&exe, &Conf::default(), |node| {
+            node.fund_node_wallet()?;
+            Ok(())
+        })
+        .unwrap();
 
-        let user: &str = "bitcoind_user";
-        let password: &str = "bitcoind_password";
+        let first = template.instantiate().unwrap();
+        let second = template.instantiate().unwrap();
+        assert_ne!(first.params.rpc_socket, second.params.rpc_socket);
+        assert_ne!(first.workdir(), second.workdir());
+        for node in [&first, &second] {
+            assert_eq!(node.client.get_block_count().unwrap().0, 101);
+            assert!(node.client.get_balance().unwrap().into_model().unwrap().0 > Amount::ZERO);
+        }
 
-        std::fs::write(&node.params.cookie_file, format!("{}:{}", user, password)).unwrap();
+        // The instances do not share their data directory.
+        first.mine_blocks(1).unwrap();
+        assert_eq!(second.client.get_block_count().unwrap().0, 101);
+    }
 
-        let result_values = node.params.get_cookie_values().unwrap().unwrap();
+    #[test]
+    fn test_generate_blocks_at() {
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+        let address = node.client.new_address().unwrap();
+        let start = 1_700_000_000;
+
+        let times = [start, start + 600, start + 1200];
+        let hashes = node.generate_blocks_at(&times, &address).unwrap();
+        assert_eq!(hashes.len(), 3);
+        for (hash, time) in hashes.iter().zip(times) {
+            let header = node.client.get_block_header_verbose(hash).unwrap();
+            assert_eq!(header.time as u64, time);
+        }
+        assert_eq!(node.mocktime(), Some(start + 1200));
+
+        // Not after the median time past of the chain.
+        assert!(node.generate_blocks_at(&[start], &address).is_err());
+    }
+
+    #[test]
+    fn test_mine_chain_with_schedule() {
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+        let genesis = node.client.best_block_hash().unwrap();
+        let start = node.client.get_block_header_verbose(&genesis).unwrap().time as u64;
+
+        let hashes = node.mine_chain_with_schedule(&[(3, 600), (2, -1)]).unwrap();
+        let times = hashes
+            .iter()
+            .map(|hash| node.client.get_block_header_verbose(hash).unwrap().time as u64)
+            .collect::<Vec<_>>();
+        assert_eq!(times, [600, 1200, 1800, 1799, 1798].map(|step| start + step));
+        assert_eq!(node.mocktime(), Some(start + 1798));
+    }
+
+    #[test]
+    fn test_prune() {
+        let exe = init();
+        let mut conf = Conf { prune: Some(1), ..Default::default() };
+        let node = BitcoinD::with_conf(&exe, &conf).unwrap();
+
+        let info = node.client.get_blockchain_info().unwrap();
+        assert!(info.pruned);
+        assert_eq!(info.prune_height, Some(0));
+
+        conf.args.push("-txindex");
+        let err = BitcoinD::with_conf(&exe, &conf).unwrap_err();
+        assert!(matches!(err.downcast_ref(), Some(Error::PruneConflict(arg)) if arg == "-txindex"));
+    }
+
+    #[test]
+    #[cfg(feature = "23_2")]
+    fn test_prune_to_height() {
+        let exe = init();
+        let mut conf = Conf::default();
+        // Small block files, so that the blocks below the prune height fill whole files.
+        conf.args.push("-fastprune");
+        conf.prune = Some(1);
+        let node = BitcoinD::with_conf(exe, &conf).unwrap();
+
+        // Core keeps the last 288 blocks.
+        let address = node.client.new_address().unwrap();
+        node.client.generate_to_address(1000, &address).unwrap();
+        let pruned = node.prune_to_height(500).unwrap().expect("blocks pruned");
+        assert!(pruned > 0);
+
+        // The height of the first block stored.
+        let prune_height = node.client.get_blockchain_info().unwrap().prune_height.unwrap();
+        assert!(prune_height as u64 >= pruned);
+        let hash = node.client.get_block_hash(1).unwrap().block_hash().unwrap();
+        assert!(node.client.get_block(hash).is_err());
+        let hash = node.client.get_block_hash(prune_height as u64).unwrap().block_hash().unwrap();
+        assert!(node.client.get_block(hash).is_ok());
+    }
+
+    #[test]
+    fn test_resource_usage() {
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+        let before = node.resource_usage().unwrap();
+        assert!(before.datadir_size > 0);
+
+        let address = node.client.new_address().unwrap();
+        node.client.generate_to_address(101, &address).unwrap();
+        let after = node.resource_usage().unwrap();
+        if cfg!(unix) {
+            assert!(after.rss.unwrap() > 0);
+            assert!(after.cpu_time.unwrap() >= before.cpu_time.unwrap());
+        }
+    }
+
+    #[cfg(feature = "26_2")]
+    #[test]
+    fn test_dump_utxo_snapshot() {
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+        let address = node.client.new_address().unwrap();
+        node.client.generate_to_address(101, &address).unwrap();
+
+        let path = node.workdir().join("utxo.dat");
+        let dump = node.dump_utxo_snapshot(&path).unwrap();
+        assert!(path.exists());
+        assert_eq!(dump.base_height, 101);
+    }
+
+    #[cfg(feature = "26_2")]
+    #[test]
+    fn test_load_utxo_snapshot() {
+        // Regtest only loads the snapshot of the assumeutxo entry in the chain params, at height
+        // 110 of the chain mined by Core's `TestChain100Setup`: one block per second from this
+        // time, paying to the compressed public key of private key 1.
+        const MOCKTIME: u64 = 1598887952;
+        const DESCRIPTOR: &str =
+            "pk(0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798)";
+        const HEIGHT: u64 = 110;
+
+        let exe = init();
+        let node = BitcoinD::new(&exe).unwrap();
+        for i in 0..HEIGHT {
+            node.set_mocktime(MOCKTIME + i).unwrap();
+            node.client.generate_to_descriptor(1, DESCRIPTOR).unwrap();
+        }
+        let path = node.workdir().join("utxo.dat");
+        let dump = node.dump_utxo_snapshot(&path).unwrap();
+
+        let loader = BitcoinD::new(&exe).unwrap();
+        for height in 1..=HEIGHT {
+            let hash = node.client.get_block_hash(height).unwrap().block_hash().unwrap();
+            let header = node.client.get_block_header(&hash).unwrap().block_header().unwrap();
+            loader.client.submit_header(&header).unwrap();
+        }
+        let loaded = loader.load_utxo_snapshot(&path, Duration::from_secs(30)).unwrap();
+        assert_eq!(loaded.tip_hash, dump.base_hash);
+        assert_eq!(loaded.base_height, HEIGHT as u32);
+        assert_eq!(loaded.coins_loaded, dump.coins_written);
+    }
+
+    #[cfg(feature = "26_2")]
+    #[test]
+    fn test_read_utxo_snapshot() {
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+        let address = node.client.new_address().unwrap();
+        node.client.generate_to_address(101, &address).unwrap();
+
+        let path = node.workdir().join("utxo.dat");
+        let dump = node.dump_utxo_snapshot(&path).unwrap();
+        let mut snapshot = utxo_snapshot::read(&path).unwrap();
+        assert_eq!(snapshot.metadata().base_block_hash, dump.base_hash);
+        assert_eq!(snapshot.metadata().coins_count, dump.coins_written.to_sat());
+
+        let entries = snapshot.by_ref().collect::<anyhow::Result<Vec<_>>>().unwrap();
+        assert_eq!(entries.len() as u64, dump.coins_written.to_sat());
+        let script_pubkey = address.script_pubkey();
+        assert!(entries.iter().all(|entry| entry.is_coinbase
+            && entry.txout.script_pubkey == script_pubkey
+            && entry.txout.value.to_btc() == 50.0));
+    }
+
+    #[test]
+    fn test_bench() {
+        let exe = init();
+        let mut small_cache = Conf::default();
+        small_cache.args.push("-dbcache=4");
+        let variants = [("default", Conf::default()), ("dbcache=4", small_cache)];
+
+        let reports = bench::run(exe, 20, &variants, Duration::from_secs(60)).unwrap();
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[1].label, "dbcache=4");
+        assert!(reports.iter().all(|report| report.blocks == 20));
+    }
+
+    #[test]
+    fn test_parse_debug_log() {
+        use crate::logparse::Event;
+
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+        let hash = node.mine_blocks(1).unwrap()[0];
+
+        let lines = node.parse_debug_log().unwrap();
+        assert!(lines.iter().any(|line| line.event == Event::UpdateTip { height: 1, hash }));
+    }
+
+    #[test]
+    fn test_bootstrap_fee_estimates() {
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+        let estimate = node.client.call::<serde_json::Value>("estimatesmartfee", &[2.into()]);
+        assert!(estimate.unwrap()["feerate"].is_null());
+
+        let mined = node.bootstrap_fee_estimates(50).unwrap();
+        assert!(mined <= 50);
+        let estimate = node.client.call::<serde_json::Value>("estimatesmartfee", &[6.into()]);
+        assert!(estimate.unwrap()["feerate"].as_f64().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_mempool_persistence() {
+        use corepc_client::bitcoin::Amount;
+
+        let exe = init();
+        let conf = Conf::<'_> { wallet: None, ..Default::default() };
+        let mut node = BitcoinD::with_conf(&exe, &conf).unwrap();
+        // Not the wallet of the node, so it is not loaded again to resubmit its transactions.
+        let auth = Auth::CookieFile(node.params.cookie_file.clone());
+        let wallet =
+            BitcoinD::create_client_wallet(&node.client, &node.rpc_url(), &auth, "other").unwrap();
+        let address = wallet.new_address().unwrap();
+        wallet.generate_to_address(101, &address).unwrap();
+        let txid = wallet.send_to_address(&address, Amount::from_sat(10_000)).unwrap();
+        let txid = txid.txid().unwrap().to_string();
+        let mempool = |node: &BitcoinD| node.client.call::<Vec<String>>("getrawmempool", &[]);
+
+        let path = node.save_mempool().unwrap();
+        assert!(path.ends_with("mempool.dat"));
+        let dir = TempDir::new().unwrap();
+        let saved = dir.path().join("mempool.dat");
+        std::fs::copy(&path, &saved).unwrap();
+
+        node.flush_mempool().unwrap();
+        assert!(mempool(&node).unwrap().is_empty());
+
+        node.import_mempool(&saved).unwrap();
+        assert_eq!(mempool(&node).unwrap(), vec![txid]);
+    }
+
+    #[cfg(feature = "0_21_2")]
+    #[test]
+    fn test_persisted_mempool() {
+        use corepc_client::bitcoin::hashes::Hash as _;
+        use corepc_client::bitcoin::{Amount, Txid};
+
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+        let address = node.client.new_address().unwrap();
+        node.client.generate_to_address(101, &address).unwrap();
+        let txid = node
+            .client
+            .send_to_address(&address, Amount::from_sat(10_000))
+            .unwrap()
+            .txid()
+            .unwrap();
+        let unknown = Txid::from_byte_array([1; 32]);
+        for id in [txid, unknown] {
+            let args = [id.to_string().into(), 0.into(), 1_000.into()];
+            let _ = node.client.call::<bool>("prioritisetransaction", &args).unwrap();
+        }
+        let _ = node.client.call::<serde_json::Value>("savemempool", &[]).unwrap();
+
+        let dat = node.persisted_mempool().unwrap();
+        assert_eq!(dat.txs.len(), 1);
+        assert_eq!(dat.txs[0].tx.compute_txid(), txid);
+        assert_eq!(dat.txs[0].fee_delta.to_sat(), 1_000);
+        assert_eq!(dat.fee_deltas.get(&unknown).map(|delta| delta.to_sat()), Some(1_000));
+        // The node has no peers, the wallet transaction is never relayed.
+        assert!(dat.unbroadcast.contains(&txid));
+    }
+
+    #[cfg(all(unix, feature = "22_1"))]
+    #[test]
+    fn test_external_signer() {
+        let exe = init();
+        let dir = TempDir::new().unwrap();
+        let conf = Conf {
+            wallet: None,
+            external_signer: Some(signer::install_mock(dir.path()).unwrap()),
+            ..Default::default()
+        };
+        let node = BitcoinD::with_conf(exe, &conf).unwrap();
+
+        let signers = node.client.call::<serde_json::Value>("enumeratesigners", &[]).unwrap();
+        assert_eq!(signers["signers"][0]["fingerprint"], signer::MOCK_FINGERPRINT);
+    }
+
+    #[cfg(feature = "22_1")]
+    #[test]
+    fn test_wallet_tool() {
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+        let _ = node.client.unload_wallet("default").unwrap();
+
+        let dump = node.workdir().join("default.dump");
+        let dump_arg = format!("-dumpfile={}", dump.display());
+        node.wallet_tool("dump", &["-wallet=default", &dump_arg]).unwrap();
+        assert!(dump.exists());
+
+        let info = node.wallet_tool("info", &["-wallet=default"]).unwrap();
+        assert!(info.contains("Wallet info"));
+    }
+
+    #[test]
+    fn test_wait_for() {
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+        let address = node.client.new_address().unwrap();
+
+        node.start_miner(Duration::from_millis(100), &address).unwrap();
+        node.wait_for_block_height(5, Duration::from_secs(10)).unwrap();
+        node.stop_miner().unwrap();
+
+        let err = node.wait_for_peers(1, Duration::from_millis(300)).unwrap_err();
+        let timeout = err.downcast_ref::<WaitTimeout>().expect("WaitTimeout");
+        assert_eq!(timeout.last_observed, "0 peers");
+    }
+
+    #[test]
+    fn test_notifications() {
+        use std::str::FromStr;
+
+        use corepc_client::bitcoin::{Amount, BlockHash};
+
+        let exe = init();
+        let conf = Conf::<'_> { block_notify: true, wallet_notify: true, ..Default::default() };
+        let node = BitcoinD::with_conf(exe, &conf).unwrap();
+        let events = node.take_notifications().unwrap();
+        assert!(node.take_notifications().is_none());
+
+        let address = node.client.new_address().unwrap();
+        let blocks = node.client.generate_to_address(101, &address).unwrap();
+        let tip = BlockHash::from_str(blocks.0.last().unwrap()).unwrap();
+        let txid = node
+            .client
+            .send_to_address(&address, Amount::from_sat(10_000))
+            .unwrap()
+            .txid()
+            .unwrap();
+
+        // The node runs the notify commands concurrently, the events may arrive in any order.
+        let mut expected = vec![NotifyEvent::Block(tip), NotifyEvent::Wallet(txid)];
+        while !expected.is_empty() {
+            let event = events.recv_timeout(Duration::from_secs(10)).expect("notification");
+            expected.retain(|e| *e != event);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "26_2")]
+    fn test_log_events() {
+        let exe = init();
+        let mut conf = Conf::<'_> { log_events: true, ..Default::default() };
+        conf.args.push("-debug=validation");
+        let node = BitcoinD::with_conf(exe, &conf).unwrap();
+        let events = node.take_log_events().unwrap();
+        assert!(node.take_log_events().is_none());
+
+        let address = node.client.new_address().unwrap();
+        node.client.generate_to_address(1, &address).unwrap();
+        loop {
+            let event = events.recv_timeout(Duration::from_secs(10)).expect("log event");
+            if event.category.as_deref() == Some("validation") {
+                assert!(event.timestamp.is_some());
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn test_fund_address_unconfirmed() {
+        use corepc_client::bitcoin::Amount;
+
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+        let address = node.client.new_address().unwrap();
+        // The node wallet is funded on demand.
+        let txid = node.fund_address_unconfirmed(&address, Amount::from_btc(1.0).unwrap()).unwrap();
+        let tx = node.client.get_transaction(txid).unwrap().into_model().unwrap();
+        assert_eq!(tx.confirmations, 0);
+
+        let err = node.fund_address_unconfirmed(&address, Amount::from_int_btc(100_000));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn test_chain_helpers() {
+        use corepc_client::bitcoin::Amount;
+
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+        assert!(node.fund_node_wallet().unwrap() > Amount::ZERO);
+
+        let address = node.client.new_address().unwrap();
+        let amount = Amount::from_sat(100_000);
+        let (outpoint, txout) = node.fund_address(&address, amount).unwrap();
+        assert_eq!(txout.value, amount);
+        assert_eq!(txout.script_pubkey, address.script_pubkey());
+
+        let height = node.client.get_block_count().unwrap().0;
+        let tip = node.client.best_block_hash().unwrap();
+        let replaced = node.reorg(2).unwrap();
+        assert_eq!(replaced.len(), 2);
+        assert_eq!(replaced[0], tip);
+        assert_eq!(node.client.get_block_count().unwrap().0, height + 1);
+        assert_ne!(node.client.best_block_hash().unwrap(), tip);
+        // Mined again in the new blocks.
+        let tx = node.client.get_transaction(outpoint.txid).unwrap().into_model().unwrap();
+        assert!(tx.confirmations > 0);
+        assert!(node.reorg(0).is_err());
+    }
+
+    #[test]
+    fn test_miner() {
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+        let address = node.client.new_address().unwrap();
+        let start = node.client.get_block_count().unwrap().0;
+
+        node.start_miner(Duration::from_millis(100), &address).unwrap();
+        thread::sleep(Duration::from_secs(1));
+        let mined = node.stop_miner().unwrap();
+        assert!(mined > 0);
+
+        let end = node.client.get_block_count().unwrap().0;
+        assert_eq!(end - start, mined as u64);
+        // Stopping again is a no-op.
+        assert_eq!(node.stop_miner().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_envs_and_cwd() {
+        let exe = init();
+        let cwd = TempDir::new().unwrap();
+
+        let mut conf = Conf {
+            envs: vec![("LC_ALL".to_string(), "C".to_string())],
+            cwd: Some(cwd.path().to_path_buf()),
+            ..Default::default()
+        };
+        let node = BitcoinD::with_conf(&exe, &conf).unwrap();
+        assert_eq!(node.client.get_blockchain_info().unwrap().blocks, 0);
+
+        conf.cwd = Some(cwd.path().join("missing"));
+        conf.attempts = 1;
+        assert!(BitcoinD::with_conf(&exe, &conf).is_err());
+    }
+
+    #[cfg(feature = "0_18_1")]
+    #[test]
+    fn test_import_descriptors() {
+        use corepc_client::bitcoin::secp256k1::{Secp256k1, SecretKey};
+        use corepc_client::bitcoin::{Address, CompressedPublicKey, Network, PrivateKey};
+
+        let exe = init();
+        let secp = Secp256k1::new();
+        let key = PrivateKey::new(SecretKey::from_slice(&[1; 32]).unwrap(), Network::Regtest);
+        let pubkey = CompressedPublicKey::from_private_key(&secp, &key).unwrap();
+        let address = Address::p2wpkh(&pubkey, Network::Regtest);
+
+        let mut conf = Conf {
+            import_descriptors: vec![DescriptorImport::new(format!("wpkh({})", key.to_wif()))],
+            ..Default::default()
+        };
+        let node = BitcoinD::with_conf(&exe, &conf).unwrap();
+        let info: serde_json::Value =
+            node.client.call("getaddressinfo", &[address.to_string().into()]).unwrap();
+        assert_eq!(info["ismine"], true);
+
+        conf.import_descriptors = vec![DescriptorImport::new("not a descriptor")];
+        conf.attempts = 1;
+        assert!(BitcoinD::with_conf(&exe, &conf).is_err());
+    }
+
+    #[cfg(feature = "23_2")]
+    #[test]
+    fn test_deterministic_seed() {
+        let exe = init();
+        let mut conf = Conf { deterministic_seed: Some([7; 32]), ..Default::default() };
+
+        let addresses = |conf: &Conf| {
+            let node = BitcoinD::with_conf(&exe, conf).unwrap();
+            let change: String = node.client.call("getrawchangeaddress", &[]).unwrap();
+            (node.client.new_address().unwrap(), change)
+        };
+        let first = addresses(&conf);
+        assert_eq!(addresses(&conf), first);
+
+        conf.deterministic_seed = Some([8; 32]);
+        assert_ne!(addresses(&conf), first);
+    }
+
+    #[cfg(feature = "0_21_2")]
+    #[test]
+    fn test_isolated() {
+        let exe = init();
+
+        let mut conf = Conf::isolated();
+        conf.args = vec!["-signet"];
+        conf.network = "signet";
+        conf.wallet = None;
+        let node = BitcoinD::with_conf(&exe, &conf).unwrap();
+        assert_eq!(node.client.get_network_info().unwrap().connections, 0);
+    }
+
+    #[test]
+    fn test_wallet_passphrase() {
+        let exe = init();
+
+        let conf = Conf { wallet_passphrase: Some("passphrase".to_string()), ..Default::default() };
+        let node = BitcoinD::with_conf(&exe, &conf).unwrap();
+        let unlocked_until = || {
+            let info: serde_json::Value = node.client.call("getwalletinfo", &[]).unwrap();
+            info["unlocked_until"].as_u64().expect("encrypted wallet")
+        };
+        assert_eq!(unlocked_until(), 0);
+
+        node.unlock_wallet(Duration::from_secs(60)).unwrap();
+        assert!(unlocked_until() > 0);
+        node.lock_wallet().unwrap();
+        assert_eq!(unlocked_until(), 0);
+
+        let node = BitcoinD::with_conf(&exe, &Conf::default()).unwrap();
+        assert!(node.unlock_wallet(Duration::from_secs(60)).is_err());
+    }
+
+    #[cfg(feature = "22_1")]
+    #[test]
+    fn test_whitelist_and_whitebind() {
+        let exe = init();
+
+        let conf = Conf {
+            p2p: P2P::Yes,
+            whitelist: vec![Permission::Download],
+            whitebind: vec![Permission::NoBan, Permission::Mempool],
+            ..Default::default()
+        };
+        let node = BitcoinD::with_conf(&exe, &conf).unwrap();
+
+        let peer = node.connect_raw_peers(1).unwrap().remove(0);
+        let address = peer.local_addr().unwrap().to_string();
+        let peers = node.client.get_peer_info().unwrap().0;
+        let info = peers.iter().find(|info| info.address == address).unwrap();
+        for permission in ["download", "noban", "mempool"] {
+            assert!(info.permissions.iter().any(|p| p == permission), "{:?}", info.permissions);
+        }
+    }
+
+    #[test]
+    fn test_health() {
+        let exe = init();
+        let mut node = BitcoinD::new(exe).unwrap();
+
+        let health = node.health().unwrap();
+        assert!(health.is_healthy(), "{:?}", health);
+        assert!(health.uptime.is_some());
+        assert_eq!(health.connections, Some(0));
+
+        node.process.kill().unwrap();
+        node.process.wait().unwrap();
+        let health = node.health().unwrap();
+        assert!(!health.alive);
+        assert!(!health.is_healthy());
+    }
+
+    #[cfg(feature = "0_21_2")]
+    #[test]
+    fn test_custom_signet() {
+        use corepc_client::bitcoin::secp256k1::Secp256k1;
+        use corepc_client::bitcoin::{Network, PrivateKey};
+
+        let exe = init();
+        let key = PrivateKey::from_slice(&[1; 32], Network::Signet).unwrap();
+        let challenge = signet_challenge(&key.public_key(&Secp256k1::new()));
+        let node = BitcoinD::with_conf(&exe, &Conf::custom_signet(challenge)).unwrap();
+        assert_eq!(node.client.get_blockchain_info().unwrap().chain, "signet");
+
+        let address = node.client.new_address().unwrap();
+        let hash = node.mine_signet_block(&key, &address).unwrap();
+        assert_eq!(node.client.get_best_block_hash().unwrap().0, hash.to_string());
+        assert_eq!(node.client.get_block_count().unwrap().0, 1);
+    }
+
+    #[test]
+    fn test_leave_running_on_drop() {
+        let exe = init();
+        let conf = Conf::<'_> { leave_running_on_drop: true, ..Default::default() };
+        let node = BitcoinD::with_conf(exe, &conf).unwrap();
+        let workdir = node.workdir();
+        let rpc_url = node.rpc_url();
+        let cookie_file = node.params.cookie_file.clone();
+        drop(node);
+
+        // The temporary directory is kept and the node still answers.
+        assert!(cookie_file.exists());
+        let client = Client::new_with_auth(&rpc_url, Auth::CookieFile(cookie_file)).unwrap();
+        client.get_blockchain_info().unwrap();
+        client.stop().unwrap();
+        thread::sleep(Duration::from_secs(2));
+        let _ = fs::remove_dir_all(workdir);
+    }
+
+    #[test]
+    fn test_stop_report() {
+        let exe = init();
+        let mut node = BitcoinD::new(exe).unwrap();
+        let report = node.stop().unwrap();
+        assert!(report.is_clean(), "{:?}", report);
+    }
+
+    #[test]
+    fn test_early_exit_includes_stderr() {
+        let exe = init();
+
+        let mut conf = Conf::default();
+        conf.args.push("-notanoption");
+        conf.attempts = 1;
+
+        let err = BitcoinD::with_conf(exe, &conf).unwrap_err();
+        let early_exit = err.chain().any(|e| match e.downcast_ref::<Error>() {
+            Some(Error::EarlyExit(_, stderr)) => stderr.contains("notanoption"),
+            _ => false,
+        });
+        assert!(early_exit, "unexpected error: {:?}", err);
+    }
+
+    #[test]
+    fn test_conf_rpc_auth() {
+        let exe = init();
+
+        let (rpc_auth, password) = RpcAuth::generate("alice");
+        let conf = Conf::<'_> { rpc_auth: Some(rpc_auth), ..Default::default() };
+        let node = BitcoinD::with_conf(exe, &conf).unwrap();
+
+        let auth = Auth::UserPass("alice".into(), password.into());
+        let client = Client::new_with_auth(node.rpc_url().as_str(), auth).unwrap();
+        assert_eq!(client.get_blockchain_info().unwrap().blocks, 0);
+
+        let auth = Auth::UserPass("alice".into(), "wrong".into());
+        let client = Client::new_with_auth(node.rpc_url().as_str(), auth).unwrap();
+        assert!(client.get_blockchain_info().is_err());
+    }
+
+    #[test]
+    fn test_custom_cookie_file() {
+        let exe = init();
+
+        let conf =
+            Conf::<'_> { cookie_file: Some(PathBuf::from("rpc.cookie")), ..Default::default() };
+        let mut node = BitcoinD::with_conf(exe, &conf).unwrap();
+        let net_dir = node.workdir().join("regtest");
+        assert_eq!(node.params.cookie_file, net_dir.join("rpc.cookie"));
+        assert!(!net_dir.join(".cookie").exists());
+
+        // A client created before the node restarts reads the new cookie.
+        let auth = Auth::CookieFile(node.params.cookie_file.clone());
+        let client = Client::new_with_auth(node.rpc_url().as_str(), auth).unwrap();
+        let cookie = fs::read_to_string(&node.params.cookie_file).unwrap();
+        node.restart_with_reindex(false).unwrap();
+        assert_ne!(fs::read_to_string(&node.params.cookie_file).unwrap(), cookie);
+        assert_eq!(client.get_blockchain_info().unwrap().blocks, 0);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_wrapper() {
+        let exe = init();
+
+        // Not the last command, so `sh` runs the node as a child like `perf record` does.
+        let wrapper = ["sh", "-c", "\"$0\" \"$@\"; exit $?"];
+        let conf = Conf::<'_> {
+            wrapper: Some(wrapper.iter().map(|arg| arg.to_string()).collect()),
+            ..Default::default()
+        };
+        let mut node = BitcoinD::with_conf(exe, &conf).unwrap();
+        let pid = node.pid();
+        assert_ne!(pid, node.process.id());
+        assert_eq!(node.client.get_blockchain_info().unwrap().blocks, 0);
+
+        node.shutdown(ShutdownMode::Kill).unwrap();
+        let running = || {
+            std::process::Command::new("kill")
+                .args(["-0", &pid.to_string()])
+                .stderr(Stdio::null())
+                .status()
+                .unwrap()
+                .success()
+        };
+        let start = std::time::Instant::now();
+        while running() && start.elapsed() < Duration::from_secs(5) {
+            thread::sleep(Duration::from_millis(100));
+        }
+        assert!(!running());
+    }
+
+    #[test]
+    fn test_node_rpcauth() {
+        let exe = init();
+
+        let mut conf = Conf::default();
+        // rpcauth generated with [rpcauth.py](https://github.com/bitcoin/bitcoin/blob/master/share/rpcauth/rpcauth.py)
+        // `Conf::rpc_auth` generates it instead, see `test_conf_rpc_auth`.
+        conf.args.push("-rpcauth=bitcoind:cccd5d7fd36e55c1b8576b8077dc1b83$60b5676a09f8518dcb4574838fb86f37700cd690d99bd2fdc2ea2bf2ab80ead6");
+
+        let node = BitcoinD::with_conf(exe, &conf).unwrap();
+
+        let auth = Auth::UserPass("bitcoind".into(), "bitcoind".into());
+        let client = Client::new_with_auth(
+            format!("{}/wallet/default", node.rpc_url().as_str()).as_str(),
+            auth,
+        )
+        .unwrap();
+        let info = client.get_blockchain_info().unwrap();
+        assert_eq!(0, info.blocks);
+
+        let address = client.new_address().unwrap();
+        let _ = client.generate_to_address(1, &address).unwrap();
+        let info = node.client.get_blockchain_info().unwrap();
+        assert_eq!(1, info.blocks);
+    }
+
+    #[test]
+    fn test_get_cookie_user_and_pass() {
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+
+        let user: &str = "bitcoind_user";
+        let password: &str = "bitcoind_password";
+
+        std::fs::write(&node.params.cookie_file, format!("{}:{}", user, password)).unwrap();
+
+        let result_values = node.params.get_cookie_values().unwrap().unwrap();
 
         assert_eq!(user, result_values.user);
         assert_eq!(password, result_values.password);
@@ -929,6 +2582,41 @@ mod test {
         assert!(node.params.zmq_pub_raw_block_socket.is_none());
     }
 
+    #[test]
+    fn test_events() {
+        use corepc_client::bitcoin::BlockHash;
+
+        let exe = init();
+        for enable_zmq in [false, true] {
+            let conf = Conf::<'_> { enable_zmq, ..Default::default() };
+            let node = BitcoinD::with_conf(&exe, &conf).unwrap();
+            let events = node.events().unwrap();
+
+            let address = node.client.new_address().unwrap();
+            let hashes = node.client.generate_to_address(1, &address).unwrap().0;
+            let tip: BlockHash = hashes[0].parse().unwrap();
+
+            let mut received = vec![];
+            while received.last() != Some(&NodeEvent::Block(tip)) {
+                received.push(events.recv_timeout(Duration::from_secs(10)).unwrap());
+            }
+            // The coinbase transaction of the block.
+            assert!(received.iter().any(|event| matches!(event, NodeEvent::Tx(_))));
+        }
+    }
+
+    #[test]
+    fn test_version() {
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+        let version = node.version();
+        let server_version = node.client.server_version().unwrap();
+        assert_eq!(version, CoreVersion::from_server_version(server_version));
+        assert!(node.at_least(17));
+        assert!(node.at_least(version.major));
+        assert!(!node.at_least(version.major + 1));
+    }
+
     fn peers_connected(client: &Client) -> usize {
         let json = client.get_peer_info().expect("get_peer_info");
         json.0.len()
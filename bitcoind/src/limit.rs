@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Limiting the number of nodes running at once in this process.
+//!
+//! A test suite run with many test threads may otherwise start as many nodes as there are threads,
+//! exhausting the memory of the host. If `CORPC_NODE_MAX_NODES` is set, launching a node waits
+//! until fewer than that many nodes launched by this process are running. A test running several
+//! nodes at once needs the limit to be at least that many, otherwise it waits forever.
+
+use std::env;
+use std::sync::{Condvar, Mutex};
+
+/// Env var setting the maximum number of nodes running at once, unlimited if not set.
+const MAX_NODES_ENV: &str = "CORPC_NODE_MAX_NODES";
+
+/// The nodes running in this process.
+static NODES: Semaphore = Semaphore::new();
+
+/// Waits until a node can be launched, returns `None` if the number of nodes is not limited.
+///
+/// The returned permit is held by the node for as long as it runs.
+pub(crate) fn acquire() -> anyhow::Result<Option<Permit<'static>>> {
+    let max = match env::var(MAX_NODES_ENV) {
+        Ok(max) => parse_max(&max)?,
+        Err(env::VarError::NotPresent) => return Ok(None),
+        Err(e) => return Err(anyhow::anyhow!("invalid {}: {}", MAX_NODES_ENV, e)),
+    };
+    Ok(Some(NODES.acquire(max)))
+}
+
+/// Parses the value of [`MAX_NODES_ENV`], a positive number.
+fn parse_max(max: &str) -> anyhow::Result<usize> {
+    match max.trim().parse() {
+        Ok(max) if max > 0 => Ok(max),
+        _ => Err(anyhow::anyhow!(
+            "invalid {}: expected a positive number, got {:?}",
+            MAX_NODES_ENV,
+            max
+        )),
+    }
+}
+
+/// Counts the permits held.
+#[derive(Debug)]
+struct Semaphore {
+    held: Mutex<usize>,
+    released: Condvar,
+}
+
+impl Semaphore {
+    const fn new() -> Self { Semaphore { held: Mutex::new(0), released: Condvar::new() } }
+
+    /// Waits until fewer than `max` permits are held, then takes one.
+    fn acquire(&self, max: usize) -> Permit<'_> {
+        let mut held = self.held.lock().expect("poisoned lock");
+        while *held >= max {
+            held = self.released.wait(held).expect("poisoned lock");
+        }
+        *held += 1;
+        Permit { semaphore: self }
+    }
+}
+
+/// Allows a node to run, released when dropped.
+#[derive(Debug)]
+pub(crate) struct Permit<'a> {
+    semaphore: &'a Semaphore,
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        let mut held = self.semaphore.held.lock().expect("poisoned lock");
+        *held -= 1;
+        // Waiters may have been waiting on different limits if the env var changed.
+        self.semaphore.released.notify_all();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn parses_max() {
+        assert_eq!(parse_max("4").unwrap(), 4);
+        assert_eq!(parse_max(" 4\n").unwrap(), 4);
+        assert!(parse_max("0").is_err());
+        assert!(parse_max("-1").is_err());
+        assert!(parse_max("four").is_err());
+    }
+
+    #[test]
+    fn limits_permits_held() {
+        let semaphore = Semaphore::new();
+        let (running, most_running) = (AtomicUsize::new(0), AtomicUsize::new(0));
+        thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    let _permit = semaphore.acquire(3);
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    most_running.fetch_max(now, Ordering::SeqCst);
+                    thread::sleep(Duration::from_millis(20));
+                    running.fetch_sub(1, Ordering::SeqCst);
+                });
+            }
+        });
+        assert!(most_running.load(Ordering::SeqCst) <= 3);
+        assert_eq!(*semaphore.held.lock().unwrap(), 0);
+    }
+}
@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Structured events from the log the node prints to stdout, see [`crate::Conf::log_events`].
+//!
+//! A line looks like `2024-05-01T12:34:56Z [validation] Enqueuing BlockConnected`, the timestamp
+//! is missing with `-nologtimestamps` and the category is only printed for debug messages. Core
+//! appends the level to the category with `-loglevelalways` e.g., `[net:debug]`, the level is
+//! dropped.
+
+use std::io::{BufRead, BufReader, Read};
+use std::process::Stdio;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::BitcoinD;
+
+/// A line of the node log.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LogEvent {
+    /// The timestamp as printed by the node e.g., `2024-05-01T12:34:56Z`, `None` if the node runs
+    /// with `-nologtimestamps`.
+    pub timestamp: Option<String>,
+    /// The category of the message e.g., `validation` or `mempool`, `None` for messages logged
+    /// unconditionally.
+    pub category: Option<String>,
+    /// The rest of the line.
+    pub message: String,
+}
+
+impl LogEvent {
+    /// Parses a line of the node log.
+    ///
+    /// Lines the node did not format, like the output of a crash, end up in the message.
+    pub(crate) fn from_line(line: &str) -> Self {
+        let line = line.trim_end();
+        let (timestamp, rest) = match line.split_once(' ') {
+            Some((first, rest)) if is_timestamp(first) => (Some(first.to_string()), rest),
+            _ if is_timestamp(line) => (Some(line.to_string()), ""),
+            _ => (None, line),
+        };
+        let (category, message) = match rest.strip_prefix('[').and_then(|r| r.split_once("] ")) {
+            Some((category, message)) if is_category(category) => {
+                let category = category.split(':').next().unwrap_or(category);
+                (Some(category.to_string()), message)
+            }
+            _ => (None, rest),
+        };
+        LogEvent { timestamp, category, message: message.to_string() }
+    }
+}
+
+/// Returns `true` if `s` looks like an ISO 8601 timestamp as printed by the node, with or without
+/// `-logtimemicros`.
+fn is_timestamp(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    bytes.len() >= 20
+        && bytes[4] == b'-'
+        && bytes[10] == b'T'
+        && s.ends_with('Z')
+        && bytes[..4].iter().all(u8::is_ascii_digit)
+}
+
+/// Returns `true` if `s` is a category, optionally followed by a level.
+///
+/// Categories are lower case words, this keeps prefixes like `[default wallet]` in the message.
+fn is_category(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b':')
+}
+
+/// The channel the log events of the node are sent on.
+#[derive(Debug)]
+pub(crate) struct LogEvents {
+    /// Cloned for each process, restarting the node keeps the channel.
+    sender: Sender<LogEvent>,
+    /// The receiving end of the channel, until taken by the user.
+    events: Mutex<Option<Receiver<LogEvent>>>,
+}
+
+impl LogEvents {
+    /// Creates the channel if log events are enabled.
+    pub(crate) fn new(enabled: bool) -> Option<Self> {
+        enabled.then(|| {
+            let (sender, events) = mpsc::channel();
+            LogEvents { sender, events: Mutex::new(Some(events)) }
+        })
+    }
+
+    /// Parses the lines read from `stdout` on a background thread, until EOF.
+    ///
+    /// If `echo` is `true` every line is also written to the stdout of the current process.
+    pub(crate) fn forward<R: Read + Send + 'static>(&self, stdout: R, echo: bool) {
+        let sender = self.sender.clone();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let Ok(line) = line else { break };
+                if echo {
+                    println!("{}", line);
+                }
+                // Keep draining once the receiver is dropped so the node does not block.
+                let _ = sender.send(LogEvent::from_line(&line));
+            }
+        });
+    }
+}
+
+/// Returns the stdout of the node, piped if `log_events`, otherwise inherited if `view_stdout`.
+pub(crate) fn stdout(log_events: bool, view_stdout: bool) -> Stdio {
+    if log_events {
+        Stdio::piped()
+    } else if view_stdout {
+        Stdio::inherit()
+    } else {
+        Stdio::null()
+    }
+}
+
+impl BitcoinD {
+    /// Takes the channel receiving the log events enabled with [`crate::Conf::log_events`].
+    ///
+    /// Returns `None` if not enabled or the channel was already taken. Events are sent until the
+    /// node is dropped, restarting the node keeps the channel.
+    pub fn take_log_events(&self) -> Option<Receiver<LogEvent>> {
+        self.log_events.as_ref()?.events.lock().expect("poisoned lock").take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn event(timestamp: Option<&str>, category: Option<&str>, message: &str) -> LogEvent {
+        LogEvent {
+            timestamp: timestamp.map(ToString::to_string),
+            category: category.map(ToString::to_string),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_lines() {
+        assert_eq!(
+            LogEvent::from_line("2024-05-01T12:34:56Z [validation] Enqueuing BlockConnected\n"),
+            event(Some("2024-05-01T12:34:56Z"), Some("validation"), "Enqueuing BlockConnected")
+        );
+        assert_eq!(
+            LogEvent::from_line("2024-05-01T12:34:56.123456Z [net:debug] received: ping"),
+            event(Some("2024-05-01T12:34:56.123456Z"), Some("net"), "received: ping")
+        );
+        assert_eq!(
+            LogEvent::from_line("2024-05-01T12:34:56Z Bitcoin Core version v26.2.0"),
+            event(Some("2024-05-01T12:34:56Z"), None, "Bitcoin Core version v26.2.0")
+        );
+        assert_eq!(
+            LogEvent::from_line("2024-05-01T12:34:56Z [default wallet] Wallet completed loading"),
+            event(Some("2024-05-01T12:34:56Z"), None, "[default wallet] Wallet completed loading")
+        );
+        assert_eq!(
+            LogEvent::from_line("[mempool] AcceptToMemoryPool: accepted"),
+            event(None, Some("mempool"), "AcceptToMemoryPool: accepted")
+        );
+        assert_eq!(LogEvent::from_line(""), event(None, None, ""));
+    }
+
+    #[test]
+    fn forward_sends_events() {
+        let log_events = LogEvents::new(true).unwrap();
+        let events = log_events.events.lock().unwrap().take().unwrap();
+        let stdout = "2024-05-01T12:34:56Z [mempool] first\nsecond\n";
+        log_events.forward(std::io::Cursor::new(stdout), false);
+
+        let timeout = Duration::from_secs(5);
+        assert_eq!(events.recv_timeout(timeout).unwrap().category.as_deref(), Some("mempool"));
+        assert_eq!(events.recv_timeout(timeout).unwrap(), event(None, None, "second"));
+        assert!(LogEvents::new(false).is_none());
+    }
+}
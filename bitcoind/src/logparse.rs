@@ -0,0 +1,204 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Typed events parsed from the lines of `debug.log`.
+//!
+//! Lines are split into timestamp, category and message like [`LogEvent`]s, the messages of
+//! interest are then parsed into an [`Event`]. Most events are only logged in some debug
+//! categories, enable them with `args` e.g., `-debug=net` or `-debug=bench`.
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use std::time::Duration;
+
+use corepc_client::bitcoin::BlockHash;
+
+use crate::{BitcoinD, LogEvent};
+
+/// A parsed line of `debug.log`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    /// The timestamp as printed by the node e.g., `2024-05-01T12:34:56Z`, `None` if the node runs
+    /// with `-nologtimestamps`.
+    pub timestamp: Option<String>,
+    /// The category of the message e.g., `validation` or `net`, `None` for messages logged
+    /// unconditionally.
+    pub category: Option<String>,
+    /// The event the message is about.
+    pub event: Event,
+}
+
+/// An event logged by the node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Event {
+    /// The tip of the active chain changed, logged unconditionally.
+    UpdateTip {
+        /// The height of the new tip.
+        height: u32,
+        /// The hash of the new tip.
+        hash: BlockHash,
+    },
+    /// A connection to a peer was added, logged with `-debug=net` (v22 and later).
+    PeerConnected {
+        /// The id of the peer.
+        peer: u64,
+    },
+    /// A peer is being disconnected, logged with `-debug=net`.
+    PeerDisconnected {
+        /// The id of the peer.
+        peer: u64,
+    },
+    /// A step of flushing the chain state to disk completed, logged with `-debug=bench` or
+    /// `-debug=coindb` (v25 and later).
+    Flush {
+        /// The step e.g., `write coins cache to disk (101 coins, 13.84kB)`.
+        step: String,
+        /// How long the step took.
+        duration: Duration,
+    },
+    /// The RPC server rejected a request e.g., an incorrect password attempt.
+    RpcError {
+        /// The message, without the `ThreadRPCServer` prefix.
+        message: String,
+    },
+    /// Any other message.
+    Other(String),
+}
+
+impl From<LogEvent> for LogLine {
+    fn from(event: LogEvent) -> Self {
+        LogLine {
+            timestamp: event.timestamp,
+            category: event.category,
+            event: Event::from_message(event.message),
+        }
+    }
+}
+
+impl Event {
+    /// Parses the message of a line, after the timestamp and category.
+    fn from_message(message: String) -> Self {
+        let event = if let Some(rest) = message.strip_prefix("UpdateTip: ") {
+            update_tip(rest)
+        } else if message.starts_with("Added connection") {
+            field(&message, "peer").map(|peer| Event::PeerConnected { peer })
+        } else if let Some(peer) = message.strip_prefix("disconnecting peer=") {
+            peer.trim().parse().ok().map(|peer| Event::PeerDisconnected { peer })
+        } else if let Some(rest) = message.strip_prefix("FlushStateToDisk: ") {
+            flush(rest)
+        } else if let Some(rest) = message.strip_prefix("ThreadRPCServer ") {
+            // The other messages with this prefix log the method of each call.
+            (!rest.starts_with("method=")).then(|| Event::RpcError { message: rest.to_string() })
+        } else {
+            None
+        };
+        event.unwrap_or(Event::Other(message))
+    }
+}
+
+/// Parses `new best=<hash> height=<height> ...`.
+fn update_tip(rest: &str) -> Option<Event> {
+    let hash = field(rest, "best")?;
+    let height = field(rest, "height")?;
+    Some(Event::UpdateTip { height, hash })
+}
+
+/// Parses `<step> completed (<duration>)`, `None` for the line logged when a step starts.
+fn flush(rest: &str) -> Option<Event> {
+    let (step, duration) = rest.strip_suffix(')')?.rsplit_once(" completed (")?;
+    let duration = if let Some(ms) = duration.strip_suffix("ms") {
+        Duration::from_micros((ms.parse::<f64>().ok()? * 1_000.0).round() as u64)
+    } else if let Some(us) = duration.strip_suffix("μs") {
+        Duration::from_micros(us.parse().ok()?)
+    } else {
+        Duration::from_secs_f64(duration.strip_suffix('s')?.parse().ok()?)
+    };
+    Some(Event::Flush { step: step.to_string(), duration })
+}
+
+/// Returns the value of the first `<name>=<value>` field in `message`.
+fn field<T: std::str::FromStr>(message: &str, name: &str) -> Option<T> {
+    message
+        .split_whitespace()
+        .find_map(|word| word.strip_prefix(name)?.strip_prefix('='))
+        .and_then(|value| value.trim_end_matches(',').parse().ok())
+}
+
+/// Parses a line of `debug.log`.
+pub fn parse_line(line: &str) -> LogLine { LogEvent::from_line(line).into() }
+
+/// Parses the lines of the log file at `path`.
+pub fn read(path: &Path) -> anyhow::Result<Vec<LogLine>> {
+    let mut lines = Vec::new();
+    for line in BufReader::new(File::open(path)?).lines() {
+        lines.push(parse_line(&line?));
+    }
+    Ok(lines)
+}
+
+impl BitcoinD {
+    /// Parses the `debug.log` of the node, see [`read`].
+    ///
+    /// The file holds the lines of all the runs of the node, including before a restart.
+    pub fn parse_debug_log(&self) -> anyhow::Result<Vec<LogLine>> { read(&self.debug_log) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "0f9188f13cb7b2c71f2a335e3a4fc328bf5beb436012afca590b1a11466e2206";
+
+    #[test]
+    fn parse_events() {
+        let line = parse_line(&format!(
+            "2024-05-01T12:34:56Z UpdateTip: new best={} height=0 version=0x00000001 \
+             log2_work=1.000000 tx=1 date='2011-02-02T23:16:42Z' progress=1.000000",
+            HASH
+        ));
+        assert_eq!(line.timestamp.as_deref(), Some("2024-05-01T12:34:56Z"));
+        assert_eq!(line.category, None);
+        assert_eq!(line.event, Event::UpdateTip { height: 0, hash: HASH.parse().unwrap() });
+
+        let line = parse_line("2024-05-01T12:34:56Z [net] Added connection peer=3");
+        assert_eq!(line.category.as_deref(), Some("net"));
+        assert_eq!(line.event, Event::PeerConnected { peer: 3 });
+        assert_eq!(
+            parse_line("[net] Added connection to 127.0.0.1:18444 peer=4").event,
+            Event::PeerConnected { peer: 4 }
+        );
+        assert_eq!(
+            parse_line("[net] disconnecting peer=3").event,
+            Event::PeerDisconnected { peer: 3 }
+        );
+
+        assert_eq!(
+            parse_line("ThreadRPCServer incorrect password attempt from 127.0.0.1:1").event,
+            Event::RpcError { message: "incorrect password attempt from 127.0.0.1:1".into() }
+        );
+        let call = "[rpc] ThreadRPCServer method=getblockcount user=__cookie__";
+        assert_eq!(
+            parse_line(call).event,
+            Event::Other("ThreadRPCServer method=getblockcount user=__cookie__".into())
+        );
+    }
+
+    #[test]
+    fn parse_flush_timings() {
+        let step = "write coins cache to disk (101 coins, 13.84kB)";
+        assert_eq!(
+            parse_line(&format!("[coindb] FlushStateToDisk: {} completed (1.50ms)", step)).event,
+            Event::Flush { step: step.to_string(), duration: Duration::from_micros(1_500) }
+        );
+        assert_eq!(
+            parse_line("[bench] FlushStateToDisk: write block index to disk completed (2s)").event,
+            Event::Flush {
+                step: "write block index to disk".to_string(),
+                duration: Duration::from_secs(2)
+            }
+        );
+        let started = format!("FlushStateToDisk: {} started", step);
+        assert_eq!(parse_line(&started).event, Event::Other(started));
+    }
+}
@@ -0,0 +1,247 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Reading the `mempool.dat` file Core writes at shutdown and with `savemempool`.
+//!
+//! Use [`read`] to inspect a file from any node, or [`BitcoinD::persisted_mempool`] to check what
+//! a node persisted e.g., before and after a restart. Files of Core v28 and later are obfuscated
+//! with a key stored at their start, older files are read as well.
+//!
+//! [`BitcoinD::save_mempool`], [`BitcoinD::flush_mempool`] and [`BitcoinD::import_mempool`] write,
+//! drop and load the mempool of a node the same way on all versions.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use corepc_client::bitcoin::{SignedAmount, Transaction, Txid, VarInt};
+
+use crate::utxo_snapshot::decode;
+use crate::{BitcoinD, ShutdownMode};
+
+/// The version of files without an obfuscation key, written before Core v28.
+const VERSION_NO_XOR_KEY: u64 = 1;
+
+/// The version of files obfuscated with a key, written by Core v28 and later.
+const VERSION_XOR_KEY: u64 = 2;
+
+/// The content of a `mempool.dat` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MempoolDat {
+    /// The version of the file format.
+    pub version: u64,
+    /// The transactions in the mempool when the file was written.
+    pub txs: Vec<PersistedTx>,
+    /// The fee deltas set with `prioritisetransaction` for transactions not in the mempool.
+    pub fee_deltas: BTreeMap<Txid, SignedAmount>,
+    /// The transactions of the wallets not yet seen relayed by a peer, empty before Core v0.21.
+    pub unbroadcast: BTreeSet<Txid>,
+}
+
+/// A transaction in a `mempool.dat` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PersistedTx {
+    /// The transaction.
+    pub tx: Transaction,
+    /// When the transaction entered the mempool, in seconds since the Unix epoch.
+    pub time: i64,
+    /// The fee delta set with `prioritisetransaction`, zero if none.
+    pub fee_delta: SignedAmount,
+}
+
+/// Reads the `mempool.dat` file at `path`.
+pub fn read(path: &Path) -> anyhow::Result<MempoolDat> { from_bytes(fs::read(path)?) }
+
+/// Parses the content of a `mempool.dat` file.
+pub fn from_bytes(mut bytes: Vec<u8>) -> anyhow::Result<MempoolDat> {
+    let mut reader = &bytes[..];
+    let version: u64 = decode(&mut reader)?;
+    let start = match version {
+        VERSION_NO_XOR_KEY => bytes.len() - reader.len(),
+        VERSION_XOR_KEY => {
+            let key: Vec<u8> = decode(&mut reader)?;
+            let start = bytes.len() - reader.len();
+            // The rest of the file is XORed with the key, indexed by the offset in the file.
+            if !key.is_empty() {
+                for (i, byte) in bytes.iter_mut().enumerate().skip(start) {
+                    *byte ^= key[i % key.len()];
+                }
+            }
+            start
+        }
+        _ => anyhow::bail!("unsupported mempool.dat version {}", version),
+    };
+    parse(version, &bytes[start..])
+}
+
+/// Parses the transactions and deltas following the version and key.
+fn parse(version: u64, mut reader: &[u8]) -> anyhow::Result<MempoolDat> {
+    let count: u64 = decode(&mut reader)?;
+    let mut txs = Vec::new();
+    for _ in 0..count {
+        let tx = decode(&mut reader)?;
+        let time: i64 = decode(&mut reader)?;
+        let fee_delta: i64 = decode(&mut reader)?;
+        txs.push(PersistedTx { tx, time, fee_delta: SignedAmount::from_sat(fee_delta) });
+    }
+
+    let mut fee_deltas = BTreeMap::new();
+    for _ in 0..decode::<VarInt, _>(&mut reader)?.0 {
+        let txid = decode(&mut reader)?;
+        let fee_delta: i64 = decode(&mut reader)?;
+        fee_deltas.insert(txid, SignedAmount::from_sat(fee_delta));
+    }
+
+    // Not written before Core v0.21.
+    let mut unbroadcast = BTreeSet::new();
+    if !reader.is_empty() {
+        for _ in 0..decode::<VarInt, _>(&mut reader)?.0 {
+            unbroadcast.insert(decode(&mut reader)?);
+        }
+    }
+    Ok(MempoolDat { version, txs, fee_deltas, unbroadcast })
+}
+
+impl BitcoinD {
+    /// Reads the `mempool.dat` file of the node, written at shutdown or by `savemempool`.
+    pub fn persisted_mempool(&self) -> anyhow::Result<MempoolDat> { read(&self.mempool_dat()) }
+
+    /// Writes the mempool of the node to its `mempool.dat` file with `savemempool`, returns the
+    /// path of the file.
+    pub fn save_mempool(&self) -> anyhow::Result<PathBuf> {
+        let res = self.client.call::<serde_json::Value>("savemempool", &[])?;
+        // Core v23 and later return the path, older versions return null.
+        match res.get("filename").and_then(|filename| filename.as_str()) {
+            Some(filename) => Ok(PathBuf::from(filename)),
+            None => Ok(self.mempool_dat()),
+        }
+    }
+
+    /// Empties the mempool of the node by restarting it with `-persistmempool=0`.
+    ///
+    /// The `mempool.dat` file written at shutdown is removed, so later restarts do not load the
+    /// flushed transactions either. Waits until the node is back at its tip, the client is
+    /// replaced as in [`BitcoinD::restart_with_reindex`].
+    pub fn flush_mempool(&mut self) -> anyhow::Result<()> {
+        let tip = self.client.call::<serde_json::Value>("getbestblockhash", &[])?;
+        let _ports = self.hold_ports()?;
+        self.shutdown(ShutdownMode::default())?;
+        remove_if_exists(&self.mempool_dat())?;
+        self.relaunch(&["-persistmempool=0"], &tip)
+    }
+
+    /// Loads the transactions of the `mempool.dat` file at `path` into the mempool of the node.
+    ///
+    /// Uses `importmempool` on Core v26 and later. Older versions restart the node with the file
+    /// as its `mempool.dat`, which replaces the mempool rather than adding to it. Files written by
+    /// Core v28 and later cannot be read by older versions.
+    pub fn import_mempool(&mut self, path: &Path) -> anyhow::Result<()> {
+        let path = path
+            .canonicalize()
+            .with_context(|| format!("mempool file {} not found", path.display()))?;
+        if self.at_least(26) {
+            let path = path.to_str().context("mempool file path is not UTF-8")?;
+            self.client.call::<serde_json::Value>("importmempool", &[path.into()])?;
+            return Ok(());
+        }
+        let tip = self.client.call::<serde_json::Value>("getbestblockhash", &[])?;
+        let _ports = self.hold_ports()?;
+        self.shutdown(ShutdownMode::default())?;
+        fs::copy(&path, self.mempool_dat())
+            .with_context(|| format!("failed to copy mempool file {}", path.display()))?;
+        self.relaunch(&[], &tip)
+    }
+
+    /// Returns the path of the `mempool.dat` file of the node.
+    fn mempool_dat(&self) -> PathBuf { self.debug_log.with_file_name("mempool.dat") }
+}
+
+/// Removes the file at `path`, if any.
+fn remove_if_exists(path: &Path) -> anyhow::Result<()> {
+    match fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use corepc_client::bitcoin::consensus::Encodable;
+    use corepc_client::bitcoin::hashes::Hash;
+    use corepc_client::bitcoin::{absolute, transaction, Amount, ScriptBuf, TxIn, TxOut};
+
+    use super::*;
+
+    fn tx() -> Transaction {
+        Transaction {
+            version: transaction::Version::TWO,
+            lock_time: absolute::LockTime::ZERO,
+            input: vec![TxIn::default()],
+            output: vec![TxOut { value: Amount::from_sat(1_000), script_pubkey: ScriptBuf::new() }],
+        }
+    }
+
+    /// Returns the content of a file with `tx` and a fee delta for `txid`, after the version.
+    fn body(tx: &Transaction, txid: Txid, unbroadcast: bool) -> Vec<u8> {
+        let mut bytes = vec![];
+        1u64.consensus_encode(&mut bytes).unwrap();
+        tx.consensus_encode(&mut bytes).unwrap();
+        1_700_000_000i64.consensus_encode(&mut bytes).unwrap();
+        500i64.consensus_encode(&mut bytes).unwrap();
+        VarInt(1).consensus_encode(&mut bytes).unwrap();
+        txid.consensus_encode(&mut bytes).unwrap();
+        (-200i64).consensus_encode(&mut bytes).unwrap();
+        if unbroadcast {
+            VarInt(1).consensus_encode(&mut bytes).unwrap();
+            tx.compute_txid().consensus_encode(&mut bytes).unwrap();
+        }
+        bytes
+    }
+
+    fn check(dat: &MempoolDat, tx: &Transaction, txid: Txid) {
+        assert_eq!(dat.txs.len(), 1);
+        assert_eq!(dat.txs[0].tx, *tx);
+        assert_eq!(dat.txs[0].time, 1_700_000_000);
+        assert_eq!(dat.txs[0].fee_delta, SignedAmount::from_sat(500));
+        assert_eq!(dat.fee_deltas.get(&txid), Some(&SignedAmount::from_sat(-200)));
+    }
+
+    #[test]
+    fn read_without_key() {
+        let (tx, txid) = (tx(), Txid::from_byte_array([7; 32]));
+        let mut bytes = vec![];
+        VERSION_NO_XOR_KEY.consensus_encode(&mut bytes).unwrap();
+        bytes.extend(body(&tx, txid, false));
+
+        let dat = from_bytes(bytes).unwrap();
+        assert_eq!(dat.version, VERSION_NO_XOR_KEY);
+        check(&dat, &tx, txid);
+        assert!(dat.unbroadcast.is_empty());
+    }
+
+    #[test]
+    fn read_with_key() {
+        let (tx, txid) = (tx(), Txid::from_byte_array([7; 32]));
+        let key = vec![0x5a, 0x01, 0xff, 0x00, 0x33, 0x44, 0x10, 0x80];
+        let mut bytes = vec![];
+        VERSION_XOR_KEY.consensus_encode(&mut bytes).unwrap();
+        key.consensus_encode(&mut bytes).unwrap();
+        let start = bytes.len();
+        bytes.extend(body(&tx, txid, true));
+        for (i, byte) in bytes.iter_mut().enumerate().skip(start) {
+            *byte ^= key[i % key.len()];
+        }
+
+        let dat = from_bytes(bytes).unwrap();
+        assert_eq!(dat.version, VERSION_XOR_KEY);
+        check(&dat, &tx, txid);
+        assert!(dat.unbroadcast.contains(&tx.compute_txid()));
+    }
+
+    #[test]
+    fn unknown_version() {
+        let mut bytes = vec![];
+        3u64.consensus_encode(&mut bytes).unwrap();
+        assert!(from_bytes(bytes).is_err());
+    }
+}
@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Background block production.
+
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use corepc_client::bitcoin::Address;
+use corepc_client::client_sync::Auth;
+
+use crate::{BitcoinD, Client};
+
+/// A thread mining a block every interval.
+#[derive(Debug)]
+pub(crate) struct Miner {
+    /// Dropping or sending on this stops the thread.
+    stop: Sender<()>,
+    /// Returns the number of blocks mined.
+    handle: JoinHandle<anyhow::Result<usize>>,
+}
+
+impl Miner {
+    /// Stops the thread, returns the number of blocks mined or the error that stopped it early.
+    fn stop(self) -> anyhow::Result<usize> {
+        let _ = self.stop.send(());
+        self.handle.join().map_err(|_| anyhow::anyhow!("miner thread panicked"))?
+    }
+}
+
+impl BitcoinD {
+    /// Starts a background thread that mines a block to `address` every `interval`.
+    ///
+    /// Any miner already running on this node is stopped first. The miner uses its own RPC
+    /// connection and runs until [`BitcoinD::stop_miner`] is called, the node is shut down or
+    /// mining fails.
+    pub fn start_miner(&self, interval: Duration, address: &Address) -> anyhow::Result<()> {
+        let _ = self.stop_miner();
+
+        let client = Client::new_with_auth(
+            &self.rpc_url(),
+            Auth::CookieFile(self.params.cookie_file.clone()),
+        )?;
+        let address = address.clone();
+        let (stop, stopped) = mpsc::channel();
+        let handle = thread::spawn(move || -> anyhow::Result<usize> {
+            let mut mined = 0;
+            // Both a stop message and the sender being dropped end the loop.
+            while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(interval) {
+                client.generate_to_address(1, &address)?;
+                mined += 1;
+            }
+            Ok(mined)
+        });
+
+        *self.miner.lock().expect("poisoned lock") = Some(Miner { stop, handle });
+        Ok(())
+    }
+
+    /// Stops the miner started with [`BitcoinD::start_miner`], returns the number of blocks it
+    /// mined.
+    ///
+    /// Returns `Ok(0)` if no miner is running, or the error that made the miner stop early.
+    pub fn stop_miner(&self) -> anyhow::Result<usize> {
+        let miner = self.miner.lock().expect("poisoned lock").take();
+        match miner {
+            Some(miner) => miner.stop(),
+            None => Ok(0),
+        }
+    }
+}
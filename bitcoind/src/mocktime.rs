@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Control of the node's notion of the current time (regtest only).
+
+use std::sync::atomic::Ordering;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use corepc_client::bitcoin::{Address, BlockHash};
+
+use crate::BitcoinD;
+
+impl BitcoinD {
+    /// Sets the node's mock time to `timestamp` (UNIX time in seconds) using `setmocktime`.
+    ///
+    /// Passing `0` goes back to using the system clock.
+    pub fn set_mocktime(&self, timestamp: u64) -> anyhow::Result<()> {
+        self.client.call::<serde_json::Value>("setmocktime", &[timestamp.into()])?;
+        self.mocktime.store(timestamp, Ordering::SeqCst);
+        Ok(())
+    }
+
+    /// Moves the node's mock time forward by `duration` (rounded down to whole seconds).
+    ///
+    /// If mock time is not currently set, time is advanced from the current system time.
+    /// Returns the new mock time.
+    pub fn advance_time(&self, duration: Duration) -> anyhow::Result<u64> {
+        let now = match self.mocktime() {
+            Some(timestamp) => timestamp,
+            None => SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+        };
+        let timestamp = now + duration.as_secs();
+        self.set_mocktime(timestamp)?;
+        Ok(timestamp)
+    }
+
+    /// Mines a block to `address` at each of `times` (UNIX time in seconds), returns their hashes.
+    ///
+    /// The mock time is set before each block, the node is left at the last time. Returns an error
+    /// if a block could not get its time, blocks must be later than the median time of the 11
+    /// blocks before them.
+    pub fn generate_blocks_at(
+        &self,
+        times: &[u64],
+        address: &Address,
+    ) -> anyhow::Result<Vec<BlockHash>> {
+        let mut hashes = Vec::with_capacity(times.len());
+        for &time in times {
+            self.set_mocktime(time)?;
+            let hash = self.client.generate_to_address(1, address)?.into_model()?.0[0];
+            let block_time = self.client.get_block(hash)?.header.time;
+            anyhow::ensure!(
+                u64::from(block_time) == time,
+                "block {} has time {} instead of {}, earlier than the median time past",
+                hash,
+                block_time,
+                time
+            );
+            hashes.push(hash);
+        }
+        Ok(hashes)
+    }
+
+    /// Mines a chain following `schedule`, returns the hashes of the blocks mined.
+    ///
+    /// Each `(blocks, step)` entry mines `blocks` blocks, each `step` seconds after the one before,
+    /// starting from the time of the current tip. Steps can be zero or negative to produce
+    /// timestamps at the median time past e.g., to test difficulty adjustment boundaries or MTP
+    /// edge cases. Blocks are mined to a new address of the node wallet, see
+    /// [`BitcoinD::generate_blocks_at`] for the errors.
+    pub fn mine_chain_with_schedule(
+        &self,
+        schedule: &[(usize, i64)],
+    ) -> anyhow::Result<Vec<BlockHash>> {
+        let tip = self.client.best_block_hash()?;
+        let mut time = i64::from(self.client.get_block(tip)?.header.time);
+        let mut times = Vec::with_capacity(schedule.iter().map(|(blocks, _)| blocks).sum());
+        for &(blocks, step) in schedule {
+            for _ in 0..blocks {
+                time += step;
+                times.push(u64::try_from(time)?);
+            }
+        }
+        let address = self.client.new_address()?;
+        self.generate_blocks_at(&times, &address)
+    }
+
+    /// Returns the current mock time, `None` if the node is using the system clock.
+    ///
+    /// Only tracks mock time set via [`Conf::mocktime`](crate::Conf::mocktime) and the methods on
+    /// this type, calls to `setmocktime` made directly through the client are not seen.
+    pub fn mocktime(&self) -> Option<u64> {
+        match self.mocktime.load(Ordering::SeqCst) {
+            0 => None,
+            timestamp => Some(timestamp),
+        }
+    }
+}
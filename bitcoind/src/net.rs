@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! I2P and CJDNS networks, and the reachability of each network.
+
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+
+use crate::{BitcoinD, Conf, OnlyNet, P2P};
+
+/// Whether a network can be used for outbound connections, from `getnetworkinfo`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reachability {
+    /// The network is not used because of [`Conf::onlynet`].
+    pub limited: bool,
+    /// The node can connect to peers on the network.
+    pub reachable: bool,
+    /// The proxy used for the network, if any.
+    pub proxy: Option<String>,
+}
+
+/// Returns the I2P and CJDNS args for `conf`.
+pub(crate) fn net_args(conf: &Conf) -> Vec<String> {
+    let mut args = vec![];
+    if let Some(sam) = conf.i2p_sam {
+        args.push(format!("-i2psam={}", sam));
+        if !conf.i2p_accept_incoming {
+            args.push("-i2pacceptincoming=0".to_string());
+        }
+    }
+    if conf.cjdns_reachable {
+        args.push("-cjdnsreachable=1".to_string());
+    }
+    args
+}
+
+/// Returns the network named `name` in `getnetworkinfo`, `None` if it is unknown.
+fn network(name: &str) -> Option<OnlyNet> {
+    match name {
+        "ipv4" => Some(OnlyNet::Ipv4),
+        "ipv6" => Some(OnlyNet::Ipv6),
+        "onion" => Some(OnlyNet::Onion),
+        "i2p" => Some(OnlyNet::I2p),
+        "cjdns" => Some(OnlyNet::Cjdns),
+        _ => None,
+    }
+}
+
+impl Conf<'_> {
+    /// Configures the node to only connect to I2P peers through the SAM proxy at `sam`, and to
+    /// accept connections from I2P peers.
+    ///
+    /// The I2P router must already be running. The node listens for p2p connections, which is
+    /// required to accept connections over I2P. Supported since Core v22.
+    pub fn i2p_only(&mut self, sam: SocketAddr) -> &mut Self {
+        self.i2p_sam = Some(sam);
+        self.i2p_accept_incoming = true;
+        self.onlynet = vec![OnlyNet::I2p];
+        if self.p2p == P2P::No {
+            self.p2p = P2P::Yes;
+        }
+        self
+    }
+}
+
+impl BitcoinD {
+    /// Returns the I2P address of the node, `None` if the node has no I2P session yet.
+    ///
+    /// The node must have been started with [`Conf::i2p_sam`] set and
+    /// [`Conf::i2p_accept_incoming`], the session is created shortly after the node starts.
+    pub fn i2p_address(&self) -> anyhow::Result<Option<String>> {
+        let info = self.client.call::<serde_json::Value>("getnetworkinfo", &[])?;
+        let address = info["localaddresses"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|local| local["address"].as_str())
+            .find(|address| address.ends_with(".b32.i2p"));
+        Ok(address.map(ToOwned::to_owned))
+    }
+
+    /// Returns the reachability of each network the node knows about, from `getnetworkinfo`.
+    ///
+    /// Older versions of Core do not list the networks they do not support e.g., there is no I2P
+    /// entry before Core v22.
+    pub fn reachability(&self) -> anyhow::Result<BTreeMap<OnlyNet, Reachability>> {
+        let info = self.client.get_network_info()?;
+        let map = info
+            .networks
+            .into_iter()
+            .filter_map(|net| {
+                let reachability = Reachability {
+                    limited: net.limited,
+                    reachable: net.reachable,
+                    proxy: Some(net.proxy).filter(|proxy| !proxy.is_empty()),
+                };
+                network(&net.name).map(|name| (name, reachability))
+            })
+            .collect();
+        Ok(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn net_args_i2p_cjdns() {
+        assert!(net_args(&Conf::default()).is_empty());
+
+        let conf = Conf {
+            i2p_sam: Some("127.0.0.1:7656".parse().unwrap()),
+            cjdns_reachable: true,
+            ..Default::default()
+        };
+        assert_eq!(net_args(&conf), vec!["-i2psam=127.0.0.1:7656", "-cjdnsreachable=1"]);
+    }
+
+    #[test]
+    fn net_args_i2p_only() {
+        let mut conf = Conf::default();
+        conf.i2p_only("127.0.0.1:7656".parse().unwrap());
+        assert_eq!(conf.p2p, P2P::Yes);
+        assert_eq!(conf.onlynet, vec![OnlyNet::I2p]);
+        assert_eq!(net_args(&conf), vec!["-i2psam=127.0.0.1:7656"]);
+
+        conf.i2p_accept_incoming = false;
+        assert_eq!(net_args(&conf), vec!["-i2psam=127.0.0.1:7656", "-i2pacceptincoming=0"]);
+    }
+
+    #[test]
+    fn network_names() {
+        for net in [OnlyNet::Ipv4, OnlyNet::Ipv6, OnlyNet::Onion, OnlyNet::I2p, OnlyNet::Cjdns] {
+            assert_eq!(network(&net.to_string()), Some(net));
+        }
+        assert_eq!(network("internal"), None);
+    }
+}
@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Block and wallet notifications using `-blocknotify` and `-walletnotify`.
+//!
+//! The node runs a shell command appending each event to a file in the work directory, a thread
+//! reads the new lines and sends them on a channel. Unlike ZMQ this needs no library support in
+//! the node, the commands work with both `sh` and `cmd.exe`.
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use corepc_client::bitcoin::{BlockHash, Txid};
+
+use crate::{BitcoinD, Conf};
+
+/// Name of the file the notify commands append to, in the work directory.
+const NOTIFY_FILE_NAME: &str = "notify.log";
+
+/// How often the file is checked for new events.
+const NOTIFY_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// An event reported by the node, see [`Conf::block_notify`] and [`Conf::wallet_notify`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NotifyEvent {
+    /// The best block changed to this block.
+    Block(BlockHash),
+    /// A wallet transaction was added to the mempool, or confirmed, or changed.
+    Wallet(Txid),
+}
+
+impl NotifyEvent {
+    /// Parses a line written by the notify commands, `None` if it is not an event.
+    fn from_line(line: &str) -> Option<Self> {
+        let (kind, id) = line.trim().split_once(' ')?;
+        match kind {
+            "block" => BlockHash::from_str(id.trim()).ok().map(NotifyEvent::Block),
+            "wallet" => Txid::from_str(id.trim()).ok().map(NotifyEvent::Wallet),
+            _ => None,
+        }
+    }
+}
+
+/// A thread sending the events appended to the notify file on a channel.
+#[derive(Debug)]
+pub(crate) struct Notifier {
+    /// The file the notify commands append to.
+    file: PathBuf,
+    /// Dropping this stops the thread.
+    _stop: Sender<()>,
+    /// The receiving end of the channel, until taken by the user.
+    events: Mutex<Option<Receiver<NotifyEvent>>>,
+}
+
+impl Notifier {
+    /// Starts watching the notify file in `work_dir` if notifications are enabled in `conf`.
+    ///
+    /// The file is truncated so that events from a previous run of a persistent node are not
+    /// sent again, call this before launching the node.
+    pub(crate) fn start(conf: &Conf, work_dir: &Path) -> io::Result<Option<Self>> {
+        if !conf.block_notify && !conf.wallet_notify {
+            return Ok(None);
+        }
+        let file = work_dir.join(NOTIFY_FILE_NAME);
+        File::create(&file)?;
+        let reader = BufReader::new(File::open(&file)?);
+
+        let (stop, stopped) = mpsc::channel();
+        let (sender, events) = mpsc::channel();
+        thread::spawn(move || watch(reader, &sender, &stopped));
+        Ok(Some(Notifier { file, _stop: stop, events: Mutex::new(Some(events)) }))
+    }
+
+    /// Returns the `-blocknotify` and `-walletnotify` args enabled in `conf`.
+    pub(crate) fn args(&self, conf: &Conf) -> Vec<String> {
+        let mut args = vec![];
+        if conf.block_notify {
+            args.push(format!("-blocknotify={}", append_command("block", &self.file)));
+        }
+        if conf.wallet_notify {
+            args.push(format!("-walletnotify={}", append_command("wallet", &self.file)));
+        }
+        args
+    }
+}
+
+/// Returns the shell command appending `kind` and the id the node substitutes for `%s` to `file`.
+fn append_command(kind: &str, file: &Path) -> String {
+    format!("echo {} %s >> \"{}\"", kind, file.display())
+}
+
+/// Sends the events read from `reader` until `stopped` or the receiver is dropped.
+fn watch(mut reader: BufReader<File>, events: &Sender<NotifyEvent>, stopped: &Receiver<()>) {
+    let mut line = String::new();
+    while let Err(RecvTimeoutError::Timeout) = stopped.recv_timeout(NOTIFY_POLL_INTERVAL) {
+        loop {
+            match reader.read_line(&mut line) {
+                Ok(0) | Err(_) => break,
+                // A command is still writing the line, finish it on the next poll.
+                Ok(_) if !line.ends_with('\n') => break,
+                Ok(_) => {
+                    if let Some(event) = NotifyEvent::from_line(&line) {
+                        if events.send(event).is_err() {
+                            return;
+                        }
+                    }
+                    line.clear();
+                }
+            }
+        }
+    }
+}
+
+impl BitcoinD {
+    /// Takes the channel receiving the events enabled with [`Conf::block_notify`] and
+    /// [`Conf::wallet_notify`].
+    ///
+    /// Returns `None` if neither is enabled or the channel was already taken. Events are sent
+    /// until the node is dropped, restarting the node keeps the channel.
+    pub fn take_notifications(&self) -> Option<Receiver<NotifyEvent>> {
+        self.notifier.as_ref()?.events.lock().expect("poisoned lock").take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::OpenOptions;
+    use std::io::Write;
+
+    use super::*;
+
+    const HASH: &str = "0f9188f13cb7b2c71f2a335e3a4fc328bf5beb436012afca590b1a11466e2206";
+
+    #[test]
+    fn parse_lines() {
+        let hash = BlockHash::from_str(HASH).unwrap();
+        let txid = Txid::from_str(HASH).unwrap();
+
+        assert_eq!(
+            NotifyEvent::from_line(&format!("block {}\n", HASH)),
+            Some(NotifyEvent::Block(hash))
+        );
+        // `cmd.exe` keeps the space before the redirection.
+        assert_eq!(
+            NotifyEvent::from_line(&format!("wallet {} \r\n", HASH)),
+            Some(NotifyEvent::Wallet(txid))
+        );
+        assert_eq!(NotifyEvent::from_line("block"), None);
+        assert_eq!(NotifyEvent::from_line("block 00zz"), None);
+        assert_eq!(NotifyEvent::from_line(&format!("mempool {}", HASH)), None);
+    }
+
+    #[test]
+    fn args_enabled_in_conf() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut conf = Conf::default();
+        assert!(Notifier::start(&conf, dir.path()).unwrap().is_none());
+
+        conf.wallet_notify = true;
+        let notifier = Notifier::start(&conf, dir.path()).unwrap().unwrap();
+        let file = dir.path().join(NOTIFY_FILE_NAME);
+        assert_eq!(
+            notifier.args(&conf),
+            vec![format!("-walletnotify=echo wallet %s >> \"{}\"", file.display())]
+        );
+    }
+
+    #[test]
+    fn watch_sends_appended_events() {
+        let dir = tempfile::tempdir().unwrap();
+        let conf = Conf { block_notify: true, ..Default::default() };
+        let notifier = Notifier::start(&conf, dir.path()).unwrap().unwrap();
+        let events = notifier.events.lock().unwrap().take().unwrap();
+
+        let mut file = OpenOptions::new().append(true).open(&notifier.file).unwrap();
+        write!(file, "block {}", HASH).unwrap();
+        file.flush().unwrap();
+        thread::sleep(NOTIFY_POLL_INTERVAL * 3);
+        // Not sent until the line is complete.
+        assert!(events.try_recv().is_err());
+
+        writeln!(file).unwrap();
+        let event = events.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(event, NotifyEvent::Block(BlockHash::from_str(HASH).unwrap()));
+    }
+}
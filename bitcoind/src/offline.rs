@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Running a node that makes no network connections, see [`Conf::offline`].
+
+use crate::{exe_version, Conf, P2P, VERSION};
+
+/// Returns the args disabling all the network connections if [`Conf::offline`] is set.
+///
+/// Not listening is already implied by [`P2P::No`], any other [`Conf::p2p`] is an error. Args are
+/// only passed if Core `version` e.g., `28.2.0` knows them, the version selected by the version
+/// feature is assumed if `version` is `None`. Without any version only the args known by all
+/// versions are passed, `-connect=0` alone already stops the node making connections.
+pub(crate) fn offline_args(conf: &Conf, version: Option<&str>) -> anyhow::Result<Vec<String>> {
+    if !conf.offline {
+        return Ok(vec![]);
+    }
+    anyhow::ensure!(conf.p2p == P2P::No, "an offline node cannot use p2p: {:?}", conf.p2p);
+
+    let mut args = vec!["-connect=0", "-dnsseed=0"];
+    if let Some(major) = exe_version::major(version.unwrap_or(VERSION)) {
+        if major >= 21 {
+            args.push("-fixedseeds=0");
+        }
+        if major >= 22 {
+            args.push("-natpmp=0");
+        }
+        // UPnP support was removed in Core v29.
+        if major < 29 {
+            args.push("-upnp=0");
+        }
+    }
+    Ok(args.into_iter().map(String::from).collect())
+}
+
+impl Conf<'_> {
+    /// Returns the default configuration with [`Conf::offline`] set.
+    pub fn isolated() -> Self { Conf { offline: true, ..Default::default() } }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offline_args_disable_connections() {
+        assert!(offline_args(&Conf::default(), None).unwrap().is_empty());
+
+        let args = offline_args(&Conf::isolated(), None).unwrap();
+        assert!(args.starts_with(&["-connect=0".to_string(), "-dnsseed=0".to_string()]));
+
+        let mut conf = Conf::isolated();
+        conf.p2p = P2P::Yes;
+        assert!(offline_args(&conf, None).is_err());
+    }
+
+    #[test]
+    fn offline_args_by_version() {
+        let args = |version| offline_args(&Conf::isolated(), Some(version)).unwrap();
+        assert_eq!(args("0.17.2"), ["-connect=0", "-dnsseed=0", "-upnp=0"]);
+        assert_eq!(args("0.21.2"), ["-connect=0", "-dnsseed=0", "-fixedseeds=0", "-upnp=0"]);
+        assert_eq!(
+            args("28.2.0"),
+            ["-connect=0", "-dnsseed=0", "-fixedseeds=0", "-natpmp=0", "-upnp=0"]
+        );
+        assert_eq!(args("29.0.0"), ["-connect=0", "-dnsseed=0", "-fixedseeds=0", "-natpmp=0"]);
+        assert_eq!(args("master"), ["-connect=0", "-dnsseed=0"]);
+    }
+}
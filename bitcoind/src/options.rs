@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The options of `bitcoind` that only exist in some versions of Core.
+//!
+//! `bitcoind` exits straight away if passed an option it does not know, with an error only
+//! visible in its stderr. Checking the args against the version of the `bitcoind` launched, from
+//! `bitcoind -version`, reports the mistake before spawning the node.
+
+use crate::exe_version::major;
+use crate::Error;
+
+/// The options added or removed by a version of Core: the option (or option and value), the first
+/// major version having it and the first major version without it.
+const OPTIONS: [(&str, Option<u32>, Option<u32>); 16] = [
+    ("blockfilterindex", Some(19), None),
+    ("blocksxor", Some(28), None),
+    ("cjdnsreachable", Some(23), None),
+    ("coinstatsindex", Some(22), None),
+    ("deprecatedrpc=accounts", None, Some(20)),
+    ("fixedseeds", Some(21), None),
+    ("i2psam", Some(22), None),
+    ("mempoolfullrbf", Some(24), Some(29)),
+    ("natpmp", Some(22), None),
+    ("persistmempoolv1", Some(27), None),
+    ("rpcserialversion", None, Some(28)),
+    ("signet", Some(21), None),
+    ("signetchallenge", Some(21), None),
+    ("upnp", None, Some(29)),
+    ("v2transport", Some(26), None),
+    ("zapwallettxes", None, Some(21)),
+];
+
+/// Returns an error if `arg` is an option Core `version` e.g., `28.2.0` does not have.
+///
+/// Not checked if `version` does not start with a major version.
+pub(crate) fn check(arg: &str, version: &str) -> Result<(), Error> {
+    let Some(major) = major(version) else { return Ok(()) };
+    match unsupported(arg, major) {
+        Some(supported) => Err(Error::UnsupportedArg {
+            arg: arg.to_string(),
+            version: version.to_owned(),
+            supported,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Returns the versions having the option `arg` e.g., `added in v24`, if the major version `major`
+/// of Core does not have it.
+fn unsupported(arg: &str, major: u32) -> Option<String> {
+    let option = arg.strip_prefix('-')?;
+    let matches = |name: &str| {
+        let unnegated = option.strip_prefix("no").unwrap_or(option);
+        [option, unnegated].iter().any(|option| {
+            option.strip_prefix(name).is_some_and(|rest| rest.is_empty() || rest.starts_with('='))
+        })
+    };
+    let (_, since, until) = OPTIONS.into_iter().find(|(name, _, _)| matches(name))?;
+    match (since, until) {
+        (Some(since), _) if major < since => Some(format!("added in v{}", since)),
+        (_, Some(until)) if major >= until => Some(format!("removed in v{}", until)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_checked_against_version() {
+        assert!(unsupported("-mempoolfullrbf=1", 24).is_none());
+        assert!(unsupported("-nomempoolfullrbf", 28).is_none());
+        assert!(unsupported("-mempoolfullrbf", 23).is_some());
+        assert!(unsupported("-mempoolfullrbf=0", 29).is_some());
+        assert!(unsupported("-deprecatedrpc=accounts", 19).is_none());
+        assert!(unsupported("-deprecatedrpc=accounts", 20).is_some());
+        assert!(unsupported("-deprecatedrpc=create_bdb", 20).is_none());
+        assert!(unsupported("-upnp=0", 28).is_none());
+        assert!(unsupported("-upnp", 29).is_some());
+        // Prefixes of a listed option are other options.
+        assert!(unsupported("-signetseednode=127.0.0.1", 20).is_none());
+        assert!(unsupported("-dbcache=300", 17).is_none());
+
+        match check("-v2transport", "25.2.0") {
+            Err(Error::UnsupportedArg { arg, version, supported }) => {
+                assert_eq!(arg, "-v2transport");
+                assert_eq!(version, "25.2.0");
+                assert_eq!(supported, "added in v26");
+            }
+            res => panic!("unexpected: {:?}", res),
+        }
+        assert!(check("-v2transport", "26.2.0").is_ok());
+    }
+}
@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Raw p2p connections to a node, without running another node.
+//!
+//! A [`RawPeer`] does the version handshake and nothing else, it does not answer pings nor relay
+//! anything unless told to. Use it to fill the inbound connection slots of a node e.g., to test
+//! eviction or `-maxconnections`, see [`BitcoinD::connect_raw_peers`].
+
+use std::io::{self, Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use corepc_client::bitcoin::consensus::encode;
+use corepc_client::bitcoin::p2p::message::{NetworkMessage, RawNetworkMessage};
+use corepc_client::bitcoin::p2p::message_network::VersionMessage;
+use corepc_client::bitcoin::p2p::{Address, Magic, ServiceFlags};
+use corepc_client::bitcoin::Network;
+
+use crate::{BitcoinD, Error};
+
+/// Protocol version sent in the version message, the version of Core v0.21 and later.
+const PROTOCOL_VERSION: u32 = 70016;
+
+/// User agent sent in the version message.
+const USER_AGENT: &str = "/corepc-bitcoind:0.1/";
+
+/// How long to wait to connect and for the handshake to complete.
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Size of a message header: magic, command, payload length and checksum.
+const HEADER_LEN: usize = 24;
+
+/// Largest payload accepted, Core does not send messages over 4 MB.
+const MAX_PAYLOAD_LEN: usize = 32 * 1024 * 1024;
+
+/// Makes the nonces sent in version messages unique in this process.
+static NONCE: AtomicU64 = AtomicU64::new(0);
+
+/// A p2p connection to a node that completed the version handshake.
+#[derive(Debug)]
+pub struct RawPeer {
+    stream: TcpStream,
+    magic: Magic,
+}
+
+impl RawPeer {
+    /// Connects to the node listening on `address` and does the version handshake.
+    ///
+    /// Announces no services and asks the node to relay transactions.
+    pub fn connect(address: SocketAddr, network: Network) -> anyhow::Result<Self> {
+        let stream = TcpStream::connect_timeout(&address, HANDSHAKE_TIMEOUT)?;
+        stream.set_read_timeout(Some(HANDSHAKE_TIMEOUT))?;
+        let mut peer = RawPeer { stream, magic: network.magic() };
+
+        peer.send(NetworkMessage::Version(version_message(address, peer.local_addr()?)))?;
+        let (mut version, mut verack) = (false, false);
+        while !(version && verack) {
+            match peer.receive()? {
+                NetworkMessage::Version(_) => {
+                    version = true;
+                    peer.send(NetworkMessage::Verack)?;
+                }
+                NetworkMessage::Verack => verack = true,
+                _ => {}
+            }
+        }
+        peer.stream.set_read_timeout(None)?;
+        Ok(peer)
+    }
+
+    /// Sends `message` to the node.
+    pub fn send(&mut self, message: NetworkMessage) -> io::Result<()> {
+        let raw = RawNetworkMessage::new(self.magic, message);
+        self.stream.write_all(&encode::serialize(&raw))
+    }
+
+    /// Returns the next message from the node, waiting up to the read timeout if one is set.
+    pub fn receive(&mut self) -> io::Result<NetworkMessage> {
+        let mut bytes = vec![0; HEADER_LEN];
+        self.stream.read_exact(&mut bytes)?;
+        let len = u32::from_le_bytes([bytes[16], bytes[17], bytes[18], bytes[19]]) as usize;
+        if len > MAX_PAYLOAD_LEN {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "message payload too large"));
+        }
+        bytes.resize(HEADER_LEN + len, 0);
+        self.stream.read_exact(&mut bytes[HEADER_LEN..])?;
+
+        let raw: RawNetworkMessage = encode::deserialize(&bytes)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e.to_string()))?;
+        if *raw.magic() != self.magic {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "message for another network"));
+        }
+        Ok(raw.into_payload())
+    }
+
+    /// Sets how long [`RawPeer::receive`] waits for a message, `None` to wait forever.
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> io::Result<()> {
+        self.stream.set_read_timeout(timeout)
+    }
+
+    /// Returns `false` if the node closed the connection e.g., because it evicted this peer.
+    pub fn is_connected(&self) -> bool {
+        if self.stream.set_nonblocking(true).is_err() {
+            return false;
+        }
+        let peeked = self.stream.peek(&mut [0; 1]);
+        let _ = self.stream.set_nonblocking(false);
+        match peeked {
+            Ok(0) => false,
+            Ok(_) => true,
+            Err(e) => e.kind() == io::ErrorKind::WouldBlock,
+        }
+    }
+
+    /// Returns the local address of the connection, the address the node sees for this peer.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> { self.stream.local_addr() }
+}
+
+/// Returns the version message sent to the node at `address` from `local`.
+fn version_message(address: SocketAddr, local: SocketAddr) -> VersionMessage {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    // The node disconnects peers sending the nonce of one of its own connections.
+    let nonce = (now.as_nanos() as u64) ^ NONCE.fetch_add(1, Ordering::Relaxed).rotate_left(32);
+    let mut message = VersionMessage::new(
+        ServiceFlags::NONE,
+        now.as_secs() as i64,
+        Address::new(&address, ServiceFlags::NONE),
+        Address::new(&local, ServiceFlags::NONE),
+        nonce,
+        USER_AGENT.to_string(),
+        0,
+    );
+    message.version = PROTOCOL_VERSION;
+    message.relay = true;
+    message
+}
+
+impl BitcoinD {
+    /// Opens `n` raw p2p connections to the node, returns once all completed the handshake.
+    ///
+    /// The node must listen for p2p connections. Each connection takes an inbound slot of the
+    /// node, once they are all taken the node evicts an inbound peer for each new connection,
+    /// see [`RawPeer::is_connected`].
+    pub fn connect_raw_peers(&self, n: usize) -> anyhow::Result<Vec<RawPeer>> {
+        let address = self.params.p2p_socket.ok_or(Error::NoP2p)?;
+        let network = self.network()?;
+        (0..n).map(|_| RawPeer::connect(address, network)).collect()
+    }
+
+    /// Returns the network the node runs on.
+    pub(crate) fn network(&self) -> anyhow::Result<Network> {
+        let chain = self.client.get_blockchain_info()?.chain;
+        Ok(Network::from_core_arg(&chain)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn version_nonces_differ() {
+        let address = "127.0.0.1:18444".parse().unwrap();
+        let local = "127.0.0.1:50000".parse().unwrap();
+        let first = version_message(address, local);
+        let second = version_message(address, local);
+
+        assert_eq!(first.version, PROTOCOL_VERSION);
+        assert!(first.relay);
+        assert_ne!(first.nonce, second.nonce);
+    }
+
+    #[test]
+    fn send_and_receive_frame() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let stream = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (other, _) = listener.accept().unwrap();
+
+        let mut sender = RawPeer { stream, magic: Network::Regtest.magic() };
+        let mut receiver = RawPeer { stream: other, magic: Network::Regtest.magic() };
+        sender.send(NetworkMessage::Ping(42)).unwrap();
+        assert_eq!(receiver.receive().unwrap(), NetworkMessage::Ping(42));
+        assert!(receiver.is_connected());
+
+        drop(sender);
+        assert!(receiver.receive().is_err());
+        assert!(!receiver.is_connected());
+    }
+}
@@ -0,0 +1,274 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Observing the node over the p2p protocol, independent of the RPC view.
+//!
+//! A [`Probe`] is a [`RawPeer`] that answers pings, asks for headers announcements and records the
+//! transactions and blocks the node announces to it.
+
+use std::collections::VecDeque;
+use std::io;
+use std::net::SocketAddr;
+use std::time::{Duration, Instant};
+
+use corepc_client::bitcoin::block::Header;
+use corepc_client::bitcoin::p2p::message::NetworkMessage;
+use corepc_client::bitcoin::p2p::message_blockdata::{GetHeadersMessage, Inventory};
+use corepc_client::bitcoin::{BlockHash, Network, Txid};
+
+use crate::p2p::RawPeer;
+use crate::{BitcoinD, Error, WaitTimeout};
+
+/// An item the node announced to the probe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Announcement {
+    /// A transaction, announced with an `inv`.
+    Tx(Txid),
+    /// A block, announced with an `inv` or a `headers` message.
+    Block(BlockHash),
+}
+
+/// A p2p connection to a node recording what the node announces.
+#[derive(Debug)]
+pub struct Probe {
+    peer: RawPeer,
+    /// Announcements received and not yet taken, oldest first.
+    announcements: VecDeque<Announcement>,
+    /// Nonce of the next ping.
+    nonce: u64,
+}
+
+impl Probe {
+    /// Connects to the node listening on `address`, does the version handshake and asks the node
+    /// to announce blocks with headers.
+    pub fn connect(address: SocketAddr, network: Network) -> anyhow::Result<Self> {
+        let mut peer = RawPeer::connect(address, network)?;
+        peer.send(NetworkMessage::SendHeaders)?;
+        Ok(Probe { peer, announcements: VecDeque::new(), nonce: 0 })
+    }
+
+    /// Sends a ping and waits up to `timeout` for the pong, returns the round trip time.
+    ///
+    /// The node handles messages in order, once it answers it has handled everything sent before.
+    pub fn ping(&mut self, timeout: Duration) -> anyhow::Result<Duration> {
+        self.nonce += 1;
+        let nonce = self.nonce;
+        let start = Instant::now();
+        self.peer.send(NetworkMessage::Ping(nonce))?;
+        self.receive_until(timeout, |message| match message {
+            NetworkMessage::Pong(n) if n == nonce => Ok(()),
+            message => Err(Box::new(message)),
+        })?
+        .ok_or_else(|| anyhow::anyhow!("no pong received in {:?}", timeout))?;
+        Ok(start.elapsed())
+    }
+
+    /// Asks the node for the headers following the first block of `locator` it knows, up to
+    /// `stop` or 2000 headers, `BlockHash::all_zeros()` for no stop.
+    pub fn get_headers(
+        &mut self,
+        locator: Vec<BlockHash>,
+        stop: BlockHash,
+        timeout: Duration,
+    ) -> anyhow::Result<Vec<Header>> {
+        self.peer.send(NetworkMessage::GetHeaders(GetHeadersMessage::new(locator, stop)))?;
+        self.receive_until(timeout, |message| match message {
+            NetworkMessage::Headers(headers) => Ok(headers),
+            message => Err(Box::new(message)),
+        })?
+        .ok_or_else(|| anyhow::anyhow!("no headers received in {:?}", timeout))
+    }
+
+    /// Waits up to `timeout` for the node to announce `item`, returns `false` if it did not.
+    ///
+    /// Earlier announcements are kept, see [`Probe::take_announcements`]. The node delays
+    /// transaction announcements to inbound peers by about five seconds on average.
+    pub fn wait_for_announcement(
+        &mut self,
+        item: Announcement,
+        timeout: Duration,
+    ) -> anyhow::Result<bool> {
+        if self.take_announcement(item) {
+            return Ok(true);
+        }
+        let found = self.receive_until(timeout, |message| {
+            if announcements(&message).contains(&item) {
+                Ok(message)
+            } else {
+                Err(Box::new(message))
+            }
+        })?;
+        match found {
+            Some(message) => {
+                // Record the other items announced with `item`.
+                self.handle(message)?;
+                Ok(self.take_announcement(item))
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Returns the announcements received so far and not yet taken, oldest first.
+    ///
+    /// Only announcements received while waiting on the probe are recorded, call
+    /// [`Probe::ping`] to receive everything the node sent before.
+    pub fn take_announcements(&mut self) -> Vec<Announcement> {
+        self.announcements.drain(..).collect()
+    }
+
+    /// Returns the underlying connection e.g., to send other messages.
+    pub fn peer(&mut self) -> &mut RawPeer { &mut self.peer }
+
+    /// Removes `item` from the recorded announcements, returns `true` if it was there.
+    fn take_announcement(&mut self, item: Announcement) -> bool {
+        let len = self.announcements.len();
+        self.announcements.retain(|announced| *announced != item);
+        self.announcements.len() != len
+    }
+
+    /// Receives messages until `f` returns `Ok` or `timeout` elapses, returns `None` if it timed
+    /// out.
+    ///
+    /// Messages `f` hands back with `Err` are handled as usual: pings are answered and
+    /// announcements recorded. They are boxed, a `NetworkMessage` is large.
+    fn receive_until<T, F>(&mut self, timeout: Duration, mut f: F) -> anyhow::Result<Option<T>>
+    where
+        F: FnMut(NetworkMessage) -> Result<T, Box<NetworkMessage>>,
+    {
+        let start = Instant::now();
+        loop {
+            let remaining = match timeout.checked_sub(start.elapsed()) {
+                Some(remaining) if !remaining.is_zero() => remaining,
+                _ => return Ok(None),
+            };
+            self.peer.set_read_timeout(Some(remaining))?;
+            let message = match self.peer.receive() {
+                Ok(message) => message,
+                Err(e) if is_timeout(&e) => return Ok(None),
+                Err(e) => return Err(e.into()),
+            };
+            match f(message) {
+                Ok(value) => return Ok(Some(value)),
+                Err(message) => self.handle(*message)?,
+            }
+        }
+    }
+
+    /// Answers pings and records announcements in `message`.
+    fn handle(&mut self, message: NetworkMessage) -> io::Result<()> {
+        if let NetworkMessage::Ping(nonce) = message {
+            return self.peer.send(NetworkMessage::Pong(nonce));
+        }
+        self.announcements.extend(announcements(&message));
+        Ok(())
+    }
+}
+
+/// Returns the transactions and blocks announced by `message`.
+fn announcements(message: &NetworkMessage) -> Vec<Announcement> {
+    match message {
+        NetworkMessage::Inv(inventory) => inventory
+            .iter()
+            .filter_map(|inv| match *inv {
+                Inventory::Transaction(txid) | Inventory::WitnessTransaction(txid) =>
+                    Some(Announcement::Tx(txid)),
+                Inventory::Block(hash) | Inventory::WitnessBlock(hash) =>
+                    Some(Announcement::Block(hash)),
+                _ => None,
+            })
+            .collect(),
+        NetworkMessage::Headers(headers) =>
+            headers.iter().map(|header| Announcement::Block(header.block_hash())).collect(),
+        _ => vec![],
+    }
+}
+
+/// Returns `true` if `e` is a read timing out.
+fn is_timeout(e: &io::Error) -> bool {
+    matches!(e.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut)
+}
+
+impl BitcoinD {
+    /// Connects a [`Probe`] to the node, the node must listen for p2p connections.
+    pub fn p2p_probe(&self) -> anyhow::Result<Probe> {
+        let address = self.params.p2p_socket.ok_or(Error::NoP2p)?;
+        Probe::connect(address, self.network()?)
+    }
+
+    /// Checks that the node announced `txid` to `probe` in `timeout`.
+    ///
+    /// The probe must be connected before the node accepts the transaction, the node does not
+    /// announce its mempool to new peers. Unless the probe is whitelisted with
+    /// [`crate::Permission::NoBan`] the node delays announcements by about five seconds on
+    /// average, see [`crate::Conf::whitelist`].
+    ///
+    /// Returns a [`WaitTimeout`] saying whether the transaction is in the mempool if it was not
+    /// announced e.g., it was accepted but is below the relay fee of the node.
+    pub fn assert_tx_relayed(
+        &self,
+        probe: &mut Probe,
+        txid: Txid,
+        timeout: Duration,
+    ) -> anyhow::Result<()> {
+        if probe.wait_for_announcement(Announcement::Tx(txid), timeout)? {
+            return Ok(());
+        }
+        let in_mempool = match self.client.get_raw_mempool() {
+            Ok(mempool) => mempool.0.contains(&txid.to_string()).to_string(),
+            Err(e) => format!("error: {}", e),
+        };
+        Err(WaitTimeout {
+            condition: format!("transaction {} announced", txid),
+            timeout,
+            last_observed: format!("in the mempool: {}", in_mempool),
+        }
+        .into())
+    }
+
+    /// Checks that the node announced the block `hash` to `probe` in `timeout`.
+    ///
+    /// The probe must be connected before the node accepts the block, the node only announces
+    /// its new tips.
+    ///
+    /// Returns a [`WaitTimeout`] including the best block hash of the node if it was not
+    /// announced.
+    pub fn assert_block_announced(
+        &self,
+        probe: &mut Probe,
+        hash: BlockHash,
+        timeout: Duration,
+    ) -> anyhow::Result<()> {
+        if probe.wait_for_announcement(Announcement::Block(hash), timeout)? {
+            return Ok(());
+        }
+        let best = match self.client.get_best_block_hash() {
+            Ok(best) => best.0,
+            Err(e) => format!("error: {}", e),
+        };
+        Err(WaitTimeout {
+            condition: format!("block {} announced", hash),
+            timeout,
+            last_observed: format!("best block {}", best),
+        }
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use corepc_client::bitcoin::hashes::Hash;
+
+    use super::*;
+
+    #[test]
+    fn announcements_from_inv() {
+        let txid = Txid::from_byte_array([1; 32]);
+        let hash = BlockHash::from_byte_array([2; 32]);
+        let inv = NetworkMessage::Inv(vec![
+            Inventory::Transaction(txid),
+            Inventory::Block(hash),
+            Inventory::Error,
+        ]);
+        assert_eq!(announcements(&inv), vec![Announcement::Tx(txid), Announcement::Block(hash)]);
+        assert!(announcements(&NetworkMessage::Verack).is_empty());
+    }
+}
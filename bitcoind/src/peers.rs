@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Connecting and disconnecting running nodes.
+
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use crate::wait::{poll, Poll};
+use crate::{AddNodeCommand, BitcoinD, Conf, Error};
+
+/// How long to wait for a connection to show up in, or go away from, `getpeerinfo`.
+const PEER_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long [`BitcoinD::spawn_peer`] waits for the new node to sync.
+const SYNC_TIMEOUT: Duration = Duration::from_secs(60);
+
+impl BitcoinD {
+    /// Connects this node to `other` using `addnode`, returns once the connection shows up in
+    /// `getpeerinfo`.
+    ///
+    /// `other` must listen for p2p connections, this node need not. Unlike [`P2P::Connect`]
+    /// this works while the nodes are running, the connection is not kept by the node and is not
+    /// retried if it drops.
+    ///
+    /// [`P2P::Connect`]: crate::P2P::Connect
+    pub fn connect_to(&self, other: &BitcoinD) -> anyhow::Result<()> {
+        let address = other.params.p2p_socket.ok_or(Error::NoP2p)?;
+        self.client.add_node(&address.to_string(), AddNodeCommand::OneTry)?;
+        self.wait_for_peer(address, true)
+    }
+
+    /// Disconnects this node from `other` using `disconnectnode`, returns once the connection is
+    /// gone from `getpeerinfo`.
+    ///
+    /// Only disconnects a connection made by this node e.g., with [`BitcoinD::connect_to`] or
+    /// [`P2P::Connect`], call it on `other` to drop a connection made by `other`.
+    ///
+    /// [`P2P::Connect`]: crate::P2P::Connect
+    pub fn disconnect_from(&self, other: &BitcoinD) -> anyhow::Result<()> {
+        let address = other.params.p2p_socket.ok_or(Error::NoP2p)?;
+        self.client.disconnect_node(&address.to_string())?;
+        self.wait_for_peer(address, false)
+    }
+
+    /// Starts a node configured with `conf` running the same `bitcoind` as this node, connects it
+    /// to this node, and returns it once it synced to the tip of this node.
+    ///
+    /// This node must listen for p2p connections. Waits up to 60 seconds for the sync, use
+    /// [`BitcoinD::spawn_peer_with_timeout`] to wait longer e.g., for a long chain.
+    pub fn spawn_peer(&self, conf: &Conf) -> anyhow::Result<BitcoinD> {
+        self.spawn_peer_with_timeout(conf, SYNC_TIMEOUT)
+    }
+
+    /// Like [`BitcoinD::spawn_peer`], waiting up to `timeout` for the new node to sync.
+    ///
+    /// Returns a [`WaitTimeout`] including the height the new node got to if it did not sync in
+    /// `timeout`.
+    ///
+    /// [`WaitTimeout`]: crate::WaitTimeout
+    pub fn spawn_peer_with_timeout(
+        &self,
+        conf: &Conf,
+        timeout: Duration,
+    ) -> anyhow::Result<BitcoinD> {
+        self.params.p2p_socket.ok_or(Error::NoP2p)?;
+        let peer = BitcoinD::with_conf(&self.launch.node_exe, conf)?;
+        peer.connect_to(self)?;
+        peer.wait_for_tip_of(self, timeout)?;
+        Ok(peer)
+    }
+
+    /// Waits until the tip of this node is the tip `other` has when called.
+    fn wait_for_tip_of(&self, other: &BitcoinD, timeout: Duration) -> anyhow::Result<()> {
+        let tip: String = other.client.call("getbestblockhash", &[])?;
+        let height = other.client.get_block_count()?.0;
+        poll(format!("tip {} at height {}", tip, height), timeout, || {
+            let best: String = self.client.call("getbestblockhash", &[])?;
+            Ok(if best == tip {
+                Poll::Ready
+            } else {
+                let count = self.client.get_block_count()?.0;
+                Poll::Pending(format!("height {} of {}", count, height))
+            })
+        })?;
+        Ok(())
+    }
+
+    /// Waits until a peer at `address` is `connected`, or not, according to `getpeerinfo`.
+    fn wait_for_peer(&self, address: SocketAddr, connected: bool) -> anyhow::Result<()> {
+        let address = address.to_string();
+        let state = if connected { "connected" } else { "disconnected" };
+        poll(format!("peer {} {}", address, state), PEER_TIMEOUT, || {
+            let peers = self.client.get_peer_info()?.0;
+            let found = peers.iter().any(|peer| peer.address == address);
+            Ok(if found == connected {
+                Poll::Ready
+            } else {
+                Poll::Pending(format!("{} peers, peer {} not {}", peers.len(), address, state))
+            })
+        })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! P2P permissions granted to peers with `-whitelist` and `-whitebind` (Core v0.19 and later).
+
+use std::fmt;
+use std::net::SocketAddr;
+
+use crate::Conf;
+
+/// A permission granted to whitelisted peers, see [`Conf::whitelist`] and [`Conf::whitebind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Permission {
+    /// Can request filtered blocks and transactions even if `-peerbloomfilters` is off.
+    BloomFilter,
+    /// Not banned nor disconnected for misbehaving.
+    NoBan,
+    /// Transactions relayed even if they are already in the mempool, or fail policy checks.
+    ForceRelay,
+    /// Transactions accepted even if `-blocksonly` is set.
+    Relay,
+    /// Can request the whole mempool with the `mempool` message.
+    Mempool,
+    /// Can download blocks even past `-maxuploadtarget`.
+    Download,
+    /// Gets more and unthrottled `addr` responses (Core v22 and later).
+    Addr,
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Permission::BloomFilter => "bloomfilter",
+            Permission::NoBan => "noban",
+            Permission::ForceRelay => "forcerelay",
+            Permission::Relay => "relay",
+            Permission::Mempool => "mempool",
+            Permission::Download => "download",
+            Permission::Addr => "addr",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Returns `permissions` prefixed to `target` as expected by `-whitelist` and `-whitebind`.
+fn with_permissions(permissions: &[Permission], target: &str) -> String {
+    let permissions: Vec<String> = permissions.iter().map(ToString::to_string).collect();
+    format!("{}@{}", permissions.join(","), target)
+}
+
+/// Returns the `-whitelist` args granting the permissions in `conf` to peers on the loopback
+/// addresses, where the peers launched by this crate connect from.
+pub(crate) fn whitelist_args(conf: &Conf) -> Vec<String> {
+    if conf.whitelist.is_empty() {
+        return vec![];
+    }
+    ["127.0.0.1", "::1"]
+        .iter()
+        .map(|ip| format!("-whitelist={}", with_permissions(&conf.whitelist, ip)))
+        .collect()
+}
+
+/// Returns the arg binding the p2p port to `bind`, granting `permissions` to peers connecting to
+/// it if any.
+pub(crate) fn bind_arg(permissions: &[Permission], bind: SocketAddr) -> String {
+    if permissions.is_empty() {
+        format!("-bind={}", bind)
+    } else {
+        format!("-whitebind={}", with_permissions(permissions, &bind.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn whitelist_args_loopback() {
+        let mut conf = Conf::default();
+        assert!(whitelist_args(&conf).is_empty());
+
+        conf.whitelist = vec![Permission::NoBan, Permission::ForceRelay];
+        assert_eq!(
+            whitelist_args(&conf),
+            vec!["-whitelist=noban,forcerelay@127.0.0.1", "-whitelist=noban,forcerelay@::1"]
+        );
+    }
+
+    #[test]
+    fn bind_arg_permissions() {
+        let bind = "127.0.0.1:18444".parse().unwrap();
+        assert_eq!(bind_arg(&[], bind), "-bind=127.0.0.1:18444");
+        assert_eq!(
+            bind_arg(&[Permission::Relay, Permission::Mempool], bind),
+            "-whitebind=relay,mempool@127.0.0.1:18444"
+        );
+    }
+}
@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Snapshot of the relay policy a running node is enforcing.
+
+use anyhow::Context;
+use corepc_client::bitcoin::{Amount, Denomination, FeeRate, Weight};
+
+use crate::{launch_arg, launch_flag, BitcoinD};
+
+/// Maximum weight of a standard transaction, not configurable in Core.
+const MAX_STANDARD_TX_WEIGHT: Weight = Weight::from_wu(400_000);
+
+/// Default `-dustrelayfee` in sats per kvB (the same for all supported versions).
+const DEFAULT_DUST_RELAY_FEE: u64 = 3_000;
+
+/// Default `-datacarriersize` before Core v30.
+const DEFAULT_DATA_CARRIER_SIZE: u64 = 83;
+
+/// Default `-datacarriersize` for Core v30 and later.
+const DEFAULT_DATA_CARRIER_SIZE_V30: u64 = 100_000;
+
+/// The relay/mempool policy active on a node.
+///
+/// Combines values from `getnetworkinfo`, `getmempoolinfo` and the node launch args, falling back
+/// to the Core default for the running version when neither reports a value. Lets tests branch on
+/// the active policy instead of hard-coding values that differ between versions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicySnapshot {
+    /// Version of the running node as reported by `getnetworkinfo` e.g., `280200`.
+    pub server_version: usize,
+    /// Minimum fee rate for a transaction to be relayed (`-minrelaytxfee`).
+    pub relay_fee: FeeRate,
+    /// Minimum fee rate increment for mempool limiting or replacement (`-incrementalrelayfee`).
+    pub incremental_relay_fee: FeeRate,
+    /// Fee rate used to decide if an output is dust (`-dustrelayfee`).
+    pub dust_relay_fee: FeeRate,
+    /// Maximum weight of a standard transaction.
+    pub max_standard_tx_weight: Weight,
+    /// True if transactions with `OP_RETURN` outputs are relayed (`-datacarrier`).
+    pub data_carrier: bool,
+    /// Maximum number of bytes of `OP_RETURN` data relayed (`-datacarriersize`).
+    pub data_carrier_size: u64,
+    /// True if replacements are accepted without BIP-125 signaling (`-mempoolfullrbf`).
+    pub full_rbf: bool,
+    /// True if transactions with bare multisig outputs are relayed (`-permitbaremultisig`).
+    pub permit_bare_multisig: bool,
+}
+
+impl BitcoinD {
+    /// Returns a snapshot of the relay policy currently active on this node.
+    pub fn policy(&self) -> anyhow::Result<PolicySnapshot> {
+        let network = self.client.get_network_info()?.into_model()?;
+        let mempool = self.client.get_mempool_info()?.into_model()?;
+        let server_version = network.version;
+
+        let relay_fee = mempool
+            .min_relay_tx_fee
+            .or(network.relay_fee)
+            .context("node did not report a relay fee")?;
+        let incremental_relay_fee = mempool
+            .incremental_relay_fee
+            .or(network.incremental_fee)
+            .context("node did not report an incremental relay fee")?;
+
+        let dust_relay_fee = match launch_arg(&self.args, "dustrelayfee") {
+            Some(value) => fee_rate_from_btc_per_kvb(value)?,
+            None => FeeRate::from_sat_per_kwu(DEFAULT_DUST_RELAY_FEE / 4),
+        };
+
+        let data_carrier = launch_flag(&self.args, "datacarrier").unwrap_or(true);
+        let data_carrier_size_arg = launch_arg(&self.args, "datacarriersize");
+        let data_carrier_size = match (mempool.max_data_carrier_size, data_carrier_size_arg) {
+            (Some(size), _) => size,
+            (None, Some(value)) => value.parse().context("invalid -datacarriersize")?,
+            (None, None) if server_version >= 300_000 => DEFAULT_DATA_CARRIER_SIZE_V30,
+            (None, None) => DEFAULT_DATA_CARRIER_SIZE,
+        };
+
+        // Full RBF became the default in v28 and is not reported by `getmempoolinfo` before v24.
+        let full_rbf = mempool
+            .full_rbf
+            .or_else(|| launch_flag(&self.args, "mempoolfullrbf"))
+            .unwrap_or(server_version >= 280_000);
+        let permit_bare_multisig = mempool
+            .permit_bare_multisig
+            .or_else(|| launch_flag(&self.args, "permitbaremultisig"))
+            .unwrap_or(true);
+
+        Ok(PolicySnapshot {
+            server_version,
+            relay_fee,
+            incremental_relay_fee,
+            dust_relay_fee,
+            max_standard_tx_weight: MAX_STANDARD_TX_WEIGHT,
+            data_carrier,
+            data_carrier_size,
+            full_rbf,
+            permit_bare_multisig,
+        })
+    }
+}
+
+/// Parses a fee rate given in BTC/kvB, as used by Core fee rate args.
+fn fee_rate_from_btc_per_kvb(value: &str) -> anyhow::Result<FeeRate> {
+    let amount = Amount::from_str_in(value, Denomination::Bitcoin)
+        .with_context(|| format!("invalid fee rate: {}", value))?;
+    Ok(FeeRate::from_sat_per_kwu(amount.to_sat() / 4))
+}
@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Reserving ports until the node binds them.
+//!
+//! The OS returns a free port, but nothing stops another node launched at the same time from
+//! getting it too before either binds it. Ports handed out are recorded so that they are not
+//! handed out again in this process, and, if `BITCOIND_PORT_LOCK_DIR` is set, in other processes
+//! using the same directory.
+
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::net::{IpAddr, TcpListener};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use std::{env, io};
+
+/// Env var naming a directory used to reserve ports across processes e.g., parallel test
+/// binaries.
+const PORT_LOCK_DIR_ENV: &str = "BITCOIND_PORT_LOCK_DIR";
+
+/// How long a reservation lasts if it is not released, long enough for a node to start.
+const RESERVATION_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How many ports to ask the OS for before giving up on finding one not reserved.
+const ATTEMPTS: usize = 100;
+
+/// The ports reserved in this process and when.
+static RESERVED: Mutex<BTreeMap<u16, Instant>> = Mutex::new(BTreeMap::new());
+
+/// The ports reserved for a node being launched, released when dropped.
+#[derive(Debug)]
+pub(crate) struct Reservation {
+    ports: Vec<u16>,
+    lock_dir: Option<PathBuf>,
+}
+
+impl Reservation {
+    /// Creates an empty reservation.
+    pub(crate) fn new() -> Self { Reservation { ports: vec![], lock_dir: lock_dir() } }
+
+    /// Returns a free port on `ip` that is not reserved, and reserves it.
+    pub(crate) fn port(&mut self, ip: IpAddr) -> io::Result<u16> {
+        let port = reserve(ip, self.lock_dir.as_deref())?;
+        self.ports.push(port);
+        Ok(port)
+    }
+
+    /// Reserves `port`, bound by a node that is restarting, until the node binds it again.
+    ///
+    /// Returns `false` if the port is already reserved, the node then races for it as usual.
+    pub(crate) fn hold(&mut self, port: u16) -> io::Result<bool> {
+        let held = try_reserve(port, self.lock_dir.as_deref())?;
+        if held {
+            self.ports.push(port);
+        }
+        Ok(held)
+    }
+}
+
+impl Drop for Reservation {
+    fn drop(&mut self) {
+        let mut reserved = RESERVED.lock().expect("poisoned lock");
+        for port in &self.ports {
+            reserved.remove(port);
+            if let Some(ref dir) = self.lock_dir {
+                let _ = fs::remove_file(lock_file(dir, *port));
+            }
+        }
+    }
+}
+
+/// Returns a free port on `ip` that is not reserved, the reservation expires after
+/// [`RESERVATION_TIMEOUT`].
+pub(crate) fn reserve_until_timeout(ip: IpAddr) -> io::Result<u16> {
+    reserve(ip, lock_dir().as_deref())
+}
+
+/// Returns the directory to reserve ports across processes in, if set.
+fn lock_dir() -> Option<PathBuf> { env::var_os(PORT_LOCK_DIR_ENV).map(PathBuf::from) }
+
+/// Returns the lock file reserving `port` in `dir`.
+fn lock_file(dir: &Path, port: u16) -> PathBuf { dir.join(format!("{}.lock", port)) }
+
+/// Asks the OS for free ports on `ip` until one is not reserved, and reserves it.
+fn reserve(ip: IpAddr, lock_dir: Option<&Path>) -> io::Result<u16> {
+    for _ in 0..ATTEMPTS {
+        // Using 0 as port lets the OS choose a free port.
+        let port = TcpListener::bind((ip, 0))?.local_addr()?.port();
+        if try_reserve(port, lock_dir)? {
+            return Ok(port);
+        }
+    }
+    Err(io::Error::new(io::ErrorKind::AddrInUse, "all the free ports returned are reserved"))
+}
+
+/// Reserves `port`, returns `false` if it is already reserved.
+fn try_reserve(port: u16, lock_dir: Option<&Path>) -> io::Result<bool> {
+    let mut reserved = RESERVED.lock().expect("poisoned lock");
+    let now = Instant::now();
+    reserved.retain(|_, at| now.duration_since(*at) < RESERVATION_TIMEOUT);
+    if reserved.contains_key(&port) {
+        return Ok(false);
+    }
+    if let Some(dir) = lock_dir {
+        if !try_lock(dir, port)? {
+            return Ok(false);
+        }
+    }
+    reserved.insert(port, now);
+    Ok(true)
+}
+
+/// Creates the lock file for `port` in `dir`, returns `false` if another process holds it.
+fn try_lock(dir: &Path, port: u16) -> io::Result<bool> {
+    fs::create_dir_all(dir)?;
+    let path = lock_file(dir, port);
+    match OpenOptions::new().write(true).create_new(true).open(&path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            // A process that did not release its ports, e.g. it crashed, leaves its lock files
+            // behind. Remove them once expired so that the next attempt can use the port.
+            let expired = fs::metadata(&path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+                .and_then(|modified| modified.elapsed().ok())
+                .is_some_and(|age| age > RESERVATION_TIMEOUT);
+            if expired {
+                let _ = fs::remove_file(&path);
+            }
+            Ok(false)
+        }
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use super::*;
+
+    const LOCALHOST: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+    #[test]
+    fn reserved_port_not_reserved_again() {
+        let port = reserve(LOCALHOST, None).unwrap();
+        assert!(!try_reserve(port, None).unwrap());
+    }
+
+    #[test]
+    fn reservation_released_on_drop() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut reservation = Reservation { ports: vec![], lock_dir: Some(dir.path().into()) };
+        let port = reservation.port(LOCALHOST).unwrap();
+        assert!(lock_file(dir.path(), port).exists());
+        assert!(!try_lock(dir.path(), port).unwrap());
+
+        drop(reservation);
+        assert!(!lock_file(dir.path(), port).exists());
+        assert!(try_reserve(port, Some(dir.path())).unwrap());
+    }
+
+    #[test]
+    fn held_port_released_on_drop() {
+        let port = TcpListener::bind((LOCALHOST, 0)).unwrap().local_addr().unwrap().port();
+        let mut reservation = Reservation { ports: vec![], lock_dir: None };
+        assert!(reservation.hold(port).unwrap());
+        assert!(!try_reserve(port, None).unwrap());
+
+        drop(reservation);
+        assert!(try_reserve(port, None).unwrap());
+    }
+}
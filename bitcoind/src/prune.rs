@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Running a pruned node.
+
+use crate::{BitcoinD, Error};
+
+/// Args that cannot be used together with `-prune`, unless disabled with `=0`.
+const CONFLICTING_ARGS: &[&str] = &["-txindex"];
+
+/// Returns the `-prune` arg, or an error if an arg in `args` conflicts with pruning.
+pub(crate) fn prune_args(prune: Option<u64>, args: &[&str]) -> Result<Vec<String>, Error> {
+    match prune {
+        Some(mib) => {
+            if let Some(arg) = args.iter().find(|arg| conflicts(arg)) {
+                return Err(Error::PruneConflict(arg.to_string()));
+            }
+            Ok(vec![format!("-prune={}", mib)])
+        }
+        None => Ok(vec![]),
+    }
+}
+
+/// Returns `true` if `arg` is one of [`CONFLICTING_ARGS`] and enables it.
+fn conflicts(arg: &str) -> bool {
+    CONFLICTING_ARGS.iter().any(|name| match arg.strip_prefix(name) {
+        Some(rest) => rest.is_empty() || (rest.starts_with('=') && rest != "=0"),
+        None => false,
+    })
+}
+
+impl BitcoinD {
+    /// Prunes the block files up to `height` using `pruneblockchain`.
+    ///
+    /// The node must have been started with [`Conf::prune`](crate::Conf::prune) set. Returns the
+    /// height of the last block pruned, `None` if no blocks could be pruned.
+    pub fn prune_to_height(&self, height: u64) -> anyhow::Result<Option<u64>> {
+        let pruned = self.client.prune_blockchain(height)?;
+        Ok(u64::try_from(pruned.0).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prune_args_rejects_txindex() {
+        for arg in ["-txindex", "-txindex=1"] {
+            let args = ["-regtest", arg];
+            assert!(
+                matches!(prune_args(Some(550), &args), Err(Error::PruneConflict(a)) if a == arg)
+            );
+        }
+
+        let args = ["-regtest", "-txindex=0", "-txindexfoo"];
+        assert_eq!(prune_args(Some(550), &args).unwrap(), vec!["-prune=550".to_string()]);
+        assert!(prune_args(None, &["-txindex"]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,145 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Killing the `bitcoind` process if this process dies without dropping [`crate::BitcoinD`].
+//!
+//! `Drop` does not run if the test harness is killed (e.g., `SIGKILL` on a CI timeout) or aborts
+//! on a panic during unwinding, the node then keeps running and holds its ports. Each node gets a
+//! watchdog process that waits for this process to exit then kills the node, `sh` on Unix and
+//! PowerShell on Windows so that no system library bindings are needed.
+//!
+//! Starting the watchdog is best effort. If it cannot be started, e.g. `sh` or `powershell` is
+//! not in the `PATH`, a warning is logged with the `log` crate and the node is then only killed
+//! when dropped.
+
+use std::io;
+use std::process::{self, Child, Command, Stdio};
+
+/// How often the Unix watchdog checks whether the processes are still running, in seconds.
+#[cfg(unix)]
+const POLL_INTERVAL_SECS: u32 = 1;
+
+/// A watchdog process killing the node if this process dies, stopped when dropped.
+#[derive(Debug)]
+pub(crate) struct Reaper {
+    watchdog: Child,
+}
+
+impl Reaper {
+    /// Starts a watchdog killing the process `node` once this process exits.
+    ///
+    /// Returns `None`, and logs a warning, if the watchdog cannot be started.
+    pub(crate) fn spawn(node: u32) -> Option<Self> {
+        match Reaper::watch(process::id(), node) {
+            Ok(reaper) => Some(reaper),
+            Err(e) => {
+                log::warn!(
+                    "cannot start the watchdog of bitcoind process {}, it is not killed if this \
+                     process dies without dropping it: {}",
+                    node,
+                    e
+                );
+                None
+            }
+        }
+    }
+
+    /// Starts a watchdog killing `node` once `parent` exits.
+    fn watch(parent: u32, node: u32) -> io::Result<Self> {
+        let watchdog = watchdog_command(parent, node)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        Ok(Reaper { watchdog })
+    }
+}
+
+impl Drop for Reaper {
+    fn drop(&mut self) {
+        let _ = self.watchdog.kill();
+        let _ = self.watchdog.wait();
+    }
+}
+
+/// Returns the command polling `parent` and killing `node` once it exited.
+///
+/// The watchdog exits on its own if `node` exits first, so it never kills a process reusing the
+/// pid of the node.
+#[cfg(unix)]
+fn watchdog_command(parent: u32, node: u32) -> Command {
+    let script = format!(
+        "while kill -0 {parent} 2>/dev/null && kill -0 {node} 2>/dev/null; do sleep {poll}; done; \
+         kill -0 {parent} 2>/dev/null || kill -9 {node} 2>/dev/null",
+        parent = parent,
+        node = node,
+        poll = POLL_INTERVAL_SECS,
+    );
+    let mut command = Command::new("sh");
+    command.args(["-c", &script]);
+    command
+}
+
+/// Returns the command waiting for `parent` and killing `node` once it exited.
+///
+/// The handle to `node` is opened first, so a process reusing the pid of the node is not killed.
+#[cfg(not(unix))]
+fn watchdog_command(parent: u32, node: u32) -> Command {
+    let script = format!(
+        "$node = Get-Process -Id {node} -ErrorAction Stop; \
+         Wait-Process -Id {parent} -ErrorAction SilentlyContinue; \
+         if (-not $node.HasExited) {{ $node.Kill() }}",
+        parent = parent,
+        node = node,
+    );
+    let mut command = Command::new("powershell");
+    command.args(["-NoProfile", "-NonInteractive", "-Command", &script]);
+    command
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use super::*;
+
+    fn sleep(secs: u32) -> Child {
+        Command::new("sleep").arg(secs.to_string()).spawn().expect("sleep")
+    }
+
+    /// Waits for `child` to exit, `false` if it is still running after `timeout`.
+    fn exits_within(child: &mut Child, timeout: Duration) -> bool {
+        let start = Instant::now();
+        while start.elapsed() < timeout {
+            if child.try_wait().unwrap().is_some() {
+                return true;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+        false
+    }
+
+    #[test]
+    fn kills_node_once_parent_exits() {
+        let mut parent = sleep(60);
+        let mut node = sleep(60);
+        let _reaper = Reaper::watch(parent.id(), node.id()).unwrap();
+
+        assert!(!exits_within(&mut node, Duration::from_millis(500)));
+        parent.kill().unwrap();
+        parent.wait().unwrap();
+        assert!(exits_within(&mut node, Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn stops_when_dropped() {
+        let mut parent = sleep(60);
+        let mut node = sleep(60);
+        let reaper = Reaper::watch(parent.id(), node.id()).unwrap();
+        drop(reaper);
+        parent.kill().unwrap();
+        parent.wait().unwrap();
+        assert!(!exits_within(&mut node, Duration::from_secs(2)));
+        node.kill().unwrap();
+    }
+}
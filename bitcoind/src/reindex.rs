@@ -0,0 +1,215 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Restarting a node to rebuild its chainstate from the blocks on disk.
+
+use std::ffi::{OsStr, OsString};
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+use std::{io, thread};
+
+use anyhow::Context;
+use corepc_client::client_sync::Auth;
+
+use crate::capture::{OutputCapture, CAPTURE_LINES};
+use crate::ports::Reservation;
+use crate::reaper::Reaper;
+use crate::{log_events, wrapper, BitcoinD, Client, Conf, Error, ShutdownMode};
+
+/// How a node was launched, used to launch it again on the same data directory.
+#[derive(Debug, Clone)]
+pub(crate) struct Launch {
+    /// The `bitcoind` executable, or the wrapper running it.
+    exe: OsString,
+    /// The `bitcoind` executable, the same as `exe` unless the node runs under a wrapper.
+    pub(crate) node_exe: OsString,
+    /// All the args the node was launched with.
+    args: Vec<OsString>,
+    /// The env vars set, or removed if `None`, for the node.
+    envs: Vec<(OsString, Option<OsString>)>,
+    /// The working directory of the node, if set.
+    cwd: Option<PathBuf>,
+    /// Whether to show the node output.
+    view_stdout: bool,
+    /// The wallet loaded after startup.
+    wallet: Option<String>,
+    /// The passphrase the wallet is encrypted with, if set.
+    pub(crate) wallet_passphrase: Option<String>,
+    /// How long to wait for the node to write its cookie file.
+    startup_timeout: Duration,
+    /// How long to wait for the RPC server to answer calls.
+    rpc_ready_timeout: Duration,
+    /// How often to check whether the node is ready while waiting.
+    poll_interval: Duration,
+    /// How long to wait for the node to get back to its tip.
+    reindex_timeout: Duration,
+}
+
+impl Launch {
+    /// Records how `command` launched the node `node_exe` configured with `conf`.
+    pub(crate) fn new(command: &Command, node_exe: &OsStr, conf: &Conf) -> Self {
+        Launch {
+            exe: command.get_program().to_owned(),
+            node_exe: node_exe.to_owned(),
+            args: command.get_args().map(ToOwned::to_owned).collect(),
+            envs: command
+                .get_envs()
+                .map(|(key, value)| (key.to_owned(), value.map(ToOwned::to_owned)))
+                .collect(),
+            cwd: command.get_current_dir().map(ToOwned::to_owned),
+            view_stdout: conf.view_stdout,
+            wallet: conf.wallet.clone(),
+            wallet_passphrase: conf.wallet_passphrase.clone(),
+            startup_timeout: conf.startup_timeout,
+            rpc_ready_timeout: conf.rpc_ready_timeout,
+            poll_interval: conf.poll_interval,
+            reindex_timeout: conf.reindex_timeout,
+        }
+    }
+}
+
+/// Returns the arg to rebuild the block index and chainstate if `full`, otherwise only the
+/// chainstate.
+fn reindex_arg(full: bool) -> &'static str {
+    if full {
+        "-reindex"
+    } else {
+        "-reindex-chainstate"
+    }
+}
+
+impl BitcoinD {
+    /// Restarts the node with `-reindex` if `full`, otherwise with `-reindex-chainstate`.
+    ///
+    /// Shuts the node down gracefully, launches it again on the same data directory and with the
+    /// same args, then waits until it is back at the tip it had before the restart, for at most
+    /// [`Conf::reindex_timeout`]. Its ports stay reserved during the restart so that nodes
+    /// launched meanwhile do not take them. The client is replaced and the wallet loaded again,
+    /// the mock time set on the node is restored.
+    ///
+    /// Not supported on pruned nodes, Core refuses to reindex the chainstate without all blocks.
+    pub fn restart_with_reindex(&mut self, full: bool) -> anyhow::Result<()> {
+        let tip = self.client.call::<serde_json::Value>("getbestblockhash", &[])?;
+        let _ports = self.hold_ports()?;
+        self.shutdown(ShutdownMode::default())?;
+        self.relaunch(&[reindex_arg(full)], &tip)
+    }
+
+    /// Launches the node again after a shutdown, with `extra_args` added to the args it was
+    /// launched with, and waits until it is back at `tip`.
+    ///
+    /// The client is replaced and the wallet loaded again, the mock time set on the node is
+    /// restored. The extra args only apply to this launch.
+    pub(crate) fn relaunch(
+        &mut self,
+        extra_args: &[&str],
+        tip: &serde_json::Value,
+    ) -> anyhow::Result<()> {
+        let launch = self.launch.clone();
+        let stdout = log_events::stdout(self.log_events.is_some(), launch.view_stdout);
+        let mut command = Command::new(&launch.exe);
+        command.args(&launch.args).args(extra_args).stdout(stdout).stderr(Stdio::piped());
+        for (key, value) in &launch.envs {
+            match value {
+                Some(value) => command.env(key, value),
+                None => command.env_remove(key),
+            };
+        }
+        if let Some(ref cwd) = launch.cwd {
+            command.current_dir(cwd);
+        }
+        let mut process =
+            command.spawn().with_context(|| format!("Error while executing {:?}", launch.exe))?;
+        let reaper = Reaper::spawn(process.id());
+        let mut stderr = OutputCapture::spawn(
+            process.stderr.take().expect("stderr is piped"),
+            CAPTURE_LINES,
+            launch.view_stdout,
+        );
+        if let Some(ref log_events) = self.log_events {
+            log_events.forward(process.stdout.take().expect("stdout is piped"), launch.view_stdout);
+        }
+
+        let cookie_file = self.params.cookie_file.clone();
+        if Self::wait_for_cookie_file(&cookie_file, launch.startup_timeout, launch.poll_interval)
+            .is_err()
+        {
+            let err = Self::kill_after_failure(&mut process, self.pid_file.as_deref(), &mut stderr);
+            return Err(err.into());
+        }
+        // The new process owns the data directory, from here on drop shuts it down.
+        self.pid = wrapper::node_pid(&process, self.pid_file.as_deref());
+        self.reaper = if self.pid == process.id() { reaper } else { Reaper::spawn(self.pid) };
+        self.process = process;
+
+        let rpc_url = self.rpc_url();
+        let auth = Auth::CookieFile(cookie_file);
+        let client_base = Self::create_client_base(&rpc_url, &auth)?;
+        Self::wait_for_client(&client_base, launch.rpc_ready_timeout, launch.poll_interval)?;
+        Self::wait_for_tip(&client_base, tip, launch.reindex_timeout, launch.poll_interval)?;
+
+        // Load the wallet once the chainstate is rebuilt so that it does not need to rescan.
+        self.client = match launch.wallet {
+            Some(ref wallet) => Self::create_client_wallet(&client_base, &rpc_url, &auth, wallet)?,
+            None => client_base,
+        };
+        if let Some(timestamp) = self.mocktime() {
+            self.set_mocktime(timestamp)?;
+        }
+        Ok(())
+    }
+
+    /// Reserves the ports of the node, so that no other node gets them while it restarts.
+    ///
+    /// Hold the reservation from before the shutdown until the node is launched again.
+    pub(crate) fn hold_ports(&self) -> io::Result<Reservation> {
+        let params = &self.params;
+        let sockets =
+            [params.p2p_socket, params.zmq_pub_raw_block_socket, params.zmq_pub_raw_tx_socket];
+        let mut ports = Reservation::new();
+        ports.hold(params.rpc_socket.port())?;
+        for socket in sockets.into_iter().flatten() {
+            ports.hold(socket.port())?;
+        }
+        Ok(ports)
+    }
+
+    /// Returns `Ok` once the best block of the node is `tip`, or an error if it times out.
+    fn wait_for_tip(
+        client: &Client,
+        tip: &serde_json::Value,
+        timeout: Duration,
+        poll_interval: Duration,
+    ) -> anyhow::Result<()> {
+        let start = Instant::now();
+        while start.elapsed() < timeout {
+            let best = client.call::<serde_json::Value>("getbestblockhash", &[]);
+            if best.is_ok_and(|hash| hash == *tip) {
+                return Ok(());
+            }
+            thread::sleep(poll_interval);
+        }
+        Err(Error::NoBitcoindInstance(format!("node did not reach the tip {}", tip)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn launch_records_command() {
+        let mut command = Command::new("bitcoind");
+        command.args(["-regtest", "-datadir=/tmp/node"]).env("LC_ALL", "C").current_dir("/tmp");
+        let launch = Launch::new(&command, OsStr::new("bitcoind"), &Conf::default());
+
+        assert_eq!(launch.exe, "bitcoind");
+        assert_eq!(launch.node_exe, "bitcoind");
+        assert_eq!(launch.args, vec![OsString::from("-regtest"), "-datadir=/tmp/node".into()]);
+        assert_eq!(launch.envs, vec![("LC_ALL".into(), Some("C".into()))]);
+        assert_eq!(launch.cwd, Some(PathBuf::from("/tmp")));
+        assert_eq!(launch.wallet.as_deref(), Some("default"));
+        assert_eq!(reindex_arg(true), "-reindex");
+        assert_eq!(reindex_arg(false), "-reindex-chainstate");
+    }
+}
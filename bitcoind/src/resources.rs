@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Resource usage of the `bitcoind` process.
+
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::BitcoinD;
+
+/// A sample of the resources used by a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceUsage {
+    /// Resident set size of the process in bytes, `None` if it cannot be read on this platform.
+    pub rss: Option<u64>,
+    /// CPU time (user and system) used by the process, `None` if it cannot be read on this
+    /// platform.
+    pub cpu_time: Option<Duration>,
+    /// Total size of the files in the data directory in bytes.
+    pub datadir_size: u64,
+}
+
+impl BitcoinD {
+    /// Samples the memory, CPU time and on-disk data directory size of the node.
+    ///
+    /// Memory and CPU time are read from `/proc` on Linux and using `ps` on other Unix platforms,
+    /// they are not available on Windows.
+    pub fn resource_usage(&self) -> anyhow::Result<ResourceUsage> {
+        let (rss, cpu_time) = process_usage(self.pid);
+        Ok(ResourceUsage { rss, cpu_time, datadir_size: dir_size(&self.workdir())? })
+    }
+}
+
+/// Returns the total size of the files in `dir`, recursively.
+fn dir_size(dir: &Path) -> anyhow::Result<u64> {
+    let mut size = 0;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        // Files can be removed by the node while we walk the directory.
+        let Ok(metadata) = entry.metadata() else { continue };
+        if metadata.is_dir() {
+            size += dir_size(&entry.path())?;
+        } else {
+            size += metadata.len();
+        }
+    }
+    Ok(size)
+}
+
+/// Clock ticks per second used by `/proc/<pid>/stat`, this is `USER_HZ` which is 100 on all
+/// mainstream architectures.
+#[cfg(target_os = "linux")]
+const CLOCK_TICKS: u64 = 100;
+
+/// Returns the resident set size and CPU time of the process `pid`.
+#[cfg(target_os = "linux")]
+fn process_usage(pid: u32) -> (Option<u64>, Option<Duration>) {
+    let rss = fs::read_to_string(format!("/proc/{}/status", pid)).ok().and_then(|s| rss_kib(&s));
+    let cpu_time =
+        fs::read_to_string(format!("/proc/{}/stat", pid)).ok().and_then(|s| cpu_ticks(&s));
+    (
+        rss.map(|kib| kib * 1024),
+        cpu_time.map(|ticks| Duration::from_millis(ticks * 1000 / CLOCK_TICKS)),
+    )
+}
+
+/// Returns the resident set size and CPU time of the process `pid`.
+#[cfg(all(unix, not(target_os = "linux")))]
+fn process_usage(pid: u32) -> (Option<u64>, Option<Duration>) {
+    let output = std::process::Command::new("ps")
+        .args(["-o", "rss=", "-o", "time=", "-p", &pid.to_string()])
+        .output();
+    let Ok(output) = output else { return (None, None) };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut fields = stdout.split_whitespace();
+    let rss = fields.next().and_then(|kib| kib.parse::<u64>().ok()).map(|kib| kib * 1024);
+    let cpu_time = fields.next().and_then(ps_time);
+    (rss, cpu_time)
+}
+
+/// Returns the resident set size and CPU time of the process `pid`.
+#[cfg(not(unix))]
+fn process_usage(_: u32) -> (Option<u64>, Option<Duration>) { (None, None) }
+
+/// Returns the `VmRSS` in KiB from the contents of `/proc/<pid>/status`.
+#[cfg(any(target_os = "linux", test))]
+fn rss_kib(status: &str) -> Option<u64> {
+    let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+    line.split_whitespace().nth(1)?.parse().ok()
+}
+
+/// Returns `utime + stime` in clock ticks from the contents of `/proc/<pid>/stat`.
+#[cfg(any(target_os = "linux", test))]
+fn cpu_ticks(stat: &str) -> Option<u64> {
+    // The command name (field 2) is in parentheses and may contain spaces.
+    let rest = &stat[stat.rfind(')')? + 1..];
+    let fields = rest.split_whitespace().collect::<Vec<_>>();
+    // `utime` and `stime` are fields 14 and 15, `rest` starts at field 3.
+    let utime = fields.get(11)?.parse::<u64>().ok()?;
+    let stime = fields.get(12)?.parse::<u64>().ok()?;
+    Some(utime + stime)
+}
+
+/// Parses the `[[dd-]hh:]mm:ss[.cc]` time format used by `ps`.
+#[cfg(any(all(unix, not(target_os = "linux")), test))]
+fn ps_time(time: &str) -> Option<Duration> {
+    let (days, time) = match time.split_once('-') {
+        Some((days, time)) => (days.parse::<u64>().ok()?, time),
+        None => (0, time),
+    };
+    let mut seconds = 0.0;
+    for part in time.split(':') {
+        seconds = seconds * 60.0 + part.parse::<f64>().ok()?;
+    }
+    Some(Duration::from_secs(days * 86_400) + Duration::from_secs_f64(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_proc_files() {
+        let status = "Name:\tbitcoind\nVmPeak:\t  900000 kB\nVmRSS:\t  123456 kB\nThreads:\t20\n";
+        assert_eq!(rss_kib(status), Some(123_456));
+
+        let stat = "4242 (bitcoin d) S 1 4242 4242 0 -1 4194560 100 0 0 0 250 50 0 0 20 0 20 0";
+        assert_eq!(cpu_ticks(stat), Some(300));
+    }
+
+    #[test]
+    fn parse_ps_time() {
+        assert_eq!(ps_time("01:02"), Some(Duration::from_secs(62)));
+        assert_eq!(ps_time("0:01.50"), Some(Duration::from_millis(1500)));
+        assert_eq!(ps_time("1-02:00:00"), Some(Duration::from_secs(93_600)));
+        assert_eq!(ps_time("junk"), None);
+    }
+}
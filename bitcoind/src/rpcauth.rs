@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! RPC authentication, generating `-rpcauth` credentials as done by Core's
+//! `share/rpcauth/rpcauth.py` and locating the cookie file.
+
+use std::collections::hash_map::RandomState;
+use std::fmt::Write as _;
+use std::hash::{BuildHasher, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use corepc_client::bitcoin::hashes::{hmac, sha256, Hash, HashEngine};
+
+/// A user allowed to call the RPC interface with a password, see [`crate::Conf::rpc_auth`].
+///
+/// Only the salted hash of the password is kept, as passed to `-rpcauth`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RpcAuth {
+    user: String,
+    salt: String,
+    hash: String,
+}
+
+impl RpcAuth {
+    /// Generates a random password for `user`, returns the credentials and the password.
+    ///
+    /// The randomness is good enough for tests, do not use it to secure a real node.
+    pub fn generate(user: &str) -> (RpcAuth, String) {
+        let password = hex(&random_bytes(32));
+        (RpcAuth::new(user, &password), password)
+    }
+
+    /// Creates the credentials of `user` with `password`, using a random salt.
+    pub fn new(user: &str, password: &str) -> Self {
+        RpcAuth::with_salt(user, password, hex(&random_bytes(16)))
+    }
+
+    /// Returns the user name.
+    pub fn user(&self) -> &str { &self.user }
+
+    /// Returns the `-rpcauth` arg value, `<user>:<salt>$<hash>`.
+    pub fn value(&self) -> String { format!("{}:{}${}", self.user, self.salt, self.hash) }
+
+    /// Creates the credentials of `user` with `password` and `salt`.
+    fn with_salt(user: &str, password: &str, salt: String) -> Self {
+        let mut engine = hmac::HmacEngine::<sha256::Hash>::new(salt.as_bytes());
+        engine.input(password.as_bytes());
+        let hash = hmac::Hmac::<sha256::Hash>::from_engine(engine).to_string();
+        RpcAuth { user: user.to_string(), salt, hash }
+    }
+}
+
+/// Returns the `-rpcauth` args for `rpc_auth`.
+pub(crate) fn rpc_auth_args(rpc_auth: Option<&RpcAuth>) -> Vec<String> {
+    rpc_auth.iter().map(|auth| format!("-rpcauth={}", auth.value())).collect()
+}
+
+/// Returns the `-rpccookiefile` args for `cookie_file` and the path the node writes the cookie to.
+///
+/// Core resolves a relative path against the network directory `net_dir`.
+pub(crate) fn cookie_file_args(
+    cookie_file: Option<&Path>,
+    net_dir: &Path,
+) -> (Vec<String>, PathBuf) {
+    match cookie_file {
+        Some(path) => (vec![format!("-rpccookiefile={}", path.display())], net_dir.join(path)),
+        None => (vec![], net_dir.join(".cookie")),
+    }
+}
+
+/// Returns `n` random bytes.
+///
+/// Each [`RandomState`] is randomly seeded, which avoids adding a dependency to get randomness.
+fn random_bytes(n: usize) -> Vec<u8> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+    let mut bytes = Vec::with_capacity(n + 8);
+    while bytes.len() < n {
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u128(now.as_nanos());
+        bytes.extend_from_slice(&hasher.finish().to_le_bytes());
+    }
+    bytes.truncate(n);
+    bytes
+}
+
+/// Returns `bytes` as lower case hex.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::with_capacity(bytes.len() * 2), |mut s, byte| {
+        let _ = write!(s, "{:02x}", byte);
+        s
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rpcauth_matches_rpcauth_py() {
+        // Generated with `rpcauth.py bitcoind bitcoind`.
+        let auth = RpcAuth::with_salt(
+            "bitcoind",
+            "bitcoind",
+            "cccd5d7fd36e55c1b8576b8077dc1b83".to_string(),
+        );
+        assert_eq!(
+            auth.value(),
+            "bitcoind:cccd5d7fd36e55c1b8576b8077dc1b83$60b5676a09f8518dcb4574838fb86f37700cd690d99bd2fdc2ea2bf2ab80ead6"
+        );
+    }
+
+    #[test]
+    fn generate_random_password_and_salt() {
+        let (first, first_password) = RpcAuth::generate("user");
+        let (second, second_password) = RpcAuth::generate("user");
+        assert_eq!(first_password.len(), 64);
+        assert_eq!(first.salt.len(), 32);
+        assert_ne!(first_password, second_password);
+        assert_ne!(first.salt, second.salt);
+        assert_eq!(rpc_auth_args(Some(&first)), vec![format!("-rpcauth={}", first.value())]);
+        assert!(rpc_auth_args(None).is_empty());
+    }
+
+    #[test]
+    fn cookie_file_location() {
+        let net_dir = Path::new("/data/regtest");
+        assert_eq!(cookie_file_args(None, net_dir), (vec![], net_dir.join(".cookie")));
+
+        let (args, path) = cookie_file_args(Some(Path::new("auth/cookie")), net_dir);
+        assert_eq!(args, vec![format!("-rpccookiefile={}", Path::new("auth/cookie").display())]);
+        assert_eq!(path, net_dir.join("auth").join("cookie"));
+
+        let absolute = std::env::temp_dir().join("cookie");
+        assert_eq!(cookie_file_args(Some(&absolute), net_dir).1, absolute);
+    }
+}
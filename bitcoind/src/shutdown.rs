@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Shutting down the `bitcoind` process.
+
+use std::path::Path;
+use std::process::{Child, ExitStatus, Stdio};
+use std::time::{Duration, Instant};
+use std::{fs, thread};
+
+use crate::{BitcoinD, DataDir};
+
+/// Logged by the node once it shut down cleanly.
+const SHUTDOWN_DONE: &str = "Shutdown: done";
+
+/// Logged by the node when it starts, the log of the last run starts at the last one.
+const STARTUP_BANNER: &str = "Bitcoin Core version";
+
+/// Lower case parts of the messages logged about a corrupted block, chainstate or wallet database.
+const CORRUPTION_MESSAGES: [&str; 3] =
+    ["corrupt", "fatal leveldb error", "error reading from database"];
+
+/// How to shut down a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShutdownMode {
+    /// Ask the node to stop via the `stop` RPC and escalate if it does not exit in time.
+    ///
+    /// The node gets `stop_timeout` to exit after the `stop` RPC, then it is sent `SIGTERM` and
+    /// gets `term_timeout` to exit, then it is killed. On Windows there is no `SIGTERM` step.
+    Graceful {
+        /// How long to wait for the process to exit after calling `stop`.
+        stop_timeout: Duration,
+        /// How long to wait for the process to exit after sending `SIGTERM`.
+        term_timeout: Duration,
+    },
+    /// Kill the process immediately (`SIGKILL`).
+    Kill,
+}
+
+impl Default for ShutdownMode {
+    fn default() -> Self {
+        ShutdownMode::Graceful {
+            stop_timeout: Duration::from_secs(30),
+            term_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// How a node stopped, see [`BitcoinD::stop`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StopReport {
+    /// The exit status of the process.
+    pub status: ExitStatus,
+    /// The node logged `Shutdown: done`, `false` if the log cannot be read.
+    pub clean_shutdown: bool,
+    /// The lines logged about a corrupted database since the node last started.
+    pub corruption: Vec<String>,
+}
+
+impl StopReport {
+    /// Returns the report of a node that exited with `status`, reading its `debug.log`.
+    pub(crate) fn new(status: ExitStatus, debug_log: &Path) -> Self {
+        let log = fs::read(debug_log).unwrap_or_default();
+        StopReport::from_log(status, &String::from_utf8_lossy(&log))
+    }
+
+    fn from_log(status: ExitStatus, log: &str) -> Self {
+        let last_run = match log.rfind(STARTUP_BANNER) {
+            Some(start) => &log[start..],
+            None => log,
+        };
+        let corruption = last_run
+            .lines()
+            .filter(|line| {
+                let line = line.to_lowercase();
+                CORRUPTION_MESSAGES.iter().any(|message| line.contains(message))
+            })
+            .map(ToOwned::to_owned)
+            .collect();
+        StopReport { status, clean_shutdown: last_run.contains(SHUTDOWN_DONE), corruption }
+    }
+
+    /// Returns `true` if the node exited successfully after a clean shutdown and logged no
+    /// corruption.
+    pub fn is_clean(&self) -> bool {
+        self.status.success() && self.clean_shutdown && self.corruption.is_empty()
+    }
+}
+
+impl BitcoinD {
+    /// Shuts the node down using the given `mode`, returns once the process has been reaped.
+    ///
+    /// Under [`crate::Conf::wrapper`] the signals go to the node, the exit status is the wrapper's.
+    pub fn shutdown(&mut self, mode: ShutdownMode) -> anyhow::Result<ExitStatus> {
+        let _ = self.stop_miner();
+        if let ShutdownMode::Graceful { stop_timeout, term_timeout } = mode {
+            if self.client.stop().is_ok() {
+                if let Some(status) = wait_timeout(&mut self.process, stop_timeout)? {
+                    return Ok(status);
+                }
+            }
+            if terminate(self.pid) {
+                if let Some(status) = wait_timeout(&mut self.process, term_timeout)? {
+                    return Ok(status);
+                }
+            }
+        }
+        // A wrapper may run the node in a child process that outlives it.
+        if self.pid != self.process.id() {
+            kill(self.pid);
+        }
+        // Errors if the process has already exited, `wait` still returns the exit status.
+        let _ = self.process.kill();
+        Ok(self.process.wait()?)
+    }
+
+    /// Stops the miner, keeps the data directory and prints how to reach the node, which keeps
+    /// running once this is dropped.
+    pub(crate) fn leave_running(&mut self) {
+        let _ = self.stop_miner();
+        if let DataDir::Temporary(_) = self.work_dir {
+            let path = self.work_dir.path();
+            if let DataDir::Temporary(dir) =
+                std::mem::replace(&mut self.work_dir, DataDir::Persistent(path))
+            {
+                // Forgetting the `TempDir` skips its drop, which would delete the directory.
+                std::mem::forget(dir);
+            }
+        }
+        eprintln!(
+            "bitcoind left running (pid {}):\n  datadir: {}\n  rpc url: {}\n  cookie file: {}",
+            self.pid,
+            self.work_dir.path().display(),
+            self.rpc_url(),
+            self.params.cookie_file.display(),
+        );
+    }
+}
+
+/// Waits up to `timeout` for `process` to exit, returns `None` if it is still running.
+fn wait_timeout(process: &mut Child, timeout: Duration) -> anyhow::Result<Option<ExitStatus>> {
+    let start = Instant::now();
+    loop {
+        if let Some(status) = process.try_wait()? {
+            return Ok(Some(status));
+        }
+        if start.elapsed() >= timeout {
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(100));
+    }
+}
+
+/// Sends `SIGTERM` to the process `pid`, returns `true` if the signal was sent.
+#[cfg(unix)]
+fn terminate(pid: u32) -> bool {
+    std::process::Command::new("kill")
+        .args(["-TERM", &pid.to_string()])
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+/// There is no `SIGTERM` on Windows, the caller falls through to killing the process.
+#[cfg(not(unix))]
+fn terminate(_: u32) -> bool { false }
+
+/// Kills the process `pid`, which is not a child of this process.
+#[cfg(unix)]
+pub(crate) fn kill(pid: u32) {
+    let _ = std::process::Command::new("kill")
+        .args(["-KILL", &pid.to_string()])
+        .stderr(Stdio::null())
+        .status();
+}
+
+/// Kills the process `pid`, which is not a child of this process.
+#[cfg(not(unix))]
+pub(crate) fn kill(pid: u32) {
+    let _ = std::process::Command::new("taskkill")
+        .args(["/F", "/PID", &pid.to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns a successful exit status.
+    fn success() -> ExitStatus {
+        #[cfg(unix)]
+        use std::os::unix::process::ExitStatusExt;
+        #[cfg(windows)]
+        use std::os::windows::process::ExitStatusExt;
+        ExitStatus::from_raw(0)
+    }
+
+    #[test]
+    fn report_from_log() {
+        let first_run = "2024-05-01T12:00:00Z Bitcoin Core version v26.2.0\n\
+                         2024-05-01T12:00:01Z Fatal LevelDB error: Corruption: block checksum mismatch\n";
+        let second_run = "2024-05-01T12:01:00Z Bitcoin Core version v26.2.0\n\
+                          2024-05-01T12:01:01Z init message: Done loading\n";
+
+        let report = StopReport::from_log(success(), &format!("{}{}", first_run, second_run));
+        assert!(!report.clean_shutdown);
+        assert!(report.corruption.is_empty());
+        assert!(!report.is_clean());
+
+        let report = StopReport::from_log(success(), first_run);
+        assert_eq!(report.corruption.len(), 1);
+
+        let log = format!("{}{}2024-05-01T12:01:02Z Shutdown: done\n", first_run, second_run);
+        assert!(StopReport::from_log(success(), &log).is_clean());
+    }
+}
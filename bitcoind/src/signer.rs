@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! External signer support, see [`Conf::external_signer`](crate::Conf::external_signer).
+//!
+//! Includes a mock [HWI] compatible signer, good enough to create an external signer wallet and
+//! call `enumeratesigners` and `walletdisplayaddress` without a hardware wallet.
+//!
+//! [HWI]: <https://github.com/bitcoin-core/HWI>
+
+use std::path::Path;
+#[cfg(unix)]
+use std::path::PathBuf;
+#[cfg(unix)]
+use std::{fs, io};
+
+/// Fingerprint of the master key of the mock signer.
+pub const MOCK_FINGERPRINT: &str = "00000001";
+
+/// Account xpub returned by the mock signer, from Core's `wallet_signer.py`.
+pub const MOCK_XPUB: &str = "tpubD6NzVbkrYhZ4WaWSyoBvQwbpLkojyoTZPRsgXELWz3Popb3qkjcJyJUGLnL4qHHoQvao8ESaAstxYSnhyswJ76uZPStJRJCTKvosUCJZL5B";
+
+/// Address the mock signer displays for `walletdisplayaddress`, from Core's `wallet_signer.py`.
+pub const MOCK_ADDRESS: &str = "bcrt1qm90ugl4d48jv8n6e5t9ln6t9zlpm5th68x4f8g";
+
+/// File name of the mock signer script written by [`install_mock`].
+#[cfg(unix)]
+const MOCK_FILE_NAME: &str = "mock_signer.sh";
+
+/// Returns the mock signer script, it ignores the options and handles the HWI commands used by
+/// Core.
+#[cfg(unix)]
+fn mock_script() -> String {
+    format!(
+        r#"#!/bin/sh
+CMD=""
+while [ $# -gt 0 ]; do
+    case "$1" in enumerate|getdescriptors|displayaddress) CMD="$1" ;; esac
+    shift
+done
+case "$CMD" in
+    enumerate)      echo '[{{"fingerprint":"{fingerprint}","type":"trezor","model":"trezor_t"}}]' ;;
+    getdescriptors) echo '{{"receive":["wpkh([{fingerprint}/84h/1h/0h]{xpub}/0/*)"],"internal":["wpkh([{fingerprint}/84h/1h/0h]{xpub}/1/*)"]}}' ;;
+    displayaddress) echo '{{"address":"{address}"}}' ;;
+    *)              echo '{{"error":"unknown command"}}'; exit 1 ;;
+esac
+"#,
+        fingerprint = MOCK_FINGERPRINT,
+        xpub = MOCK_XPUB,
+        address = MOCK_ADDRESS,
+    )
+}
+
+/// Writes the mock signer script to `dir` and returns its path, to use as
+/// [`Conf::external_signer`](crate::Conf::external_signer).
+///
+/// Use a directory that outlives the node e.g., a [`tempfile::TempDir`], and a different one for
+/// each node so that a script is never rewritten while a node runs it.
+#[cfg(unix)]
+pub fn install_mock(dir: &Path) -> io::Result<PathBuf> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let path = dir.join(MOCK_FILE_NAME);
+    fs::write(&path, mock_script())?;
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+    Ok(path)
+}
+
+/// Returns the `-signer` arg if `signer` is set.
+pub(crate) fn signer_args(signer: Option<&Path>) -> Vec<String> {
+    signer.iter().map(|path| format!("-signer={}", path.display())).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(unix)]
+    fn mock_script_outputs() {
+        let script = mock_script();
+        assert!(script.contains(r#"[{"fingerprint":"00000001","type":"trezor""#));
+        assert!(script.contains(&format!(r#"{{"address":"{}"}}"#, MOCK_ADDRESS)));
+    }
+
+    #[test]
+    fn signer_args_path() {
+        assert!(signer_args(None).is_empty());
+        assert_eq!(signer_args(Some(Path::new("/tmp/hwi"))), vec!["-signer=/tmp/hwi"]);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn install_mock_is_executable() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = install_mock(dir.path()).unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0o111);
+    }
+}
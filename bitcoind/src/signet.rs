@@ -0,0 +1,242 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Private signets, where blocks are signed by a key chosen by the test (Core v0.21 and later).
+//!
+//! Mining a block is what `contrib/signet/miner` does: the coinbase commits to a signature of the
+//! block (BIP-325), then the block is ground to the (low) signet difficulty.
+
+use corepc_client::bitcoin::block::Header;
+use corepc_client::bitcoin::consensus::encode;
+use corepc_client::bitcoin::hashes::{sha256d, Hash};
+use corepc_client::bitcoin::opcodes::all::{OP_CHECKMULTISIG, OP_RETURN};
+use corepc_client::bitcoin::opcodes::OP_0;
+use corepc_client::bitcoin::script::{Builder, PushBytesBuf};
+use corepc_client::bitcoin::secp256k1::{Message, Secp256k1};
+use corepc_client::bitcoin::sighash::{EcdsaSighashType, SighashCache};
+use corepc_client::bitcoin::{
+    absolute, ecdsa, transaction, Address, Amount, Block, BlockHash, OutPoint, PrivateKey,
+    PublicKey, ScriptBuf, Sequence, Transaction, TxIn, TxMerkleNode, TxOut, Witness,
+};
+
+use crate::{vtype, BitcoinD, Conf};
+
+/// Prefix of the signet solution in the witness commitment output.
+const SIGNET_HEADER: [u8; 4] = [0xec, 0xc7, 0xda, 0xa2];
+
+/// Prefix of the witness commitment in the witness commitment output.
+const WITNESS_HEADER: [u8; 4] = [0xaa, 0x21, 0xa9, 0xed];
+
+/// Returns the challenge of a signet whose blocks are signed by `key`, `1 <key> 1 CHECKMULTISIG`.
+///
+/// This is the challenge [`BitcoinD::mine_signet_block`] knows how to sign for.
+pub fn signet_challenge(key: &PublicKey) -> ScriptBuf {
+    Builder::new().push_int(1).push_key(key).push_int(1).push_opcode(OP_CHECKMULTISIG).into_script()
+}
+
+impl Conf<'_> {
+    /// Returns the default configuration changed to run a private signet with `challenge`.
+    ///
+    /// See [`signet_challenge`] to get a challenge for a key.
+    pub fn custom_signet(challenge: ScriptBuf) -> Self {
+        Conf {
+            args: vec!["-signet", "-fallbackfee=0.0001"],
+            network: "signet",
+            signet_challenge: Some(challenge),
+            ..Default::default()
+        }
+    }
+}
+
+/// Returns the `-signetchallenge` arg for `challenge`, if any.
+pub(crate) fn signet_args(challenge: Option<&ScriptBuf>) -> Vec<String> {
+    challenge.iter().map(|script| format!("-signetchallenge={}", script.to_hex_string())).collect()
+}
+
+impl BitcoinD {
+    /// Mines a block on a private signet paying the reward to `address`, signing it with `key`.
+    ///
+    /// The node must run with the challenge returned by [`signet_challenge`] for `key`, see
+    /// [`Conf::custom_signet`]. The block includes the transactions of the block template. Blocks
+    /// still need proof of work, expect a few seconds per block in debug builds.
+    pub fn mine_signet_block(
+        &self,
+        key: &PrivateKey,
+        address: &Address,
+    ) -> anyhow::Result<BlockHash> {
+        let secp = Secp256k1::signing_only();
+        let challenge = signet_challenge(&key.public_key(&secp));
+
+        let request = serde_json::json!({ "rules": ["segwit", "signet"] });
+        let template =
+            self.client.call::<vtype::GetBlockTemplate>("getblocktemplate", &[request])?;
+        let template = template.into_model()?;
+
+        let coinbase_value = template.coinbase_value.to_unsigned()?;
+        let mut txdata = vec![coinbase(template.height, coinbase_value, address)];
+        txdata.extend(template.transactions.into_iter().map(|tx| tx.data));
+        let header = Header {
+            version: template.version,
+            prev_blockhash: template.previous_block_hash,
+            merkle_root: TxMerkleNode::all_zeros(),
+            time: template.current_time as u32,
+            bits: template.bits,
+            nonce: 0,
+        };
+        let mut block = Block { header, txdata };
+
+        // The signature commits to the block with only the header of the solution.
+        let witness_root = block.witness_root().expect("the coinbase is always there");
+        let commitment = Block::compute_witness_commitment(&witness_root, &[0; 32]);
+        let mut commitment_data = WITNESS_HEADER.to_vec();
+        commitment_data.extend_from_slice(commitment.as_byte_array());
+        set_commitment(&mut block, &commitment_data, &SIGNET_HEADER)?;
+
+        let solution = sign(&block, &challenge, key)?;
+        let mut signet_data = SIGNET_HEADER.to_vec();
+        signet_data.extend(solution);
+        set_commitment(&mut block, &commitment_data, &signet_data)?;
+
+        grind(&mut block.header)?;
+        self.client.submit_block(&block)?;
+        Ok(block.block_hash())
+    }
+}
+
+/// Returns a coinbase at `height` paying `value` to `address`, without the witness commitment.
+fn coinbase(height: u32, value: Amount, address: &Address) -> Transaction {
+    // BIP-34 height, padded to the minimum coinbase script length.
+    let script_sig = Builder::new().push_int(height.into()).push_opcode(OP_0).into_script();
+    Transaction {
+        version: transaction::Version::TWO,
+        lock_time: absolute::LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig,
+            sequence: Sequence::MAX,
+            witness: Witness::from_slice(&[[0u8; 32]]),
+        }],
+        output: vec![TxOut { value, script_pubkey: address.script_pubkey() }],
+    }
+}
+
+/// Sets the witness commitment output of the coinbase of `block`, pushing `commitment` then
+/// `signet`, and updates the merkle root.
+fn set_commitment(block: &mut Block, commitment: &[u8], signet: &[u8]) -> anyhow::Result<()> {
+    let script_pubkey = Builder::new()
+        .push_opcode(OP_RETURN)
+        .push_slice(PushBytesBuf::try_from(commitment.to_vec())?)
+        .push_slice(PushBytesBuf::try_from(signet.to_vec())?)
+        .into_script();
+    let output = TxOut { value: Amount::ZERO, script_pubkey };
+    let coinbase = &mut block.txdata[0];
+    if coinbase.output.len() == 1 {
+        coinbase.output.push(output);
+    } else {
+        coinbase.output[1] = output;
+    }
+    block.header.merkle_root = block.compute_merkle_root().expect("the coinbase is always there");
+    Ok(())
+}
+
+/// Returns the signet solution of `block` for `challenge`, the script sig and witness of the
+/// transaction spending the challenge.
+fn sign(block: &Block, challenge: &ScriptBuf, key: &PrivateKey) -> anyhow::Result<Vec<u8>> {
+    // The block data committed to: version, previous block hash, merkle root and time.
+    let mut block_data = encode::serialize(&block.header.version);
+    block_data.extend(encode::serialize(&block.header.prev_blockhash));
+    block_data.extend(encode::serialize(&block.header.merkle_root));
+    block_data.extend(encode::serialize(&block.header.time));
+
+    let to_spend = Transaction {
+        version: transaction::Version(0),
+        lock_time: absolute::LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: Builder::new()
+                .push_opcode(OP_0)
+                .push_slice(PushBytesBuf::try_from(block_data)?)
+                .into_script(),
+            sequence: Sequence::ZERO,
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut { value: Amount::ZERO, script_pubkey: challenge.clone() }],
+    };
+    let mut to_sign = Transaction {
+        version: transaction::Version(0),
+        lock_time: absolute::LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::new(to_spend.compute_txid(), 0),
+            script_sig: ScriptBuf::new(),
+            sequence: Sequence::ZERO,
+            witness: Witness::new(),
+        }],
+        output: vec![TxOut {
+            value: Amount::ZERO,
+            script_pubkey: Builder::new().push_opcode(OP_RETURN).into_script(),
+        }],
+    };
+
+    let sighash_type = EcdsaSighashType::All;
+    let sighash =
+        SighashCache::new(&to_sign).legacy_signature_hash(0, challenge, sighash_type.to_u32())?;
+    let message = Message::from_digest(sighash.to_byte_array());
+    let signature = Secp256k1::signing_only().sign_ecdsa(&message, &key.inner);
+    let signature = ecdsa::Signature { signature, sighash_type };
+    // `CHECKMULTISIG` pops an extra element.
+    to_sign.input[0].script_sig =
+        Builder::new().push_opcode(OP_0).push_slice(signature.serialize()).into_script();
+
+    let mut solution = encode::serialize(&to_sign.input[0].script_sig);
+    solution.extend(encode::serialize(&to_sign.input[0].witness));
+    Ok(solution)
+}
+
+/// Finds a nonce meeting the target of `header`.
+fn grind(header: &mut Header) -> anyhow::Result<()> {
+    let target = header.target();
+    let mut bytes = encode::serialize(&*header);
+    for nonce in 0..=u32::MAX {
+        bytes[76..].copy_from_slice(&nonce.to_le_bytes());
+        let hash = BlockHash::from_raw_hash(sha256d::Hash::hash(&bytes));
+        if target.is_met_by(hash) {
+            header.nonce = nonce;
+            return Ok(());
+        }
+    }
+    Err(anyhow::anyhow!("no nonce meets the target {:?}", target))
+}
+
+#[cfg(test)]
+mod tests {
+    use corepc_client::bitcoin::block::Version as BlockVersion;
+    use corepc_client::bitcoin::{CompactTarget, Network};
+
+    use super::*;
+
+    #[test]
+    fn signet_args_challenge() {
+        let key = PrivateKey::from_slice(&[1; 32], Network::Signet).unwrap();
+        let challenge = signet_challenge(&key.public_key(&Secp256k1::signing_only()));
+        assert_eq!(challenge.len(), 37);
+        assert!(signet_args(None).is_empty());
+        assert_eq!(
+            signet_args(Some(&challenge)),
+            vec![format!("-signetchallenge={}", challenge.to_hex_string())]
+        );
+    }
+
+    #[test]
+    fn grind_meets_target() {
+        let mut header = Header {
+            version: BlockVersion::TWO,
+            prev_blockhash: BlockHash::all_zeros(),
+            merkle_root: TxMerkleNode::all_zeros(),
+            time: 0,
+            // The regtest proof of work limit, any other nonce out of two works.
+            bits: CompactTarget::from_consensus(0x207fffff),
+            nonce: 0,
+        };
+        grind(&mut header).unwrap();
+        assert!(header.validate_pow(header.target()).is_ok());
+    }
+}
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! AssumeUTXO snapshots (Core v26 and later).
+
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Context;
+
+use crate::wait::{poll, Poll};
+use crate::{mtype, BitcoinD};
+
+impl BitcoinD {
+    /// Writes a UTXO snapshot of the current chain tip to `path` using `dumptxoutset`.
+    pub fn dump_utxo_snapshot(&self, path: &Path) -> anyhow::Result<mtype::DumpTxOutSet> {
+        let path = path.to_str().context("snapshot path is not valid UTF-8")?;
+        #[cfg(not(feature = "29_0"))]
+        let json = self.client.dump_tx_out_set(path)?;
+        #[cfg(feature = "29_0")]
+        let json = self.client.dump_tx_out_set(path, "latest")?;
+        Ok(json.into_model()?)
+    }
+
+    /// Loads the UTXO snapshot at `path` using `loadtxoutset`.
+    ///
+    /// The node must already have the headers up to the snapshot base block, and the snapshot
+    /// must match an assumeutxo entry in the chain params. Waits until `getchainstates` lists
+    /// the snapshot chainstate next to the background chainstate that validates it, returns a
+    /// [`WaitTimeout`](crate::WaitTimeout) if it does not in `timeout`.
+    pub fn load_utxo_snapshot(
+        &self,
+        path: &Path,
+        timeout: Duration,
+    ) -> anyhow::Result<mtype::LoadTxOutSet> {
+        let path = path.to_str().context("snapshot path is not valid UTF-8")?;
+        let loaded = self.client.load_tx_out_set(path)?.into_model()?;
+
+        poll("the background chainstate".to_string(), timeout, || {
+            let states = self.client.get_chain_states()?.chain_states.len();
+            Ok(if states > 1 {
+                Poll::Ready
+            } else {
+                Poll::Pending(format!("{} chainstate", states))
+            })
+        })?;
+        Ok(loaded)
+    }
+}
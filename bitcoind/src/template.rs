@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Nodes started from a copy of a prepared data directory, to share an expensive setup.
+
+use std::ffi::{OsStr, OsString};
+use std::fs;
+use std::path::Path;
+
+use tempfile::TempDir;
+
+use crate::{BitcoinD, Conf, DataDir};
+
+/// The files of a running node, not copied to the instances.
+const RUNTIME_FILES: [&str; 4] = [".cookie", ".lock", "bitcoind.pid", "debug.log"];
+
+/// A data directory prepared once, from which any number of nodes are started.
+///
+/// The setup (e.g., funding wallets or building indexes) runs on a node that is then stopped, its
+/// data directory is the image. Each instance runs in a temporary copy of the image, on its own
+/// ports and with its own cookie.
+///
+/// ```no_run
+/// # fn main() -> anyhow::Result<()> {
+/// use bitcoind::{Conf, NodeTemplate};
+///
+/// let template = NodeTemplate::build(bitcoind::exe_path()?, &Conf::default(), |node| {
+///     node.fund_node_wallet()?;
+///     Ok(())
+/// })?;
+/// let node = template.instantiate()?;
+/// assert_eq!(node.client.get_block_count()?.0, 101);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct NodeTemplate<'a> {
+    exe: OsString,
+    /// The configuration of the instances, without a static directory.
+    conf: Conf<'a>,
+    /// The data directory of the stopped node.
+    image: TempDir,
+}
+
+impl<'a> NodeTemplate<'a> {
+    /// Starts a node running `exe` with `conf`, runs `setup` on it and keeps its data directory
+    /// once stopped.
+    ///
+    /// The instances use `conf` too, except [`Conf::staticdir`] which is ignored.
+    pub fn build<S, F>(exe: S, conf: &Conf<'a>, setup: F) -> anyhow::Result<Self>
+    where
+        S: AsRef<OsStr>,
+        F: FnOnce(&BitcoinD) -> anyhow::Result<()>,
+    {
+        let mut conf = conf.clone();
+        conf.staticdir = None;
+        let image = TempDir::new()?;
+
+        let image_path = image.path().to_path_buf();
+        let mut node = BitcoinD::launch(&exe, &conf, |_| {
+            // A failed attempt may leave files behind.
+            fs::remove_dir_all(&image_path)?;
+            fs::create_dir_all(&image_path)?;
+            Ok(DataDir::Persistent(image_path.clone()))
+        })?;
+        setup(&node)?;
+        // A clean shutdown flushes the chain state and the wallets to disk.
+        let report = node.stop()?;
+        anyhow::ensure!(report.is_clean(), "template node did not shut down cleanly: {:?}", report);
+
+        Ok(NodeTemplate { exe: exe.as_ref().to_owned(), conf, image })
+    }
+
+    /// Starts a node in a fresh copy of the image.
+    pub fn instantiate(&self) -> anyhow::Result<BitcoinD> {
+        BitcoinD::launch(&self.exe, &self.conf, |conf| {
+            let work_dir = BitcoinD::init_work_dir(conf)?;
+            copy_dir(self.image.path(), &work_dir.path())?;
+            Ok(work_dir)
+        })
+    }
+
+    /// Returns the data directory the instances are copied from.
+    pub fn image(&self) -> &Path { self.image.path() }
+}
+
+/// Copies the content of the directory `from` into `to`, except the [`RUNTIME_FILES`].
+fn copy_dir(from: &Path, to: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if RUNTIME_FILES.iter().any(|file| name == OsStr::new(file)) {
+            continue;
+        }
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &to.join(&name))?;
+        } else {
+            fs::copy(entry.path(), to.join(&name))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copy_skips_runtime_files() {
+        let from = TempDir::new().unwrap();
+        let regtest = from.path().join("regtest");
+        fs::create_dir_all(regtest.join("wallets")).unwrap();
+        fs::write(regtest.join("wallets").join("wallet.dat"), b"wallet").unwrap();
+        fs::write(regtest.join(".cookie"), b"__cookie__:secret").unwrap();
+        fs::write(regtest.join("debug.log"), b"log").unwrap();
+        fs::write(from.path().join("bitcoin.conf"), b"").unwrap();
+
+        let to = TempDir::new().unwrap();
+        copy_dir(from.path(), to.path()).unwrap();
+        let regtest = to.path().join("regtest");
+        assert_eq!(fs::read(regtest.join("wallets").join("wallet.dat")).unwrap(), b"wallet");
+        assert!(to.path().join("bitcoin.conf").exists());
+        assert!(!regtest.join(".cookie").exists());
+        assert!(!regtest.join("debug.log").exists());
+    }
+}
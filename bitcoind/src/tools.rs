@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The command line tools shipped with Bitcoin Core alongside `bitcoind`.
+
+use std::fmt;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::Context;
+
+use crate::{BitcoinD, Error};
+
+/// Args selecting the chain, passed on from the node launch args to the tools.
+const CHAIN_ARGS: &[&str] = &["-regtest", "-testnet", "-testnet4", "-signet", "-chain="];
+
+/// A command line tool from the Bitcoin Core release.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tool {
+    /// `bitcoin-wallet`, offline wallet inspection and repair.
+    Wallet,
+    /// `bitcoin-tx`, raw transaction creation and editing.
+    Tx,
+    /// `bitcoin-util`, miscellaneous utilities (v22 and later).
+    Util,
+}
+
+impl Tool {
+    /// Returns the name of the executable e.g., `bitcoin-wallet`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Tool::Wallet => "bitcoin-wallet",
+            Tool::Tx => "bitcoin-tx",
+            Tool::Util => "bitcoin-util",
+        }
+    }
+
+    /// Returns the env var used to provide the executable path e.g., `BITCOIN_WALLET_EXE`.
+    pub fn env_var(self) -> &'static str {
+        match self {
+            Tool::Wallet => "BITCOIN_WALLET_EXE",
+            Tool::Tx => "BITCOIN_TX_EXE",
+            Tool::Util => "BITCOIN_UTIL_EXE",
+        }
+    }
+
+    /// Returns the file name of the executable, including the extension on Windows.
+    fn file_name(self) -> String {
+        if cfg!(target_os = "windows") {
+            format!("{}.exe", self.name())
+        } else {
+            self.name().to_string()
+        }
+    }
+}
+
+impl fmt::Display for Tool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str(self.name()) }
+}
+
+/// Returns the path of the `tool` executable with the following precedence:
+///
+/// 1) If it's specified in the tool env var, see [`Tool::env_var`].
+/// 2) If the auto-download feature is enabled, the path of the downloaded executable.
+/// 3) If `BITCOIND_EXE` is set and the tool is in the same directory, that path.
+/// 4) If neither of the precedent are available, the executable is searched in the `PATH`.
+pub fn exe_path_for(tool: Tool) -> anyhow::Result<String> {
+    if let Ok(path) = std::env::var(tool.env_var()) {
+        return Ok(path);
+    }
+    #[cfg(feature = "download")]
+    if let Ok(path) = crate::downloaded_bin_path(&tool.file_name()) {
+        return Ok(path);
+    }
+    if let Ok(bitcoind) = std::env::var("BITCOIND_EXE") {
+        let path = Path::new(&bitcoind).with_file_name(tool.file_name());
+        if path.exists() {
+            return Ok(path.display().to_string());
+        }
+    }
+    which::which(tool.name())
+        .map_err(|_| Error::NoToolExecutableFound(tool).into())
+        .map(|p| p.display().to_string())
+}
+
+impl BitcoinD {
+    /// Runs `bitcoin-wallet <command>` against this node's data directory, returns its stdout.
+    ///
+    /// The data directory and chain args are added, pass any other options in `args` e.g.,
+    /// `["-wallet=default", "-dumpfile=/tmp/dump"]`. Unload the wallet from the node first,
+    /// `bitcoin-wallet` cannot open a wallet that is in use.
+    pub fn wallet_tool(&self, command: &str, args: &[&str]) -> anyhow::Result<String> {
+        let exe = exe_path_for(Tool::Wallet)?;
+        let datadir = format!("-datadir={}", self.work_dir.path().display());
+        let chain =
+            self.args.iter().filter(|arg| CHAIN_ARGS.iter().any(|chain| arg.starts_with(chain)));
+
+        let output = Command::new(&exe)
+            .arg(datadir)
+            .args(chain)
+            .args(args)
+            .arg(command)
+            .output()
+            .with_context(|| format!("Error while executing {:?}", exe))?;
+        if !output.status.success() {
+            return Err(anyhow::anyhow!(
+                "{} {} failed with {}: {}",
+                Tool::Wallet,
+                command,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+}
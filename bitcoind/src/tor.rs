@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Running a node behind a proxy or as a Tor onion service.
+
+use std::fmt;
+use std::net::SocketAddr;
+
+use crate::{BitcoinD, Conf, P2P};
+
+/// A network outbound connections can be restricted to, see [`Conf::onlynet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum OnlyNet {
+    /// IPv4.
+    Ipv4,
+    /// IPv6.
+    Ipv6,
+    /// Tor onion services.
+    Onion,
+    /// I2P, supported since Core v22.
+    I2p,
+    /// CJDNS, supported since Core v23.
+    Cjdns,
+}
+
+impl fmt::Display for OnlyNet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            OnlyNet::Ipv4 => "ipv4",
+            OnlyNet::Ipv6 => "ipv6",
+            OnlyNet::Onion => "onion",
+            OnlyNet::I2p => "i2p",
+            OnlyNet::Cjdns => "cjdns",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Returns the proxy, `-onlynet` and Tor control args for `conf`.
+pub(crate) fn tor_args(conf: &Conf) -> Vec<String> {
+    let mut args = vec![];
+    if let Some(proxy) = conf.proxy {
+        args.push(format!("-proxy={}", proxy));
+    }
+    if let Some(proxy) = conf.onion_proxy {
+        args.push(format!("-onion={}", proxy));
+    }
+    for net in &conf.onlynet {
+        args.push(format!("-onlynet={}", net));
+    }
+    if let Some(control) = conf.tor_control {
+        args.push(format!("-torcontrol={}", control));
+        args.push("-listenonion=1".to_string());
+        if let Some(ref password) = conf.tor_password {
+            args.push(format!("-torpassword={}", password));
+        }
+    }
+    if let Some(bind) = conf.onion_bind {
+        args.push(format!("-bind={}=onion", bind));
+    }
+    args
+}
+
+impl Conf<'_> {
+    /// Configures the node to only connect through the Tor SOCKS5 proxy at `socks` and to
+    /// publish an onion service using the Tor control port at `control`.
+    ///
+    /// Tor must already be running, authentication with the control port uses the Tor cookie file
+    /// unless [`Conf::tor_password`] is set. The node listens for p2p connections, which is
+    /// required to accept connections on the onion service.
+    pub fn tor_only(&mut self, socks: SocketAddr, control: SocketAddr) -> &mut Self {
+        self.proxy = Some(socks);
+        self.onlynet = vec![OnlyNet::Onion];
+        self.tor_control = Some(control);
+        if self.p2p == P2P::No {
+            self.p2p = P2P::Yes;
+        }
+        self
+    }
+}
+
+impl BitcoinD {
+    /// Returns the onion address of the node, `None` if the onion service is not published yet.
+    ///
+    /// The node must have been started with [`Conf::tor_control`] set, Tor publishes the service
+    /// shortly after the node starts.
+    pub fn onion_address(&self) -> anyhow::Result<Option<String>> {
+        let info = self.client.call::<serde_json::Value>("getnetworkinfo", &[])?;
+        let address = info["localaddresses"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|local| local["address"].as_str())
+            .find(|address| address.ends_with(".onion"));
+        Ok(address.map(ToOwned::to_owned))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tor_args_default() {
+        assert!(tor_args(&Conf::default()).is_empty());
+    }
+
+    #[test]
+    fn tor_args_tor_only() {
+        let socks = "127.0.0.1:9050".parse().unwrap();
+        let control = "127.0.0.1:9051".parse().unwrap();
+        let mut conf = Conf::default();
+        conf.tor_only(socks, control).tor_password = Some("secret".to_string());
+
+        assert_eq!(conf.p2p, P2P::Yes);
+        assert_eq!(
+            tor_args(&conf),
+            vec![
+                "-proxy=127.0.0.1:9050".to_string(),
+                "-onlynet=onion".to_string(),
+                "-torcontrol=127.0.0.1:9051".to_string(),
+                "-listenonion=1".to_string(),
+                "-torpassword=secret".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn tor_args_onion_proxy_and_bind() {
+        let conf = Conf {
+            onion_proxy: Some("127.0.0.1:9050".parse().unwrap()),
+            onion_bind: Some("127.0.0.1:18445".parse().unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(
+            tor_args(&conf),
+            vec!["-onion=127.0.0.1:9050".to_string(), "-bind=127.0.0.1:18445=onion".to_string()]
+        );
+    }
+
+    #[test]
+    fn tor_args_proxy_onlynet() {
+        let conf = Conf {
+            proxy: Some("[::1]:1080".parse().unwrap()),
+            onlynet: vec![OnlyNet::Ipv4, OnlyNet::I2p],
+            ..Default::default()
+        };
+        assert_eq!(
+            tor_args(&conf),
+            vec![
+                "-proxy=[::1]:1080".to_string(),
+                "-onlynet=ipv4".to_string(),
+                "-onlynet=i2p".to_string(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,372 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Reading the UTXO snapshots written by `dumptxoutset`, without a node.
+//!
+//! [`read`] returns an iterator over the coins of a snapshot, e.g. to analyse the UTXO set or to
+//! check a snapshot before loading it with `loadtxoutset`. Both the format of Core v28 and later
+//! and the format of v26 and v27 (no magic bytes, one outpoint per coin) are supported.
+//!
+//! ```no_run
+//! # fn main() -> anyhow::Result<()> {
+//! let mut snapshot = bitcoind::utxo_snapshot::read("utxo.dat".as_ref())?;
+//! println!("{} coins at {}", snapshot.metadata().coins_count, snapshot.metadata().base_block_hash);
+//! for entry in &mut snapshot {
+//!     let entry = entry?;
+//!     println!("{} {}", entry.outpoint, entry.txout.value);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::Path;
+
+use corepc_client::bitcoin::consensus::{encode, Decodable};
+use corepc_client::bitcoin::hashes::Hash;
+use corepc_client::bitcoin::io::from_std_mut;
+use corepc_client::bitcoin::opcodes::all::{OP_CHECKSIG, OP_RETURN};
+use corepc_client::bitcoin::p2p::Magic;
+use corepc_client::bitcoin::script::Builder;
+use corepc_client::bitcoin::{
+    secp256k1, Amount, BlockHash, OutPoint, PubkeyHash, ScriptBuf, ScriptHash, TxOut, Txid, VarInt,
+};
+
+/// The magic bytes starting the snapshots of Core v28 and later.
+const SNAPSHOT_MAGIC: [u8; 5] = [b'u', b't', b'x', b'o', 0xff];
+
+/// The snapshot format version of Core v28 and later, the only one with magic bytes.
+const SNAPSHOT_VERSION: u16 = 2;
+
+/// The number of special script encodings in the compressed script format.
+const SPECIAL_SCRIPTS: u64 = 6;
+
+/// Scripts longer than this are stored by Core but replaced with `OP_RETURN` when read.
+const MAX_SCRIPT_SIZE: u64 = 10_000;
+
+/// The header of a snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotMetadata {
+    /// The network magic of the chain the snapshot is from, `None` for snapshots of Core v26 and
+    /// v27.
+    pub network_magic: Option<Magic>,
+    /// The hash of the block the snapshot was taken at.
+    pub base_block_hash: BlockHash,
+    /// The number of coins in the snapshot.
+    pub coins_count: u64,
+}
+
+/// An unspent output read from a snapshot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UtxoEntry {
+    /// The output spent by a transaction spending this coin.
+    pub outpoint: OutPoint,
+    /// The amount and script of the output.
+    pub txout: TxOut,
+    /// The height of the block that created the output.
+    pub height: u32,
+    /// The output was created by a coinbase transaction.
+    pub is_coinbase: bool,
+}
+
+/// An iterator over the coins of a snapshot, see [`read`].
+///
+/// Stops after the number of coins in the metadata, or at the first error.
+#[derive(Debug)]
+pub struct UtxoSnapshot<R> {
+    reader: R,
+    metadata: SnapshotMetadata,
+    /// Whether the coins are grouped by transaction, as in the snapshots of Core v28 and later.
+    grouped: bool,
+    /// The number of coins left to read.
+    remaining: u64,
+    /// The transaction of the current group and the number of its coins left to read.
+    group: Option<(Txid, u64)>,
+}
+
+/// Opens the snapshot written by `dumptxoutset` at `path` and reads its metadata.
+pub fn read(path: &Path) -> anyhow::Result<UtxoSnapshot<BufReader<File>>> {
+    UtxoSnapshot::new(BufReader::new(File::open(path)?))
+}
+
+impl<R: BufRead> UtxoSnapshot<R> {
+    /// Reads the metadata of the snapshot from `reader`, positioned at the start of the snapshot.
+    pub fn new(mut reader: R) -> anyhow::Result<Self> {
+        let mut start = [0; 5];
+        reader.read_exact(&mut start)?;
+        let (network_magic, base_block_hash) = if start == SNAPSHOT_MAGIC {
+            let version: u16 = decode(&mut reader)?;
+            anyhow::ensure!(
+                version == SNAPSHOT_VERSION,
+                "unsupported snapshot version {}",
+                version
+            );
+            let magic: Magic = decode(&mut reader)?;
+            (Some(magic), decode(&mut reader)?)
+        } else {
+            // Without magic bytes the snapshot starts with the base block hash.
+            let mut hash = [0; 32];
+            hash[..5].copy_from_slice(&start);
+            reader.read_exact(&mut hash[5..])?;
+            (None, BlockHash::from_byte_array(hash))
+        };
+        let coins_count: u64 = decode(&mut reader)?;
+        Ok(UtxoSnapshot {
+            reader,
+            metadata: SnapshotMetadata { network_magic, base_block_hash, coins_count },
+            grouped: network_magic.is_some(),
+            remaining: coins_count,
+            group: None,
+        })
+    }
+
+    /// Returns the metadata of the snapshot.
+    pub fn metadata(&self) -> &SnapshotMetadata { &self.metadata }
+
+    /// Reads the outpoint of the next coin.
+    fn read_outpoint(&mut self) -> anyhow::Result<OutPoint> {
+        if !self.grouped {
+            return Ok(decode(&mut self.reader)?);
+        }
+        let (txid, left) = match self.group {
+            Some((txid, left)) if left > 0 => (txid, left),
+            _ => {
+                let txid: Txid = decode(&mut self.reader)?;
+                let count = decode::<VarInt, _>(&mut self.reader)?.0;
+                anyhow::ensure!(count > 0, "empty group of coins for {}", txid);
+                (txid, count)
+            }
+        };
+        self.group = Some((txid, left - 1));
+        let vout = decode::<VarInt, _>(&mut self.reader)?.0;
+        let vout = u32::try_from(vout)
+            .map_err(|_| anyhow::anyhow!("output index {} of {} out of range", vout, txid))?;
+        Ok(OutPoint::new(txid, vout))
+    }
+
+    /// Reads the next coin.
+    fn read_entry(&mut self) -> anyhow::Result<UtxoEntry> {
+        let outpoint = self.read_outpoint()?;
+        let code = read_varint(&mut self.reader)?;
+        let height = u32::try_from(code >> 1)
+            .map_err(|_| anyhow::anyhow!("height of {} out of range", outpoint))?;
+        let value = Amount::from_sat(decompress_amount(read_varint(&mut self.reader)?));
+        let script_pubkey = read_script(&mut self.reader)?;
+        Ok(UtxoEntry {
+            outpoint,
+            txout: TxOut { value, script_pubkey },
+            height,
+            is_coinbase: code & 1 == 1,
+        })
+    }
+}
+
+impl<R: BufRead> Iterator for UtxoSnapshot<R> {
+    type Item = anyhow::Result<UtxoEntry>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let entry = self.read_entry();
+        // Nothing can be read after an error, the position in the snapshot is unknown.
+        self.remaining = if entry.is_ok() { self.remaining - 1 } else { 0 };
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { (0, usize::try_from(self.remaining).ok()) }
+}
+
+/// Decodes a `T` from `reader`, rust-bitcoin has its own IO traits.
+pub(crate) fn decode<T: Decodable, R: BufRead>(reader: &mut R) -> Result<T, encode::Error> {
+    T::consensus_decode(from_std_mut(reader))
+}
+
+/// Reads a number in Core's `VARINT` format, base 128 with an offset on each continuation byte.
+fn read_varint<R: Read>(reader: &mut R) -> anyhow::Result<u64> {
+    let mut n: u64 = 0;
+    loop {
+        let mut byte = [0];
+        reader.read_exact(&mut byte)?;
+        anyhow::ensure!(n <= u64::MAX >> 7, "VARINT out of range");
+        n = (n << 7) | u64::from(byte[0] & 0x7f);
+        if byte[0] & 0x80 == 0 {
+            return Ok(n);
+        }
+        n = n.checked_add(1).ok_or_else(|| anyhow::anyhow!("VARINT out of range"))?;
+    }
+}
+
+/// Returns the amount in satoshis compressed with Core's `CompressAmount`.
+fn decompress_amount(x: u64) -> u64 {
+    if x == 0 {
+        return 0;
+    }
+    let mut x = x - 1;
+    // The exponent of the trailing zeros, then the last non-zero digit if the exponent is below 9.
+    let e = x % 10;
+    x /= 10;
+    let mut n = if e < 9 {
+        let d = x % 9 + 1;
+        x /= 9;
+        x.saturating_mul(10).saturating_add(d)
+    } else {
+        x.saturating_add(1)
+    };
+    for _ in 0..e {
+        n = n.saturating_mul(10);
+    }
+    n
+}
+
+/// Reads a script compressed with Core's `ScriptCompression`.
+fn read_script<R: Read>(reader: &mut R) -> anyhow::Result<ScriptBuf> {
+    let size = read_varint(reader)?;
+    match size {
+        0 => Ok(ScriptBuf::new_p2pkh(&PubkeyHash::from_byte_array(read_array(reader)?))),
+        1 => Ok(ScriptBuf::new_p2sh(&ScriptHash::from_byte_array(read_array(reader)?))),
+        2 | 3 => {
+            let mut key = [0; 33];
+            key[0] = size as u8;
+            key[1..].copy_from_slice(&read_array::<_, 32>(reader)?);
+            Ok(Builder::new().push_slice(key).push_opcode(OP_CHECKSIG).into_script())
+        }
+        4 | 5 => {
+            // Uncompressed keys are stored compressed, the parity is in the size.
+            let mut key = [0; 33];
+            key[0] = size as u8 - 2;
+            key[1..].copy_from_slice(&read_array::<_, 32>(reader)?);
+            let key = secp256k1::PublicKey::from_slice(&key)?.serialize_uncompressed();
+            Ok(Builder::new().push_slice(key).push_opcode(OP_CHECKSIG).into_script())
+        }
+        _ => {
+            let len = size - SPECIAL_SCRIPTS;
+            if len > MAX_SCRIPT_SIZE {
+                // As done by Core, the script is unspendable anyway.
+                io::copy(&mut reader.by_ref().take(len), &mut io::sink())?;
+                return Ok(Builder::new().push_opcode(OP_RETURN).into_script());
+            }
+            let mut script = vec![0; len as usize];
+            reader.read_exact(&mut script)?;
+            Ok(ScriptBuf::from_bytes(script))
+        }
+    }
+}
+
+/// Reads `N` bytes.
+fn read_array<R: Read, const N: usize>(reader: &mut R) -> io::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use corepc_client::bitcoin::consensus::Encodable;
+    use corepc_client::bitcoin::Network;
+
+    use super::*;
+
+    /// Writes `n` in Core's `VARINT` format.
+    fn write_varint(mut n: u64, out: &mut Vec<u8>) {
+        let mut bytes = vec![(n & 0x7f) as u8];
+        while n > 0x7f {
+            n = (n >> 7) - 1;
+            bytes.push((n & 0x7f) as u8 | 0x80);
+        }
+        out.extend(bytes.iter().rev());
+    }
+
+    /// Writes a coin at `height` paying 50 BTC (compressed to 50) to the compressed `script`.
+    fn write_coin(height: u64, coinbase: bool, script: &[u8], out: &mut Vec<u8>) {
+        write_varint(height * 2 + u64::from(coinbase), out);
+        write_varint(50, out);
+        out.extend_from_slice(script);
+    }
+
+    fn p2pkh() -> Vec<u8> { [&[0][..], &[0xab; 20]].concat() }
+
+    fn raw_script() -> Vec<u8> {
+        let mut script = vec![];
+        write_varint(SPECIAL_SCRIPTS + 2, &mut script);
+        script.extend_from_slice(&[0x6a, 0x00]);
+        script
+    }
+
+    #[test]
+    fn varint_round_trip() {
+        for n in [0, 1, 127, 128, 255, 16_511, 16_512, u32::MAX.into(), u64::MAX] {
+            let mut bytes = vec![];
+            write_varint(n, &mut bytes);
+            assert_eq!(read_varint(&mut &bytes[..]).unwrap(), n);
+        }
+    }
+
+    #[test]
+    fn amounts() {
+        assert_eq!(decompress_amount(0), 0);
+        assert_eq!(decompress_amount(1), 1);
+        assert_eq!(decompress_amount(50), 5_000_000_000);
+        assert_eq!(decompress_amount(9), 100_000_000);
+    }
+
+    #[test]
+    fn read_grouped_snapshot() {
+        let base = BlockHash::from_byte_array([1; 32]);
+        let txid = Txid::from_byte_array([2; 32]);
+        let mut bytes = SNAPSHOT_MAGIC.to_vec();
+        SNAPSHOT_VERSION.consensus_encode(&mut bytes).unwrap();
+        Network::Regtest.magic().consensus_encode(&mut bytes).unwrap();
+        base.consensus_encode(&mut bytes).unwrap();
+        2u64.consensus_encode(&mut bytes).unwrap();
+        txid.consensus_encode(&mut bytes).unwrap();
+        VarInt(2).consensus_encode(&mut bytes).unwrap();
+        VarInt(0).consensus_encode(&mut bytes).unwrap();
+        write_coin(110, true, &p2pkh(), &mut bytes);
+        VarInt(3).consensus_encode(&mut bytes).unwrap();
+        write_coin(111, false, &raw_script(), &mut bytes);
+
+        let mut snapshot = UtxoSnapshot::new(&bytes[..]).unwrap();
+        assert_eq!(snapshot.metadata().network_magic, Some(Network::Regtest.magic()));
+        assert_eq!(snapshot.metadata().base_block_hash, base);
+        let entries = snapshot.by_ref().collect::<anyhow::Result<Vec<_>>>().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].outpoint, OutPoint::new(txid, 0));
+        assert_eq!(entries[0].height, 110);
+        assert!(entries[0].is_coinbase);
+        assert_eq!(entries[0].txout.value, Amount::from_int_btc(50));
+        assert!(entries[0].txout.script_pubkey.is_p2pkh());
+        assert_eq!(entries[1].outpoint, OutPoint::new(txid, 3));
+        assert!(!entries[1].is_coinbase);
+        assert_eq!(entries[1].txout.script_pubkey.as_bytes(), [0x6a, 0x00]);
+    }
+
+    #[test]
+    fn read_legacy_snapshot() {
+        let base = BlockHash::from_byte_array([1; 32]);
+        let outpoint = OutPoint::new(Txid::from_byte_array([2; 32]), 7);
+        let mut bytes = vec![];
+        base.consensus_encode(&mut bytes).unwrap();
+        1u64.consensus_encode(&mut bytes).unwrap();
+        outpoint.consensus_encode(&mut bytes).unwrap();
+        write_coin(5, false, &p2pkh(), &mut bytes);
+
+        let mut snapshot = UtxoSnapshot::new(&bytes[..]).unwrap();
+        assert_eq!(snapshot.metadata().network_magic, None);
+        assert_eq!(snapshot.metadata().base_block_hash, base);
+        let entry = snapshot.next().unwrap().unwrap();
+        assert_eq!(entry.outpoint, outpoint);
+        assert!(snapshot.next().is_none());
+    }
+
+    #[test]
+    fn truncated_snapshot_stops() {
+        let mut bytes = vec![];
+        BlockHash::from_byte_array([1; 32]).consensus_encode(&mut bytes).unwrap();
+        3u64.consensus_encode(&mut bytes).unwrap();
+
+        let mut snapshot = UtxoSnapshot::new(&bytes[..]).unwrap();
+        assert!(snapshot.next().unwrap().is_err());
+        assert!(snapshot.next().is_none());
+    }
+}
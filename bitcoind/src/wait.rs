@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Waiting for the node to reach a state, instead of sleeping in tests.
+
+use std::time::{Duration, Instant};
+use std::{fmt, thread};
+
+use corepc_client::bitcoin::Txid;
+use corepc_client::client_sync;
+
+use crate::BitcoinD;
+
+/// How often the node is polled while waiting.
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Returned when the node did not reach the expected state in time.
+///
+/// The helpers on [`BitcoinD`] return it inside an [`anyhow::Error`], use `downcast_ref` to get
+/// at it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WaitTimeout {
+    /// The state waited for e.g., `block height 101`.
+    pub condition: String,
+    /// How long was waited.
+    pub timeout: Duration,
+    /// What the node returned the last time it was polled, or the error it returned.
+    pub last_observed: String,
+}
+
+impl fmt::Display for WaitTimeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "timed out after {:?} waiting for {}, last observed: {}",
+            self.timeout, self.condition, self.last_observed
+        )
+    }
+}
+
+impl std::error::Error for WaitTimeout {}
+
+/// The result of polling the node once.
+pub(crate) enum Poll {
+    /// The node reached the expected state.
+    Ready,
+    /// The node is not there yet, with what was observed.
+    Pending(String),
+}
+
+/// Polls `check` until it returns [`Poll::Ready`] or `timeout` elapses.
+///
+/// Errors returned by `check` are not fatal, the node may be busy, they are reported as the last
+/// observed state if it times out.
+pub(crate) fn poll<F>(condition: String, timeout: Duration, mut check: F) -> Result<(), WaitTimeout>
+where
+    F: FnMut() -> Result<Poll, client_sync::Error>,
+{
+    let start = Instant::now();
+    loop {
+        let last_observed = match check() {
+            Ok(Poll::Ready) => return Ok(()),
+            Ok(Poll::Pending(observed)) => observed,
+            Err(e) => format!("error: {}", e),
+        };
+        let elapsed = start.elapsed();
+        if elapsed >= timeout {
+            return Err(WaitTimeout { condition, timeout, last_observed });
+        }
+        thread::sleep(POLL_INTERVAL.min(timeout - elapsed));
+    }
+}
+
+impl BitcoinD {
+    /// Waits until the node's chain is at least `height` blocks high.
+    ///
+    /// Returns a [`WaitTimeout`] including the last block count if it is not reached in `timeout`.
+    pub fn wait_for_block_height(&self, height: u64, timeout: Duration) -> anyhow::Result<()> {
+        poll(format!("block height {}", height), timeout, || {
+            let count = self.client.get_block_count()?.0;
+            Ok(if count >= height {
+                Poll::Ready
+            } else {
+                Poll::Pending(format!("height {}", count))
+            })
+        })?;
+        Ok(())
+    }
+
+    /// Waits until `txid` is in the node's mempool.
+    ///
+    /// Returns a [`WaitTimeout`] including the last mempool size if it is not there in `timeout`.
+    pub fn wait_for_tx_in_mempool(&self, txid: Txid, timeout: Duration) -> anyhow::Result<()> {
+        let txid = txid.to_string();
+        poll(format!("transaction {} in the mempool", txid), timeout, || {
+            let mempool = self.client.get_raw_mempool()?.0;
+            Ok(if mempool.contains(&txid) {
+                Poll::Ready
+            } else {
+                Poll::Pending(format!("{} transactions in the mempool", mempool.len()))
+            })
+        })?;
+        Ok(())
+    }
+
+    /// Waits until the node is connected to at least `n` peers.
+    ///
+    /// Returns a [`WaitTimeout`] including the last number of peers if it is not reached in
+    /// `timeout`.
+    pub fn wait_for_peers(&self, n: usize, timeout: Duration) -> anyhow::Result<()> {
+        poll(format!("{} peers", n), timeout, || {
+            let peers = self.client.get_peer_info()?.0.len();
+            Ok(if peers >= n { Poll::Ready } else { Poll::Pending(format!("{} peers", peers)) })
+        })?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn poll_ready() {
+        let mut calls = 0;
+        let result = poll("ready".to_string(), Duration::from_secs(5), || {
+            calls += 1;
+            Ok(if calls == 3 { Poll::Ready } else { Poll::Pending(calls.to_string()) })
+        });
+        assert!(result.is_ok());
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn poll_timeout_reports_last_observed() {
+        let mut calls = 0;
+        let err = poll("block height 10".to_string(), Duration::from_millis(250), || {
+            calls += 1;
+            Ok(Poll::Pending(format!("height {}", calls)))
+        })
+        .unwrap_err();
+
+        assert_eq!(err.condition, "block height 10");
+        assert_eq!(err.last_observed, format!("height {}", calls));
+        assert!(err.to_string().starts_with("timed out after 250ms waiting for block height 10"));
+    }
+}
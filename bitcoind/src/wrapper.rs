@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Running `bitcoind` under a wrapper command e.g., `valgrind`, `perf record` or `rr record`.
+//!
+//! The process spawned is then the wrapper, which runs the node either in the same process
+//! (`valgrind`) or in a child process (`perf`, `rr`). The pid of the node is read from the pid file
+//! Core writes to its network directory, before the cookie file.
+
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Child, Command};
+
+use crate::BitcoinD;
+
+/// The file Core writes its pid to, in the network directory.
+const PID_FILE: &str = "bitcoind.pid";
+
+/// Returns the command running `exe`, under `wrapper` unless it is `None` or empty.
+pub(crate) fn command(exe: &OsStr, wrapper: Option<&[String]>) -> Command {
+    match wrapper {
+        Some([program, args @ ..]) => {
+            let mut command = Command::new(program);
+            command.args(args).arg(exe);
+            command
+        }
+        _ => Command::new(exe),
+    }
+}
+
+/// Returns the pid file of a node in `net_dir`, `None` unless it runs under `wrapper`.
+pub(crate) fn pid_file(wrapper: Option<&[String]>, net_dir: &Path) -> Option<PathBuf> {
+    wrapper.filter(|wrapper| !wrapper.is_empty()).map(|_| net_dir.join(PID_FILE))
+}
+
+/// Returns the pid of the node run by `process`, read from `pid_file` if it is wrapped.
+///
+/// Falls back to the pid of `process` if the pid file cannot be read.
+pub(crate) fn node_pid(process: &Child, pid_file: Option<&Path>) -> u32 {
+    pid_file
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|pid| pid.trim().parse().ok())
+        .unwrap_or_else(|| process.id())
+}
+
+impl BitcoinD {
+    /// Returns the pid of the node.
+    ///
+    /// This is the pid of the process run by the wrapper if the node runs under
+    /// [`crate::Conf::wrapper`], which may differ from the pid of the process spawned.
+    pub fn pid(&self) -> u32 { self.pid }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrapper(command: &[&str]) -> Vec<String> {
+        command.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn wrapped_command() {
+        let exe = OsStr::new("/usr/bin/bitcoind");
+        let massif = wrapper(&["valgrind", "--tool=massif"]);
+        let command = command(exe, Some(&massif));
+        assert_eq!(command.get_program(), "valgrind");
+        assert_eq!(command.get_args().collect::<Vec<_>>(), ["--tool=massif", "/usr/bin/bitcoind"]);
+
+        assert_eq!(super::command(exe, None).get_program(), exe);
+        assert_eq!(super::command(exe, Some(&[])).get_program(), exe);
+    }
+
+    #[test]
+    fn pid_file_only_if_wrapped() {
+        let net_dir = Path::new("/data/regtest");
+        assert_eq!(pid_file(None, net_dir), None);
+        assert_eq!(pid_file(Some(&[]), net_dir), None);
+        let perf = wrapper(&["perf", "record"]);
+        assert_eq!(pid_file(Some(&perf), net_dir), Some(net_dir.join("bitcoind.pid")));
+    }
+}
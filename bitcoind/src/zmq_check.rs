@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Checking the ZMQ publishers of a node once it started, see [`crate::Conf::enable_zmq`].
+//!
+//! A node that does not publish on the allocated ports (e.g., built without ZMQ support, or with
+//! args overriding the publishers) otherwise only shows up as subscribers waiting forever.
+
+use std::net::SocketAddr;
+
+use crate::{Client, Error};
+
+/// Returns an error unless the node publishes raw transactions and blocks on the ports of
+/// `raw_tx` and `raw_block`, as advertised by `getzmqnotifications`.
+pub(crate) fn check_endpoints(
+    client: &Client,
+    raw_tx: Option<SocketAddr>,
+    raw_block: Option<SocketAddr>,
+) -> Result<(), Error> {
+    if raw_tx.is_none() && raw_block.is_none() {
+        return Ok(());
+    }
+    let advertised: serde_json::Value =
+        client.call("getzmqnotifications", &[]).map_err(Error::Rpc)?;
+    let expected = [("pubrawtx", raw_tx), ("pubrawblock", raw_block)];
+    match find_mismatch(&advertised, &expected) {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Returns the error for the first notification in `expected` not advertised on its port.
+fn find_mismatch(
+    advertised: &serde_json::Value,
+    expected: &[(&str, Option<SocketAddr>)],
+) -> Option<Error> {
+    let notifications = advertised.as_array().map(Vec::as_slice).unwrap_or_default();
+    for (kind, socket) in expected {
+        let socket = match socket {
+            Some(socket) => socket,
+            None => continue,
+        };
+        // The node binds all interfaces, only the port is compared.
+        let addresses: Vec<String> = notifications
+            .iter()
+            .filter(|notification| notification["type"] == *kind)
+            .filter_map(|notification| notification["address"].as_str().map(ToOwned::to_owned))
+            .collect();
+        if !addresses.iter().any(|address| port(address) == Some(socket.port())) {
+            return Some(Error::ZmqEndpointMismatch {
+                notification: kind.to_string(),
+                expected_port: socket.port(),
+                advertised: addresses,
+            });
+        }
+    }
+    None
+}
+
+/// Returns the port of a ZMQ `address` e.g., `tcp://0.0.0.0:28332`.
+fn port(address: &str) -> Option<u16> { address.rsplit(':').next()?.parse().ok() }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn mismatched_endpoints() {
+        let raw_tx: SocketAddr = "127.0.0.1:28332".parse().unwrap();
+        let raw_block: SocketAddr = "127.0.0.1:28333".parse().unwrap();
+        let expected = [("pubrawtx", Some(raw_tx)), ("pubrawblock", Some(raw_block))];
+
+        let advertised = json!([
+            { "type": "pubrawtx", "address": "tcp://0.0.0.0:28332", "hwm": 1000 },
+            { "type": "pubrawblock", "address": "tcp://0.0.0.0:28333", "hwm": 1000 },
+        ]);
+        assert!(find_mismatch(&advertised, &expected).is_none());
+        assert!(find_mismatch(&json!([]), &[("pubrawtx", None)]).is_none());
+
+        let advertised = json!([
+            { "type": "pubrawtx", "address": "tcp://0.0.0.0:28332" },
+            { "type": "pubrawblock", "address": "tcp://0.0.0.0:1" },
+        ]);
+        match find_mismatch(&advertised, &expected) {
+            Some(Error::ZmqEndpointMismatch { notification, expected_port, advertised }) => {
+                assert_eq!(notification, "pubrawblock");
+                assert_eq!(expected_port, 28333);
+                assert_eq!(advertised, ["tcp://0.0.0.0:1"]);
+            }
+            err => panic!("unexpected: {:?}", err),
+        }
+        assert!(find_mismatch(&json!([]), &expected).is_some());
+    }
+}
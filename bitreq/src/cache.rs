@@ -0,0 +1,212 @@
+//! Caching of responses using the `ETag` and `Last-Modified` validators.
+
+use alloc::collections::BTreeMap;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::{fs, io};
+
+use crate::Response;
+
+/// An opt-in cache of `GET` responses, keyed by URL.
+///
+/// Attach to a request with [`Request::with_cache`](crate::Request::with_cache). Responses that
+/// carry an `ETag` or `Last-Modified` header are stored, later requests for the same URL are sent
+/// with `If-None-Match`/`If-Modified-Since` and a `304 Not Modified` reply is answered with the
+/// stored response. Only [`Request::send`](crate::Request::send) and
+/// [`Request::send_async`](crate::Request::send_async) use the cache.
+///
+/// Clones share the same entries. A cache created with [`Cache::with_dir`] also keeps entries on
+/// disk so they survive restarts. Writing to disk is best effort, failures are ignored.
+#[derive(Clone, Debug, Default)]
+pub struct Cache {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    entries: Mutex<HashMap<String, Response>>,
+    dir: Option<PathBuf>,
+}
+
+impl Cache {
+    /// Creates an empty in-memory cache.
+    pub fn new() -> Self { Self::default() }
+
+    /// Creates a cache that also stores its entries as files in `dir`, creating it if needed.
+    pub fn with_dir<P: AsRef<Path>>(dir: P) -> io::Result<Self> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+        let inner = Inner { entries: Mutex::default(), dir: Some(dir) };
+        Ok(Cache { inner: Arc::new(inner) })
+    }
+
+    /// Removes all entries, including the ones stored on disk.
+    pub fn clear(&self) {
+        let mut entries = self.inner.entries.lock().expect("poisoned lock");
+        if let Some(dir) = &self.inner.dir {
+            if let Ok(files) = fs::read_dir(dir) {
+                for file in files.flatten() {
+                    if file.path().extension().is_some_and(|ext| ext == ENTRY_EXTENSION) {
+                        let _ = fs::remove_file(file.path());
+                    }
+                }
+            }
+        }
+        entries.clear();
+    }
+
+    /// Returns the stored response for `url`, if any.
+    pub fn get(&self, url: &str) -> Option<Response> {
+        let mut entries = self.inner.entries.lock().expect("poisoned lock");
+        if let Some(response) = entries.get(url) {
+            return Some(response.clone());
+        }
+        let dir = self.inner.dir.as_ref()?;
+        let response = fs::read(entry_path(dir, url)).ok().and_then(|f| decode(url, &f))?;
+        entries.insert(url.to_string(), response.clone());
+        Some(response)
+    }
+
+    /// Stores `response` for `url`.
+    fn insert(&self, url: &str, response: &Response) {
+        if let Some(dir) = &self.inner.dir {
+            let _ = fs::write(entry_path(dir, url), encode(url, response));
+        }
+        let mut entries = self.inner.entries.lock().expect("poisoned lock");
+        entries.insert(url.to_string(), response.clone());
+    }
+}
+
+impl PartialEq for Cache {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.inner, &other.inner) }
+}
+
+impl Eq for Cache {}
+
+/// A request that may be answered from the cache.
+pub(crate) struct Lookup {
+    cache: Cache,
+    url: String,
+    cached: Option<Response>,
+}
+
+impl Lookup {
+    /// Looks up `url`, returns the headers to add to the request to revalidate the cached entry.
+    pub(crate) fn new(cache: Cache, url: &str) -> (Lookup, Vec<(&'static str, String)>) {
+        let cached = cache.get(url);
+        let mut headers = Vec::new();
+        if let Some(cached) = &cached {
+            if let Some(etag) = cached.headers.get("etag") {
+                headers.push(("If-None-Match", etag.clone()));
+            }
+            if let Some(last_modified) = cached.headers.get("last-modified") {
+                headers.push(("If-Modified-Since", last_modified.clone()));
+            }
+        }
+        (Lookup { cache, url: url.to_string(), cached }, headers)
+    }
+
+    /// Returns the cached response if `response` says it is still valid, stores `response` if it
+    /// carries validators.
+    pub(crate) fn finish(self, response: Response) -> Response {
+        match (response.status_code, self.cached) {
+            (304, Some(cached)) => cached,
+            (200, _) => {
+                if has_validators(&response) {
+                    self.cache.insert(&self.url, &response);
+                }
+                response
+            }
+            _ => response,
+        }
+    }
+}
+
+fn has_validators(response: &Response) -> bool {
+    response.headers.contains_key("etag") || response.headers.contains_key("last-modified")
+}
+
+/// Extension of the files holding cache entries.
+const ENTRY_EXTENSION: &str = "bitreq-cache";
+
+/// The file an entry is stored in, named by a hash of the URL.
+fn entry_path(dir: &Path, url: &str) -> PathBuf {
+    // FNV-1a, the file names must not change between builds so we don't use `DefaultHasher`.
+    let hash = url.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    dir.join(format!("{:016x}.{}", hash, ENTRY_EXTENSION))
+}
+
+/// Serializes an entry as the URL, status line, response URL and headers, one per line, followed
+/// by an empty line and the body.
+fn encode(url: &str, response: &Response) -> Vec<u8> {
+    let mut file =
+        format!("{}\n{} {}\n{}\n", url, response.status_code, response.reason_phrase, response.url);
+    for (name, value) in &response.headers {
+        file.push_str(&format!("{}: {}\n", name, value));
+    }
+    file.push('\n');
+    let mut file = file.into_bytes();
+    file.extend_from_slice(response.as_bytes());
+    file
+}
+
+/// Parses an entry written by [`encode`], returns `None` if it is invalid or for another URL.
+fn decode(url: &str, file: &[u8]) -> Option<Response> {
+    let split = file.windows(2).position(|window| window == b"\n\n")?;
+    let head = core::str::from_utf8(&file[..split]).ok()?;
+    let body = file[split + 2..].to_vec();
+
+    let mut lines = head.split('\n');
+    if lines.next()? != url {
+        return None;
+    }
+    let (status_code, reason_phrase) = lines.next()?.split_once(' ')?;
+    let response_url = lines.next()?;
+    let mut headers = BTreeMap::new();
+    for line in lines {
+        let (name, value) = line.split_once(": ")?;
+        headers.insert(name.to_string(), value.to_string());
+    }
+    Some(Response::from_parts(
+        status_code.parse().ok()?,
+        reason_phrase.to_string(),
+        headers,
+        response_url.to_string(),
+        body,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(headers: &[(&str, &str)]) -> Response {
+        let headers = headers.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+        Response::from_parts(200, "OK".into(), headers, "http://a.b/c".into(), b"\n\nbody".to_vec())
+    }
+
+    #[test]
+    fn encode_decode_roundtrip() {
+        let response = response(&[("etag", "\"abc\""), ("content-length", "6")]);
+        let file = encode("http://a.b/c", &response);
+        assert_eq!(decode("http://a.b/c", &file), Some(response));
+        assert_eq!(decode("http://a.b/d", &file), None);
+    }
+
+    #[test]
+    fn stores_only_responses_with_validators() {
+        let cache = Cache::new();
+        let (lookup, headers) = Lookup::new(cache.clone(), "http://a.b/c");
+        assert!(headers.is_empty());
+        lookup.finish(response(&[]));
+        assert_eq!(cache.get("http://a.b/c"), None);
+
+        let (lookup, _) = Lookup::new(cache.clone(), "http://a.b/c");
+        lookup.finish(response(&[("etag", "\"abc\"")]));
+        let (_, headers) = Lookup::new(cache.clone(), "http://a.b/c");
+        assert_eq!(headers, vec![("If-None-Match", "\"abc\"".to_string())]);
+    }
+}
@@ -0,0 +1,107 @@
+//! Cancellation of in-flight requests.
+
+use std::net::{Shutdown, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::Error;
+
+/// A handle used to abort in-flight requests from another thread or task.
+///
+/// Attach a clone of the token to one or more requests with
+/// [`Request::with_cancellation`](crate::Request::with_cancellation) then call
+/// [`cancel`](CancellationToken::cancel) to abort them. Blocking requests are aborted by shutting
+/// down their socket, async requests are dropped at their next await point. Aborted requests
+/// return [`Error::Cancelled`].
+///
+/// Once cancelled a token stays cancelled, requests sent with it fail immediately. A token keeps a
+/// handle to the socket of each blocking request it was used for until it is cancelled or dropped
+/// so prefer a new token per request over one long-lived token.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    /// Clones of the sockets used by blocking requests, shut down on cancellation.
+    sockets: Mutex<Vec<TcpStream>>,
+    #[cfg(feature = "async")]
+    notify: tokio::sync::Notify,
+}
+
+impl CancellationToken {
+    /// Creates a new, not cancelled, token.
+    pub fn new() -> Self { Self::default() }
+
+    /// Aborts all requests using this token.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        for socket in self.inner.sockets.lock().expect("poisoned lock").drain(..) {
+            let _ = socket.shutdown(Shutdown::Both);
+        }
+        #[cfg(feature = "async")]
+        self.inner.notify.notify_waiters();
+    }
+
+    /// Returns `true` if [`cancel`](CancellationToken::cancel) has been called.
+    pub fn is_cancelled(&self) -> bool { self.inner.cancelled.load(Ordering::SeqCst) }
+
+    /// Registers the socket of a blocking request so that it is shut down on cancellation.
+    pub(crate) fn register(&self, socket: &TcpStream) -> Result<(), Error> {
+        let mut sockets = self.inner.sockets.lock().expect("poisoned lock");
+        // Checked while holding the lock so we can't miss a concurrent `cancel`.
+        if self.is_cancelled() {
+            return Err(Error::Cancelled);
+        }
+        sockets.push(socket.try_clone()?);
+        Ok(())
+    }
+
+    /// Completes once the token is cancelled.
+    #[cfg(feature = "async")]
+    pub(crate) async fn cancelled(&self) {
+        loop {
+            // A `Notified` receives `notify_waiters` wakeups from the moment it is created.
+            let notified = self.inner.notify.notified();
+            if self.is_cancelled() {
+                return;
+            }
+            notified.await;
+        }
+    }
+}
+
+/// Runs `request`, completing early with [`Error::Cancelled`] if `token` is cancelled first.
+#[cfg(feature = "async")]
+pub(crate) async fn cancellable<F, T>(
+    token: Option<CancellationToken>,
+    request: F,
+) -> Result<T, Error>
+where
+    F: core::future::Future<Output = Result<T, Error>>,
+{
+    use core::future::Future as _;
+    use core::task::Poll;
+
+    let token = match token {
+        Some(token) => token,
+        None => return request.await,
+    };
+    let mut request = Box::pin(request);
+    let mut cancelled = Box::pin(token.cancelled());
+    core::future::poll_fn(|cx| {
+        if cancelled.as_mut().poll(cx).is_ready() {
+            return Poll::Ready(Err(Error::Cancelled));
+        }
+        request.as_mut().poll(cx)
+    })
+    .await
+}
+
+impl PartialEq for CancellationToken {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.inner, &other.inner) }
+}
+
+impl Eq for CancellationToken {}
@@ -2,25 +2,41 @@
 //!
 //! The `Client` caches connections to avoid repeated TCP handshakes and TLS negotiations.
 //!
-//! Due to std limitations, `Client` currently only supports async requests.
+//! Blocking requests only reuse plain HTTP connections, the TLS session of an HTTPS connection
+//! cannot be taken back from the response that reads it.
 
-#![cfg(feature = "async")]
-
-use std::collections::{hash_map, HashMap, VecDeque};
+use std::collections::VecDeque;
+#[cfg(feature = "async")]
+use std::collections::{hash_map, HashMap};
+use std::net::TcpStream;
 use std::sync::{Arc, Mutex};
+use std::{fmt, io};
 
+#[cfg(feature = "async")]
 use crate::connection::AsyncConnection;
-use crate::request::{OwnedConnectionParams as ConnectionKey, ParsedRequest};
-use crate::{Error, Request, Response};
+use crate::connection::Connection;
+#[cfg(feature = "async")]
+use crate::request::finish_lookup;
+use crate::request::{map_cancelled, OwnedConnectionParams as ConnectionKey, ParsedRequest};
+use crate::{Error, RateLimit, Request, Response};
 
 /// A client that caches connections for reuse.
 ///
 /// The client maintains a pool of up to `capacity` connections, evicting
-/// the least recently used connection when the cache is full.
+/// the least recently used connection when the cache is full. Blocking requests sent with
+/// [`Client::send`] keep up to `capacity` idle connections open, separately from async requests.
 ///
 /// # Example
 ///
 /// ```no_run
+/// use bitreq::{Client, RequestExt};
+///
+/// let client = Client::new(10); // Cache up to 10 connections
+/// let response = bitreq::get("http://example.com").send_with_client(&client);
+/// ```
+///
+/// ```no_run
+/// # #[cfg(feature = "async")]
 /// # async fn request() {
 /// use bitreq::{Client, RequestExt};
 ///
@@ -32,15 +48,35 @@ use crate::{Error, Request, Response};
 /// ```
 #[derive(Clone)]
 pub struct Client {
+    #[cfg(feature = "async")]
     r#async: Arc<Mutex<ClientImpl<AsyncConnection>>>,
+    sync: Arc<Mutex<SyncPool>>,
+    read_rate_limit: Option<RateLimit>,
+    write_rate_limit: Option<RateLimit>,
+}
+
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("read_rate_limit", &self.read_rate_limit)
+            .field("write_rate_limit", &self.write_rate_limit)
+            .finish_non_exhaustive()
+    }
 }
 
+#[cfg(feature = "async")]
 struct ClientImpl<T> {
     connections: HashMap<ConnectionKey, Arc<T>>,
     lru_order: VecDeque<ConnectionKey>,
     capacity: usize,
 }
 
+/// The idle connections of the blocking requests, the least recently used first.
+struct SyncPool {
+    idle: VecDeque<(ConnectionKey, TcpStream)>,
+    capacity: usize,
+}
+
 impl Client {
     /// Creates a new `Client` with the specified connection cache capacity.
     ///
@@ -50,17 +86,111 @@ impl Client {
     ///   reached, the least recently used connection is evicted.
     pub fn new(capacity: usize) -> Self {
         Client {
+            #[cfg(feature = "async")]
             r#async: Arc::new(Mutex::new(ClientImpl {
                 connections: HashMap::new(),
                 lru_order: VecDeque::new(),
                 capacity,
             })),
+            sync: Arc::new(Mutex::new(SyncPool { idle: VecDeque::new(), capacity })),
+            read_rate_limit: None,
+            write_rate_limit: None,
+        }
+    }
+
+    /// Limits how fast responses are read, shared by all requests sent with this client.
+    ///
+    /// Requests with their own read limit use that instead.
+    pub fn with_read_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.read_rate_limit = Some(limit);
+        self
+    }
+
+    /// Limits how fast requests are written, shared by all requests sent with this client.
+    ///
+    /// Requests with their own write limit use that instead.
+    pub fn with_write_rate_limit(mut self, limit: RateLimit) -> Self {
+        self.write_rate_limit = Some(limit);
+        self
+    }
+
+    /// Applies the rate limits of the client to `request`, unless it has its own.
+    fn apply_rate_limits(&self, request: &mut Request) {
+        if request.read_rate_limit.is_none() {
+            request.read_rate_limit = self.read_rate_limit.clone();
+        }
+        if request.write_rate_limit.is_none() {
+            request.write_rate_limit = self.write_rate_limit.clone();
         }
     }
 
+    /// Sends a request using an idle connection to the server if available, keeping the
+    /// connection open afterwards if the server allows it.
+    ///
+    /// A connection closed by the server while idle is noticed before sending, and the request is
+    /// sent on a new connection instead. HTTPS requests always use a new connection.
+    pub fn send(&self, mut request: Request) -> Result<Response, Error> {
+        self.apply_rate_limits(&mut request);
+        let cancellation = request.cancellation.clone();
+        let result = ParsedRequest::new(request).and_then(|request| self.send_inner(request));
+        map_cancelled(result, cancellation.as_ref())
+    }
+
+    fn send_inner(&self, request: ParsedRequest) -> Result<Response, Error> {
+        let params = request.connection_params();
+        if params.https {
+            let connection = Connection::new(params, request.timeout_at)?;
+            return request.send_with(connection);
+        }
+        let key = ConnectionKey::from(params);
+        let socket = match self.take_idle(&key) {
+            Some(socket) => socket,
+            None => Connection::connect(params, request.timeout_at)?,
+        };
+
+        // The URL of the response if it is not redirected.
+        let mut url = String::new();
+        request.url.write_base_url_to(&mut url).unwrap();
+        request.url.write_resource_to(&mut url).unwrap();
+
+        // The response consumes the stream it reads, give it a second handle to the socket.
+        let connection = Connection::from_socket(socket.try_clone()?, request.timeout_at);
+        let response = request.send_with(connection)?;
+        // A redirect is followed on a new connection, leaving its body unread on this one.
+        if response.url == url && keeps_alive(&response) {
+            let mut pool = self.sync.lock().unwrap();
+            pool.idle.push_back((key, socket));
+            if pool.idle.len() > pool.capacity {
+                pool.idle.pop_front();
+            }
+        }
+        Ok(response)
+    }
+
+    /// Takes the most recently used idle connection for `key` still open.
+    fn take_idle(&self, key: &ConnectionKey) -> Option<TcpStream> {
+        let mut pool = self.sync.lock().unwrap();
+        while let Some(i) = pool.idle.iter().rposition(|(k, _)| k == key) {
+            let (_, socket) = pool.idle.remove(i)?;
+            if is_open(&socket) {
+                return Some(socket);
+            }
+        }
+        None
+    }
+
     /// Sends a request asynchronously using a cached connection if available.
+    #[cfg(feature = "async")]
     pub async fn send_async(&self, request: Request) -> Result<Response, Error> {
-        let parsed_request = ParsedRequest::new(request)?;
+        let cancellation = request.cancellation.clone();
+        crate::cancel::cancellable(cancellation, self.send_async_inner(request)).await
+    }
+
+    #[cfg(feature = "async")]
+    async fn send_async_inner(&self, mut request: Request) -> Result<Response, Error> {
+        self.apply_rate_limits(&mut request);
+        let mut parsed_request = ParsedRequest::new(request)?;
+        let lookup = parsed_request.cache_lookup();
         let key = parsed_request.connection_params();
         let owned_key = key.into();
 
@@ -94,13 +224,41 @@ impl Client {
         };
 
         // Send the request
-        conn.send(parsed_request).await
+        let response = conn.send(parsed_request).await?;
+        Ok(finish_lookup(lookup, response))
     }
 }
 
+/// Returns true if the server keeps the connection open after `response`, read to its end.
+fn keeps_alive(response: &Response) -> bool {
+    let header = |name: &str| response.headers.get(name).map(|value| value.trim());
+    let delimited = matches!(response.status_code, 204 | 304)
+        || header("content-length").is_some()
+        || header("transfer-encoding").is_some_and(|value| value.eq_ignore_ascii_case("chunked"));
+    let close = header("connection").is_some_and(|value| value.eq_ignore_ascii_case("close"));
+    delimited && !close
+}
+
+/// Returns true if the idle `socket` was not closed by the server, and has nothing to read.
+fn is_open(socket: &TcpStream) -> bool {
+    if socket.set_nonblocking(true).is_err() {
+        return false;
+    }
+    let open = match socket.peek(&mut [0; 1]) {
+        Err(e) => e.kind() == io::ErrorKind::WouldBlock,
+        // Closed, or sent bytes that are not the response to any request.
+        Ok(_) => false,
+    };
+    socket.set_nonblocking(false).is_ok() && open
+}
+
 /// Extension trait for `Request` to use with `Client`.
 pub trait RequestExt {
+    /// Sends this request using the provided client's connection pool.
+    fn send_with_client(self, client: &Client) -> Result<Response, Error>;
+
     /// Sends this request asynchronously using the provided client's connection pool.
+    #[cfg(feature = "async")]
     fn send_async_with_client(
         self,
         client: &Client,
@@ -108,6 +266,9 @@ pub trait RequestExt {
 }
 
 impl RequestExt for Request {
+    fn send_with_client(self, client: &Client) -> Result<Response, Error> { client.send(self) }
+
+    #[cfg(feature = "async")]
     fn send_async_with_client(
         self,
         client: &Client,
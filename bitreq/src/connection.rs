@@ -13,16 +13,20 @@ use std::sync::{Arc, Mutex};
 use std::task::{Context, Poll};
 use std::time::Instant;
 
-#[cfg(all(feature = "async", feature = "proxy"))]
-use tokio::io::AsyncReadExt;
 #[cfg(feature = "async")]
-use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt, ReadHalf, WriteHalf};
+use tokio::io::{AsyncRead, AsyncWrite, ReadHalf, WriteHalf};
+#[cfg(all(feature = "async", feature = "proxy"))]
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
 #[cfg(feature = "async")]
 use tokio::net::TcpStream as AsyncTcpStream;
 #[cfg(feature = "async")]
 use tokio::sync::Mutex as AsyncMutex;
 
 use crate::request::{ConnectionParams, OwnedConnectionParams, ParsedRequest};
+use crate::throttle::RateLimit;
+#[cfg(feature = "async")]
+use crate::throttle::{self, AsyncThrottledRead};
+use crate::url::socket_host;
 #[cfg(feature = "async")]
 use crate::Response;
 use crate::{Error, Method, ResponseLazy};
@@ -40,6 +44,8 @@ pub(crate) enum HttpStream {
     Secured(Box<SecuredStream>, Option<Instant>),
     #[cfg(feature = "async")]
     Buffer(std::io::Cursor<Vec<u8>>),
+    /// A stream with read and write rate limits.
+    Throttled(Box<HttpStream>, Option<RateLimit>, Option<RateLimit>),
 }
 
 impl HttpStream {
@@ -51,9 +57,42 @@ impl HttpStream {
     pub(crate) fn create_buffer(buffer: Vec<u8>) -> HttpStream {
         HttpStream::Buffer(std::io::Cursor::new(buffer))
     }
+
+    /// Returns the underlying TCP socket, if any.
+    fn tcp(&self) -> Option<&TcpStream> {
+        match self {
+            HttpStream::Unsecured(inner, _) => Some(inner),
+            #[cfg(feature = "rustls")]
+            HttpStream::Secured(inner, _) => Some(inner.get_ref()),
+            #[cfg(feature = "async")]
+            HttpStream::Buffer(_) => None,
+            HttpStream::Throttled(inner, _, _) => inner.tcp(),
+        }
+    }
+
+    /// Returns when the request on the stream times out, if set.
+    fn timeout_at(&self) -> Option<Instant> {
+        match self {
+            HttpStream::Unsecured(_, timeout_at) => *timeout_at,
+            #[cfg(feature = "rustls")]
+            HttpStream::Secured(_, timeout_at) => *timeout_at,
+            #[cfg(feature = "async")]
+            HttpStream::Buffer(_) => None,
+            HttpStream::Throttled(inner, _, _) => inner.timeout_at(),
+        }
+    }
+
+    /// Wraps the stream to enforce the given rate limits, if any.
+    fn throttled(self, read: Option<RateLimit>, write: Option<RateLimit>) -> HttpStream {
+        if read.is_none() && write.is_none() {
+            self
+        } else {
+            HttpStream::Throttled(Box::new(self), read, write)
+        }
+    }
 }
 
-fn timeout_err() -> io::Error {
+pub(crate) fn timeout_err() -> io::Error {
     io::Error::new(io::ErrorKind::TimedOut, "the timeout of the request was reached")
 }
 
@@ -88,6 +127,11 @@ impl Read for HttpStream {
             }
             #[cfg(feature = "async")]
             HttpStream::Buffer(cursor) => std::io::Read::read(cursor, buf),
+            HttpStream::Throttled(inner, Some(limit), _) => {
+                let timeout_at = inner.timeout_at();
+                limit.read(inner, buf, timeout_at)
+            }
+            HttpStream::Throttled(inner, None, _) => inner.read(buf),
         };
         match result {
             Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
@@ -121,6 +165,11 @@ impl Write for HttpStream {
                 debug_assert!(false, "We shouldn't write to a pre-loaded stream");
                 Ok(buf.len())
             }
+            HttpStream::Throttled(inner, _, Some(limit)) => {
+                let timeout_at = inner.timeout_at();
+                limit.write(inner, buf, timeout_at)
+            }
+            HttpStream::Throttled(inner, _, None) => inner.write(buf),
         };
         match result {
             Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
@@ -147,6 +196,7 @@ impl Write for HttpStream {
                 debug_assert!(false, "We shouldn't write to a pre-loaded stream");
                 Ok(())
             }
+            HttpStream::Throttled(inner, _, _) => inner.flush(),
         };
         match result {
             Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
@@ -277,7 +327,7 @@ impl AsyncConnection {
                 )))]
                 return Err(Error::HttpsFeatureNotEnabled);
                 #[cfg(any(feature = "async-https-rustls", feature = "async-https-rustls-probe"))]
-                rustls_stream::wrap_async_stream(socket, params.host).await
+                rustls_stream::wrap_async_stream(socket, &socket_host(params.host)).await
             } else {
                 Ok(AsyncHttpStream::Unsecured(socket))
             }
@@ -305,7 +355,8 @@ impl AsyncConnection {
         #[cfg(feature = "log")]
         log::trace!("Looking up host {host}");
 
-        let addrs = tokio::net::lookup_host((host, port)).await.map_err(Error::IoError)?;
+        let host = socket_host(host);
+        let addrs = tokio::net::lookup_host((&*host, port)).await.map_err(Error::IoError)?;
         let addrs: Vec<_> = addrs.collect();
         let addrs_count = addrs.len();
 
@@ -376,7 +427,7 @@ impl AsyncConnection {
         }
 
         #[cfg(not(feature = "proxy"))]
-        Self::tcp_connect(&params.host, params.port).await
+        Self::tcp_connect(params.host, params.port).await
     }
 
     async fn timeout<O, F: Future<Output = O>>(timeout: Option<Instant>, f: F) -> Result<O, Error> {
@@ -486,8 +537,15 @@ impl AsyncConnection {
                     request.connection_params(),
                 );
                 this_request.id = Some(request_id);
-                let write_res =
-                    Self::timeout(request.timeout_at, write.write_all(&request.as_bytes())).await;
+                let write_res = Self::timeout(
+                    request.timeout_at,
+                    throttle::write_all_async(
+                        &mut *write,
+                        &request.as_bytes(),
+                        request.config.write_rate_limit.as_ref(),
+                    ),
+                )
+                .await;
                 match write_res {
                     Err(e) => {
                         // If we failed to write the request, mark the socket as dead for future
@@ -551,7 +609,7 @@ impl AsyncConnection {
                 );
 
                 let response = Response::create_async(
-                    &mut *read,
+                    AsyncThrottledRead::new(&mut *read, request.config.read_rate_limit.clone()),
                     request.config.method == Method::Head,
                     request.config.max_headers_size,
                     request.config.max_status_line_len,
@@ -660,7 +718,7 @@ impl Connection {
             return Err(Error::HttpsFeatureNotEnabled);
             #[cfg(feature = "rustls")]
             {
-                let tls = rustls_stream::wrap_stream(socket, params.host)?;
+                let tls = rustls_stream::wrap_stream(socket, &socket_host(params.host))?;
                 HttpStream::Secured(Box::new(tls), timeout_at)
             }
         } else {
@@ -670,11 +728,17 @@ impl Connection {
         Ok(Connection { stream })
     }
 
+    /// Creates a `Connection` over `socket`, a plain HTTP connection to the server e.g., one kept
+    /// open by a [`Client`](crate::Client).
+    pub(crate) fn from_socket(socket: TcpStream, timeout_at: Option<Instant>) -> Connection {
+        Connection { stream: HttpStream::create_unsecured(socket, timeout_at) }
+    }
+
     fn tcp_connect(host: &str, port: u16, timeout_at: Option<Instant>) -> Result<TcpStream, Error> {
         #[cfg(feature = "log")]
         log::trace!("Looking up host {host}");
 
-        let addrs = (host, port).to_socket_addrs().map_err(Error::IoError)?;
+        let addrs = (&*socket_host(host), port).to_socket_addrs().map_err(Error::IoError)?;
         let addrs_count = addrs.len();
 
         // Try all resolved addresses. Return the first one to which we could connect. If all
@@ -706,7 +770,7 @@ impl Connection {
     }
 
     /// Connect to the server.
-    fn connect(
+    pub(crate) fn connect(
         params: ConnectionParams<'_>,
         timeout_at: Option<Instant>,
     ) -> Result<TcpStream, Error> {
@@ -753,18 +817,25 @@ impl Connection {
 
     /// Sends the [`Request`](struct.Request.html), consumes this
     /// connection, and returns a [`Response`](struct.Response.html).
-    pub(crate) fn send(mut self, request: ParsedRequest) -> Result<ResponseLazy, Error> {
+    pub(crate) fn send(self, request: ParsedRequest) -> Result<ResponseLazy, Error> {
+        if let (Some(token), Some(tcp)) = (&request.config.cancellation, self.stream.tcp()) {
+            token.register(tcp)?;
+        }
+        let mut stream = self.stream.throttled(
+            request.config.read_rate_limit.clone(),
+            request.config.write_rate_limit.clone(),
+        );
         enforce_timeout(request.timeout_at, move || {
             // Send request
             #[cfg(feature = "log")]
             log::trace!("Writing HTTP request.");
-            self.stream.write_all(&request.as_bytes())?;
+            stream.write_all(&request.as_bytes())?;
 
             // Receive response
             #[cfg(feature = "log")]
             log::trace!("Reading HTTP response.");
             let response = ResponseLazy::from_stream(
-                self.stream,
+                stream,
                 request.config.max_headers_size,
                 request.config.max_status_line_len,
                 request.config.max_body_size,
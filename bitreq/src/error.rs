@@ -78,6 +78,10 @@ pub enum Error {
     /// The response body size surpasses
     /// [Request::with_max_body_size](crate::request::Request::with_max_body_size).
     BodyOverflow,
+    /// The request was aborted via its
+    /// [`CancellationToken`](crate::CancellationToken).
+    #[cfg(feature = "std")]
+    Cancelled,
     // TODO: Uncomment these two for 3.0
     // /// The URL does not start with http:// or https://.
     // InvalidProtocol,
@@ -126,6 +130,8 @@ impl fmt::Display for Error {
             #[cfg(feature = "proxy")]
             InvalidProxyCreds => write!(f, "the provided proxy credentials are invalid"),
             BodyOverflow => write!(f, "the response body size surpassed max_body_size"),
+            #[cfg(feature = "std")]
+            Cancelled => write!(f, "the request was cancelled"),
             // TODO: Uncomment these two for 3.0
             // InvalidProtocol => write!(f, "the url does not start with http:// or https://"),
             // InvalidProtocolInRedirect => write!(f, "got redirected to an absolute url which does not start with http:// or https://"),
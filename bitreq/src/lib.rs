@@ -250,6 +250,10 @@
 
 extern crate alloc;
 
+#[cfg(feature = "std")]
+mod cache;
+#[cfg(feature = "std")]
+mod cancel;
 #[cfg(feature = "std")]
 mod client;
 #[cfg(feature = "std")]
@@ -261,9 +265,15 @@ mod proxy;
 mod request;
 #[cfg(feature = "std")]
 mod response;
+#[cfg(feature = "std")]
+mod throttle;
 mod url;
 
-#[cfg(feature = "async")]
+#[cfg(feature = "std")]
+pub use cache::Cache;
+#[cfg(feature = "std")]
+pub use cancel::CancellationToken;
+#[cfg(feature = "std")]
 pub use client::{Client, RequestExt};
 pub use error::*;
 #[cfg(feature = "proxy")]
@@ -272,4 +282,6 @@ pub use proxy::*;
 pub use request::*;
 #[cfg(feature = "std")]
 pub use response::{Response, ResponseLazy};
+#[cfg(feature = "std")]
+pub use throttle::RateLimit;
 pub use url::{ParseError as UrlParseError, Url};
@@ -8,6 +8,10 @@ use std::env;
 #[cfg(feature = "std")]
 use std::time::Instant;
 
+#[cfg(feature = "std")]
+use crate::cache::{Cache, Lookup};
+#[cfg(feature = "std")]
+use crate::cancel::CancellationToken;
 #[cfg(feature = "async")]
 use crate::connection::AsyncConnection;
 #[cfg(feature = "std")]
@@ -15,6 +19,8 @@ use crate::connection::Connection;
 #[cfg(feature = "proxy")]
 use crate::proxy::Proxy;
 #[cfg(feature = "std")]
+use crate::throttle::RateLimit;
+#[cfg(feature = "std")]
 use crate::url::Url;
 #[cfg(feature = "std")]
 use crate::{Error, Response, ResponseLazy};
@@ -97,6 +103,24 @@ pub struct Request {
     max_redirects: usize,
     #[cfg(feature = "proxy")]
     pub(crate) proxy: Option<Proxy>,
+    #[cfg(feature = "std")]
+    pub(crate) cancellation: Option<CancellationToken>,
+    #[cfg(feature = "std")]
+    pub(crate) read_rate_limit: Option<RateLimit>,
+    #[cfg(feature = "std")]
+    pub(crate) write_rate_limit: Option<RateLimit>,
+    #[cfg(feature = "std")]
+    cache: Option<Cache>,
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Request {
+    fn drop(&mut self) {
+        // Header values may hold credentials e.g., `Authorization`.
+        for value in self.headers.values_mut() {
+            zeroize::Zeroize::zeroize(value);
+        }
+    }
 }
 
 impl Request {
@@ -127,6 +151,14 @@ impl Request {
             max_redirects: 100,
             #[cfg(feature = "proxy")]
             proxy: None,
+            #[cfg(feature = "std")]
+            cancellation: None,
+            #[cfg(feature = "std")]
+            read_rate_limit: None,
+            #[cfg(feature = "std")]
+            write_rate_limit: None,
+            #[cfg(feature = "std")]
+            cache: None,
         }
     }
 
@@ -268,6 +300,42 @@ impl Request {
         self
     }
 
+    /// Sets a token that can be used to abort this request from another thread or task.
+    ///
+    /// See [`CancellationToken`] for details.
+    #[cfg(feature = "std")]
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Request {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Limits how fast the response is read.
+    ///
+    /// See [`RateLimit`] for details, e.g. to share a limit between requests.
+    #[cfg(feature = "std")]
+    pub fn with_read_rate_limit(mut self, limit: RateLimit) -> Request {
+        self.read_rate_limit = Some(limit);
+        self
+    }
+
+    /// Limits how fast the request is written.
+    ///
+    /// See [`RateLimit`] for details, e.g. to share a limit between requests.
+    #[cfg(feature = "std")]
+    pub fn with_write_rate_limit(mut self, limit: RateLimit) -> Request {
+        self.write_rate_limit = Some(limit);
+        self
+    }
+
+    /// Sets the cache used to revalidate and store the response of this request.
+    ///
+    /// Only `GET` requests are cached, see [`Cache`] for details.
+    #[cfg(feature = "std")]
+    pub fn with_cache(mut self, cache: Cache) -> Request {
+        self.cache = Some(cache);
+        self
+    }
+
     /// Enables HTTP request pipelining for this request.
     ///
     /// Note that because pipelined requests may be replayed in case of failure, you should only
@@ -294,13 +362,13 @@ impl Request {
     /// [`InvalidUtf8InBody`](enum.Error.html#variant.InvalidUtf8InBody).
     #[cfg(feature = "std")]
     pub fn send(self) -> Result<Response, Error> {
-        let parsed_request = ParsedRequest::new(self)?;
-        let is_head = parsed_request.config.method == Method::Head;
-        let max_body_size = parsed_request.config.max_body_size;
-        let connection =
-            Connection::new(parsed_request.connection_params(), parsed_request.timeout_at)?;
-        let response = connection.send(parsed_request)?;
-        Response::create(response, is_head, max_body_size)
+        let cancellation = self.cancellation.clone();
+        let result = ParsedRequest::new(self).and_then(|parsed_request| {
+            let connection =
+                Connection::new(parsed_request.connection_params(), parsed_request.timeout_at)?;
+            parsed_request.send_with(connection)
+        });
+        map_cancelled(result, cancellation.as_ref())
     }
 
     /// Sends this request to the host, loaded lazily.
@@ -310,9 +378,12 @@ impl Request {
     /// See [`send`](struct.Request.html#method.send).
     #[cfg(feature = "std")]
     pub fn send_lazy(self) -> Result<ResponseLazy, Error> {
-        let parsed_request = ParsedRequest::new(self)?;
-        Connection::new(parsed_request.connection_params(), parsed_request.timeout_at)?
-            .send(parsed_request)
+        let cancellation = self.cancellation.clone();
+        let result = ParsedRequest::new(self).and_then(|parsed_request| {
+            Connection::new(parsed_request.connection_params(), parsed_request.timeout_at)?
+                .send(parsed_request)
+        });
+        map_cancelled(result, cancellation.as_ref())
     }
 
     /// Sends this request to the host asynchronously.
@@ -326,11 +397,18 @@ impl Request {
     /// [`InvalidUtf8InBody`](enum.Error.html#variant.InvalidUtf8InBody).
     #[cfg(feature = "async")]
     pub async fn send_async(self) -> Result<Response, Error> {
-        let parsed_request = ParsedRequest::new(self)?;
-        AsyncConnection::new(parsed_request.connection_params(), parsed_request.timeout_at)
-            .await?
-            .send(parsed_request)
-            .await
+        let cancellation = self.cancellation.clone();
+        let send = async move {
+            let mut parsed_request = ParsedRequest::new(self)?;
+            let lookup = parsed_request.cache_lookup();
+            let response =
+                AsyncConnection::new(parsed_request.connection_params(), parsed_request.timeout_at)
+                    .await?
+                    .send(parsed_request)
+                    .await?;
+            Ok(finish_lookup(lookup, response))
+        };
+        crate::cancel::cancellable(cancellation, send).await
     }
 
     /// Sends this request to the host asynchronously, "loaded lazily".
@@ -351,6 +429,48 @@ impl Request {
     }
 }
 
+/// Replaces the error of a request aborted via its cancellation token with [`Error::Cancelled`].
+///
+/// Shutting down the socket of a blocking request surfaces as an I/O error. A request that
+/// completed before the token was cancelled keeps its response.
+#[cfg(feature = "std")]
+pub(crate) fn map_cancelled<T>(
+    result: Result<T, Error>,
+    cancellation: Option<&CancellationToken>,
+) -> Result<T, Error> {
+    match result {
+        Err(_) if cancellation.is_some_and(CancellationToken::is_cancelled) =>
+            Err(Error::Cancelled),
+        result => result,
+    }
+}
+
+/// Answers from the cache if the server said the cached response is still valid.
+#[cfg(feature = "std")]
+pub(crate) fn finish_lookup(lookup: Option<Lookup>, response: Response) -> Response {
+    match lookup {
+        Some(lookup) => lookup.finish(response),
+        None => response,
+    }
+}
+
+/// An HTTP request as sent to the server, wiped from memory once dropped if the `zeroize`
+/// feature is enabled.
+#[cfg(feature = "std")]
+pub(crate) struct RequestBytes(Vec<u8>);
+
+#[cfg(feature = "std")]
+impl core::ops::Deref for RequestBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] { &self.0 }
+}
+
+#[cfg(all(feature = "std", feature = "zeroize"))]
+impl Drop for RequestBytes {
+    fn drop(&mut self) { zeroize::Zeroize::zeroize(&mut self.0); }
+}
+
 #[cfg(feature = "std")]
 pub(crate) struct ParsedRequest {
     pub(crate) url: Url,
@@ -411,8 +531,24 @@ impl ParsedRequest {
         Ok(ParsedRequest { url, redirects: Vec::new(), config, timeout_at })
     }
 
+    /// Looks the request up in its cache, adding the headers needed to revalidate a hit.
+    pub(crate) fn cache_lookup(&mut self) -> Option<Lookup> {
+        let cache = self.config.cache.clone()?;
+        if self.config.method != Method::Get {
+            return None;
+        }
+        let (lookup, headers) = Lookup::new(cache, self.url.as_str());
+        for (name, value) in headers {
+            self.config.headers.insert(name.to_string(), value);
+        }
+        Some(lookup)
+    }
+
     fn get_http_head(&self) -> String {
-        let mut http = String::with_capacity(32);
+        // Allocate once up front so that growing the head doesn't leave copies of the
+        // headers behind.
+        let headers: usize = self.config.headers.iter().map(|(k, v)| k.len() + v.len() + 4).sum();
+        let mut http = String::with_capacity(128 + 2 * self.url.as_str().len() + headers);
 
         // NOTE: As of 2.10.0, the fragment is intentionally left out of the request, based on:
         // - [RFC 3986 section 3.5](https://datatracker.ietf.org/doc/html/rfc3986#section-3.5):
@@ -429,7 +565,7 @@ impl ParsedRequest {
             "{} {} HTTP/1.1\r\nHost: {}",
             self.config.method,
             self.url.path_and_query(),
-            self.url.base_url()
+            self.url.host_header()
         )
         .unwrap();
         if self.url.has_explicit_non_default_port() {
@@ -469,12 +605,13 @@ impl ParsedRequest {
 
     /// Returns the HTTP request as bytes, ready to be sent to
     /// the server.
-    pub(crate) fn as_bytes(&self) -> Vec<u8> {
+    pub(crate) fn as_bytes(&self) -> RequestBytes {
         let mut head = self.get_http_head().into_bytes();
         if let Some(body) = &self.config.body {
+            head.reserve_exact(body.len());
             head.extend(body);
         }
-        head
+        RequestBytes(head)
     }
 
     /// Returns the redirected version of this Request, unless an
@@ -527,6 +664,16 @@ impl ParsedRequest {
     pub(crate) fn connection_params(&self) -> ConnectionParams<'_> {
         ConnectionParams::from_request(self)
     }
+
+    /// Sends the request over `connection` and reads the whole response.
+    pub(crate) fn send_with(mut self, connection: Connection) -> Result<Response, Error> {
+        let is_head = self.config.method == Method::Head;
+        let max_body_size = self.config.max_body_size;
+        let lookup = self.cache_lookup();
+        let response = connection.send(self)?;
+        let response = Response::create(response, is_head, max_body_size)?;
+        Ok(finish_lookup(lookup, response))
+    }
 }
 
 /// A key which determines whether an existing connection can be reused
@@ -697,3 +844,19 @@ mod encoding_tests {
         assert_eq!(req.url.path_and_query(), "/?foo=bar");
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "std")]
+mod cancellation_tests {
+    use super::map_cancelled;
+    use crate::{CancellationToken, Error};
+
+    #[test]
+    fn test_cancelled_after_response() {
+        let token = CancellationToken::new();
+        token.cancel();
+        assert_eq!(map_cancelled(Ok(200), Some(&token)).unwrap(), 200);
+        let result = map_cancelled::<u16>(Err(Error::HeadersOverflow), Some(&token));
+        assert!(matches!(result, Err(Error::Cancelled)));
+    }
+}
@@ -74,6 +74,18 @@ impl Response {
         Ok(Response { status_code, reason_phrase, headers, url, body })
     }
 
+    /// Creates a response from its parts, used to restore cached responses.
+    #[cfg(feature = "std")]
+    pub(crate) fn from_parts(
+        status_code: i32,
+        reason_phrase: String,
+        headers: BTreeMap<String, String>,
+        url: String,
+        body: Vec<u8>,
+    ) -> Response {
+        Response { status_code, reason_phrase, headers, url, body }
+    }
+
     #[cfg(feature = "async")]
     /// Fully read a [`Response`] from an async stream.
     ///
@@ -674,9 +686,9 @@ macro_rules! define_read_methods {
             overflow_error: Error,
         ) -> Result<String, Error> {
             let mut bytes = Vec::with_capacity(32);
-            while let Some(byte) = maybe_await!(stream.next(), $($await)?) {
-                match byte {
-                    Ok(byte) => {
+            loop {
+                match maybe_await!(stream.next(), $($await)?) {
+                    Some(Ok(byte)) => {
                         if let Some(max_len) = max_len {
                             if bytes.len() >= max_len {
                                 return Err(overflow_error);
@@ -691,7 +703,9 @@ macro_rules! define_read_methods {
                             bytes.push(byte);
                         }
                     }
-                    Err(err) => return Err(Error::IoError(err)),
+                    Some(Err(err)) => return Err(Error::IoError(err)),
+                    // The connection was closed mid-line, the response is truncated.
+                    None => return Err(Error::IoError(io::ErrorKind::UnexpectedEof.into())),
                 }
             }
             String::from_utf8(bytes).map_err(|_error| Error::InvalidUtf8InResponse)
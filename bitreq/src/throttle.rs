@@ -0,0 +1,265 @@
+//! Bandwidth throttling of requests.
+
+use core::time::Duration;
+#[cfg(feature = "async")]
+use std::future::Future;
+use std::io;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+#[cfg(feature = "async")]
+use std::task::{ready, Context, Poll};
+use std::time::Instant;
+
+#[cfg(feature = "async")]
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt, ReadBuf};
+
+/// A bandwidth limit, in bytes per second.
+///
+/// Attach to a request with [`Request::with_read_rate_limit`](crate::Request::with_read_rate_limit)
+/// and [`Request::with_write_rate_limit`](crate::Request::with_write_rate_limit). Clones share the
+/// same budget, so a single `RateLimit` attached to several requests limits their combined
+/// bandwidth, create a new one for each request to limit them individually.
+///
+/// Up to one second worth of bytes can be transferred in a burst. Only the HTTP bytes are counted,
+/// not TLS overhead.
+#[derive(Clone, Debug)]
+pub struct RateLimit {
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+/// A token bucket holding one token per byte.
+#[derive(Debug)]
+struct Bucket {
+    bytes_per_second: u64,
+    /// Bytes that can be transferred right away, negative if we over-spent.
+    available: i64,
+    /// When `available` was last refilled.
+    refilled_at: Instant,
+}
+
+impl RateLimit {
+    /// Creates a limit of `bytes_per_second`, which must be non-zero.
+    ///
+    /// # Panics
+    ///
+    /// If `bytes_per_second` is zero.
+    pub fn new(bytes_per_second: u64) -> Self {
+        assert!(bytes_per_second > 0, "rate limit must be non-zero");
+        let bucket = Bucket {
+            bytes_per_second,
+            available: capacity(bytes_per_second),
+            refilled_at: Instant::now(),
+        };
+        RateLimit { bucket: Arc::new(Mutex::new(bucket)) }
+    }
+
+    /// Returns the limit in bytes per second.
+    pub fn bytes_per_second(&self) -> u64 {
+        self.bucket.lock().expect("poisoned lock").bytes_per_second
+    }
+
+    /// Returns how many bytes may be transferred now, or how long to wait before asking again.
+    pub(crate) fn allowance(&self) -> Result<usize, Duration> {
+        let mut bucket = self.bucket.lock().expect("poisoned lock");
+        let rate = bucket.bytes_per_second;
+        let now = Instant::now();
+        let earned =
+            now.duration_since(bucket.refilled_at).as_nanos() * u128::from(rate) / 1_000_000_000;
+        if earned > 0 {
+            let earned = i64::try_from(earned).unwrap_or(i64::MAX);
+            bucket.available = bucket.available.saturating_add(earned).min(capacity(rate));
+            bucket.refilled_at = now;
+        }
+        if bucket.available > 0 {
+            return Ok(usize::try_from(bucket.available).unwrap_or(usize::MAX));
+        }
+        // Wait for a tenth of a second worth of bytes so transfers aren't split into tiny chunks.
+        let wanted = (capacity(rate) / 10).max(1) - bucket.available;
+        let nanos = u128::try_from(wanted).unwrap_or(0) * 1_000_000_000 / u128::from(rate);
+        Err(Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX)))
+    }
+
+    /// Records that `bytes` were transferred.
+    pub(crate) fn consume(&self, bytes: usize) {
+        let mut bucket = self.bucket.lock().expect("poisoned lock");
+        bucket.available =
+            bucket.available.saturating_sub(i64::try_from(bytes).unwrap_or(i64::MAX));
+    }
+
+    /// Blocks until some bytes may be transferred, returns how many (at most `len`).
+    ///
+    /// Returns a `TimedOut` error instead of waiting past `timeout_at`.
+    pub(crate) fn wait(&self, len: usize, timeout_at: Option<Instant>) -> io::Result<usize> {
+        loop {
+            let delay = match self.allowance() {
+                Ok(allowed) => return Ok(allowed.min(len)),
+                Err(delay) => delay,
+            };
+            let delay = match timeout_at {
+                Some(timeout_at) => match timeout_at.checked_duration_since(Instant::now()) {
+                    Some(remaining) if !remaining.is_zero() => delay.min(remaining),
+                    _ => return Err(crate::connection::timeout_err()),
+                },
+                None => delay,
+            };
+            std::thread::sleep(delay);
+        }
+    }
+
+    /// Reads into `buf` from `reader` without exceeding the limit, or waiting past `timeout_at`.
+    pub(crate) fn read<R: io::Read>(
+        &self,
+        reader: &mut R,
+        buf: &mut [u8],
+        timeout_at: Option<Instant>,
+    ) -> io::Result<usize> {
+        let len = self.wait(buf.len(), timeout_at)?;
+        let read = reader.read(&mut buf[..len])?;
+        self.consume(read);
+        Ok(read)
+    }
+
+    /// Writes from `buf` to `writer` without exceeding the limit, or waiting past `timeout_at`.
+    pub(crate) fn write<W: io::Write>(
+        &self,
+        writer: &mut W,
+        buf: &[u8],
+        timeout_at: Option<Instant>,
+    ) -> io::Result<usize> {
+        let len = self.wait(buf.len(), timeout_at)?;
+        let written = writer.write(&buf[..len])?;
+        self.consume(written);
+        Ok(written)
+    }
+}
+
+/// The bucket holds up to one second worth of bytes.
+fn capacity(bytes_per_second: u64) -> i64 { i64::try_from(bytes_per_second).unwrap_or(i64::MAX) }
+
+impl PartialEq for RateLimit {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.bucket, &other.bucket) }
+}
+
+impl Eq for RateLimit {}
+
+/// Writes all of `buf` to `writer` without exceeding `limit`.
+#[cfg(feature = "async")]
+pub(crate) async fn write_all_async<W: AsyncWrite + Unpin>(
+    writer: &mut W,
+    mut buf: &[u8],
+    limit: Option<&RateLimit>,
+) -> io::Result<()> {
+    let limit = match limit {
+        Some(limit) => limit,
+        None => return writer.write_all(buf).await,
+    };
+    while !buf.is_empty() {
+        match limit.allowance() {
+            Ok(allowed) => {
+                let len = allowed.min(buf.len());
+                writer.write_all(&buf[..len]).await?;
+                limit.consume(len);
+                buf = &buf[len..];
+            }
+            Err(delay) => tokio::time::sleep(delay).await,
+        }
+    }
+    Ok(())
+}
+
+/// An [`AsyncRead`] that does not read faster than its limit.
+#[cfg(feature = "async")]
+pub(crate) struct AsyncThrottledRead<R> {
+    inner: R,
+    limit: Option<RateLimit>,
+    /// Pending wait for the limit to allow more bytes.
+    delay: Option<Pin<Box<tokio::time::Sleep>>>,
+    /// Reads are done into this buffer so they can be truncated to the allowed length.
+    scratch: Vec<u8>,
+}
+
+#[cfg(feature = "async")]
+impl<R> AsyncThrottledRead<R> {
+    pub(crate) fn new(inner: R, limit: Option<RateLimit>) -> Self {
+        AsyncThrottledRead { inner, limit, delay: None, scratch: Vec::new() }
+    }
+}
+
+#[cfg(feature = "async")]
+impl<R: AsyncRead + Unpin> AsyncRead for AsyncThrottledRead<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = &mut *self;
+        let limit = match &this.limit {
+            Some(limit) => limit,
+            None => return Pin::new(&mut this.inner).poll_read(cx, buf),
+        };
+        loop {
+            if let Some(delay) = this.delay.as_mut() {
+                ready!(delay.as_mut().poll(cx));
+                this.delay = None;
+            }
+            match limit.allowance() {
+                Ok(allowed) => {
+                    let len = allowed.min(buf.remaining());
+                    this.scratch.resize(len, 0);
+                    let mut limited = ReadBuf::new(&mut this.scratch);
+                    ready!(Pin::new(&mut this.inner).poll_read(cx, &mut limited))?;
+                    let read = limited.filled();
+                    limit.consume(read.len());
+                    buf.put_slice(read);
+                    return Poll::Ready(Ok(()));
+                }
+                Err(delay) => this.delay = Some(Box::pin(tokio::time::sleep(delay))),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn allowance_starts_full() {
+        let limit = RateLimit::new(1_000);
+        assert_eq!(limit.allowance(), Ok(1_000));
+    }
+
+    #[test]
+    fn allowance_waits_when_spent() {
+        let limit = RateLimit::new(1_000);
+        limit.consume(1_000);
+        let delay = limit.allowance().unwrap_err();
+        // A tenth of the rate, 100 bytes at 1000 bytes per second.
+        assert!(delay <= Duration::from_millis(100));
+        assert!(delay > Duration::from_millis(50));
+    }
+
+    #[test]
+    fn wait_stops_at_timeout() {
+        let limit = RateLimit::new(1);
+        limit.consume(1_000);
+        let start = Instant::now();
+        let err = limit.wait(10, Some(start + Duration::from_millis(50))).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        let err = limit.wait(10, Some(start)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+    }
+
+    #[test]
+    fn clones_share_budget() {
+        let limit = RateLimit::new(1_000);
+        let clone = limit.clone();
+        clone.consume(600);
+        assert!(limit.allowance().unwrap() <= 400);
+        assert_eq!(limit, clone);
+        assert_ne!(limit, RateLimit::new(1_000));
+    }
+}
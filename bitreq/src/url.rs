@@ -1,5 +1,7 @@
 //! A minimal library for parsing and validating URLs.
 
+#[cfg(feature = "std")]
+use alloc::borrow::Cow;
 use alloc::format;
 use alloc::string::{String, ToString as _};
 use alloc::vec::Vec;
@@ -16,6 +18,21 @@ fn default_port_for_scheme(scheme: &str) -> Option<u16> {
     }
 }
 
+/// Returns `host` in the form expected by name resolution and TLS.
+///
+/// Brackets are removed from IPv6 literals and a percent-encoded zone ID (RFC 6874) is decoded
+/// e.g., `[fe80::1%25eth0]` becomes `fe80::1%eth0`. Other hosts are returned unchanged.
+#[cfg(feature = "std")]
+pub(crate) fn socket_host(host: &str) -> Cow<'_, str> {
+    match host.strip_prefix('[').and_then(|h| h.strip_suffix(']')) {
+        Some(literal) => match literal.find("%25") {
+            Some(pos) => Cow::Owned(format!("{}%{}", &literal[..pos], &literal[pos + 3..])),
+            None => Cow::Borrowed(literal),
+        },
+        None => Cow::Borrowed(host),
+    }
+}
+
 /// Errors that can occur during URL parsing.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParseError {
@@ -305,6 +322,22 @@ impl Url {
     /// Returns the base URL (host portion).
     pub fn base_url(&self) -> &str { &self.serialization[self.host.clone()] }
 
+    /// Returns the host as sent in the `Host` header.
+    ///
+    /// The zone ID of a scoped IPv6 literal is only meaningful to the client so it is removed
+    /// e.g., `[fe80::1%25eth0]` becomes `[fe80::1]`.
+    #[cfg(feature = "std")]
+    pub(crate) fn host_header(&self) -> Cow<'_, str> {
+        let host = self.base_url();
+        match host.strip_prefix('[').and_then(|h| h.strip_suffix(']')) {
+            Some(literal) => match literal.find('%') {
+                Some(pos) => Cow::Owned(format!("[{}]", &literal[..pos])),
+                None => Cow::Borrowed(host),
+            },
+            None => Cow::Borrowed(host),
+        }
+    }
+
     /// Returns the port number for the URL.
     ///
     /// If a port was explicitly specified in the URL, that port is returned.
@@ -835,6 +868,36 @@ mod tests {
         assert_eq!(url.port(), 443);
     }
 
+    #[test]
+    fn ipv6_with_zone_id() {
+        let url = Url::parse("http://[fe80::1%25eth0]:8332/").unwrap();
+        assert_eq!(url.base_url(), "[fe80::1%25eth0]");
+        assert_eq!(url.port(), 8332);
+        assert_eq!(url.path(), "/");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn ipv6_host_header_strips_zone_id() {
+        let url = Url::parse("http://[fe80::1%25eth0]:8332/").unwrap();
+        assert_eq!(url.host_header(), "[fe80::1]");
+
+        let url = Url::parse("http://[::1]:8332/").unwrap();
+        assert_eq!(url.host_header(), "[::1]");
+
+        let url = Url::parse("http://example.com/").unwrap();
+        assert_eq!(url.host_header(), "example.com");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn socket_host_removes_brackets() {
+        assert_eq!(socket_host("[::1]"), "::1");
+        assert_eq!(socket_host("[fe80::1%25eth0]"), "fe80::1%eth0");
+        assert_eq!(socket_host("127.0.0.1"), "127.0.0.1");
+        assert_eq!(socket_host("example.com"), "example.com");
+    }
+
     #[test]
     fn ipv6_as_str_roundtrip() {
         let url = Url::parse("http://[::1]:8080/path").unwrap();
@@ -267,3 +267,145 @@ async fn test_future_drop_doesnt_hang() {
         "Request should complete quickly, and certainly not have to wait for its timeout to try again"
     );
 }
+
+#[test]
+fn test_truncated_status_line() {
+    use std::io::{Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let _ = stream.read(&mut [0; 1024]).unwrap();
+        stream.write_all(b"HTTP/1.1 20").unwrap();
+    });
+
+    let result = bitreq::get(format!("http://127.0.0.1:{port}")).with_timeout(5).send();
+    server.join().unwrap();
+    match result {
+        Err(bitreq::Error::IoError(err)) => assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof),
+        result => panic!("truncated response did not return an error: {result:?}"),
+    }
+}
+
+#[test]
+fn test_cancel_blocking_request() {
+    setup();
+    let token = bitreq::CancellationToken::new();
+    let request = bitreq::get(url("/slow_a")).with_timeout(10).with_cancellation(token.clone());
+
+    let canceller = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        token.cancel();
+    });
+    let start = std::time::Instant::now();
+    let result = request.send();
+    canceller.join().unwrap();
+
+    assert!(matches!(result, Err(bitreq::Error::Cancelled)));
+    assert!(start.elapsed() < std::time::Duration::from_secs(2));
+}
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn test_cancel_async_request() {
+    setup();
+    let token = bitreq::CancellationToken::new();
+    let request = bitreq::get(url("/slow_a")).with_timeout(10).with_cancellation(token.clone());
+
+    tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        token.cancel();
+    });
+    let result = request.send_async().await;
+    assert!(matches!(result, Err(bitreq::Error::Cancelled)));
+}
+
+#[test]
+fn test_cancelled_token_fails_immediately() {
+    setup();
+    let token = bitreq::CancellationToken::new();
+    token.cancel();
+    let result = bitreq::get(url("/a")).with_cancellation(token).send();
+    assert!(matches!(result, Err(bitreq::Error::Cancelled)));
+}
+
+#[test]
+fn test_read_rate_limit() {
+    setup();
+    let body = "x".repeat(3_000);
+    let request = bitreq::post(url("/echo"))
+        .with_body(body.clone())
+        .with_timeout(10)
+        .with_read_rate_limit(bitreq::RateLimit::new(1_000));
+
+    let start = std::time::Instant::now();
+    let response = request.send().unwrap();
+    // The first second worth of bytes is read right away, the rest takes about two seconds.
+    assert!(start.elapsed() >= std::time::Duration::from_millis(1_500));
+    assert_eq!(response.as_str().unwrap(), body);
+}
+
+#[test]
+fn test_client_keeps_connection_alive() {
+    setup();
+    let remote_port = |client: &bitreq::Client| {
+        let response = client.send(bitreq::get(url("/remote_port"))).unwrap();
+        response.as_str().unwrap().to_owned()
+    };
+
+    let client = bitreq::Client::new(1);
+    let port = remote_port(&client);
+    assert_eq!(remote_port(&client), port);
+    assert_eq!(remote_port(&client.clone()), port);
+
+    // Without idle connections kept open each request uses a new one.
+    let client = bitreq::Client::new(0);
+    assert_ne!(remote_port(&client), remote_port(&client));
+}
+
+#[test]
+fn test_write_rate_limit() {
+    setup();
+    let body = "x".repeat(3_000);
+    let request = bitreq::post(url("/echo"))
+        .with_body(body.clone())
+        .with_timeout(10)
+        .with_write_rate_limit(bitreq::RateLimit::new(1_000));
+
+    let start = std::time::Instant::now();
+    let response = request.send().unwrap();
+    assert!(start.elapsed() >= std::time::Duration::from_millis(1_500));
+    assert_eq!(response.as_str().unwrap(), body);
+}
+
+#[tokio::test]
+#[cfg(feature = "async")]
+async fn test_client_rate_limit() {
+    setup();
+    let body = "x".repeat(3_000);
+    let client = bitreq::Client::new(1).with_read_rate_limit(bitreq::RateLimit::new(1_000));
+
+    let start = std::time::Instant::now();
+    let response = client.send_async(bitreq::post(url("/echo")).with_body(body.clone())).await;
+    assert!(start.elapsed() >= std::time::Duration::from_millis(1_500));
+    assert_eq!(response.unwrap().as_str().unwrap(), body);
+}
+
+#[tokio::test]
+async fn test_cache_revalidates_with_etag() {
+    setup();
+    let cache = bitreq::Cache::new();
+
+    let first = bitreq::get(url("/etag")).with_cache(cache.clone()).send().unwrap();
+    assert_eq!(first.status_code, 200);
+    // The server replies `304 Not Modified` so we get the cached body back.
+    let second = bitreq::get(url("/etag")).with_cache(cache.clone()).send().unwrap();
+    assert_eq!(second.status_code, 200);
+    assert_eq!(second.as_str().unwrap(), first.as_str().unwrap());
+    #[cfg(feature = "async")]
+    {
+        let third = bitreq::get(url("/etag")).with_cache(cache).send_async().await.unwrap();
+        assert_eq!(third.as_str().unwrap(), first.as_str().unwrap());
+    }
+}
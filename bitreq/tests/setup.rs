@@ -4,6 +4,7 @@ extern crate bitreq;
 extern crate tiny_http;
 use std::io::Read;
 use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Once};
 use std::thread;
 use std::time::Duration;
@@ -12,6 +13,9 @@ use self::tiny_http::{Header, Method, Response, Server, StatusCode};
 
 static INIT: Once = Once::new();
 
+/// Number of full responses served by `/etag`.
+static ETAG_HITS: AtomicUsize = AtomicUsize::new(0);
+
 pub fn setup() {
     INIT.call_once(|| {
         let server = Arc::new(Server::http("localhost:35562").unwrap());
@@ -154,10 +158,31 @@ pub fn setup() {
                         respond!(response);
                     }
 
+                    Method::Get if url == "/etag" => {
+                        let revalidating = headers.iter().any(|header| {
+                            header.field.as_str() == "If-None-Match"
+                                && header.value.as_str() == "\"v1\""
+                        });
+                        if revalidating {
+                            respond!(Response::empty(304));
+                        } else {
+                            let hits = ETAG_HITS.fetch_add(1, Ordering::SeqCst) + 1;
+                            let response = Response::from_string(format!("hit {}", hits))
+                                .with_header(Header::from_str("ETag: \"v1\"").unwrap());
+                            respond!(response);
+                        }
+                    }
+
                     Method::Post if url == "/echo" => {
                         respond!(Response::from_string(content));
                     }
 
+                    Method::Get if url == "/remote_port" => {
+                        // Without a random `Connection: close`, to check the connection is reused.
+                        let port = request.remote_addr().map_or(0, |addr| addr.port());
+                        request.respond(Response::from_string(port.to_string())).unwrap();
+                    }
+
                     Method::Head if url == "/b" => {
                         respond!(Response::empty(418));
                     }
@@ -201,6 +226,7 @@ pub fn setup() {
 
 pub fn url(req: &str) -> String { format!("http://localhost:35562{}", req) }
 
+static SYNC_CLIENT: std::sync::OnceLock<bitreq::Client> = std::sync::OnceLock::new();
 #[cfg(feature = "async")]
 static CLIENT: std::sync::OnceLock<bitreq::Client> = std::sync::OnceLock::new();
 #[cfg(feature = "async")]
@@ -208,7 +234,7 @@ static RUNTIME: std::sync::OnceLock<tokio::runtime::Runtime> = std::sync::OnceLo
 
 pub async fn maybe_make_request(
     request: bitreq::Request,
-    _slow_request: bool,
+    slow_request: bool,
 ) -> Result<bitreq::Response, bitreq::Error> {
     let response = request.clone().send();
     let lazy_response = request.clone().send_lazy();
@@ -224,6 +250,20 @@ pub async fn maybe_make_request(
         (res, lazy_res) => panic!("{res:?} != {}", lazy_res.is_err()),
     }
 
+    if !slow_request {
+        // Sent on a connection kept alive from an earlier request, if any.
+        let client = SYNC_CLIENT.get_or_init(|| bitreq::Client::new(100));
+        match (&response, client.send(request.clone())) {
+            (Ok(resp), Ok(client_resp)) => {
+                assert_eq!(client_resp.status_code, resp.status_code);
+                assert_eq!(client_resp.reason_phrase, resp.reason_phrase);
+                assert_eq!(client_resp.as_bytes(), resp.as_bytes());
+            }
+            (Err(e), Err(client_e)) => assert_eq!(format!("{e:?}"), format!("{client_e:?}")),
+            (res, client_res) => panic!("{res:?} != {client_res:?}"),
+        }
+    }
+
     #[cfg(feature = "async")]
     {
         if let Ok(resp) = &response {
@@ -236,7 +276,7 @@ pub async fn maybe_make_request(
         let async_future = request.clone().send_async();
         let lazy_async_future = request.clone().send_lazy_async();
         let client_future = async move {
-            if !_slow_request {
+            if !slow_request {
                 // In order to ensure that clients are able to continue doing things after the tokio
                 // runtime of other tests has been shut down, we spawn them on a global runtime.
                 let client = CLIENT.get_or_init(|| bitreq::Client::new(100)).clone();
@@ -8,7 +8,7 @@
 FEATURES_WITH_STD=""
 
 # Test all these features without "std" enabled.
-FEATURES_WITHOUT_STD="client-sync"
+FEATURES_WITHOUT_STD="broadcast client-async client-sync deposits failover fetch headers quorum split state supply withdrawals zeroize"
 
 # Run these examples.
 EXAMPLES=""
@@ -0,0 +1,262 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Broadcasting a transaction through several nodes.
+//!
+//! [`MultiBroadcaster`] submits a transaction to each of its endpoints concurrently with
+//! `sendrawtransaction` and reports the result of each, a transaction accepted by any of them is
+//! likely to propagate. An endpoint is any `Client`, of any version, including the client of a
+//! node started with `corepc-node`.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let tx: corepc_client::bitcoin::Transaction = unimplemented!();
+//! use corepc_client::broadcast::MultiBroadcaster;
+//! use corepc_client::client_sync::{v28, v29, Auth};
+//!
+//! let primary = v29::Client::new_with_auth("http://10.0.0.1:8332", Auth::CookieFile("a".into()))?;
+//! let backup = v28::Client::new_with_auth("http://10.0.0.2:8332", Auth::CookieFile("b".into()))?;
+//! let broadcaster = MultiBroadcaster::new().add("primary", &primary).add("backup", &backup);
+//!
+//! let report = broadcaster.broadcast(&tx);
+//! match report.first_success {
+//!     Some(label) => println!("{} accepted by {}", report.txid, label),
+//!     None => println!("{} rejected: {:?}", report.txid, report.results),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+use std::{error, fmt, thread};
+
+use bitcoin::consensus::encode;
+use bitcoin::{Transaction, Txid};
+
+use crate::client_sync::{Call, Error, Result};
+
+/// The RPC error code of a transaction that is already confirmed.
+const RPC_VERIFY_ALREADY_IN_CHAIN: i32 = -27;
+
+/// The RPC error codes of a transaction rejected by the node: invalid, rejected by policy, and
+/// failing to decode.
+const RPC_REJECTED: [i32; 3] = [-25, -26, -22];
+
+/// A node a transaction can be submitted to, implemented for the `Client` of every version.
+pub trait Endpoint: Sync {
+    /// Submits the consensus encoded `tx_hex` with `sendrawtransaction`.
+    fn submit(&self, tx_hex: &str) -> Result<()>;
+}
+
+impl<C: Call + Sync> Endpoint for C {
+    fn submit(&self, tx_hex: &str) -> Result<()> {
+        let _: serde_json::Value = self.call("sendrawtransaction", &[tx_hex.into()])?;
+        Ok(())
+    }
+}
+
+/// Submits transactions to several endpoints, see the [module docs](self).
+#[derive(Default)]
+pub struct MultiBroadcaster<'a> {
+    endpoints: Vec<(String, &'a dyn Endpoint)>,
+}
+
+impl fmt::Debug for MultiBroadcaster<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let labels: Vec<_> = self.endpoints.iter().map(|(label, _)| label).collect();
+        f.debug_struct("MultiBroadcaster").field("endpoints", &labels).finish()
+    }
+}
+
+impl<'a> MultiBroadcaster<'a> {
+    /// Creates a broadcaster without endpoints.
+    pub fn new() -> Self { Self::default() }
+
+    /// Adds `endpoint`, identified by `label` in the reports.
+    pub fn add<L: Into<String>>(mut self, label: L, endpoint: &'a dyn Endpoint) -> Self {
+        self.endpoints.push((label.into(), endpoint));
+        self
+    }
+
+    /// Submits `tx` to all the endpoints concurrently, returns once all of them answered.
+    pub fn broadcast(&self, tx: &Transaction) -> BroadcastReport {
+        let tx_hex = encode::serialize_hex(tx);
+        let start = Instant::now();
+        let (sender, receiver) = mpsc::channel();
+        thread::scope(|scope| {
+            for (index, (_, endpoint)) in self.endpoints.iter().enumerate() {
+                let (sender, tx_hex) = (sender.clone(), &tx_hex);
+                scope.spawn(move || {
+                    let result = endpoint.submit(tx_hex).map(|()| Accepted::Mempool);
+                    let _ = sender.send((index, classify(result), start.elapsed()));
+                });
+            }
+        });
+        drop(sender);
+
+        // In the order the endpoints answered.
+        let mut results: Vec<(usize, EndpointResult)> = receiver
+            .into_iter()
+            .map(|(index, result, elapsed)| {
+                let label = self.endpoints[index].0.clone();
+                (index, EndpointResult { label, result, elapsed })
+            })
+            .collect();
+        let first_success = results
+            .iter()
+            .find(|(_, result)| result.result.is_ok())
+            .map(|(_, result)| result.label.clone());
+        results.sort_by_key(|(index, _)| *index);
+        let results = results.into_iter().map(|(_, result)| result).collect();
+        BroadcastReport { txid: tx.compute_txid(), results, first_success }
+    }
+}
+
+/// Turns the errors meaning the transaction is known or was rejected into their variants.
+fn classify(result: Result<Accepted>) -> std::result::Result<Accepted, BroadcastError> {
+    match result {
+        Ok(accepted) => Ok(accepted),
+        Err(Error::JsonRpc(jsonrpc::error::Error::Rpc(e)))
+            if e.code == RPC_VERIFY_ALREADY_IN_CHAIN =>
+            Ok(Accepted::Confirmed),
+        Err(Error::JsonRpc(jsonrpc::error::Error::Rpc(e))) if RPC_REJECTED.contains(&e.code) =>
+            Err(BroadcastError::Rejected { code: e.code, message: e.message }),
+        Err(e) => Err(BroadcastError::Rpc(e)),
+    }
+}
+
+/// The results of submitting a transaction to all the endpoints.
+#[derive(Debug)]
+pub struct BroadcastReport {
+    /// The id of the transaction submitted.
+    pub txid: Txid,
+    /// The result of each endpoint, in the order they were added.
+    pub results: Vec<EndpointResult>,
+    /// The label of the first endpoint that accepted the transaction, `None` if none did.
+    pub first_success: Option<String>,
+}
+
+impl BroadcastReport {
+    /// Returns `true` if any endpoint accepted the transaction.
+    pub fn is_success(&self) -> bool { self.first_success.is_some() }
+}
+
+/// The result of submitting a transaction to one endpoint.
+#[derive(Debug)]
+pub struct EndpointResult {
+    /// The label of the endpoint.
+    pub label: String,
+    /// Whether the endpoint accepted the transaction.
+    pub result: std::result::Result<Accepted, BroadcastError>,
+    /// The time from the start of the broadcast until the endpoint answered.
+    pub elapsed: Duration,
+}
+
+/// How an endpoint accepted a transaction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Accepted {
+    /// The transaction is in the mempool of the node, it was added or already there.
+    Mempool,
+    /// The transaction is already confirmed.
+    Confirmed,
+}
+
+/// Why an endpoint did not accept a transaction.
+#[derive(Debug)]
+pub enum BroadcastError {
+    /// The node rejected the transaction e.g., it is invalid, spends missing inputs or breaks a
+    /// policy rule, with the code and message of the RPC error.
+    Rejected { code: i32, message: String },
+    /// The node could not be reached or returned an unexpected error.
+    Rpc(Error),
+}
+
+impl fmt::Display for BroadcastError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            BroadcastError::Rejected { code, ref message } =>
+                write!(f, "transaction rejected ({}): {}", code, message),
+            BroadcastError::Rpc(ref e) => write!(f, "RPC error: {}", e),
+        }
+    }
+}
+
+impl error::Error for BroadcastError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match *self {
+            BroadcastError::Rejected { .. } => None,
+            BroadcastError::Rpc(ref e) => Some(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use jsonrpc::error::RpcError;
+
+    use super::*;
+
+    /// Answers `sendrawtransaction` with `result` after `delay`.
+    struct Node {
+        code: Option<i32>,
+        delay: Duration,
+    }
+
+    impl Call for Node {
+        fn call<T: for<'a> serde::de::Deserialize<'a>>(
+            &self,
+            method: &str,
+            args: &[serde_json::Value],
+        ) -> Result<T> {
+            assert_eq!(method, "sendrawtransaction");
+            thread::sleep(self.delay);
+            match self.code {
+                None => Ok(serde_json::from_value(args[0].clone())?),
+                Some(code) => Err(Error::JsonRpc(jsonrpc::error::Error::Rpc(RpcError {
+                    code,
+                    message: "rejected".to_string(),
+                    data: None,
+                }))),
+            }
+        }
+    }
+
+    fn tx() -> Transaction {
+        Transaction {
+            version: bitcoin::transaction::Version::TWO,
+            lock_time: bitcoin::absolute::LockTime::ZERO,
+            input: vec![],
+            output: vec![],
+        }
+    }
+
+    #[test]
+    fn reports_each_endpoint() {
+        let slow = Node { code: None, delay: Duration::from_millis(200) };
+        let fast = Node { code: Some(RPC_VERIFY_ALREADY_IN_CHAIN), delay: Duration::ZERO };
+        let rejecting = Node { code: Some(-26), delay: Duration::ZERO };
+        let down = Node { code: Some(-1), delay: Duration::ZERO };
+        let broadcaster = MultiBroadcaster::new()
+            .add("slow", &slow)
+            .add("fast", &fast)
+            .add("rejecting", &rejecting)
+            .add("down", &down);
+
+        let report = broadcaster.broadcast(&tx());
+        assert!(report.is_success());
+        assert_eq!(report.first_success.as_deref(), Some("fast"));
+        let labels: Vec<_> = report.results.iter().map(|r| r.label.as_str()).collect();
+        assert_eq!(labels, ["slow", "fast", "rejecting", "down"]);
+        assert!(matches!(report.results[0].result, Ok(Accepted::Mempool)));
+        assert!(matches!(report.results[1].result, Ok(Accepted::Confirmed)));
+        assert!(matches!(
+            report.results[2].result,
+            Err(BroadcastError::Rejected { code: -26, .. })
+        ));
+        assert!(matches!(report.results[3].result, Err(BroadcastError::Rpc(_))));
+
+        let report = MultiBroadcaster::new().add("rejecting", &rejecting).broadcast(&tx());
+        assert!(!report.is_success());
+        assert!(MultiBroadcaster::new().broadcast(&tx()).results.is_empty());
+    }
+}
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Middleware run around every call made by an async `Client`.
+//!
+//! The async counterpart of [`client_sync::middleware`]. Add middleware with
+//! [`ClientBuilder::middleware`], the first added is the outermost i.e., it sees the call first
+//! and the response last.
+//!
+//! [`client_sync::middleware`]: crate::client_sync::middleware
+//! [`ClientBuilder::middleware`]: crate::client_async::ClientBuilder::middleware
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Instant;
+
+pub use crate::client_sync::middleware::{Metrics, RpcRequest, RpcResponse};
+use crate::client_sync::Result;
+
+/// A boxed future that can be sent between threads, returned by [`Middleware::around`].
+pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// The function sending a call to the server, the end of the middleware chain.
+type SendFn<'a> = dyn Fn(RpcRequest<'a>) -> BoxFuture<'a, Result<RpcResponse>> + Send + Sync + 'a;
+
+/// Runs code around the calls made by an async `Client` e.g., logging, caching or fault
+/// injection.
+pub trait Middleware: Send + Sync {
+    /// Handles `req`, usually by awaiting [`Next::run`] to pass it on to the rest of the chain.
+    ///
+    /// `next` can be run more than once to repeat the call, or not at all to answer the call
+    /// without sending it to the server.
+    fn around<'a>(
+        &'a self,
+        req: RpcRequest<'a>,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<RpcResponse>>;
+}
+
+/// The rest of the middleware chain, ending with sending the call to the server.
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    middleware: &'a [Arc<dyn Middleware>],
+    send: &'a SendFn<'a>,
+}
+
+impl fmt::Debug for Next<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Next").field("remaining", &self.middleware.len()).finish()
+    }
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(middleware: &'a [Arc<dyn Middleware>], send: &'a SendFn<'a>) -> Self {
+        Self { middleware, send }
+    }
+
+    /// Passes `req` on to the next middleware, or sends it to the server if this is the last.
+    pub fn run(&self, req: RpcRequest<'a>) -> BoxFuture<'a, Result<RpcResponse>> {
+        match self.middleware.split_first() {
+            Some((first, rest)) => first.around(req, Next { middleware: rest, send: self.send }),
+            None => (self.send)(req),
+        }
+    }
+}
+
+impl Middleware for Metrics {
+    fn around<'a>(
+        &'a self,
+        req: RpcRequest<'a>,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<RpcResponse>> {
+        Box::pin(async move {
+            let start = Instant::now();
+            let result = next.run(req).await;
+            self.record(req.method, result.is_err(), start.elapsed());
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// Records the methods of the calls it sees, then passes them on.
+    #[derive(Default)]
+    struct Log(Mutex<Vec<String>>);
+
+    impl Middleware for Log {
+        fn around<'a>(
+            &'a self,
+            req: RpcRequest<'a>,
+            next: Next<'a>,
+        ) -> BoxFuture<'a, Result<RpcResponse>> {
+            self.0.lock().unwrap().push(req.method.to_owned());
+            next.run(req)
+        }
+    }
+
+    #[tokio::test]
+    async fn chain() {
+        let log = Arc::new(Log::default());
+        let metrics = Metrics::new();
+        let middleware: [Arc<dyn Middleware>; 2] = [log.clone(), Arc::new(metrics.clone())];
+
+        let sent = AtomicU32::new(0);
+        let send = |_: RpcRequest<'_>| -> BoxFuture<'_, Result<RpcResponse>> {
+            sent.fetch_add(1, Ordering::Relaxed);
+            Box::pin(async { Ok(serde_json::json!(42)) })
+        };
+        let req = RpcRequest { method: "getblockcount", args: &[] };
+        let result = Next::new(&middleware, &send).run(req).await;
+
+        assert_eq!(result.unwrap(), serde_json::json!(42));
+        assert_eq!(sent.load(Ordering::Relaxed), 1);
+        assert_eq!(*log.0.lock().unwrap(), ["getblockcount"]);
+        assert_eq!(metrics.snapshot()["getblockcount"].calls, 1);
+    }
+}
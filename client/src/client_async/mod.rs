@@ -0,0 +1,401 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Async JSON-RPC clients for specific versions of Bitcoin Core.
+//!
+//! Mirrors `client_sync`: each version module defines a `Client` with the methods of that
+//! version, returning futures instead of blocking. Built on the async `bitreq` transport, the
+//! futures are `Send` and run on `tokio`.
+//!
+//! The methods are generated from the macros of `client_sync` by the `verify` tool, leaving out
+//! the helpers that wait between calls e.g., `Client::dial`. Call the methods missing from a
+//! version with `Client::call`, returning the version specific type from `corepc_types`. The
+//! arguments, [`Auth`] and [`Error`] are shared with `client_sync`.
+//!
+//! Use [`ClientBuilder`] to set the redaction rules and to add [`middleware`].
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), corepc_client::client_async::Error> {
+//! use corepc_client::client_async::{v28::Client, Auth};
+//!
+//! let cookie = Auth::CookieFile("/home/user/.bitcoin/.cookie".into());
+//! let client = Client::new_with_auth("http://localhost:8332", cookie)?;
+//! let tip = client.best_block_hash().await?;
+//! let block = client.get_block(tip).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod middleware;
+pub mod v17;
+pub mod v18;
+pub mod v19;
+pub mod v20;
+pub mod v21;
+pub mod v22;
+pub mod v23;
+pub mod v24;
+pub mod v25;
+pub mod v26;
+pub mod v27;
+pub mod v28;
+pub mod v29;
+pub mod v30;
+pub mod v31;
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use jsonrpc::bitreq_http_async::{BitreqHttpTransport, Builder};
+
+use crate::client_async::middleware::Middleware;
+use crate::client_sync::redact::RedactionRules;
+pub use crate::client_sync::{Auth, Error, Result, Secret};
+
+/// The timeout used by a `Client` unless set with [`ClientBuilder::timeout`], the same as the
+/// default of the blocking client.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Builder for an async `Client`, obtained with `Client::builder()`.
+///
+/// ```no_run
+/// # use corepc_client::client_async::{v29::Client, Auth};
+/// # use corepc_client::client_sync::redact::RedactionRules;
+/// let client = Client::builder("http://localhost:8332")
+///     .auth(Auth::UserPass("user".into(), "pass".into()))
+///     .redaction(RedactionRules::default().result("getblocktemplate"))
+///     .build()?;
+/// # Ok::<(), corepc_client::client_async::Error>(())
+/// ```
+#[derive(Clone)]
+pub struct ClientBuilder<C> {
+    url: String,
+    path: Option<String>,
+    auth: Auth,
+    timeout: Duration,
+    headers: Vec<(String, String)>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    redaction: RedactionRules,
+    /// Constructs the version specific client from the transport, middleware and redaction rules.
+    from_parts: fn(BitreqHttpTransport, Vec<Arc<dyn Middleware>>, RedactionRules) -> C,
+}
+
+impl<C> fmt::Debug for ClientBuilder<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("url", &self.url)
+            .field("path", &self.path)
+            .field("auth", &self.auth)
+            .field("timeout", &self.timeout)
+            .field("headers", &self.headers)
+            .field("middleware", &self.middleware.len())
+            .field("redaction", &self.redaction)
+            .finish()
+    }
+}
+
+impl<C> ClientBuilder<C> {
+    pub(crate) fn new(
+        url: &str,
+        from_parts: fn(BitreqHttpTransport, Vec<Arc<dyn Middleware>>, RedactionRules) -> C,
+    ) -> Self {
+        Self {
+            url: url.to_owned(),
+            path: None,
+            auth: Auth::None,
+            timeout: DEFAULT_TIMEOUT,
+            headers: Vec::new(),
+            middleware: Vec::new(),
+            redaction: RedactionRules::default(),
+            from_parts,
+        }
+    }
+
+    /// Sets the path of the JSON-RPC endpoint, appended to the URL e.g., `/bitcoin/mainnet`.
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = Some(path.to_owned());
+        self
+    }
+
+    /// Sets the authentication method, defaults to [`Auth::None`].
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Sets the timeout after which calls abort, defaults to 60 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Adds an HTTP header to send with every call e.g., an API key.
+    pub fn header(mut self, key: &str, value: &str) -> Self {
+        self.headers.push((key.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Adds middleware to run around every call, the first added is the outermost.
+    pub fn middleware<M: Middleware + 'static>(mut self, middleware: M) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Sets the arguments and results not logged, defaults to [`RedactionRules::default`].
+    pub fn redaction(mut self, rules: RedactionRules) -> Self {
+        self.redaction = rules;
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// # Errors
+    ///
+    /// If the credentials cannot be read from the cookie file.
+    pub fn build(self) -> Result<C> {
+        let url = match self.path {
+            Some(ref path) =>
+                format!("{}/{}", self.url.trim_end_matches('/'), path.trim_start_matches('/')),
+            None => self.url.clone(),
+        };
+        let mut builder = Builder::new()
+            .url(&url)
+            .expect("jsonrpc v0.19, this function does not error")
+            .timeout(self.timeout);
+        if let (Some(user), pass) = self.auth.get_user_pass()? {
+            builder = builder.basic_auth(user, pass);
+        }
+        for (key, value) in self.headers {
+            builder = builder.header(key, value);
+        }
+        Ok((self.from_parts)(builder.build(), self.middleware, self.redaction))
+    }
+}
+
+/// Defines an async `jsonrpc::client_async::Client` using `bitreq`.
+#[macro_export]
+macro_rules! define_jsonrpc_bitreq_async_client {
+    ($version:literal) => {
+        use std::fmt;
+
+        use $crate::client_async::middleware::{BoxFuture, Middleware, Next};
+        use $crate::client_async::{Auth, ClientBuilder, Error, Result};
+        use $crate::client_sync::middleware::{RpcRequest, RpcResponse};
+
+        /// Client implements an async JSON-RPC client for the Bitcoin Core daemon or compatible
+        /// APIs.
+        pub struct Client {
+            inner: jsonrpc::client_async::Client,
+            middleware: Vec<std::sync::Arc<dyn Middleware>>,
+            redaction: $crate::client_sync::redact::RedactionRules,
+        }
+
+        impl fmt::Debug for Client {
+            fn fmt(&self, f: &mut fmt::Formatter) -> core::fmt::Result {
+                write!(
+                    f,
+                    "corepc_client::client_async::{}::Client({:?})", $version, self.inner
+                )
+            }
+        }
+
+        impl Client {
+            /// Creates a client to a bitcoind JSON-RPC server without authentication.
+            pub fn new(url: &str) -> Self {
+                Self::builder(url).build().expect("building without authentication does not error")
+            }
+
+            /// Creates a client to a bitcoind JSON-RPC server with authentication.
+            pub fn new_with_auth(url: &str, auth: Auth) -> Result<Self> {
+                if matches!(auth, Auth::None) {
+                    return Err(Error::MissingUserPassword);
+                }
+                Self::builder(url).auth(auth).build()
+            }
+
+            /// Returns a builder for a client to the bitcoind JSON-RPC server at `url`.
+            pub fn builder(url: &str) -> ClientBuilder<Self> {
+                ClientBuilder::new(url, |transport, middleware, redaction| {
+                    let inner = jsonrpc::client_async::Client::with_transport(transport);
+                    Self { inner, middleware, redaction }
+                })
+            }
+
+            /// Call an RPC `method` with given `args` list.
+            pub async fn call<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[serde_json::Value],
+            ) -> Result<T> {
+                let req = RpcRequest { method, args };
+                if self.middleware.is_empty() {
+                    // Deserialize straight into `T`, without going through `RpcResponse`.
+                    return self.send_to_server(req).await;
+                }
+                let send = |req| -> BoxFuture<'_, Result<RpcResponse>> {
+                    Box::pin(self.send_to_server::<RpcResponse>(req))
+                };
+                let result = Next::new(&self.middleware, &send).run(req).await?;
+                Ok(serde_json::from_value(result)?)
+            }
+
+            /// Sends `req` to the server, the end of the middleware chain.
+            async fn send_to_server<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                req: RpcRequest<'_>,
+            ) -> Result<T> {
+                let raw = serde_json::value::to_raw_value(req.args)?;
+                let request = self.inner.build_request(req.method, Some(&*raw));
+                if log::log_enabled!(log::Level::Debug) {
+                    let args = self.redaction.redact_args(req.method, req.args);
+                    log::debug!(target: "corepc", "request: {} {}", req.method, args);
+                }
+
+                let resp = self.inner.send_request(request).await.map_err(Error::from);
+                $crate::client_sync::log_response(
+                    req.method,
+                    &resp,
+                    self.redaction.redacts_result(req.method),
+                );
+                Ok(resp?.result()?)
+            }
+        }
+    };
+}
+
+/// Implements the async `check_expected_server_version()` on `Client`.
+///
+/// Requires `Client` to be in scope and implement `server_version()`.
+/// See and/or use `impl_async_client_v17__get_network_info`.
+///
+/// # Parameters
+///
+/// - `$expected_versions`: An vector of expected server versions e.g., `[230100, 230200]`.
+#[macro_export]
+macro_rules! impl_async_client_check_expected_server_version {
+    ($expected_versions:expr) => {
+        impl Client {
+            /// Checks that the JSON-RPC endpoint is for a `bitcoind` with the expected version.
+            pub async fn check_expected_server_version(&self) -> Result<()> {
+                let server_version = self.server_version().await?;
+                if !$expected_versions.contains(&server_version) {
+                    return Err($crate::client_sync::error::UnexpectedServerVersionError {
+                        got: server_version,
+                        expected: $expected_versions.to_vec(),
+                    })?;
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+/// Implements the async `capabilities()` on `Client`.
+///
+/// Requires `Client` to be in scope and implement `server_version()`.
+/// See and/or use `impl_async_client_v17__get_network_info`.
+#[macro_export]
+macro_rules! impl_async_client_capabilities {
+    () => {
+        impl Client {
+            /// Returns the features supported by the `bitcoind` this client is connected to.
+            pub async fn capabilities(&self) -> Result<$crate::client_sync::Capabilities> {
+                let server_version = self.server_version().await?;
+                Ok($crate::client_sync::Capabilities::from_server_version(server_version))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::thread;
+
+    use super::*;
+
+    /// Serves a single JSON-RPC request with `result`, returns the URL and the method called.
+    fn serve_one(result: serde_json::Value) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut len = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        len = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; len];
+            reader.read_exact(&mut body).unwrap();
+            let request: serde_json::Value = serde_json::from_slice(&body).unwrap();
+
+            let response =
+                serde_json::json!({ "result": result, "error": null, "id": request["id"] });
+            let response = response.to_string();
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                response.len(),
+                response
+            )
+            .unwrap();
+            request["method"].as_str().unwrap().to_owned()
+        });
+        (url, handle)
+    }
+
+    #[tokio::test]
+    async fn call_mock_server() {
+        let (url, server) = serve_one(serde_json::json!(840_000));
+        let client = v28::Client::new(&url);
+        let count = client.get_block_count().await.unwrap();
+        assert_eq!(count.0, 840_000);
+        assert_eq!(server.join().unwrap(), "getblockcount");
+    }
+
+    #[tokio::test]
+    async fn call_through_middleware() {
+        let (url, server) = serve_one(serde_json::json!(840_000));
+        let metrics = middleware::Metrics::new();
+        let client = v28::Client::builder(&url).middleware(metrics.clone()).build().unwrap();
+
+        let call = client.get_block_count();
+        fn assert_send<T: Send>(_: &T) {}
+        assert_send(&call);
+        assert_eq!(call.await.unwrap().0, 840_000);
+        assert_eq!(server.join().unwrap(), "getblockcount");
+        assert_eq!(metrics.snapshot()["getblockcount"].calls, 1);
+    }
+
+    #[test]
+    fn new() {
+        let client = v17::Client::new("http://localhost:8332");
+        let debug = format!("{:?}", client);
+        assert!(debug.starts_with("corepc_client::client_async::v17::Client("));
+        assert!(debug.contains("http://localhost:8332"));
+    }
+
+    #[test]
+    fn new_with_auth() {
+        let auth = Auth::UserPass("user".into(), "pass".into());
+        assert!(v28::Client::new_with_auth("http://localhost:8332", auth).is_ok());
+
+        let result = v28::Client::new_with_auth("http://localhost:8332", Auth::None);
+        assert!(matches!(result, Err(Error::MissingUserPassword)));
+
+        let auth = Auth::CookieFile(PathBuf::from("/nonexistent/.cookie"));
+        let result = v28::Client::new_with_auth("http://localhost:8332", auth);
+        assert!(matches!(result, Err(Error::Io(_))));
+    }
+}
@@ -0,0 +1,415 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of Bitcoin Core `v0.17`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `getblockchaininfo`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_blockchain_info {
+    () => {
+        impl Client {
+            pub async fn get_blockchain_info(&self) -> Result<GetBlockchainInfo> {
+                self.call("getblockchaininfo", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getbestblockhash`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_best_block_hash {
+    () => {
+        impl Client {
+            /// Gets the blockhash of the current chain tip.
+            pub async fn best_block_hash(&self) -> Result<bitcoin::BlockHash> {
+                let json = self.get_best_block_hash().await?;
+                Ok(json.block_hash()?)
+            }
+
+            pub async fn get_best_block_hash(&self) -> Result<GetBestBlockHash> {
+                self.call("getbestblockhash", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getblock`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_block {
+    () => {
+        impl Client {
+            /// Gets a block by blockhash.
+            pub async fn get_block(&self, hash: BlockHash) -> Result<Block> {
+                let json = self.get_block_verbose_zero(hash).await?;
+                Ok(json.block()?)
+            }
+
+            /// Gets a block by blockhash with verbose set to 0.
+            pub async fn get_block_verbose_zero(&self, hash: BlockHash) -> Result<GetBlockVerboseZero> {
+                self.call("getblock", &[into_json(hash)?, 0.into()]).await
+            }
+
+            /// Gets a block by blockhash with verbose set to 1.
+            pub async fn get_block_verbose_one(&self, hash: BlockHash) -> Result<GetBlockVerboseOne> {
+                self.call("getblock", &[into_json(hash)?, 1.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getblockcount`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_block_count {
+    () => {
+        impl Client {
+            pub async fn get_block_count(&self) -> Result<GetBlockCount> {
+                self.call("getblockcount", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getblockhash`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_block_hash {
+    () => {
+        impl Client {
+            pub async fn get_block_hash(&self, height: u64) -> Result<GetBlockHash> {
+                self.call("getblockhash", &[into_json(height)?]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getblockheader`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_block_header {
+    () => {
+        impl Client {
+            pub async fn get_block_header(&self, hash: &BlockHash) -> Result<GetBlockHeader> {
+                self.call("getblockheader", &[into_json(hash)?, into_json(false)?]).await
+            }
+
+            // This is the same as calling getblockheader with verbose==true.
+            pub async fn get_block_header_verbose(
+                &self,
+                hash: &BlockHash,
+            ) -> Result<GetBlockHeaderVerbose> {
+                self.call("getblockheader", &[into_json(hash)?]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getblockstats`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_block_stats {
+    () => {
+        impl Client {
+            pub async fn get_block_stats_by_height(
+                &self,
+                height: u32,
+                stats: Option<&[&str]>,
+            ) -> Result<GetBlockStats> {
+                self.call("getblockstats", &[into_json(height)?, into_json(stats)?]).await
+            }
+
+            pub async fn get_block_stats_by_block_hash(
+                &self,
+                hash: &BlockHash,
+                stats: Option<&[&str]>,
+            ) -> Result<GetBlockStats> {
+                self.call("getblockstats", &[into_json(hash)?, into_json(stats)?]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getchaintips`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_chain_tips {
+    () => {
+        impl Client {
+            pub async fn get_chain_tips(&self) -> Result<GetChainTips> { self.call("getchaintips", &[]).await }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getchaintxstats`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_chain_tx_stats {
+    () => {
+        impl Client {
+            pub async fn get_chain_tx_stats(&self) -> Result<GetChainTxStats> {
+                self.call("getchaintxstats", &[]).await
+            }
+
+            /// Returns the statistics of the `nblocks` blocks ending at `block_hash`.
+            pub async fn get_chain_tx_stats_window(
+                &self,
+                nblocks: u64,
+                block_hash: bitcoin::BlockHash,
+            ) -> Result<GetChainTxStats> {
+                self.call("getchaintxstats", &[nblocks.into(), into_json(block_hash)?]).await
+            }
+
+            /// Returns the average number of transactions per second in the last `window_blocks`
+            /// blocks, `0.0` if the blocks have the same timestamp.
+            pub async fn tx_rate(&self, window_blocks: u64) -> Result<f64> {
+                let stats: GetChainTxStats =
+                    self.call("getchaintxstats", &[window_blocks.into()]).await?;
+                Ok(stats.tx_rate.unwrap_or(0.0))
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getdifficulty`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_difficulty {
+    () => {
+        impl Client {
+            pub async fn get_difficulty(&self) -> Result<GetDifficulty> {
+                self.call("getdifficulty", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getmempoolancestors`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_mempool_ancestors {
+    () => {
+        impl Client {
+            pub async fn get_mempool_ancestors(&self, txid: Txid) -> Result<GetMempoolAncestors> {
+                // Equivalent to self.call("getmempoolancestors", &[into_json(txid)?, into_json(false)?]).await
+                self.call("getmempoolancestors", &[into_json(txid)?]).await
+            }
+
+            pub async fn get_mempool_ancestors_verbose(
+                &self,
+                txid: Txid,
+            ) -> Result<GetMempoolAncestorsVerbose> {
+                self.call("getmempoolancestors", &[into_json(txid)?, into_json(true)?]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getmempooldescendants`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_mempool_descendants {
+    () => {
+        impl Client {
+            pub async fn get_mempool_descendants(&self, txid: Txid) -> Result<GetMempoolDescendants> {
+                // Equivalent to self.call("getmempooldescendants", &[into_json(txid)?, into_json(false)?]).await
+                self.call("getmempooldescendants", &[into_json(txid)?]).await
+            }
+
+            pub async fn get_mempool_descendants_verbose(
+                &self,
+                txid: Txid,
+            ) -> Result<GetMempoolDescendantsVerbose> {
+                self.call("getmempooldescendants", &[into_json(txid)?, into_json(true)?]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getmempoolentry`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_mempool_entry {
+    () => {
+        impl Client {
+            pub async fn get_mempool_entry(&self, txid: Txid) -> Result<GetMempoolEntry> {
+                self.call("getmempoolentry", &[into_json(txid)?]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getmempoolinfo`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_mempool_info {
+    () => {
+        impl Client {
+            pub async fn get_mempool_info(&self) -> Result<GetMempoolInfo> {
+                self.call("getmempoolinfo", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getrawmempool`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_raw_mempool {
+    () => {
+        impl Client {
+            pub async fn get_raw_mempool(&self) -> Result<GetRawMempool> {
+                // Equivalent to self.call("getrawmempool", &[into_json(false)?]).await
+                self.call("getrawmempool", &[]).await
+            }
+
+            pub async fn get_raw_mempool_verbose(&self) -> Result<GetRawMempoolVerbose> {
+                self.call("getrawmempool", &[into_json(true)?]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `gettxout`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_tx_out {
+    () => {
+        impl Client {
+            pub async fn get_tx_out(&self, txid: Txid, vout: u64) -> Result<GetTxOut> {
+                self.call("gettxout", &[into_json(txid)?, into_json(vout)?]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `gettxoutproof`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_tx_out_proof {
+    () => {
+        impl Client {
+            /// Gets a proof that `txids` are included in a block, in `block_hash` if given.
+            pub async fn tx_out_proof(
+                &self,
+                txids: &[Txid],
+                block_hash: Option<BlockHash>,
+            ) -> Result<bitcoin::MerkleBlock> {
+                let mut args = vec![into_json(txids)?];
+                if let Some(block_hash) = block_hash {
+                    args.push(into_json(block_hash)?);
+                }
+                let hex: String = self.call("gettxoutproof", &args).await?;
+                Ok(bitcoin::consensus::encode::deserialize_hex(&hex)?)
+            }
+
+            pub async fn get_tx_out_proof(&self, txids: &[Txid]) -> Result<String> {
+                self.call("gettxoutproof", &[into_json(txids)?]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `gettxoutsetinfo`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_tx_out_set_info {
+    () => {
+        impl Client {
+            pub async fn get_tx_out_set_info(&self) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `preciousblock`.
+#[macro_export]
+macro_rules! impl_async_client_v17__precious_block {
+    () => {
+        impl Client {
+            pub async fn precious_block(&self, hash: BlockHash) -> Result<()> {
+                match self.call("preciousblock", &[into_json(hash)?]).await {
+                    Ok(serde_json::Value::Null) => Ok(()),
+                    Ok(res) => Err(Error::Returned(res.to_string())),
+                    Err(err) => Err(err.into()),
+                }
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `pruneblockchain`.
+#[macro_export]
+macro_rules! impl_async_client_v17__prune_blockchain {
+    () => {
+        impl Client {
+            /// Instructs the node to prune the blockchain up to a specified height or timestamp.
+            pub async fn prune_blockchain(&self, target: u64) -> Result<PruneBlockchain> {
+                self.call("pruneblockchain", &[target.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `savemempool`.
+#[macro_export]
+macro_rules! impl_async_client_v17__save_mempool {
+    () => {
+        impl Client {
+            pub async fn save_mempool(&self) -> Result<()> {
+                match self.call("savemempool", &[]).await {
+                    Ok(serde_json::Value::Null) => Ok(()),
+                    Ok(res) => Err(Error::Returned(res.to_string())),
+                    Err(err) => Err(err.into()),
+                }
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `scantxoutset`
+#[macro_export]
+macro_rules! impl_async_client_v17__scan_tx_out_set {
+    () => {
+        impl Client {
+            /// Aborts an ongoing `scantxoutset` scan.
+            pub async fn scan_tx_out_set_abort(&self) -> Result<ScanTxOutSetAbort> {
+                self.call("scantxoutset", &[into_json("abort")?]).await
+            }
+
+            /// Starts a scan of the UTXO set for specified descriptors.
+            pub async fn scan_tx_out_set_start(
+                &self,
+                scan_objects: &[&str],
+            ) -> Result<ScanTxOutSetStart> {
+                self.call("scantxoutset", &[into_json("start")?, into_json(scan_objects)?]).await
+            }
+
+            /// Checks the status of an ongoing `scantxoutset` scan.
+            pub async fn scan_tx_out_set_status(&self) -> Result<Option<ScanTxOutSetStatus>> {
+                self.call("scantxoutset", &[into_json("status")?]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `verifychain`
+#[macro_export]
+macro_rules! impl_async_client_v17__verify_chain {
+    () => {
+        impl Client {
+            pub async fn verify_chain(&self) -> Result<VerifyChain> { self.call("verifychain", &[]).await }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `verifytxoutproof`.
+#[macro_export]
+macro_rules! impl_async_client_v17__verify_tx_out_proof {
+    () => {
+        impl Client {
+            // `proof` is the proof returned by `tx_out_proof`.
+            pub async fn verify_tx_out_proof(
+                &self,
+                proof: &bitcoin::MerkleBlock,
+            ) -> Result<VerifyTxOutProof> {
+                let hex = bitcoin::consensus::encode::serialize_hex(proof);
+                self.call("verifytxoutproof", &[into_json(hex)?]).await
+            }
+        }
+    };
+}
@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Control ==` section of the
+//! API docs of Bitcoin Core `v0.17`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `getmemoryinfo`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_memory_info {
+    () => {
+        impl Client {
+            pub async fn get_memory_info(&self) -> Result<GetMemoryInfoStats> {
+                self.call("getmemoryinfo", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `help`.
+#[macro_export]
+macro_rules! impl_async_client_v17__help {
+    () => {
+        impl Client {
+            pub async fn help(&self) -> Result<String> { self.call("help", &[]).await }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `logging`.
+#[macro_export]
+macro_rules! impl_async_client_v17__logging {
+    () => {
+        impl Client {
+            pub async fn logging(&self) -> Result<Logging> { self.call("logging", &[]).await }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `stop`.
+#[macro_export]
+macro_rules! impl_async_client_v17__stop {
+    () => {
+        impl Client {
+            pub async fn stop(&self) -> Result<String> { self.call("stop", &[]).await }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `uptime`.
+#[macro_export]
+macro_rules! impl_async_client_v17__uptime {
+    () => {
+        impl Client {
+            pub async fn uptime(&self) -> Result<u32> { self.call("uptime", &[]).await }
+        }
+    };
+}
@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Hidden ==` section of the
+//! API docs of Bitcoin Core `v0.17`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `estimaterawfee`.
+#[macro_export]
+macro_rules! impl_async_client_v17__estimate_raw_fee {
+    () => {
+        impl Client {
+            /// # Panics
+            ///
+            /// * Panics if `conf_target` is outside the range [1, 1008].
+            pub async fn estimate_raw_fee(&self, conf_target: u32) -> Result<EstimateRawFee> {
+                assert!(
+                    (1..=1008).contains(&conf_target),
+                    "invalid conf_target, must be between 1 and 1008 inclusive"
+                );
+
+                self.call("estimaterawfee", &[conf_target.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `waitforblock`.
+#[macro_export]
+macro_rules! impl_async_client_v17__wait_for_block {
+    () => {
+        impl Client {
+            pub async fn wait_for_block(&self, hash: &bitcoin::BlockHash) -> Result<WaitForBlock> {
+                self.call("waitforblock", &[into_json(hash)?]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `waitforblockheight`.
+#[macro_export]
+macro_rules! impl_async_client_v17__wait_for_block_height {
+    () => {
+        impl Client {
+            pub async fn wait_for_block_height(&self, height: u64) -> Result<WaitForBlockHeight> {
+                self.call("waitforblockheight", &[into_json(height)?]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `waitfornewblock`.
+#[macro_export]
+macro_rules! impl_async_client_v17__wait_for_new_block {
+    () => {
+        impl Client {
+            pub async fn wait_for_new_block(&self) -> Result<WaitForNewBlock> {
+                self.call("waitfornewblock", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `syncwithvalidationinterfacequeue`.
+#[macro_export]
+macro_rules! impl_async_client_v17__sync_with_validation_interface_queue {
+    () => {
+        impl Client {
+            pub async fn sync_with_validation_interface_queue(&self) -> Result<()> {
+                self.call("syncwithvalidationinterfacequeue", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `reconsiderblock`.
+#[macro_export]
+macro_rules! impl_async_client_v17__reconsider_block {
+    () => {
+        impl Client {
+            pub async fn reconsider_block(&self, block_hash: bitcoin::BlockHash) -> Result<()> {
+                self.call("reconsiderblock", &[into_json(block_hash)?]).await
+            }
+        }
+    };
+}
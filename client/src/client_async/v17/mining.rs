@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Mining ==` section of the
+//! API docs of Bitcoin Core `v0.17`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `getblocktemplate`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_block_template {
+    () => {
+        impl Client {
+            pub async fn get_block_template(
+                &self,
+                request: &TemplateRequest,
+            ) -> Result<GetBlockTemplate> {
+                self.call("getblocktemplate", &[into_json(request)?]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getmininginfo`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_mining_info {
+    () => {
+        impl Client {
+            pub async fn get_mining_info(&self) -> Result<GetMiningInfo> {
+                self.call("getmininginfo", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getnetworkhashps`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_network_hashes_per_second {
+    () => {
+        impl Client {
+            pub async fn get_network_hash_ps(&self) -> Result<f64> { self.call("getnetworkhashps", &[]).await }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `prioritisetransaction`.
+#[macro_export]
+macro_rules! impl_async_client_v17__prioritise_transaction {
+    () => {
+        impl Client {
+            pub async fn prioritise_transaction(
+                &self,
+                txid: &Txid,
+                fee_delta: bitcoin::SignedAmount,
+            ) -> Result<bool> {
+                let sats = fee_delta.to_sat();
+                self.call("prioritisetransaction", &[into_json(txid)?, 0.into(), sats.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `submitblock`.
+#[macro_export]
+macro_rules! impl_async_client_v17__submit_block {
+    () => {
+        impl Client {
+            pub async fn submit_block(&self, block: &Block) -> Result<()> {
+                let hex: String = bitcoin::consensus::encode::serialize_hex(block);
+                match self.call("submitblock", &[into_json(hex)?]).await {
+                    Ok(serde_json::Value::Null) => Ok(()),
+                    Ok(res) => Err(Error::Returned(res.to_string())),
+                    Err(err) => Err(err.into()),
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! An async JSON-RPC client for Bitcoin Core `v0.17`.
+//!
+//! Generated by the `verify` tool from `client_sync::v17`, do not edit. Regenerate by running
+//! `cargo run -- async-client` in the `verify` directory.
+
+pub mod blockchain;
+pub mod control;
+pub mod generating;
+pub mod hidden;
+pub mod mining;
+pub mod network;
+pub mod raw_transactions;
+pub mod util;
+pub mod wallet;
+pub mod zmq;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use bitcoin::address::{Address, NetworkChecked};
+use bitcoin::{sign_message, Amount, Block, BlockHash, PublicKey, Txid};
+
+use crate::client_sync::into_json;
+use crate::types::v17::*;
+
+#[rustfmt::skip]                // Keep public re-exports separate.
+pub use crate::client_sync::v17::{AddressType, TemplateRequest, TemplateRules, Input, Output, WalletCreateFundedPsbtInput, AddNodeCommand, SetBanCommand, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, FeeEstimateMode};
+
+// This publicly re-exports `Client`.
+crate::define_jsonrpc_bitreq_async_client!("v17");
+crate::impl_async_client_check_expected_server_version!({ [170200] });
+crate::impl_async_client_capabilities!();
+
+// == Blockchain ==
+crate::impl_async_client_v17__get_best_block_hash!();
+crate::impl_async_client_v17__get_block!();
+crate::impl_async_client_v17__get_blockchain_info!();
+crate::impl_async_client_v17__get_block_count!();
+crate::impl_async_client_v17__get_block_hash!();
+crate::impl_async_client_v17__get_block_header!();
+crate::impl_async_client_v17__get_block_stats!();
+crate::impl_async_client_v17__get_chain_tips!();
+crate::impl_async_client_v17__get_chain_tx_stats!();
+crate::impl_async_client_v17__get_difficulty!();
+crate::impl_async_client_v17__get_mempool_ancestors!();
+crate::impl_async_client_v17__get_mempool_descendants!();
+crate::impl_async_client_v17__get_mempool_entry!();
+crate::impl_async_client_v17__get_mempool_info!();
+crate::impl_async_client_v17__get_raw_mempool!();
+crate::impl_async_client_v17__get_tx_out!();
+crate::impl_async_client_v17__get_tx_out_proof!();
+crate::impl_async_client_v17__get_tx_out_set_info!();
+crate::impl_async_client_v17__precious_block!();
+crate::impl_async_client_v17__prune_blockchain!();
+crate::impl_async_client_v17__save_mempool!();
+crate::impl_async_client_v17__scan_tx_out_set!();
+crate::impl_async_client_v17__verify_chain!();
+crate::impl_async_client_v17__verify_tx_out_proof!();
+
+// == Control ==
+crate::impl_async_client_v17__get_memory_info!();
+crate::impl_async_client_v17__help!();
+crate::impl_async_client_v17__logging!();
+crate::impl_async_client_v17__stop!();
+crate::impl_async_client_v17__uptime!();
+
+// == Generating ==
+crate::impl_async_client_v17__generate_to_address!();
+crate::impl_async_client_v17__generate!();
+crate::impl_async_client_v17__invalidate_block!();
+
+// == Hidden ==
+crate::impl_async_client_v17__estimate_raw_fee!();
+crate::impl_async_client_v17__wait_for_block!();
+crate::impl_async_client_v17__wait_for_block_height!();
+crate::impl_async_client_v17__wait_for_new_block!();
+crate::impl_async_client_v17__sync_with_validation_interface_queue!();
+crate::impl_async_client_v17__reconsider_block!();
+
+// == Mining ==
+crate::impl_async_client_v17__get_block_template!();
+crate::impl_async_client_v17__get_mining_info!();
+crate::impl_async_client_v17__get_network_hashes_per_second!();
+crate::impl_async_client_v17__prioritise_transaction!();
+crate::impl_async_client_v17__submit_block!();
+
+// == Network ==
+crate::impl_async_client_v17__add_node!();
+crate::impl_async_client_v17__clear_banned!();
+crate::impl_async_client_v17__disconnect_node!();
+crate::impl_async_client_v17__get_added_node_info!();
+crate::impl_async_client_v17__get_connection_count!();
+crate::impl_async_client_v17__get_net_totals!();
+crate::impl_async_client_v17__get_network_info!();
+crate::impl_async_client_v17__get_peer_info!();
+crate::impl_async_client_v17__list_banned!();
+crate::impl_async_client_v17__ping!();
+crate::impl_async_client_v17__set_ban!();
+crate::impl_async_client_v17__set_network_active!();
+
+// == Rawtransactions ==
+crate::impl_async_client_v17__combine_psbt!();
+crate::impl_async_client_v17__combine_raw_transaction!();
+crate::impl_async_client_v17__convert_to_psbt!();
+crate::impl_async_client_v17__create_psbt!();
+crate::impl_async_client_v17__create_raw_transaction!();
+crate::impl_async_client_v17__decode_psbt!();
+crate::impl_async_client_v17__decode_raw_transaction!();
+crate::impl_async_client_v17__decode_script!();
+crate::impl_async_client_v17__finalize_psbt!();
+crate::impl_async_client_v17__fund_raw_transaction!();
+crate::impl_async_client_v17__get_raw_transaction!();
+crate::impl_async_client_v17__send_raw_transaction!();
+crate::impl_async_client_v17__sign_raw_transaction!();
+crate::impl_async_client_v17__sign_raw_transaction_with_key!();
+crate::impl_async_client_v17__test_mempool_accept!();
+
+// == Util ==
+crate::impl_async_client_v17__create_multisig!();
+crate::impl_async_client_v17__estimate_smart_fee!();
+crate::impl_async_client_v17__sign_message_with_priv_key!();
+crate::impl_async_client_v17__validate_address!();
+crate::impl_async_client_v17__verify_message!();
+
+// == Wallet ==
+crate::impl_async_client_v17__abandon_transaction!();
+crate::impl_async_client_v17__abort_rescan!();
+crate::impl_async_client_v17__add_multisig_address!();
+crate::impl_async_client_v17__backup_wallet!();
+crate::impl_async_client_v17__bump_fee!();
+crate::impl_async_client_v17__create_wallet!();
+crate::impl_async_client_v17__dump_priv_key!();
+crate::impl_async_client_v17__dump_wallet!();
+crate::impl_async_client_v17__encrypt_wallet!();
+crate::impl_async_client_v17__get_addresses_by_label!();
+crate::impl_async_client_v17__get_address_info!();
+crate::impl_async_client_v17__get_balance!();
+crate::impl_async_client_v17__get_new_address!();
+crate::impl_async_client_v17__get_raw_change_address!();
+crate::impl_async_client_v17__get_received_by_address!();
+crate::impl_async_client_v17__get_transaction!();
+crate::impl_async_client_v17__get_unconfirmed_balance!();
+crate::impl_async_client_v17__get_wallet_info!();
+crate::impl_async_client_v17__import_address!();
+crate::impl_async_client_v17__import_multi!();
+crate::impl_async_client_v17__import_privkey!();
+crate::impl_async_client_v17__import_pruned_funds!();
+crate::impl_async_client_v17__import_pubkey!();
+crate::impl_async_client_v17__import_wallet!();
+crate::impl_async_client_v17__key_pool_refill!();
+crate::impl_async_client_v17__list_address_groupings!();
+crate::impl_async_client_v17__list_labels!();
+crate::impl_async_client_v17__list_lock_unspent!();
+crate::impl_async_client_v17__list_received_by_address!();
+crate::impl_async_client_v17__list_since_block!();
+crate::impl_async_client_v17__list_transactions!();
+crate::impl_async_client_v17__list_unspent!();
+crate::impl_async_client_v17__list_wallets!();
+crate::impl_async_client_v17__load_wallet!();
+crate::impl_async_client_v17__lock_unspent!();
+crate::impl_async_client_v17__remove_pruned_funds!();
+crate::impl_async_client_v17__rescan_blockchain!();
+crate::impl_async_client_v17__send_many!();
+crate::impl_async_client_v17__send_to_address!();
+crate::impl_async_client_v17__set_hd_seed!();
+crate::impl_async_client_v17__set_tx_fee!();
+crate::impl_async_client_v17__sign_message!();
+crate::impl_async_client_v17__sign_raw_transaction_with_wallet!();
+crate::impl_async_client_v17__unload_wallet!();
+crate::impl_async_client_v17__wallet_create_funded_psbt!();
+crate::impl_async_client_v17__wallet_lock!();
+crate::impl_async_client_v17__wallet_passphrase!();
+crate::impl_async_client_v17__wallet_passphrase_change!();
+crate::impl_async_client_v17__wallet_process_psbt!();
+
+// == Zmq ==
+crate::impl_async_client_v17__get_zmq_notifications!();
@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Network ==` section of the
+//! API docs of Bitcoin Core `v0.17`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `addnode`.
+#[macro_export]
+macro_rules! impl_async_client_v17__add_node {
+    () => {
+        impl Client {
+            pub async fn add_node(&self, node: &str, command: AddNodeCommand) -> Result<()> {
+                match self.call("addnode", &[into_json(node)?, into_json(command)?]).await {
+                    Ok(serde_json::Value::Null) => Ok(()),
+                    Ok(res) => Err(Error::Returned(res.to_string())),
+                    Err(err) => Err(err.into()),
+                }
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `clearbanned`.
+#[macro_export]
+macro_rules! impl_async_client_v17__clear_banned {
+    () => {
+        impl Client {
+            pub async fn clear_banned(&self) -> Result<()> {
+                match self.call("clearbanned", &[]).await {
+                    Ok(serde_json::Value::Null) => Ok(()),
+                    Ok(res) => Err(Error::Returned(res.to_string())),
+                    Err(err) => Err(err.into()),
+                }
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `disconnectnode`.
+#[macro_export]
+macro_rules! impl_async_client_v17__disconnect_node {
+    () => {
+        impl Client {
+            pub async fn disconnect_node(&self, address: &str) -> Result<()> {
+                match self.call("disconnectnode", &[into_json(address)?]).await {
+                    Ok(serde_json::Value::Null) => Ok(()),
+                    Ok(res) => Err(Error::Returned(res.to_string())),
+                    Err(err) => Err(err.into()),
+                }
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getaddednodeinfo`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_added_node_info {
+    () => {
+        impl Client {
+            pub async fn get_added_node_info(&self) -> Result<GetAddedNodeInfo> {
+                self.call("getaddednodeinfo", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getconnectioncount`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_connection_count {
+    () => {
+        impl Client {
+            pub async fn get_connection_count(&self) -> Result<GetConnectionCount> {
+                self.call("getconnectioncount", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getnettotals`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_net_totals {
+    () => {
+        impl Client {
+            pub async fn get_net_totals(&self) -> Result<GetNetTotals> { self.call("getnettotals", &[]).await }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getnetworkinfo`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_network_info {
+    () => {
+        impl Client {
+            /// Returns the server version field of `GetNetworkInfo`.
+            pub async fn server_version(&self) -> Result<usize> {
+                let info = self.get_network_info().await?;
+                Ok(info.version)
+            }
+
+            pub async fn get_network_info(&self) -> Result<GetNetworkInfo> {
+                self.call("getnetworkinfo", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getpeerinfo`.
+#[macro_export]
+macro_rules! impl_async_client_v17__get_peer_info {
+    () => {
+        impl Client {
+            pub async fn get_peer_info(&self) -> Result<GetPeerInfo> { self.call("getpeerinfo", &[]).await }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `listbanned`.
+#[macro_export]
+macro_rules! impl_async_client_v17__list_banned {
+    () => {
+        impl Client {
+            pub async fn list_banned(&self) -> Result<ListBanned> { self.call("listbanned", &[]).await }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `ping`.
+#[macro_export]
+macro_rules! impl_async_client_v17__ping {
+    () => {
+        impl Client {
+            pub async fn ping(&self) -> Result<()> {
+                match self.call("ping", &[]).await {
+                    Ok(serde_json::Value::Null) => Ok(()),
+                    Ok(res) => Err(Error::Returned(res.to_string())),
+                    Err(err) => Err(err.into()),
+                }
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `setban`.
+#[macro_export]
+macro_rules! impl_async_client_v17__set_ban {
+    () => {
+        impl Client {
+            pub async fn set_ban(&self, subnet: &str, command: SetBanCommand) -> Result<()> {
+                match self.call("setban", &[into_json(subnet)?, into_json(command)?]).await {
+                    Ok(serde_json::Value::Null) => Ok(()),
+                    Ok(res) => Err(Error::Returned(res.to_string())),
+                    Err(err) => Err(err.into()),
+                }
+            }
+
+            /// Bans `subnet` for `duration`, rounded down to whole seconds.
+            ///
+            /// A `duration` of less than a second bans for the node's default `-bantime`.
+            pub async fn ban(&self, subnet: &str, duration: std::time::Duration) -> Result<()> {
+                let args = [
+                    into_json(subnet)?,
+                    into_json(SetBanCommand::Add)?,
+                    into_json(duration.as_secs())?,
+                ];
+                match self.call("setban", &args).await {
+                    Ok(serde_json::Value::Null) => Ok(()),
+                    Ok(res) => Err(Error::Returned(res.to_string())),
+                    Err(err) => Err(err.into()),
+                }
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `setnetworkactive`.
+#[macro_export]
+macro_rules! impl_async_client_v17__set_network_active {
+    () => {
+        impl Client {
+            pub async fn set_network_active(&self, state: bool) -> Result<SetNetworkActive> {
+                self.call("setnetworkactive", &[into_json(state)?]).await
+            }
+        }
+    };
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Mining ==` section of the
+//! API docs of Bitcoin Core `v0.18`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `submitheader`.
+#[macro_export]
+macro_rules! impl_async_client_v18__submit_header {
+    () => {
+        impl Client {
+            pub async fn submit_header(&self, header: &bitcoin::block::Header) -> Result<()> {
+                let hexdata = bitcoin::consensus::encode::serialize_hex(header);
+                match self.call("submitheader", &[hexdata.into()]).await {
+                    Ok(serde_json::Value::Null) => Ok(()),
+                    Ok(res) => Err(Error::Returned(res.to_string())),
+                    Err(err) => Err(err.into()),
+                }
+            }
+        }
+    };
+}
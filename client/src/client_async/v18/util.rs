@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Util ==` section of the
+//! API docs of Bitcoin Core `v0.18`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `deriveaddresses`.
+#[macro_export]
+macro_rules! impl_async_client_v18__derive_addresses {
+    () => {
+        impl Client {
+            pub async fn derive_addresses(&self, descriptor: &str) -> Result<DeriveAddresses> {
+                self.call("deriveaddresses", &[descriptor.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getdescriptorinfo`.
+#[macro_export]
+macro_rules! impl_async_client_v18__get_descriptor_info {
+    () => {
+        impl Client {
+            pub async fn get_descriptor_info(&self, descriptor: &str) -> Result<GetDescriptorInfo> {
+                self.call("getdescriptorinfo", &[descriptor.into()]).await
+            }
+        }
+    };
+}
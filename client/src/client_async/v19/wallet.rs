@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of Bitcoin Core `v0.19`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `getbalances`.
+#[macro_export]
+macro_rules! impl_async_client_v19__get_balances {
+    () => {
+        impl Client {
+            pub async fn get_balances(&self) -> Result<GetBalances> { self.call("getbalances", &[]).await }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `setwalletflag`.
+#[macro_export]
+macro_rules! impl_async_client_v19__set_wallet_flag {
+    () => {
+        impl Client {
+            pub async fn set_wallet_flag(&self, flag: &str) -> Result<SetWalletFlag> {
+                self.call("setwalletflag", &[into_json(flag)?]).await
+            }
+        }
+    };
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Generating ==` section of the
+//! API docs of Bitcoin Core `v0.20`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `generatetodescriptor`.
+#[macro_export]
+macro_rules! impl_async_client_v20__generate_to_descriptor {
+    () => {
+        impl Client {
+            pub async fn generate_to_descriptor(
+                &self,
+                nblocks: usize,
+                descriptor: &str,
+            ) -> Result<GenerateToDescriptor> {
+                self.call("generatetodescriptor", &[nblocks.into(), descriptor.into()]).await
+            }
+        }
+    };
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Hidden ==` section of the
+//! API docs of Bitcoin Core `v0.20`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `mockscheduler`.
+#[macro_export]
+macro_rules! impl_async_client_v20__mock_scheduler {
+    () => {
+        impl Client {
+            pub async fn mock_scheduler(&self, delta_time: u64) -> Result<()> {
+                self.call("mockscheduler", &[into_json(delta_time)?]).await
+            }
+        }
+    };
+}
@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of Bitcoin Core `v24`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `gettxspendingprevout`
+#[macro_export]
+macro_rules! impl_async_client_v24__get_tx_spending_prevout {
+    () => {
+        impl Client {
+            pub async fn get_tx_spending_prevout(
+                &self,
+                outputs: &[bitcoin::OutPoint],
+            ) -> Result<GetTxSpendingPrevout> {
+                let json_outputs: Vec<_> = outputs.iter().map(|out| {
+                    serde_json::json!({
+                        "txid": out.txid.to_string(),
+                        "vout": out.vout,
+                    })
+                }).collect();
+                self.call("gettxspendingprevout", &[json_outputs.into()]).await
+            }
+
+            /// Returns the txid of the mempool transaction spending `outpoint`, `None` if unspent.
+            pub async fn is_spent_in_mempool(
+                &self,
+                outpoint: bitcoin::OutPoint,
+            ) -> Result<Option<bitcoin::Txid>> {
+                let spent = self.spent_in_mempool(&[outpoint]).await?;
+                Ok(spent.get(&outpoint).copied())
+            }
+
+            /// Returns the txids of the mempool transactions spending any of `outpoints`.
+            ///
+            /// Outpoints not spent by a transaction in the mempool are omitted.
+            pub async fn spent_in_mempool(
+                &self,
+                outpoints: &[bitcoin::OutPoint],
+            ) -> Result<std::collections::BTreeMap<bitcoin::OutPoint, bitcoin::Txid>> {
+                let model = self
+                    .get_tx_spending_prevout(outpoints).await?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::Model(Box::new(e)))?;
+                let spent = model
+                    .0
+                    .into_iter()
+                    .filter_map(|item| item.spending_txid.map(|txid| (item.outpoint, txid)))
+                    .collect();
+                Ok(spent)
+            }
+        }
+    };
+}
@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Wallet ==` section of the
+//! API docs of Bitcoin Core `v24`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `migratewallet`.
+#[macro_export]
+macro_rules! impl_async_client_v24__migrate_wallet {
+    () => {
+        impl Client {
+            pub async fn migrate_wallet(&self, wallet_name: &str) -> Result<MigrateWallet> {
+                self.call("migratewallet", &[wallet_name.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `sendall`.
+#[macro_export]
+macro_rules! impl_async_client_v24__send_all {
+    () => {
+        /// Returns the PSBT of a `sendall` dry run, without the signatures added by the wallet.
+        fn sweep_psbt(json: SendAll) -> Result<bitcoin::Psbt> {
+            let psbt = json.psbt.ok_or(Error::UnexpectedStructure)?;
+            let mut psbt: bitcoin::Psbt = psbt.parse().map_err(|_| Error::UnexpectedStructure)?;
+            for input in &mut psbt.inputs {
+                input.partial_sigs.clear();
+                input.final_script_sig = None;
+                input.final_script_witness = None;
+                input.tap_key_sig = None;
+                input.tap_script_sigs.clear();
+            }
+            Ok(psbt)
+        }
+
+        impl Client {
+            pub async fn send_all(&self, recipients: &[Address]) -> Result<SendAll> {
+                self.call("sendall", &[into_json(recipients)?]).await
+            }
+
+            /// Sends the entire wallet balance to `address` at `fee_rate` using `sendall`.
+            pub async fn sweep_to(
+                &self,
+                address: &Address,
+                fee_rate: bitcoin::FeeRate,
+            ) -> Result<SendAll> {
+                self.sweep(address, fee_rate, &[], false).await
+            }
+
+            /// Dry run of [`Client::sweep_to`], returns the unsigned PSBT without broadcasting.
+            ///
+            /// `sendall` signs the PSBT if the wallet has the private keys, the signatures are
+            /// stripped so that it can be signed elsewhere e.g., with `walletprocesspsbt`.
+            pub async fn sweep_to_psbt(
+                &self,
+                address: &Address,
+                fee_rate: bitcoin::FeeRate,
+            ) -> Result<bitcoin::Psbt> {
+                let json = self.sweep(address, fee_rate, &[], true).await?;
+                sweep_psbt(json)
+            }
+
+            /// Sends the coins received on any of `descriptors` to `address` at `fee_rate`.
+            ///
+            /// Descriptors are matched against the `parent_descs` of `listunspent`, ignoring
+            /// checksums.
+            pub async fn sweep_descriptors_to(
+                &self,
+                descriptors: &[&str],
+                address: &Address,
+                fee_rate: bitcoin::FeeRate,
+            ) -> Result<SendAll> {
+                self.sweep(address, fee_rate, descriptors, false).await
+            }
+
+            /// Dry run of [`Client::sweep_descriptors_to`], returns the unsigned PSBT without broadcasting.
+            ///
+            /// `sendall` signs the PSBT if the wallet has the private keys, the signatures are
+            /// stripped so that it can be signed elsewhere e.g., with `walletprocesspsbt`.
+            pub async fn sweep_descriptors_to_psbt(
+                &self,
+                descriptors: &[&str],
+                address: &Address,
+                fee_rate: bitcoin::FeeRate,
+            ) -> Result<bitcoin::Psbt> {
+                let json = self.sweep(address, fee_rate, descriptors, true).await?;
+                sweep_psbt(json)
+            }
+
+            /// Calls `sendall`, spending only coins from `descriptors` unless it is empty.
+            async fn sweep(
+                &self,
+                address: &Address,
+                fee_rate: bitcoin::FeeRate,
+                descriptors: &[&str],
+                dry_run: bool,
+            ) -> Result<SendAll> {
+                let strip = |desc: &str| desc.split('#').next().unwrap_or_default().to_string();
+                let mut options = serde_json::Map::new();
+                if dry_run {
+                    options.insert("psbt".to_string(), true.into());
+                    options.insert("add_to_wallet".to_string(), false.into());
+                }
+                if !descriptors.is_empty() {
+                    let wanted = descriptors.iter().map(|d| strip(d)).collect::<Vec<_>>();
+                    let inputs = self
+                        .list_unspent().await?
+                        .0
+                        .into_iter()
+                        .filter(|utxo| {
+                            utxo.parent_descriptors.as_ref().is_some_and(|parents| {
+                                parents.iter().any(|p| wanted.contains(&strip(p)))
+                            })
+                        })
+                        .map(|utxo| serde_json::json!({ "txid": utxo.txid, "vout": utxo.vout }))
+                        .collect::<Vec<_>>();
+                    if inputs.is_empty() {
+                        return Err(Error::Returned("no unspent outputs for descriptors".into()));
+                    }
+                    options.insert("inputs".to_string(), inputs.into());
+                }
+                let args = [
+                    into_json([address])?,
+                    serde_json::Value::Null,
+                    "unset".into(),
+                    $crate::client_sync::sat_per_vb(fee_rate).into(),
+                    options.into(),
+                ];
+                self.call("sendall", &args).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `simulaterawtransaction`.
+#[macro_export]
+macro_rules! impl_async_client_v24__simulate_raw_transaction {
+    () => {
+        impl Client {
+            pub async fn simulate_raw_transaction(
+                &self,
+                rawtxs: &[String],
+            ) -> Result<SimulateRawTransaction> {
+                self.call("simulaterawtransaction", &[into_json(rawtxs)?]).await
+            }
+        }
+    };
+}
@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of Bitcoin Core `v25`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `scanblocks`
+#[macro_export]
+macro_rules! impl_async_client_v25__scan_blocks {
+    () => {
+        impl Client {
+            /// Aborts an ongoing `scanblocks` scan.
+            pub async fn scan_blocks_abort(&self) -> Result<ScanBlocksAbort> {
+                self.call("scanblocks", &[into_json("abort")?]).await
+            }
+
+            /// Starts a scan of blocks for specified descriptors.
+            pub async fn scan_blocks_start(&self, scan_objects: &[&str]) -> Result<ScanBlocksStart> {
+                self.call("scanblocks", &[into_json("start")?, into_json(scan_objects)?]).await
+            }
+
+            /// Checks the status of an ongoing `scanblocks` scan.
+            pub async fn scan_blocks_status(&self) -> Result<Option<ScanBlocksStatus>> {
+                self.call("scanblocks", &[into_json("status")?]).await
+            }
+        }
+    };
+}
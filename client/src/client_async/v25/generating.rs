@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Generating ==` section of the
+//! API docs of Bitcoin Core `v25`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `generateblock`.
+#[macro_export]
+macro_rules! impl_async_client_v25__generate_block {
+    () => {
+        impl Client {
+            pub async fn generate_block(
+                &self,
+                output: &str,
+                transactions: &[String],
+                submit: bool,
+            ) -> Result<GenerateBlock> {
+                self.call(
+                    "generateblock",
+                    &[into_json(output)?, into_json(transactions)?, into_json(submit)?],
+                ).await
+            }
+        }
+    };
+}
@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of Bitcoin Core `v26`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `dumptxoutset`.
+#[macro_export]
+macro_rules! impl_async_client_v26__dump_tx_out_set {
+    () => {
+        impl Client {
+            pub async fn dump_tx_out_set(&self, path: &str) -> Result<DumpTxOutSet> {
+                self.call("dumptxoutset", &[path.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getchainstates`.
+#[macro_export]
+macro_rules! impl_async_client_v26__get_chain_states {
+    () => {
+        impl Client {
+            pub async fn get_chain_states(&self) -> Result<GetChainStates> {
+                self.call("getchainstates", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `gettxoutsetinfo`.
+#[macro_export]
+macro_rules! impl_async_client_v26__get_tx_out_set_info {
+    () => {
+        impl Client {
+            pub async fn get_tx_out_set_info(&self) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `importmempool`.
+#[macro_export]
+macro_rules! impl_async_client_v26__import_mempool {
+    () => {
+        impl Client {
+            pub async fn import_mempool(&self, filepath: &str) -> Result<()> {
+                match self.call("importmempool", &[filepath.into()]).await {
+                    Ok(serde_json::Value::Object(ref map)) if map.is_empty() => Ok(()),
+                    Ok(res) => Err(Error::Returned(res.to_string())),
+                    Err(err) => Err(err.into()),
+                }
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `loadtxoutset`.
+#[macro_export]
+macro_rules! impl_async_client_v26__load_tx_out_set {
+    () => {
+        impl Client {
+            pub async fn load_tx_out_set(&self, path: &str) -> Result<LoadTxOutSet> {
+                self.call("loadtxoutset", &[path.into()]).await
+            }
+        }
+    };
+}
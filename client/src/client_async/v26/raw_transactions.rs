@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of Bitcoin Core `v26`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `submitpackage`.
+#[macro_export]
+macro_rules! impl_async_client_v26__submit_package {
+    () => {
+        impl Client {
+            pub async fn submit_package(
+                &self,
+                package: &[bitcoin::Transaction],
+            ) -> Result<SubmitPackage> {
+                let package_txs = package
+                    .into_iter()
+                    .map(|tx| bitcoin::consensus::encode::serialize_hex(tx))
+                    .collect::<Vec<_>>();
+                self.call("submitpackage", &[package_txs.into()]).await
+            }
+        }
+    };
+}
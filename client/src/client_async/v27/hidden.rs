@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Hidden ==` section of the
+//! API docs of Bitcoin Core `v27`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `addconnection`.
+#[macro_export]
+macro_rules! impl_async_client_v27__add_connection {
+    () => {
+        impl Client {
+            pub async fn add_connection(
+                &self,
+                address: &str,
+                connection_type: &str,
+                v2transport: bool,
+            ) -> Result<AddConnection> {
+                self.call(
+                    "addconnection",
+                    &[into_json(address)?, into_json(connection_type)?, into_json(v2transport)?],
+                ).await
+            }
+        }
+    };
+}
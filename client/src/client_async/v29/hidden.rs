@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Hidden ==` section of the
+//! API docs of Bitcoin Core `v29`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `getorphantxs` verbosity level 0.
+#[macro_export]
+macro_rules! impl_async_client_v29__get_orphan_txs {
+    () => {
+        impl Client {
+            pub async fn get_orphan_txs(&self) -> Result<GetOrphanTxs> { self.call("getorphantxs", &[]).await }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getorphantxs` verbosity level 1.
+#[macro_export]
+macro_rules! impl_async_client_v29__get_orphan_txs_verbosity_1 {
+    () => {
+        impl Client {
+            pub async fn get_orphan_txs_verbosity_1(&self) -> Result<GetOrphanTxsVerboseOne> {
+                self.call("getorphantxs", &[into_json(1)?]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getorphantxs` verbosity level 2.
+#[macro_export]
+macro_rules! impl_async_client_v29__get_orphan_txs_verbosity_2 {
+    () => {
+        impl Client {
+            pub async fn get_orphan_txs_verbosity_2(&self) -> Result<GetOrphanTxsVerboseTwo> {
+                self.call("getorphantxs", &[into_json(2)?]).await
+            }
+        }
+    };
+}
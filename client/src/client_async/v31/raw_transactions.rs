@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of Bitcoin Core `v31`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+
+/// Implements Bitcoin Core JSON-RPC API method `abortprivatebroadcast`.
+#[macro_export]
+macro_rules! impl_async_client_v31__abort_private_broadcast {
+    () => {
+        impl Client {
+            pub async fn abort_private_broadcast(&self, id: &str) -> Result<AbortPrivateBroadcast> {
+                self.call("abortprivatebroadcast", &[id.into()]).await
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `getprivatebroadcastinfo`.
+#[macro_export]
+macro_rules! impl_async_client_v31__get_private_broadcast_info {
+    () => {
+        impl Client {
+            pub async fn get_private_broadcast_info(&self) -> Result<GetPrivateBroadcastInfo> {
+                self.call("getprivatebroadcastinfo", &[]).await
+            }
+        }
+    };
+}
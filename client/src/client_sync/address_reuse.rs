@@ -0,0 +1,210 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Finding the addresses of the wallet that received more than once, see
+//! `Client::detect_address_reuse`.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use bitcoin::address::NetworkUnchecked;
+use bitcoin::{Address, OutPoint, Txid};
+use serde::Deserialize;
+use types::model::{TransactionCategory, TransactionItem};
+
+use crate::client_sync::Result;
+
+/// The number of wallet transactions fetched at a time while scanning.
+pub(crate) const PAGE_SIZE: usize = 100;
+
+/// The addresses of the wallet that received more than once.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressReuse {
+    /// Whether the wallet has the `avoid_reuse` flag set, `None` if the server does not support
+    /// it (before v0.19).
+    pub avoid_reuse: Option<bool>,
+    /// The reused addresses, ordered by address.
+    pub addresses: Vec<ReusedAddress>,
+}
+
+impl AddressReuse {
+    /// Returns true if no address of the wallet received more than once.
+    pub fn is_empty(&self) -> bool { self.addresses.is_empty() }
+}
+
+/// An address of the wallet that received more than one output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ReusedAddress {
+    /// The reused address.
+    pub address: Address<NetworkUnchecked>,
+    /// The outputs paying the address, at least two.
+    pub received: Vec<OutPoint>,
+    /// The outputs paying the address that are not spent yet.
+    pub unspent: Vec<OutPoint>,
+    /// The unspent outputs marked `reused` by the wallet, only with `avoid_reuse` set. The wallet
+    /// does not spend them unless asked to.
+    pub marked_reused: Vec<OutPoint>,
+}
+
+/// The fields of a `listunspent` entry used to detect reuse, the same in all versions of Core.
+#[derive(Deserialize)]
+pub(crate) struct Unspent {
+    txid: Txid,
+    vout: u32,
+    address: Option<Address<NetworkUnchecked>>,
+    /// Only present if `avoid_reuse` is set, v0.19 and later.
+    reused: Option<bool>,
+}
+
+/// The `avoid_reuse` field of `getwalletinfo`, v0.19 and later.
+#[derive(Deserialize)]
+pub(crate) struct WalletFlags {
+    pub(crate) avoid_reuse: Option<bool>,
+}
+
+/// The outputs paying an address.
+#[derive(Default)]
+struct Outputs {
+    received: BTreeSet<OutPoint>,
+    unspent: BTreeSet<OutPoint>,
+    marked_reused: BTreeSet<OutPoint>,
+}
+
+/// Returns the addresses receiving more than one of the outputs in `transactions` and `unspent`.
+///
+/// Unspent outputs are counted as received, they include change which `listtransactions` omits.
+pub(crate) fn detect<I>(
+    transactions: I,
+    unspent: Vec<Unspent>,
+    avoid_reuse: Option<bool>,
+) -> Result<AddressReuse>
+where
+    I: Iterator<Item = Result<TransactionItem>>,
+{
+    let mut outputs = OutputsByAddress::new();
+    for item in transactions {
+        let item = item?;
+        if item.category == TransactionCategory::Send {
+            continue;
+        }
+        if let (Some(address), Some(txid)) = (item.address, item.txid) {
+            entry(&mut outputs, address).received.insert(OutPoint { txid, vout: item.vout });
+        }
+    }
+    for utxo in unspent {
+        let Some(address) = utxo.address else { continue };
+        let outpoint = OutPoint { txid: utxo.txid, vout: utxo.vout };
+        let paid = entry(&mut outputs, address);
+        paid.received.insert(outpoint);
+        paid.unspent.insert(outpoint);
+        if utxo.reused == Some(true) {
+            paid.marked_reused.insert(outpoint);
+        }
+    }
+
+    let addresses = outputs
+        .into_values()
+        .filter(|(_, outputs)| outputs.received.len() > 1)
+        .map(|(address, outputs)| ReusedAddress {
+            address,
+            received: outputs.received.into_iter().collect(),
+            unspent: outputs.unspent.into_iter().collect(),
+            marked_reused: outputs.marked_reused.into_iter().collect(),
+        })
+        .collect();
+    Ok(AddressReuse { avoid_reuse, addresses })
+}
+
+/// The outputs paying each address, keyed by the address string.
+type OutputsByAddress = BTreeMap<String, (Address<NetworkUnchecked>, Outputs)>;
+
+/// Returns the outputs paying `address`, inserting them empty if not there yet.
+fn entry(outputs: &mut OutputsByAddress, address: Address<NetworkUnchecked>) -> &mut Outputs {
+    let key = address.assume_checked_ref().to_string();
+    &mut outputs.entry(key).or_insert_with(|| (address, Outputs::default())).1
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::hashes::Hash;
+    use bitcoin::SignedAmount;
+    use types::model::Bip125Replaceable;
+
+    use super::*;
+
+    const ADDRESS: &str = "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080";
+    const OTHER: &str = "bcrt1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3qzf4jry";
+
+    fn outpoint(txid: u8) -> OutPoint {
+        OutPoint { txid: Txid::from_byte_array([txid; 32]), vout: 0 }
+    }
+
+    fn unspent(address: &str, txid: u8, reused: Option<bool>) -> Unspent {
+        let OutPoint { txid, vout } = outpoint(txid);
+        Unspent { txid, vout, address: Some(address.parse().unwrap()), reused }
+    }
+
+    fn item(address: &str, category: TransactionCategory, txid: u8) -> Result<TransactionItem> {
+        Ok(TransactionItem {
+            involves_watch_only: None,
+            address: Some(address.parse().unwrap()),
+            category,
+            amount: SignedAmount::from_sat(50_000),
+            vout: 0,
+            fee: SignedAmount::ZERO,
+            confirmations: 1,
+            generated: None,
+            trusted: None,
+            block_hash: None,
+            block_height: None,
+            block_index: None,
+            block_time: None,
+            txid: Some(outpoint(txid).txid),
+            wtxid: None,
+            wallet_conflicts: None,
+            replaced_by_txid: None,
+            replaces_txid: None,
+            mempool_conflicts: None,
+            to: None,
+            time: 0,
+            time_received: 0,
+            comment: None,
+            bip125_replaceable: Bip125Replaceable::Unknown,
+            parent_descriptors: None,
+            abandoned: None,
+            label: None,
+        })
+    }
+
+    #[test]
+    fn detects_addresses_receiving_more_than_once() {
+        use TransactionCategory::{Receive, Send};
+
+        let transactions = vec![
+            item(ADDRESS, Receive, 1),
+            item(ADDRESS, Receive, 2),
+            item(OTHER, Receive, 3),
+            // Paying an address is not receiving on it.
+            item(OTHER, Send, 4),
+        ];
+        let unspent = vec![unspent(ADDRESS, 2, Some(true)), unspent(OTHER, 3, Some(false))];
+
+        let reuse = detect(transactions.into_iter(), unspent, Some(true)).unwrap();
+        assert_eq!(reuse.avoid_reuse, Some(true));
+        assert_eq!(reuse.addresses.len(), 1);
+        let reused = &reuse.addresses[0];
+        assert_eq!(reused.address, ADDRESS.parse().unwrap());
+        assert_eq!(reused.received, [outpoint(1), outpoint(2)]);
+        assert_eq!(reused.unspent, [outpoint(2)]);
+        assert_eq!(reused.marked_reused, [outpoint(2)]);
+    }
+
+    #[test]
+    fn unspent_outputs_count_as_received() {
+        // Change is not listed by `listtransactions`.
+        let unspent = vec![unspent(ADDRESS, 1, None), unspent(ADDRESS, 2, None)];
+        let reuse = detect(std::iter::empty(), unspent, None).unwrap();
+        assert_eq!(reuse.addresses[0].received, [outpoint(1), outpoint(2)]);
+        assert!(reuse.addresses[0].marked_reused.is_empty());
+
+        assert!(detect(std::iter::empty(), vec![], None).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Features supported by a specific version of Bitcoin Core.
+
+/// The features supported by the Bitcoin Core server a client is connected to.
+///
+/// Use this instead of comparing the result of `server_version()` against magic numbers when
+/// writing code that runs against multiple versions of Core.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Capabilities {
+    /// The server version these capabilities were computed from e.g., `280200`.
+    pub server_version: usize,
+    /// `createwallet` can create descriptor wallets. v0.21 and later.
+    pub supports_descriptor_wallets: bool,
+    /// `createwallet` creates descriptor wallets by default. v23 and later.
+    pub default_descriptor_wallets: bool,
+    /// Legacy (BDB) wallets can be created. v29 and earlier.
+    pub supports_legacy_wallets: bool,
+    /// `testmempoolaccept` takes a `maxfeerate` argument. v0.19 and later.
+    pub supports_testmempoolaccept_maxfeerate: bool,
+    /// `getindexinfo` is available. v0.21 and later.
+    pub supports_getindexinfo: bool,
+    /// `send` is available. v0.21 and later.
+    pub supports_send: bool,
+    /// `getblock` supports verbosity level 3 (includes prevout data). v23 and later.
+    pub supports_getblock_verbosity_3: bool,
+    /// `sendall` is available. v24 and later.
+    pub supports_sendall: bool,
+    /// `gettxspendingprevout` is available. v24 and later.
+    pub supports_gettxspendingprevout: bool,
+    /// `scanblocks` is available. v25 and later.
+    pub supports_scanblocks: bool,
+    /// `submitpackage` is available. v26 and later.
+    pub supports_submitpackage: bool,
+    /// The `warnings` field of `getnetworkinfo` and `getblockchaininfo` is an array. v28 and later.
+    pub warnings_is_array: bool,
+    /// Full RBF is enabled by default. v28 and later.
+    pub full_rbf_by_default: bool,
+    /// `getdescriptoractivity` is available. v29 and later.
+    pub supports_getdescriptoractivity: bool,
+}
+
+impl Capabilities {
+    /// Computes the capabilities of a server from its version number e.g., `280200`.
+    pub fn from_server_version(server_version: usize) -> Self {
+        let since = |version: usize| server_version >= version;
+        Capabilities {
+            server_version,
+            supports_descriptor_wallets: since(210_000),
+            default_descriptor_wallets: since(230_000),
+            supports_legacy_wallets: !since(300_000),
+            supports_testmempoolaccept_maxfeerate: since(190_000),
+            supports_getindexinfo: since(210_000),
+            supports_send: since(210_000),
+            supports_getblock_verbosity_3: since(230_000),
+            supports_sendall: since(240_000),
+            supports_gettxspendingprevout: since(240_000),
+            supports_scanblocks: since(250_000),
+            supports_submitpackage: since(260_000),
+            warnings_is_array: since(280_000),
+            full_rbf_by_default: since(280_000),
+            supports_getdescriptoractivity: since(290_000),
+        }
+    }
+}
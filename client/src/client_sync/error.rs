@@ -1,5 +1,6 @@
 // SPDX-License-Identifier: CC0-1.0
 
+use std::time::Duration;
 use std::{error, fmt, io};
 
 use bitcoin::hex;
@@ -22,6 +23,20 @@ pub enum Error {
     ServerVersion(UnexpectedServerVersionError),
     /// Missing user/password.
     MissingUserPassword,
+    /// The result could not be converted into the version nonspecific model.
+    Model(Box<dyn error::Error + Send + Sync>),
+    /// The request id of an idempotent send was already used to pay a different address or
+    /// amount, by the transaction `txid`.
+    RequestIdReused {
+        request_id: String,
+        txid: bitcoin::Txid,
+    },
+    /// The response is inconsistent with the request or with itself, see
+    /// [`VerifyResponses`](crate::client_sync::middleware::VerifyResponses).
+    ResponseInconsistent {
+        method: String,
+        reason: String,
+    },
 }
 
 impl From<jsonrpc::error::Error> for Error {
@@ -64,6 +79,14 @@ impl fmt::Display for Error {
             Returned(ref s) => write!(f, "the daemon returned an error string: {}", s),
             ServerVersion(ref e) => write!(f, "server version: {}", e),
             MissingUserPassword => write!(f, "missing user and/or password"),
+            Model(ref e) => write!(f, "cannot convert the result into the model: {}", e),
+            RequestIdReused { ref request_id, ref txid } => write!(
+                f,
+                "request id {} was already used for a different payment by {}",
+                request_id, txid
+            ),
+            ResponseInconsistent { ref method, ref reason } =>
+                write!(f, "inconsistent {} response: {}", method, reason),
         }
     }
 }
@@ -80,7 +103,13 @@ impl error::Error for Error {
             BitcoinSerialization(ref e) => Some(e),
             Io(ref e) => Some(e),
             ServerVersion(ref e) => Some(e),
-            InvalidCookieFile | UnexpectedStructure | Returned(_) | MissingUserPassword => None,
+            Model(ref e) => Some(&**e),
+            InvalidCookieFile
+            | UnexpectedStructure
+            | Returned(_)
+            | MissingUserPassword
+            | RequestIdReused { .. }
+            | ResponseInconsistent { .. } => None,
         }
     }
 }
@@ -110,3 +139,42 @@ impl error::Error for UnexpectedServerVersionError {}
 impl From<UnexpectedServerVersionError> for Error {
     fn from(e: UnexpectedServerVersionError) -> Self { Self::ServerVersion(e) }
 }
+
+/// Error returned by `Client::dial` when the node did not open a new connection.
+#[derive(Debug)]
+pub enum DialError {
+    /// Calling the node failed.
+    Rpc(Error),
+    /// The node is already connected to the address, no new connection is opened.
+    AlreadyConnected,
+    /// The connection did not show up in `getpeerinfo` in time e.g., nothing listens on the
+    /// address or the peer disconnected straight away.
+    Timeout(Duration),
+}
+
+impl fmt::Display for DialError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use DialError::*;
+
+        match *self {
+            Rpc(ref e) => write!(f, "RPC error: {}", e),
+            AlreadyConnected => write!(f, "already connected to the address"),
+            Timeout(timeout) => write!(f, "no connection after {:?}", timeout),
+        }
+    }
+}
+
+impl error::Error for DialError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        use DialError::*;
+
+        match *self {
+            Rpc(ref e) => Some(e),
+            AlreadyConnected | Timeout(_) => None,
+        }
+    }
+}
+
+impl From<Error> for DialError {
+    fn from(e: Error) -> Self { Self::Rpc(e) }
+}
@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Sends that are safe to retry, see `Client::send_idempotent`.
+//!
+//! The request id is recorded in the wallet as the comment of the transaction. Before sending, the
+//! wallet transactions are searched for a send with the same comment.
+
+use bitcoin::{Address, Amount, Txid};
+use types::model::{TransactionCategory, TransactionItem};
+
+use crate::client_sync::{Error, Result};
+
+/// The number of wallet transactions fetched at a time while searching for a previous send.
+pub(crate) const PAGE_SIZE: usize = 100;
+
+/// Prefixes the request id in the comment of a transaction.
+const COMMENT_PREFIX: &str = "send_idempotent:";
+
+/// Returns the comment recording `request_id`.
+pub(crate) fn comment(request_id: &str) -> String { format!("{}{}", COMMENT_PREFIX, request_id) }
+
+/// Returns the transaction already sent for `request_id`, `None` if there is none.
+///
+/// Abandoned and conflicted transactions are ignored, their coins can be spent again. Returns an
+/// error if the transaction sent for `request_id` does not pay `amount` to `to`.
+pub(crate) fn find_sent<I>(
+    transactions: I,
+    request_id: &str,
+    to: &Address,
+    amount: Amount,
+) -> Result<Option<Txid>>
+where
+    I: Iterator<Item = Result<TransactionItem>>,
+{
+    let comment = comment(request_id);
+    for item in transactions {
+        let item = item?;
+        if item.category != TransactionCategory::Send
+            || item.comment.as_deref() != Some(comment.as_str())
+            || item.abandoned == Some(true)
+            || item.confirmations < 0
+        {
+            continue;
+        }
+        let Some(txid) = item.txid else { continue };
+        if item.address.as_ref() != Some(to.as_unchecked()) || item.amount.unsigned_abs() != amount
+        {
+            return Err(Error::RequestIdReused { request_id: request_id.to_string(), txid });
+        }
+        return Ok(Some(txid));
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::hashes::Hash;
+    use bitcoin::SignedAmount;
+    use types::model::Bip125Replaceable;
+
+    use super::*;
+
+    const ADDRESS: &str = "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080";
+
+    fn send(txid: u8, comment: &str, amount: i64) -> Result<TransactionItem> {
+        Ok(TransactionItem {
+            involves_watch_only: None,
+            address: Some(ADDRESS.parse().unwrap()),
+            category: TransactionCategory::Send,
+            amount: SignedAmount::from_sat(amount),
+            vout: 0,
+            fee: SignedAmount::from_sat(-200),
+            confirmations: 0,
+            generated: None,
+            trusted: Some(true),
+            block_hash: None,
+            block_height: None,
+            block_index: None,
+            block_time: None,
+            txid: Some(Txid::from_byte_array([txid; 32])),
+            wtxid: None,
+            wallet_conflicts: None,
+            replaced_by_txid: None,
+            replaces_txid: None,
+            mempool_conflicts: None,
+            to: None,
+            time: 0,
+            time_received: 0,
+            comment: Some(comment.to_string()),
+            bip125_replaceable: Bip125Replaceable::Unknown,
+            parent_descriptors: None,
+            abandoned: Some(false),
+            label: None,
+        })
+    }
+
+    #[test]
+    fn finds_previous_send() {
+        let to = ADDRESS.parse::<Address<_>>().unwrap().assume_checked();
+        let amount = Amount::from_sat(5_000);
+        let sent = || {
+            vec![
+                send(1, &comment("a"), -5_000),
+                send(2, "unrelated", -5_000),
+                send(3, &comment("b"), -7_000),
+            ]
+        };
+
+        let txid = find_sent(sent().into_iter(), "a", &to, amount).unwrap();
+        assert_eq!(txid, Some(Txid::from_byte_array([1; 32])));
+        assert_eq!(find_sent(sent().into_iter(), "c", &to, amount).unwrap(), None);
+        match find_sent(sent().into_iter(), "b", &to, amount) {
+            Err(Error::RequestIdReused { request_id, txid }) => {
+                assert_eq!(request_id, "b");
+                assert_eq!(txid, Txid::from_byte_array([3; 32]));
+            }
+            res => panic!("unexpected: {:?}", res),
+        }
+
+        let mut abandoned = send(1, &comment("a"), -5_000).unwrap();
+        abandoned.abandoned = Some(true);
+        assert_eq!(find_sent(vec![Ok(abandoned)].into_iter(), "a", &to, amount).unwrap(), None);
+    }
+}
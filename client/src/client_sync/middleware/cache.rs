@@ -0,0 +1,307 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Caching of calls returning immutable chain data.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+use serde_json::Value;
+
+use super::{Middleware, Next, RpcRequest, RpcResponse};
+use crate::client_sync::Result;
+
+/// Caches the results of calls returning chain data that does not change, least recently used
+/// results are evicted first.
+///
+/// Only these calls are cached:
+///
+/// - `getblock` with verbosity `0` (the serialized block).
+/// - `getblockheader` with `verbose` set to `false` (the serialized header).
+/// - `getrawtransaction` with `verbose` unset or `false` and a block hash.
+/// - `getblockfilter`.
+///
+/// Verbose results are not cached because they include the number of confirmations.
+///
+/// The block hash at a height changes when the chain reorganizes, `getblockhash` is only cached
+/// if enabled with [`Cache::with_heights`].
+///
+/// Clones share the same cache, keep a clone to invalidate it after adding the middleware.
+///
+/// # Reorganizations
+///
+/// The cache does not follow the chain tip, it cannot tell when the chain reorganizes. With
+/// [`Cache::with_heights`] the caller must detect reorganizations, e.g. when the next block does
+/// not build on the last one processed (see `ScanState::extends` with the `state` feature), and
+/// call [`Cache::reorg`] with the fork height before using the cached block hashes again.
+#[derive(Clone, Debug)]
+pub struct Cache {
+    lru: Arc<Mutex<Lru>>,
+    /// Whether `getblockhash` is cached.
+    heights: bool,
+}
+
+impl Cache {
+    /// Creates a cache holding at most `max_entries` results, using at most about `max_bytes` of
+    /// JSON.
+    pub fn new(max_entries: usize, max_bytes: usize) -> Self {
+        let lru = Lru {
+            max_entries,
+            max_bytes,
+            bytes: 0,
+            tick: 0,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+        };
+        Self { lru: Arc::new(Mutex::new(lru)), heights: false }
+    }
+
+    /// Also caches `getblockhash`.
+    ///
+    /// The cached block hashes go stale when the chain reorganizes, only enable this if you call
+    /// [`Cache::reorg`] on every reorganization, the cache does not detect them itself.
+    pub fn with_heights(mut self) -> Self {
+        self.heights = true;
+        self
+    }
+
+    /// Invalidates the results that depend on the chain above `fork_height`.
+    ///
+    /// Call this when the chain reorganizes, `fork_height` is the height of the last block common
+    /// to the old and new chains. Results looked up by block hash or txid and block hash stay
+    /// valid, only the block hashes of the heights above `fork_height` are removed.
+    pub fn reorg(&self, fork_height: u64) {
+        self.lru.lock().expect("poisoned lock").retain(|entry| match entry.height {
+            Some(height) => height <= fork_height,
+            None => true,
+        })
+    }
+
+    /// Removes all cached results.
+    pub fn clear(&self) { self.lru.lock().expect("poisoned lock").retain(|_| false) }
+
+    /// Returns the number of cached results.
+    pub fn len(&self) -> usize { self.lru.lock().expect("poisoned lock").entries.len() }
+
+    /// Returns `true` if no results are cached.
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+}
+
+impl Middleware for Cache {
+    fn around(&self, req: RpcRequest<'_>, next: Next<'_>) -> Result<RpcResponse> {
+        let Some(kind) = cacheable(&req, self.heights) else { return next.run(req) };
+        let key = (req.method.to_owned(), Value::from(req.args).to_string());
+
+        if let Some(value) = self.lru.lock().expect("poisoned lock").get(&key) {
+            return Ok(value);
+        }
+        let value = next.run(req)?;
+        self.lru.lock().expect("poisoned lock").insert(key, value.clone(), kind);
+        Ok(value)
+    }
+}
+
+/// How a cacheable result is invalidated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Kind {
+    /// Never changes.
+    Immutable,
+    /// Changes if the block at this height is reorganized out.
+    Height(u64),
+}
+
+/// Returns how the result of `req` can be cached, `None` if it cannot.
+///
+/// Results depending on a height are only cached if `heights` is `true`.
+fn cacheable(req: &RpcRequest<'_>, heights: bool) -> Option<Kind> {
+    let arg = |index: usize| req.args.get(index);
+    let is_false = |value: Option<&Value>| match value {
+        Some(Value::Bool(b)) => !b,
+        Some(Value::Number(n)) => n.as_u64() == Some(0),
+        _ => false,
+    };
+    match req.method {
+        "getblock" if is_false(arg(1)) => Some(Kind::Immutable),
+        "getblockheader" if arg(1) == Some(&Value::Bool(false)) => Some(Kind::Immutable),
+        "getrawtransaction"
+            if (arg(1).is_none() || arg(1) == Some(&Value::Null) || is_false(arg(1)))
+                && arg(2).is_some_and(Value::is_string) =>
+            Some(Kind::Immutable),
+        "getblockfilter" if arg(0).is_some() => Some(Kind::Immutable),
+        "getblockhash" if heights => arg(0).and_then(Value::as_u64).map(Kind::Height),
+        _ => None,
+    }
+}
+
+/// A cached result.
+#[derive(Debug)]
+struct Entry {
+    value: Value,
+    /// Approximate size of the result in bytes.
+    bytes: usize,
+    /// The height the result depends on, if any.
+    height: Option<u64>,
+    /// When the result was last used, the key in [`Lru::order`].
+    tick: u64,
+}
+
+/// The key of a cached result, the method and the JSON encoded args.
+type Key = (String, String);
+
+/// A least recently used cache.
+#[derive(Debug)]
+struct Lru {
+    max_entries: usize,
+    max_bytes: usize,
+    /// Total size of the cached results.
+    bytes: usize,
+    /// Incremented each time a result is used.
+    tick: u64,
+    entries: HashMap<Key, Entry>,
+    /// The keys of the entries, least recently used first.
+    order: BTreeMap<u64, Key>,
+}
+
+impl Lru {
+    /// Returns the result cached for `key`, marking it as the most recently used.
+    fn get(&mut self, key: &Key) -> Option<Value> {
+        self.tick += 1;
+        let entry = self.entries.get_mut(key)?;
+        self.order.remove(&entry.tick);
+        entry.tick = self.tick;
+        self.order.insert(entry.tick, key.clone());
+        Some(entry.value.clone())
+    }
+
+    /// Caches `value`, evicting the least recently used results to stay within the limits.
+    fn insert(&mut self, key: Key, value: Value, kind: Kind) {
+        let bytes = match value {
+            Value::String(ref s) => s.len(),
+            ref value => value.to_string().len(),
+        };
+        if bytes > self.max_bytes || self.max_entries == 0 {
+            return;
+        }
+        if let Some(old) = self.entries.remove(&key) {
+            self.order.remove(&old.tick);
+            self.bytes -= old.bytes;
+        }
+        while self.entries.len() >= self.max_entries || self.bytes + bytes > self.max_bytes {
+            let Some((_, oldest)) = self.order.pop_first() else { break };
+            if let Some(old) = self.entries.remove(&oldest) {
+                self.bytes -= old.bytes;
+            }
+        }
+
+        self.tick += 1;
+        let height = match kind {
+            Kind::Immutable => None,
+            Kind::Height(height) => Some(height),
+        };
+        self.order.insert(self.tick, key.clone());
+        self.entries.insert(key, Entry { value, bytes, height, tick: self.tick });
+        self.bytes += bytes;
+    }
+
+    /// Keeps only the entries for which `keep` returns `true`.
+    fn retain<F: FnMut(&Entry) -> bool>(&mut self, mut keep: F) {
+        let (order, bytes) = (&mut self.order, &mut self.bytes);
+        self.entries.retain(|_, entry| {
+            let keep = keep(entry);
+            if !keep {
+                order.remove(&entry.tick);
+                *bytes -= entry.bytes;
+            }
+            keep
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::slice;
+
+    use serde_json::json;
+
+    use super::*;
+
+    /// Calls `method` through `cache`, returns the result and whether it was sent to the server.
+    fn call(cache: &Cache, method: &str, args: &[Value]) -> (Value, bool) {
+        let sent = Cell::new(false);
+        let send = |req: RpcRequest<'_>| {
+            sent.set(true);
+            Ok(json!(format!("{}{}", req.method, Value::from(req.args))))
+        };
+        let middleware: [Arc<dyn Middleware>; 1] = [Arc::new(cache.clone())];
+        let result = Next::new(&middleware, &send).run(RpcRequest { method, args }).unwrap();
+        (result, sent.get())
+    }
+
+    #[test]
+    fn caches_immutable_calls_only() {
+        let cache = Cache::new(10, 1_000);
+        let hash = json!("00aa");
+
+        assert!(call(&cache, "getblock", &[hash.clone(), json!(0)]).1);
+        assert!(!call(&cache, "getblock", &[hash.clone(), json!(0)]).1);
+        // Verbose results include the number of confirmations.
+        assert!(call(&cache, "getblock", &[hash.clone(), json!(1)]).1);
+        assert!(call(&cache, "getblock", &[hash.clone(), json!(1)]).1);
+        assert!(call(&cache, "getblock", slice::from_ref(&hash)).1);
+        assert!(call(&cache, "getblock", slice::from_ref(&hash)).1);
+        // A transaction without a block hash could still be in the mempool.
+        assert!(call(&cache, "getrawtransaction", &[json!("bb")]).1);
+        assert!(call(&cache, "getrawtransaction", &[json!("bb")]).1);
+        assert!(call(&cache, "getrawtransaction", &[json!("bb"), json!(false), hash.clone()]).1);
+        assert!(!call(&cache, "getrawtransaction", &[json!("bb"), json!(false), hash]).1);
+        assert!(call(&cache, "getblockcount", &[]).1);
+        assert!(call(&cache, "getblockcount", &[]).1);
+        // The block hash at a height changes on reorgs.
+        assert!(call(&cache, "getblockhash", &[json!(1)]).1);
+        assert!(call(&cache, "getblockhash", &[json!(1)]).1);
+
+        assert_eq!(cache.len(), 2);
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let cache = Cache::new(2, 1_000).with_heights();
+        call(&cache, "getblockhash", &[json!(1)]);
+        call(&cache, "getblockhash", &[json!(2)]);
+        // Use 1 so that 2 is the least recently used.
+        assert!(!call(&cache, "getblockhash", &[json!(1)]).1);
+        call(&cache, "getblockhash", &[json!(3)]);
+
+        assert_eq!(cache.len(), 2);
+        assert!(!call(&cache, "getblockhash", &[json!(1)]).1);
+        assert!(call(&cache, "getblockhash", &[json!(2)]).1);
+    }
+
+    #[test]
+    fn evicts_to_max_bytes() {
+        // Each result is a string of 15 bytes e.g., `getblockhash[1]`.
+        let cache = Cache::new(10, 40).with_heights();
+        for height in 0..5 {
+            call(&cache, "getblockhash", &[json!(height)]);
+        }
+        assert_eq!(cache.len(), 2);
+        assert!(cache.lru.lock().unwrap().bytes <= 40);
+    }
+
+    #[test]
+    fn reorg_removes_heights_above_fork() {
+        let cache = Cache::new(10, 1_000).with_heights();
+        for height in 0..5 {
+            call(&cache, "getblockhash", &[json!(height)]);
+        }
+        call(&cache, "getblock", &[json!("00aa"), json!(0)]);
+
+        cache.reorg(2);
+        assert_eq!(cache.len(), 4);
+        assert!(!call(&cache, "getblockhash", &[json!(2)]).1);
+        assert!(call(&cache, "getblockhash", &[json!(3)]).1);
+        assert!(!call(&cache, "getblock", &[json!("00aa"), json!(0)]).1);
+    }
+}
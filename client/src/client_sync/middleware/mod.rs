@@ -0,0 +1,262 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Middleware run around every call made by a `Client`.
+//!
+//! Add middleware with `ClientBuilder::middleware`, the first added is the outermost i.e., it
+//! sees the call first and the response last.
+
+mod cache;
+mod verify;
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use std::{fmt, thread};
+
+pub use self::cache::Cache;
+pub use self::verify::VerifyResponses;
+use crate::client_sync::{Error, Result};
+
+/// A call made by a `Client`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RpcRequest<'a> {
+    /// The RPC method e.g., `getblockchaininfo`.
+    pub method: &'a str,
+    /// The arguments of the call.
+    pub args: &'a [serde_json::Value],
+}
+
+/// The result of a successful call, before it is deserialized into the type returned by the
+/// `Client` method.
+pub type RpcResponse = serde_json::Value;
+
+/// Runs code around the calls made by a `Client` e.g., logging, caching or fault injection.
+pub trait Middleware: Send + Sync {
+    /// Handles `req`, usually by calling [`Next::run`] to pass it on to the rest of the chain.
+    ///
+    /// `next` can be run more than once to repeat the call, or not at all to answer the call
+    /// without sending it to the server.
+    fn around(&self, req: RpcRequest<'_>, next: Next<'_>) -> Result<RpcResponse>;
+}
+
+/// The rest of the middleware chain, ending with sending the call to the server.
+#[derive(Clone, Copy)]
+pub struct Next<'a> {
+    middleware: &'a [Arc<dyn Middleware>],
+    send: &'a dyn Fn(RpcRequest<'_>) -> Result<RpcResponse>,
+}
+
+impl fmt::Debug for Next<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Next").field("remaining", &self.middleware.len()).finish()
+    }
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(
+        middleware: &'a [Arc<dyn Middleware>],
+        send: &'a dyn Fn(RpcRequest<'_>) -> Result<RpcResponse>,
+    ) -> Self {
+        Self { middleware, send }
+    }
+
+    /// Passes `req` on to the next middleware, or sends it to the server if this is the last.
+    pub fn run(&self, req: RpcRequest<'_>) -> Result<RpcResponse> {
+        match self.middleware.split_first() {
+            Some((first, rest)) => first.around(req, Next { middleware: rest, send: self.send }),
+            None => (self.send)(req),
+        }
+    }
+}
+
+/// Retries calls that fail because the server could not be reached.
+///
+/// Errors returned by the server are not retried, only transport errors e.g., connection
+/// refused or a timeout.
+///
+/// A call that timed out may have been executed by the server, retrying it then runs it twice.
+/// Only the methods the [`types::methods`] registry lists as not mutating are retried, unless
+/// `mutating` is set. Methods missing from the registry are treated as mutating. Set `mutating`
+/// only if running a call twice is harmless e.g., for `sendrawtransaction`, never for
+/// `sendtoaddress`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Retry {
+    /// The maximum number of times a call is sent, including the first.
+    pub attempts: u32,
+    /// How long to wait before the first retry, doubled after each retry.
+    pub backoff: Duration,
+    /// Also retry the methods changing the state of the node or a wallet.
+    pub mutating: bool,
+}
+
+impl Retry {
+    /// Returns `true` if calls to `method` are retried.
+    pub fn retries(&self, method: &str) -> bool {
+        self.mutating || types::methods::get(method).is_some_and(|info| !info.is_mutating())
+    }
+}
+
+impl Default for Retry {
+    fn default() -> Self {
+        Retry { attempts: 3, backoff: Duration::from_millis(100), mutating: false }
+    }
+}
+
+impl Middleware for Retry {
+    fn around(&self, req: RpcRequest<'_>, next: Next<'_>) -> Result<RpcResponse> {
+        if !self.retries(req.method) {
+            return next.run(req);
+        }
+        let mut backoff = self.backoff;
+        let mut attempt = 1;
+        loop {
+            match next.run(req) {
+                Err(Error::JsonRpc(jsonrpc::Error::Transport(e))) if attempt < self.attempts => {
+                    log::debug!(target: "corepc", "retrying {} after error: {}", req.method, e);
+                    thread::sleep(backoff);
+                    backoff *= 2;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Counts the calls made for each RPC method and how long they took.
+///
+/// Clones share the same counters, keep a clone to read them after adding the middleware.
+#[derive(Clone, Debug, Default)]
+pub struct Metrics {
+    methods: Arc<Mutex<BTreeMap<String, MethodMetrics>>>,
+}
+
+/// The calls made for an RPC method, see [`Metrics`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MethodMetrics {
+    /// The number of calls made.
+    pub calls: u64,
+    /// The number of calls that returned an error.
+    pub errors: u64,
+    /// The total time taken by the calls.
+    pub duration: Duration,
+}
+
+impl Metrics {
+    /// Creates new metrics with all counters at zero.
+    pub fn new() -> Self { Self::default() }
+
+    /// Returns the metrics of each RPC method called so far.
+    pub fn snapshot(&self) -> BTreeMap<String, MethodMetrics> {
+        self.methods.lock().expect("poisoned lock").clone()
+    }
+
+    /// Resets all counters to zero.
+    pub fn reset(&self) { self.methods.lock().expect("poisoned lock").clear() }
+
+    /// Counts a call to `method` that took `elapsed`.
+    pub(crate) fn record(&self, method: &str, is_err: bool, elapsed: Duration) {
+        let mut methods = self.methods.lock().expect("poisoned lock");
+        let method = methods.entry(method.to_owned()).or_default();
+        method.calls += 1;
+        method.errors += u64::from(is_err);
+        method.duration += elapsed;
+    }
+}
+
+impl Middleware for Metrics {
+    fn around(&self, req: RpcRequest<'_>, next: Next<'_>) -> Result<RpcResponse> {
+        let start = Instant::now();
+        let result = next.run(req);
+        self.record(req.method, result.is_err(), start.elapsed());
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::{io, slice};
+
+    use super::*;
+
+    fn transport_error() -> Error {
+        let e = io::Error::new(io::ErrorKind::ConnectionRefused, "refused");
+        Error::JsonRpc(jsonrpc::Error::Transport(Box::new(e)))
+    }
+
+    /// Answers calls without a server, failing the first `failures` calls with a transport error.
+    fn run(middleware: &[Arc<dyn Middleware>], failures: u32) -> (Result<RpcResponse>, u32) {
+        run_method(middleware, "getblockcount", failures)
+    }
+
+    /// Like [`run`], calling `method`.
+    fn run_method(
+        middleware: &[Arc<dyn Middleware>],
+        method: &str,
+        failures: u32,
+    ) -> (Result<RpcResponse>, u32) {
+        let sent = Cell::new(0);
+        let send = |_: RpcRequest<'_>| {
+            sent.set(sent.get() + 1);
+            if sent.get() <= failures {
+                Err(transport_error())
+            } else {
+                Ok(serde_json::json!(42))
+            }
+        };
+        let req = RpcRequest { method, args: &[] };
+        let result = Next::new(middleware, &send).run(req);
+        (result, sent.get())
+    }
+
+    #[test]
+    fn retry() {
+        let retry: Arc<dyn Middleware> =
+            Arc::new(Retry { attempts: 3, backoff: Duration::ZERO, mutating: false });
+
+        let (result, sent) = run(slice::from_ref(&retry), 2);
+        assert_eq!(result.unwrap(), serde_json::json!(42));
+        assert_eq!(sent, 3);
+
+        let (result, sent) = run(&[retry], 3);
+        assert!(matches!(result, Err(Error::JsonRpc(jsonrpc::Error::Transport(_)))));
+        assert_eq!(sent, 3);
+    }
+
+    #[test]
+    fn retry_mutating_opt_in() {
+        let retry = Retry { attempts: 3, backoff: Duration::ZERO, mutating: false };
+        assert!(!retry.retries("sendtoaddress"));
+        assert!(!retry.retries("getnewaddress"));
+        assert!(!retry.retries("unknownmethod"));
+        let (result, sent) = run_method(&[Arc::new(retry)], "sendtoaddress", 1);
+        assert!(result.is_err());
+        assert_eq!(sent, 1);
+
+        let retry = Retry { mutating: true, ..retry };
+        let (result, sent) = run_method(&[Arc::new(retry)], "sendrawtransaction", 1);
+        assert!(result.is_ok());
+        assert_eq!(sent, 2);
+    }
+
+    #[test]
+    fn metrics_count_retries_once() {
+        let metrics = Metrics::new();
+        let retry = Retry { attempts: 2, backoff: Duration::ZERO, mutating: false };
+
+        // Outermost first, metrics sees the call once however many times it is retried.
+        let middleware: [Arc<dyn Middleware>; 2] = [Arc::new(metrics.clone()), Arc::new(retry)];
+        let (result, _) = run(&middleware, 1);
+        assert!(result.is_ok());
+        let (result, _) = run(&middleware, 2);
+        assert!(result.is_err());
+
+        let snapshot = metrics.snapshot();
+        let method = snapshot["getblockcount"];
+        assert_eq!((method.calls, method.errors), (2, 1));
+
+        metrics.reset();
+        assert!(metrics.snapshot().is_empty());
+    }
+}
@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Checking that the chain data returned by a server is consistent, without trusting the server.
+
+use bitcoin::block::{self, Header};
+use bitcoin::consensus::encode;
+use bitcoin::hashes::Hash as _;
+use bitcoin::{merkle_tree, Block, BlockHash, CompactTarget, Transaction, TxMerkleNode, Txid};
+use serde_json::Value;
+
+use super::{Middleware, Next, RpcRequest, RpcResponse};
+use crate::client_sync::{Error, Result};
+
+/// Checks the results of calls returning chain data against what they can be computed from,
+/// returning [`Error::ResponseInconsistent`] on mismatch.
+///
+/// Useful when the server is run by a third party. These calls are checked, in all verbosities:
+///
+/// - `getblock`: the hash of the header is the requested hash, and the merkle root of the header
+///   is the root of the transactions. With verbosity `2` the txid of each transaction matches
+///   its serialized bytes.
+/// - `getblockheader`: the hash of the header is the requested hash.
+/// - `getrawtransaction`: the txid of the serialized transaction is the requested txid.
+///
+/// This does not check that the block is in the best chain or that its proof of work is valid,
+/// see the `headers` module for that. Other calls are passed through unchecked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct VerifyResponses;
+
+impl Middleware for VerifyResponses {
+    fn around(&self, req: RpcRequest<'_>, next: Next<'_>) -> Result<RpcResponse> {
+        let res = next.run(req)?;
+        let requested = req.args.first().and_then(Value::as_str);
+        let checked = match (req.method, requested) {
+            ("getblock", Some(hash)) => check_block(hash, &res),
+            ("getblockheader", Some(hash)) => check_header(hash, &res),
+            ("getrawtransaction", Some(txid)) => check_transaction(txid, &res),
+            _ => Ok(()),
+        };
+        checked.map_err(|reason| Error::ResponseInconsistent {
+            method: req.method.to_owned(),
+            reason,
+        })?;
+        Ok(res)
+    }
+}
+
+/// The result of a check, the reason the response is inconsistent on error.
+type Check = std::result::Result<(), String>;
+
+/// Returns an error with `reason` if `ok` is false.
+fn ensure(ok: bool, reason: impl FnOnce() -> String) -> Check {
+    if ok {
+        Ok(())
+    } else {
+        Err(reason())
+    }
+}
+
+/// Checks a `getblock` result for the block `hash`.
+fn check_block(hash: &str, res: &Value) -> Check {
+    if let Some(hex) = res.as_str() {
+        let block: Block = decode(hex)?;
+        check_hash(hash, block.block_hash())?;
+        return ensure(block.check_merkle_root(), || "merkle root does not match the txs".into());
+    }
+    let header = header_from_json(res)?;
+    check_hash(hash, header.block_hash())?;
+    check_hash(hash, field(res, "hash")?)?;
+
+    let txs = res["tx"].as_array().ok_or("missing field `tx`")?;
+    let txids = txs
+        .iter()
+        .map(|tx| match tx {
+            // Verbosity 1.
+            Value::String(txid) => parse(txid),
+            // Verbosity 2 and 3.
+            tx => {
+                let txid: Txid = field(tx, "txid")?;
+                if let Some(hex) = tx["hex"].as_str() {
+                    let computed = decode::<Transaction>(hex)?.compute_txid();
+                    ensure(computed == txid, || format!("tx {} has txid {}", txid, computed))?;
+                }
+                Ok(txid)
+            }
+        })
+        .collect::<std::result::Result<Vec<Txid>, String>>()?;
+    let root = merkle_tree::calculate_root(txids.into_iter().map(|txid| txid.to_raw_hash()))
+        .map(TxMerkleNode::from_raw_hash);
+    ensure(root == Some(header.merkle_root), || "merkle root does not match the txs".into())
+}
+
+/// Checks a `getblockheader` result for the block `hash`.
+fn check_header(hash: &str, res: &Value) -> Check {
+    let header = match res.as_str() {
+        Some(hex) => decode(hex)?,
+        None => {
+            check_hash(hash, field(res, "hash")?)?;
+            header_from_json(res)?
+        }
+    };
+    check_hash(hash, header.block_hash())
+}
+
+/// Checks a `getrawtransaction` result for the transaction `txid`.
+fn check_transaction(txid: &str, res: &Value) -> Check {
+    let requested: Txid = parse(txid)?;
+    let hex = match res {
+        Value::String(hex) => hex,
+        res => {
+            let got: Txid = field(res, "txid")?;
+            ensure(got == requested, || format!("txid {} for requested {}", got, requested))?;
+            res["hex"].as_str().ok_or("missing field `hex`")?
+        }
+    };
+    let computed = decode::<Transaction>(hex)?.compute_txid();
+    ensure(computed == requested, || format!("tx has txid {}, requested {}", computed, requested))
+}
+
+/// Checks that the block hash `got` is the requested `hash`.
+fn check_hash(hash: &str, got: BlockHash) -> Check {
+    let requested: BlockHash = parse(hash)?;
+    ensure(got == requested, || format!("block hash {} for requested {}", got, requested))
+}
+
+/// Returns the header of a verbose `getblock` or `getblockheader` result.
+fn header_from_json(res: &Value) -> std::result::Result<Header, String> {
+    let bits = res["bits"].as_str().ok_or("missing field `bits`")?;
+    let bits = u32::from_str_radix(bits, 16).map_err(|e| format!("invalid `bits`: {}", e))?;
+    let number = |name: &str| res[name].as_i64().ok_or_else(|| format!("missing field `{}`", name));
+    Ok(Header {
+        version: block::Version::from_consensus(number("version")? as i32),
+        // The genesis block has no previous block.
+        prev_blockhash: match res.get("previousblockhash") {
+            Some(_) => field(res, "previousblockhash")?,
+            None => BlockHash::all_zeros(),
+        },
+        merkle_root: field(res, "merkleroot")?,
+        time: number("time")? as u32,
+        bits: CompactTarget::from_consensus(bits),
+        nonce: number("nonce")? as u32,
+    })
+}
+
+/// Parses the string field `name` of `res`.
+fn field<T: std::str::FromStr>(res: &Value, name: &str) -> std::result::Result<T, String> {
+    parse(res[name].as_str().ok_or_else(|| format!("missing field `{}`", name))?)
+}
+
+/// Parses the hex string `s`.
+fn parse<T: std::str::FromStr>(s: &str) -> std::result::Result<T, String> {
+    s.parse().map_err(|_| format!("invalid hex `{}`", s))
+}
+
+/// Decodes the consensus encoded hex `hex`.
+fn decode<T: encode::Decodable>(hex: &str) -> std::result::Result<T, String> {
+    encode::deserialize_hex(hex).map_err(|e| format!("invalid serialization: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::slice;
+    use std::sync::Arc;
+
+    use bitcoin::blockdata::constants::genesis_block;
+    use bitcoin::{absolute, Network};
+
+    use super::*;
+
+    /// Runs `req` through [`VerifyResponses`], answered with `res`.
+    fn run(method: &str, args: &[Value], res: Value) -> Result<RpcResponse> {
+        let middleware: [Arc<dyn Middleware>; 1] = [Arc::new(VerifyResponses)];
+        let send = |_: RpcRequest<'_>| Ok(res.clone());
+        Next::new(&middleware, &send).run(RpcRequest { method, args })
+    }
+
+    fn is_inconsistent(res: Result<RpcResponse>) -> bool {
+        matches!(res, Err(Error::ResponseInconsistent { .. }))
+    }
+
+    fn verbose_block(block: &Block) -> Value {
+        let header = &block.header;
+        serde_json::json!({
+            "hash": block.block_hash().to_string(),
+            "version": header.version.to_consensus(),
+            "merkleroot": header.merkle_root.to_string(),
+            "time": header.time,
+            "bits": format!("{:08x}", header.bits.to_consensus()),
+            "nonce": header.nonce,
+            "tx": block.txdata.iter().map(|tx| tx.compute_txid().to_string()).collect::<Vec<_>>(),
+        })
+    }
+
+    #[test]
+    fn getblock() {
+        let block = genesis_block(Network::Bitcoin);
+        let hash = Value::from(block.block_hash().to_string());
+        let other = Value::from(genesis_block(Network::Regtest).block_hash().to_string());
+        let hex = Value::from(encode::serialize_hex(&block));
+
+        assert!(run("getblock", &[hash.clone(), 0.into()], hex.clone()).is_ok());
+        assert!(is_inconsistent(run("getblock", &[other.clone(), 0.into()], hex)));
+
+        let verbose = verbose_block(&block);
+        assert!(run("getblock", slice::from_ref(&hash), verbose.clone()).is_ok());
+        assert!(is_inconsistent(run("getblock", &[other], verbose.clone())));
+
+        let mut wrong_txs = verbose.clone();
+        wrong_txs["tx"] = serde_json::json!([Txid::all_zeros().to_string()]);
+        assert!(is_inconsistent(run("getblock", slice::from_ref(&hash), wrong_txs)));
+
+        // Verbosity 2, a transaction with bytes not matching its txid.
+        let mut tx = serde_json::json!({
+            "txid": block.txdata[0].compute_txid().to_string(),
+            "hex": encode::serialize_hex(&block.txdata[0]),
+        });
+        let mut verbose_txs = verbose.clone();
+        verbose_txs["tx"] = serde_json::json!([tx.clone()]);
+        assert!(run("getblock", &[hash.clone(), 2.into()], verbose_txs.clone()).is_ok());
+        let mut other_tx = block.txdata[0].clone();
+        other_tx.lock_time = absolute::LockTime::from_consensus(1);
+        tx["hex"] = encode::serialize_hex(&other_tx).into();
+        verbose_txs["tx"] = serde_json::json!([tx]);
+        assert!(is_inconsistent(run("getblock", &[hash, 2.into()], verbose_txs)));
+    }
+
+    #[test]
+    fn getblockheader() {
+        let block = genesis_block(Network::Bitcoin);
+        let hash = Value::from(block.block_hash().to_string());
+        let hex = Value::from(encode::serialize_hex(&block.header));
+        assert!(run("getblockheader", &[hash.clone(), false.into()], hex.clone()).is_ok());
+
+        let mut verbose = verbose_block(&block);
+        assert!(run("getblockheader", slice::from_ref(&hash), verbose.clone()).is_ok());
+        verbose["nonce"] = 0.into();
+        assert!(is_inconsistent(run("getblockheader", &[hash], verbose)));
+    }
+
+    #[test]
+    fn getrawtransaction() {
+        let tx = &genesis_block(Network::Bitcoin).txdata[0];
+        let txid = Value::from(tx.compute_txid().to_string());
+        let hex = Value::from(encode::serialize_hex(tx));
+        assert!(run("getrawtransaction", slice::from_ref(&txid), hex.clone()).is_ok());
+        assert!(is_inconsistent(run(
+            "getrawtransaction",
+            &[Txid::all_zeros().to_string().into()],
+            hex.clone()
+        )));
+
+        let verbose = serde_json::json!({ "txid": txid, "hex": hex });
+        assert!(run("getrawtransaction", &[txid, true.into()], verbose).is_ok());
+    }
+
+    #[test]
+    fn other_calls_pass_through() {
+        assert_eq!(run("getblockcount", &[], 42.into()).unwrap(), Value::from(42));
+    }
+}
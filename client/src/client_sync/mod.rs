@@ -2,7 +2,15 @@
 
 //! JSON-RPC clients for testing against specific versions of Bitcoin Core.
 
-mod error;
+mod address_reuse;
+mod capabilities;
+pub(crate) mod error;
+mod idempotent;
+pub mod middleware;
+pub mod redact;
+mod transactions;
+mod tx_windows;
+mod unlock;
 pub mod v17;
 pub mod v18;
 pub mod v19;
@@ -19,11 +27,23 @@ pub mod v29;
 pub mod v30;
 pub mod v31;
 
+use std::fmt;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 
-pub use crate::client_sync::error::Error;
+use jsonrpc::http::bitreq_http::BitreqHttpTransport;
+
+pub use crate::client_sync::address_reuse::{AddressReuse, ReusedAddress};
+pub use crate::client_sync::capabilities::Capabilities;
+pub use crate::client_sync::error::{DialError, Error};
+use crate::client_sync::middleware::Middleware;
+use crate::client_sync::redact::RedactionRules;
+pub use crate::client_sync::transactions::{FetchPage, Transactions};
+pub use crate::client_sync::tx_windows::{TxWindow, TxWindows};
+pub use crate::client_sync::unlock::UnlockGuard;
 
 /// Crate-specific Result type.
 ///
@@ -34,7 +54,7 @@ pub type Result<T> = std::result::Result<T, Error>;
 #[derive(Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Auth {
     None,
-    UserPass(String, String),
+    UserPass(Secret, Secret),
     CookieFile(PathBuf),
 }
 
@@ -43,31 +63,285 @@ impl Auth {
     pub fn get_user_pass(self) -> Result<(Option<String>, Option<String>)> {
         match self {
             Auth::None => Ok((None, None)),
-            Auth::UserPass(u, p) => Ok((Some(u), Some(p))),
-            Auth::CookieFile(path) => {
-                let line = BufReader::new(File::open(path)?)
+            Auth::UserPass(u, p) => Ok((Some(u.into_string()), Some(p.into_string()))),
+            Auth::CookieFile(ref path) => {
+                let mut line = BufReader::new(File::open(path)?)
                     .lines()
                     .next()
                     .ok_or(Error::InvalidCookieFile)??;
-                let colon = line.find(':').ok_or(Error::InvalidCookieFile)?;
-                Ok((Some(line[..colon].into()), Some(line[colon + 1..].into())))
+                let user_pass = match line.find(':') {
+                    Some(colon) => Ok((Some(line[..colon].into()), Some(line[colon + 1..].into()))),
+                    None => Err(Error::InvalidCookieFile),
+                };
+                wipe(&mut line);
+                user_pass
             }
         }
     }
 }
 
+/// The user or password of [`Auth::UserPass`].
+///
+/// With the `zeroize` feature the secret is wiped from memory when dropped. The `Debug`
+/// implementation prints [`redact::REDACTED`] in place of the secret.
+#[derive(Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+pub struct Secret(String);
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Secret").field(&format_args!("{}", redact::REDACTED)).finish()
+    }
+}
+
+impl Secret {
+    /// Returns the secret.
+    pub fn as_str(&self) -> &str { &self.0 }
+
+    /// Returns the secret as a `String`, which is not wiped when dropped.
+    pub fn into_string(mut self) -> String {
+        // Taken rather than moved out, `Secret` implements `Drop` with the `zeroize` feature.
+        std::mem::take(&mut self.0)
+    }
+}
+
+impl From<String> for Secret {
+    fn from(s: String) -> Self { Self(s) }
+}
+
+impl From<&str> for Secret {
+    fn from(s: &str) -> Self { Self(s.to_owned()) }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Secret {
+    fn drop(&mut self) { wipe(&mut self.0) }
+}
+
+/// Overwrites `secret` with zeros if the `zeroize` feature is enabled, does nothing otherwise.
+pub(crate) fn wipe(secret: &mut String) {
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(secret);
+    #[cfg(not(feature = "zeroize"))]
+    let _ = secret;
+}
+
+/// Wipes the string arguments of a call, see [`wipe`].
+///
+/// Used for the arguments holding wallet passphrases once the call returns. Copies made while
+/// serializing the request are not wiped.
+#[doc(hidden)]
+pub fn wipe_args(args: &mut [serde_json::Value]) {
+    for arg in args {
+        if let serde_json::Value::String(ref mut s) = arg {
+            wipe(s);
+        }
+    }
+}
+
+/// Raw JSON-RPC calls, implemented by the `Client` of every version.
+///
+/// Lets version agnostic code be written once for all the clients.
+pub trait Call {
+    /// Call an RPC `method` with given `args` list.
+    fn call<T: for<'a> serde::de::Deserialize<'a>>(
+        &self,
+        method: &str,
+        args: &[serde_json::Value],
+    ) -> Result<T>;
+}
+
+/// A handle used to abort the calls in flight on a `Client`.
+///
+/// Obtained with `Client::call_handle()`, can be sent to another thread. Calls aborted with
+/// [`CallHandle::cancel`] return an error, the client can be used as normal afterwards.
+#[derive(Clone, Debug)]
+pub struct CallHandle(jsonrpc::http::bitreq_http::CancelHandle);
+
+impl CallHandle {
+    pub(crate) fn new(handle: jsonrpc::http::bitreq_http::CancelHandle) -> Self { Self(handle) }
+
+    /// Aborts all calls currently in flight on the client this handle was obtained from.
+    pub fn cancel(&self) { self.0.cancel() }
+}
+
+/// The timeout used by a `Client` unless set with [`ClientBuilder::timeout`].
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The `User-Agent` sent by a `Client` unless set with [`ClientBuilder::user_agent`].
+pub const DEFAULT_USER_AGENT: &str = concat!("corepc-client/", env!("CARGO_PKG_VERSION"));
+
+/// Builder for a `Client`, obtained with `Client::builder()`.
+///
+/// Allows configuring what [`Client::new`] and [`Client::new_with_auth`] do not e.g., the
+/// headers required by a gateway in front of Core.
+///
+/// ```no_run
+/// # use corepc_client::client_sync::{v29::Client, Auth};
+/// let client = Client::builder("https://gateway.example.com")
+///     .path("/bitcoin/mainnet")
+///     .auth(Auth::UserPass("user".into(), "pass".into()))
+///     .header("X-Api-Key", "secret")
+///     .build()?;
+/// # Ok::<(), corepc_client::client_sync::Error>(())
+/// ```
+///
+/// See [`middleware`] to run code around every call e.g., to retry calls or collect metrics.
+///
+/// [`Client::new`]: crate::client_sync::v17::Client::new
+/// [`Client::new_with_auth`]: crate::client_sync::v17::Client::new_with_auth
+#[derive(Clone)]
+pub struct ClientBuilder<C> {
+    url: String,
+    path: Option<String>,
+    auth: Auth,
+    timeout: Duration,
+    user_agent: String,
+    headers: Vec<(String, String)>,
+    keep_alive: bool,
+    middleware: Vec<Arc<dyn Middleware>>,
+    redaction: RedactionRules,
+    /// Constructs the version specific client from the transport, middleware and redaction rules.
+    from_parts: fn(BitreqHttpTransport, Vec<Arc<dyn Middleware>>, RedactionRules) -> C,
+}
+
+impl<C> fmt::Debug for ClientBuilder<C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ClientBuilder")
+            .field("url", &self.url)
+            .field("path", &self.path)
+            .field("auth", &self.auth)
+            .field("timeout", &self.timeout)
+            .field("user_agent", &self.user_agent)
+            .field("headers", &self.headers)
+            .field("keep_alive", &self.keep_alive)
+            .field("middleware", &self.middleware.len())
+            .field("redaction", &self.redaction)
+            .finish()
+    }
+}
+
+impl<C> ClientBuilder<C> {
+    pub(crate) fn new(
+        url: &str,
+        from_parts: fn(BitreqHttpTransport, Vec<Arc<dyn Middleware>>, RedactionRules) -> C,
+    ) -> Self {
+        Self {
+            url: url.to_owned(),
+            path: None,
+            auth: Auth::None,
+            timeout: DEFAULT_TIMEOUT,
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+            headers: Vec::new(),
+            keep_alive: true,
+            middleware: Vec::new(),
+            redaction: RedactionRules::default(),
+            from_parts,
+        }
+    }
+
+    /// Sets the path of the JSON-RPC endpoint, appended to the URL e.g., `/bitcoin/mainnet`.
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = Some(path.to_owned());
+        self
+    }
+
+    /// Sets the authentication method, defaults to [`Auth::None`].
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Sets the timeout after which calls abort, defaults to 60 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the `User-Agent` HTTP header e.g., to tell apart the services calling a node in its
+    /// logs or in a proxy, defaults to [`DEFAULT_USER_AGENT`].
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_owned();
+        self
+    }
+
+    /// Adds an HTTP header to send with every call e.g., an API key.
+    pub fn header(mut self, key: &str, value: &str) -> Self {
+        self.headers.push((key.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Sets whether the connection to the node is kept open and reused between calls, defaults to
+    /// `true`. Saves a TCP handshake per call when making many calls.
+    pub fn keep_alive(mut self, keep_alive: bool) -> Self {
+        self.keep_alive = keep_alive;
+        self
+    }
+
+    /// Adds middleware to run around every call, the first added is the outermost.
+    pub fn middleware<M: Middleware + 'static>(mut self, middleware: M) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
+    /// Sets the arguments and results not logged, defaults to [`RedactionRules::default`].
+    pub fn redaction(mut self, rules: RedactionRules) -> Self {
+        self.redaction = rules;
+        self
+    }
+
+    /// Returns the URL of the JSON-RPC endpoint.
+    fn endpoint(&self) -> String {
+        match self.path {
+            Some(ref path) =>
+                format!("{}/{}", self.url.trim_end_matches('/'), path.trim_start_matches('/')),
+            None => self.url.clone(),
+        }
+    }
+
+    /// Builds the client.
+    ///
+    /// With [`Auth::CookieFile`] the cookie file is read again if the node rejects the credentials,
+    /// so that the client keeps working after the node restarted and wrote a new cookie.
+    ///
+    /// # Errors
+    ///
+    /// If the credentials cannot be read from the cookie file.
+    pub fn build(self) -> Result<C> {
+        let mut builder = jsonrpc::http::bitreq_http::Builder::new()
+            .url(&self.endpoint())
+            .expect("jsonrpc v0.19, this function does not error")
+            .timeout(self.timeout)
+            .user_agent(self.user_agent)
+            .keep_alive(self.keep_alive);
+        if let Auth::CookieFile(ref path) = self.auth {
+            builder = builder.reload_cookie_file(path.clone());
+        }
+        if let (Some(user), pass) = self.auth.get_user_pass()? {
+            builder = builder.basic_auth(user, pass);
+        }
+        for (key, value) in self.headers {
+            builder = builder.header(key, value);
+        }
+        Ok((self.from_parts)(builder.build(), self.middleware, self.redaction))
+    }
+}
+
 /// Defines a `jsonrpc::Client` using `bitreq`.
 #[macro_export]
 macro_rules! define_jsonrpc_bitreq_client {
     ($version:literal) => {
         use std::fmt;
 
-        use $crate::client_sync::{log_response, Auth, Result};
+        use $crate::client_sync::{log_response, Auth, CallHandle, ClientBuilder, Result};
         use $crate::client_sync::error::Error;
+        use $crate::client_sync::middleware::{Middleware, Next, RpcRequest, RpcResponse};
 
         /// Client implements a JSON-RPC client for the Bitcoin Core daemon or compatible APIs.
         pub struct Client {
             inner: jsonrpc::client::Client,
+            cancel: jsonrpc::http::bitreq_http::CancelHandle,
+            middleware: Vec<std::sync::Arc<dyn Middleware>>,
+            redaction: $crate::client_sync::redact::RedactionRules,
         }
 
         impl fmt::Debug for Client {
@@ -82,14 +356,7 @@ macro_rules! define_jsonrpc_bitreq_client {
         impl Client {
             /// Creates a client to a bitcoind JSON-RPC server without authentication.
             pub fn new(url: &str) -> Self {
-                let transport = jsonrpc::http::bitreq_http::Builder::new()
-                    .url(url)
-                    .expect("jsonrpc v0.19, this function does not error")
-                    .timeout(std::time::Duration::from_secs(60))
-                    .build();
-                let inner = jsonrpc::client::Client::with_transport(transport);
-
-                Self { inner }
+                Self::builder(url).build().expect("building without authentication does not error")
             }
 
             /// Creates a client to a bitcoind JSON-RPC server with authentication.
@@ -97,36 +364,64 @@ macro_rules! define_jsonrpc_bitreq_client {
                 if matches!(auth, Auth::None) {
                     return Err(Error::MissingUserPassword);
                 }
-                let (user, pass) = auth.get_user_pass()?;
-
-                let transport = jsonrpc::http::bitreq_http::Builder::new()
-                    .url(url)
-                    .expect("jsonrpc v0.19, this function does not error")
-                    .timeout(std::time::Duration::from_secs(60))
-                    .basic_auth(user.unwrap(), pass)
-                    .build();
-                let inner = jsonrpc::client::Client::with_transport(transport);
+                Self::builder(url).auth(auth).build()
+            }
 
-                Ok(Self { inner })
+            /// Returns a builder for a client to the bitcoind JSON-RPC server at `url`.
+            pub fn builder(url: &str) -> ClientBuilder<Self> {
+                ClientBuilder::new(url, |transport, middleware, redaction| {
+                    let cancel = transport.cancel_handle();
+                    let inner = jsonrpc::client::Client::with_transport(transport);
+                    Self { inner, cancel, middleware, redaction }
+                })
             }
 
+            /// Returns a handle that can be used to abort calls in flight from another thread.
+            pub fn call_handle(&self) -> CallHandle { CallHandle::new(self.cancel.clone()) }
+
             /// Call an RPC `method` with given `args` list.
             pub fn call<T: for<'a> serde::de::Deserialize<'a>>(
                 &self,
                 method: &str,
                 args: &[serde_json::Value],
             ) -> Result<T> {
-                let raw = serde_json::value::to_raw_value(args)?;
-                let req = self.inner.build_request(&method, Some(&*raw));
+                let req = RpcRequest { method, args };
+                if self.middleware.is_empty() {
+                    // Deserialize straight into `T`, without going through `RpcResponse`.
+                    return self.send_to_server(req);
+                }
+                let send = |req: RpcRequest<'_>| self.send_to_server::<RpcResponse>(req);
+                let result = Next::new(&self.middleware, &send).run(req)?;
+                Ok(serde_json::from_value(result)?)
+            }
+
+            /// Sends `req` to the server, the end of the middleware chain.
+            fn send_to_server<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                req: RpcRequest<'_>,
+            ) -> Result<T> {
+                let raw = serde_json::value::to_raw_value(req.args)?;
+                let request = self.inner.build_request(req.method, Some(&*raw));
                 if log::log_enabled!(log::Level::Debug) {
-                    log::debug!(target: "corepc", "request: {} {}", method, serde_json::Value::from(args));
+                    let args = self.redaction.redact_args(req.method, req.args);
+                    log::debug!(target: "corepc", "request: {} {}", req.method, args);
                 }
 
-                let resp = self.inner.send_request(req).map_err(Error::from);
-                log_response(method, &resp);
+                let resp = self.inner.send_request(request).map_err(Error::from);
+                log_response(req.method, &resp, self.redaction.redacts_result(req.method));
                 Ok(resp?.result()?)
             }
         }
+
+        impl $crate::client_sync::Call for Client {
+            fn call<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[serde_json::Value],
+            ) -> Result<T> {
+                Client::call(self, method, args)
+            }
+        }
     }
 }
 
@@ -157,16 +452,38 @@ macro_rules! impl_client_check_expected_server_version {
     };
 }
 
+/// Implements `capabilities()` on `Client`.
+///
+/// Requires `Client` to be in scope and implement `server_version()`.
+/// See and/or use `impl_client_v17__getnetworkinfo`.
+#[macro_export]
+macro_rules! impl_client_capabilities {
+    () => {
+        impl Client {
+            /// Returns the features supported by the `bitcoind` instance this client is connected to.
+            pub fn capabilities(&self) -> Result<$crate::client_sync::Capabilities> {
+                let server_version = self.server_version()?;
+                Ok($crate::client_sync::Capabilities::from_server_version(server_version))
+            }
+        }
+    };
+}
+
 /// Shorthand for converting a variable into a `serde_json::Value`.
-fn into_json<T>(val: T) -> Result<serde_json::Value>
+pub(crate) fn into_json<T>(val: T) -> Result<serde_json::Value>
 where
     T: serde::ser::Serialize,
 {
     Ok(serde_json::to_value(val)?)
 }
 
-/// Helper to log an RPC response.
-fn log_response(method: &str, resp: &Result<jsonrpc::Response>) {
+/// Returns `fee_rate` in sat/vB, the unit of the `fee_rate` arguments and options.
+pub(crate) fn sat_per_vb(fee_rate: bitcoin::FeeRate) -> f64 {
+    fee_rate.to_sat_per_kwu() as f64 / 250.0
+}
+
+/// Helper to log an RPC response, logs [`redact::REDACTED`] in place of the result if `redact`.
+pub(crate) fn log_response(method: &str, resp: &Result<jsonrpc::Response>, redact: bool) {
     use log::Level::{Debug, Trace, Warn};
 
     if log::log_enabled!(Warn) || log::log_enabled!(Debug) || log::log_enabled!(Trace) {
@@ -180,6 +497,8 @@ fn log_response(method: &str, resp: &Result<jsonrpc::Response>) {
                     if log::log_enabled!(Debug) {
                         log::debug!(target: "corepc", "response error for {}: {:?}", method, e);
                     }
+                } else if log::log_enabled!(Trace) && redact {
+                    log::trace!(target: "corepc", "response for {}: {}", method, redact::REDACTED);
                 } else if log::log_enabled!(Trace) {
                     let def =
                         serde_json::value::to_raw_value(&serde_json::value::Value::Null).unwrap();
@@ -189,3 +508,74 @@ fn log_response(method: &str, resp: &Result<jsonrpc::Response>) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_endpoint() {
+        let builder = v17::Client::builder("http://localhost:8332");
+        assert_eq!(builder.endpoint(), "http://localhost:8332");
+
+        let builder = builder.path("/bitcoin/mainnet");
+        assert_eq!(builder.endpoint(), "http://localhost:8332/bitcoin/mainnet");
+
+        let builder = v17::Client::builder("https://gateway.example.com/").path("bitcoin");
+        assert_eq!(builder.endpoint(), "https://gateway.example.com/bitcoin");
+    }
+
+    #[test]
+    fn builder_user_agent() {
+        let builder = v17::Client::builder("http://localhost:8332");
+        assert_eq!(builder.user_agent, DEFAULT_USER_AGENT);
+        assert!(DEFAULT_USER_AGENT.starts_with("corepc-client/"));
+
+        let builder = builder.user_agent("wallet-service/1.2");
+        assert_eq!(builder.user_agent, "wallet-service/1.2");
+    }
+
+    #[test]
+    fn auth_user_pass() {
+        let auth = Auth::UserPass("user".into(), "pass".into());
+        let (user, pass) = auth.get_user_pass().unwrap();
+        assert_eq!(user.as_deref(), Some("user"));
+        assert_eq!(pass.as_deref(), Some("pass"));
+    }
+
+    #[test]
+    fn auth_destructure() {
+        // `Auth` does not implement `Drop`, with or without the `zeroize` feature.
+        let Auth::UserPass(user, pass) = Auth::UserPass("user".into(), "pass".into()) else {
+            unreachable!()
+        };
+        assert_eq!((user.as_str(), pass.as_str()), ("user", "pass"));
+    }
+
+    #[test]
+    fn auth_debug_redacted() {
+        let auth = Auth::UserPass("user".into(), "pass".into());
+        assert_eq!(format!("{:?}", auth), "UserPass(Secret([redacted]), Secret([redacted]))");
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn wipe_args_strings() {
+        let mut args = [serde_json::Value::from("passphrase"), serde_json::Value::from(60)];
+        wipe_args(&mut args);
+        assert_eq!(args, [serde_json::Value::from(""), serde_json::Value::from(60)]);
+    }
+
+    #[test]
+    fn builder_missing_cookie_file() {
+        let auth = Auth::CookieFile(PathBuf::from("/nonexistent/.cookie"));
+        let result = v17::Client::builder("http://localhost:8332").auth(auth).build();
+        assert!(matches!(result, Err(Error::Io(_))));
+    }
+
+    #[test]
+    fn fee_rate_in_sat_per_vb() {
+        assert_eq!(sat_per_vb(bitcoin::FeeRate::from_sat_per_vb(7).unwrap()), 7.0);
+        assert_eq!(sat_per_vb(bitcoin::FeeRate::from_sat_per_kwu(1)), 0.004);
+    }
+}
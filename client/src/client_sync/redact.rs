@@ -0,0 +1,126 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Keeping secrets out of the logs.
+//!
+//! The client logs the arguments of every call at debug level and every result at trace level.
+//! [`RedactionRules`] list, by method, the arguments and results replaced by [`REDACTED`] in the
+//! logs. The defaults cover the private keys and passphrases of the methods the client
+//! implements, add rules with `ClientBuilder::redaction` e.g., for methods called with
+//! `Client::call`.
+
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+/// Logged in place of a redacted argument or result.
+pub const REDACTED: &str = "[redacted]";
+
+/// What is not logged for a method.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Redaction {
+    /// The positions of the redacted arguments.
+    args: Vec<usize>,
+    /// The result is redacted.
+    result: bool,
+}
+
+/// The arguments and results not logged, by method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedactionRules(BTreeMap<String, Redaction>);
+
+impl RedactionRules {
+    /// Returns rules redacting nothing.
+    pub fn none() -> Self { RedactionRules(BTreeMap::new()) }
+
+    /// Redacts the arguments of `method` at the zero-based positions `args`.
+    pub fn args(mut self, method: &str, args: &[usize]) -> Self {
+        self.0.entry(method.to_owned()).or_default().args.extend_from_slice(args);
+        self
+    }
+
+    /// Redacts the result of `method`.
+    pub fn result(mut self, method: &str) -> Self {
+        self.0.entry(method.to_owned()).or_default().result = true;
+        self
+    }
+
+    /// Returns `args` as logged for `method`.
+    pub(crate) fn redact_args(&self, method: &str, args: &[Value]) -> Value {
+        match self.0.get(method) {
+            Some(redaction) =>
+                args.iter()
+                    .enumerate()
+                    .map(|(i, arg)| {
+                        if redaction.args.contains(&i) {
+                            Value::from(REDACTED)
+                        } else {
+                            arg.clone()
+                        }
+                    })
+                    .collect(),
+            None => Value::from(args),
+        }
+    }
+
+    /// Returns `true` if the result of `method` is not logged.
+    pub(crate) fn redacts_result(&self, method: &str) -> bool {
+        self.0.get(method).is_some_and(|redaction| redaction.result)
+    }
+}
+
+impl Default for RedactionRules {
+    /// Redacts the private keys, passphrases and seeds passed to or returned by Core.
+    fn default() -> Self {
+        // Listed by hand, the `types::methods` registry knows whether a method changes the state of
+        // the node but not which of its arguments or results are secret.
+        RedactionRules::none()
+            .args("createwallet", &[3])
+            .args("encryptwallet", &[0])
+            .args("importdescriptors", &[0])
+            .args("importmulti", &[0])
+            .args("importprivkey", &[0])
+            .args("migratewallet", &[1])
+            .args("sethdseed", &[1])
+            .args("signmessagewithprivkey", &[0])
+            .args("signrawtransactionwithkey", &[1])
+            .args("walletpassphrase", &[0])
+            .args("walletpassphrasechange", &[0, 1])
+            .result("dumpprivkey")
+            .result("gethdkeys")
+            .result("listdescriptors")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_redacts_private_keys() {
+        let rules = RedactionRules::default();
+        let args = [Value::from("cSecret"), Value::from("label")];
+        assert_eq!(
+            rules.redact_args("importprivkey", &args),
+            serde_json::json!([REDACTED, "label"])
+        );
+        assert_eq!(rules.redact_args("getblock", &args), Value::from(&args[..]));
+        assert!(rules.redacts_result("dumpprivkey"));
+        assert!(!rules.redacts_result("getblock"));
+    }
+
+    #[test]
+    fn default_methods_exist() {
+        for method in RedactionRules::default().0.keys() {
+            assert!(types::methods::get(method).is_some(), "{}", method);
+        }
+    }
+
+    #[test]
+    fn custom_rules() {
+        let rules = RedactionRules::none().args("mymethod", &[1]).result("mymethod");
+        let args = [Value::from(1), Value::from("secret")];
+        assert_eq!(rules.redact_args("mymethod", &args), serde_json::json!([1, REDACTED]));
+        assert!(rules.redacts_result("mymethod"));
+        assert!(!rules.redacts_result("importprivkey"));
+    }
+}
@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Paging through the wallet transactions, using `listtransactions`.
+
+use std::collections::HashSet;
+
+use bitcoin::{SignedAmount, Txid};
+use types::model::TransactionItem;
+
+use crate::client_sync::Result;
+
+/// Fetches the page of at most `count` transactions after skipping the `skip` most recent ones,
+/// ordered from the oldest to the most recent as returned by `listtransactions`.
+pub type FetchPage<C> =
+    fn(client: &C, label: &str, count: usize, skip: usize) -> Result<Vec<TransactionItem>>;
+
+/// Identifies an entry across pages, the category of a coinbase entry changes as it matures.
+type Key = (Option<Txid>, u32, SignedAmount);
+
+/// An iterator over the wallet transactions, from the most recent to the oldest.
+///
+/// Obtained with `Client::transactions`. Transactions are fetched a page at a time. An entry
+/// pushed to the next page by transactions arriving while iterating is only returned once, but an
+/// entry pulled back to the previous page (e.g., by `abandontransaction`) may be missed.
+#[derive(Debug)]
+pub struct Transactions<'a, C> {
+    client: &'a C,
+    /// The label to filter by, `*` for all the transactions.
+    label: String,
+    page_size: usize,
+    /// The number of entries already fetched.
+    skip: usize,
+    /// The rest of the current page, the most recent entry last.
+    page: Vec<TransactionItem>,
+    /// Whether the current page is the last.
+    last_page: bool,
+    /// The entries already returned.
+    seen: HashSet<Key>,
+    fetch: FetchPage<C>,
+}
+
+impl<'a, C> Transactions<'a, C> {
+    /// Starts iterating over the transactions with `label`, all of them if `None`, fetching
+    /// `page_size` at a time.
+    ///
+    /// A `page_size` of zero is treated as one.
+    pub fn new(client: &'a C, label: Option<&str>, page_size: usize, fetch: FetchPage<C>) -> Self {
+        Transactions {
+            client,
+            label: label.unwrap_or("*").to_string(),
+            page_size: page_size.max(1),
+            skip: 0,
+            page: Vec::new(),
+            last_page: false,
+            seen: HashSet::new(),
+            fetch,
+        }
+    }
+}
+
+impl<C> Iterator for Transactions<'_, C> {
+    type Item = Result<TransactionItem>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.page.pop() {
+                if self.seen.insert((item.txid, item.vout, item.amount)) {
+                    return Some(Ok(item));
+                }
+                continue;
+            }
+            if self.last_page {
+                return None;
+            }
+            match (self.fetch)(self.client, &self.label, self.page_size, self.skip) {
+                Ok(page) => {
+                    self.last_page = page.len() < self.page_size;
+                    self.skip += page.len();
+                    self.page = page;
+                }
+                Err(e) => {
+                    self.last_page = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use bitcoin::hashes::Hash;
+    use types::model::{Bip125Replaceable, TransactionCategory};
+
+    use super::*;
+
+    /// A wallet whose transactions are the txids in `txids`, the most recent last.
+    struct Wallet {
+        txids: RefCell<Vec<u8>>,
+        /// Received after the first page was fetched.
+        incoming: Option<u8>,
+    }
+
+    fn item(txid: u8) -> TransactionItem {
+        TransactionItem {
+            involves_watch_only: None,
+            address: None,
+            category: TransactionCategory::Receive,
+            amount: SignedAmount::from_sat(50_000),
+            vout: 0,
+            fee: SignedAmount::ZERO,
+            confirmations: 1,
+            generated: None,
+            trusted: None,
+            block_hash: None,
+            block_height: None,
+            block_index: None,
+            block_time: None,
+            txid: Some(Txid::from_byte_array([txid; 32])),
+            wtxid: None,
+            wallet_conflicts: None,
+            replaced_by_txid: None,
+            replaces_txid: None,
+            mempool_conflicts: None,
+            to: None,
+            time: 0,
+            time_received: 0,
+            comment: None,
+            bip125_replaceable: Bip125Replaceable::Unknown,
+            parent_descriptors: None,
+            abandoned: None,
+            label: None,
+        }
+    }
+
+    fn fetch(
+        wallet: &Wallet,
+        label: &str,
+        count: usize,
+        skip: usize,
+    ) -> Result<Vec<TransactionItem>> {
+        assert_eq!(label, "*");
+        let mut txids = wallet.txids.borrow_mut();
+        let end = txids.len().saturating_sub(skip);
+        let page = txids[end.saturating_sub(count)..end].iter().copied().map(item).collect();
+        if let Some(txid) = wallet.incoming {
+            if skip == 0 {
+                txids.push(txid);
+            }
+        }
+        Ok(page)
+    }
+
+    fn txids(wallet: &Wallet, page_size: usize) -> Vec<u8> {
+        Transactions::new(wallet, None, page_size, fetch)
+            .map(|item| item.unwrap().txid.unwrap().to_byte_array()[0])
+            .collect()
+    }
+
+    #[test]
+    fn pages_from_most_recent() {
+        let wallet = Wallet { txids: RefCell::new((1..=7).collect()), incoming: None };
+        assert_eq!(txids(&wallet, 3), [7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(txids(&wallet, 7), [7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(txids(&wallet, 0), [7, 6, 5, 4, 3, 2, 1]);
+
+        let empty = Wallet { txids: RefCell::new(vec![]), incoming: None };
+        assert!(txids(&empty, 3).is_empty());
+    }
+
+    #[test]
+    fn deduplicates_across_pages() {
+        // Transaction 8 arrives after the first page, pushing 5 to the second page.
+        let wallet = Wallet { txids: RefCell::new((1..=7).collect()), incoming: Some(8) };
+        assert_eq!(txids(&wallet, 3), [7, 6, 5, 4, 3, 2, 1]);
+    }
+}
@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Transaction statistics over consecutive windows of blocks, using `getchaintxstats`.
+
+use bitcoin::BlockHash;
+use serde::Deserialize;
+
+use crate::client_sync::{Call, Result};
+
+/// The transactions in a window of blocks, the `Option`s of `getchaintxstats` replaced by zero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TxWindow {
+    /// The hash of the last block of the window.
+    pub final_block_hash: BlockHash,
+    /// The height of the last block of the window.
+    pub final_block_height: u64,
+    /// The number of blocks in the window, the window starts after height
+    /// `final_block_height - block_count`.
+    pub block_count: u64,
+    /// The number of transactions in the window.
+    pub tx_count: u64,
+    /// The time elapsed in the window in seconds, according to the block timestamps.
+    pub interval: i64,
+    /// The average number of transactions per second in the window, `0.0` if `interval` is not
+    /// positive.
+    pub tx_rate: f64,
+}
+
+/// The fields of `getchaintxstats` used by [`TxWindows`], the same in all versions of Core.
+#[derive(Deserialize)]
+struct ChainTxStats {
+    window_final_block_hash: String,
+    window_block_count: u64,
+    window_tx_count: Option<u64>,
+    window_interval: Option<i64>,
+    #[serde(rename = "txrate")]
+    tx_rate: Option<f64>,
+}
+
+/// An iterator over consecutive windows of blocks, from the tip back to the genesis block.
+///
+/// Obtained with `Client::tx_windows`. Each window is anchored on its last block by hash, so
+/// blocks mined while iterating do not shift the windows. The last window starts at height 2,
+/// Core needs the block before a window to compute its interval and does not use the genesis
+/// block.
+#[derive(Debug)]
+pub struct TxWindows<'a, C: Call> {
+    client: &'a C,
+    window_blocks: u64,
+    /// The height of the last block of the next window, `None` once done.
+    next_height: Option<u64>,
+}
+
+impl<'a, C: Call> TxWindows<'a, C> {
+    /// Starts iterating over windows of `window_blocks` blocks ending at the current tip.
+    pub fn new(client: &'a C, window_blocks: u64) -> Result<Self> {
+        let tip: u64 = client.call("getblockcount", &[])?;
+        let next_height = (window_blocks > 0 && tip > 1).then_some(tip);
+        Ok(TxWindows { client, window_blocks, next_height })
+    }
+
+    /// Returns the window of at most `window_blocks` blocks ending at `height`.
+    fn window(&self, height: u64) -> Result<TxWindow> {
+        let hash: String = self.client.call("getblockhash", &[height.into()])?;
+        // Core rejects windows starting at height 1 or before.
+        let nblocks = self.window_blocks.min(height - 1);
+        let stats: ChainTxStats =
+            self.client.call("getchaintxstats", &[nblocks.into(), hash.into()])?;
+        Ok(TxWindow {
+            final_block_hash: stats.window_final_block_hash.parse()?,
+            final_block_height: height,
+            block_count: stats.window_block_count,
+            tx_count: stats.window_tx_count.unwrap_or(0),
+            interval: stats.window_interval.unwrap_or(0),
+            tx_rate: stats.tx_rate.unwrap_or(0.0),
+        })
+    }
+}
+
+impl<C: Call> Iterator for TxWindows<'_, C> {
+    type Item = Result<TxWindow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let height = self.next_height.take()?;
+        let window = self.window(height);
+        if let Ok(ref window) = window {
+            let start = window.final_block_height - window.block_count;
+            self.next_height = (start > 1).then_some(start);
+        }
+        Some(window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A chain of `height` blocks, each with 2 transactions and 600 seconds after the previous.
+    struct Chain {
+        height: u64,
+    }
+
+    impl Call for Chain {
+        fn call<T: for<'a> serde::de::Deserialize<'a>>(
+            &self,
+            method: &str,
+            args: &[serde_json::Value],
+        ) -> Result<T> {
+            let value = match method {
+                "getblockcount" => self.height.into(),
+                "getblockhash" => format!("{:064x}", args[0].as_u64().unwrap()).into(),
+                "getchaintxstats" => {
+                    let nblocks = args[0].as_u64().unwrap();
+                    serde_json::json!({
+                        "window_final_block_hash": args[1],
+                        "window_block_count": nblocks,
+                        "window_tx_count": nblocks * 2,
+                        "window_interval": nblocks * 600,
+                        "txrate": 2.0 / 600.0,
+                    })
+                }
+                _ => unreachable!("{}", method),
+            };
+            Ok(serde_json::from_value(value)?)
+        }
+    }
+
+    #[test]
+    fn windows_back_to_genesis() {
+        let chain = Chain { height: 25 };
+        let windows = TxWindows::new(&chain, 10).unwrap().collect::<Result<Vec<_>>>().unwrap();
+        let heights = windows.iter().map(|w| (w.final_block_height, w.block_count));
+        assert_eq!(heights.collect::<Vec<_>>(), [(25, 10), (15, 10), (5, 4)]);
+        assert_eq!(windows[0].tx_count, 20);
+        assert_eq!(windows[0].final_block_hash.to_string(), format!("{:064x}", 25));
+
+        assert_eq!(TxWindows::new(&Chain { height: 1 }, 10).unwrap().count(), 0);
+        assert_eq!(TxWindows::new(&chain, 0).unwrap().count(), 0);
+    }
+}
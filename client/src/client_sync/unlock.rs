@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Keeping an encrypted wallet unlocked for a scope.
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::client_sync::{wipe, wipe_args, Call, Error, Result};
+
+/// An unlocked wallet, locked again with `walletlock` when dropped.
+///
+/// Obtained with `Client::unlock_wallet`. The wallet also locks itself once the timeout passed to
+/// `walletpassphrase` expires, call [`UnlockGuard::keep_unlocked_for`] during long operations.
+/// With the `zeroize` feature the passphrase kept to refresh the unlock is wiped when dropped.
+pub struct UnlockGuard<'a, C: Call> {
+    client: &'a C,
+    passphrase: String,
+    timeout: Duration,
+    /// When the wallet locks itself.
+    expires: Instant,
+}
+
+impl<'a, C: Call> UnlockGuard<'a, C> {
+    /// Unlocks the wallet of `client` for `timeout`, rounded down to whole seconds.
+    pub fn unlock(client: &'a C, passphrase: &str, timeout: Duration) -> Result<Self> {
+        let mut guard = UnlockGuard {
+            client,
+            passphrase: passphrase.to_owned(),
+            timeout,
+            expires: Instant::now(),
+        };
+        guard.refresh()?;
+        Ok(guard)
+    }
+
+    /// Returns how long until the wallet locks itself.
+    pub fn remaining(&self) -> Duration { self.expires.saturating_duration_since(Instant::now()) }
+
+    /// Unlocks the wallet again for the timeout it was first unlocked for.
+    pub fn refresh(&mut self) -> Result<()> {
+        let now = Instant::now();
+        let mut args: [serde_json::Value; 2] =
+            [self.passphrase.as_str().into(), self.timeout.as_secs().into()];
+        let res = self.client.call("walletpassphrase", &args);
+        wipe_args(&mut args);
+        match res {
+            Ok(serde_json::Value::Null) => {
+                self.expires = now + self.timeout;
+                Ok(())
+            }
+            Ok(res) => Err(Error::Returned(res.to_string())),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Refreshes the unlock if the wallet would lock itself within `duration`.
+    ///
+    /// Returns `true` if the unlock was refreshed. `duration` should not be longer than the
+    /// timeout, the wallet is then refreshed every time.
+    pub fn keep_unlocked_for(&mut self, duration: Duration) -> Result<bool> {
+        if self.remaining() >= duration {
+            return Ok(false);
+        }
+        self.refresh()?;
+        Ok(true)
+    }
+
+    /// Locks the wallet now, unlike dropping the guard this returns the error if there is one.
+    pub fn lock(mut self) -> Result<()> { self.wallet_lock() }
+
+    fn wallet_lock(&mut self) -> Result<()> {
+        // Nothing to do when dropped after `lock`.
+        self.expires = Instant::now();
+        match self.client.call("walletlock", &[]) {
+            Ok(serde_json::Value::Null) => Ok(()),
+            Ok(res) => Err(Error::Returned(res.to_string())),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl<C: Call> Drop for UnlockGuard<'_, C> {
+    fn drop(&mut self) {
+        if !self.remaining().is_zero() {
+            let _ = self.wallet_lock();
+        }
+        wipe(&mut self.passphrase);
+    }
+}
+
+impl<C: Call> fmt::Debug for UnlockGuard<'_, C> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("UnlockGuard")
+            .field("timeout", &self.timeout)
+            .field("remaining", &self.remaining())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    /// Records the methods called.
+    #[derive(Default)]
+    struct Recorder(RefCell<Vec<String>>);
+
+    impl Call for Recorder {
+        fn call<T: for<'a> serde::de::Deserialize<'a>>(
+            &self,
+            method: &str,
+            _args: &[serde_json::Value],
+        ) -> Result<T> {
+            self.0.borrow_mut().push(method.to_owned());
+            Ok(serde_json::from_value(serde_json::Value::Null)?)
+        }
+    }
+
+    #[test]
+    fn locks_when_dropped() {
+        let client = Recorder::default();
+        let mut guard = UnlockGuard::unlock(&client, "pass", Duration::from_secs(60)).unwrap();
+        assert!(!guard.keep_unlocked_for(Duration::from_secs(30)).unwrap());
+        assert!(guard.keep_unlocked_for(Duration::from_secs(61)).unwrap());
+        drop(guard);
+        assert_eq!(*client.0.borrow(), ["walletpassphrase", "walletpassphrase", "walletlock"]);
+    }
+
+    #[test]
+    fn lock_once() {
+        let client = Recorder::default();
+        let guard = UnlockGuard::unlock(&client, "pass", Duration::from_secs(60)).unwrap();
+        guard.lock().unwrap();
+        assert_eq!(*client.0.borrow(), ["walletpassphrase", "walletlock"]);
+    }
+}
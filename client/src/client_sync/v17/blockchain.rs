@@ -149,6 +149,32 @@ macro_rules! impl_client_v17__get_chain_tx_stats {
             pub fn get_chain_tx_stats(&self) -> Result<GetChainTxStats> {
                 self.call("getchaintxstats", &[])
             }
+
+            /// Returns the statistics of the `nblocks` blocks ending at `block_hash`.
+            pub fn get_chain_tx_stats_window(
+                &self,
+                nblocks: u64,
+                block_hash: bitcoin::BlockHash,
+            ) -> Result<GetChainTxStats> {
+                self.call("getchaintxstats", &[nblocks.into(), into_json(block_hash)?])
+            }
+
+            /// Returns the average number of transactions per second in the last `window_blocks`
+            /// blocks, `0.0` if the blocks have the same timestamp.
+            pub fn tx_rate(&self, window_blocks: u64) -> Result<f64> {
+                let stats: GetChainTxStats =
+                    self.call("getchaintxstats", &[window_blocks.into()])?;
+                Ok(stats.tx_rate.unwrap_or(0.0))
+            }
+
+            /// Returns an iterator over consecutive windows of `window_blocks` blocks, from the tip
+            /// back to the genesis block.
+            pub fn tx_windows(
+                &self,
+                window_blocks: u64,
+            ) -> Result<$crate::client_sync::TxWindows<'_, Self>> {
+                $crate::client_sync::TxWindows::new(self, window_blocks)
+            }
         }
     };
 }
@@ -263,6 +289,20 @@ macro_rules! impl_client_v17__get_tx_out {
 macro_rules! impl_client_v17__get_tx_out_proof {
     () => {
         impl Client {
+            /// Gets a proof that `txids` are included in a block, in `block_hash` if given.
+            pub fn tx_out_proof(
+                &self,
+                txids: &[Txid],
+                block_hash: Option<BlockHash>,
+            ) -> Result<bitcoin::MerkleBlock> {
+                let mut args = vec![into_json(txids)?];
+                if let Some(block_hash) = block_hash {
+                    args.push(into_json(block_hash)?);
+                }
+                let hex: String = self.call("gettxoutproof", &args)?;
+                Ok(bitcoin::consensus::encode::deserialize_hex(&hex)?)
+            }
+
             pub fn get_tx_out_proof(&self, txids: &[Txid]) -> Result<String> {
                 self.call("gettxoutproof", &[into_json(txids)?])
             }
@@ -368,9 +408,13 @@ macro_rules! impl_client_v17__verify_chain {
 macro_rules! impl_client_v17__verify_tx_out_proof {
     () => {
         impl Client {
-            // `proof` is the hex-encoded proof generated by `gettxoutproof`.
-            pub fn verify_tx_out_proof(&self, proof: &str) -> Result<VerifyTxOutProof> {
-                self.call("verifytxoutproof", &[into_json(proof)?])
+            // `proof` is the proof returned by `tx_out_proof`.
+            pub fn verify_tx_out_proof(
+                &self,
+                proof: &bitcoin::MerkleBlock,
+            ) -> Result<VerifyTxOutProof> {
+                let hex = bitcoin::consensus::encode::serialize_hex(proof);
+                self.call("verifytxoutproof", &[into_json(hex)?])
             }
         }
     };
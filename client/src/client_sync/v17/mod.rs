@@ -27,6 +27,7 @@ use crate::types::v17::*;
 
 crate::define_jsonrpc_bitreq_client!("v17");
 crate::impl_client_check_expected_server_version!({ [170200] });
+crate::impl_client_capabilities!();
 
 // == Blockchain ==
 crate::impl_client_v17__get_best_block_hash!();
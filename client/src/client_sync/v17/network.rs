@@ -21,6 +21,35 @@ macro_rules! impl_client_v17__add_node {
                     Err(err) => Err(err.into()),
                 }
             }
+
+            /// Connects to `address` once, using `addnode onetry`, and waits up to `timeout` for
+            /// the outbound connection to show up in `getpeerinfo`.
+            ///
+            /// Returns the entry of the new peer, or why there is none.
+            pub fn dial(
+                &self,
+                address: &str,
+                timeout: std::time::Duration,
+            ) -> std::result::Result<PeerInfo, $crate::client_sync::DialError> {
+                use $crate::client_sync::DialError;
+
+                let is_dialed = |peer: &PeerInfo| !peer.inbound && peer.address == address;
+                if self.get_peer_info()?.0.iter().any(is_dialed) {
+                    return Err(DialError::AlreadyConnected);
+                }
+                self.add_node(address, AddNodeCommand::OneTry)?;
+
+                let start = std::time::Instant::now();
+                loop {
+                    if let Some(peer) = self.get_peer_info()?.0.into_iter().find(is_dialed) {
+                        return Ok(peer);
+                    }
+                    if start.elapsed() >= timeout {
+                        return Err(DialError::Timeout(timeout));
+                    }
+                    std::thread::sleep(std::time::Duration::from_millis(100));
+                }
+            }
         }
     };
 }
@@ -157,6 +186,22 @@ macro_rules! impl_client_v17__set_ban {
                     Err(err) => Err(err.into()),
                 }
             }
+
+            /// Bans `subnet` for `duration`, rounded down to whole seconds.
+            ///
+            /// A `duration` of less than a second bans for the node's default `-bantime`.
+            pub fn ban(&self, subnet: &str, duration: std::time::Duration) -> Result<()> {
+                let args = [
+                    into_json(subnet)?,
+                    into_json(SetBanCommand::Add)?,
+                    into_json(duration.as_secs())?,
+                ];
+                match self.call("setban", &args) {
+                    Ok(serde_json::Value::Null) => Ok(()),
+                    Ok(res) => Err(Error::Returned(res.to_string())),
+                    Err(err) => Err(err.into()),
+                }
+            }
         }
     };
 }
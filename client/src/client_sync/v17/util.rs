@@ -50,13 +50,22 @@ macro_rules! impl_client_v17__estimate_smart_fee {
 macro_rules! impl_client_v17__sign_message_with_priv_key {
     () => {
         impl Client {
-            pub fn sign_message_with_privkey(
+            pub fn sign_message_with_priv_key(
                 &self,
                 privkey: &bitcoin::PrivateKey,
                 message: &str,
             ) -> Result<SignMessageWithPrivKey> {
                 self.call("signmessagewithprivkey", &[into_json(privkey)?, message.into()])
             }
+
+            #[deprecated(since = "0.15.0", note = "use `sign_message_with_priv_key` instead")]
+            pub fn sign_message_with_privkey(
+                &self,
+                privkey: &bitcoin::PrivateKey,
+                message: &str,
+            ) -> Result<SignMessageWithPrivKey> {
+                self.sign_message_with_priv_key(privkey, message)
+            }
         }
     };
 }
@@ -131,7 +131,10 @@ macro_rules! impl_client_v17__encrypt_wallet {
         impl Client {
             // filename is either absolute or relative to bitcoind.
             pub fn encrypt_wallet(&self, passphrase: &str) -> Result<EncryptWallet> {
-                self.call("encryptwallet", &[into_json(passphrase)?])
+                let mut args = [into_json(passphrase)?];
+                let res = self.call("encryptwallet", &args);
+                $crate::client_sync::wipe_args(&mut args);
+                res
             }
         }
     };
@@ -463,6 +466,30 @@ macro_rules! impl_client_v17__list_transactions {
             pub fn list_transactions(&self) -> Result<ListTransactions> {
                 self.call("listtransactions", &[])
             }
+
+            /// Returns an iterator over the wallet transactions with `label`, all of them if
+            /// `None`, from the most recent to the oldest.
+            ///
+            /// Calls `listtransactions` for `page_size` transactions at a time.
+            pub fn transactions<'a>(
+                &'a self,
+                label: Option<&str>,
+                page_size: usize,
+            ) -> $crate::client_sync::Transactions<'a, Self> {
+                $crate::client_sync::Transactions::new(
+                    self,
+                    label,
+                    page_size,
+                    |client, label, count, skip| {
+                        let json: ListTransactions = client
+                            .call("listtransactions", &[label.into(), count.into(), skip.into()])?;
+                        let model = json
+                            .into_model()
+                            .map_err(|e| $crate::client_sync::Error::Model(Box::new(e)))?;
+                        Ok(model.0)
+                    },
+                )
+            }
         }
     };
 }
@@ -473,6 +500,21 @@ macro_rules! impl_client_v17__list_unspent {
     () => {
         impl Client {
             pub fn list_unspent(&self) -> Result<ListUnspent> { self.call("listunspent", &[]) }
+
+            /// Returns the addresses of the wallet that received more than one output, to audit
+            /// the privacy of the wallet.
+            ///
+            /// Scans all the wallet transactions with `listtransactions` and the unspent outputs,
+            /// unconfirmed included, with `listunspent`. Also reports whether the `avoid_reuse`
+            /// wallet flag is set and which unspent outputs the wallet marks as reused.
+            pub fn detect_address_reuse(&self) -> Result<$crate::client_sync::AddressReuse> {
+                use $crate::client_sync::address_reuse;
+
+                let transactions = self.transactions(None, address_reuse::PAGE_SIZE);
+                let unspent = self.call("listunspent", &[0.into()])?;
+                let flags: address_reuse::WalletFlags = self.call("getwalletinfo", &[])?;
+                address_reuse::detect(transactions, unspent, flags.avoid_reuse)
+            }
         }
     };
 }
@@ -606,6 +648,36 @@ macro_rules! impl_client_v17__send_to_address {
                 ];
                 self.call("sendtoaddress", &args)
             }
+
+            /// Sends `amount` to `address` once per `request_id`, safe to retry after an error.
+            ///
+            /// The request id is recorded as the comment of the transaction. If the wallet already
+            /// sent a transaction for it, that is returned instead of sending again, or an error if
+            /// it pays a different address or amount. Abandoned and conflicted transactions do not
+            /// count as sent.
+            ///
+            /// Concurrent calls with the same request id may both send, serialize them.
+            pub fn send_idempotent(
+                &self,
+                request_id: &str,
+                address: &Address<NetworkChecked>,
+                amount: Amount,
+            ) -> Result<Txid> {
+                use $crate::client_sync::idempotent;
+
+                let transactions = self.transactions(None, idempotent::PAGE_SIZE);
+                let sent = idempotent::find_sent(transactions, request_id, address, amount)?;
+                if let Some(txid) = sent {
+                    return Ok(txid);
+                }
+                let args = [
+                    address.to_string().into(),
+                    into_json(amount.to_btc())?,
+                    idempotent::comment(request_id).into(),
+                ];
+                let json: SendToAddress = self.call("sendtoaddress", &args)?;
+                Ok(json.txid()?)
+            }
         }
     };
 }
@@ -690,12 +762,24 @@ macro_rules! impl_client_v17__wallet_passphrase {
     () => {
         impl Client {
             pub fn wallet_passphrase(&self, passphrase: &str, timeout: u64) -> Result<()> {
-                match self.call("walletpassphrase", &[passphrase.into(), timeout.into()]) {
+                let mut args: [serde_json::Value; 2] = [passphrase.into(), timeout.into()];
+                let res = self.call("walletpassphrase", &args);
+                $crate::client_sync::wipe_args(&mut args);
+                match res {
                     Ok(serde_json::Value::Null) => Ok(()),
                     Ok(res) => Err(Error::Returned(res.to_string())),
                     Err(err) => Err(err.into()),
                 }
             }
+
+            /// Unlocks the wallet for `timeout`, it is locked again when the guard is dropped.
+            pub fn unlock_wallet(
+                &self,
+                passphrase: &str,
+                timeout: std::time::Duration,
+            ) -> Result<$crate::client_sync::UnlockGuard<'_, Self>> {
+                $crate::client_sync::UnlockGuard::unlock(self, passphrase, timeout)
+            }
         }
     };
 }
@@ -751,9 +835,11 @@ macro_rules! impl_client_v17__wallet_passphrase_change {
                 old_passphrase: &str,
                 new_passphrase: &str,
             ) -> Result<()> {
-                match self
-                    .call("walletpassphrasechange", &[old_passphrase.into(), new_passphrase.into()])
-                {
+                let mut args: [serde_json::Value; 2] =
+                    [old_passphrase.into(), new_passphrase.into()];
+                let res = self.call("walletpassphrasechange", &args);
+                $crate::client_sync::wipe_args(&mut args);
+                match res {
                     Ok(serde_json::Value::Null) => Ok(()),
                     Ok(res) => Err(Error::Returned(res.to_string())),
                     Err(err) => Err(err.into()),
@@ -31,6 +31,7 @@ pub use crate::client_sync::{
 // This publicly re-exports `Client`.
 crate::define_jsonrpc_bitreq_client!("v18");
 crate::impl_client_check_expected_server_version!({ [180100] });
+crate::impl_client_capabilities!();
 
 // == Blockchain ==
 crate::impl_client_v17__get_blockchain_info!();
@@ -26,6 +26,7 @@ pub use crate::client_sync::{
 
 crate::define_jsonrpc_bitreq_client!("v19");
 crate::impl_client_check_expected_server_version!({ [190100] });
+crate::impl_client_capabilities!();
 
 // == Blockchain ==
 crate::impl_client_v17__get_best_block_hash!();
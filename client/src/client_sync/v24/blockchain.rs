@@ -26,6 +26,34 @@ macro_rules! impl_client_v24__get_tx_spending_prevout {
                 }).collect();
                 self.call("gettxspendingprevout", &[json_outputs.into()])
             }
+
+            /// Returns the txid of the mempool transaction spending `outpoint`, `None` if unspent.
+            pub fn is_spent_in_mempool(
+                &self,
+                outpoint: bitcoin::OutPoint,
+            ) -> Result<Option<bitcoin::Txid>> {
+                let spent = self.spent_in_mempool(&[outpoint])?;
+                Ok(spent.get(&outpoint).copied())
+            }
+
+            /// Returns the txids of the mempool transactions spending any of `outpoints`.
+            ///
+            /// Outpoints not spent by a transaction in the mempool are omitted.
+            pub fn spent_in_mempool(
+                &self,
+                outpoints: &[bitcoin::OutPoint],
+            ) -> Result<std::collections::BTreeMap<bitcoin::OutPoint, bitcoin::Txid>> {
+                let model = self
+                    .get_tx_spending_prevout(outpoints)?
+                    .into_model()
+                    .map_err(|e| $crate::client_sync::Error::Model(Box::new(e)))?;
+                let spent = model
+                    .0
+                    .into_iter()
+                    .filter_map(|item| item.spending_txid.map(|txid| (item.outpoint, txid)))
+                    .collect();
+                Ok(spent)
+            }
         }
     };
 }
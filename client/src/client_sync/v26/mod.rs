@@ -31,6 +31,7 @@ pub use crate::client_sync::{
 
 crate::define_jsonrpc_bitreq_client!("v26");
 crate::impl_client_check_expected_server_version!({ [260000, 260100, 260200] });
+crate::impl_client_capabilities!();
 
 // == Blockchain ==
 crate::impl_client_v26__dump_tx_out_set!();
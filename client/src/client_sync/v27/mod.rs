@@ -27,6 +27,7 @@ pub use crate::client_sync::{
 
 crate::define_jsonrpc_bitreq_client!("v27");
 crate::impl_client_check_expected_server_version!({ [270000, 270100, 270200] });
+crate::impl_client_capabilities!();
 
 // == Blockchain ==
 crate::impl_client_v26__dump_tx_out_set!();
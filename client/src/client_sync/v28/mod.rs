@@ -28,6 +28,7 @@ pub use crate::client_sync::{
 
 crate::define_jsonrpc_bitreq_client!("v28");
 crate::impl_client_check_expected_server_version!({ [280000, 280100, 280200] });
+crate::impl_client_capabilities!();
 
 // == Blockchain ==
 crate::impl_client_v26__dump_tx_out_set!();
@@ -30,6 +30,7 @@ pub use crate::client_sync::{
 
 crate::define_jsonrpc_bitreq_client!("v31");
 crate::impl_client_check_expected_server_version!({ [310000] });
+crate::impl_client_capabilities!();
 
 // == Blockchain ==
 crate::impl_client_v29__dump_tx_out_set!();
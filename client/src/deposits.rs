@@ -0,0 +1,258 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Detection of incoming wallet payments.
+//!
+//! [`DepositTracker`] turns the result of `listsinceblock` into [`Event`]s as deposits are seen,
+//! confirmed and (if a reorg or a conflicting transaction removes them) reversed. It does not
+//! talk to Core itself, which keeps it independent of the version specific clients. Call
+//! `listsinceblock` on a timer, or whenever a ZMQ `hashblock`/`hashtx` notification arrives, and
+//! feed the result to [`DepositTracker::update`].
+//!
+//! ```no_run
+//! # #[cfg(feature = "client-sync")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use corepc_client::client_sync::{v28::Client, Auth};
+//! use corepc_client::deposits::{DepositTracker, Event};
+//!
+//! let cookie = Auth::CookieFile("/home/user/.bitcoin/.cookie".into());
+//! let client = Client::new_with_auth("http://localhost:8332", cookie)?;
+//! let mut tracker = DepositTracker::new(6).with_reorg_margin(6);
+//! loop {
+//!     let list = client.list_since_block()?.into_model()?;
+//!     for event in tracker.update(&list) {
+//!         if let Event::Confirmed(deposit) = event {
+//!             println!("credit {} to {:?}", deposit.amount, deposit.address);
+//!         }
+//!     }
+//!     std::thread::sleep(std::time::Duration::from_secs(10));
+//! }
+//! # }
+//! # #[cfg(not(feature = "client-sync"))]
+//! # fn main() {}
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use bitcoin::address::NetworkUnchecked;
+use bitcoin::{Address, Amount, OutPoint};
+use types::model::{ListSinceBlock, TransactionCategory, TransactionItem};
+
+/// A payment received by the wallet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Deposit {
+    /// The output paying the wallet.
+    pub outpoint: OutPoint,
+    /// The wallet address that was paid.
+    pub address: Address<NetworkUnchecked>,
+    /// The amount received.
+    pub amount: Amount,
+    /// The number of confirmations when the event was emitted.
+    pub confirmations: u32,
+}
+
+/// A change in the state of a deposit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    /// A new deposit with fewer than the required number of confirmations.
+    Pending(Deposit),
+    /// The deposit reached the required number of confirmations.
+    Confirmed(Deposit),
+    /// A previously reported deposit is no longer valid, either it was reorged out below the
+    /// required number of confirmations or it conflicts with another transaction.
+    ///
+    /// A deposit reorged out but still in the mempool will be reported again.
+    Reversed(Deposit),
+}
+
+/// Tracks wallet deposits across calls to `listsinceblock`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DepositTracker {
+    /// Confirmations before a deposit is reported as confirmed.
+    required: u32,
+    /// Additional confirmations a confirmed deposit is watched for reorgs.
+    reorg_margin: u32,
+    /// Deposits being watched, and whether they were reported as confirmed.
+    tracked: BTreeMap<OutPoint, bool>,
+    /// Deposits buried deep enough that they are no longer watched.
+    finalized: BTreeSet<OutPoint>,
+}
+
+impl DepositTracker {
+    /// Creates a tracker that reports deposits as confirmed at `required` confirmations.
+    ///
+    /// Use `0` to report deposits as confirmed as soon as they are seen in the mempool.
+    pub fn new(required: u32) -> Self {
+        DepositTracker {
+            required,
+            reorg_margin: 0,
+            tracked: BTreeMap::new(),
+            finalized: BTreeSet::new(),
+        }
+    }
+
+    /// Keeps watching confirmed deposits for `blocks` more confirmations so that a reorg undoing
+    /// them is reported as [`Event::Reversed`].
+    pub fn with_reorg_margin(mut self, blocks: u32) -> Self {
+        self.reorg_margin = blocks;
+        self
+    }
+
+    /// Returns the number of deposits currently being watched.
+    pub fn tracked(&self) -> usize { self.tracked.len() }
+
+    /// Updates the tracker from the result of `listsinceblock`, returns the resulting events.
+    ///
+    /// Call `listsinceblock` with a block old enough to cover `required + reorg_margin`
+    /// confirmations (or with no block) so that no watched deposit is missing from `list`.
+    pub fn update(&mut self, list: &ListSinceBlock) -> Vec<Event> {
+        let mut events = vec![];
+
+        for item in &list.removed {
+            if let Some(deposit) = deposit(item) {
+                self.reverse(deposit, &mut events);
+            }
+        }
+
+        for item in &list.transactions {
+            let Some(deposit) = deposit(item) else { continue };
+            if self.finalized.contains(&deposit.outpoint) {
+                continue;
+            }
+            // Negative confirmations mean the transaction conflicts with the chain.
+            if item.confirmations < 0 {
+                self.reverse(deposit, &mut events);
+                continue;
+            }
+
+            let confirmed = deposit.confirmations >= self.required;
+            let was_confirmed = self.tracked.insert(deposit.outpoint, confirmed);
+            match (was_confirmed, confirmed) {
+                (None, false) => events.push(Event::Pending(deposit.clone())),
+                (None | Some(false), true) => events.push(Event::Confirmed(deposit.clone())),
+                (Some(true), false) => events.push(Event::Reversed(deposit.clone())),
+                (Some(false), false) | (Some(true), true) => {}
+            }
+
+            if deposit.confirmations >= self.required.saturating_add(self.reorg_margin) {
+                self.tracked.remove(&deposit.outpoint);
+                self.finalized.insert(deposit.outpoint);
+            }
+        }
+        events
+    }
+
+    /// Stops watching `deposit`, reporting it as reversed if it was reported before.
+    fn reverse(&mut self, deposit: Deposit, events: &mut Vec<Event>) {
+        if self.tracked.remove(&deposit.outpoint).is_some() {
+            events.push(Event::Reversed(deposit));
+        }
+    }
+}
+
+/// Returns the deposit described by `item`, `None` if it is not an incoming payment.
+fn deposit(item: &TransactionItem) -> Option<Deposit> {
+    if item.category != TransactionCategory::Receive {
+        return None;
+    }
+    Some(Deposit {
+        outpoint: OutPoint { txid: item.txid?, vout: item.vout },
+        address: item.address.clone()?,
+        amount: item.amount.to_unsigned().ok()?,
+        confirmations: u32::try_from(item.confirmations).unwrap_or(0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::hashes::Hash as _;
+    use bitcoin::{BlockHash, SignedAmount, Txid};
+    use types::model::Bip125Replaceable;
+
+    use super::*;
+
+    fn receive(txid: u8, confirmations: i64) -> TransactionItem {
+        TransactionItem {
+            involves_watch_only: None,
+            address: Some("bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080".parse().unwrap()),
+            category: TransactionCategory::Receive,
+            amount: SignedAmount::from_sat(50_000),
+            vout: 0,
+            fee: SignedAmount::ZERO,
+            confirmations,
+            generated: None,
+            trusted: None,
+            block_hash: None,
+            block_height: None,
+            block_index: None,
+            block_time: None,
+            txid: Some(Txid::from_byte_array([txid; 32])),
+            wtxid: None,
+            wallet_conflicts: None,
+            replaced_by_txid: None,
+            replaces_txid: None,
+            mempool_conflicts: None,
+            to: None,
+            time: 0,
+            time_received: 0,
+            comment: None,
+            bip125_replaceable: Bip125Replaceable::Unknown,
+            parent_descriptors: None,
+            abandoned: None,
+            label: None,
+        }
+    }
+
+    fn list(transactions: Vec<TransactionItem>) -> ListSinceBlock {
+        ListSinceBlock { transactions, removed: vec![], last_block: BlockHash::all_zeros() }
+    }
+
+    fn confirmations(events: &[Event]) -> Vec<(&'static str, u32)> {
+        events
+            .iter()
+            .map(|event| match event {
+                Event::Pending(d) => ("pending", d.confirmations),
+                Event::Confirmed(d) => ("confirmed", d.confirmations),
+                Event::Reversed(d) => ("reversed", d.confirmations),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn deposit_lifecycle() {
+        let mut tracker = DepositTracker::new(2).with_reorg_margin(1);
+
+        let events = tracker.update(&list(vec![receive(1, 0)]));
+        assert_eq!(confirmations(&events), vec![("pending", 0)]);
+        assert!(tracker.update(&list(vec![receive(1, 1)])).is_empty());
+
+        let events = tracker.update(&list(vec![receive(1, 2)]));
+        assert_eq!(confirmations(&events), vec![("confirmed", 2)]);
+
+        // Reorged back into the mempool.
+        let events = tracker.update(&list(vec![receive(1, 0)]));
+        assert_eq!(confirmations(&events), vec![("reversed", 0)]);
+
+        let events = tracker.update(&list(vec![receive(1, 3)]));
+        assert_eq!(confirmations(&events), vec![("confirmed", 3)]);
+        assert_eq!(tracker.tracked(), 0);
+        assert!(tracker.update(&list(vec![receive(1, 4)])).is_empty());
+    }
+
+    #[test]
+    fn conflicted_deposit_is_reversed() {
+        let mut tracker = DepositTracker::new(1);
+        tracker.update(&list(vec![receive(1, 0)]));
+
+        let events = tracker.update(&list(vec![receive(1, -1)]));
+        assert_eq!(confirmations(&events), vec![("reversed", 0)]);
+        assert_eq!(tracker.tracked(), 0);
+    }
+
+    #[test]
+    fn ignores_outgoing_payments() {
+        let mut tracker = DepositTracker::new(1);
+        let mut send = receive(1, 0);
+        send.category = TransactionCategory::Send;
+        assert!(tracker.update(&list(vec![send])).is_empty());
+    }
+}
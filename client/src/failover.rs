@@ -0,0 +1,297 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A client failing over between several nodes.
+//!
+//! [`FailoverClient`] sends each call to the first healthy node in its list, and to the next one if
+//! the node could not be reached (a transport error) or is still starting up. A node that failed is
+//! skipped until a cooldown passes or [`FailoverClient::check_health`] finds it reachable again.
+//! Errors returned by a node for the call itself are returned as is, they would be the same on
+//! any node.
+//!
+//! Wallets may not be loaded on all the nodes. A call failing because the node does not have the
+//! wallet is sent to the next node, and from then on wallet calls skip the nodes known not to have
+//! it. Each node is a `Client` of any version, use the wallet URL of the node for wallet calls.
+//!
+//! A call that timed out may have been executed by the node, failing over then sends it again.
+//! Prefer idempotent calls e.g., `Client::send_idempotent` for payments.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use corepc_client::client_sync::{v29::Client, Auth, Call};
+//! use corepc_client::failover::FailoverClient;
+//!
+//! let primary = Client::new_with_auth("http://10.0.0.1:8332", Auth::CookieFile("a".into()))?;
+//! let backup = Client::new_with_auth("http://10.0.0.2:8332", Auth::CookieFile("b".into()))?;
+//! let client = FailoverClient::new(vec![("primary", primary), ("backup", backup)]);
+//!
+//! let height: u64 = client.call("getblockcount", &[])?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::client_sync::{Call, Error, Result};
+
+/// The RPC error code of a node still loading its state.
+const RPC_IN_WARMUP: i32 = -28;
+
+/// The RPC error codes of a call to a wallet the node does not have, or without a wallet while the
+/// node has several.
+const RPC_WALLET_MISSING: [i32; 2] = [-18, -19];
+
+/// How long a node that failed is skipped by default.
+const DEFAULT_COOLDOWN: Duration = Duration::from_secs(30);
+
+/// The method called by [`FailoverClient::check_health`], available in all versions of Core.
+const HEALTH_CHECK_METHOD: &str = "getblockcount";
+
+/// A client sending calls to the first healthy node of a list, see the [module docs](self).
+pub struct FailoverClient<C> {
+    nodes: Vec<(String, C)>,
+    cooldown: Duration,
+    state: Mutex<State>,
+}
+
+/// What the client learned about the nodes.
+#[derive(Debug, Default)]
+struct State {
+    /// Until when each node is skipped, indexed like the nodes.
+    down_until: Vec<Option<Instant>>,
+    /// The nodes that returned a missing wallet error.
+    without_wallet: HashSet<usize>,
+    /// The methods that returned a missing wallet error on some node.
+    wallet_methods: HashSet<String>,
+}
+
+impl<C> fmt::Debug for FailoverClient<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let labels: Vec<_> = self.nodes.iter().map(|(label, _)| label).collect();
+        f.debug_struct("FailoverClient")
+            .field("nodes", &labels)
+            .field("cooldown", &self.cooldown)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl<C: Call> FailoverClient<C> {
+    /// Creates a client over the labelled `nodes`, in order of preference.
+    pub fn new<L: Into<String>>(nodes: Vec<(L, C)>) -> Self {
+        let nodes: Vec<_> = nodes.into_iter().map(|(label, node)| (label.into(), node)).collect();
+        let state = State { down_until: vec![None; nodes.len()], ..Default::default() };
+        FailoverClient { nodes, cooldown: DEFAULT_COOLDOWN, state: Mutex::new(state) }
+    }
+
+    /// Sets how long a node that failed is skipped, 30 seconds by default.
+    pub fn with_cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+
+    /// Calls each node and updates whether it is healthy, returns the health of each node.
+    pub fn check_health(&self) -> Vec<NodeHealth> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(index, (label, node))| {
+                let result: Result<serde_json::Value> = node.call(HEALTH_CHECK_METHOD, &[]);
+                let healthy = !matches!(result, Err(ref e) if is_transient(e));
+                let mut state = self.state.lock().expect("poisoned lock");
+                state.down_until[index] =
+                    if healthy { None } else { Some(Instant::now() + self.cooldown) };
+                NodeHealth { label: label.clone(), healthy }
+            })
+            .collect()
+    }
+
+    /// Returns the health of each node as last seen, without calling them.
+    pub fn health(&self) -> Vec<NodeHealth> {
+        let state = self.state.lock().expect("poisoned lock");
+        let now = Instant::now();
+        self.nodes
+            .iter()
+            .zip(&state.down_until)
+            .map(|((label, _), until)| NodeHealth {
+                label: label.clone(),
+                healthy: until.map_or(true, |until| until <= now),
+            })
+            .collect()
+    }
+
+    /// Returns the indexes of the nodes to send a call to `method` to, in order.
+    ///
+    /// Nodes that are down come last, so that a call is attempted even if all the nodes failed
+    /// recently.
+    fn candidates(&self, method: &str) -> Vec<usize> {
+        let state = self.state.lock().expect("poisoned lock");
+        let now = Instant::now();
+        let wallet_call = state.wallet_methods.contains(method);
+        let (mut up, down): (Vec<usize>, Vec<usize>) = (0..self.nodes.len())
+            .filter(|index| !(wallet_call && state.without_wallet.contains(index)))
+            .partition(|&index| state.down_until[index].map_or(true, |until| until <= now));
+        up.extend(down);
+        up
+    }
+}
+
+impl<C: Call> Call for FailoverClient<C> {
+    fn call<T: for<'a> serde::de::Deserialize<'a>>(
+        &self,
+        method: &str,
+        args: &[serde_json::Value],
+    ) -> Result<T> {
+        let mut last_error = None;
+        for index in self.candidates(method) {
+            let (label, node) = &self.nodes[index];
+            // Deserialized here so that a result of the wrong type is not mistaken for a failure
+            // of the node.
+            let error = match node.call::<serde_json::Value>(method, args) {
+                Ok(result) => {
+                    self.state.lock().expect("poisoned lock").down_until[index] = None;
+                    return Ok(serde_json::from_value(result)?);
+                }
+                Err(e) => e,
+            };
+
+            let mut state = self.state.lock().expect("poisoned lock");
+            if is_transient(&error) {
+                log::debug!(target: "corepc", "{} failed on {}: {}", method, label, error);
+                state.down_until[index] = Some(Instant::now() + self.cooldown);
+            } else if is_wallet_missing(&error) {
+                log::debug!(target: "corepc", "{} has no wallet for {}", label, method);
+                state.without_wallet.insert(index);
+                state.wallet_methods.insert(method.to_owned());
+            } else {
+                return Err(error);
+            }
+            last_error = Some(error);
+        }
+        Err(last_error.unwrap_or(Error::Returned("no nodes to send the call to".to_string())))
+    }
+}
+
+/// Returns `true` if `e` means the node could not answer, the call may succeed on another node.
+fn is_transient(e: &Error) -> bool {
+    match e {
+        Error::JsonRpc(jsonrpc::Error::Transport(_)) => true,
+        Error::JsonRpc(jsonrpc::Error::Rpc(e)) => e.code == RPC_IN_WARMUP,
+        _ => false,
+    }
+}
+
+/// Returns `true` if `e` means the node does not have the wallet the call is for.
+fn is_wallet_missing(e: &Error) -> bool {
+    matches!(e, Error::JsonRpc(jsonrpc::Error::Rpc(e)) if RPC_WALLET_MISSING.contains(&e.code))
+}
+
+/// Whether a node of a [`FailoverClient`] is used.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodeHealth {
+    /// The label of the node.
+    pub label: String,
+    /// `false` if the node failed and is skipped until its cooldown passes.
+    pub healthy: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::io;
+
+    use jsonrpc::error::RpcError;
+
+    use super::*;
+
+    /// Fails calls with the error code of the method in `errors`, `None` for a transport error,
+    /// answers others with `label`.
+    struct Node {
+        label: &'static str,
+        errors: Vec<(&'static str, Option<i32>)>,
+        calls: RefCell<Vec<String>>,
+    }
+
+    fn node(label: &'static str, errors: Vec<(&'static str, Option<i32>)>) -> Node {
+        Node { label, errors, calls: RefCell::new(vec![]) }
+    }
+
+    impl Call for Node {
+        fn call<T: for<'a> serde::de::Deserialize<'a>>(
+            &self,
+            method: &str,
+            _args: &[serde_json::Value],
+        ) -> Result<T> {
+            self.calls.borrow_mut().push(method.to_string());
+            match self.errors.iter().find(|(m, _)| *m == method) {
+                None => Ok(serde_json::from_value(self.label.into())?),
+                Some((_, None)) => {
+                    let e = io::Error::new(io::ErrorKind::ConnectionRefused, "refused");
+                    Err(Error::JsonRpc(jsonrpc::Error::Transport(Box::new(e))))
+                }
+                Some((_, Some(code))) => Err(Error::JsonRpc(jsonrpc::Error::Rpc(RpcError {
+                    code: *code,
+                    message: "error".to_string(),
+                    data: None,
+                }))),
+            }
+        }
+    }
+
+    fn call(client: &FailoverClient<Node>, method: &str) -> Result<String> {
+        client.call(method, &[])
+    }
+
+    #[test]
+    fn fails_over_transient_errors() {
+        let primary = node("primary", vec![("getblockcount", None), ("getblock", Some(-5))]);
+        let client =
+            FailoverClient::new(vec![("primary", primary), ("backup", node("backup", vec![]))]);
+
+        assert_eq!(call(&client, "getblockcount").unwrap(), "backup");
+        let health: Vec<_> = client.health().into_iter().map(|node| node.healthy).collect();
+        assert_eq!(health, [false, true]);
+        // The primary is skipped until the cooldown passes.
+        assert_eq!(call(&client, "getbestblockhash").unwrap(), "backup");
+        assert_eq!(client.nodes[0].1.calls.borrow().len(), 1);
+
+        assert_eq!(client.check_health().iter().filter(|node| node.healthy).count(), 1);
+
+        // Without a cooldown the primary is used again, errors of the call itself are not failed
+        // over.
+        let client = client.with_cooldown(Duration::ZERO);
+        client.check_health();
+        assert!(call(&client, "getblock").is_err());
+        assert_eq!(client.nodes[1].1.calls.borrow().iter().filter(|m| *m == "getblock").count(), 0);
+    }
+
+    #[test]
+    fn wallet_calls_stick_to_nodes_with_the_wallet() {
+        let primary = node("primary", vec![("getbalance", Some(-18))]);
+        let client =
+            FailoverClient::new(vec![("primary", primary), ("backup", node("backup", vec![]))]);
+
+        assert_eq!(call(&client, "getbalance").unwrap(), "backup");
+        assert_eq!(call(&client, "getbalance").unwrap(), "backup");
+        assert_eq!(call(&client, "getblockcount").unwrap(), "primary");
+        let primary_calls = client.nodes[0].1.calls.borrow();
+        assert_eq!(*primary_calls, ["getbalance", "getblockcount"]);
+    }
+
+    #[test]
+    fn returns_last_error_if_all_fail() {
+        let client = FailoverClient::new(vec![
+            ("a", node("a", vec![("getblockcount", None)])),
+            ("b", node("b", vec![("getblockcount", Some(-28))])),
+        ]);
+        assert!(matches!(
+            call(&client, "getblockcount"),
+            Err(Error::JsonRpc(jsonrpc::Error::Rpc(RpcError { code: -28, .. })))
+        ));
+        // Both down, still attempted.
+        assert!(call(&client, "getblockcount").is_err());
+        assert_eq!(client.nodes[0].1.calls.borrow().len(), 2);
+    }
+}
@@ -0,0 +1,277 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A JSON-RPC client sending its requests with a pluggable HTTP backend.
+//!
+//! The blocking client in `client_sync` opens TCP connections, which a browser cannot do.
+//! [`FetchClient`] builds the JSON-RPC requests and parses the responses, an [`HttpBackend`] sends
+//! them e.g., with `fetch` using `web-sys` against an RPC gateway in front of Core. This module
+//! builds for `wasm32-unknown-unknown`, calls are async because `fetch` is.
+//!
+//! The results deserialize into the version specific types of `corepc-types`, and from there into
+//! the model types:
+//!
+//! ```ignore
+//! use corepc_client::fetch::FetchClient;
+//! use corepc_client::types::v29::GetBlockchainInfo;
+//!
+//! let client = FetchClient::new(WebSysFetch, "https://gateway.example.com/rpc");
+//! let info: GetBlockchainInfo = client.call("getblockchaininfo", &[]).await?;
+//! let info = info.into_model()?;
+//! ```
+
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::{error, fmt};
+
+use bitcoin::base64::engine::general_purpose::STANDARD;
+use bitcoin::base64::Engine as _;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+/// Sends the HTTP requests of a [`FetchClient`].
+pub trait HttpBackend {
+    /// The error returned if the request could not be sent or got no response.
+    type Error;
+
+    /// POSTs `body`, a JSON-RPC request, to `url` with a `Content-Type: application/json` header,
+    /// and an `Authorization` header set to `authorization` if not `None`.
+    ///
+    /// Returns the body of the response whatever its status, Core answers a call that failed with
+    /// a 500 status and the JSON-RPC error in the body. Futures returned by `fetch` are not `Send`,
+    /// neither is this one required to be.
+    fn post(
+        &self,
+        url: &str,
+        authorization: Option<&str>,
+        body: String,
+    ) -> impl Future<Output = Result<Vec<u8>, Self::Error>>;
+}
+
+/// A JSON-RPC client sending its requests with an [`HttpBackend`], see the [module docs](self).
+pub struct FetchClient<B> {
+    backend: B,
+    url: String,
+    authorization: Option<String>,
+    /// The id of the next request.
+    next_id: AtomicUsize,
+}
+
+impl<B> fmt::Debug for FetchClient<B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The authorization holds the credentials.
+        f.debug_struct("FetchClient")
+            .field("url", &self.url)
+            .field("next_id", &self.next_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<B: HttpBackend> FetchClient<B> {
+    /// Creates a client sending the calls to `url` with `backend`, without authentication.
+    pub fn new(backend: B, url: &str) -> Self {
+        FetchClient {
+            backend,
+            url: url.to_owned(),
+            authorization: None,
+            next_id: AtomicUsize::new(0),
+        }
+    }
+
+    /// Authenticates the calls with the RPC user `user` and password `pass`.
+    pub fn with_user_pass(mut self, user: &str, pass: &str) -> Self {
+        let credentials = STANDARD.encode(format!("{}:{}", user, pass));
+        self.authorization = Some(format!("Basic {}", credentials));
+        self
+    }
+
+    /// Calls the RPC `method` with `args`, deserializing the result into `T`.
+    pub async fn call<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        args: &[serde_json::Value],
+    ) -> Result<T, Error<B::Error>> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": args,
+        });
+        let body = self
+            .backend
+            .post(&self.url, self.authorization.as_deref(), request.to_string())
+            .await
+            .map_err(Error::Backend)?;
+        parse_response(&body, id)
+    }
+}
+
+/// A JSON-RPC response.
+#[derive(Deserialize)]
+struct Response {
+    result: Option<serde_json::Value>,
+    error: Option<RpcError>,
+    id: serde_json::Value,
+}
+
+/// The error of a JSON-RPC response.
+#[derive(Deserialize)]
+struct RpcError {
+    code: i32,
+    message: String,
+}
+
+/// Returns the result of the response `body` to the request with id `id`.
+fn parse_response<T: DeserializeOwned, E>(body: &[u8], id: usize) -> Result<T, Error<E>> {
+    let response: Response = serde_json::from_slice(body)?;
+    if response.id != id {
+        return Err(Error::IdMismatch);
+    }
+    if let Some(RpcError { code, message }) = response.error {
+        return Err(Error::Rpc { code, message });
+    }
+    Ok(serde_json::from_value(response.result.unwrap_or_default())?)
+}
+
+/// The error returned by a [`FetchClient`] call.
+#[derive(Debug)]
+pub enum Error<E> {
+    /// The backend could not send the request.
+    Backend(E),
+    /// The response is not JSON-RPC, or the result does not deserialize into the expected type.
+    Json(serde_json::Error),
+    /// The response is for another request.
+    IdMismatch,
+    /// The node returned an error.
+    Rpc {
+        /// The JSON-RPC error code e.g., `-5` for an invalid address or key.
+        code: i32,
+        /// The error message.
+        message: String,
+    },
+}
+
+impl<E> From<serde_json::Error> for Error<E> {
+    fn from(e: serde_json::Error) -> Self { Error::Json(e) }
+}
+
+impl<E: fmt::Display> fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Error::*;
+
+        match *self {
+            Backend(ref e) => write!(f, "HTTP backend error: {}", e),
+            Json(ref e) => write!(f, "JSON error: {}", e),
+            IdMismatch => write!(f, "the response is for another request"),
+            Rpc { code, ref message } => write!(f, "RPC error {}: {}", code, message),
+        }
+    }
+}
+
+impl<E: error::Error + 'static> error::Error for Error<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use Error::*;
+
+        match *self {
+            Backend(ref e) => Some(e),
+            Json(ref e) => Some(e),
+            IdMismatch | Rpc { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+    use super::*;
+
+    /// Answers each request with the response made by `respond` from the request.
+    struct Backend {
+        respond: fn(&serde_json::Value) -> String,
+        requests: RefCell<Vec<(String, Option<String>, serde_json::Value)>>,
+    }
+
+    impl HttpBackend for Backend {
+        type Error = String;
+
+        fn post(
+            &self,
+            url: &str,
+            authorization: Option<&str>,
+            body: String,
+        ) -> impl Future<Output = Result<Vec<u8>, String>> {
+            let request: serde_json::Value = serde_json::from_str(&body).unwrap();
+            let response = (self.respond)(&request);
+            let authorization = authorization.map(ToOwned::to_owned);
+            self.requests.borrow_mut().push((url.to_owned(), authorization, request));
+            future::ready(Ok(response.into_bytes()))
+        }
+    }
+
+    fn fetch_client(respond: fn(&serde_json::Value) -> String) -> FetchClient<Backend> {
+        FetchClient::new(Backend { respond, requests: RefCell::new(vec![]) }, "http://gateway")
+    }
+
+    /// Polls `future` to completion, the futures of [`Backend`] are always ready.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        fn clone(_: *const ()) -> RawWaker { RawWaker::new(std::ptr::null(), &VTABLE) }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+
+        // SAFETY: The vtable functions do nothing with the null data pointer.
+        let waker = unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) };
+        let mut future = pin!(future);
+        match future.as_mut().poll(&mut Context::from_waker(&waker)) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("backend futures are always ready"),
+        }
+    }
+
+    #[test]
+    fn call_sends_request_and_parses_result() {
+        let client = fetch_client(|request| {
+            serde_json::json!({ "result": 42, "error": null, "id": request["id"] }).to_string()
+        })
+        .with_user_pass("user", "pass");
+
+        let count: u64 = block_on(client.call("getblockcount", &[])).unwrap();
+        assert_eq!(count, 42);
+        let _: u64 = block_on(client.call("getblockhash", &[1.into()])).unwrap();
+
+        let requests = client.backend.requests.borrow();
+        let (url, authorization, request) = &requests[1];
+        assert_eq!(url, "http://gateway");
+        assert_eq!(authorization.as_deref(), Some("Basic dXNlcjpwYXNz"));
+        assert_eq!(request["method"], "getblockhash");
+        assert_eq!(request["params"], serde_json::json!([1]));
+        assert_eq!(request["id"], 1);
+    }
+
+    #[test]
+    fn call_returns_errors() {
+        let client = fetch_client(|request| {
+            let error = serde_json::json!({ "code": -8, "message": "Block height out of range" });
+            serde_json::json!({ "result": null, "error": error, "id": request["id"] }).to_string()
+        });
+        match block_on(client.call::<String>("getblockhash", &[1000.into()])) {
+            Err(Error::Rpc { code, message }) => {
+                assert_eq!(code, -8);
+                assert_eq!(message, "Block height out of range");
+            }
+            res => panic!("unexpected: {:?}", res),
+        }
+
+        let client = fetch_client(|_| r#"{"result": 1, "error": null, "id": 99}"#.to_string());
+        assert!(matches!(
+            block_on(client.call::<u64>("getblockcount", &[])),
+            Err(Error::IdMismatch)
+        ));
+
+        let client = fetch_client(|_| "<html>Bad Gateway</html>".to_string());
+        assert!(matches!(block_on(client.call::<u64>("getblockcount", &[])), Err(Error::Json(_))));
+    }
+}
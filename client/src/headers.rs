@@ -0,0 +1,316 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Verifying block headers locally, without trusting the node they were fetched from.
+//!
+//! [`verify_chain`] checks that each header builds on the one before it, that its proof of work
+//! meets its target, that its target follows the difficulty adjustment rules of the network, and
+//! that the headers match the given checkpoints. A node (or third party RPC endpoint) lying about
+//! the chain has to do the work to get past these checks.
+//!
+//! Works with the `Client` of any version.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use corepc_client::bitcoin::Network;
+//! use corepc_client::client_sync::{v28::Client, Auth};
+//! use corepc_client::headers::{self, Checkpoint};
+//!
+//! let cookie = Auth::CookieFile("/home/user/.bitcoin/.cookie".into());
+//! let client = Client::new_with_auth("http://localhost:8332", cookie)?;
+//!
+//! let checkpoint = Checkpoint {
+//!     height: 11_111,
+//!     hash: "0000000069e244f73d78e8fd29ba2fd2ed618bd6fa2ee92559f542fdb26e7c1d".parse()?,
+//! };
+//! let chain = headers::fetch(&client, 0, 20_160)?;
+//! headers::verify_chain(0, &chain, &[checkpoint], Network::Bitcoin)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{error, fmt};
+
+use bitcoin::block::Header;
+use bitcoin::consensus::encode;
+use bitcoin::consensus::params::Params;
+use bitcoin::{BlockHash, CompactTarget, Network};
+
+use crate::client_sync::{self, Call};
+
+/// A block hash known to be at a height of the chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// The height of the block.
+    pub height: u32,
+    /// The hash of the block.
+    pub hash: BlockHash,
+}
+
+/// Fetches `count` consecutive block headers of the best chain of the node, from height `start`.
+pub fn fetch<C: Call>(client: &C, start: u32, count: u32) -> client_sync::Result<Vec<Header>> {
+    (start..start + count)
+        .map(|height| {
+            let hash: String = client.call("getblockhash", &[height.into()])?;
+            let hex: String = client.call("getblockheader", &[hash.into(), false.into()])?;
+            Ok(encode::deserialize_hex(&hex)?)
+        })
+        .collect()
+}
+
+/// Verifies the chain of `headers`, the first of which is at height `start`, on `network`.
+///
+/// Checks that:
+///
+/// - each header builds on the one before it;
+/// - the target of each header is not above the limit of the network;
+/// - the hash of each header meets its target;
+/// - the target of each header follows the difficulty adjustment rules, when the headers it
+///   depends on are part of `headers`;
+/// - the headers at the heights of `checkpoints` have the expected hash.
+///
+/// Returns the hash of the last header, or the first check that failed.
+pub fn verify_chain(
+    start: u32,
+    headers: &[Header],
+    checkpoints: &[Checkpoint],
+    network: Network,
+) -> Result<Option<BlockHash>, Error> {
+    let params = Params::new(network);
+    let interval = params.difficulty_adjustment_interval() as usize;
+    let pow_limit_bits = params.max_attainable_target.to_compact_lossy();
+    // The bits of the last block not mined at the minimum difficulty, if known.
+    let mut last_regular_bits = None;
+    let mut tip = None;
+
+    for (i, header) in headers.iter().enumerate() {
+        let height = start + i as u32;
+        if let Some(prev_hash) = tip {
+            if header.prev_blockhash != prev_hash {
+                return Err(Error::Discontinuous { height });
+            }
+        }
+        if header.target() > params.max_attainable_target {
+            return Err(Error::TargetAboveLimit { height });
+        }
+        let hash =
+            header.validate_pow(header.target()).map_err(|_| Error::InsufficientWork { height })?;
+
+        let at_boundary = height as usize % interval == 0;
+        if let Some(prev) = i.checked_sub(1).map(|prev| &headers[prev]) {
+            let expected = if params.no_pow_retargeting {
+                Some(prev.bits)
+            } else if at_boundary {
+                // The timespan of the period is from its first block to its last block.
+                i.checked_sub(interval).map(|first| {
+                    let timespan = u64::from(prev.time.saturating_sub(headers[first].time));
+                    CompactTarget::from_next_work_required(prev.bits, timespan, &params)
+                })
+            } else if params.allow_min_difficulty_blocks {
+                // A block may be mined at the minimum difficulty if it is more than twice the
+                // target spacing after the previous block.
+                if u64::from(header.time) > u64::from(prev.time) + 2 * params.pow_target_spacing {
+                    Some(pow_limit_bits)
+                } else {
+                    last_regular_bits
+                }
+            } else {
+                Some(prev.bits)
+            };
+            if let Some(expected) = expected {
+                if header.bits != expected {
+                    return Err(Error::UnexpectedTarget { height, expected, got: header.bits });
+                }
+            }
+        }
+        if at_boundary || header.bits != pow_limit_bits {
+            last_regular_bits = Some(header.bits);
+        }
+        tip = Some(hash);
+    }
+
+    let end = start + headers.len() as u32;
+    for checkpoint in checkpoints.iter().filter(|c| (start..end).contains(&c.height)) {
+        let got = headers[(checkpoint.height - start) as usize].block_hash();
+        if got != checkpoint.hash {
+            return Err(Error::CheckpointMismatch {
+                height: checkpoint.height,
+                expected: checkpoint.hash,
+                got,
+            });
+        }
+    }
+    Ok(tip)
+}
+
+/// Error returned by [`verify_chain`], for the header at `height`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The header does not build on the header before it.
+    Discontinuous {
+        /// The height of the header.
+        height: u32,
+    },
+    /// The target of the header is above the limit of the network.
+    TargetAboveLimit {
+        /// The height of the header.
+        height: u32,
+    },
+    /// The hash of the header does not meet its target.
+    InsufficientWork {
+        /// The height of the header.
+        height: u32,
+    },
+    /// The target of the header does not follow the difficulty adjustment rules.
+    UnexpectedTarget {
+        /// The height of the header.
+        height: u32,
+        /// The target required at that height.
+        expected: CompactTarget,
+        /// The target of the header.
+        got: CompactTarget,
+    },
+    /// The header does not match the checkpoint at its height.
+    CheckpointMismatch {
+        /// The height of the header.
+        height: u32,
+        /// The hash of the checkpoint.
+        expected: BlockHash,
+        /// The hash of the header.
+        got: BlockHash,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Error::*;
+
+        match *self {
+            Discontinuous { height } =>
+                write!(f, "header at height {} does not build on the previous header", height),
+            TargetAboveLimit { height } =>
+                write!(f, "target of header at height {} is above the network limit", height),
+            InsufficientWork { height } =>
+                write!(f, "header at height {} does not meet its target", height),
+            UnexpectedTarget { height, expected, got } => write!(
+                f,
+                "header at height {} has target {:#x}, expected {:#x}",
+                height,
+                got.to_consensus(),
+                expected.to_consensus()
+            ),
+            CheckpointMismatch { height, expected, got } => write!(
+                f,
+                "header at height {} has hash {}, checkpoint is {}",
+                height, got, expected
+            ),
+        }
+    }
+}
+
+impl error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::blockdata::constants::genesis_block;
+    use bitcoin::hashes::Hash as _;
+    use bitcoin::{block, TxMerkleNode};
+
+    use super::*;
+
+    /// Returns the header after `prev` with `bits`, mined by searching for a nonce.
+    fn mine(prev: &Header, bits: CompactTarget) -> Header {
+        let mut header = Header {
+            version: block::Version::TWO,
+            prev_blockhash: prev.block_hash(),
+            merkle_root: TxMerkleNode::all_zeros(),
+            time: prev.time + 600,
+            bits,
+            nonce: 0,
+        };
+        while header.validate_pow(header.target()).is_err() {
+            header.nonce += 1;
+        }
+        header
+    }
+
+    fn regtest_chain(len: usize) -> Vec<Header> {
+        let mut chain = vec![genesis_block(Network::Regtest).header];
+        while chain.len() < len {
+            let prev = chain.last().unwrap();
+            chain.push(mine(prev, prev.bits));
+        }
+        chain
+    }
+
+    #[test]
+    fn mainnet_genesis_and_first_block() {
+        let genesis = genesis_block(Network::Bitcoin).header;
+        let first = Header {
+            version: block::Version::ONE,
+            prev_blockhash: genesis.block_hash(),
+            merkle_root: "0e3e2357e806b6cdb1f70b54c3a3a17b6714ee1f0e68bebb44a74b1efd512098"
+                .parse()
+                .unwrap(),
+            time: 1231469665,
+            bits: genesis.bits,
+            nonce: 2573394689,
+        };
+        let hash =
+            "00000000839a8e6886ab5951d76f411475428afc90947ee320161bbf18eb6048".parse().unwrap();
+        let checkpoint = Checkpoint { height: 1, hash };
+
+        let tip = verify_chain(0, &[genesis, first], &[checkpoint], Network::Bitcoin).unwrap();
+        assert_eq!(tip, Some(hash));
+
+        let mut wrong = first;
+        wrong.nonce += 1;
+        let res = verify_chain(0, &[genesis, wrong], &[], Network::Bitcoin);
+        assert_eq!(res, Err(Error::InsufficientWork { height: 1 }));
+    }
+
+    #[test]
+    fn regtest_chain_verifies() {
+        let chain = regtest_chain(10);
+        let tip = verify_chain(0, &chain, &[], Network::Regtest).unwrap();
+        assert_eq!(tip, Some(chain[9].block_hash()));
+        // A chain not starting at genesis.
+        assert_eq!(verify_chain(5, &chain[5..], &[], Network::Regtest).unwrap(), tip);
+        assert_eq!(verify_chain(0, &[], &[], Network::Regtest).unwrap(), None);
+    }
+
+    #[test]
+    fn discontinuous() {
+        let mut chain = regtest_chain(5);
+        chain.remove(2);
+        let res = verify_chain(0, &chain, &[], Network::Regtest);
+        assert_eq!(res, Err(Error::Discontinuous { height: 2 }));
+    }
+
+    #[test]
+    fn unexpected_target() {
+        let mut chain = regtest_chain(3);
+        let bits = CompactTarget::from_consensus(0x2000ffff);
+        chain.push(mine(&chain[2], bits));
+        let res = verify_chain(0, &chain, &[], Network::Regtest);
+        assert_eq!(
+            res,
+            Err(Error::UnexpectedTarget { height: 3, expected: chain[2].bits, got: bits })
+        );
+    }
+
+    #[test]
+    fn checkpoint_mismatch() {
+        let chain = regtest_chain(5);
+        let expected = BlockHash::all_zeros();
+        let checkpoints = [
+            Checkpoint { height: 2, hash: chain[2].block_hash() },
+            Checkpoint { height: 3, hash: expected },
+            // Out of range checkpoints are ignored.
+            Checkpoint { height: 100, hash: expected },
+        ];
+        let res = verify_chain(0, &chain, &checkpoints, Network::Regtest);
+        let got = chain[3].block_hash();
+        assert_eq!(res, Err(Error::CheckpointMismatch { height: 3, expected, got }));
+    }
+}
@@ -8,6 +8,40 @@ pub extern crate bitcoin;
 /// Re-export the `corepc-types` crate.
 pub extern crate types;
 
+#[cfg(feature = "broadcast")]
+pub mod broadcast;
+
+#[cfg(feature = "client-async")]
+#[macro_use]
+pub mod client_async;
+
 #[cfg(feature = "client-sync")]
 #[macro_use]
 pub mod client_sync;
+
+#[cfg(feature = "deposits")]
+pub mod deposits;
+
+#[cfg(feature = "failover")]
+pub mod failover;
+
+#[cfg(feature = "fetch")]
+pub mod fetch;
+
+#[cfg(feature = "headers")]
+pub mod headers;
+
+#[cfg(feature = "quorum")]
+pub mod quorum;
+
+#[cfg(feature = "split")]
+pub mod split;
+
+#[cfg(feature = "state")]
+pub mod state;
+
+#[cfg(feature = "supply")]
+pub mod supply;
+
+#[cfg(feature = "withdrawals")]
+pub mod withdrawals;
@@ -0,0 +1,289 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Checking that redundant nodes agree on the chain tip.
+//!
+//! [`TipQuorum`] polls the tip of each of its nodes and reports, as [`TipEvent`]s, the nodes that
+//! could not be reached, those lagging behind the others by more than a threshold, and nodes on
+//! different chains (a split). A node is any `Client`, of any version.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use corepc_client::client_sync::{v28, v29, Auth};
+//! use corepc_client::quorum::{TipEvent, TipQuorum};
+//!
+//! let a = v29::Client::new_with_auth("http://10.0.0.1:8332", Auth::CookieFile("a".into()))?;
+//! let b = v28::Client::new_with_auth("http://10.0.0.2:8332", Auth::CookieFile("b".into()))?;
+//! let quorum = TipQuorum::new(2).add("a", &a).add("b", &b);
+//!
+//! for event in quorum.poll().events {
+//!     if let TipEvent::Split { height, .. } = event {
+//!         println!("nodes disagree on the block at height {}", height);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use bitcoin::BlockHash;
+use serde::Deserialize;
+
+use crate::client_sync::{Call, Error, Result};
+
+/// A node whose tip can be polled, implemented for the `Client` of every version.
+pub trait TipSource {
+    /// Returns the tip of the node's best chain.
+    fn tip(&self) -> Result<Tip>;
+
+    /// Returns the hash of the block at `height` in the node's best chain.
+    fn block_hash(&self, height: u64) -> Result<BlockHash>;
+}
+
+/// The fields of `getblockchaininfo` used by [`TipSource::tip`], the same in all versions of Core.
+#[derive(Deserialize)]
+struct ChainInfo {
+    blocks: u64,
+    #[serde(rename = "bestblockhash")]
+    best_block_hash: String,
+}
+
+impl<C: Call> TipSource for C {
+    fn tip(&self) -> Result<Tip> {
+        // A single call so that the height and hash are of the same block.
+        let info: ChainInfo = self.call("getblockchaininfo", &[])?;
+        Ok(Tip { height: info.blocks, hash: info.best_block_hash.parse()? })
+    }
+
+    fn block_hash(&self, height: u64) -> Result<BlockHash> {
+        let hash: String = self.call("getblockhash", &[height.into()])?;
+        Ok(hash.parse()?)
+    }
+}
+
+/// The tip of a node's best chain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Tip {
+    /// The height of the tip.
+    pub height: u64,
+    /// The hash of the tip.
+    pub hash: BlockHash,
+}
+
+/// Polls several nodes and reports how their tips diverge, see the [module docs](self).
+pub struct TipQuorum<'a> {
+    nodes: Vec<(String, &'a dyn TipSource)>,
+    max_lag: u64,
+}
+
+impl fmt::Debug for TipQuorum<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let labels: Vec<_> = self.nodes.iter().map(|(label, _)| label).collect();
+        f.debug_struct("TipQuorum").field("nodes", &labels).field("max_lag", &self.max_lag).finish()
+    }
+}
+
+impl<'a> TipQuorum<'a> {
+    /// Creates a quorum without nodes, tolerating nodes up to `max_lag` blocks behind the highest.
+    ///
+    /// Nodes relaying blocks to each other are briefly a block apart, a `max_lag` of zero reports
+    /// them as lagging.
+    pub fn new(max_lag: u64) -> Self { TipQuorum { nodes: Vec::new(), max_lag } }
+
+    /// Adds `node`, identified by `label` in the events.
+    pub fn add<L: Into<String>>(mut self, label: L, node: &'a dyn TipSource) -> Self {
+        self.nodes.push((label.into(), node));
+        self
+    }
+
+    /// Polls the tip of each node, one after the other, and checks they agree.
+    ///
+    /// The nodes that were reached are compared at the height of the lowest of their tips, a node
+    /// with a higher tip is asked for its block at that height. Nodes on different blocks at that
+    /// height are reported in a [`TipEvent::Split`].
+    pub fn poll(&self) -> TipPoll {
+        let mut tips = Vec::with_capacity(self.nodes.len());
+        let mut events = Vec::new();
+        for (label, node) in &self.nodes {
+            match node.tip() {
+                Ok(tip) => tips.push(NodeTip { label: label.clone(), tip: Some(tip) }),
+                Err(error) => {
+                    tips.push(NodeTip { label: label.clone(), tip: None });
+                    events.push(TipEvent::Unreachable { label: label.clone(), error });
+                }
+            }
+        }
+
+        let reached = || self.nodes.iter().zip(&tips).filter_map(|(node, t)| Some((node, t.tip?)));
+        let (Some(best), Some(lowest)) = (
+            reached().map(|(_, tip)| tip.height).max(),
+            reached().map(|(_, tip)| tip.height).min(),
+        ) else {
+            return TipPoll { tips, events };
+        };
+
+        for ((label, _), tip) in reached() {
+            if best - tip.height > self.max_lag {
+                events.push(TipEvent::Lagging {
+                    label: label.clone(),
+                    height: tip.height,
+                    best_height: best,
+                });
+            }
+        }
+
+        let mut branches: BTreeMap<BlockHash, Vec<String>> = BTreeMap::new();
+        for ((label, node), tip) in reached() {
+            let hash = if tip.height == lowest { Ok(tip.hash) } else { node.block_hash(lowest) };
+            match hash {
+                Ok(hash) => branches.entry(hash).or_default().push(label.clone()),
+                Err(error) => events.push(TipEvent::Unreachable { label: label.clone(), error }),
+            }
+        }
+        if branches.len() > 1 {
+            let branches =
+                branches.into_iter().map(|(hash, labels)| Branch { hash, labels }).collect();
+            events.push(TipEvent::Split { height: lowest, branches });
+        }
+        TipPoll { tips, events }
+    }
+}
+
+/// The result of polling the nodes of a [`TipQuorum`].
+#[derive(Debug)]
+pub struct TipPoll {
+    /// The tip of each node, in the order they were added.
+    pub tips: Vec<NodeTip>,
+    /// How the nodes diverge, empty if they agree.
+    pub events: Vec<TipEvent>,
+}
+
+impl TipPoll {
+    /// Returns `true` if all the nodes were reached and agree on the chain.
+    pub fn is_consistent(&self) -> bool { self.events.is_empty() }
+}
+
+/// The tip of one node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodeTip {
+    /// The label of the node.
+    pub label: String,
+    /// The tip of the node, `None` if it could not be reached.
+    pub tip: Option<Tip>,
+}
+
+/// How the nodes of a [`TipQuorum`] diverge.
+#[derive(Debug)]
+pub enum TipEvent {
+    /// The node could not be polled.
+    Unreachable {
+        /// The label of the node.
+        label: String,
+        /// The error returned by the node.
+        error: Error,
+    },
+    /// The node is more than the tolerated number of blocks behind the highest tip.
+    Lagging {
+        /// The label of the node.
+        label: String,
+        /// The height of the node's tip.
+        height: u64,
+        /// The height of the highest tip.
+        best_height: u64,
+    },
+    /// The nodes are on different blocks at `height`, the height of the lowest tip.
+    Split {
+        /// The height the nodes were compared at.
+        height: u64,
+        /// The nodes on each block, ordered by block hash.
+        branches: Vec<Branch>,
+    },
+}
+
+/// The nodes on the same block, see [`TipEvent::Split`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Branch {
+    /// The hash of the block.
+    pub hash: BlockHash,
+    /// The labels of the nodes on the block.
+    pub labels: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::hashes::Hash;
+
+    use super::*;
+
+    /// A node whose best chain is the blocks `chain`, indexed by height, or is unreachable if empty.
+    struct Node {
+        chain: Vec<u8>,
+    }
+
+    impl Call for Node {
+        fn call<T: for<'a> serde::de::Deserialize<'a>>(
+            &self,
+            method: &str,
+            args: &[serde_json::Value],
+        ) -> Result<T> {
+            let hash_at = |height: usize| hash(self.chain[height]).to_string();
+            let json = match method {
+                _ if self.chain.is_empty() =>
+                    return Err(Error::Returned("unreachable".to_string())),
+                "getblockchaininfo" => serde_json::json!({
+                    "chain": "regtest",
+                    "blocks": self.chain.len() - 1,
+                    "bestblockhash": hash_at(self.chain.len() - 1),
+                }),
+                "getblockhash" => hash_at(args[0].as_u64().unwrap() as usize).into(),
+                _ => panic!("unexpected method {}", method),
+            };
+            Ok(serde_json::from_value(json)?)
+        }
+    }
+
+    fn hash(block: u8) -> BlockHash { BlockHash::from_byte_array([block; 32]) }
+
+    #[test]
+    fn agreeing_nodes() {
+        let a = Node { chain: vec![0, 1, 2, 3] };
+        let b = Node { chain: vec![0, 1, 2] };
+        let poll = TipQuorum::new(1).add("a", &a).add("b", &b).poll();
+        assert!(poll.is_consistent(), "{:?}", poll.events);
+        assert_eq!(poll.tips[0].tip, Some(Tip { height: 3, hash: hash(3) }));
+        assert_eq!(poll.tips[1].tip, Some(Tip { height: 2, hash: hash(2) }));
+    }
+
+    #[test]
+    fn reports_divergence() {
+        let a = Node { chain: vec![0, 1, 2, 3, 4] };
+        let b = Node { chain: vec![0, 1, 20] };
+        let c = Node { chain: vec![0, 1, 2] };
+        let down = Node { chain: vec![] };
+        let poll =
+            TipQuorum::new(1).add("a", &a).add("b", &b).add("c", &c).add("down", &down).poll();
+
+        assert_eq!(poll.tips[3].tip, None);
+        assert_eq!(poll.events.len(), 4, "{:?}", poll.events);
+        assert!(matches!(&poll.events[0], TipEvent::Unreachable { label, .. } if label == "down"));
+        assert!(matches!(
+            &poll.events[1],
+            TipEvent::Lagging { label, height: 2, best_height: 4 } if label == "b"
+        ));
+        assert!(matches!(&poll.events[2], TipEvent::Lagging { label, .. } if label == "c"));
+        match &poll.events[3] {
+            TipEvent::Split { height, branches } => {
+                assert_eq!(*height, 2);
+                assert_eq!(
+                    branches,
+                    &[
+                        Branch { hash: hash(2), labels: vec!["a".to_string(), "c".to_string()] },
+                        Branch { hash: hash(20), labels: vec!["b".to_string()] },
+                    ]
+                );
+            }
+            event => panic!("unexpected event: {:?}", event),
+        }
+    }
+}
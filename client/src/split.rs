@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A client sending reads to replicas and everything else to a primary node.
+//!
+//! [`SplitClient`] spreads the calls that only read the chain, the mempool or decode data (e.g.,
+//! `getblock`, `gettxout`, `estimatesmartfee`) over replica nodes, round robin. Wallet calls, calls
+//! changing the state of the node (e.g., `sendrawtransaction`) and calls about the node itself
+//! (e.g., `getpeerinfo`) go to the primary. [`Routing`] lists the methods sent to the replicas.
+//!
+//! Replicas may lag behind the primary, a read following a write can miss it e.g.,
+//! `getmempoolentry` right after `sendrawtransaction`. A read failing to reach its replica is sent
+//! to the primary.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use corepc_client::client_sync::{v29::Client, Auth, Call};
+//! use corepc_client::split::SplitClient;
+//!
+//! let primary = Client::new_with_auth("http://10.0.0.1:8332", Auth::CookieFile("a".into()))?;
+//! let replica = Client::new_with_auth("http://10.0.0.2:8332", Auth::CookieFile("b".into()))?;
+//! let client = SplitClient::new(primary, vec![replica]);
+//!
+//! let height: u64 = client.call("getblockcount", &[])?; // Answered by the replica.
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use types::methods::{self, Section};
+
+use crate::client_sync::{Call, Error, Result};
+
+/// The sections of the methods sent to the replicas by default.
+///
+/// The other sections are about the node itself (e.g., `Network`) or called on its wallets.
+const REPLICA_SECTIONS: [Section; 4] =
+    [Section::Blockchain, Section::Mining, Section::Rawtransactions, Section::Util];
+
+/// The methods only reading, in [`REPLICA_SECTIONS`], sent to the primary by default.
+const PRIMARY: &[&str] = &[
+    // About the node itself rather than the chain or the mempool.
+    "getblocktemplate",
+    "getchainstates",
+    "getindexinfo",
+    "getmininginfo",
+    "getprioritisedtransactions",
+    "getprivatebroadcastinfo",
+    "verifychain",
+    // Given private keys, not sent to more nodes than needed.
+    "descriptorprocesspsbt",
+    "signmessagewithprivkey",
+    "signrawtransaction",
+    "signrawtransactionwithkey",
+    // Long running, or waiting for the tip of the node.
+    "scanblocks",
+    "scantxoutset",
+    "waitforblock",
+    "waitforblockheight",
+    "waitfornewblock",
+];
+
+/// The hidden methods sent to the replicas by default, `bitcoin-cli help` does not list them so
+/// they are missing from the [`methods`] registry.
+const HIDDEN_REPLICA: &[&str] = &["estimaterawfee"];
+
+/// The methods a [`SplitClient`] sends to the replicas, all other methods go to the primary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Routing(BTreeSet<String>);
+
+impl Routing {
+    /// Returns a routing sending all the calls to the primary.
+    pub fn primary_only() -> Self { Routing(BTreeSet::new()) }
+
+    /// Sends the calls to `method` to the replicas.
+    pub fn replica(mut self, method: &str) -> Self {
+        self.0.insert(method.to_owned());
+        self
+    }
+
+    /// Sends the calls to `method` to the primary.
+    pub fn primary(mut self, method: &str) -> Self {
+        self.0.remove(method);
+        self
+    }
+
+    /// Returns `true` if the calls to `method` are sent to the replicas.
+    pub fn is_replica(&self, method: &str) -> bool { self.0.contains(method) }
+}
+
+impl Default for Routing {
+    /// Sends the calls reading the chain or the mempool, and those decoding data, to the replicas.
+    ///
+    /// The methods are the node methods the [`methods`] registry lists as only reading, in the
+    /// blockchain, mining, raw transaction and util sections, except a few about the node itself.
+    fn default() -> Self {
+        let read_only = methods::METHODS
+            .iter()
+            .filter(|info| {
+                !info.wallet && !info.is_mutating() && REPLICA_SECTIONS.contains(&info.section)
+            })
+            .map(|info| info.name);
+        let routing = read_only
+            .chain(HIDDEN_REPLICA.iter().copied())
+            .fold(Routing::primary_only(), |r, m| r.replica(m));
+        PRIMARY.iter().fold(routing, |r, m| r.primary(m))
+    }
+}
+
+/// A client splitting the calls between a primary and replicas, see the [module docs](self).
+#[derive(Debug)]
+pub struct SplitClient<C> {
+    primary: C,
+    replicas: Vec<C>,
+    routing: Routing,
+    /// The number of calls sent to the replicas so far, picks the next replica.
+    next: AtomicUsize,
+}
+
+impl<C: Call> SplitClient<C> {
+    /// Creates a client sending the reads to `replicas`, and the other calls to `primary`.
+    ///
+    /// Without replicas all the calls go to the primary.
+    pub fn new(primary: C, replicas: Vec<C>) -> Self {
+        SplitClient { primary, replicas, routing: Routing::default(), next: AtomicUsize::new(0) }
+    }
+
+    /// Sets which methods are sent to the replicas, [`Routing::default`] if not set.
+    pub fn with_routing(mut self, routing: Routing) -> Self {
+        self.routing = routing;
+        self
+    }
+
+    /// Returns the primary node.
+    pub fn primary(&self) -> &C { &self.primary }
+
+    /// Returns the replica nodes.
+    pub fn replicas(&self) -> &[C] { &self.replicas }
+}
+
+impl<C: Call> Call for SplitClient<C> {
+    fn call<T: for<'a> serde::de::Deserialize<'a>>(
+        &self,
+        method: &str,
+        args: &[serde_json::Value],
+    ) -> Result<T> {
+        if self.replicas.is_empty() || !self.routing.is_replica(method) {
+            return self.primary.call(method, args);
+        }
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.replicas.len();
+        match self.replicas[index].call(method, args) {
+            Err(Error::JsonRpc(jsonrpc::Error::Transport(e))) => {
+                log::debug!(target: "corepc", "replica {} unreachable: {}", index, e);
+                self.primary.call(method, args)
+            }
+            result => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::io;
+
+    use super::*;
+
+    /// Answers calls with its name, or fails them with a transport error if `down`.
+    struct Node {
+        name: &'static str,
+        down: bool,
+        calls: RefCell<Vec<String>>,
+    }
+
+    fn node(name: &'static str, down: bool) -> Node {
+        Node { name, down, calls: RefCell::new(vec![]) }
+    }
+
+    impl Call for Node {
+        fn call<T: for<'a> serde::de::Deserialize<'a>>(
+            &self,
+            method: &str,
+            _args: &[serde_json::Value],
+        ) -> Result<T> {
+            self.calls.borrow_mut().push(method.to_string());
+            if self.down {
+                let e = io::Error::new(io::ErrorKind::ConnectionRefused, "refused");
+                return Err(Error::JsonRpc(jsonrpc::Error::Transport(Box::new(e))));
+            }
+            Ok(serde_json::from_value(self.name.into())?)
+        }
+    }
+
+    fn call(client: &SplitClient<Node>, method: &str) -> String {
+        client.call(method, &[]).unwrap()
+    }
+
+    #[test]
+    fn routes_reads_to_replicas() {
+        let client =
+            SplitClient::new(node("primary", false), vec![node("a", false), node("b", false)]);
+        assert_eq!(call(&client, "getblock"), "a");
+        assert_eq!(call(&client, "estimatesmartfee"), "b");
+        assert_eq!(call(&client, "gettxout"), "a");
+        assert_eq!(call(&client, "sendrawtransaction"), "primary");
+        assert_eq!(call(&client, "getbalance"), "primary");
+        assert_eq!(call(&client, "getpeerinfo"), "primary");
+        assert_eq!(call(&client, "unknownmethod"), "primary");
+
+        let routing = Routing::default().primary("getblock").replica("getpeerinfo");
+        let client = client.with_routing(routing);
+        assert_eq!(call(&client, "getblock"), "primary");
+        assert_eq!(call(&client, "getpeerinfo"), "b");
+
+        let client = SplitClient::new(node("primary", false), vec![]);
+        assert_eq!(call(&client, "getblock"), "primary");
+    }
+
+    #[test]
+    fn default_routing_overrides() {
+        let routing = Routing::default();
+        for method in PRIMARY {
+            let info = methods::get(method).expect("known method");
+            assert!(!info.is_mutating() && REPLICA_SECTIONS.contains(&info.section), "{}", method);
+            assert!(!routing.is_replica(method));
+        }
+        for method in HIDDEN_REPLICA {
+            assert!(methods::get(method).is_none());
+            assert!(routing.is_replica(method));
+        }
+        assert!(routing.is_replica("getmempoolcluster"));
+        assert!(!routing.is_replica("getzmqnotifications"));
+        assert!(!routing.is_replica("listunspent"));
+    }
+
+    #[test]
+    fn unreachable_replica_falls_back_to_primary() {
+        let client = SplitClient::new(node("primary", false), vec![node("a", true)]);
+        assert_eq!(call(&client, "getblockcount"), "primary");
+        assert_eq!(*client.replicas()[0].calls.borrow(), ["getblockcount"]);
+    }
+}
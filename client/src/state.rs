@@ -0,0 +1,351 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Persisted progress of long-running block scanners.
+//!
+//! [`ScanState`] records the blocks a scanner has processed, together with named positions
+//! (e.g. the number of compact block filters matched so far), in a file that is written
+//! atomically. An indexer can then be stopped at any point and resume where it left off.
+//!
+//! The last few blocks are kept so that a reorg can be handled: when the next block does not
+//! build on the tip, [`ScanState::rewind`] the tip and try again. The positions are rewound with
+//! the blocks. Like [`deposits`](crate::deposits) it does not talk to Core itself.
+//!
+//! ```no_run
+//! # #[cfg(feature = "client-sync")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use corepc_client::client_sync::{v28::Client, Auth};
+//! use corepc_client::state::{BlockId, ScanState};
+//!
+//! let cookie = Auth::CookieFile("/home/user/.bitcoin/.cookie".into());
+//! let client = Client::new_with_auth("http://localhost:8332", cookie)?;
+//! let mut state = ScanState::open("/var/lib/indexer/state.json")?;
+//! loop {
+//!     let height = state.next_height();
+//!     if height > client.get_block_count()?.0 {
+//!         std::thread::sleep(std::time::Duration::from_secs(10));
+//!         continue;
+//!     }
+//!     let hash = client.get_block_hash(height)?.block_hash()?;
+//!     let block = client.get_block(hash)?;
+//!     if !state.extends(&block.header.prev_blockhash) {
+//!         // Reorg, undo the effects of the tip block then try the height below.
+//!         state.rewind()?;
+//!         continue;
+//!     }
+//!     // Index `block` ...
+//!     state.advance(BlockId { height, hash })?;
+//!     state.save()?;
+//! }
+//! # }
+//! # #[cfg(not(feature = "client-sync"))]
+//! # fn main() {}
+//! ```
+
+use std::collections::{BTreeMap, VecDeque};
+use std::fs::{self, File};
+use std::io::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::{error, fmt};
+
+use bitcoin::BlockHash;
+use serde::{Deserialize, Serialize};
+
+/// Number of blocks kept to rewind after a reorg, unless set with [`ScanState::with_depth`].
+pub const DEFAULT_DEPTH: usize = 100;
+
+/// The version of the state file format.
+const VERSION: u32 = 1;
+
+/// A block identified by its height and hash.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct BlockId {
+    /// The height of the block.
+    pub height: u64,
+    /// The hash of the block.
+    pub hash: BlockHash,
+}
+
+/// A processed block and the positions after processing it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Entry {
+    block: BlockId,
+    positions: BTreeMap<String, u64>,
+}
+
+/// The contents of the state file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Contents {
+    version: u32,
+    /// The positions before the oldest retained block, or before any block is processed.
+    base: BTreeMap<String, u64>,
+    /// The most recently processed blocks, oldest first.
+    blocks: VecDeque<Entry>,
+}
+
+/// The progress of a block scanner, persisted to a file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScanState {
+    path: PathBuf,
+    depth: usize,
+    contents: Contents,
+}
+
+impl ScanState {
+    /// Opens the state persisted at `path`, or creates an empty state if the file does not exist.
+    ///
+    /// Nothing is written until [`ScanState::save`] is called.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref().to_path_buf();
+        let contents = match fs::read(&path) {
+            Ok(bytes) => {
+                let contents: Contents = serde_json::from_slice(&bytes)?;
+                if contents.version != VERSION {
+                    return Err(Error::UnsupportedVersion(contents.version));
+                }
+                contents
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound =>
+                Contents { version: VERSION, base: BTreeMap::new(), blocks: VecDeque::new() },
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self { path, depth: DEFAULT_DEPTH, contents })
+    }
+
+    /// Sets the number of blocks kept to rewind after a reorg, at least one block is kept.
+    pub fn with_depth(mut self, depth: usize) -> Self {
+        self.depth = depth.max(1);
+        self.trim();
+        self
+    }
+
+    /// Returns the last processed block, `None` if no block has been processed.
+    pub fn tip(&self) -> Option<BlockId> { self.contents.blocks.back().map(|entry| entry.block) }
+
+    /// Returns the height of the next block to process, `0` if no block has been processed.
+    pub fn next_height(&self) -> u64 { self.tip().map_or(0, |tip| tip.height + 1) }
+
+    /// Returns `true` if a block with the previous block hash `prev_blockhash` builds on the tip.
+    ///
+    /// Always `true` if no block has been processed.
+    pub fn extends(&self, prev_blockhash: &BlockHash) -> bool {
+        self.tip().map_or(true, |tip| tip.hash == *prev_blockhash)
+    }
+
+    /// Records that `block` was processed, it must be at the height after the tip.
+    ///
+    /// The positions are carried over from the tip.
+    pub fn advance(&mut self, block: BlockId) -> Result<(), Error> {
+        if let Some(tip) = self.tip() {
+            if block.height != tip.height + 1 {
+                return Err(Error::NotNext { tip, block });
+            }
+        }
+        let positions = self.positions().clone();
+        self.contents.blocks.push_back(Entry { block, positions });
+        self.trim();
+        Ok(())
+    }
+
+    /// Removes the tip after it was reorged out, returns the new tip.
+    ///
+    /// The positions go back to what they were after processing the new tip.
+    ///
+    /// # Errors
+    ///
+    /// If the tip is the oldest block kept, the reorg is deeper than the depth of the state.
+    pub fn rewind(&mut self) -> Result<Option<BlockId>, Error> {
+        match self.contents.blocks.len() {
+            0 => Ok(None),
+            1 if self.contents.blocks[0].block.height > 0 => Err(Error::ReorgTooDeep),
+            _ => {
+                self.contents.blocks.pop_back();
+                Ok(self.tip())
+            }
+        }
+    }
+
+    /// Returns the value of the named position, `None` if it was never set.
+    pub fn position(&self, name: &str) -> Option<u64> { self.positions().get(name).copied() }
+
+    /// Sets the value of the named position at the tip e.g., after processing the tip block.
+    pub fn set_position(&mut self, name: &str, value: u64) {
+        let positions = match self.contents.blocks.back_mut() {
+            Some(entry) => &mut entry.positions,
+            None => &mut self.contents.base,
+        };
+        positions.insert(name.to_owned(), value);
+    }
+
+    /// Writes the state to its file atomically, either the previous or the new state is on disk
+    /// if the process is interrupted.
+    pub fn save(&self) -> Result<(), Error> {
+        let json = serde_json::to_vec_pretty(&self.contents)?;
+        let mut tmp_name = self.path.file_name().unwrap_or_default().to_os_string();
+        tmp_name.push(".tmp");
+        let tmp = self.path.with_file_name(tmp_name);
+
+        let mut file = File::create(&tmp)?;
+        file.write_all(&json)?;
+        file.sync_all()?;
+        fs::rename(&tmp, &self.path)?;
+        // Persist the rename, directories cannot be opened as files on Windows.
+        #[cfg(unix)]
+        {
+            if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                File::open(dir)?.sync_all()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the path of the state file.
+    pub fn path(&self) -> &Path { &self.path }
+
+    /// Returns the positions at the tip.
+    fn positions(&self) -> &BTreeMap<String, u64> {
+        match self.contents.blocks.back() {
+            Some(entry) => &entry.positions,
+            None => &self.contents.base,
+        }
+    }
+
+    /// Drops the oldest blocks beyond the depth.
+    fn trim(&mut self) {
+        while self.contents.blocks.len() > self.depth {
+            let oldest = self.contents.blocks.pop_front().expect("not empty");
+            self.contents.base = oldest.positions;
+        }
+    }
+}
+
+/// Error returned by [`ScanState`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// Reading or writing the state file failed.
+    Io(io::Error),
+    /// The state file is not valid JSON.
+    Json(serde_json::Error),
+    /// The state file was written by an unsupported version of this library.
+    UnsupportedVersion(u32),
+    /// The block passed to [`ScanState::advance`] is not at the height after the tip.
+    NotNext {
+        /// The tip of the state.
+        tip: BlockId,
+        /// The block passed to `advance`.
+        block: BlockId,
+    },
+    /// The reorg is deeper than the blocks kept, the scanner must start over.
+    ReorgTooDeep,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Error::*;
+
+        match *self {
+            Io(ref e) => write!(f, "state file I/O error: {}", e),
+            Json(ref e) => write!(f, "invalid state file: {}", e),
+            UnsupportedVersion(v) => write!(f, "unsupported state file version: {}", v),
+            NotNext { tip, block } => write!(
+                f,
+                "block at height {} is not next after the tip at height {}",
+                block.height, tip.height
+            ),
+            ReorgTooDeep => write!(f, "reorg deeper than the blocks kept"),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use Error::*;
+
+        match *self {
+            Io(ref e) => Some(e),
+            Json(ref e) => Some(e),
+            UnsupportedVersion(_) | NotNext { .. } | ReorgTooDeep => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self { Self::Io(e) }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self { Self::Json(e) }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::hashes::Hash as _;
+
+    use super::*;
+
+    fn block(height: u64, fork: u8) -> BlockId {
+        let mut bytes = [fork; 32];
+        bytes[..8].copy_from_slice(&height.to_le_bytes());
+        BlockId { height, hash: BlockHash::from_byte_array(bytes) }
+    }
+
+    fn state_path(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("corepc-state-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn advance_and_resume() {
+        let path = state_path("resume.json");
+        let mut state = ScanState::open(&path).unwrap();
+        assert_eq!((state.tip(), state.next_height()), (None, 0));
+
+        for height in 0..3 {
+            state.advance(block(height, 0)).unwrap();
+            state.set_position("filters", height * 10);
+        }
+        assert!(matches!(state.advance(block(5, 0)), Err(Error::NotNext { .. })));
+        state.save().unwrap();
+
+        let resumed = ScanState::open(&path).unwrap();
+        assert_eq!(resumed, state);
+        assert_eq!(resumed.tip(), Some(block(2, 0)));
+        assert_eq!(resumed.next_height(), 3);
+        assert_eq!(resumed.position("filters"), Some(20));
+    }
+
+    #[test]
+    fn rewind_restores_positions() {
+        let mut state = ScanState::open(state_path("rewind.json")).unwrap().with_depth(3);
+        for height in 0..5 {
+            state.advance(block(height, 0)).unwrap();
+            state.set_position("filters", height);
+        }
+
+        // Block 4 on another fork does not build on block 4 of the first fork.
+        assert!(!state.extends(&block(4, 1).hash));
+        assert!(state.extends(&block(4, 0).hash));
+
+        assert_eq!(state.rewind().unwrap(), Some(block(3, 0)));
+        assert_eq!(state.position("filters"), Some(3));
+        assert_eq!(state.rewind().unwrap(), Some(block(2, 0)));
+        // Only 3 blocks are kept, block 2 is the oldest.
+        assert!(matches!(state.rewind(), Err(Error::ReorgTooDeep)));
+
+        state.advance(block(3, 1)).unwrap();
+        assert_eq!(state.position("filters"), Some(2));
+    }
+
+    #[test]
+    fn position_before_any_block() {
+        let mut state = ScanState::open(state_path("base.json")).unwrap();
+        state.set_position("start", 42);
+        state.advance(block(0, 0)).unwrap();
+        assert_eq!(state.position("start"), Some(42));
+        assert_eq!(state.rewind().unwrap(), None);
+        assert_eq!(state.position("start"), Some(42));
+    }
+}
@@ -0,0 +1,237 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Block subsidy and coin supply.
+//!
+//! [`circulating_at`] computes the coins issued by the block subsidies up to a height, without
+//! asking the node. [`check`] compares it with the `total_amount` of the node's UTXO set, as
+//! reported by `gettxoutsetinfo`, to catch a node (or chain) issuing more coins than allowed.
+//!
+//! Works with the `Client` of any version.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use corepc_client::bitcoin::Network;
+//! use corepc_client::client_sync::{v28::Client, Auth};
+//! use corepc_client::supply;
+//!
+//! let cookie = Auth::CookieFile("/home/user/.bitcoin/.cookie".into());
+//! let client = Client::new_with_auth("http://localhost:8332", cookie)?;
+//!
+//! let check = supply::check(&client, Network::Bitcoin)?;
+//! if let Some(discrepancy) = check.discrepancy() {
+//!     println!("supply at height {} does not add up: {:?}", check.height, discrepancy);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use bitcoin::{Amount, BlockHash, Network};
+use serde::Deserialize;
+
+use crate::client_sync::{Call, Result};
+
+/// The subsidy of the blocks before the first halving.
+const INITIAL_SUBSIDY: Amount = Amount::from_int_btc(50);
+
+/// The number of halvings after which Core returns a zero subsidy, shifting by 64 or more is
+/// undefined behaviour in C++.
+const MAX_HALVINGS: u64 = 64;
+
+/// Returns the number of blocks between two subsidy halvings on `network`.
+pub fn halving_interval(network: Network) -> u32 {
+    match network {
+        Network::Regtest => 150,
+        _ => 210_000,
+    }
+}
+
+/// Returns the subsidy of the block at `height` on `network`, excluding fees.
+pub fn subsidy_at(height: u32, network: Network) -> Amount {
+    let halvings = u64::from(height / halving_interval(network));
+    if halvings >= MAX_HALVINGS {
+        return Amount::ZERO;
+    }
+    Amount::from_sat(INITIAL_SUBSIDY.to_sat() >> halvings)
+}
+
+/// Returns the coins issued by the subsidies of the blocks up to and including `height`.
+///
+/// The subsidy of the genesis block is not included, its output cannot be spent and is not in
+/// the UTXO set.
+pub fn circulating_at(height: u32, network: Network) -> Amount {
+    let interval = u64::from(halving_interval(network));
+    let height = u64::from(height);
+
+    let mut total = Amount::ZERO;
+    let mut start = 1;
+    while start <= height && start / interval < MAX_HALVINGS {
+        let end = ((start / interval + 1) * interval - 1).min(height);
+        // `start` is at most `height`, which fits in a `u32`.
+        total += subsidy_at(start as u32, network) * (end - start + 1);
+        start = end + 1;
+    }
+    total
+}
+
+/// The UTXO set of a node compared with the coins issued up to its tip, see [`check`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SupplyCheck {
+    /// The height of the tip of the node.
+    pub height: u32,
+    /// The hash of the tip of the node.
+    pub best_block: BlockHash,
+    /// The coins issued up to `height`, see [`circulating_at`].
+    pub expected: Amount,
+    /// The total amount of the UTXO set, `total_amount` of `gettxoutsetinfo`.
+    pub total_amount: Amount,
+    /// The coins issued but permanently excluded from the UTXO set (e.g., `OP_RETURN` outputs and
+    /// unclaimed fees), excluding the genesis block subsidy.
+    ///
+    /// Only known if the node runs with `-coinstatsindex` (v21 and later).
+    pub unspendable: Option<Amount>,
+}
+
+impl SupplyCheck {
+    /// Returns how the UTXO set does not add up to the coins issued, `None` if it does.
+    ///
+    /// Without `unspendable` a UTXO set holding less than was issued is not reported, coins can be
+    /// burned or left unclaimed by miners.
+    pub fn discrepancy(&self) -> Option<Discrepancy> {
+        let accounted = self.total_amount + self.unspendable.unwrap_or(Amount::ZERO);
+        if accounted > self.expected {
+            Some(Discrepancy::Excess(accounted - self.expected))
+        } else if self.unspendable.is_some() && accounted < self.expected {
+            Some(Discrepancy::Shortfall(self.expected - accounted))
+        } else {
+            None
+        }
+    }
+}
+
+/// How the UTXO set of a node does not add up to the coins issued.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Discrepancy {
+    /// The node accounts for more coins than the subsidies issued, by this amount.
+    Excess(Amount),
+    /// The node accounts for fewer coins than the subsidies issued, by this amount.
+    Shortfall(Amount),
+}
+
+/// The fields of `gettxoutsetinfo` used by [`check`], the same in all versions of Core.
+#[derive(Deserialize)]
+struct TxOutSetInfo {
+    height: u32,
+    #[serde(rename = "bestblock")]
+    best_block: String,
+    #[serde(with = "bitcoin::amount::serde::as_btc")]
+    total_amount: Amount,
+    #[serde(default, with = "bitcoin::amount::serde::as_btc::opt")]
+    total_unspendable_amount: Option<Amount>,
+}
+
+/// Compares the UTXO set of the node behind `client` with the coins issued up to its tip.
+///
+/// `network` is the chain the node runs on, it determines the halving interval. This calls
+/// `gettxoutsetinfo`, which is slow on mainnet unless the node runs with `-coinstatsindex`.
+pub fn check<C: Call>(client: &C, network: Network) -> Result<SupplyCheck> {
+    let info: TxOutSetInfo = client.call("gettxoutsetinfo", &[])?;
+    // With `-coinstatsindex` the genesis block subsidy is counted as unspendable.
+    let unspendable = info
+        .total_unspendable_amount
+        .map(|amount| amount.checked_sub(INITIAL_SUBSIDY).unwrap_or(Amount::ZERO));
+    Ok(SupplyCheck {
+        height: info.height,
+        best_block: info.best_block.parse()?,
+        expected: circulating_at(info.height, network),
+        total_amount: info.total_amount,
+        unspendable,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Answers `gettxoutsetinfo` with the JSON it holds.
+    struct Node(serde_json::Value);
+
+    impl Call for Node {
+        fn call<T: for<'a> serde::de::Deserialize<'a>>(
+            &self,
+            method: &str,
+            _args: &[serde_json::Value],
+        ) -> Result<T> {
+            assert_eq!(method, "gettxoutsetinfo");
+            Ok(serde_json::from_value(self.0.clone())?)
+        }
+    }
+
+    fn node(total_amount: f64, total_unspendable_amount: Option<f64>) -> Node {
+        let mut info = serde_json::json!({
+            "height": 200,
+            "bestblock": format!("{:064x}", 200),
+            "transactions": 200,
+            "txouts": 200,
+            "total_amount": total_amount,
+        });
+        if let Some(amount) = total_unspendable_amount {
+            info["total_unspendable_amount"] = amount.into();
+        }
+        Node(info)
+    }
+
+    #[test]
+    fn subsidy_halves() {
+        assert_eq!(subsidy_at(0, Network::Bitcoin), Amount::from_int_btc(50));
+        assert_eq!(subsidy_at(209_999, Network::Bitcoin), Amount::from_int_btc(50));
+        assert_eq!(subsidy_at(210_000, Network::Bitcoin), Amount::from_int_btc(25));
+        assert_eq!(subsidy_at(840_000, Network::Bitcoin), Amount::from_sat(312_500_000));
+        assert_eq!(subsidy_at(150, Network::Regtest), Amount::from_int_btc(25));
+        assert_eq!(subsidy_at(u32::MAX, Network::Bitcoin), Amount::ZERO);
+        assert_eq!(subsidy_at(64 * 150, Network::Regtest), Amount::ZERO);
+    }
+
+    #[test]
+    fn circulating_excludes_genesis() {
+        assert_eq!(circulating_at(0, Network::Bitcoin), Amount::ZERO);
+        assert_eq!(circulating_at(1, Network::Bitcoin), Amount::from_int_btc(50));
+        assert_eq!(
+            circulating_at(210_000, Network::Bitcoin),
+            Amount::from_int_btc(209_999 * 50 + 25)
+        );
+        assert_eq!(circulating_at(200, Network::Regtest), Amount::from_int_btc(149 * 50 + 51 * 25));
+    }
+
+    #[test]
+    fn circulating_is_capped() {
+        // 21 million less the rounding of the halvings and the genesis block subsidy.
+        let cap = Amount::from_sat(2_099_999_997_690_000) - INITIAL_SUBSIDY;
+        assert_eq!(circulating_at(u32::MAX, Network::Bitcoin), cap);
+        assert_eq!(
+            circulating_at(u32::MAX, Network::Regtest),
+            circulating_at(64 * 150, Network::Regtest)
+        );
+    }
+
+    #[test]
+    fn discrepancies() {
+        let expected = circulating_at(200, Network::Regtest).to_btc();
+
+        let check = super::check(&node(expected, None), Network::Regtest).unwrap();
+        assert_eq!(check.height, 200);
+        assert_eq!(check.discrepancy(), None);
+
+        // Burned coins are only reported with `-coinstatsindex`.
+        let check = super::check(&node(expected - 1.0, None), Network::Regtest).unwrap();
+        assert_eq!(check.discrepancy(), None);
+        let check = super::check(&node(expected - 1.0, Some(50.5)), Network::Regtest).unwrap();
+        assert_eq!(check.unspendable, Some(Amount::from_btc(0.5).unwrap()));
+        assert_eq!(
+            check.discrepancy(),
+            Some(Discrepancy::Shortfall(Amount::from_btc(0.5).unwrap()))
+        );
+
+        let check = super::check(&node(expected + 1.0, Some(50.0)), Network::Regtest).unwrap();
+        assert_eq!(check.discrepancy(), Some(Discrepancy::Excess(Amount::ONE_BTC)));
+    }
+}
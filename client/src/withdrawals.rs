@@ -0,0 +1,319 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Batching of wallet payouts.
+//!
+//! [`Batcher`] collects payouts and pays them with a single replaceable transaction at a target
+//! fee rate, the wallet selects the inputs and adds change. Batches that get stuck in the mempool
+//! can be fee bumped with [`Batcher::bump`].
+//!
+//! Works with the `Client` of any version from v0.21 (the first to accept a `fee_rate`). The
+//! results of `sendmany`, `bumpfee` and `walletcreatefundedpsbt` have not changed shape since
+//! v0.17 so they are deserialized with the `v17` types whatever the version of the node. The result
+//! of `gettransaction` has, only its `confirmations` field is read.
+//!
+//! ```no_run
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use corepc_client::bitcoin::address::NetworkUnchecked;
+//! use corepc_client::bitcoin::{Address, Amount, FeeRate};
+//! use corepc_client::client_sync::{v28::Client, Auth};
+//! use corepc_client::withdrawals::Batcher;
+//!
+//! let cookie = Auth::CookieFile("/home/user/.bitcoin/.cookie".into());
+//! let client = Client::new_with_auth("http://localhost:8332/wallet/hot", cookie)?;
+//! let mut batcher = Batcher::new(FeeRate::from_sat_per_vb(5).unwrap());
+//!
+//! let address = "bc1qar0srrr7xfkvy5l643lydnw9re59gtzzwf5mdq".parse::<Address<NetworkUnchecked>>()?;
+//! batcher.add(address.assume_checked(), Amount::from_sat(100_000));
+//! if let Some(batch) = batcher.send(&client)? {
+//!     println!("paid {} payouts in {}", batch.payouts.len(), batch.txid);
+//! }
+//!
+//! // Later, if the batch has not confirmed.
+//! batcher.bump(&client, FeeRate::from_sat_per_vb(20).unwrap())?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+
+use bitcoin::{Address, Amount, FeeRate, Psbt, SignedAmount, Txid};
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::client_sync::{sat_per_vb, Call, Error, Result};
+
+/// A payment to make.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Payout {
+    /// The address to pay.
+    pub address: Address,
+    /// The amount to pay.
+    pub amount: Amount,
+}
+
+/// A batch of payouts sent in a single transaction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Batch {
+    /// The id of the transaction paying the batch, updated when it is fee bumped.
+    pub txid: Txid,
+    /// The payouts paid by the transaction.
+    pub payouts: Vec<Payout>,
+    /// The fee rate the transaction was last sent with.
+    pub fee_rate: FeeRate,
+}
+
+/// A funded but unsigned batch transaction, see [`Batcher::create_psbt`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnsignedBatch {
+    /// The funded transaction.
+    pub psbt: Psbt,
+    /// The fee the transaction pays.
+    pub fee: SignedAmount,
+    /// The index of the change output, `None` if there is no change.
+    pub change_position: Option<u32>,
+}
+
+/// Collects payouts and sends them in batches.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Batcher {
+    /// Fee rate new batches are sent with.
+    fee_rate: FeeRate,
+    /// Payouts not yet sent, keyed by address.
+    pending: BTreeMap<Address, Amount>,
+    /// Batches sent but not yet confirmed.
+    in_flight: Vec<Batch>,
+}
+
+impl Batcher {
+    /// Creates a batcher that sends batches at `fee_rate`.
+    pub fn new(fee_rate: FeeRate) -> Self {
+        Batcher { fee_rate, pending: BTreeMap::new(), in_flight: vec![] }
+    }
+
+    /// Sets the fee rate used for the next batches.
+    pub fn set_fee_rate(&mut self, fee_rate: FeeRate) { self.fee_rate = fee_rate; }
+
+    /// Adds a payout to the next batch, payouts to the same address are merged.
+    pub fn add(&mut self, address: Address, amount: Amount) {
+        let total = self.pending.entry(address).or_insert(Amount::ZERO);
+        *total += amount;
+    }
+
+    /// Returns the payouts that will be paid by the next batch.
+    pub fn pending(&self) -> Vec<Payout> {
+        self.pending
+            .iter()
+            .map(|(address, amount)| Payout { address: address.clone(), amount: *amount })
+            .collect()
+    }
+
+    /// Returns the batches sent but not seen confirmed by [`Batcher::bump`] yet.
+    pub fn in_flight(&self) -> &[Batch] { &self.in_flight }
+
+    /// Pays all pending payouts in one replaceable transaction using `sendmany`.
+    ///
+    /// Returns `None` if there are no pending payouts. The payouts are only removed from the
+    /// pending set if the transaction was sent.
+    pub fn send<C: Call>(&mut self, client: &C) -> Result<Option<Batch>> {
+        if self.pending.is_empty() {
+            return Ok(None);
+        }
+        let args = [
+            json!(""),
+            self.outputs(),
+            json!(1),
+            json!(""),
+            json!([]),
+            json!(true), // replaceable
+            json!(null),
+            json!("unset"),
+            json!(sat_per_vb(self.fee_rate)),
+        ];
+        let json: types::v17::SendMany = client.call("sendmany", &args)?;
+        let txid = json.into_model().map_err(|e| Error::Model(Box::new(e)))?.0;
+
+        let batch = Batch { txid, payouts: self.pending(), fee_rate: self.fee_rate };
+        self.pending.clear();
+        self.in_flight.push(batch.clone());
+        Ok(Some(batch))
+    }
+
+    /// Funds a replaceable transaction paying all pending payouts using `walletcreatefundedpsbt`.
+    ///
+    /// Use this to sign the batch elsewhere, the payouts stay pending. Returns `None` if there
+    /// are no pending payouts.
+    pub fn create_psbt<C: Call>(&self, client: &C) -> Result<Option<UnsignedBatch>> {
+        if self.pending.is_empty() {
+            return Ok(None);
+        }
+        let outputs = self
+            .pending
+            .iter()
+            .map(|(address, amount)| json!({ address.to_string(): amount.to_btc() }))
+            .collect::<Vec<_>>();
+        let options = json!({ "replaceable": true, "fee_rate": sat_per_vb(self.fee_rate) });
+        let args = [json!([]), json!(outputs), json!(0), options];
+        let json: types::v17::WalletCreateFundedPsbt =
+            client.call("walletcreatefundedpsbt", &args)?;
+        // Not converted with `into_model`, which rejects the `-1` returned when there is no change.
+        let psbt = json.psbt.parse::<Psbt>().map_err(|e| Error::Model(Box::new(e)))?;
+        let fee = SignedAmount::from_btc(json.fee).map_err(|e| Error::Model(Box::new(e)))?;
+        let change_position = u32::try_from(json.change_position).ok();
+
+        Ok(Some(UnsignedBatch { psbt, fee, change_position }))
+    }
+
+    /// Replaces the unconfirmed in-flight batches paying less than `fee_rate` using `bumpfee`.
+    ///
+    /// Confirmed and conflicted batches are no longer tracked. Returns the batches that were
+    /// bumped, with their new txid.
+    pub fn bump<C: Call>(&mut self, client: &C, fee_rate: FeeRate) -> Result<Vec<Batch>> {
+        let mut bumped = vec![];
+        let mut i = 0;
+        while i < self.in_flight.len() {
+            let batch = &mut self.in_flight[i];
+            let tx: Confirmations = client.call("gettransaction", &[json!(batch.txid)])?;
+            if tx.confirmations != 0 {
+                self.in_flight.remove(i);
+                continue;
+            }
+            if batch.fee_rate < fee_rate {
+                let options = json!({ "fee_rate": sat_per_vb(fee_rate) });
+                let json: types::v17::BumpFee =
+                    client.call("bumpfee", &[json!(batch.txid), options])?;
+                batch.txid = json.into_model().map_err(|e| Error::Model(Box::new(e)))?.txid;
+                batch.fee_rate = fee_rate;
+                bumped.push(batch.clone());
+            }
+            i += 1;
+        }
+        Ok(bumped)
+    }
+
+    /// Returns the pending payouts in the `{ "address": amount }` format used by `sendmany`.
+    fn outputs(&self) -> serde_json::Value {
+        let outputs = self
+            .pending
+            .iter()
+            .map(|(address, amount)| (address.to_string(), json!(amount.to_btc())))
+            .collect::<serde_json::Map<_, _>>();
+        serde_json::Value::Object(outputs)
+    }
+}
+
+/// The field of the `gettransaction` result used to track a batch, the same in all versions.
+#[derive(Deserialize)]
+struct Confirmations {
+    /// The number of confirmations, negative if the transaction conflicts with the chain.
+    confirmations: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use bitcoin::address::NetworkUnchecked;
+    use bitcoin::{absolute, transaction, OutPoint, ScriptBuf, Sequence, Transaction, TxIn, TxOut};
+
+    use super::*;
+
+    /// A wallet whose batch confirms after `bumps_until_confirmed` fee bumps.
+    struct Wallet {
+        bumps_until_confirmed: Cell<u32>,
+    }
+
+    impl Call for Wallet {
+        fn call<T: for<'a> serde::de::Deserialize<'a>>(
+            &self,
+            method: &str,
+            _args: &[serde_json::Value],
+        ) -> Result<T> {
+            let json = match method {
+                "sendmany" => json!(txid(1).to_string()),
+                "gettransaction" => json!({
+                    "confirmations": u32::from(self.bumps_until_confirmed.get() == 0),
+                    "txid": txid(1).to_string(),
+                }),
+                "bumpfee" => {
+                    self.bumps_until_confirmed.set(self.bumps_until_confirmed.get() - 1);
+                    json!({ "txid": txid(2).to_string(), "origfee": 0.0001, "fee": 0.0002, "errors": [] })
+                }
+                "walletcreatefundedpsbt" =>
+                    json!({ "psbt": psbt().to_string(), "fee": 0.0001, "changepos": -1 }),
+                _ => panic!("unexpected call to {}", method),
+            };
+            Ok(serde_json::from_value(json)?)
+        }
+    }
+
+    fn txid(n: u8) -> Txid { format!("{:064x}", n).parse().unwrap() }
+
+    fn psbt() -> Psbt {
+        let tx = Transaction {
+            version: transaction::Version::TWO,
+            lock_time: absolute::LockTime::ZERO,
+            input: vec![TxIn {
+                previous_output: OutPoint { txid: txid(3), vout: 0 },
+                script_sig: ScriptBuf::new(),
+                sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+                witness: Default::default(),
+            }],
+            output: vec![TxOut {
+                value: Amount::from_sat(1_000),
+                script_pubkey: address().script_pubkey(),
+            }],
+        };
+        Psbt::from_unsigned_tx(tx).unwrap()
+    }
+
+    fn address() -> Address {
+        "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080"
+            .parse::<Address<NetworkUnchecked>>()
+            .unwrap()
+            .assume_checked()
+    }
+
+    #[test]
+    fn payouts_to_same_address_are_merged() {
+        let mut batcher = Batcher::new(FeeRate::from_sat_per_vb(1).unwrap());
+        batcher.add(address(), Amount::from_sat(1_000));
+        batcher.add(address(), Amount::from_sat(500));
+
+        let pending = batcher.pending();
+        assert_eq!(pending, vec![Payout { address: address(), amount: Amount::from_sat(1_500) }]);
+        assert_eq!(batcher.outputs(), json!({ address().to_string(): 0.000015 }));
+    }
+
+    #[test]
+    fn send_and_bump_until_confirmed() {
+        let wallet = Wallet { bumps_until_confirmed: Cell::new(1) };
+        let mut batcher = Batcher::new(FeeRate::from_sat_per_vb(1).unwrap());
+        assert_eq!(batcher.send(&wallet).unwrap(), None);
+
+        batcher.add(address(), Amount::from_sat(1_000));
+        let batch = batcher.send(&wallet).unwrap().unwrap();
+        assert_eq!(batch.txid, txid(1));
+        assert!(batcher.pending().is_empty());
+
+        let fee_rate = FeeRate::from_sat_per_vb(5).unwrap();
+        let bumped = batcher.bump(&wallet, fee_rate).unwrap();
+        assert_eq!(bumped, vec![Batch { txid: txid(2), payouts: batch.payouts, fee_rate }]);
+        assert_eq!(batcher.in_flight(), bumped);
+
+        assert!(batcher.bump(&wallet, fee_rate).unwrap().is_empty());
+        assert!(batcher.in_flight().is_empty());
+    }
+
+    #[test]
+    fn create_psbt_without_change() {
+        let wallet = Wallet { bumps_until_confirmed: Cell::new(0) };
+        let mut batcher = Batcher::new(FeeRate::from_sat_per_vb(1).unwrap());
+        batcher.add(address(), Amount::from_sat(1_000));
+
+        let unsigned = batcher.create_psbt(&wallet).unwrap().unwrap();
+        assert_eq!(unsigned.psbt, psbt());
+        assert_eq!(unsigned.fee, SignedAmount::from_sat(10_000));
+        assert_eq!(unsigned.change_position, None);
+        assert_eq!(batcher.pending().len(), 1);
+    }
+}
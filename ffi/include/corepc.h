@@ -0,0 +1,41 @@
+/* SPDX-License-Identifier: CC0-1.0 */
+
+/*
+ * C ABI of `corepc-ffi`, see the crate docs.
+ *
+ * All strings are NUL terminated UTF-8, results are JSON. Functions returning a pointer return
+ * NULL on error, `corepc_last_error` then returns the error message. Panics in the library are
+ * returned as errors.
+ */
+
+#ifndef COREPC_H
+#define COREPC_H
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+typedef struct CorepcClient CorepcClient;
+
+/* Creates a client, authenticating with `cookie_file` if not NULL, else `user` and `pass`. */
+CorepcClient *corepc_client_new(const char *url, const char *cookie_file, const char *user,
+                                const char *pass);
+void corepc_client_free(CorepcClient *client);
+
+/* Calls `method` with the JSON array `params` (NULL for none), returns the result as JSON. */
+char *corepc_call(const CorepcClient *client, const char *method, const char *params);
+/* Calls `method`, returns the result converted into its model type as JSON. */
+char *corepc_call_model(const CorepcClient *client, const char *method);
+/* Returns the JSON array of the methods supported by `corepc_call_model`. */
+char *corepc_model_methods(void);
+
+/* Returns the last error on this thread, owned by the library, NULL if none. */
+const char *corepc_last_error(void);
+/* Frees a string returned by the library. */
+void corepc_string_free(char *s);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* COREPC_H */
@@ -0,0 +1,451 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! C ABI for connecting to Bitcoin Core, calling methods and getting the results as JSON.
+//!
+//! Lets test infrastructure written in other languages reuse the client and the normalization of
+//! the version specific types into the model types. All strings are NUL terminated UTF-8, results
+//! are JSON. See `include/corepc.h` for the C declarations and the README for use from Python.
+//!
+//! Functions returning a pointer return null on error, [`corepc_last_error`] then returns the error
+//! message. The last error is cleared at the start of each call that can fail, and panics are
+//! caught and reported as errors instead of unwinding into the caller.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::{error, fmt, ptr};
+
+use client::client_sync::v31::Client;
+use client::client_sync::{self, Auth};
+use client::types;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// The oldest major version of Core supported.
+const MIN_MAJOR: u32 = 17;
+/// The latest major version of Core supported, later versions use its types.
+const MAX_MAJOR: u32 = 31;
+
+thread_local! {
+    /// The message of the last error on this thread.
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// A client connected to a node, created by [`corepc_client_new`].
+pub struct CorepcClient {
+    client: Client,
+    /// The major version of the node, read on first use.
+    major: OnceLock<u32>,
+}
+
+impl CorepcClient {
+    /// Creates a client for the node at `url`.
+    pub fn new(url: &str, auth: Auth) -> Result<Self, Error> {
+        let client = match auth {
+            Auth::None => Client::new(url),
+            auth => Client::new_with_auth(url, auth)?,
+        };
+        Ok(CorepcClient { client, major: OnceLock::new() })
+    }
+
+    /// Calls `method` with the JSON array `params`, returning the result as JSON.
+    pub fn call(&self, method: &str, params: &str) -> Result<String, Error> {
+        let args = parse_params(params)?;
+        let result: Value = self.client.call(method, &args)?;
+        Ok(result.to_string())
+    }
+
+    /// Calls `method` without params, returning the result converted into its model type as JSON.
+    ///
+    /// Supported methods are listed in [`MODEL_METHODS`].
+    pub fn call_model(&self, method: &str) -> Result<String, Error> {
+        if !MODEL_METHODS.contains(&method) {
+            return Err(Error::UnsupportedMethod(method.to_owned()));
+        }
+        let major = self.major()?;
+        let result: Value = self.client.call(method, &[])?;
+        Ok(model(major, method, result)?.to_string())
+    }
+
+    /// Returns the major version of the node, clamped to the versions supported.
+    fn major(&self) -> Result<u32, Error> {
+        if let Some(major) = self.major.get() {
+            return Ok(*major);
+        }
+        let version = self.client.server_version()?;
+        let major = (version / 10_000) as u32;
+        if major < MIN_MAJOR {
+            return Err(Error::UnsupportedVersion(version));
+        }
+        Ok(*self.major.get_or_init(|| major.min(MAX_MAJOR)))
+    }
+}
+
+/// The methods [`corepc_call_model`] supports.
+pub const MODEL_METHODS: &[&str] =
+    &["getbestblockhash", "getblockchaininfo", "getmempoolinfo", "getnetworkinfo", "getrawmempool"];
+
+/// Converts `result` into the model type of `method`, using the types of Core `major`.
+macro_rules! model {
+    ($major:expr, $result:expr, $ty:ident) => {
+        match $major {
+            17 => to_model($result, types::v17::$ty::into_model),
+            18 => to_model($result, types::v18::$ty::into_model),
+            19 => to_model($result, types::v19::$ty::into_model),
+            20 => to_model($result, types::v20::$ty::into_model),
+            21 => to_model($result, types::v21::$ty::into_model),
+            22 => to_model($result, types::v22::$ty::into_model),
+            23 => to_model($result, types::v23::$ty::into_model),
+            24 => to_model($result, types::v24::$ty::into_model),
+            25 => to_model($result, types::v25::$ty::into_model),
+            26 => to_model($result, types::v26::$ty::into_model),
+            27 => to_model($result, types::v27::$ty::into_model),
+            28 => to_model($result, types::v28::$ty::into_model),
+            29 => to_model($result, types::v29::$ty::into_model),
+            30 => to_model($result, types::v30::$ty::into_model),
+            _ => to_model($result, types::v31::$ty::into_model),
+        }
+    };
+}
+
+/// Returns the model type of `method` for `result`, returned by a node of major version `major`.
+fn model(major: u32, method: &str, result: Value) -> Result<Value, Error> {
+    match method {
+        "getbestblockhash" => model!(major, result, GetBestBlockHash),
+        "getblockchaininfo" => model!(major, result, GetBlockchainInfo),
+        "getmempoolinfo" => model!(major, result, GetMempoolInfo),
+        "getnetworkinfo" => model!(major, result, GetNetworkInfo),
+        "getrawmempool" => model!(major, result, GetRawMempool),
+        _ => Err(Error::UnsupportedMethod(method.to_owned())),
+    }
+}
+
+/// Deserializes `result` into the version specific type `T` and converts it with `into_model`.
+fn to_model<T, M, E>(result: Value, into_model: fn(T) -> Result<M, E>) -> Result<Value, Error>
+where
+    T: DeserializeOwned,
+    M: Serialize,
+    E: fmt::Display,
+{
+    let model =
+        into_model(serde_json::from_value(result)?).map_err(|e| Error::Model(e.to_string()))?;
+    Ok(serde_json::to_value(model)?)
+}
+
+/// Parses the JSON array `params`, an empty string is no params.
+fn parse_params(params: &str) -> Result<Vec<Value>, Error> {
+    if params.trim().is_empty() {
+        return Ok(vec![]);
+    }
+    Ok(serde_json::from_str(params)?)
+}
+
+/// Creates a client for the node at `url`, null on error.
+///
+/// Authenticates with `cookie_file` if not null, otherwise with `user` and `pass` if not null.
+///
+/// # Safety
+///
+/// The arguments must be null or valid NUL terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn corepc_client_new(
+    url: *const c_char,
+    cookie_file: *const c_char,
+    user: *const c_char,
+    pass: *const c_char,
+) -> *mut CorepcClient {
+    let client = guard(|| {
+        let url = str_arg(url)?.ok_or(Error::NullArg("url"))?;
+        let auth = match (str_arg(cookie_file)?, str_arg(user)?, str_arg(pass)?) {
+            (Some(cookie_file), _, _) => Auth::CookieFile(PathBuf::from(cookie_file)),
+            (None, Some(user), Some(pass)) => Auth::UserPass(user.into(), pass.into()),
+            _ => Auth::None,
+        };
+        CorepcClient::new(url, auth)
+    });
+    match client {
+        Ok(client) => Box::into_raw(Box::new(client)),
+        Err(e) => set_last_error(e),
+    }
+}
+
+/// Frees a client created by [`corepc_client_new`].
+///
+/// # Safety
+///
+/// `client` must be null or returned by [`corepc_client_new`], and not used after this call.
+#[no_mangle]
+pub unsafe extern "C" fn corepc_client_free(client: *mut CorepcClient) {
+    if !client.is_null() {
+        drop(Box::from_raw(client));
+    }
+}
+
+/// Calls `method` with the JSON array `params` (null for none), returning the result as JSON.
+///
+/// Free the returned string with [`corepc_string_free`], null on error.
+///
+/// # Safety
+///
+/// `client` must be returned by [`corepc_client_new`], the strings must be null or valid.
+#[no_mangle]
+pub unsafe extern "C" fn corepc_call(
+    client: *const CorepcClient,
+    method: *const c_char,
+    params: *const c_char,
+) -> *mut c_char {
+    let result = guard(|| {
+        let client = client.as_ref().ok_or(Error::NullArg("client"))?;
+        let method = str_arg(method)?.ok_or(Error::NullArg("method"))?;
+        client.call(method, str_arg(params)?.unwrap_or(""))
+    });
+    into_c_string(result)
+}
+
+/// Calls `method`, one of the methods without params in `corepc_model_methods`, returning the
+/// result converted into its model type as JSON.
+///
+/// Free the returned string with [`corepc_string_free`], null on error.
+///
+/// # Safety
+///
+/// `client` must be returned by [`corepc_client_new`], `method` must be null or valid.
+#[no_mangle]
+pub unsafe extern "C" fn corepc_call_model(
+    client: *const CorepcClient,
+    method: *const c_char,
+) -> *mut c_char {
+    let result = guard(|| {
+        let client = client.as_ref().ok_or(Error::NullArg("client"))?;
+        let method = str_arg(method)?.ok_or(Error::NullArg("method"))?;
+        client.call_model(method)
+    });
+    into_c_string(result)
+}
+
+/// Returns the JSON array of the methods supported by [`corepc_call_model`].
+///
+/// Free the returned string with [`corepc_string_free`].
+#[no_mangle]
+pub extern "C" fn corepc_model_methods() -> *mut c_char {
+    into_c_string(Ok(serde_json::to_string(MODEL_METHODS).expect("strings serialize")))
+}
+
+/// Returns the message of the last error on this thread, null if none.
+///
+/// The string is owned by the library and valid until the next call on this thread.
+#[no_mangle]
+pub extern "C" fn corepc_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ref().map_or(ptr::null(), |msg| msg.as_ptr()))
+}
+
+/// Frees a string returned by this library.
+///
+/// # Safety
+///
+/// `s` must be null or returned by this library, and not used after this call.
+#[no_mangle]
+pub unsafe extern "C" fn corepc_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Returns the string `s` points to, `None` if null.
+///
+/// # Safety
+///
+/// `s` must be null or a valid NUL terminated string living for `'a`.
+unsafe fn str_arg<'a>(s: *const c_char) -> Result<Option<&'a str>, Error> {
+    if s.is_null() {
+        return Ok(None);
+    }
+    CStr::from_ptr(s).to_str().map(Some).map_err(|_| Error::InvalidUtf8)
+}
+
+/// Clears the last error of this thread and runs `f`, returning a panic in `f` as an error.
+///
+/// Unwinding out of an `extern "C"` function aborts the caller's process.
+fn guard<T>(f: impl FnOnce() -> Result<T, Error>) -> Result<T, Error> {
+    LAST_ERROR.with(|last| *last.borrow_mut() = None);
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|payload| Err(Error::Panic(panic_message(&*payload))))
+}
+
+/// Returns the message of a panic with `payload`.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match (payload.downcast_ref::<&str>(), payload.downcast_ref::<String>()) {
+        (Some(msg), _) => (*msg).to_owned(),
+        (None, Some(msg)) => msg.clone(),
+        (None, None) => "unknown panic".to_owned(),
+    }
+}
+
+/// Returns `result` as an owned C string, or sets the last error and returns null.
+fn into_c_string(result: Result<String, Error>) -> *mut c_char {
+    match result.and_then(|s| CString::new(s).map_err(|_| Error::InteriorNul)) {
+        Ok(s) => s.into_raw(),
+        Err(e) => set_last_error(e),
+    }
+}
+
+/// Sets the last error of this thread to `e`, returns null for convenience.
+fn set_last_error<T>(e: Error) -> *mut T {
+    // Error messages do not contain NUL bytes, but do not panic across the FFI boundary if so.
+    let msg = CString::new(e.to_string().replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(msg));
+    ptr::null_mut()
+}
+
+/// Error returned by the FFI functions, as a message by [`corepc_last_error`].
+#[derive(Debug)]
+pub enum Error {
+    /// A required argument is null.
+    NullArg(&'static str),
+    /// A string argument is not UTF-8.
+    InvalidUtf8,
+    /// A result contains a NUL byte.
+    InteriorNul,
+    /// The client returned an error.
+    Client(client_sync::Error),
+    /// The params or result are not valid JSON of the expected type.
+    Json(serde_json::Error),
+    /// The result could not be converted into the model type.
+    Model(String),
+    /// The method is not supported by [`corepc_call_model`].
+    UnsupportedMethod(String),
+    /// The node is older than the oldest version supported.
+    UnsupportedVersion(usize),
+    /// The library panicked, with the panic message.
+    Panic(String),
+}
+
+impl From<client_sync::Error> for Error {
+    fn from(e: client_sync::Error) -> Self { Self::Client(e) }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self { Self::Json(e) }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Error::*;
+
+        match *self {
+            NullArg(name) => write!(f, "argument `{}` is null", name),
+            InvalidUtf8 => write!(f, "string argument is not valid UTF-8"),
+            InteriorNul => write!(f, "result contains a NUL byte"),
+            Client(ref e) => write!(f, "client error: {}", e),
+            Json(ref e) => write!(f, "JSON error: {}", e),
+            Model(ref e) => write!(f, "failed to convert into the model type: {}", e),
+            UnsupportedMethod(ref m) => write!(f, "method `{}` has no model conversion", m),
+            UnsupportedVersion(v) => write!(f, "node version {} is not supported", v),
+            Panic(ref msg) => write!(f, "panicked: {}", msg),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        use Error::*;
+
+        match *self {
+            Client(ref e) => Some(e),
+            Json(ref e) => Some(e),
+            NullArg(_)
+            | InvalidUtf8
+            | InteriorNul
+            | Model(_)
+            | UnsupportedMethod(_)
+            | UnsupportedVersion(_)
+            | Panic(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH: &str = "0f9188f13cb7b2c71f2a335e3a4fc328bf5beb436012afca590b1a11466e2206";
+
+    #[test]
+    fn model_of_each_version() {
+        for major in MIN_MAJOR..=MAX_MAJOR {
+            let m = model(major, "getbestblockhash", Value::from(HASH)).unwrap();
+            assert_eq!(m, Value::from(HASH));
+
+            let m = model(major, "getrawmempool", serde_json::json!([HASH])).unwrap();
+            assert_eq!(m, serde_json::json!([HASH]));
+        }
+    }
+
+    #[test]
+    fn model_errors() {
+        assert!(matches!(
+            model(MAX_MAJOR, "getbestblockhash", Value::from("not a hash")),
+            Err(Error::Model(_))
+        ));
+        assert!(matches!(model(MAX_MAJOR, "getbestblockhash", Value::Null), Err(Error::Json(_))));
+        assert!(matches!(
+            model(MAX_MAJOR, "getblock", Value::Null),
+            Err(Error::UnsupportedMethod(_))
+        ));
+    }
+
+    #[test]
+    fn params() {
+        assert!(parse_params("").unwrap().is_empty());
+        assert_eq!(parse_params(r#"[1, "a"]"#).unwrap(), vec![Value::from(1), Value::from("a")]);
+        assert!(parse_params("{}").is_err());
+    }
+
+    #[test]
+    fn last_error() {
+        // SAFETY: All arguments are null.
+        let client =
+            unsafe { corepc_client_new(ptr::null(), ptr::null(), ptr::null(), ptr::null()) };
+        assert!(client.is_null());
+        // SAFETY: The error is set on this thread and read before the next call.
+        let msg = unsafe { CStr::from_ptr(corepc_last_error()) };
+        assert_eq!(msg.to_str().unwrap(), "argument `url` is null");
+    }
+
+    #[test]
+    fn last_error_cleared() {
+        // SAFETY: All arguments are null.
+        let client =
+            unsafe { corepc_client_new(ptr::null(), ptr::null(), ptr::null(), ptr::null()) };
+        assert!(client.is_null());
+        assert!(!corepc_last_error().is_null());
+
+        let url = CString::new("http://localhost:18443").unwrap();
+        // SAFETY: `url` is a valid string.
+        let client =
+            unsafe { corepc_client_new(url.as_ptr(), ptr::null(), ptr::null(), ptr::null()) };
+        assert!(!client.is_null());
+        assert!(corepc_last_error().is_null());
+        // SAFETY: Returned by `corepc_client_new`, freed once.
+        unsafe { corepc_client_free(client) };
+    }
+
+    #[test]
+    fn panic_is_error() {
+        let result = guard(|| -> Result<(), Error> { panic!("boom") });
+        assert_eq!(result.unwrap_err().to_string(), "panicked: boom");
+    }
+
+    #[test]
+    fn model_methods() {
+        let methods = corepc_model_methods();
+        // SAFETY: Returned by the library, freed once.
+        let json = unsafe { CStr::from_ptr(methods) }.to_str().unwrap().to_owned();
+        unsafe { corepc_string_free(methods) };
+        assert_eq!(serde_json::from_str::<Vec<String>>(&json).unwrap(), MODEL_METHODS);
+    }
+}
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Harness for testing data created by one version of Core with another version.
+//!
+//! Nodes are started from releases downloaded at runtime with [`bitcoind::download::fetch`], on
+//! a persistent data directory that outlives the node. Calls use untyped JSON because the client
+//! only matches the version selected by the version feature.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use bitcoind::serde_json::{json, Value};
+use bitcoind::{Conf, P2P};
+
+use crate::BitcoinD;
+
+/// Versions of Core able to create descriptor wallets, oldest first.
+pub const DESCRIPTOR_WALLET_VERSIONS: &[&str] =
+    &["22.1", "23.2", "24.2", "25.2", "26.2", "27.2", "28.2", "29.0", "30.2", "31.0"];
+
+/// All supported versions of Core, oldest first.
+pub const VERSIONS: &[&str] = &[
+    "0.17.2", "0.18.1", "0.19.1", "0.20.2", "0.21.2", "22.1", "23.2", "24.2", "25.2", "26.2",
+    "27.2", "28.2", "29.0", "30.2", "31.0",
+];
+
+/// Name of the wallet created by [`create_wallet`].
+pub const WALLET: &str = "compat";
+
+/// Returns the `(from, to)` version pairs to test upgrading between.
+///
+/// Uses the comma separated versions in `BITCOIND_COMPAT_VERSIONS` if set, otherwise `versions`.
+/// Each version is paired with the next, and the first with the last.
+pub fn upgrade_pairs(versions: &[&str]) -> Vec<(String, String)> {
+    let versions = match std::env::var("BITCOIND_COMPAT_VERSIONS") {
+        Ok(list) => list.split(',').map(|v| v.trim().to_owned()).collect::<Vec<_>>(),
+        Err(_) => versions.iter().map(|v| v.to_string()).collect(),
+    };
+    let mut pairs = versions.windows(2).map(|w| (w[0].clone(), w[1].clone())).collect::<Vec<_>>();
+    if versions.len() > 2 {
+        pairs.push((versions[0].clone(), versions[versions.len() - 1].clone()));
+    }
+    pairs
+}
+
+/// Returns the `(from, to)` version pairs to test downgrading between, see [`upgrade_pairs`].
+pub fn downgrade_pairs(versions: &[&str]) -> Vec<(String, String)> {
+    upgrade_pairs(versions).into_iter().map(|(from, to)| (to, from)).collect()
+}
+
+/// Starts Core `version` on the persistent data directory `datadir`.
+///
+/// Returns the error if the node fails to start e.g., because `datadir` was written by a newer
+/// version.
+pub fn start(version: &str, datadir: &Path) -> bitcoind::anyhow::Result<BitcoinD> {
+    let exe = bitcoind::download::fetch(version)?;
+    let mut conf = Conf::default();
+    conf.wallet = None;
+    conf.p2p = P2P::No;
+    conf.staticdir = Some(datadir.to_path_buf());
+    // A node that fails to start on this datadir will not start on a retry.
+    conf.attempts = 1;
+    BitcoinD::with_conf(exe, &conf)
+}
+
+/// Calls `method` on `node`, panics on error.
+pub fn call(node: &BitcoinD, method: &str, args: &[Value]) -> Value {
+    node.client.call(method, args).unwrap_or_else(|e| panic!("{} failed: {}", method, e))
+}
+
+/// Creates the descriptor wallet [`WALLET`] and mines 101 blocks to it.
+pub fn create_wallet(node: &BitcoinD) {
+    // Args: name, disable_private_keys, blank, passphrase, avoid_reuse, descriptors.
+    let args = [json!(WALLET), json!(false), json!(false), json!(""), json!(false), json!(true)];
+    call(node, "createwallet", &args);
+    let address = call(node, "getnewaddress", &[]);
+    call(node, "generatetoaddress", &[json!(101), address]);
+}
+
+/// The parts of a wallet that must survive moving it to another version.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WalletState {
+    /// The trusted balance.
+    pub balance: Value,
+    /// The descriptors in the wallet, private keys are not included.
+    pub descriptors: BTreeSet<String>,
+    /// The number of unspent outputs.
+    pub unspent: usize,
+}
+
+impl WalletState {
+    /// Returns the state of the only loaded wallet on `node`.
+    pub fn of(node: &BitcoinD) -> Self {
+        let list = call(node, "listdescriptors", &[]);
+        let descriptors = list["descriptors"]
+            .as_array()
+            .expect("descriptors")
+            .iter()
+            .map(|d| d["desc"].as_str().expect("desc").to_owned())
+            .collect();
+        let unspent = call(node, "listunspent", &[]).as_array().expect("listunspent").len();
+        WalletState { balance: call(node, "getbalance", &[]), descriptors, unspent }
+    }
+}
+
+/// Mines `blocks` blocks on `node`, no wallet is needed.
+pub fn mine(node: &BitcoinD, blocks: u64) {
+    // A regtest P2WPKH address, every supported version can mine to it.
+    let address = "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080";
+    call(node, "generatetoaddress", &[json!(blocks), json!(address)]);
+}
+
+/// The chain tip of a node.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChainTip {
+    /// The height of the tip.
+    pub height: u64,
+    /// The hash of the tip.
+    pub hash: String,
+}
+
+impl ChainTip {
+    /// Returns the chain tip of `node`.
+    pub fn of(node: &BitcoinD) -> Self {
+        let info = call(node, "getblockchaininfo", &[]);
+        ChainTip {
+            height: info["blocks"].as_u64().expect("blocks"),
+            hash: info["bestblockhash"].as_str().expect("bestblockhash").to_owned(),
+        }
+    }
+}
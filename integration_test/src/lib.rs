@@ -1,5 +1,8 @@
 //! Provides a macro that implements the tests.
 
+#[cfg(feature = "compat")]
+pub mod compat;
+
 use std::path::PathBuf;
 
 use bitcoin::bip32::{Fingerprint, Xpriv, Xpub};
@@ -75,10 +78,7 @@ impl BitcoinDExt for BitcoinD {
         BitcoinD::with_conf(exe, &conf).expect("failed to create node")
     }
 
-    fn fund_wallet(&self) {
-        let address = self.client.new_address().expect("failed to get new address");
-        self.client.generate_to_address(101, &address).expect("failed to generate to address");
-    }
+    fn fund_wallet(&self) { self.fund_node_wallet().expect("failed to fund wallet"); }
 
     fn mine_a_block(&self) {
         let address = self.client.new_address().expect("failed to get new address");
@@ -89,13 +89,8 @@ impl BitcoinDExt for BitcoinD {
         const MILLION_SATS: bitcoin::Amount = bitcoin::Amount::from_sat(1000000);
 
         let address = self.client.new_address().expect("failed to get new address");
-
-        let txid = self
-            .client
-            .send_to_address(&address, MILLION_SATS)
-            .expect("failed to send to address")
-            .txid()
-            .expect("failed to convert hex to txid");
+        let txid =
+            self.fund_address_unconfirmed(&address, MILLION_SATS).expect("failed to fund address");
         (address, txid)
     }
 
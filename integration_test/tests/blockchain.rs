@@ -381,6 +381,32 @@ fn blockchain__get_chain_tx_stats__modelled() {
     assert!(chain_tx_stats.tx_rate.unwrap() > 0.0);
 }
 
+#[test]
+fn blockchain__tx_rate_and_windows() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let (_address, _tx) = node.create_mined_transaction();
+
+    assert!(node.client.tx_rate(10).expect("tx_rate") >= 0.0);
+
+    let tip = node.client.best_block_hash().expect("best_block_hash");
+    let json: GetChainTxStats =
+        node.client.get_chain_tx_stats_window(10, tip).expect("getchaintxstats");
+    let model: Result<mtype::GetChainTxStats, GetChainTxStatsError> = json.into_model();
+    assert_eq!(model.unwrap().window_final_block_hash, tip);
+
+    let windows = node
+        .client
+        .tx_windows(50)
+        .expect("tx_windows")
+        .collect::<Result<Vec<_>, _>>()
+        .expect("getchaintxstats");
+    assert_eq!(windows[0].final_block_hash, tip);
+    // Every block from height 2 is in a window.
+    let blocks: u64 = windows.iter().map(|window| window.block_count).sum();
+    assert_eq!(blocks, windows[0].final_block_height - 1);
+}
+
 #[test]
 #[cfg(feature = "v30_and_below")]
 #[cfg(not(feature = "v22_and_below"))]
@@ -595,6 +621,10 @@ fn blockchain__get_tx_out_proof() {
     let txid = tx.compute_txid();
 
     let _ = node.client.get_tx_out_proof(&[txid]).expect("gettxoutproof");
+
+    let block_hash = node.client.best_block_hash().expect("getbestblockhash");
+    let proof = node.client.tx_out_proof(&[txid], Some(block_hash)).expect("gettxoutproof");
+    assert_eq!(proof.header.block_hash(), block_hash);
 }
 
 #[test]
@@ -632,6 +662,27 @@ fn blockchain__get_tx_spending_prevout__modelled() {
     assert_eq!(spending_prevout.0[0].outpoint.vout, 0);
 }
 
+#[test]
+#[cfg(not(feature = "v23_and_below"))]
+fn blockchain__is_spent_in_mempool() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let (_address, txid) = node.create_mempool_transaction();
+    let tx = node.client.get_raw_transaction(txid).unwrap().transaction().unwrap();
+    let spent = tx.input[0].previous_output;
+    let unspent = bitcoin::OutPoint { txid, vout: 0 };
+
+    let spending = node.client.is_spent_in_mempool(spent).expect("gettxspendingprevout");
+    assert_eq!(spending, Some(txid));
+    let spending = node.client.is_spent_in_mempool(unspent).expect("gettxspendingprevout");
+    assert_eq!(spending, None);
+
+    let all = node.client.spent_in_mempool(&[spent, unspent]).expect("gettxspendingprevout");
+    assert_eq!(all.len(), 1);
+    assert_eq!(all.get(&spent), Some(&txid));
+}
+
 #[test]
 #[cfg(not(feature = "v25_and_below"))]
 fn blockchain__import_mempool() {
@@ -756,7 +807,7 @@ fn blockchain__verify_tx_out_proof__modelled() {
     let (_address, tx) = node.create_mined_transaction();
     let txid = tx.compute_txid();
 
-    let proof = node.client.get_tx_out_proof(&[txid]).expect("gettxoutproof");
+    let proof = node.client.tx_out_proof(&[txid], None).expect("gettxoutproof");
 
     let json: VerifyTxOutProof = node.client.verify_tx_out_proof(&proof).expect("verifytxoutproof");
     let model: Result<mtype::VerifyTxOutProof, hex::HexToArrayError> = json.into_model();
@@ -822,6 +873,48 @@ fn blockchain__wait_for_new_block__modelled() {
     assert_ne!(block.hash, prev_hash);
 }
 
+#[test]
+fn blockchain__wait_for_new_block__cancelled() {
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    let handle = node.client.call_handle();
+
+    // No block is mined so the call only returns once it is cancelled.
+    let result = std::thread::scope(|s| {
+        let call =
+            s.spawn(|| node.client.call::<bitcoind::serde_json::Value>("waitfornewblock", &[]));
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        handle.cancel();
+        call.join().expect("waitfornewblock thread panicked")
+    });
+    assert!(result.is_err());
+
+    // The client is still usable after cancelling.
+    node.client.get_block_count().expect("getblockcount");
+}
+
+#[test]
+fn blockchain__get_block_count__middleware() {
+    use bitcoind::client::client_sync::middleware::{Metrics, Retry};
+    use bitcoind::client::client_sync::Auth;
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    let metrics = Metrics::new();
+    let client = bitcoind::Client::builder(&node.rpc_url())
+        .auth(Auth::CookieFile(node.params.cookie_file.clone()))
+        .middleware(metrics.clone())
+        .middleware(Retry::default())
+        .build()
+        .expect("build client");
+
+    let count = client.get_block_count().expect("getblockcount");
+    assert_eq!(count.0, 0);
+    assert!(client.call::<bitcoind::serde_json::Value>("notamethod", &[]).is_err());
+
+    let snapshot = metrics.snapshot();
+    assert_eq!((snapshot["getblockcount"].calls, snapshot["getblockcount"].errors), (1, 0));
+    assert_eq!((snapshot["notamethod"].calls, snapshot["notamethod"].errors), (1, 1));
+}
+
 /// Create and broadcast a child transaction spending vout 0 of the given parent mempool txid.
 /// Returns the child's txid.
 fn create_child_spending_parent(node: &BitcoinD, parent_txid: bitcoin::Txid) -> bitcoin::Txid {
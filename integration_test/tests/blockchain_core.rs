@@ -197,10 +197,14 @@ fn verify_tx_out_proof_round_trips_txid() {
     let (_, tx) = node.create_mined_transaction();
     let txid = tx.compute_txid();
 
-    let proof = node.client.get_tx_out_proof(&[txid]).unwrap();
+    let proof = node.client.tx_out_proof(&[txid], None).unwrap();
     let json: VerifyTxOutProof = node.client.verify_tx_out_proof(&proof).unwrap();
 
     assert_eq!(json.0[0], txid.to_string());
+    let mut matches = vec![];
+    let mut indexes = vec![];
+    proof.txn.extract_matches(&mut matches, &mut indexes).unwrap();
+    assert_eq!(matches, vec![txid]);
 }
 
 #[test]
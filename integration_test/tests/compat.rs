@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Tests loading data created by one version of Core with another version.
+//!
+//! Downloads every version tested, run with `cargo test --features=latest,compat --test compat`.
+//! Set `BITCOIND_COMPAT_VERSIONS` (e.g. "26.2,31.0") to test a subset.
+
+#![cfg(feature = "compat")]
+#![allow(non_snake_case)] // Test names intentionally use double underscore.
+
+use bitcoind::serde_json::json;
+use integration_test::compat::{
+    self, ChainTip, WalletState, DESCRIPTOR_WALLET_VERSIONS, VERSIONS, WALLET,
+};
+
+#[test]
+fn compat__wallet_upgrade() {
+    for (from, to) in compat::upgrade_pairs(DESCRIPTOR_WALLET_VERSIONS) {
+        let datadir = bitcoind::tempfile::tempdir().expect("failed to create datadir");
+
+        let node = compat::start(&from, datadir.path()).expect("failed to start node");
+        compat::create_wallet(&node);
+        let before = WalletState::of(&node);
+        drop(node); // Shuts the node down gracefully, the datadir is persistent.
+
+        let node = compat::start(&to, datadir.path())
+            .unwrap_or_else(|e| panic!("{} cannot start on a {} datadir: {}", to, from, e));
+        compat::call(&node, "loadwallet", &[json!(WALLET)]);
+        let after = WalletState::of(&node);
+
+        assert_eq!(after, before, "wallet created by {} changed when loaded by {}", from, to);
+    }
+}
+
+#[test]
+fn compat__chainstate_upgrade() {
+    for (from, to) in compat::upgrade_pairs(VERSIONS) {
+        let datadir = bitcoind::tempfile::tempdir().expect("failed to create datadir");
+
+        let node = compat::start(&from, datadir.path()).expect("failed to start node");
+        compat::mine(&node, 200);
+        let before = ChainTip::of(&node);
+        drop(node);
+
+        let node = compat::start(&to, datadir.path())
+            .unwrap_or_else(|e| panic!("{} cannot start on a {} datadir: {:#}", to, from, e));
+        assert_eq!(ChainTip::of(&node), before, "chain synced by {} changed in {}", from, to);
+    }
+}
+
+#[test]
+fn compat__chainstate_downgrade() {
+    for (from, to) in compat::downgrade_pairs(VERSIONS) {
+        let datadir = bitcoind::tempfile::tempdir().expect("failed to create datadir");
+
+        let node = compat::start(&from, datadir.path()).expect("failed to start node");
+        compat::mine(&node, 200);
+        let before = ChainTip::of(&node);
+        drop(node);
+
+        // Core either loads the newer chainstate or refuses it and exits with an error.
+        match compat::start(&to, datadir.path()) {
+            Ok(node) => assert_eq!(
+                ChainTip::of(&node),
+                before,
+                "chain synced by {} changed in {}",
+                from,
+                to
+            ),
+            Err(e) => match e.downcast_ref::<bitcoind::Error>() {
+                Some(bitcoind::Error::EarlyExit(..)) =>
+                    eprintln!("{} refuses a {} datadir: {:#}", to, from, e),
+                _ => panic!("{} failed to start on a {} datadir: {:#}", to, from, e),
+            },
+        }
+    }
+}
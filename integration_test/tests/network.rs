@@ -26,6 +26,31 @@ fn network__add_node() {
     let _: () = node.client.add_node(dummy_peer, AddNodeCommand::Remove).expect("addnode remove");
 }
 
+#[test]
+fn network__dial() {
+    use std::time::Duration;
+
+    use bitcoind::client::client_sync::DialError;
+
+    let exe = bitcoind::exe_path().expect("failed to get bitcoind executable");
+    let mut conf = bitcoind::Conf::default();
+    conf.p2p = bitcoind::P2P::Yes;
+    let listener = BitcoinD::with_conf(&exe, &conf).expect("listening node");
+    let address = listener.params.p2p_socket.expect("p2p socket").to_string();
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    let peer: PeerInfo = node.client.dial(&address, Duration::from_secs(10)).expect("dial");
+    assert_eq!(peer.address, address);
+    assert!(!peer.inbound);
+
+    let err = node.client.dial(&address, Duration::from_secs(10)).unwrap_err();
+    assert!(matches!(err, DialError::AlreadyConnected), "{}", err);
+
+    // Nothing listens on the discard port.
+    let err = node.client.dial("127.0.0.1:9", Duration::from_secs(1)).unwrap_err();
+    assert!(matches!(err, DialError::Timeout(_)), "{}", err);
+}
+
 #[test]
 fn network__clear_banned() {
     let node = BitcoinD::with_wallet(Wallet::None, &[]);
@@ -89,6 +114,16 @@ fn network__get_network_info__modelled() {
     node.client.check_expected_server_version().expect("unexpected version");
 }
 
+#[test]
+fn network__capabilities() {
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    let capabilities = node.client.capabilities().expect("capabilities");
+
+    assert_eq!(capabilities.supports_descriptor_wallets, cfg!(not(feature = "v20_and_below")));
+    assert_eq!(capabilities.supports_sendall, cfg!(not(feature = "v23_and_below")));
+    assert_eq!(capabilities.warnings_is_array, cfg!(not(feature = "v27_and_below")));
+}
+
 #[test]
 #[cfg(not(feature = "v17"))]
 fn network__get_node_addresses() {
@@ -158,6 +193,22 @@ fn network__list_banned() {
     assert!(json.0.iter().all(|item| item.address != dummy_subnet));
 }
 
+#[test]
+fn network__list_banned__modelled() {
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    let dummy_subnet = "192.0.2.6/32";
+
+    node.client.ban(dummy_subnet, std::time::Duration::from_secs(3600)).expect("ban");
+    let json: ListBanned = node.client.list_banned().expect("listbanned");
+    let model: bitcoind::mtype::ListBanned = json.into_model();
+    let banned = model.0.iter().find(|item| item.subnet.0 == dummy_subnet).expect("banned");
+
+    assert_eq!(banned.ban_duration, 3600);
+    assert_eq!(banned.banned_until, banned.ban_created + 3600);
+
+    node.client.set_ban(dummy_subnet, SetBanCommand::Remove).expect("setban remove");
+}
+
 #[test]
 fn network__ping() {
     let node = BitcoinD::with_wallet(Wallet::None, &[]);
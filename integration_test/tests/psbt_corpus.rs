@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Tests decoding and analyzing the PSBTs in `tests/psbt-corpus` with all supported versions.
+//!
+//! The corpus is synthetic, see `tests/psbt-corpus/README.md`.
+
+#![allow(non_snake_case)] // Test names intentionally use double underscore.
+#![allow(unused_imports)] // Because of feature gated tests.
+
+use std::fs;
+use std::path::PathBuf;
+
+use bitcoind::mtype;
+use bitcoind::vtype::*; // All the version specific types.
+use integration_test::{BitcoinD, BitcoinDExt as _, Wallet};
+
+/// Returns the name and the base64 encoded PSBT of each file in the corpus.
+fn corpus() -> Vec<(String, String)> {
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests").join("psbt-corpus");
+    let mut corpus = fs::read_dir(&dir)
+        .expect("failed to read corpus dir")
+        .map(|entry| entry.expect("failed to read corpus entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "psbt"))
+        .map(|path| {
+            let name = path.file_stem().expect("file name").to_string_lossy().into_owned();
+            let psbt = fs::read_to_string(&path).expect("failed to read psbt");
+            (name, psbt.trim().to_owned())
+        })
+        .collect::<Vec<_>>();
+    corpus.sort();
+    assert!(!corpus.is_empty(), "PSBT corpus is empty");
+    corpus
+}
+
+#[test]
+fn psbt_corpus__decode_psbt__modelled() {
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    for (name, psbt) in corpus() {
+        let json: DecodePsbt =
+            node.client.decode_psbt(&psbt).unwrap_or_else(|e| panic!("{}: {}", name, e));
+        let model: Result<mtype::DecodePsbt, DecodePsbtError> = json.into_model();
+        let decoded = model.unwrap_or_else(|e| panic!("{}: {}", name, e));
+
+        let psbt = psbt.parse::<bitcoin::Psbt>().unwrap_or_else(|e| panic!("{}: {}", name, e));
+        assert_eq!(decoded.psbt.unsigned_tx, psbt.unsigned_tx, "{}", name);
+        assert_eq!(decoded.psbt.inputs.len(), psbt.inputs.len(), "{}", name);
+    }
+}
+
+#[test]
+#[cfg(not(feature = "v17"))] // analyzepsbt was added in v0.18.
+fn psbt_corpus__analyze_psbt__modelled() {
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    for (name, psbt) in corpus() {
+        let psbt = psbt.parse::<bitcoin::Psbt>().unwrap_or_else(|e| panic!("{}: {}", name, e));
+        let json: AnalyzePsbt =
+            node.client.analyze_psbt(&psbt).unwrap_or_else(|e| panic!("{}: {}", name, e));
+        let model: Result<mtype::AnalyzePsbt, AnalyzePsbtError> = json.into_model();
+        let analyzed = model.unwrap_or_else(|e| panic!("{}: {}", name, e));
+        assert_eq!(analyzed.inputs.len(), psbt.inputs.len(), "{}", name);
+    }
+}
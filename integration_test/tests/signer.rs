@@ -40,33 +40,11 @@ fn signer__enumerate_signers() {
 #[cfg(unix)]
 #[cfg(not(feature = "v21_and_below"))]
 fn signer__wallet_display_address__modelled() {
-    use std::os::unix::fs::PermissionsExt;
-
-    // MOCK address and xpub from Bitcoin Core's `wallet_signer.py`.
-    let address = "bcrt1qm90ugl4d48jv8n6e5t9ln6t9zlpm5th68x4f8g";
-    let xpub = "tpubD6NzVbkrYhZ4WaWSyoBvQwbpLkojyoTZPRsgXELWz3Popb3qkjcJyJUGLnL4qHHoQvao8ESaAstxYSnhyswJ76uZPStJRJCTKvosUCJZL5B";
-
-    // Mock signer script that handles `enumerate`, `getdescriptors`, and
-    // `displayaddress` sub-commands — the three commands bitcoind invokes when
-    // creating an external-signer wallet and then displaying an address.
-    let script_path = integration_test::random_tmp_file();
-    let script_body = format!(
-        r#"#!/bin/sh
-CMD=""
-while [ $# -gt 0 ]; do
-    case "$1" in enumerate|getdescriptors|displayaddress) CMD="$1" ;; esac
-    shift
-done
-case "$CMD" in
-    enumerate)     echo '[{{"fingerprint":"00000001","type":"trezor","model":"trezor_t"}}]' ;;
-    getdescriptors) echo '{{"receive":["wpkh([00000001/84h/1h/0h]{xpub}/0/*)"],"internal":[]}}' ;;
-    displayaddress) echo '{{"address":"{address}"}}' ;;
-    *)             echo '{{"error":"unknown command"}}'; exit 1 ;;
-esac
-"#
-    );
-    std::fs::write(&script_path, script_body).expect("write signer script");
-    std::fs::set_permissions(&script_path, std::fs::Permissions::from_mode(0o755)).expect("chmod");
+    // The bundled mock signer handles the `enumerate`, `getdescriptors` and `displayaddress`
+    // commands bitcoind invokes when creating an external signer wallet and displaying an address.
+    let dir = bitcoind::tempfile::tempdir().expect("tempdir");
+    let script_path = bitcoind::signer::install_mock(dir.path()).expect("install mock signer");
+    let address = bitcoind::signer::MOCK_ADDRESS;
 
     let signer_arg = format!("-signer={}", script_path.to_str().unwrap());
     let node = BitcoinD::with_wallet(Wallet::None, &[&signer_arg]);
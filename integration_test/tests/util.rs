@@ -128,7 +128,7 @@ fn util__sign_message_with_priv_key__modelled() {
 
     // Sign the message with the private key
     let json: SignMessageWithPrivKey =
-        node.client.sign_message_with_privkey(&privkey, message).expect("signmessagewithprivkey");
+        node.client.sign_message_with_priv_key(&privkey, message).expect("signmessagewithprivkey");
     let model: Result<mtype::SignMessageWithPrivKey, sign_message::MessageSignatureError> =
         json.into_model();
     let sig = model.unwrap();
@@ -654,6 +654,69 @@ fn wallet__list_transactions__modelled() {
     assert_eq!(first_tx.txid.unwrap().to_string().len(), 64);
 }
 
+#[test]
+fn wallet__transactions() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+
+    node.fund_wallet();
+    let addr = node.client.new_address().expect("newaddress");
+    let txid = node
+        .client
+        .send_to_address(&addr, Amount::from_sat(5_000))
+        .expect("sendtoaddress")
+        .txid()
+        .expect("txid");
+
+    let all: ListTransactions = node
+        .client
+        .call("listtransactions", &["*".into(), 1_000.into()])
+        .expect("listtransactions");
+    let paged =
+        node.client.transactions(None, 7).collect::<Result<Vec<_>, _>>().expect("transactions");
+    assert_eq!(paged.len(), all.0.len());
+    assert_eq!(paged[0].txid, Some(txid));
+}
+
+#[test]
+fn wallet__send_idempotent() {
+    use bitcoind::client::client_sync::Error;
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let addr = node.client.new_address().expect("newaddress");
+    let amount = Amount::from_sat(5_000);
+
+    let txid = node.client.send_idempotent("payout-1", &addr, amount).expect("send");
+    let retried = node.client.send_idempotent("payout-1", &addr, amount).expect("retry");
+    assert_eq!(retried, txid);
+
+    let other = node.client.send_idempotent("payout-2", &addr, amount).expect("send");
+    assert_ne!(other, txid);
+
+    let err = node.client.send_idempotent("payout-1", &addr, amount * 2).unwrap_err();
+    assert!(matches!(err, Error::RequestIdReused { txid: reused, .. } if reused == txid));
+}
+
+#[test]
+fn wallet__detect_address_reuse() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let addr = node.client.new_address().expect("newaddress");
+    let amount = Amount::from_sat(5_000);
+    for _ in 0..2 {
+        node.client.send_to_address(&addr, amount).expect("sendtoaddress");
+    }
+
+    let reuse = node.client.detect_address_reuse().expect("detect_address_reuse");
+    let reused = reuse
+        .addresses
+        .iter()
+        .find(|reused| reused.address == *addr.as_unchecked())
+        .expect("reused address");
+    assert_eq!(reused.received.len(), 2);
+    assert_eq!(reused.unspent.len(), 2);
+}
+
 #[test]
 #[cfg(feature = "v29_and_below")]
 fn wallet__import_multi() {
@@ -1001,6 +1064,63 @@ fn wallet__send_all__modelled() {
     model.unwrap();
 }
 
+#[test]
+#[cfg(not(feature = "v23_and_below"))]
+fn wallet__sweep_to() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let address = node.client.new_address().expect("failed to create new address");
+    let fee_rate = FeeRate::from_sat_per_vb(2).expect("2 sat/vb is valid");
+
+    // A dry run only returns the transaction.
+    let psbt = node.client.sweep_to_psbt(&address, fee_rate).expect("sendall");
+    assert_eq!(psbt.unsigned_tx.output.len(), 1);
+    assert_eq!(psbt.unsigned_tx.output[0].script_pubkey, address.script_pubkey());
+    assert!(psbt.inputs.iter().all(|input| input.final_script_witness.is_none()
+        && input.final_script_sig.is_none()
+        && input.partial_sigs.is_empty()));
+    assert!(node.client.get_raw_mempool().expect("getrawmempool").0.is_empty());
+
+    let json = node.client.sweep_to(&address, fee_rate).expect("sendall");
+    let model: Result<mtype::SendAll, SendAllError> = json.into_model();
+    let txid = model.unwrap().txid.expect("sweep was broadcast");
+    assert!(node.client.get_raw_mempool().expect("getrawmempool").0.contains(&txid.to_string()));
+}
+
+#[test]
+#[cfg(not(feature = "v23_and_below"))]
+fn wallet__sweep_descriptors_to() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let address = node.client.new_address().expect("failed to create new address");
+    let fee_rate = FeeRate::from_sat_per_vb(2).expect("2 sat/vb is valid");
+
+    let unspent = node.client.list_unspent().expect("listunspent").0;
+    let parents = unspent[0].parent_descriptors.clone().expect("descriptor wallet");
+    let descriptor = parents[0].as_str();
+    let from_descriptor = unspent
+        .iter()
+        .filter(|utxo| utxo.parent_descriptors.as_ref().is_some_and(|p| p.contains(&parents[0])))
+        .count();
+
+    // A dry run only returns the transaction.
+    let psbt =
+        node.client.sweep_descriptors_to_psbt(&[descriptor], &address, fee_rate).expect("sendall");
+    assert_eq!(psbt.unsigned_tx.input.len(), from_descriptor);
+    assert_eq!(psbt.unsigned_tx.output.len(), 1);
+    assert_eq!(psbt.unsigned_tx.output[0].script_pubkey, address.script_pubkey());
+    assert!(node.client.get_raw_mempool().expect("getrawmempool").0.is_empty());
+
+    let json =
+        node.client.sweep_descriptors_to(&[descriptor], &address, fee_rate).expect("sendall");
+    let model: Result<mtype::SendAll, SendAllError> = json.into_model();
+    let txid = model.unwrap().txid.expect("sweep was broadcast");
+    assert!(node.client.get_raw_mempool().expect("getrawmempool").0.contains(&txid.to_string()));
+
+    let unknown = "wpkh(02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9)";
+    assert!(node.client.sweep_descriptors_to(&[unknown], &address, fee_rate).is_err());
+}
+
 #[test]
 fn wallet__send_to_address__modelled() {
     let node = BitcoinD::with_wallet(Wallet::Default, &[]);
@@ -1165,6 +1285,24 @@ fn wallet__wallet_passphrase() {
     let _: () = node.client.wallet_passphrase("passphrase", timeout).expect("walletpassphrase");
 }
 
+#[test]
+fn wallet__unlock_wallet() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.client.encrypt_wallet("passphrase").expect("encryptwallet");
+
+    let unlocked_until = || node.client.get_wallet_info().expect("getwalletinfo").unlocked_until;
+    {
+        let mut guard = node
+            .client
+            .unlock_wallet("passphrase", std::time::Duration::from_secs(60))
+            .expect("unlock_wallet");
+        assert!(unlocked_until().unwrap() > 0);
+        guard.refresh().expect("refresh");
+    }
+    // Locked when the guard is dropped.
+    assert_eq!(unlocked_until(), Some(0));
+}
+
 #[test]
 fn wallet__wallet_passphrase_change() {
     let node = BitcoinD::with_wallet(Wallet::Default, &[]);
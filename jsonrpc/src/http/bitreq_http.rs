@@ -5,13 +5,10 @@
 
 #[cfg(jsonrpc_fuzz)]
 use std::io::{self, Read, Write};
-#[cfg(jsonrpc_fuzz)]
-use std::sync::Mutex;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
-use std::{error, fmt};
-
-use base64::engine::general_purpose::STANDARD as BASE64;
-use base64::Engine;
+use std::{error, fmt, fs};
 
 use crate::client::Transport;
 use crate::{Request, Response};
@@ -22,6 +19,8 @@ const DEFAULT_PORT: u16 = 8332; // the default RPC port for bitcoind.
 const DEFAULT_TIMEOUT_SECONDS: u64 = 15;
 #[cfg(jsonrpc_fuzz)]
 const DEFAULT_TIMEOUT_SECONDS: u64 = 1;
+/// The number of idle connections to the server kept open for reuse, one per concurrent request.
+const IDLE_CONNECTIONS: usize = 4;
 
 /// An HTTP transport that uses [`bitreq`] and is useful for running a bitcoind RPC client.
 #[derive(Clone, Debug)]
@@ -31,7 +30,20 @@ pub struct BitreqHttpTransport {
     /// Timeout only supports second granularity.
     timeout: Duration,
     /// The value of the `Authorization` HTTP header, i.e., a base64 encoding of 'user:password'.
-    basic_auth: Option<String>,
+    ///
+    /// Shared with the clones of the transport, replaced when the cookie file is read again.
+    basic_auth: Arc<Mutex<Option<String>>>,
+    /// The cookie file to read the credentials from again if the server rejects them.
+    cookie_file: Option<PathBuf>,
+    /// The value of the `User-Agent` HTTP header, not sent if `None`.
+    user_agent: Option<String>,
+    /// Additional HTTP headers sent with every request.
+    headers: Vec<(String, String)>,
+    /// Used to abort requests in flight.
+    cancel: CancelHandle,
+    /// Keeps connections to the server open between requests, shared with the clones of the
+    /// transport. Each request opens a new connection if `None`.
+    client: Option<bitreq::Client>,
 }
 
 impl Default for BitreqHttpTransport {
@@ -39,7 +51,24 @@ impl Default for BitreqHttpTransport {
         BitreqHttpTransport {
             url: format!("{}:{}", DEFAULT_URL, DEFAULT_PORT),
             timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECONDS),
-            basic_auth: None,
+            basic_auth: Arc::new(Mutex::new(None)),
+            cookie_file: None,
+            user_agent: None,
+            headers: Vec::new(),
+            cancel: CancelHandle::default(),
+            client: Some(bitreq::Client::new(IDLE_CONNECTIONS)),
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for BitreqHttpTransport {
+    fn drop(&mut self) {
+        // Only the last clone wipes the credentials.
+        if let Some(auth) = Arc::get_mut(&mut self.basic_auth) {
+            if let Some(ref mut auth) = *auth.get_mut().expect("poisoned lock") {
+                super::wipe(auth);
+            }
         }
     }
 }
@@ -51,6 +80,9 @@ impl BitreqHttpTransport {
     /// Returns a builder for [`BitreqHttpTransport`].
     pub fn builder() -> Builder { Builder::new() }
 
+    /// Returns a handle that can be used to abort the requests in flight on this transport.
+    pub fn cancel_handle(&self) -> CancelHandle { self.cancel.clone() }
+
     /// Returns the timeout in whole seconds, rounding positive sub-second values up to one.
     fn timeout_secs(&self) -> u64 {
         let secs = self.timeout.as_secs();
@@ -61,26 +93,75 @@ impl BitreqHttpTransport {
         }
     }
 
+    /// Sends `req`, reading the cookie file again and retrying once if the credentials are
+    /// rejected e.g., because the node restarted and wrote a new cookie.
     fn request<R>(&self, req: impl serde::Serialize) -> Result<R, Error>
     where
         R: for<'a> serde::de::Deserialize<'a>,
     {
-        let timeout_secs = self.timeout_secs();
+        match self.send(&req) {
+            Err(Error::Http(ref e)) if e.status_code == 401 && self.reload_cookie() =>
+                self.send(&req),
+            res => res,
+        }
+    }
 
-        let req = match &self.basic_auth {
-            Some(auth) => bitreq::Request::new(bitreq::Method::Post, &self.url)
-                .with_timeout(timeout_secs)
-                .with_header("Authorization", auth)
-                .with_json(&req)?,
-            None => bitreq::Request::new(bitreq::Method::Post, &self.url)
-                .with_timeout(timeout_secs)
-                .with_json(&req)?,
+    /// Reads the cookie file again, returns `true` if it holds new credentials.
+    fn reload_cookie(&self) -> bool {
+        let path = match self.cookie_file {
+            Some(ref path) => path,
+            None => return false,
         };
+        let mut cookie = match fs::read_to_string(path) {
+            Ok(cookie) => cookie,
+            Err(_) => return false,
+        };
+        let mut header = super::basic_auth_header(cookie.lines().next().unwrap_or(""));
+        super::wipe(&mut cookie);
+
+        let mut auth = self.basic_auth.lock().expect("poisoned lock");
+        if auth.as_ref() == Some(&header) {
+            super::wipe(&mut header);
+            return false;
+        }
+        if let Some(ref mut old) = *auth {
+            super::wipe(old);
+        }
+        *auth = Some(header);
+        true
+    }
+
+    fn send<R>(&self, req: &impl serde::Serialize) -> Result<R, Error>
+    where
+        R: for<'a> serde::de::Deserialize<'a>,
+    {
+        let timeout_secs = self.timeout_secs();
+        let token = self.cancel.start();
+
+        let mut request = bitreq::Request::new(bitreq::Method::Post, &self.url)
+            .with_timeout(timeout_secs)
+            .with_cancellation(token.clone());
+        // With the `zeroize` feature `bitreq` wipes the copy of the credentials once sent.
+        if let Some(ref auth) = *self.basic_auth.lock().expect("poisoned lock") {
+            request = request.with_header("Authorization", auth);
+        }
+        if let Some(user_agent) = &self.user_agent {
+            request = request.with_header("User-Agent", user_agent);
+        }
+        for (key, value) in &self.headers {
+            request = request.with_header(key, value);
+        }
+        let req = request.with_json(req)?;
 
         // Send the request and parse the response. If the response is an error that does not
         // contain valid JSON in its body (for instance if the bitcoind HTTP server work queue
         // depth is exceeded), return the raw HTTP error so users can match against it.
-        let resp = req.send()?;
+        let resp = match self.client {
+            Some(ref client) => client.send(req),
+            None => req.send(),
+        };
+        self.cancel.finish(&token);
+        let resp = resp?;
         match resp.json() {
             Ok(json) => Ok(json),
             Err(bitreq_err) =>
@@ -108,6 +189,37 @@ impl Transport for BitreqHttpTransport {
     fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.url) }
 }
 
+/// A handle used to abort the requests in flight on a [`BitreqHttpTransport`].
+///
+/// Cancelling only affects the requests in flight at the time of the call, the transport can be
+/// used as normal afterwards. Aborted requests fail with [`bitreq::Error::Cancelled`].
+#[derive(Clone, Debug, Default)]
+pub struct CancelHandle {
+    /// The tokens of the requests currently in flight.
+    in_flight: Arc<Mutex<Vec<bitreq::CancellationToken>>>,
+}
+
+impl CancelHandle {
+    /// Aborts all requests currently in flight.
+    pub fn cancel(&self) {
+        for token in self.in_flight.lock().expect("poisoned lock").drain(..) {
+            token.cancel();
+        }
+    }
+
+    /// Returns the token to use for a new request.
+    fn start(&self) -> bitreq::CancellationToken {
+        let token = bitreq::CancellationToken::new();
+        self.in_flight.lock().expect("poisoned lock").push(token.clone());
+        token
+    }
+
+    /// Forgets the token of a completed request.
+    fn finish(&self, token: &bitreq::CancellationToken) {
+        self.in_flight.lock().expect("poisoned lock").retain(|t| t != token);
+    }
+}
+
 /// Builder for simple bitcoind [`BitreqHttpTransport`].
 #[derive(Clone, Debug)]
 pub struct Builder {
@@ -135,10 +247,12 @@ impl Builder {
     pub fn basic_auth(mut self, user: String, pass: Option<String>) -> Self {
         let mut s = user;
         s.push(':');
-        if let Some(ref pass) = pass {
-            s.push_str(pass.as_ref());
+        if let Some(mut pass) = pass {
+            s.push_str(&pass);
+            super::wipe(&mut pass);
         }
-        self.tp.basic_auth = Some(format!("Basic {}", &BASE64.encode(s.as_bytes())));
+        self.tp.basic_auth = Arc::new(Mutex::new(Some(super::basic_auth_header(&s))));
+        super::wipe(&mut s);
         self
     }
 
@@ -159,7 +273,42 @@ impl Builder {
     /// let client = BitreqHttpTransport::builder().cookie_auth(cookie);
     /// ```
     pub fn cookie_auth<S: AsRef<str>>(mut self, cookie: S) -> Self {
-        self.tp.basic_auth = Some(format!("Basic {}", &BASE64.encode(cookie.as_ref().as_bytes())));
+        self.tp.basic_auth = Arc::new(Mutex::new(Some(super::basic_auth_header(cookie.as_ref()))));
+        self
+    }
+
+    /// Reads the credentials again from the cookie file at `path` if the server rejects them.
+    ///
+    /// Bitcoin Core writes a new cookie each time it starts, this keeps the transport working
+    /// across restarts of the node. The request is retried once with the new credentials. Does
+    /// not read the cookie file now, set the initial credentials with [`Builder::cookie_auth`].
+    pub fn reload_cookie_file<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.tp.cookie_file = Some(path.into());
+        self
+    }
+
+    /// Sets the `User-Agent` HTTP header e.g., to tell apart the services calling a node in its
+    /// logs or in a proxy. No `User-Agent` is sent by default.
+    pub fn user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.tp.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Adds an HTTP header to send with every request e.g., an API key required by a gateway.
+    ///
+    /// A header with the same name as one set by the transport (e.g. `Authorization`) replaces it.
+    pub fn header<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.tp.headers.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sets whether connections to the server are kept open and reused between requests, saving
+    /// the TCP handshake of each request. Enabled by default.
+    ///
+    /// A connection closed by the server while idle, e.g., because the node restarted, is replaced
+    /// by a new one.
+    pub fn keep_alive(mut self, keep_alive: bool) -> Self {
+        self.tp.client = keep_alive.then(|| bitreq::Client::new(IDLE_CONNECTIONS));
         self
     }
 
@@ -283,4 +432,54 @@ mod tests {
             .build();
         let _ = Client::with_transport(tp);
     }
+
+    #[test]
+    fn keep_alive() {
+        assert!(Builder::new().build().client.is_some());
+        assert!(Builder::new().keep_alive(false).build().client.is_none());
+    }
+
+    #[test]
+    fn construct_with_headers() {
+        let tp = Builder::new()
+            .url("http://localhost:22/gateway/bitcoin")
+            .unwrap()
+            .header("X-Api-Key", "secret")
+            .header("X-Request-Source", String::from("corepc"))
+            .build();
+        assert_eq!(
+            tp.headers,
+            vec![
+                ("X-Api-Key".to_owned(), "secret".to_owned()),
+                ("X-Request-Source".to_owned(), "corepc".to_owned()),
+            ]
+        );
+        let _ = Client::with_transport(tp);
+    }
+
+    #[test]
+    fn construct_with_user_agent() {
+        let tp = Builder::new().build();
+        assert_eq!(tp.user_agent, None);
+
+        let tp = Builder::new().user_agent("wallet-service/1.2").build();
+        assert_eq!(tp.user_agent.as_deref(), Some("wallet-service/1.2"));
+    }
+
+    #[test]
+    fn reload_cookie_file() {
+        let path = std::env::temp_dir().join(format!("jsonrpc-cookie-{}", std::process::id()));
+        fs::write(&path, "__cookie__:first\n").unwrap();
+        let tp = Builder::new().cookie_auth("__cookie__:first").reload_cookie_file(&path).build();
+        assert!(!tp.reload_cookie());
+
+        fs::write(&path, "__cookie__:second\n").unwrap();
+        assert!(tp.reload_cookie());
+        let expected = crate::http::basic_auth_header("__cookie__:second");
+        assert_eq!(*tp.basic_auth.lock().unwrap(), Some(expected));
+
+        fs::remove_file(&path).unwrap();
+        assert!(!tp.reload_cookie());
+        assert!(!Builder::new().build().reload_cookie());
+    }
 }
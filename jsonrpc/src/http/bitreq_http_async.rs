@@ -6,9 +6,6 @@
 use std::time::Duration;
 use std::{error, fmt};
 
-use base64::engine::general_purpose::STANDARD as BASE64;
-use base64::Engine;
-
 use crate::client_async::{BoxFuture, Transport};
 use crate::{Request, Response};
 
@@ -25,6 +22,8 @@ pub struct BitreqHttpTransport {
     timeout: Duration,
     /// The value of the `Authorization` HTTP header, i.e., a base64 encoding of 'user:password'.
     basic_auth: Option<String>,
+    /// Additional HTTP headers sent with every request.
+    headers: Vec<(String, String)>,
 }
 
 impl Default for BitreqHttpTransport {
@@ -33,6 +32,16 @@ impl Default for BitreqHttpTransport {
             url: format!("{}:{}", DEFAULT_URL, DEFAULT_PORT),
             timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECONDS),
             basic_auth: None,
+            headers: Vec::new(),
+        }
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for BitreqHttpTransport {
+    fn drop(&mut self) {
+        if let Some(ref mut auth) = self.basic_auth {
+            super::wipe(auth);
         }
     }
 }
@@ -67,15 +76,16 @@ impl BitreqHttpTransport {
     {
         let timeout_secs = self.timeout_secs();
 
-        let req = match &self.basic_auth {
-            Some(auth) => bitreq::Request::new(bitreq::Method::Post, &self.url)
-                .with_timeout(timeout_secs)
-                .with_header("Authorization", auth)
-                .with_json(&req)?,
-            None => bitreq::Request::new(bitreq::Method::Post, &self.url)
-                .with_timeout(timeout_secs)
-                .with_json(&req)?,
-        };
+        let mut request =
+            bitreq::Request::new(bitreq::Method::Post, &self.url).with_timeout(timeout_secs);
+        // With the `zeroize` feature `bitreq` wipes the copy of the credentials once sent.
+        if let Some(auth) = &self.basic_auth {
+            request = request.with_header("Authorization", auth);
+        }
+        for (key, value) in &self.headers {
+            request = request.with_header(key, value);
+        }
+        let req = request.with_json(&req)?;
 
         // Send the request and parse the response. If the response is an error that does not
         // contain valid JSON in its body (for instance if the bitcoind HTTP server work queue
@@ -141,10 +151,12 @@ impl Builder {
     pub fn basic_auth(mut self, user: String, pass: Option<String>) -> Self {
         let mut s = user;
         s.push(':');
-        if let Some(ref pass) = pass {
-            s.push_str(pass.as_ref());
+        if let Some(mut pass) = pass {
+            s.push_str(&pass);
+            super::wipe(&mut pass);
         }
-        self.tp.basic_auth = Some(format!("Basic {}", &BASE64.encode(s.as_bytes())));
+        self.tp.basic_auth = Some(super::basic_auth_header(&s));
+        super::wipe(&mut s);
         self
     }
 
@@ -166,7 +178,15 @@ impl Builder {
     /// let client = BitreqHttpTransport::builder().cookie_auth(cookie);
     /// ```
     pub fn cookie_auth<S: AsRef<str>>(mut self, cookie: S) -> Self {
-        self.tp.basic_auth = Some(format!("Basic {}", &BASE64.encode(cookie.as_ref().as_bytes())));
+        self.tp.basic_auth = Some(super::basic_auth_header(cookie.as_ref()));
+        self
+    }
+
+    /// Adds an HTTP header to send with every request e.g., an API key required by a gateway.
+    ///
+    /// A header with the same name as one set by the transport (e.g. `Authorization`) replaces it.
+    pub fn header<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
+        self.tp.headers.push((key.into(), value.into()));
         self
     }
 
@@ -18,3 +18,23 @@ pub const DEFAULT_PORT: u16 = 8332;
 // Currently only used by `simple_http` module, here for consistency.
 #[cfg(feature = "proxy")]
 pub const DEFAULT_PROXY_PORT: u16 = 9050;
+
+/// Returns the value of the `Authorization` HTTP header for `credentials` ('user:pass').
+#[cfg(any(feature = "bitreq_http", feature = "bitreq_http_async"))]
+pub(crate) fn basic_auth_header(credentials: &str) -> String {
+    use base64::engine::general_purpose::STANDARD as BASE64;
+    use base64::Engine;
+
+    let mut header = String::from("Basic ");
+    BASE64.encode_string(credentials.as_bytes(), &mut header);
+    header
+}
+
+/// Overwrites `secret` with zeros if the `zeroize` feature is enabled, does nothing otherwise.
+#[cfg(any(feature = "bitreq_http", feature = "bitreq_http_async"))]
+pub(crate) fn wipe(secret: &mut String) {
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(secret);
+    #[cfg(not(feature = "zeroize"))]
+    let _ = secret;
+}
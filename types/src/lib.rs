@@ -6,6 +6,8 @@
 //! really didn't make sense. Only required arguments are documented. To see what optional arguments
 //! are available run `bitcoin-cli help <method>` against the version of Core you are interested in.
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
 /// Re-export the `rust-bitcoin` crate.
 pub extern crate bitcoin;
 
@@ -14,6 +16,7 @@ extern crate alloc;
 // TODO: Consider updating https://en.bitcoin.it/wiki/API_reference_%28JSON-RPC%29 when this is complete.
 
 mod error;
+mod prelude;
 mod psbt;
 
 // JSON types, for each specific version of `bitcoind`.
@@ -36,6 +39,13 @@ pub mod v31;
 // JSON types that model _all_ `bitcoind` versions.
 pub mod model;
 
+// Metadata about the methods of _all_ `bitcoind` versions.
+pub mod methods;
+
+// JSON Schema for the JSON types of each `bitcoind` version.
+#[cfg(feature = "schemars")]
+pub mod schema;
+
 use core::fmt;
 
 use bitcoin::address::{self, Address, NetworkUnchecked};
@@ -45,6 +55,7 @@ use bitcoin::{Amount, FeeRate, ScriptBuf, Witness};
 use serde::{Deserialize, Serialize};
 
 use crate::error::write_err;
+use crate::prelude::*;
 
 /// Converts an `i64` numeric type to a `u32`.
 ///
@@ -183,6 +194,7 @@ pub fn compact_size_decode(slice: &mut &[u8]) -> u64 {
 /// backwards compatible so we only provide it not a v0.17 specific type. The `mtype::ScriptPubKey`
 /// mirrors this design (but with concrete `rust-bitcoin` types).
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ScriptPubKey {
     /// Script assembly.
@@ -281,6 +293,7 @@ impl ScriptPubKey {
 
 /// Data returned by Core for a script signature.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ScriptSig {
     /// Assembly representation of the script.
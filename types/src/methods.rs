@@ -0,0 +1,331 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Metadata about the JSON-RPC methods of all the supported versions of Bitcoin Core.
+//!
+//! The [`METHODS`] table is generated by the `verify` tool from the output of `bitcoin-cli help`
+//! for each version of Core, the same data the version specific modules are verified against.
+//! Regenerate it by running `cargo run -- registry` in the `verify` directory.
+//!
+//! Use it instead of hand maintained lists of methods e.g., to route the calls that only read to
+//! some nodes, or to check a method exists in the version of Core a node runs.
+//!
+//! ```
+//! use corepc_types::methods::{self, Section};
+//!
+//! let info = methods::get("getblock").expect("known method");
+//! assert_eq!(info.section, Section::Blockchain);
+//! assert!(!info.is_mutating());
+//! assert!(methods::get("getaccount").is_some_and(|info| !info.is_available(18)));
+//! ```
+
+use self::Access::{Read, Write};
+use self::Section::*;
+
+/// The section a method is listed under by `bitcoin-cli help`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Section {
+    /// `== Blockchain ==`.
+    Blockchain,
+    /// `== Control ==`.
+    Control,
+    /// `== Generating ==`.
+    Generating,
+    /// `== Mining ==`.
+    Mining,
+    /// `== Network ==`.
+    Network,
+    /// `== Rawtransactions ==`.
+    Rawtransactions,
+    /// `== Signer ==`.
+    Signer,
+    /// `== Util ==`.
+    Util,
+    /// `== Wallet ==`.
+    Wallet,
+    /// `== Zmq ==`.
+    Zmq,
+}
+
+/// Whether calling a method changes the state of the node or its wallets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Access {
+    /// Only reads e.g., `getblock`, `decodepsbt`, `getbalance`.
+    Read,
+    /// Changes the state of the node or a wallet, or acts on the network e.g., `addnode`,
+    /// `sendrawtransaction`, `getnewaddress`.
+    Write,
+}
+
+/// Metadata about a JSON-RPC method.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MethodInfo {
+    /// The method name.
+    pub name: &'static str,
+    /// The section the method is listed under by `bitcoin-cli help`.
+    pub section: Section,
+    /// The first major version of Core with the method (`17` for v0.17).
+    pub since: u32,
+    /// The first major version of Core without the method, `None` if all later versions have it.
+    pub until: Option<u32>,
+    /// Whether calling the method changes the state of the node.
+    pub access: Access,
+    /// `true` if the method is called on a wallet i.e., using the wallet URL of the node.
+    pub wallet: bool,
+}
+
+impl MethodInfo {
+    /// Returns `true` if calling the method changes the state of the node or a wallet.
+    pub fn is_mutating(&self) -> bool { self.access == Write }
+
+    /// Returns `true` if the major version `version` of Core has the method (`17` for v0.17).
+    pub fn is_available(&self, version: u32) -> bool {
+        version >= self.since && self.until.map_or(true, |until| version < until)
+    }
+}
+
+/// Returns the metadata of the method `name`, `None` if no supported version of Core has it.
+pub fn get(name: &str) -> Option<&'static MethodInfo> {
+    METHODS.binary_search_by(|info| info.name.cmp(name)).ok().map(|index| &METHODS[index])
+}
+
+/// Returns the methods of the major version `version` of Core (`17` for v0.17).
+pub fn methods(version: u32) -> impl Iterator<Item = &'static MethodInfo> {
+    METHODS.iter().filter(move |info| info.is_available(version))
+}
+
+/// Returns the metadata of a method not called on a wallet.
+const fn node(
+    name: &'static str,
+    section: Section,
+    since: u32,
+    until: Option<u32>,
+    access: Access,
+) -> MethodInfo {
+    MethodInfo { name, section, since, until, access, wallet: false }
+}
+
+/// Returns the metadata of a method called on a wallet.
+const fn wallet(
+    name: &'static str,
+    section: Section,
+    since: u32,
+    until: Option<u32>,
+    access: Access,
+) -> MethodInfo {
+    MethodInfo { name, section, since, until, access, wallet: true }
+}
+
+/// The methods of all the supported versions of Core, sorted by name.
+// Start of generated code, see the module docs.
+pub const METHODS: &[MethodInfo] = &[
+    wallet("abandontransaction", Wallet, 17, None, Write),
+    node("abortprivatebroadcast", Rawtransactions, 31, None, Write),
+    wallet("abortrescan", Wallet, 17, None, Write),
+    wallet("addmultisigaddress", Wallet, 17, Some(30), Write),
+    node("addnode", Network, 17, None, Write),
+    node("analyzepsbt", Rawtransactions, 18, None, Read),
+    wallet("backupwallet", Wallet, 17, None, Write),
+    wallet("bumpfee", Wallet, 17, None, Write),
+    node("clearbanned", Network, 17, None, Write),
+    node("combinepsbt", Rawtransactions, 17, None, Read),
+    node("combinerawtransaction", Rawtransactions, 17, None, Read),
+    node("converttopsbt", Rawtransactions, 17, None, Read),
+    node("createmultisig", Util, 17, None, Read),
+    node("createpsbt", Rawtransactions, 17, None, Read),
+    node("createrawtransaction", Rawtransactions, 17, None, Read),
+    node("createwallet", Wallet, 17, None, Write),
+    wallet("createwalletdescriptor", Wallet, 28, None, Write),
+    node("decodepsbt", Rawtransactions, 17, None, Read),
+    node("decoderawtransaction", Rawtransactions, 17, None, Read),
+    node("decodescript", Rawtransactions, 17, None, Read),
+    node("deriveaddresses", Util, 18, None, Read),
+    node("descriptorprocesspsbt", Rawtransactions, 26, None, Read),
+    node("disconnectnode", Network, 17, None, Write),
+    wallet("dumpprivkey", Wallet, 17, Some(30), Read),
+    node("dumptxoutset", Blockchain, 26, None, Write),
+    wallet("dumpwallet", Wallet, 17, Some(30), Write),
+    wallet("encryptwallet", Wallet, 17, None, Write),
+    node("enumeratesigners", Signer, 22, None, Read),
+    node("estimatesmartfee", Util, 17, None, Read),
+    node("finalizepsbt", Rawtransactions, 17, None, Read),
+    wallet("fundrawtransaction", Rawtransactions, 17, None, Write),
+    wallet("generate", Generating, 17, Some(19), Write),
+    node("generateblock", Generating, 21, None, Write),
+    node("generatetoaddress", Generating, 17, None, Write),
+    node("generatetodescriptor", Generating, 20, None, Write),
+    wallet("getaccount", Wallet, 17, Some(18), Read),
+    wallet("getaccountaddress", Wallet, 17, Some(18), Write),
+    node("getaddednodeinfo", Network, 17, None, Read),
+    wallet("getaddressbyaccount", Wallet, 17, Some(18), Read),
+    wallet("getaddressesbylabel", Wallet, 17, None, Read),
+    wallet("getaddressinfo", Wallet, 17, None, Read),
+    node("getaddrmaninfo", Network, 26, None, Read),
+    wallet("getbalance", Wallet, 17, None, Read),
+    wallet("getbalances", Wallet, 19, None, Read),
+    node("getbestblockhash", Blockchain, 17, None, Read),
+    node("getblock", Blockchain, 17, None, Read),
+    node("getblockchaininfo", Blockchain, 17, None, Read),
+    node("getblockcount", Blockchain, 17, None, Read),
+    node("getblockfilter", Blockchain, 19, None, Read),
+    node("getblockfrompeer", Blockchain, 23, None, Write),
+    node("getblockhash", Blockchain, 17, None, Read),
+    node("getblockheader", Blockchain, 17, None, Read),
+    node("getblockstats", Blockchain, 17, None, Read),
+    node("getblocktemplate", Mining, 17, None, Read),
+    node("getchainstates", Blockchain, 26, None, Read),
+    node("getchaintips", Blockchain, 17, None, Read),
+    node("getchaintxstats", Blockchain, 17, None, Read),
+    node("getconnectioncount", Network, 17, None, Read),
+    node("getdeploymentinfo", Blockchain, 23, None, Read),
+    node("getdescriptoractivity", Blockchain, 29, None, Read),
+    node("getdescriptorinfo", Util, 18, None, Read),
+    node("getdifficulty", Blockchain, 17, None, Read),
+    wallet("gethdkeys", Wallet, 28, None, Read),
+    node("getindexinfo", Util, 21, None, Read),
+    node("getmemoryinfo", Control, 17, None, Read),
+    node("getmempoolancestors", Blockchain, 17, None, Read),
+    node("getmempoolcluster", Blockchain, 31, None, Read),
+    node("getmempooldescendants", Blockchain, 17, None, Read),
+    node("getmempoolentry", Blockchain, 17, None, Read),
+    node("getmempoolinfo", Blockchain, 17, None, Read),
+    node("getmininginfo", Mining, 17, None, Read),
+    node("getnettotals", Network, 17, None, Read),
+    node("getnetworkhashps", Mining, 17, None, Read),
+    node("getnetworkinfo", Network, 17, None, Read),
+    wallet("getnewaddress", Wallet, 17, None, Write),
+    node("getnodeaddresses", Network, 18, None, Read),
+    node("getpeerinfo", Network, 17, None, Read),
+    node("getprioritisedtransactions", Mining, 26, None, Read),
+    node("getprivatebroadcastinfo", Rawtransactions, 31, None, Read),
+    wallet("getrawchangeaddress", Wallet, 17, None, Write),
+    node("getrawmempool", Blockchain, 17, None, Read),
+    node("getrawtransaction", Rawtransactions, 17, None, Read),
+    wallet("getreceivedbyaccount", Wallet, 17, Some(18), Read),
+    wallet("getreceivedbyaddress", Wallet, 17, None, Read),
+    wallet("getreceivedbylabel", Wallet, 18, None, Read),
+    node("getrpcinfo", Control, 18, None, Read),
+    wallet("gettransaction", Wallet, 17, None, Read),
+    node("gettxout", Blockchain, 17, None, Read),
+    node("gettxoutproof", Blockchain, 17, None, Read),
+    node("gettxoutsetinfo", Blockchain, 17, None, Read),
+    node("gettxspendingprevout", Blockchain, 24, None, Read),
+    wallet("getunconfirmedbalance", Wallet, 17, Some(30), Read),
+    wallet("getwalletinfo", Wallet, 17, None, Read),
+    node("getzmqnotifications", Zmq, 17, None, Read),
+    node("help", Control, 17, None, Read),
+    wallet("importaddress", Wallet, 17, Some(30), Write),
+    wallet("importdescriptors", Wallet, 21, None, Write),
+    node("importmempool", Blockchain, 26, None, Write),
+    wallet("importmulti", Wallet, 17, Some(30), Write),
+    wallet("importprivkey", Wallet, 17, Some(30), Write),
+    wallet("importprunedfunds", Wallet, 17, None, Write),
+    wallet("importpubkey", Wallet, 17, Some(30), Write),
+    wallet("importwallet", Wallet, 17, Some(30), Write),
+    node("joinpsbts", Rawtransactions, 18, None, Read),
+    wallet("keypoolrefill", Wallet, 17, None, Write),
+    wallet("listaccounts", Wallet, 17, Some(18), Read),
+    wallet("listaddressgroupings", Wallet, 17, None, Read),
+    node("listbanned", Network, 17, None, Read),
+    wallet("listdescriptors", Wallet, 22, None, Read),
+    wallet("listlabels", Wallet, 17, None, Read),
+    wallet("listlockunspent", Wallet, 17, None, Read),
+    wallet("listreceivedbyaccount", Wallet, 17, Some(18), Read),
+    wallet("listreceivedbyaddress", Wallet, 17, None, Read),
+    wallet("listreceivedbylabel", Wallet, 18, None, Read),
+    wallet("listsinceblock", Wallet, 17, None, Read),
+    wallet("listtransactions", Wallet, 17, None, Read),
+    wallet("listunspent", Wallet, 17, None, Read),
+    node("listwalletdir", Wallet, 18, None, Read),
+    node("listwallets", Wallet, 17, None, Read),
+    node("loadtxoutset", Blockchain, 26, None, Write),
+    node("loadwallet", Wallet, 17, None, Write),
+    wallet("lockunspent", Wallet, 17, None, Write),
+    node("logging", Control, 17, None, Write),
+    wallet("migratewallet", Wallet, 24, None, Write),
+    wallet("move", Wallet, 17, Some(18), Write),
+    wallet("newkeypool", Wallet, 23, Some(30), Write),
+    node("ping", Network, 17, None, Write),
+    node("preciousblock", Blockchain, 17, None, Write),
+    node("prioritisetransaction", Mining, 17, None, Write),
+    node("pruneblockchain", Blockchain, 17, None, Write),
+    wallet("psbtbumpfee", Wallet, 21, None, Write),
+    wallet("removeprunedfunds", Wallet, 17, None, Write),
+    wallet("rescanblockchain", Wallet, 17, None, Write),
+    node("restorewallet", Wallet, 23, None, Write),
+    node("savemempool", Blockchain, 17, None, Write),
+    node("scanblocks", Blockchain, 25, None, Read),
+    node("scantxoutset", Blockchain, 17, None, Read),
+    wallet("send", Wallet, 21, None, Write),
+    wallet("sendall", Wallet, 24, None, Write),
+    wallet("sendfrom", Wallet, 17, Some(18), Write),
+    wallet("sendmany", Wallet, 17, None, Write),
+    node("sendrawtransaction", Rawtransactions, 17, None, Write),
+    wallet("sendtoaddress", Wallet, 17, None, Write),
+    wallet("setaccount", Wallet, 17, Some(18), Write),
+    node("setban", Network, 17, None, Write),
+    wallet("sethdseed", Wallet, 17, Some(30), Write),
+    wallet("setlabel", Wallet, 18, None, Write),
+    node("setnetworkactive", Network, 17, None, Write),
+    wallet("settxfee", Wallet, 17, Some(31), Write),
+    wallet("setwalletflag", Wallet, 19, None, Write),
+    wallet("signmessage", Wallet, 17, None, Read),
+    node("signmessagewithprivkey", Util, 17, None, Read),
+    node("signrawtransaction", Rawtransactions, 17, Some(18), Read),
+    node("signrawtransactionwithkey", Rawtransactions, 17, None, Read),
+    wallet("signrawtransactionwithwallet", Wallet, 17, None, Read),
+    wallet("simulaterawtransaction", Wallet, 24, None, Read),
+    node("stop", Control, 17, None, Write),
+    node("submitblock", Mining, 17, None, Write),
+    node("submitheader", Mining, 18, None, Write),
+    node("submitpackage", Rawtransactions, 26, None, Write),
+    node("testmempoolaccept", Rawtransactions, 17, None, Read),
+    wallet("unloadwallet", Wallet, 17, None, Write),
+    wallet("upgradewallet", Wallet, 21, Some(30), Write),
+    node("uptime", Control, 17, None, Read),
+    node("utxoupdatepsbt", Rawtransactions, 18, None, Read),
+    node("validateaddress", Util, 17, None, Read),
+    node("verifychain", Blockchain, 17, None, Read),
+    node("verifymessage", Util, 17, None, Read),
+    node("verifytxoutproof", Blockchain, 17, None, Read),
+    node("waitforblock", Blockchain, 30, None, Read),
+    node("waitforblockheight", Blockchain, 30, None, Read),
+    node("waitfornewblock", Blockchain, 30, None, Read),
+    wallet("walletcreatefundedpsbt", Wallet, 17, None, Write),
+    wallet("walletdisplayaddress", Wallet, 22, None, Read),
+    wallet("walletlock", Wallet, 17, None, Write),
+    wallet("walletpassphrase", Wallet, 17, None, Write),
+    wallet("walletpassphrasechange", Wallet, 17, None, Write),
+    wallet("walletprocesspsbt", Wallet, 17, None, Read),
+];
+// End of generated code.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn methods_are_sorted() {
+        assert!(METHODS.windows(2).all(|pair| pair[0].name < pair[1].name));
+    }
+
+    #[test]
+    fn method_metadata() {
+        let info = get("sendtoaddress").expect("known method");
+        assert_eq!(info.section, Wallet);
+        assert!(info.is_mutating());
+        assert!(info.wallet);
+
+        let info = get("loadwallet").expect("known method");
+        assert!(!info.wallet);
+
+        let info = get("getblockchaininfo").expect("known method");
+        assert!(!info.is_mutating());
+        assert!(!info.wallet);
+
+        assert!(get("notamethod").is_none());
+        assert!(methods(17).any(|info| info.name == "getaccount"));
+        assert!(!methods(18).any(|info| info.name == "getaccount"));
+        assert!(methods(26).any(|info| info.name == "submitpackage"));
+        assert!(!methods(25).any(|info| info.name == "submitpackage"));
+    }
+}
@@ -16,6 +16,7 @@ use bitcoin::{
 use serde::{Deserialize, Serialize};
 
 use super::{GetRawTransactionVerbose, ScriptPubKey};
+use crate::prelude::*;
 
 /// Models the result of JSON-RPC method `dumptxoutset`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -550,7 +551,7 @@ pub struct GetDeploymentInfo {
     /// Requested block height (or tip).
     pub height: u32,
     /// Deployments info, keyed by deployment name.
-    pub deployments: std::collections::BTreeMap<String, DeploymentInfo>,
+    pub deployments: alloc::collections::BTreeMap<String, DeploymentInfo>,
 }
 
 /// Deployment info. Part of `getdeploymentinfo`.
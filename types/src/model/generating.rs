@@ -8,6 +8,8 @@
 use bitcoin::{Block, BlockHash};
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Models the result of JSON-RPC method `generate`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct Generate(pub Vec<BlockHash>);
@@ -8,6 +8,8 @@
 use bitcoin::{FeeRate, Transaction, Txid, Wtxid};
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Models the result of JSON-RPC method `estimaterawfee`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct EstimateRawFee {
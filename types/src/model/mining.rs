@@ -5,7 +5,7 @@
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
 
-use std::collections::BTreeMap;
+use alloc::collections::BTreeMap;
 
 use bitcoin::{
     block, BlockHash, CompactTarget, FeeRate, SignedAmount, Target, Transaction, Txid, Weight,
@@ -13,6 +13,8 @@ use bitcoin::{
 };
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Models the result of JSON-RPC method `getblocktemplate`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetBlockTemplate {
@@ -50,7 +50,9 @@ pub use self::{
         BlockTemplateTransaction, GetBlockTemplate, GetMiningInfo, GetPrioritisedTransactions,
         NextBlockInfo, PrioritisedTransaction,
     },
-    network::{GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork},
+    network::{
+        Banned, GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork, ListBanned, Subnet,
+    },
     raw_transactions::{
         AbortPrivateBroadcast, AnalyzePsbt, AnalyzePsbtInput, AnalyzePsbtInputMissing, CombinePsbt,
         CombineRawTransaction, ConvertToPsbt, CreatePsbt, CreateRawTransaction, DecodePsbt,
@@ -79,6 +81,7 @@ pub use self::{
         UnloadWallet, WalletCreateFundedPsbt, WalletDisplayAddress, WalletProcessPsbt,
     },
 };
+use crate::prelude::*;
 
 /// Models the data returned by Core for a scriptPubKey.
 ///
@@ -5,9 +5,13 @@
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
 
+use core::fmt;
+
 use bitcoin::FeeRate;
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Models the result of JSON-RPC method `getnetworkinfo`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetNetworkInfo {
@@ -70,3 +74,34 @@ pub struct GetNetworkInfoAddress {
     /// Relative score.
     pub score: u32,
 }
+
+/// Models the result of JSON-RPC method `listbanned`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ListBanned(pub Vec<Banned>);
+
+/// A banned IP address or subnet. Part of `listbanned`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Banned {
+    /// The banned IP address or subnet.
+    pub subnet: Subnet,
+    /// The UNIX epoch time the ban was created.
+    pub ban_created: u32,
+    /// The UNIX epoch time the ban expires.
+    pub banned_until: u32,
+    /// The ban duration, in seconds.
+    pub ban_duration: u32,
+    /// The time remaining until the ban expires, in seconds. v22 and later only.
+    pub time_remaining: Option<u32>,
+    /// The reason for the ban. v19 and earlier only.
+    pub ban_reason: Option<String>,
+}
+
+/// An IP address or subnet as formatted by Core e.g., `192.0.2.0/24`.
+///
+/// A single address is formatted with a prefix length of 32 for IPv4 and 128 for IPv6.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct Subnet(pub String);
+
+impl fmt::Display for Subnet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str(&self.0) }
+}
@@ -12,6 +12,8 @@ use bitcoin::hashes::{hash160, sha256};
 use bitcoin::{Amount, BlockHash, FeeRate, Psbt, ScriptBuf, Sequence, Transaction, Txid, Wtxid};
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Models the result of JSON-RPC method `abortprivatebroadcast`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct AbortPrivateBroadcast {
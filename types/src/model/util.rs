@@ -9,6 +9,8 @@ use bitcoin::address::NetworkUnchecked;
 use bitcoin::{sign_message, Address, FeeRate, ScriptBuf, WitnessProgram, WitnessVersion};
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Models the result of JSON-RPC method `createmultisig`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct CreateMultisig {
@@ -17,6 +17,7 @@ use bitcoin::{
 use serde::{Deserialize, Serialize};
 
 use super::SignRawTransaction;
+use crate::prelude::*;
 
 /// The purpose of an address. Part of `getaddressesbylabel`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The `alloc` items used throughout the crate, imported explicitly so we build without `std`.
+
+#![allow(unused_imports)]
+
+pub(crate) use alloc::borrow::ToOwned;
+pub(crate) use alloc::boxed::Box;
+pub(crate) use alloc::string::{String, ToString};
+pub(crate) use alloc::vec::Vec;
+pub(crate) use alloc::{format, vec};
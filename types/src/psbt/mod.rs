@@ -2,7 +2,7 @@
 
 mod error;
 
-use std::collections::{BTreeMap, HashMap};
+use alloc::collections::BTreeMap;
 
 use bitcoin::hex::{self, FromHex as _};
 use bitcoin::{
@@ -15,6 +15,7 @@ pub use self::error::{
     Bip32DerivError, PartialSignatureError, RawTransactionError, RawTransactionInputError,
     RawTransactionOutputError, WitnessUtxoError,
 };
+use crate::prelude::*;
 use crate::{ScriptPubKey, ScriptSig};
 
 /// Represents a bitcoin transaction.
@@ -22,6 +23,7 @@ use crate::{ScriptPubKey, ScriptSig};
 /// Part of `decoderawtransaction` and `decodepsbt`.
 // This JSON data can be encapsulated by a `bitcoin::Transaction`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct RawTransaction {
     /// The transaction id.
@@ -74,6 +76,7 @@ impl RawTransaction {
 /// Represents a transaction input.
 // This JSON data can be encapsulated by a `bitcoin::TxIn`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct RawTransactionInput {
     /// Coinbase data (present on coinbase transactions only).
@@ -138,6 +141,7 @@ impl RawTransactionInput {
 /// Represents a transaction output.
 // This JSON data can be encapsulated by a `bitcoin::TxOut` + index.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct RawTransactionOutput {
     /// The value in BTC.
@@ -165,6 +169,7 @@ impl RawTransactionOutput {
 /// Transaction output for witness UTXOs.
 // This JSON data can be encapsulated by a `bitcoin::TxOut`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct WitnessUtxo {
     /// The value in BTC.
@@ -188,6 +193,7 @@ impl WitnessUtxo {
 
 /// A script part of a PSBT input or output.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct PsbtScript {
     /// The asm.
@@ -214,6 +220,7 @@ impl PsbtScript {
 // bip32_derivation: BTreeMap<secp256k1::PublicKey, KeySource>,
 // KeySource = (Fingerprint, DerivationPath);
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct Bip32Deriv {
     /// The public key this path corresponds to.
@@ -227,6 +234,7 @@ pub struct Bip32Deriv {
 /// The key source data for a BIP-32 derivation.
 // In v0.17 the BIP-32 derivation for inputs is a map of pubkey to this type.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct InputKeySource {
     /// The fingerprint of the master key.
@@ -237,6 +245,7 @@ pub struct InputKeySource {
 
 /// Final script data.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct FinalScript {
     /// The asm.
@@ -247,7 +256,7 @@ pub struct FinalScript {
 
 /// Converts a map of unknown key-value pairs.
 pub fn into_unknown(
-    hash_map: HashMap<String, String>,
+    hash_map: BTreeMap<String, String>,
 ) -> Result<BTreeMap<psbt::raw::Key, Vec<u8>>, hex::HexToBytesError> {
     let mut map = BTreeMap::default();
     for (k, v) in hash_map.iter() {
@@ -273,7 +282,7 @@ pub fn into_unknown(
 
 /// Converts a map of partial signature key-value pairs.
 pub fn into_partial_signatures(
-    hash_map: HashMap<String, String>,
+    hash_map: BTreeMap<String, String>,
 ) -> Result<BTreeMap<PublicKey, ecdsa::Signature>, PartialSignatureError> {
     use PartialSignatureError as E;
 
@@ -286,9 +295,9 @@ pub fn into_partial_signatures(
     Ok(map)
 }
 
-/// Converts a hash map of BIP-32 derivation data into a map suitable for use with `psbt::Psbt`.
+/// Converts a map of BIP-32 derivation data into a map suitable for use with `psbt::Psbt`.
 pub fn map_into_bip32_derivation(
-    hash_map: HashMap<String, InputKeySource>,
+    hash_map: BTreeMap<String, InputKeySource>,
 ) -> Result<BTreeMap<secp256k1::PublicKey, bip32::KeySource>, Bip32DerivError> {
     use bip32::{DerivationPath, Fingerprint};
     use Bip32DerivError as E;
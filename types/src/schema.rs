@@ -0,0 +1,1822 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! JSON Schema for the types returned by the JSON-RPC methods of each version of Bitcoin Core.
+//!
+//! This module is generated by the `verify` tool from its method data, do not edit it. Regenerate
+//! it by running `cargo run -- schema` in the `verify` directory.
+//!
+//! Methods returning nothing, a number, a boolean or a string, and the methods that are not yet
+//! supported, are not included.
+//!
+//! ```
+//! let schemas = corepc_types::schema::v29();
+//! let (_, schema) = schemas.iter().find(|(method, _)| *method == "getblockchaininfo").unwrap();
+//! println!("{}", serde_json::to_string_pretty(schema).unwrap());
+//! ```
+
+use schemars::schema::RootSchema;
+use schemars::schema_for;
+
+/// Returns the JSON Schema of the type returned by each method of `v17`, by method name.
+pub fn v17() -> Vec<(&'static str, RootSchema)> {
+    use crate::v17::*;
+
+    vec![
+        ("getbestblockhash", schema_for!(GetBestBlockHash)),
+        ("getblock", schema_for!(GetBlockVerboseZero)),
+        ("getblockchaininfo", schema_for!(GetBlockchainInfo)),
+        ("getblockcount", schema_for!(GetBlockCount)),
+        ("getblockhash", schema_for!(GetBlockHash)),
+        ("getblockheader", schema_for!(GetBlockHeader)),
+        ("getblockstats", schema_for!(GetBlockStats)),
+        ("getchaintips", schema_for!(GetChainTips)),
+        ("getchaintxstats", schema_for!(GetChainTxStats)),
+        ("getdifficulty", schema_for!(GetDifficulty)),
+        ("getmempoolancestors", schema_for!(GetMempoolAncestors)),
+        ("getmempooldescendants", schema_for!(GetMempoolDescendants)),
+        ("getmempoolentry", schema_for!(GetMempoolEntry)),
+        ("getmempoolinfo", schema_for!(GetMempoolInfo)),
+        ("getrawmempool", schema_for!(GetRawMempool)),
+        ("gettxout", schema_for!(GetTxOut)),
+        ("gettxoutsetinfo", schema_for!(GetTxOutSetInfo)),
+        ("pruneblockchain", schema_for!(PruneBlockchain)),
+        ("scantxoutset", schema_for!(ScanTxOutSetStart)),
+        ("verifychain", schema_for!(VerifyChain)),
+        ("verifytxoutproof", schema_for!(VerifyTxOutProof)),
+        ("getmemoryinfo", schema_for!(GetMemoryInfoStats)),
+        ("logging", schema_for!(Logging)),
+        ("generate", schema_for!(Generate)),
+        ("generatetoaddress", schema_for!(GenerateToAddress)),
+        ("getblocktemplate", schema_for!(GetBlockTemplate)),
+        ("getmininginfo", schema_for!(GetMiningInfo)),
+        ("getaddednodeinfo", schema_for!(GetAddedNodeInfo)),
+        ("getconnectioncount", schema_for!(GetConnectionCount)),
+        ("getnettotals", schema_for!(GetNetTotals)),
+        ("getnetworkinfo", schema_for!(GetNetworkInfo)),
+        ("getpeerinfo", schema_for!(GetPeerInfo)),
+        ("listbanned", schema_for!(ListBanned)),
+        ("setnetworkactive", schema_for!(SetNetworkActive)),
+        ("combinepsbt", schema_for!(CombinePsbt)),
+        ("combinerawtransaction", schema_for!(CombineRawTransaction)),
+        ("converttopsbt", schema_for!(ConvertToPsbt)),
+        ("createpsbt", schema_for!(CreatePsbt)),
+        ("createrawtransaction", schema_for!(CreateRawTransaction)),
+        ("decodepsbt", schema_for!(DecodePsbt)),
+        ("decoderawtransaction", schema_for!(DecodeRawTransaction)),
+        ("decodescript", schema_for!(DecodeScript)),
+        ("finalizepsbt", schema_for!(FinalizePsbt)),
+        ("fundrawtransaction", schema_for!(FundRawTransaction)),
+        ("getrawtransaction", schema_for!(GetRawTransaction)),
+        ("sendrawtransaction", schema_for!(SendRawTransaction)),
+        ("signrawtransaction", schema_for!(SignRawTransaction)),
+        ("signrawtransactionwithkey", schema_for!(SignRawTransaction)),
+        ("testmempoolaccept", schema_for!(TestMempoolAccept)),
+        ("createmultisig", schema_for!(CreateMultisig)),
+        ("estimatesmartfee", schema_for!(EstimateSmartFee)),
+        ("signmessagewithprivkey", schema_for!(SignMessageWithPrivKey)),
+        ("validateaddress", schema_for!(ValidateAddress)),
+        ("verifymessage", schema_for!(VerifyMessage)),
+        ("abortrescan", schema_for!(AbortRescan)),
+        ("addmultisigaddress", schema_for!(AddMultisigAddress)),
+        ("bumpfee", schema_for!(BumpFee)),
+        ("createwallet", schema_for!(CreateWallet)),
+        ("dumpprivkey", schema_for!(DumpPrivKey)),
+        ("dumpwallet", schema_for!(DumpWallet)),
+        ("encryptwallet", schema_for!(EncryptWallet)),
+        ("getaddressesbylabel", schema_for!(GetAddressesByLabel)),
+        ("getaddressinfo", schema_for!(GetAddressInfo)),
+        ("getbalance", schema_for!(GetBalance)),
+        ("getnewaddress", schema_for!(GetNewAddress)),
+        ("getrawchangeaddress", schema_for!(GetRawChangeAddress)),
+        ("getreceivedbyaddress", schema_for!(GetReceivedByAddress)),
+        ("gettransaction", schema_for!(GetTransaction)),
+        ("getunconfirmedbalance", schema_for!(GetUnconfirmedBalance)),
+        ("getwalletinfo", schema_for!(GetWalletInfo)),
+        ("importmulti", schema_for!(ImportMulti)),
+        ("listaddressgroupings", schema_for!(ListAddressGroupings)),
+        ("listlabels", schema_for!(ListLabels)),
+        ("listlockunspent", schema_for!(ListLockUnspent)),
+        ("listreceivedbyaddress", schema_for!(ListReceivedByAddress)),
+        ("listsinceblock", schema_for!(ListSinceBlock)),
+        ("listtransactions", schema_for!(ListTransactions)),
+        ("listunspent", schema_for!(ListUnspent)),
+        ("listwallets", schema_for!(ListWallets)),
+        ("loadwallet", schema_for!(LoadWallet)),
+        ("lockunspent", schema_for!(LockUnspent)),
+        ("rescanblockchain", schema_for!(RescanBlockchain)),
+        ("sendmany", schema_for!(SendMany)),
+        ("sendtoaddress", schema_for!(SendToAddress)),
+        ("settxfee", schema_for!(SetTxFee)),
+        ("signmessage", schema_for!(SignMessage)),
+        ("signrawtransactionwithwallet", schema_for!(SignRawTransaction)),
+        ("walletcreatefundedpsbt", schema_for!(WalletCreateFundedPsbt)),
+        ("walletprocesspsbt", schema_for!(WalletProcessPsbt)),
+        ("getzmqnotifications", schema_for!(GetZmqNotifications)),
+    ]
+}
+
+/// Returns the JSON Schema of the type returned by each method of `v18`, by method name.
+pub fn v18() -> Vec<(&'static str, RootSchema)> {
+    use crate::v18::*;
+
+    vec![
+        ("getbestblockhash", schema_for!(GetBestBlockHash)),
+        ("getblock", schema_for!(GetBlockVerboseZero)),
+        ("getblockchaininfo", schema_for!(GetBlockchainInfo)),
+        ("getblockcount", schema_for!(GetBlockCount)),
+        ("getblockhash", schema_for!(GetBlockHash)),
+        ("getblockheader", schema_for!(GetBlockHeader)),
+        ("getblockstats", schema_for!(GetBlockStats)),
+        ("getchaintips", schema_for!(GetChainTips)),
+        ("getchaintxstats", schema_for!(GetChainTxStats)),
+        ("getdifficulty", schema_for!(GetDifficulty)),
+        ("getmempoolancestors", schema_for!(GetMempoolAncestors)),
+        ("getmempooldescendants", schema_for!(GetMempoolDescendants)),
+        ("getmempoolentry", schema_for!(GetMempoolEntry)),
+        ("getmempoolinfo", schema_for!(GetMempoolInfo)),
+        ("getrawmempool", schema_for!(GetRawMempool)),
+        ("gettxout", schema_for!(GetTxOut)),
+        ("gettxoutsetinfo", schema_for!(GetTxOutSetInfo)),
+        ("pruneblockchain", schema_for!(PruneBlockchain)),
+        ("scantxoutset", schema_for!(ScanTxOutSetStart)),
+        ("verifychain", schema_for!(VerifyChain)),
+        ("verifytxoutproof", schema_for!(VerifyTxOutProof)),
+        ("getmemoryinfo", schema_for!(GetMemoryInfoStats)),
+        ("getrpcinfo", schema_for!(GetRpcInfo)),
+        ("logging", schema_for!(Logging)),
+        ("generate", schema_for!(Generate)),
+        ("generatetoaddress", schema_for!(GenerateToAddress)),
+        ("getblocktemplate", schema_for!(GetBlockTemplate)),
+        ("getmininginfo", schema_for!(GetMiningInfo)),
+        ("getaddednodeinfo", schema_for!(GetAddedNodeInfo)),
+        ("getconnectioncount", schema_for!(GetConnectionCount)),
+        ("getnettotals", schema_for!(GetNetTotals)),
+        ("getnetworkinfo", schema_for!(GetNetworkInfo)),
+        ("getnodeaddresses", schema_for!(GetNodeAddresses)),
+        ("getpeerinfo", schema_for!(GetPeerInfo)),
+        ("listbanned", schema_for!(ListBanned)),
+        ("setnetworkactive", schema_for!(SetNetworkActive)),
+        ("analyzepsbt", schema_for!(AnalyzePsbt)),
+        ("combinepsbt", schema_for!(CombinePsbt)),
+        ("combinerawtransaction", schema_for!(CombineRawTransaction)),
+        ("converttopsbt", schema_for!(ConvertToPsbt)),
+        ("createpsbt", schema_for!(CreatePsbt)),
+        ("createrawtransaction", schema_for!(CreateRawTransaction)),
+        ("decodepsbt", schema_for!(DecodePsbt)),
+        ("decoderawtransaction", schema_for!(DecodeRawTransaction)),
+        ("decodescript", schema_for!(DecodeScript)),
+        ("finalizepsbt", schema_for!(FinalizePsbt)),
+        ("fundrawtransaction", schema_for!(FundRawTransaction)),
+        ("getrawtransaction", schema_for!(GetRawTransaction)),
+        ("joinpsbts", schema_for!(JoinPsbts)),
+        ("sendrawtransaction", schema_for!(SendRawTransaction)),
+        ("signrawtransactionwithkey", schema_for!(SignRawTransaction)),
+        ("testmempoolaccept", schema_for!(TestMempoolAccept)),
+        ("utxoupdatepsbt", schema_for!(UtxoUpdatePsbt)),
+        ("createmultisig", schema_for!(CreateMultisig)),
+        ("deriveaddresses", schema_for!(DeriveAddresses)),
+        ("estimatesmartfee", schema_for!(EstimateSmartFee)),
+        ("getdescriptorinfo", schema_for!(GetDescriptorInfo)),
+        ("signmessagewithprivkey", schema_for!(SignMessageWithPrivKey)),
+        ("validateaddress", schema_for!(ValidateAddress)),
+        ("abortrescan", schema_for!(AbortRescan)),
+        ("addmultisigaddress", schema_for!(AddMultisigAddress)),
+        ("bumpfee", schema_for!(BumpFee)),
+        ("createwallet", schema_for!(CreateWallet)),
+        ("dumpprivkey", schema_for!(DumpPrivKey)),
+        ("dumpwallet", schema_for!(DumpWallet)),
+        ("encryptwallet", schema_for!(EncryptWallet)),
+        ("getaddressesbylabel", schema_for!(GetAddressesByLabel)),
+        ("getaddressinfo", schema_for!(GetAddressInfo)),
+        ("getbalance", schema_for!(GetBalance)),
+        ("getnewaddress", schema_for!(GetNewAddress)),
+        ("getrawchangeaddress", schema_for!(GetRawChangeAddress)),
+        ("getreceivedbyaddress", schema_for!(GetReceivedByAddress)),
+        ("getreceivedbylabel", schema_for!(GetReceivedByLabel)),
+        ("gettransaction", schema_for!(GetTransaction)),
+        ("getunconfirmedbalance", schema_for!(GetUnconfirmedBalance)),
+        ("getwalletinfo", schema_for!(GetWalletInfo)),
+        ("importmulti", schema_for!(ImportMulti)),
+        ("listaddressgroupings", schema_for!(ListAddressGroupings)),
+        ("listlabels", schema_for!(ListLabels)),
+        ("listlockunspent", schema_for!(ListLockUnspent)),
+        ("listreceivedbyaddress", schema_for!(ListReceivedByAddress)),
+        ("listreceivedbylabel", schema_for!(ListReceivedByLabel)),
+        ("listsinceblock", schema_for!(ListSinceBlock)),
+        ("listtransactions", schema_for!(ListTransactions)),
+        ("listunspent", schema_for!(ListUnspent)),
+        ("listwalletdir", schema_for!(ListWalletDir)),
+        ("listwallets", schema_for!(ListWallets)),
+        ("loadwallet", schema_for!(LoadWallet)),
+        ("lockunspent", schema_for!(LockUnspent)),
+        ("rescanblockchain", schema_for!(RescanBlockchain)),
+        ("sendmany", schema_for!(SendMany)),
+        ("sendtoaddress", schema_for!(SendToAddress)),
+        ("settxfee", schema_for!(SetTxFee)),
+        ("signmessage", schema_for!(SignMessage)),
+        ("signrawtransactionwithwallet", schema_for!(SignRawTransaction)),
+        ("walletcreatefundedpsbt", schema_for!(WalletCreateFundedPsbt)),
+        ("walletprocesspsbt", schema_for!(WalletProcessPsbt)),
+        ("getzmqnotifications", schema_for!(GetZmqNotifications)),
+    ]
+}
+
+/// Returns the JSON Schema of the type returned by each method of `v19`, by method name.
+pub fn v19() -> Vec<(&'static str, RootSchema)> {
+    use crate::v19::*;
+
+    vec![
+        ("getbestblockhash", schema_for!(GetBestBlockHash)),
+        ("getblock", schema_for!(GetBlockVerboseZero)),
+        ("getblockchaininfo", schema_for!(GetBlockchainInfo)),
+        ("getblockcount", schema_for!(GetBlockCount)),
+        ("getblockfilter", schema_for!(GetBlockFilter)),
+        ("getblockhash", schema_for!(GetBlockHash)),
+        ("getblockheader", schema_for!(GetBlockHeader)),
+        ("getblockstats", schema_for!(GetBlockStats)),
+        ("getchaintips", schema_for!(GetChainTips)),
+        ("getchaintxstats", schema_for!(GetChainTxStats)),
+        ("getdifficulty", schema_for!(GetDifficulty)),
+        ("getmempoolancestors", schema_for!(GetMempoolAncestors)),
+        ("getmempooldescendants", schema_for!(GetMempoolDescendants)),
+        ("getmempoolentry", schema_for!(GetMempoolEntry)),
+        ("getmempoolinfo", schema_for!(GetMempoolInfo)),
+        ("getrawmempool", schema_for!(GetRawMempool)),
+        ("gettxout", schema_for!(GetTxOut)),
+        ("gettxoutsetinfo", schema_for!(GetTxOutSetInfo)),
+        ("pruneblockchain", schema_for!(PruneBlockchain)),
+        ("scantxoutset", schema_for!(ScanTxOutSetStart)),
+        ("verifychain", schema_for!(VerifyChain)),
+        ("verifytxoutproof", schema_for!(VerifyTxOutProof)),
+        ("getrpcinfo", schema_for!(GetRpcInfo)),
+        ("getmemoryinfo", schema_for!(GetMemoryInfoStats)),
+        ("logging", schema_for!(Logging)),
+        ("generatetoaddress", schema_for!(GenerateToAddress)),
+        ("getblocktemplate", schema_for!(GetBlockTemplate)),
+        ("getmininginfo", schema_for!(GetMiningInfo)),
+        ("getaddednodeinfo", schema_for!(GetAddedNodeInfo)),
+        ("getconnectioncount", schema_for!(GetConnectionCount)),
+        ("getnettotals", schema_for!(GetNetTotals)),
+        ("getnetworkinfo", schema_for!(GetNetworkInfo)),
+        ("getnodeaddresses", schema_for!(GetNodeAddresses)),
+        ("getpeerinfo", schema_for!(GetPeerInfo)),
+        ("listbanned", schema_for!(ListBanned)),
+        ("setnetworkactive", schema_for!(SetNetworkActive)),
+        ("analyzepsbt", schema_for!(AnalyzePsbt)),
+        ("combinepsbt", schema_for!(CombinePsbt)),
+        ("combinerawtransaction", schema_for!(CombineRawTransaction)),
+        ("converttopsbt", schema_for!(ConvertToPsbt)),
+        ("createpsbt", schema_for!(CreatePsbt)),
+        ("createrawtransaction", schema_for!(CreateRawTransaction)),
+        ("decodepsbt", schema_for!(DecodePsbt)),
+        ("decoderawtransaction", schema_for!(DecodeRawTransaction)),
+        ("decodescript", schema_for!(DecodeScript)),
+        ("finalizepsbt", schema_for!(FinalizePsbt)),
+        ("fundrawtransaction", schema_for!(FundRawTransaction)),
+        ("getrawtransaction", schema_for!(GetRawTransaction)),
+        ("joinpsbts", schema_for!(JoinPsbts)),
+        ("sendrawtransaction", schema_for!(SendRawTransaction)),
+        ("signrawtransactionwithkey", schema_for!(SignRawTransaction)),
+        ("testmempoolaccept", schema_for!(TestMempoolAccept)),
+        ("utxoupdatepsbt", schema_for!(UtxoUpdatePsbt)),
+        ("createmultisig", schema_for!(CreateMultisig)),
+        ("deriveaddresses", schema_for!(DeriveAddresses)),
+        ("estimatesmartfee", schema_for!(EstimateSmartFee)),
+        ("getdescriptorinfo", schema_for!(GetDescriptorInfo)),
+        ("signmessagewithprivkey", schema_for!(SignMessageWithPrivKey)),
+        ("validateaddress", schema_for!(ValidateAddress)),
+        ("abortrescan", schema_for!(AbortRescan)),
+        ("addmultisigaddress", schema_for!(AddMultisigAddress)),
+        ("bumpfee", schema_for!(BumpFee)),
+        ("createwallet", schema_for!(CreateWallet)),
+        ("dumpprivkey", schema_for!(DumpPrivKey)),
+        ("dumpwallet", schema_for!(DumpWallet)),
+        ("encryptwallet", schema_for!(EncryptWallet)),
+        ("getaddressesbylabel", schema_for!(GetAddressesByLabel)),
+        ("getaddressinfo", schema_for!(GetAddressInfo)),
+        ("getbalance", schema_for!(GetBalance)),
+        ("getbalances", schema_for!(GetBalances)),
+        ("getnewaddress", schema_for!(GetNewAddress)),
+        ("getrawchangeaddress", schema_for!(GetRawChangeAddress)),
+        ("getreceivedbyaddress", schema_for!(GetReceivedByAddress)),
+        ("getreceivedbylabel", schema_for!(GetReceivedByLabel)),
+        ("gettransaction", schema_for!(GetTransaction)),
+        ("getunconfirmedbalance", schema_for!(GetUnconfirmedBalance)),
+        ("getwalletinfo", schema_for!(GetWalletInfo)),
+        ("importmulti", schema_for!(ImportMulti)),
+        ("listaddressgroupings", schema_for!(ListAddressGroupings)),
+        ("listlabels", schema_for!(ListLabels)),
+        ("listlockunspent", schema_for!(ListLockUnspent)),
+        ("listreceivedbyaddress", schema_for!(ListReceivedByAddress)),
+        ("listreceivedbylabel", schema_for!(ListReceivedByLabel)),
+        ("listsinceblock", schema_for!(ListSinceBlock)),
+        ("listtransactions", schema_for!(ListTransactions)),
+        ("listunspent", schema_for!(ListUnspent)),
+        ("listwalletdir", schema_for!(ListWalletDir)),
+        ("listwallets", schema_for!(ListWallets)),
+        ("loadwallet", schema_for!(LoadWallet)),
+        ("lockunspent", schema_for!(LockUnspent)),
+        ("rescanblockchain", schema_for!(RescanBlockchain)),
+        ("sendmany", schema_for!(SendMany)),
+        ("sendtoaddress", schema_for!(SendToAddress)),
+        ("settxfee", schema_for!(SetTxFee)),
+        ("setwalletflag", schema_for!(SetWalletFlag)),
+        ("signmessage", schema_for!(SignMessage)),
+        ("signrawtransactionwithwallet", schema_for!(SignRawTransaction)),
+        ("walletcreatefundedpsbt", schema_for!(WalletCreateFundedPsbt)),
+        ("walletprocesspsbt", schema_for!(WalletProcessPsbt)),
+        ("getzmqnotifications", schema_for!(GetZmqNotifications)),
+    ]
+}
+
+/// Returns the JSON Schema of the type returned by each method of `v20`, by method name.
+pub fn v20() -> Vec<(&'static str, RootSchema)> {
+    use crate::v20::*;
+
+    vec![
+        ("getbestblockhash", schema_for!(GetBestBlockHash)),
+        ("getblock", schema_for!(GetBlockVerboseZero)),
+        ("getblockchaininfo", schema_for!(GetBlockchainInfo)),
+        ("getblockcount", schema_for!(GetBlockCount)),
+        ("getblockfilter", schema_for!(GetBlockFilter)),
+        ("getblockhash", schema_for!(GetBlockHash)),
+        ("getblockheader", schema_for!(GetBlockHeader)),
+        ("getblockstats", schema_for!(GetBlockStats)),
+        ("getchaintips", schema_for!(GetChainTips)),
+        ("getchaintxstats", schema_for!(GetChainTxStats)),
+        ("getdifficulty", schema_for!(GetDifficulty)),
+        ("getmempoolancestors", schema_for!(GetMempoolAncestors)),
+        ("getmempooldescendants", schema_for!(GetMempoolDescendants)),
+        ("getmempoolentry", schema_for!(GetMempoolEntry)),
+        ("getmempoolinfo", schema_for!(GetMempoolInfo)),
+        ("getrawmempool", schema_for!(GetRawMempool)),
+        ("gettxout", schema_for!(GetTxOut)),
+        ("gettxoutsetinfo", schema_for!(GetTxOutSetInfo)),
+        ("pruneblockchain", schema_for!(PruneBlockchain)),
+        ("scantxoutset", schema_for!(ScanTxOutSetStart)),
+        ("verifychain", schema_for!(VerifyChain)),
+        ("verifytxoutproof", schema_for!(VerifyTxOutProof)),
+        ("getrpcinfo", schema_for!(GetRpcInfo)),
+        ("getmemoryinfo", schema_for!(GetMemoryInfoStats)),
+        ("logging", schema_for!(Logging)),
+        ("generatetoaddress", schema_for!(GenerateToAddress)),
+        ("generatetodescriptor", schema_for!(GenerateToDescriptor)),
+        ("getblocktemplate", schema_for!(GetBlockTemplate)),
+        ("getmininginfo", schema_for!(GetMiningInfo)),
+        ("getaddednodeinfo", schema_for!(GetAddedNodeInfo)),
+        ("getconnectioncount", schema_for!(GetConnectionCount)),
+        ("getnettotals", schema_for!(GetNetTotals)),
+        ("getnetworkinfo", schema_for!(GetNetworkInfo)),
+        ("getnodeaddresses", schema_for!(GetNodeAddresses)),
+        ("getpeerinfo", schema_for!(GetPeerInfo)),
+        ("listbanned", schema_for!(ListBanned)),
+        ("setnetworkactive", schema_for!(SetNetworkActive)),
+        ("analyzepsbt", schema_for!(AnalyzePsbt)),
+        ("combinepsbt", schema_for!(CombinePsbt)),
+        ("combinerawtransaction", schema_for!(CombineRawTransaction)),
+        ("converttopsbt", schema_for!(ConvertToPsbt)),
+        ("createpsbt", schema_for!(CreatePsbt)),
+        ("createrawtransaction", schema_for!(CreateRawTransaction)),
+        ("decodepsbt", schema_for!(DecodePsbt)),
+        ("decoderawtransaction", schema_for!(DecodeRawTransaction)),
+        ("decodescript", schema_for!(DecodeScript)),
+        ("finalizepsbt", schema_for!(FinalizePsbt)),
+        ("fundrawtransaction", schema_for!(FundRawTransaction)),
+        ("getrawtransaction", schema_for!(GetRawTransaction)),
+        ("joinpsbts", schema_for!(JoinPsbts)),
+        ("sendrawtransaction", schema_for!(SendRawTransaction)),
+        ("signrawtransactionwithkey", schema_for!(SignRawTransaction)),
+        ("testmempoolaccept", schema_for!(TestMempoolAccept)),
+        ("utxoupdatepsbt", schema_for!(UtxoUpdatePsbt)),
+        ("createmultisig", schema_for!(CreateMultisig)),
+        ("deriveaddresses", schema_for!(DeriveAddresses)),
+        ("estimatesmartfee", schema_for!(EstimateSmartFee)),
+        ("getdescriptorinfo", schema_for!(GetDescriptorInfo)),
+        ("signmessagewithprivkey", schema_for!(SignMessageWithPrivKey)),
+        ("validateaddress", schema_for!(ValidateAddress)),
+        ("abortrescan", schema_for!(AbortRescan)),
+        ("addmultisigaddress", schema_for!(AddMultisigAddress)),
+        ("bumpfee", schema_for!(BumpFee)),
+        ("createwallet", schema_for!(CreateWallet)),
+        ("dumpprivkey", schema_for!(DumpPrivKey)),
+        ("dumpwallet", schema_for!(DumpWallet)),
+        ("encryptwallet", schema_for!(EncryptWallet)),
+        ("getaddressesbylabel", schema_for!(GetAddressesByLabel)),
+        ("getaddressinfo", schema_for!(GetAddressInfo)),
+        ("getbalance", schema_for!(GetBalance)),
+        ("getbalances", schema_for!(GetBalances)),
+        ("getnewaddress", schema_for!(GetNewAddress)),
+        ("getrawchangeaddress", schema_for!(GetRawChangeAddress)),
+        ("getreceivedbyaddress", schema_for!(GetReceivedByAddress)),
+        ("getreceivedbylabel", schema_for!(GetReceivedByLabel)),
+        ("gettransaction", schema_for!(GetTransaction)),
+        ("getunconfirmedbalance", schema_for!(GetUnconfirmedBalance)),
+        ("getwalletinfo", schema_for!(GetWalletInfo)),
+        ("importmulti", schema_for!(ImportMulti)),
+        ("listaddressgroupings", schema_for!(ListAddressGroupings)),
+        ("listlabels", schema_for!(ListLabels)),
+        ("listlockunspent", schema_for!(ListLockUnspent)),
+        ("listreceivedbyaddress", schema_for!(ListReceivedByAddress)),
+        ("listreceivedbylabel", schema_for!(ListReceivedByLabel)),
+        ("listsinceblock", schema_for!(ListSinceBlock)),
+        ("listtransactions", schema_for!(ListTransactions)),
+        ("listunspent", schema_for!(ListUnspent)),
+        ("listwalletdir", schema_for!(ListWalletDir)),
+        ("listwallets", schema_for!(ListWallets)),
+        ("loadwallet", schema_for!(LoadWallet)),
+        ("lockunspent", schema_for!(LockUnspent)),
+        ("rescanblockchain", schema_for!(RescanBlockchain)),
+        ("sendmany", schema_for!(SendMany)),
+        ("sendtoaddress", schema_for!(SendToAddress)),
+        ("settxfee", schema_for!(SetTxFee)),
+        ("setwalletflag", schema_for!(SetWalletFlag)),
+        ("signmessage", schema_for!(SignMessage)),
+        ("signrawtransactionwithwallet", schema_for!(SignRawTransaction)),
+        ("walletcreatefundedpsbt", schema_for!(WalletCreateFundedPsbt)),
+        ("walletprocesspsbt", schema_for!(WalletProcessPsbt)),
+        ("getzmqnotifications", schema_for!(GetZmqNotifications)),
+    ]
+}
+
+/// Returns the JSON Schema of the type returned by each method of `v21`, by method name.
+pub fn v21() -> Vec<(&'static str, RootSchema)> {
+    use crate::v21::*;
+
+    vec![
+        ("getbestblockhash", schema_for!(GetBestBlockHash)),
+        ("getblock", schema_for!(GetBlockVerboseZero)),
+        ("getblockchaininfo", schema_for!(GetBlockchainInfo)),
+        ("getblockcount", schema_for!(GetBlockCount)),
+        ("getblockfilter", schema_for!(GetBlockFilter)),
+        ("getblockhash", schema_for!(GetBlockHash)),
+        ("getblockheader", schema_for!(GetBlockHeader)),
+        ("getblockstats", schema_for!(GetBlockStats)),
+        ("getchaintips", schema_for!(GetChainTips)),
+        ("getchaintxstats", schema_for!(GetChainTxStats)),
+        ("getdifficulty", schema_for!(GetDifficulty)),
+        ("getmempoolancestors", schema_for!(GetMempoolAncestors)),
+        ("getmempooldescendants", schema_for!(GetMempoolDescendants)),
+        ("getmempoolentry", schema_for!(GetMempoolEntry)),
+        ("getmempoolinfo", schema_for!(GetMempoolInfo)),
+        ("getrawmempool", schema_for!(GetRawMempool)),
+        ("gettxout", schema_for!(GetTxOut)),
+        ("gettxoutsetinfo", schema_for!(GetTxOutSetInfo)),
+        ("pruneblockchain", schema_for!(PruneBlockchain)),
+        ("scantxoutset", schema_for!(ScanTxOutSetStart)),
+        ("verifychain", schema_for!(VerifyChain)),
+        ("verifytxoutproof", schema_for!(VerifyTxOutProof)),
+        ("getmemoryinfo", schema_for!(GetMemoryInfoStats)),
+        ("getrpcinfo", schema_for!(GetRpcInfo)),
+        ("logging", schema_for!(Logging)),
+        ("generateblock", schema_for!(GenerateBlock)),
+        ("generatetoaddress", schema_for!(GenerateToAddress)),
+        ("generatetodescriptor", schema_for!(GenerateToDescriptor)),
+        ("getblocktemplate", schema_for!(GetBlockTemplate)),
+        ("getmininginfo", schema_for!(GetMiningInfo)),
+        ("getaddednodeinfo", schema_for!(GetAddedNodeInfo)),
+        ("getconnectioncount", schema_for!(GetConnectionCount)),
+        ("getnettotals", schema_for!(GetNetTotals)),
+        ("getnetworkinfo", schema_for!(GetNetworkInfo)),
+        ("getnodeaddresses", schema_for!(GetNodeAddresses)),
+        ("getpeerinfo", schema_for!(GetPeerInfo)),
+        ("listbanned", schema_for!(ListBanned)),
+        ("setnetworkactive", schema_for!(SetNetworkActive)),
+        ("analyzepsbt", schema_for!(AnalyzePsbt)),
+        ("combinepsbt", schema_for!(CombinePsbt)),
+        ("combinerawtransaction", schema_for!(CombineRawTransaction)),
+        ("converttopsbt", schema_for!(ConvertToPsbt)),
+        ("createpsbt", schema_for!(CreatePsbt)),
+        ("createrawtransaction", schema_for!(CreateRawTransaction)),
+        ("decodepsbt", schema_for!(DecodePsbt)),
+        ("decoderawtransaction", schema_for!(DecodeRawTransaction)),
+        ("decodescript", schema_for!(DecodeScript)),
+        ("finalizepsbt", schema_for!(FinalizePsbt)),
+        ("fundrawtransaction", schema_for!(FundRawTransaction)),
+        ("getrawtransaction", schema_for!(GetRawTransaction)),
+        ("joinpsbts", schema_for!(JoinPsbts)),
+        ("sendrawtransaction", schema_for!(SendRawTransaction)),
+        ("signrawtransactionwithkey", schema_for!(SignRawTransaction)),
+        ("testmempoolaccept", schema_for!(TestMempoolAccept)),
+        ("utxoupdatepsbt", schema_for!(UtxoUpdatePsbt)),
+        ("createmultisig", schema_for!(CreateMultisig)),
+        ("deriveaddresses", schema_for!(DeriveAddresses)),
+        ("estimatesmartfee", schema_for!(EstimateSmartFee)),
+        ("getdescriptorinfo", schema_for!(GetDescriptorInfo)),
+        ("getindexinfo", schema_for!(GetIndexInfo)),
+        ("signmessagewithprivkey", schema_for!(SignMessageWithPrivKey)),
+        ("validateaddress", schema_for!(ValidateAddress)),
+        ("abortrescan", schema_for!(AbortRescan)),
+        ("addmultisigaddress", schema_for!(AddMultisigAddress)),
+        ("bumpfee", schema_for!(BumpFee)),
+        ("createwallet", schema_for!(CreateWallet)),
+        ("dumpprivkey", schema_for!(DumpPrivKey)),
+        ("dumpwallet", schema_for!(DumpWallet)),
+        ("encryptwallet", schema_for!(EncryptWallet)),
+        ("getaddressesbylabel", schema_for!(GetAddressesByLabel)),
+        ("getaddressinfo", schema_for!(GetAddressInfo)),
+        ("getbalance", schema_for!(GetBalance)),
+        ("getbalances", schema_for!(GetBalances)),
+        ("getnewaddress", schema_for!(GetNewAddress)),
+        ("getrawchangeaddress", schema_for!(GetRawChangeAddress)),
+        ("getreceivedbyaddress", schema_for!(GetReceivedByAddress)),
+        ("getreceivedbylabel", schema_for!(GetReceivedByLabel)),
+        ("gettransaction", schema_for!(GetTransaction)),
+        ("getunconfirmedbalance", schema_for!(GetUnconfirmedBalance)),
+        ("getwalletinfo", schema_for!(GetWalletInfo)),
+        ("importdescriptors", schema_for!(ImportDescriptors)),
+        ("importmulti", schema_for!(ImportMulti)),
+        ("listaddressgroupings", schema_for!(ListAddressGroupings)),
+        ("listlabels", schema_for!(ListLabels)),
+        ("listlockunspent", schema_for!(ListLockUnspent)),
+        ("psbtbumpfee", schema_for!(PsbtBumpFee)),
+        ("listreceivedbyaddress", schema_for!(ListReceivedByAddress)),
+        ("listreceivedbylabel", schema_for!(ListReceivedByLabel)),
+        ("listsinceblock", schema_for!(ListSinceBlock)),
+        ("listtransactions", schema_for!(ListTransactions)),
+        ("listunspent", schema_for!(ListUnspent)),
+        ("listwalletdir", schema_for!(ListWalletDir)),
+        ("listwallets", schema_for!(ListWallets)),
+        ("loadwallet", schema_for!(LoadWallet)),
+        ("lockunspent", schema_for!(LockUnspent)),
+        ("rescanblockchain", schema_for!(RescanBlockchain)),
+        ("send", schema_for!(Send)),
+        ("sendmany", schema_for!(SendMany)),
+        ("sendtoaddress", schema_for!(SendToAddress)),
+        ("settxfee", schema_for!(SetTxFee)),
+        ("setwalletflag", schema_for!(SetWalletFlag)),
+        ("signmessage", schema_for!(SignMessage)),
+        ("signrawtransactionwithwallet", schema_for!(SignRawTransaction)),
+        ("upgradewallet", schema_for!(UpgradeWallet)),
+        ("walletcreatefundedpsbt", schema_for!(WalletCreateFundedPsbt)),
+        ("walletprocesspsbt", schema_for!(WalletProcessPsbt)),
+        ("getzmqnotifications", schema_for!(GetZmqNotifications)),
+    ]
+}
+
+/// Returns the JSON Schema of the type returned by each method of `v22`, by method name.
+pub fn v22() -> Vec<(&'static str, RootSchema)> {
+    use crate::v22::*;
+
+    vec![
+        ("getbestblockhash", schema_for!(GetBestBlockHash)),
+        ("getblock", schema_for!(GetBlockVerboseZero)),
+        ("getblockchaininfo", schema_for!(GetBlockchainInfo)),
+        ("getblockcount", schema_for!(GetBlockCount)),
+        ("getblockfilter", schema_for!(GetBlockFilter)),
+        ("getblockhash", schema_for!(GetBlockHash)),
+        ("getblockheader", schema_for!(GetBlockHeader)),
+        ("getblockstats", schema_for!(GetBlockStats)),
+        ("getchaintips", schema_for!(GetChainTips)),
+        ("getchaintxstats", schema_for!(GetChainTxStats)),
+        ("getdifficulty", schema_for!(GetDifficulty)),
+        ("getmempoolancestors", schema_for!(GetMempoolAncestors)),
+        ("getmempooldescendants", schema_for!(GetMempoolDescendants)),
+        ("getmempoolentry", schema_for!(GetMempoolEntry)),
+        ("getmempoolinfo", schema_for!(GetMempoolInfo)),
+        ("getrawmempool", schema_for!(GetRawMempool)),
+        ("gettxout", schema_for!(GetTxOut)),
+        ("gettxoutsetinfo", schema_for!(GetTxOutSetInfo)),
+        ("pruneblockchain", schema_for!(PruneBlockchain)),
+        ("scantxoutset", schema_for!(ScanTxOutSetStart)),
+        ("verifychain", schema_for!(VerifyChain)),
+        ("verifytxoutproof", schema_for!(VerifyTxOutProof)),
+        ("getmemoryinfo", schema_for!(GetMemoryInfoStats)),
+        ("getrpcinfo", schema_for!(GetRpcInfo)),
+        ("logging", schema_for!(Logging)),
+        ("generateblock", schema_for!(GenerateBlock)),
+        ("generatetoaddress", schema_for!(GenerateToAddress)),
+        ("generatetodescriptor", schema_for!(GenerateToDescriptor)),
+        ("getblocktemplate", schema_for!(GetBlockTemplate)),
+        ("getmininginfo", schema_for!(GetMiningInfo)),
+        ("getaddednodeinfo", schema_for!(GetAddedNodeInfo)),
+        ("getconnectioncount", schema_for!(GetConnectionCount)),
+        ("getnettotals", schema_for!(GetNetTotals)),
+        ("getnetworkinfo", schema_for!(GetNetworkInfo)),
+        ("getnodeaddresses", schema_for!(GetNodeAddresses)),
+        ("getpeerinfo", schema_for!(GetPeerInfo)),
+        ("listbanned", schema_for!(ListBanned)),
+        ("setnetworkactive", schema_for!(SetNetworkActive)),
+        ("analyzepsbt", schema_for!(AnalyzePsbt)),
+        ("combinepsbt", schema_for!(CombinePsbt)),
+        ("combinerawtransaction", schema_for!(CombineRawTransaction)),
+        ("converttopsbt", schema_for!(ConvertToPsbt)),
+        ("createpsbt", schema_for!(CreatePsbt)),
+        ("createrawtransaction", schema_for!(CreateRawTransaction)),
+        ("decodepsbt", schema_for!(DecodePsbt)),
+        ("decoderawtransaction", schema_for!(DecodeRawTransaction)),
+        ("decodescript", schema_for!(DecodeScript)),
+        ("finalizepsbt", schema_for!(FinalizePsbt)),
+        ("fundrawtransaction", schema_for!(FundRawTransaction)),
+        ("getrawtransaction", schema_for!(GetRawTransaction)),
+        ("joinpsbts", schema_for!(JoinPsbts)),
+        ("sendrawtransaction", schema_for!(SendRawTransaction)),
+        ("signrawtransactionwithkey", schema_for!(SignRawTransaction)),
+        ("testmempoolaccept", schema_for!(TestMempoolAccept)),
+        ("utxoupdatepsbt", schema_for!(UtxoUpdatePsbt)),
+        ("enumeratesigners", schema_for!(EnumerateSigners)),
+        ("createmultisig", schema_for!(CreateMultisig)),
+        ("deriveaddresses", schema_for!(DeriveAddresses)),
+        ("estimatesmartfee", schema_for!(EstimateSmartFee)),
+        ("getdescriptorinfo", schema_for!(GetDescriptorInfo)),
+        ("getindexinfo", schema_for!(GetIndexInfo)),
+        ("signmessagewithprivkey", schema_for!(SignMessageWithPrivKey)),
+        ("validateaddress", schema_for!(ValidateAddress)),
+        ("abortrescan", schema_for!(AbortRescan)),
+        ("addmultisigaddress", schema_for!(AddMultisigAddress)),
+        ("bumpfee", schema_for!(BumpFee)),
+        ("createwallet", schema_for!(CreateWallet)),
+        ("dumpprivkey", schema_for!(DumpPrivKey)),
+        ("dumpwallet", schema_for!(DumpWallet)),
+        ("encryptwallet", schema_for!(EncryptWallet)),
+        ("getaddressesbylabel", schema_for!(GetAddressesByLabel)),
+        ("getaddressinfo", schema_for!(GetAddressInfo)),
+        ("getbalance", schema_for!(GetBalance)),
+        ("getbalances", schema_for!(GetBalances)),
+        ("getnewaddress", schema_for!(GetNewAddress)),
+        ("getrawchangeaddress", schema_for!(GetRawChangeAddress)),
+        ("getreceivedbyaddress", schema_for!(GetReceivedByAddress)),
+        ("getreceivedbylabel", schema_for!(GetReceivedByLabel)),
+        ("gettransaction", schema_for!(GetTransaction)),
+        ("getunconfirmedbalance", schema_for!(GetUnconfirmedBalance)),
+        ("getwalletinfo", schema_for!(GetWalletInfo)),
+        ("importdescriptors", schema_for!(ImportDescriptors)),
+        ("importmulti", schema_for!(ImportMulti)),
+        ("listaddressgroupings", schema_for!(ListAddressGroupings)),
+        ("listdescriptors", schema_for!(ListDescriptors)),
+        ("listlabels", schema_for!(ListLabels)),
+        ("listlockunspent", schema_for!(ListLockUnspent)),
+        ("psbtbumpfee", schema_for!(PsbtBumpFee)),
+        ("listreceivedbyaddress", schema_for!(ListReceivedByAddress)),
+        ("listreceivedbylabel", schema_for!(ListReceivedByLabel)),
+        ("listsinceblock", schema_for!(ListSinceBlock)),
+        ("listtransactions", schema_for!(ListTransactions)),
+        ("listunspent", schema_for!(ListUnspent)),
+        ("listwalletdir", schema_for!(ListWalletDir)),
+        ("listwallets", schema_for!(ListWallets)),
+        ("loadwallet", schema_for!(LoadWallet)),
+        ("lockunspent", schema_for!(LockUnspent)),
+        ("rescanblockchain", schema_for!(RescanBlockchain)),
+        ("send", schema_for!(Send)),
+        ("sendmany", schema_for!(SendMany)),
+        ("sendtoaddress", schema_for!(SendToAddress)),
+        ("settxfee", schema_for!(SetTxFee)),
+        ("setwalletflag", schema_for!(SetWalletFlag)),
+        ("signmessage", schema_for!(SignMessage)),
+        ("signrawtransactionwithwallet", schema_for!(SignRawTransaction)),
+        ("upgradewallet", schema_for!(UpgradeWallet)),
+        ("walletcreatefundedpsbt", schema_for!(WalletCreateFundedPsbt)),
+        ("walletdisplayaddress", schema_for!(WalletDisplayAddress)),
+        ("walletprocesspsbt", schema_for!(WalletProcessPsbt)),
+        ("getzmqnotifications", schema_for!(GetZmqNotifications)),
+    ]
+}
+
+/// Returns the JSON Schema of the type returned by each method of `v23`, by method name.
+pub fn v23() -> Vec<(&'static str, RootSchema)> {
+    use crate::v23::*;
+
+    vec![
+        ("getbestblockhash", schema_for!(GetBestBlockHash)),
+        ("getblock", schema_for!(GetBlockVerboseZero)),
+        ("getblockchaininfo", schema_for!(GetBlockchainInfo)),
+        ("getblockcount", schema_for!(GetBlockCount)),
+        ("getblockfilter", schema_for!(GetBlockFilter)),
+        ("getblockhash", schema_for!(GetBlockHash)),
+        ("getblockheader", schema_for!(GetBlockHeader)),
+        ("getblockstats", schema_for!(GetBlockStats)),
+        ("getchaintips", schema_for!(GetChainTips)),
+        ("getchaintxstats", schema_for!(GetChainTxStats)),
+        ("getdeploymentinfo", schema_for!(GetDeploymentInfo)),
+        ("getdifficulty", schema_for!(GetDifficulty)),
+        ("getmempoolancestors", schema_for!(GetMempoolAncestors)),
+        ("getmempooldescendants", schema_for!(GetMempoolDescendants)),
+        ("getmempoolentry", schema_for!(GetMempoolEntry)),
+        ("getmempoolinfo", schema_for!(GetMempoolInfo)),
+        ("getrawmempool", schema_for!(GetRawMempool)),
+        ("gettxout", schema_for!(GetTxOut)),
+        ("gettxoutsetinfo", schema_for!(GetTxOutSetInfo)),
+        ("pruneblockchain", schema_for!(PruneBlockchain)),
+        ("savemempool", schema_for!(SaveMempool)),
+        ("scantxoutset", schema_for!(ScanTxOutSetStart)),
+        ("verifychain", schema_for!(VerifyChain)),
+        ("verifytxoutproof", schema_for!(VerifyTxOutProof)),
+        ("getmemoryinfo", schema_for!(GetMemoryInfoStats)),
+        ("getrpcinfo", schema_for!(GetRpcInfo)),
+        ("logging", schema_for!(Logging)),
+        ("getblocktemplate", schema_for!(GetBlockTemplate)),
+        ("getmininginfo", schema_for!(GetMiningInfo)),
+        ("getaddednodeinfo", schema_for!(GetAddedNodeInfo)),
+        ("getconnectioncount", schema_for!(GetConnectionCount)),
+        ("getnettotals", schema_for!(GetNetTotals)),
+        ("getnetworkinfo", schema_for!(GetNetworkInfo)),
+        ("getnodeaddresses", schema_for!(GetNodeAddresses)),
+        ("getpeerinfo", schema_for!(GetPeerInfo)),
+        ("listbanned", schema_for!(ListBanned)),
+        ("setnetworkactive", schema_for!(SetNetworkActive)),
+        ("analyzepsbt", schema_for!(AnalyzePsbt)),
+        ("combinepsbt", schema_for!(CombinePsbt)),
+        ("combinerawtransaction", schema_for!(CombineRawTransaction)),
+        ("converttopsbt", schema_for!(ConvertToPsbt)),
+        ("createpsbt", schema_for!(CreatePsbt)),
+        ("createrawtransaction", schema_for!(CreateRawTransaction)),
+        ("decodepsbt", schema_for!(DecodePsbt)),
+        ("decoderawtransaction", schema_for!(DecodeRawTransaction)),
+        ("decodescript", schema_for!(DecodeScript)),
+        ("finalizepsbt", schema_for!(FinalizePsbt)),
+        ("fundrawtransaction", schema_for!(FundRawTransaction)),
+        ("getrawtransaction", schema_for!(GetRawTransaction)),
+        ("joinpsbts", schema_for!(JoinPsbts)),
+        ("sendrawtransaction", schema_for!(SendRawTransaction)),
+        ("signrawtransactionwithkey", schema_for!(SignRawTransaction)),
+        ("testmempoolaccept", schema_for!(TestMempoolAccept)),
+        ("utxoupdatepsbt", schema_for!(UtxoUpdatePsbt)),
+        ("createmultisig", schema_for!(CreateMultisig)),
+        ("deriveaddresses", schema_for!(DeriveAddresses)),
+        ("estimatesmartfee", schema_for!(EstimateSmartFee)),
+        ("getdescriptorinfo", schema_for!(GetDescriptorInfo)),
+        ("getindexinfo", schema_for!(GetIndexInfo)),
+        ("signmessagewithprivkey", schema_for!(SignMessageWithPrivKey)),
+        ("validateaddress", schema_for!(ValidateAddress)),
+        ("enumeratesigners", schema_for!(EnumerateSigners)),
+        ("abortrescan", schema_for!(AbortRescan)),
+        ("addmultisigaddress", schema_for!(AddMultisigAddress)),
+        ("bumpfee", schema_for!(BumpFee)),
+        ("createwallet", schema_for!(CreateWallet)),
+        ("dumpprivkey", schema_for!(DumpPrivKey)),
+        ("dumpwallet", schema_for!(DumpWallet)),
+        ("encryptwallet", schema_for!(EncryptWallet)),
+        ("getaddressesbylabel", schema_for!(GetAddressesByLabel)),
+        ("getaddressinfo", schema_for!(GetAddressInfo)),
+        ("getbalance", schema_for!(GetBalance)),
+        ("getbalances", schema_for!(GetBalances)),
+        ("getnewaddress", schema_for!(GetNewAddress)),
+        ("getrawchangeaddress", schema_for!(GetRawChangeAddress)),
+        ("getreceivedbyaddress", schema_for!(GetReceivedByAddress)),
+        ("getreceivedbylabel", schema_for!(GetReceivedByLabel)),
+        ("gettransaction", schema_for!(GetTransaction)),
+        ("getunconfirmedbalance", schema_for!(GetUnconfirmedBalance)),
+        ("getwalletinfo", schema_for!(GetWalletInfo)),
+        ("importdescriptors", schema_for!(ImportDescriptors)),
+        ("importmulti", schema_for!(ImportMulti)),
+        ("listaddressgroupings", schema_for!(ListAddressGroupings)),
+        ("listdescriptors", schema_for!(ListDescriptors)),
+        ("listlabels", schema_for!(ListLabels)),
+        ("listlockunspent", schema_for!(ListLockUnspent)),
+        ("psbtbumpfee", schema_for!(PsbtBumpFee)),
+        ("listreceivedbyaddress", schema_for!(ListReceivedByAddress)),
+        ("listreceivedbylabel", schema_for!(ListReceivedByLabel)),
+        ("listsinceblock", schema_for!(ListSinceBlock)),
+        ("listtransactions", schema_for!(ListTransactions)),
+        ("listunspent", schema_for!(ListUnspent)),
+        ("listwalletdir", schema_for!(ListWalletDir)),
+        ("listwallets", schema_for!(ListWallets)),
+        ("loadwallet", schema_for!(LoadWallet)),
+        ("lockunspent", schema_for!(LockUnspent)),
+        ("rescanblockchain", schema_for!(RescanBlockchain)),
+        ("restorewallet", schema_for!(RestoreWallet)),
+        ("send", schema_for!(Send)),
+        ("sendmany", schema_for!(SendMany)),
+        ("sendtoaddress", schema_for!(SendToAddress)),
+        ("settxfee", schema_for!(SetTxFee)),
+        ("setwalletflag", schema_for!(SetWalletFlag)),
+        ("signmessage", schema_for!(SignMessage)),
+        ("signrawtransactionwithwallet", schema_for!(SignRawTransaction)),
+        ("upgradewallet", schema_for!(UpgradeWallet)),
+        ("walletcreatefundedpsbt", schema_for!(WalletCreateFundedPsbt)),
+        ("walletdisplayaddress", schema_for!(WalletDisplayAddress)),
+        ("walletprocesspsbt", schema_for!(WalletProcessPsbt)),
+        ("getzmqnotifications", schema_for!(GetZmqNotifications)),
+    ]
+}
+
+/// Returns the JSON Schema of the type returned by each method of `v24`, by method name.
+pub fn v24() -> Vec<(&'static str, RootSchema)> {
+    use crate::v24::*;
+
+    vec![
+        ("getbestblockhash", schema_for!(GetBestBlockHash)),
+        ("getblock", schema_for!(GetBlockVerboseZero)),
+        ("getblockchaininfo", schema_for!(GetBlockchainInfo)),
+        ("getblockcount", schema_for!(GetBlockCount)),
+        ("getblockfilter", schema_for!(GetBlockFilter)),
+        ("getblockhash", schema_for!(GetBlockHash)),
+        ("getblockheader", schema_for!(GetBlockHeader)),
+        ("getblockstats", schema_for!(GetBlockStats)),
+        ("getchaintips", schema_for!(GetChainTips)),
+        ("getchaintxstats", schema_for!(GetChainTxStats)),
+        ("getdeploymentinfo", schema_for!(GetDeploymentInfo)),
+        ("getdifficulty", schema_for!(GetDifficulty)),
+        ("getmempoolancestors", schema_for!(GetMempoolAncestors)),
+        ("getmempooldescendants", schema_for!(GetMempoolDescendants)),
+        ("getmempoolentry", schema_for!(GetMempoolEntry)),
+        ("getmempoolinfo", schema_for!(GetMempoolInfo)),
+        ("getrawmempool", schema_for!(GetRawMempool)),
+        ("gettxout", schema_for!(GetTxOut)),
+        ("gettxoutsetinfo", schema_for!(GetTxOutSetInfo)),
+        ("gettxspendingprevout", schema_for!(GetTxSpendingPrevout)),
+        ("pruneblockchain", schema_for!(PruneBlockchain)),
+        ("savemempool", schema_for!(SaveMempool)),
+        ("scantxoutset", schema_for!(ScanTxOutSetStart)),
+        ("verifychain", schema_for!(VerifyChain)),
+        ("verifytxoutproof", schema_for!(VerifyTxOutProof)),
+        ("getmemoryinfo", schema_for!(GetMemoryInfoStats)),
+        ("getrpcinfo", schema_for!(GetRpcInfo)),
+        ("logging", schema_for!(Logging)),
+        ("getblocktemplate", schema_for!(GetBlockTemplate)),
+        ("getmininginfo", schema_for!(GetMiningInfo)),
+        ("getaddednodeinfo", schema_for!(GetAddedNodeInfo)),
+        ("getconnectioncount", schema_for!(GetConnectionCount)),
+        ("getnettotals", schema_for!(GetNetTotals)),
+        ("getnetworkinfo", schema_for!(GetNetworkInfo)),
+        ("getnodeaddresses", schema_for!(GetNodeAddresses)),
+        ("getpeerinfo", schema_for!(GetPeerInfo)),
+        ("listbanned", schema_for!(ListBanned)),
+        ("setnetworkactive", schema_for!(SetNetworkActive)),
+        ("analyzepsbt", schema_for!(AnalyzePsbt)),
+        ("combinepsbt", schema_for!(CombinePsbt)),
+        ("combinerawtransaction", schema_for!(CombineRawTransaction)),
+        ("converttopsbt", schema_for!(ConvertToPsbt)),
+        ("createpsbt", schema_for!(CreatePsbt)),
+        ("createrawtransaction", schema_for!(CreateRawTransaction)),
+        ("decodepsbt", schema_for!(DecodePsbt)),
+        ("decoderawtransaction", schema_for!(DecodeRawTransaction)),
+        ("decodescript", schema_for!(DecodeScript)),
+        ("finalizepsbt", schema_for!(FinalizePsbt)),
+        ("fundrawtransaction", schema_for!(FundRawTransaction)),
+        ("getrawtransaction", schema_for!(GetRawTransaction)),
+        ("joinpsbts", schema_for!(JoinPsbts)),
+        ("sendrawtransaction", schema_for!(SendRawTransaction)),
+        ("signrawtransactionwithkey", schema_for!(SignRawTransaction)),
+        ("testmempoolaccept", schema_for!(TestMempoolAccept)),
+        ("utxoupdatepsbt", schema_for!(UtxoUpdatePsbt)),
+        ("createmultisig", schema_for!(CreateMultisig)),
+        ("deriveaddresses", schema_for!(DeriveAddresses)),
+        ("estimatesmartfee", schema_for!(EstimateSmartFee)),
+        ("getdescriptorinfo", schema_for!(GetDescriptorInfo)),
+        ("getindexinfo", schema_for!(GetIndexInfo)),
+        ("signmessagewithprivkey", schema_for!(SignMessageWithPrivKey)),
+        ("validateaddress", schema_for!(ValidateAddress)),
+        ("enumeratesigners", schema_for!(EnumerateSigners)),
+        ("abortrescan", schema_for!(AbortRescan)),
+        ("addmultisigaddress", schema_for!(AddMultisigAddress)),
+        ("bumpfee", schema_for!(BumpFee)),
+        ("createwallet", schema_for!(CreateWallet)),
+        ("dumpprivkey", schema_for!(DumpPrivKey)),
+        ("dumpwallet", schema_for!(DumpWallet)),
+        ("encryptwallet", schema_for!(EncryptWallet)),
+        ("getaddressesbylabel", schema_for!(GetAddressesByLabel)),
+        ("getaddressinfo", schema_for!(GetAddressInfo)),
+        ("getbalance", schema_for!(GetBalance)),
+        ("getbalances", schema_for!(GetBalances)),
+        ("getnewaddress", schema_for!(GetNewAddress)),
+        ("getrawchangeaddress", schema_for!(GetRawChangeAddress)),
+        ("getreceivedbyaddress", schema_for!(GetReceivedByAddress)),
+        ("getreceivedbylabel", schema_for!(GetReceivedByLabel)),
+        ("gettransaction", schema_for!(GetTransaction)),
+        ("getunconfirmedbalance", schema_for!(GetUnconfirmedBalance)),
+        ("getwalletinfo", schema_for!(GetWalletInfo)),
+        ("importdescriptors", schema_for!(ImportDescriptors)),
+        ("importmulti", schema_for!(ImportMulti)),
+        ("listaddressgroupings", schema_for!(ListAddressGroupings)),
+        ("listdescriptors", schema_for!(ListDescriptors)),
+        ("listlabels", schema_for!(ListLabels)),
+        ("listlockunspent", schema_for!(ListLockUnspent)),
+        ("migratewallet", schema_for!(MigrateWallet)),
+        ("psbtbumpfee", schema_for!(PsbtBumpFee)),
+        ("listreceivedbyaddress", schema_for!(ListReceivedByAddress)),
+        ("listreceivedbylabel", schema_for!(ListReceivedByLabel)),
+        ("listsinceblock", schema_for!(ListSinceBlock)),
+        ("listtransactions", schema_for!(ListTransactions)),
+        ("listunspent", schema_for!(ListUnspent)),
+        ("listwalletdir", schema_for!(ListWalletDir)),
+        ("listwallets", schema_for!(ListWallets)),
+        ("loadwallet", schema_for!(LoadWallet)),
+        ("lockunspent", schema_for!(LockUnspent)),
+        ("rescanblockchain", schema_for!(RescanBlockchain)),
+        ("restorewallet", schema_for!(RestoreWallet)),
+        ("send", schema_for!(Send)),
+        ("sendall", schema_for!(SendAll)),
+        ("sendmany", schema_for!(SendMany)),
+        ("sendtoaddress", schema_for!(SendToAddress)),
+        ("settxfee", schema_for!(SetTxFee)),
+        ("setwalletflag", schema_for!(SetWalletFlag)),
+        ("signmessage", schema_for!(SignMessage)),
+        ("signrawtransactionwithwallet", schema_for!(SignRawTransaction)),
+        ("simulaterawtransaction", schema_for!(SimulateRawTransaction)),
+        ("upgradewallet", schema_for!(UpgradeWallet)),
+        ("walletcreatefundedpsbt", schema_for!(WalletCreateFundedPsbt)),
+        ("walletdisplayaddress", schema_for!(WalletDisplayAddress)),
+        ("walletprocesspsbt", schema_for!(WalletProcessPsbt)),
+        ("getzmqnotifications", schema_for!(GetZmqNotifications)),
+    ]
+}
+
+/// Returns the JSON Schema of the type returned by each method of `v25`, by method name.
+pub fn v25() -> Vec<(&'static str, RootSchema)> {
+    use crate::v25::*;
+
+    vec![
+        ("getbestblockhash", schema_for!(GetBestBlockHash)),
+        ("getblock", schema_for!(GetBlockVerboseZero)),
+        ("getblockchaininfo", schema_for!(GetBlockchainInfo)),
+        ("getblockcount", schema_for!(GetBlockCount)),
+        ("getblockfilter", schema_for!(GetBlockFilter)),
+        ("getblockhash", schema_for!(GetBlockHash)),
+        ("getblockheader", schema_for!(GetBlockHeader)),
+        ("getblockstats", schema_for!(GetBlockStats)),
+        ("getchaintips", schema_for!(GetChainTips)),
+        ("getchaintxstats", schema_for!(GetChainTxStats)),
+        ("getdeploymentinfo", schema_for!(GetDeploymentInfo)),
+        ("getdifficulty", schema_for!(GetDifficulty)),
+        ("getmempoolancestors", schema_for!(GetMempoolAncestors)),
+        ("getmempooldescendants", schema_for!(GetMempoolDescendants)),
+        ("getmempoolentry", schema_for!(GetMempoolEntry)),
+        ("getmempoolinfo", schema_for!(GetMempoolInfo)),
+        ("getrawmempool", schema_for!(GetRawMempool)),
+        ("gettxout", schema_for!(GetTxOut)),
+        ("gettxoutsetinfo", schema_for!(GetTxOutSetInfo)),
+        ("gettxspendingprevout", schema_for!(GetTxSpendingPrevout)),
+        ("pruneblockchain", schema_for!(PruneBlockchain)),
+        ("savemempool", schema_for!(SaveMempool)),
+        ("scanblocks", schema_for!(ScanBlocksStart)),
+        ("scantxoutset", schema_for!(ScanTxOutSetStart)),
+        ("verifychain", schema_for!(VerifyChain)),
+        ("verifytxoutproof", schema_for!(VerifyTxOutProof)),
+        ("getmemoryinfo", schema_for!(GetMemoryInfoStats)),
+        ("getrpcinfo", schema_for!(GetRpcInfo)),
+        ("logging", schema_for!(Logging)),
+        ("getblocktemplate", schema_for!(GetBlockTemplate)),
+        ("getmininginfo", schema_for!(GetMiningInfo)),
+        ("getaddednodeinfo", schema_for!(GetAddedNodeInfo)),
+        ("getconnectioncount", schema_for!(GetConnectionCount)),
+        ("getnettotals", schema_for!(GetNetTotals)),
+        ("getnetworkinfo", schema_for!(GetNetworkInfo)),
+        ("getnodeaddresses", schema_for!(GetNodeAddresses)),
+        ("getpeerinfo", schema_for!(GetPeerInfo)),
+        ("listbanned", schema_for!(ListBanned)),
+        ("setnetworkactive", schema_for!(SetNetworkActive)),
+        ("analyzepsbt", schema_for!(AnalyzePsbt)),
+        ("combinepsbt", schema_for!(CombinePsbt)),
+        ("combinerawtransaction", schema_for!(CombineRawTransaction)),
+        ("converttopsbt", schema_for!(ConvertToPsbt)),
+        ("createpsbt", schema_for!(CreatePsbt)),
+        ("createrawtransaction", schema_for!(CreateRawTransaction)),
+        ("decodepsbt", schema_for!(DecodePsbt)),
+        ("decoderawtransaction", schema_for!(DecodeRawTransaction)),
+        ("decodescript", schema_for!(DecodeScript)),
+        ("finalizepsbt", schema_for!(FinalizePsbt)),
+        ("fundrawtransaction", schema_for!(FundRawTransaction)),
+        ("getrawtransaction", schema_for!(GetRawTransaction)),
+        ("joinpsbts", schema_for!(JoinPsbts)),
+        ("sendrawtransaction", schema_for!(SendRawTransaction)),
+        ("signrawtransactionwithkey", schema_for!(SignRawTransaction)),
+        ("testmempoolaccept", schema_for!(TestMempoolAccept)),
+        ("utxoupdatepsbt", schema_for!(UtxoUpdatePsbt)),
+        ("createmultisig", schema_for!(CreateMultisig)),
+        ("deriveaddresses", schema_for!(DeriveAddresses)),
+        ("estimatesmartfee", schema_for!(EstimateSmartFee)),
+        ("getdescriptorinfo", schema_for!(GetDescriptorInfo)),
+        ("getindexinfo", schema_for!(GetIndexInfo)),
+        ("signmessagewithprivkey", schema_for!(SignMessageWithPrivKey)),
+        ("validateaddress", schema_for!(ValidateAddress)),
+        ("enumeratesigners", schema_for!(EnumerateSigners)),
+        ("abortrescan", schema_for!(AbortRescan)),
+        ("addmultisigaddress", schema_for!(AddMultisigAddress)),
+        ("bumpfee", schema_for!(BumpFee)),
+        ("createwallet", schema_for!(CreateWallet)),
+        ("dumpprivkey", schema_for!(DumpPrivKey)),
+        ("dumpwallet", schema_for!(DumpWallet)),
+        ("encryptwallet", schema_for!(EncryptWallet)),
+        ("getaddressesbylabel", schema_for!(GetAddressesByLabel)),
+        ("getaddressinfo", schema_for!(GetAddressInfo)),
+        ("getbalance", schema_for!(GetBalance)),
+        ("getbalances", schema_for!(GetBalances)),
+        ("getnewaddress", schema_for!(GetNewAddress)),
+        ("getrawchangeaddress", schema_for!(GetRawChangeAddress)),
+        ("getreceivedbyaddress", schema_for!(GetReceivedByAddress)),
+        ("getreceivedbylabel", schema_for!(GetReceivedByLabel)),
+        ("gettransaction", schema_for!(GetTransaction)),
+        ("getunconfirmedbalance", schema_for!(GetUnconfirmedBalance)),
+        ("getwalletinfo", schema_for!(GetWalletInfo)),
+        ("importdescriptors", schema_for!(ImportDescriptors)),
+        ("importmulti", schema_for!(ImportMulti)),
+        ("listaddressgroupings", schema_for!(ListAddressGroupings)),
+        ("listdescriptors", schema_for!(ListDescriptors)),
+        ("listlabels", schema_for!(ListLabels)),
+        ("listlockunspent", schema_for!(ListLockUnspent)),
+        ("migratewallet", schema_for!(MigrateWallet)),
+        ("psbtbumpfee", schema_for!(PsbtBumpFee)),
+        ("listreceivedbyaddress", schema_for!(ListReceivedByAddress)),
+        ("listreceivedbylabel", schema_for!(ListReceivedByLabel)),
+        ("listsinceblock", schema_for!(ListSinceBlock)),
+        ("listtransactions", schema_for!(ListTransactions)),
+        ("listunspent", schema_for!(ListUnspent)),
+        ("listwalletdir", schema_for!(ListWalletDir)),
+        ("listwallets", schema_for!(ListWallets)),
+        ("loadwallet", schema_for!(LoadWallet)),
+        ("lockunspent", schema_for!(LockUnspent)),
+        ("rescanblockchain", schema_for!(RescanBlockchain)),
+        ("restorewallet", schema_for!(RestoreWallet)),
+        ("send", schema_for!(Send)),
+        ("sendall", schema_for!(SendAll)),
+        ("sendmany", schema_for!(SendMany)),
+        ("sendtoaddress", schema_for!(SendToAddress)),
+        ("settxfee", schema_for!(SetTxFee)),
+        ("setwalletflag", schema_for!(SetWalletFlag)),
+        ("signmessage", schema_for!(SignMessage)),
+        ("signrawtransactionwithwallet", schema_for!(SignRawTransaction)),
+        ("simulaterawtransaction", schema_for!(SimulateRawTransaction)),
+        ("upgradewallet", schema_for!(UpgradeWallet)),
+        ("walletcreatefundedpsbt", schema_for!(WalletCreateFundedPsbt)),
+        ("walletdisplayaddress", schema_for!(WalletDisplayAddress)),
+        ("walletprocesspsbt", schema_for!(WalletProcessPsbt)),
+        ("getzmqnotifications", schema_for!(GetZmqNotifications)),
+    ]
+}
+
+/// Returns the JSON Schema of the type returned by each method of `v26`, by method name.
+pub fn v26() -> Vec<(&'static str, RootSchema)> {
+    use crate::v26::*;
+
+    vec![
+        ("dumptxoutset", schema_for!(DumpTxOutSet)),
+        ("getbestblockhash", schema_for!(GetBestBlockHash)),
+        ("getblock", schema_for!(GetBlockVerboseZero)),
+        ("getblockchaininfo", schema_for!(GetBlockchainInfo)),
+        ("getblockcount", schema_for!(GetBlockCount)),
+        ("getblockfilter", schema_for!(GetBlockFilter)),
+        ("getblockhash", schema_for!(GetBlockHash)),
+        ("getblockheader", schema_for!(GetBlockHeader)),
+        ("getblockstats", schema_for!(GetBlockStats)),
+        ("getchainstates", schema_for!(GetChainStates)),
+        ("getchaintips", schema_for!(GetChainTips)),
+        ("getchaintxstats", schema_for!(GetChainTxStats)),
+        ("getdeploymentinfo", schema_for!(GetDeploymentInfo)),
+        ("getdifficulty", schema_for!(GetDifficulty)),
+        ("getmempoolancestors", schema_for!(GetMempoolAncestors)),
+        ("getmempooldescendants", schema_for!(GetMempoolDescendants)),
+        ("getmempoolentry", schema_for!(GetMempoolEntry)),
+        ("getmempoolinfo", schema_for!(GetMempoolInfo)),
+        ("getrawmempool", schema_for!(GetRawMempool)),
+        ("gettxout", schema_for!(GetTxOut)),
+        ("gettxoutsetinfo", schema_for!(GetTxOutSetInfo)),
+        ("gettxspendingprevout", schema_for!(GetTxSpendingPrevout)),
+        ("loadtxoutset", schema_for!(LoadTxOutSet)),
+        ("pruneblockchain", schema_for!(PruneBlockchain)),
+        ("savemempool", schema_for!(SaveMempool)),
+        ("scanblocks", schema_for!(ScanBlocksStart)),
+        ("scantxoutset", schema_for!(ScanTxOutSetStart)),
+        ("verifychain", schema_for!(VerifyChain)),
+        ("verifytxoutproof", schema_for!(VerifyTxOutProof)),
+        ("getmemoryinfo", schema_for!(GetMemoryInfoStats)),
+        ("getrpcinfo", schema_for!(GetRpcInfo)),
+        ("logging", schema_for!(Logging)),
+        ("getblocktemplate", schema_for!(GetBlockTemplate)),
+        ("getmininginfo", schema_for!(GetMiningInfo)),
+        ("getprioritisedtransactions", schema_for!(GetPrioritisedTransactions)),
+        ("getaddednodeinfo", schema_for!(GetAddedNodeInfo)),
+        ("getaddrmaninfo", schema_for!(GetAddrManInfo)),
+        ("getconnectioncount", schema_for!(GetConnectionCount)),
+        ("getnettotals", schema_for!(GetNetTotals)),
+        ("getnetworkinfo", schema_for!(GetNetworkInfo)),
+        ("getnodeaddresses", schema_for!(GetNodeAddresses)),
+        ("getpeerinfo", schema_for!(GetPeerInfo)),
+        ("listbanned", schema_for!(ListBanned)),
+        ("setnetworkactive", schema_for!(SetNetworkActive)),
+        ("analyzepsbt", schema_for!(AnalyzePsbt)),
+        ("combinepsbt", schema_for!(CombinePsbt)),
+        ("combinerawtransaction", schema_for!(CombineRawTransaction)),
+        ("converttopsbt", schema_for!(ConvertToPsbt)),
+        ("createpsbt", schema_for!(CreatePsbt)),
+        ("createrawtransaction", schema_for!(CreateRawTransaction)),
+        ("decodepsbt", schema_for!(DecodePsbt)),
+        ("decoderawtransaction", schema_for!(DecodeRawTransaction)),
+        ("decodescript", schema_for!(DecodeScript)),
+        ("descriptorprocesspsbt", schema_for!(DescriptorProcessPsbt)),
+        ("finalizepsbt", schema_for!(FinalizePsbt)),
+        ("fundrawtransaction", schema_for!(FundRawTransaction)),
+        ("getrawtransaction", schema_for!(GetRawTransaction)),
+        ("joinpsbts", schema_for!(JoinPsbts)),
+        ("sendrawtransaction", schema_for!(SendRawTransaction)),
+        ("signrawtransactionwithkey", schema_for!(SignRawTransaction)),
+        ("submitpackage", schema_for!(SubmitPackage)),
+        ("testmempoolaccept", schema_for!(TestMempoolAccept)),
+        ("utxoupdatepsbt", schema_for!(UtxoUpdatePsbt)),
+        ("createmultisig", schema_for!(CreateMultisig)),
+        ("deriveaddresses", schema_for!(DeriveAddresses)),
+        ("estimatesmartfee", schema_for!(EstimateSmartFee)),
+        ("getdescriptorinfo", schema_for!(GetDescriptorInfo)),
+        ("getindexinfo", schema_for!(GetIndexInfo)),
+        ("signmessagewithprivkey", schema_for!(SignMessageWithPrivKey)),
+        ("validateaddress", schema_for!(ValidateAddress)),
+        ("enumeratesigners", schema_for!(EnumerateSigners)),
+        ("abortrescan", schema_for!(AbortRescan)),
+        ("addmultisigaddress", schema_for!(AddMultisigAddress)),
+        ("bumpfee", schema_for!(BumpFee)),
+        ("createwallet", schema_for!(CreateWallet)),
+        ("dumpprivkey", schema_for!(DumpPrivKey)),
+        ("dumpwallet", schema_for!(DumpWallet)),
+        ("encryptwallet", schema_for!(EncryptWallet)),
+        ("getaddressesbylabel", schema_for!(GetAddressesByLabel)),
+        ("getaddressinfo", schema_for!(GetAddressInfo)),
+        ("getbalance", schema_for!(GetBalance)),
+        ("getbalances", schema_for!(GetBalances)),
+        ("getnewaddress", schema_for!(GetNewAddress)),
+        ("getrawchangeaddress", schema_for!(GetRawChangeAddress)),
+        ("getreceivedbyaddress", schema_for!(GetReceivedByAddress)),
+        ("getreceivedbylabel", schema_for!(GetReceivedByLabel)),
+        ("gettransaction", schema_for!(GetTransaction)),
+        ("getunconfirmedbalance", schema_for!(GetUnconfirmedBalance)),
+        ("getwalletinfo", schema_for!(GetWalletInfo)),
+        ("importdescriptors", schema_for!(ImportDescriptors)),
+        ("importmulti", schema_for!(ImportMulti)),
+        ("listaddressgroupings", schema_for!(ListAddressGroupings)),
+        ("listdescriptors", schema_for!(ListDescriptors)),
+        ("listlabels", schema_for!(ListLabels)),
+        ("listlockunspent", schema_for!(ListLockUnspent)),
+        ("migratewallet", schema_for!(MigrateWallet)),
+        ("psbtbumpfee", schema_for!(PsbtBumpFee)),
+        ("listreceivedbyaddress", schema_for!(ListReceivedByAddress)),
+        ("listreceivedbylabel", schema_for!(ListReceivedByLabel)),
+        ("listsinceblock", schema_for!(ListSinceBlock)),
+        ("listtransactions", schema_for!(ListTransactions)),
+        ("listunspent", schema_for!(ListUnspent)),
+        ("listwalletdir", schema_for!(ListWalletDir)),
+        ("listwallets", schema_for!(ListWallets)),
+        ("loadwallet", schema_for!(LoadWallet)),
+        ("lockunspent", schema_for!(LockUnspent)),
+        ("rescanblockchain", schema_for!(RescanBlockchain)),
+        ("restorewallet", schema_for!(RestoreWallet)),
+        ("send", schema_for!(Send)),
+        ("sendall", schema_for!(SendAll)),
+        ("sendmany", schema_for!(SendMany)),
+        ("sendtoaddress", schema_for!(SendToAddress)),
+        ("settxfee", schema_for!(SetTxFee)),
+        ("setwalletflag", schema_for!(SetWalletFlag)),
+        ("signmessage", schema_for!(SignMessage)),
+        ("signrawtransactionwithwallet", schema_for!(SignRawTransaction)),
+        ("simulaterawtransaction", schema_for!(SimulateRawTransaction)),
+        ("upgradewallet", schema_for!(UpgradeWallet)),
+        ("walletcreatefundedpsbt", schema_for!(WalletCreateFundedPsbt)),
+        ("walletdisplayaddress", schema_for!(WalletDisplayAddress)),
+        ("walletprocesspsbt", schema_for!(WalletProcessPsbt)),
+        ("getzmqnotifications", schema_for!(GetZmqNotifications)),
+    ]
+}
+
+/// Returns the JSON Schema of the type returned by each method of `v27`, by method name.
+pub fn v27() -> Vec<(&'static str, RootSchema)> {
+    use crate::v27::*;
+
+    vec![
+        ("dumptxoutset", schema_for!(DumpTxOutSet)),
+        ("getbestblockhash", schema_for!(GetBestBlockHash)),
+        ("getblock", schema_for!(GetBlockVerboseZero)),
+        ("getblockchaininfo", schema_for!(GetBlockchainInfo)),
+        ("getblockcount", schema_for!(GetBlockCount)),
+        ("getblockfilter", schema_for!(GetBlockFilter)),
+        ("getblockhash", schema_for!(GetBlockHash)),
+        ("getblockheader", schema_for!(GetBlockHeader)),
+        ("getblockstats", schema_for!(GetBlockStats)),
+        ("getchainstates", schema_for!(GetChainStates)),
+        ("getchaintips", schema_for!(GetChainTips)),
+        ("getchaintxstats", schema_for!(GetChainTxStats)),
+        ("getdeploymentinfo", schema_for!(GetDeploymentInfo)),
+        ("getdifficulty", schema_for!(GetDifficulty)),
+        ("getmempoolancestors", schema_for!(GetMempoolAncestors)),
+        ("getmempooldescendants", schema_for!(GetMempoolDescendants)),
+        ("getmempoolentry", schema_for!(GetMempoolEntry)),
+        ("getmempoolinfo", schema_for!(GetMempoolInfo)),
+        ("getrawmempool", schema_for!(GetRawMempool)),
+        ("gettxout", schema_for!(GetTxOut)),
+        ("gettxoutsetinfo", schema_for!(GetTxOutSetInfo)),
+        ("gettxspendingprevout", schema_for!(GetTxSpendingPrevout)),
+        ("loadtxoutset", schema_for!(LoadTxOutSet)),
+        ("pruneblockchain", schema_for!(PruneBlockchain)),
+        ("savemempool", schema_for!(SaveMempool)),
+        ("scanblocks", schema_for!(ScanBlocksStart)),
+        ("scantxoutset", schema_for!(ScanTxOutSetStart)),
+        ("verifychain", schema_for!(VerifyChain)),
+        ("verifytxoutproof", schema_for!(VerifyTxOutProof)),
+        ("getrpcinfo", schema_for!(GetRpcInfo)),
+        ("getmemoryinfo", schema_for!(GetMemoryInfoStats)),
+        ("logging", schema_for!(Logging)),
+        ("getblocktemplate", schema_for!(GetBlockTemplate)),
+        ("getmininginfo", schema_for!(GetMiningInfo)),
+        ("getprioritisedtransactions", schema_for!(GetPrioritisedTransactions)),
+        ("getaddednodeinfo", schema_for!(GetAddedNodeInfo)),
+        ("getaddrmaninfo", schema_for!(GetAddrManInfo)),
+        ("getconnectioncount", schema_for!(GetConnectionCount)),
+        ("getnettotals", schema_for!(GetNetTotals)),
+        ("getnetworkinfo", schema_for!(GetNetworkInfo)),
+        ("getnodeaddresses", schema_for!(GetNodeAddresses)),
+        ("getpeerinfo", schema_for!(GetPeerInfo)),
+        ("listbanned", schema_for!(ListBanned)),
+        ("setnetworkactive", schema_for!(SetNetworkActive)),
+        ("analyzepsbt", schema_for!(AnalyzePsbt)),
+        ("combinepsbt", schema_for!(CombinePsbt)),
+        ("combinerawtransaction", schema_for!(CombineRawTransaction)),
+        ("converttopsbt", schema_for!(ConvertToPsbt)),
+        ("createpsbt", schema_for!(CreatePsbt)),
+        ("createrawtransaction", schema_for!(CreateRawTransaction)),
+        ("decodepsbt", schema_for!(DecodePsbt)),
+        ("decoderawtransaction", schema_for!(DecodeRawTransaction)),
+        ("decodescript", schema_for!(DecodeScript)),
+        ("descriptorprocesspsbt", schema_for!(DescriptorProcessPsbt)),
+        ("finalizepsbt", schema_for!(FinalizePsbt)),
+        ("fundrawtransaction", schema_for!(FundRawTransaction)),
+        ("getrawtransaction", schema_for!(GetRawTransaction)),
+        ("joinpsbts", schema_for!(JoinPsbts)),
+        ("sendrawtransaction", schema_for!(SendRawTransaction)),
+        ("signrawtransactionwithkey", schema_for!(SignRawTransaction)),
+        ("submitpackage", schema_for!(SubmitPackage)),
+        ("testmempoolaccept", schema_for!(TestMempoolAccept)),
+        ("utxoupdatepsbt", schema_for!(UtxoUpdatePsbt)),
+        ("createmultisig", schema_for!(CreateMultisig)),
+        ("deriveaddresses", schema_for!(DeriveAddresses)),
+        ("estimatesmartfee", schema_for!(EstimateSmartFee)),
+        ("getdescriptorinfo", schema_for!(GetDescriptorInfo)),
+        ("getindexinfo", schema_for!(GetIndexInfo)),
+        ("signmessagewithprivkey", schema_for!(SignMessageWithPrivKey)),
+        ("validateaddress", schema_for!(ValidateAddress)),
+        ("enumeratesigners", schema_for!(EnumerateSigners)),
+        ("abortrescan", schema_for!(AbortRescan)),
+        ("addmultisigaddress", schema_for!(AddMultisigAddress)),
+        ("bumpfee", schema_for!(BumpFee)),
+        ("createwallet", schema_for!(CreateWallet)),
+        ("dumpprivkey", schema_for!(DumpPrivKey)),
+        ("dumpwallet", schema_for!(DumpWallet)),
+        ("encryptwallet", schema_for!(EncryptWallet)),
+        ("getaddressesbylabel", schema_for!(GetAddressesByLabel)),
+        ("getaddressinfo", schema_for!(GetAddressInfo)),
+        ("getbalance", schema_for!(GetBalance)),
+        ("getbalances", schema_for!(GetBalances)),
+        ("getnewaddress", schema_for!(GetNewAddress)),
+        ("getrawchangeaddress", schema_for!(GetRawChangeAddress)),
+        ("getreceivedbyaddress", schema_for!(GetReceivedByAddress)),
+        ("getreceivedbylabel", schema_for!(GetReceivedByLabel)),
+        ("gettransaction", schema_for!(GetTransaction)),
+        ("getunconfirmedbalance", schema_for!(GetUnconfirmedBalance)),
+        ("getwalletinfo", schema_for!(GetWalletInfo)),
+        ("importdescriptors", schema_for!(ImportDescriptors)),
+        ("importmulti", schema_for!(ImportMulti)),
+        ("listaddressgroupings", schema_for!(ListAddressGroupings)),
+        ("listdescriptors", schema_for!(ListDescriptors)),
+        ("listlabels", schema_for!(ListLabels)),
+        ("listlockunspent", schema_for!(ListLockUnspent)),
+        ("migratewallet", schema_for!(MigrateWallet)),
+        ("psbtbumpfee", schema_for!(PsbtBumpFee)),
+        ("listreceivedbyaddress", schema_for!(ListReceivedByAddress)),
+        ("listreceivedbylabel", schema_for!(ListReceivedByLabel)),
+        ("listsinceblock", schema_for!(ListSinceBlock)),
+        ("listtransactions", schema_for!(ListTransactions)),
+        ("listunspent", schema_for!(ListUnspent)),
+        ("listwalletdir", schema_for!(ListWalletDir)),
+        ("listwallets", schema_for!(ListWallets)),
+        ("loadwallet", schema_for!(LoadWallet)),
+        ("lockunspent", schema_for!(LockUnspent)),
+        ("rescanblockchain", schema_for!(RescanBlockchain)),
+        ("restorewallet", schema_for!(RestoreWallet)),
+        ("send", schema_for!(Send)),
+        ("sendall", schema_for!(SendAll)),
+        ("sendmany", schema_for!(SendMany)),
+        ("sendtoaddress", schema_for!(SendToAddress)),
+        ("settxfee", schema_for!(SetTxFee)),
+        ("setwalletflag", schema_for!(SetWalletFlag)),
+        ("signmessage", schema_for!(SignMessage)),
+        ("signrawtransactionwithwallet", schema_for!(SignRawTransaction)),
+        ("simulaterawtransaction", schema_for!(SimulateRawTransaction)),
+        ("upgradewallet", schema_for!(UpgradeWallet)),
+        ("walletcreatefundedpsbt", schema_for!(WalletCreateFundedPsbt)),
+        ("walletdisplayaddress", schema_for!(WalletDisplayAddress)),
+        ("walletprocesspsbt", schema_for!(WalletProcessPsbt)),
+        ("getzmqnotifications", schema_for!(GetZmqNotifications)),
+    ]
+}
+
+/// Returns the JSON Schema of the type returned by each method of `v28`, by method name.
+pub fn v28() -> Vec<(&'static str, RootSchema)> {
+    use crate::v28::*;
+
+    vec![
+        ("dumptxoutset", schema_for!(DumpTxOutSet)),
+        ("getbestblockhash", schema_for!(GetBestBlockHash)),
+        ("getblock", schema_for!(GetBlockVerboseZero)),
+        ("getblockchaininfo", schema_for!(GetBlockchainInfo)),
+        ("getblockcount", schema_for!(GetBlockCount)),
+        ("getblockfilter", schema_for!(GetBlockFilter)),
+        ("getblockhash", schema_for!(GetBlockHash)),
+        ("getblockheader", schema_for!(GetBlockHeader)),
+        ("getblockstats", schema_for!(GetBlockStats)),
+        ("getchainstates", schema_for!(GetChainStates)),
+        ("getchaintips", schema_for!(GetChainTips)),
+        ("getchaintxstats", schema_for!(GetChainTxStats)),
+        ("getdeploymentinfo", schema_for!(GetDeploymentInfo)),
+        ("getdifficulty", schema_for!(GetDifficulty)),
+        ("getmempoolancestors", schema_for!(GetMempoolAncestors)),
+        ("getmempooldescendants", schema_for!(GetMempoolDescendants)),
+        ("getmempoolentry", schema_for!(GetMempoolEntry)),
+        ("getmempoolinfo", schema_for!(GetMempoolInfo)),
+        ("getrawmempool", schema_for!(GetRawMempool)),
+        ("gettxout", schema_for!(GetTxOut)),
+        ("gettxoutsetinfo", schema_for!(GetTxOutSetInfo)),
+        ("gettxspendingprevout", schema_for!(GetTxSpendingPrevout)),
+        ("loadtxoutset", schema_for!(LoadTxOutSet)),
+        ("pruneblockchain", schema_for!(PruneBlockchain)),
+        ("savemempool", schema_for!(SaveMempool)),
+        ("scanblocks", schema_for!(ScanBlocksStart)),
+        ("scantxoutset", schema_for!(ScanTxOutSetStart)),
+        ("verifychain", schema_for!(VerifyChain)),
+        ("verifytxoutproof", schema_for!(VerifyTxOutProof)),
+        ("getrpcinfo", schema_for!(GetRpcInfo)),
+        ("getmemoryinfo", schema_for!(GetMemoryInfoStats)),
+        ("logging", schema_for!(Logging)),
+        ("getblocktemplate", schema_for!(GetBlockTemplate)),
+        ("getmininginfo", schema_for!(GetMiningInfo)),
+        ("getprioritisedtransactions", schema_for!(GetPrioritisedTransactions)),
+        ("getaddednodeinfo", schema_for!(GetAddedNodeInfo)),
+        ("getaddrmaninfo", schema_for!(GetAddrManInfo)),
+        ("getconnectioncount", schema_for!(GetConnectionCount)),
+        ("getnettotals", schema_for!(GetNetTotals)),
+        ("getnetworkinfo", schema_for!(GetNetworkInfo)),
+        ("getnodeaddresses", schema_for!(GetNodeAddresses)),
+        ("getpeerinfo", schema_for!(GetPeerInfo)),
+        ("listbanned", schema_for!(ListBanned)),
+        ("setnetworkactive", schema_for!(SetNetworkActive)),
+        ("analyzepsbt", schema_for!(AnalyzePsbt)),
+        ("combinepsbt", schema_for!(CombinePsbt)),
+        ("combinerawtransaction", schema_for!(CombineRawTransaction)),
+        ("converttopsbt", schema_for!(ConvertToPsbt)),
+        ("createpsbt", schema_for!(CreatePsbt)),
+        ("createrawtransaction", schema_for!(CreateRawTransaction)),
+        ("decodepsbt", schema_for!(DecodePsbt)),
+        ("decoderawtransaction", schema_for!(DecodeRawTransaction)),
+        ("decodescript", schema_for!(DecodeScript)),
+        ("descriptorprocesspsbt", schema_for!(DescriptorProcessPsbt)),
+        ("finalizepsbt", schema_for!(FinalizePsbt)),
+        ("fundrawtransaction", schema_for!(FundRawTransaction)),
+        ("getrawtransaction", schema_for!(GetRawTransaction)),
+        ("joinpsbts", schema_for!(JoinPsbts)),
+        ("sendrawtransaction", schema_for!(SendRawTransaction)),
+        ("signrawtransactionwithkey", schema_for!(SignRawTransaction)),
+        ("submitpackage", schema_for!(SubmitPackage)),
+        ("testmempoolaccept", schema_for!(TestMempoolAccept)),
+        ("utxoupdatepsbt", schema_for!(UtxoUpdatePsbt)),
+        ("createmultisig", schema_for!(CreateMultisig)),
+        ("deriveaddresses", schema_for!(DeriveAddresses)),
+        ("estimatesmartfee", schema_for!(EstimateSmartFee)),
+        ("getdescriptorinfo", schema_for!(GetDescriptorInfo)),
+        ("getindexinfo", schema_for!(GetIndexInfo)),
+        ("signmessagewithprivkey", schema_for!(SignMessageWithPrivKey)),
+        ("validateaddress", schema_for!(ValidateAddress)),
+        ("enumeratesigners", schema_for!(EnumerateSigners)),
+        ("abortrescan", schema_for!(AbortRescan)),
+        ("addmultisigaddress", schema_for!(AddMultisigAddress)),
+        ("bumpfee", schema_for!(BumpFee)),
+        ("createwallet", schema_for!(CreateWallet)),
+        ("createwalletdescriptor", schema_for!(CreateWalletDescriptor)),
+        ("dumpprivkey", schema_for!(DumpPrivKey)),
+        ("dumpwallet", schema_for!(DumpWallet)),
+        ("encryptwallet", schema_for!(EncryptWallet)),
+        ("getaddressesbylabel", schema_for!(GetAddressesByLabel)),
+        ("getaddressinfo", schema_for!(GetAddressInfo)),
+        ("getbalance", schema_for!(GetBalance)),
+        ("getbalances", schema_for!(GetBalances)),
+        ("gethdkeys", schema_for!(GetHdKeys)),
+        ("getnewaddress", schema_for!(GetNewAddress)),
+        ("getrawchangeaddress", schema_for!(GetRawChangeAddress)),
+        ("getreceivedbyaddress", schema_for!(GetReceivedByAddress)),
+        ("getreceivedbylabel", schema_for!(GetReceivedByLabel)),
+        ("gettransaction", schema_for!(GetTransaction)),
+        ("getunconfirmedbalance", schema_for!(GetUnconfirmedBalance)),
+        ("getwalletinfo", schema_for!(GetWalletInfo)),
+        ("importdescriptors", schema_for!(ImportDescriptors)),
+        ("importmulti", schema_for!(ImportMulti)),
+        ("listaddressgroupings", schema_for!(ListAddressGroupings)),
+        ("listdescriptors", schema_for!(ListDescriptors)),
+        ("listlabels", schema_for!(ListLabels)),
+        ("listlockunspent", schema_for!(ListLockUnspent)),
+        ("migratewallet", schema_for!(MigrateWallet)),
+        ("psbtbumpfee", schema_for!(PsbtBumpFee)),
+        ("listreceivedbyaddress", schema_for!(ListReceivedByAddress)),
+        ("listreceivedbylabel", schema_for!(ListReceivedByLabel)),
+        ("listsinceblock", schema_for!(ListSinceBlock)),
+        ("listtransactions", schema_for!(ListTransactions)),
+        ("listunspent", schema_for!(ListUnspent)),
+        ("listwalletdir", schema_for!(ListWalletDir)),
+        ("listwallets", schema_for!(ListWallets)),
+        ("loadwallet", schema_for!(LoadWallet)),
+        ("lockunspent", schema_for!(LockUnspent)),
+        ("rescanblockchain", schema_for!(RescanBlockchain)),
+        ("restorewallet", schema_for!(RestoreWallet)),
+        ("send", schema_for!(Send)),
+        ("sendall", schema_for!(SendAll)),
+        ("sendmany", schema_for!(SendMany)),
+        ("sendtoaddress", schema_for!(SendToAddress)),
+        ("settxfee", schema_for!(SetTxFee)),
+        ("setwalletflag", schema_for!(SetWalletFlag)),
+        ("signmessage", schema_for!(SignMessage)),
+        ("signrawtransactionwithwallet", schema_for!(SignRawTransaction)),
+        ("simulaterawtransaction", schema_for!(SimulateRawTransaction)),
+        ("upgradewallet", schema_for!(UpgradeWallet)),
+        ("walletcreatefundedpsbt", schema_for!(WalletCreateFundedPsbt)),
+        ("walletdisplayaddress", schema_for!(WalletDisplayAddress)),
+        ("walletprocesspsbt", schema_for!(WalletProcessPsbt)),
+        ("getzmqnotifications", schema_for!(GetZmqNotifications)),
+    ]
+}
+
+/// Returns the JSON Schema of the type returned by each method of `v29`, by method name.
+pub fn v29() -> Vec<(&'static str, RootSchema)> {
+    use crate::v29::*;
+
+    vec![
+        ("dumptxoutset", schema_for!(DumpTxOutSet)),
+        ("getbestblockhash", schema_for!(GetBestBlockHash)),
+        ("getblock", schema_for!(GetBlockVerboseZero)),
+        ("getblockchaininfo", schema_for!(GetBlockchainInfo)),
+        ("getblockcount", schema_for!(GetBlockCount)),
+        ("getblockfilter", schema_for!(GetBlockFilter)),
+        ("getblockhash", schema_for!(GetBlockHash)),
+        ("getblockheader", schema_for!(GetBlockHeader)),
+        ("getblockstats", schema_for!(GetBlockStats)),
+        ("getchainstates", schema_for!(GetChainStates)),
+        ("getchaintips", schema_for!(GetChainTips)),
+        ("getchaintxstats", schema_for!(GetChainTxStats)),
+        ("getdeploymentinfo", schema_for!(GetDeploymentInfo)),
+        ("getdescriptoractivity", schema_for!(GetDescriptorActivity)),
+        ("getdifficulty", schema_for!(GetDifficulty)),
+        ("getmempoolancestors", schema_for!(GetMempoolAncestors)),
+        ("getmempooldescendants", schema_for!(GetMempoolDescendants)),
+        ("getmempoolentry", schema_for!(GetMempoolEntry)),
+        ("getmempoolinfo", schema_for!(GetMempoolInfo)),
+        ("getrawmempool", schema_for!(GetRawMempool)),
+        ("gettxout", schema_for!(GetTxOut)),
+        ("gettxoutsetinfo", schema_for!(GetTxOutSetInfo)),
+        ("gettxspendingprevout", schema_for!(GetTxSpendingPrevout)),
+        ("loadtxoutset", schema_for!(LoadTxOutSet)),
+        ("pruneblockchain", schema_for!(PruneBlockchain)),
+        ("savemempool", schema_for!(SaveMempool)),
+        ("scanblocks", schema_for!(ScanBlocksStart)),
+        ("scantxoutset", schema_for!(ScanTxOutSetStart)),
+        ("verifychain", schema_for!(VerifyChain)),
+        ("verifytxoutproof", schema_for!(VerifyTxOutProof)),
+        ("getrpcinfo", schema_for!(GetRpcInfo)),
+        ("getmemoryinfo", schema_for!(GetMemoryInfoStats)),
+        ("logging", schema_for!(Logging)),
+        ("getblocktemplate", schema_for!(GetBlockTemplate)),
+        ("getmininginfo", schema_for!(GetMiningInfo)),
+        ("getprioritisedtransactions", schema_for!(GetPrioritisedTransactions)),
+        ("getaddednodeinfo", schema_for!(GetAddedNodeInfo)),
+        ("getaddrmaninfo", schema_for!(GetAddrManInfo)),
+        ("getconnectioncount", schema_for!(GetConnectionCount)),
+        ("getnettotals", schema_for!(GetNetTotals)),
+        ("getnetworkinfo", schema_for!(GetNetworkInfo)),
+        ("getnodeaddresses", schema_for!(GetNodeAddresses)),
+        ("getpeerinfo", schema_for!(GetPeerInfo)),
+        ("listbanned", schema_for!(ListBanned)),
+        ("setnetworkactive", schema_for!(SetNetworkActive)),
+        ("analyzepsbt", schema_for!(AnalyzePsbt)),
+        ("combinepsbt", schema_for!(CombinePsbt)),
+        ("combinerawtransaction", schema_for!(CombineRawTransaction)),
+        ("converttopsbt", schema_for!(ConvertToPsbt)),
+        ("createpsbt", schema_for!(CreatePsbt)),
+        ("createrawtransaction", schema_for!(CreateRawTransaction)),
+        ("decodepsbt", schema_for!(DecodePsbt)),
+        ("decoderawtransaction", schema_for!(DecodeRawTransaction)),
+        ("decodescript", schema_for!(DecodeScript)),
+        ("descriptorprocesspsbt", schema_for!(DescriptorProcessPsbt)),
+        ("finalizepsbt", schema_for!(FinalizePsbt)),
+        ("fundrawtransaction", schema_for!(FundRawTransaction)),
+        ("getrawtransaction", schema_for!(GetRawTransaction)),
+        ("joinpsbts", schema_for!(JoinPsbts)),
+        ("sendrawtransaction", schema_for!(SendRawTransaction)),
+        ("signrawtransactionwithkey", schema_for!(SignRawTransaction)),
+        ("submitpackage", schema_for!(SubmitPackage)),
+        ("testmempoolaccept", schema_for!(TestMempoolAccept)),
+        ("utxoupdatepsbt", schema_for!(UtxoUpdatePsbt)),
+        ("createmultisig", schema_for!(CreateMultisig)),
+        ("deriveaddresses", schema_for!(DeriveAddresses)),
+        ("estimatesmartfee", schema_for!(EstimateSmartFee)),
+        ("getdescriptorinfo", schema_for!(GetDescriptorInfo)),
+        ("getindexinfo", schema_for!(GetIndexInfo)),
+        ("signmessagewithprivkey", schema_for!(SignMessageWithPrivKey)),
+        ("validateaddress", schema_for!(ValidateAddress)),
+        ("enumeratesigners", schema_for!(EnumerateSigners)),
+        ("abortrescan", schema_for!(AbortRescan)),
+        ("addmultisigaddress", schema_for!(AddMultisigAddress)),
+        ("bumpfee", schema_for!(BumpFee)),
+        ("createwallet", schema_for!(CreateWallet)),
+        ("createwalletdescriptor", schema_for!(CreateWalletDescriptor)),
+        ("dumpprivkey", schema_for!(DumpPrivKey)),
+        ("dumpwallet", schema_for!(DumpWallet)),
+        ("encryptwallet", schema_for!(EncryptWallet)),
+        ("getaddressesbylabel", schema_for!(GetAddressesByLabel)),
+        ("getaddressinfo", schema_for!(GetAddressInfo)),
+        ("getbalance", schema_for!(GetBalance)),
+        ("getbalances", schema_for!(GetBalances)),
+        ("gethdkeys", schema_for!(GetHdKeys)),
+        ("getnewaddress", schema_for!(GetNewAddress)),
+        ("getrawchangeaddress", schema_for!(GetRawChangeAddress)),
+        ("getreceivedbyaddress", schema_for!(GetReceivedByAddress)),
+        ("getreceivedbylabel", schema_for!(GetReceivedByLabel)),
+        ("gettransaction", schema_for!(GetTransaction)),
+        ("getunconfirmedbalance", schema_for!(GetUnconfirmedBalance)),
+        ("getwalletinfo", schema_for!(GetWalletInfo)),
+        ("importdescriptors", schema_for!(ImportDescriptors)),
+        ("importmulti", schema_for!(ImportMulti)),
+        ("listaddressgroupings", schema_for!(ListAddressGroupings)),
+        ("listdescriptors", schema_for!(ListDescriptors)),
+        ("listlabels", schema_for!(ListLabels)),
+        ("listlockunspent", schema_for!(ListLockUnspent)),
+        ("migratewallet", schema_for!(MigrateWallet)),
+        ("psbtbumpfee", schema_for!(PsbtBumpFee)),
+        ("listreceivedbyaddress", schema_for!(ListReceivedByAddress)),
+        ("listreceivedbylabel", schema_for!(ListReceivedByLabel)),
+        ("listsinceblock", schema_for!(ListSinceBlock)),
+        ("listtransactions", schema_for!(ListTransactions)),
+        ("listunspent", schema_for!(ListUnspent)),
+        ("listwalletdir", schema_for!(ListWalletDir)),
+        ("listwallets", schema_for!(ListWallets)),
+        ("loadwallet", schema_for!(LoadWallet)),
+        ("lockunspent", schema_for!(LockUnspent)),
+        ("rescanblockchain", schema_for!(RescanBlockchain)),
+        ("restorewallet", schema_for!(RestoreWallet)),
+        ("send", schema_for!(Send)),
+        ("sendall", schema_for!(SendAll)),
+        ("sendmany", schema_for!(SendMany)),
+        ("sendtoaddress", schema_for!(SendToAddress)),
+        ("settxfee", schema_for!(SetTxFee)),
+        ("setwalletflag", schema_for!(SetWalletFlag)),
+        ("signmessage", schema_for!(SignMessage)),
+        ("signrawtransactionwithwallet", schema_for!(SignRawTransaction)),
+        ("simulaterawtransaction", schema_for!(SimulateRawTransaction)),
+        ("upgradewallet", schema_for!(UpgradeWallet)),
+        ("walletcreatefundedpsbt", schema_for!(WalletCreateFundedPsbt)),
+        ("walletdisplayaddress", schema_for!(WalletDisplayAddress)),
+        ("walletprocesspsbt", schema_for!(WalletProcessPsbt)),
+        ("getzmqnotifications", schema_for!(GetZmqNotifications)),
+    ]
+}
+
+/// Returns the JSON Schema of the type returned by each method of `v30`, by method name.
+pub fn v30() -> Vec<(&'static str, RootSchema)> {
+    use crate::v30::*;
+
+    vec![
+        ("dumptxoutset", schema_for!(DumpTxOutSet)),
+        ("getbestblockhash", schema_for!(GetBestBlockHash)),
+        ("getblock", schema_for!(GetBlockVerboseZero)),
+        ("getblockchaininfo", schema_for!(GetBlockchainInfo)),
+        ("getblockcount", schema_for!(GetBlockCount)),
+        ("getblockfilter", schema_for!(GetBlockFilter)),
+        ("getblockhash", schema_for!(GetBlockHash)),
+        ("getblockheader", schema_for!(GetBlockHeader)),
+        ("getblockstats", schema_for!(GetBlockStats)),
+        ("getchainstates", schema_for!(GetChainStates)),
+        ("getchaintips", schema_for!(GetChainTips)),
+        ("getchaintxstats", schema_for!(GetChainTxStats)),
+        ("getdeploymentinfo", schema_for!(GetDeploymentInfo)),
+        ("getdescriptoractivity", schema_for!(GetDescriptorActivity)),
+        ("getdifficulty", schema_for!(GetDifficulty)),
+        ("getmempoolancestors", schema_for!(GetMempoolAncestors)),
+        ("getmempooldescendants", schema_for!(GetMempoolDescendants)),
+        ("getmempoolentry", schema_for!(GetMempoolEntry)),
+        ("getmempoolinfo", schema_for!(GetMempoolInfo)),
+        ("getrawmempool", schema_for!(GetRawMempool)),
+        ("gettxout", schema_for!(GetTxOut)),
+        ("gettxoutsetinfo", schema_for!(GetTxOutSetInfo)),
+        ("gettxspendingprevout", schema_for!(GetTxSpendingPrevout)),
+        ("loadtxoutset", schema_for!(LoadTxOutSet)),
+        ("pruneblockchain", schema_for!(PruneBlockchain)),
+        ("savemempool", schema_for!(SaveMempool)),
+        ("scanblocks", schema_for!(ScanBlocksStart)),
+        ("scantxoutset", schema_for!(ScanTxOutSetStart)),
+        ("verifychain", schema_for!(VerifyChain)),
+        ("verifytxoutproof", schema_for!(VerifyTxOutProof)),
+        ("waitforblock", schema_for!(WaitForBlock)),
+        ("waitforblockheight", schema_for!(WaitForBlockHeight)),
+        ("waitfornewblock", schema_for!(WaitForNewBlock)),
+        ("getrpcinfo", schema_for!(GetRpcInfo)),
+        ("getmemoryinfo", schema_for!(GetMemoryInfoStats)),
+        ("logging", schema_for!(Logging)),
+        ("getblocktemplate", schema_for!(GetBlockTemplate)),
+        ("getmininginfo", schema_for!(GetMiningInfo)),
+        ("getprioritisedtransactions", schema_for!(GetPrioritisedTransactions)),
+        ("getaddednodeinfo", schema_for!(GetAddedNodeInfo)),
+        ("getaddrmaninfo", schema_for!(GetAddrManInfo)),
+        ("getconnectioncount", schema_for!(GetConnectionCount)),
+        ("getnettotals", schema_for!(GetNetTotals)),
+        ("getnetworkinfo", schema_for!(GetNetworkInfo)),
+        ("getnodeaddresses", schema_for!(GetNodeAddresses)),
+        ("getpeerinfo", schema_for!(GetPeerInfo)),
+        ("listbanned", schema_for!(ListBanned)),
+        ("setnetworkactive", schema_for!(SetNetworkActive)),
+        ("analyzepsbt", schema_for!(AnalyzePsbt)),
+        ("combinepsbt", schema_for!(CombinePsbt)),
+        ("combinerawtransaction", schema_for!(CombineRawTransaction)),
+        ("converttopsbt", schema_for!(ConvertToPsbt)),
+        ("createpsbt", schema_for!(CreatePsbt)),
+        ("createrawtransaction", schema_for!(CreateRawTransaction)),
+        ("decodepsbt", schema_for!(DecodePsbt)),
+        ("decoderawtransaction", schema_for!(DecodeRawTransaction)),
+        ("decodescript", schema_for!(DecodeScript)),
+        ("descriptorprocesspsbt", schema_for!(DescriptorProcessPsbt)),
+        ("finalizepsbt", schema_for!(FinalizePsbt)),
+        ("fundrawtransaction", schema_for!(FundRawTransaction)),
+        ("getrawtransaction", schema_for!(GetRawTransaction)),
+        ("joinpsbts", schema_for!(JoinPsbts)),
+        ("sendrawtransaction", schema_for!(SendRawTransaction)),
+        ("signrawtransactionwithkey", schema_for!(SignRawTransaction)),
+        ("submitpackage", schema_for!(SubmitPackage)),
+        ("testmempoolaccept", schema_for!(TestMempoolAccept)),
+        ("utxoupdatepsbt", schema_for!(UtxoUpdatePsbt)),
+        ("createmultisig", schema_for!(CreateMultisig)),
+        ("deriveaddresses", schema_for!(DeriveAddresses)),
+        ("estimatesmartfee", schema_for!(EstimateSmartFee)),
+        ("getdescriptorinfo", schema_for!(GetDescriptorInfo)),
+        ("getindexinfo", schema_for!(GetIndexInfo)),
+        ("signmessagewithprivkey", schema_for!(SignMessageWithPrivKey)),
+        ("validateaddress", schema_for!(ValidateAddress)),
+        ("enumeratesigners", schema_for!(EnumerateSigners)),
+        ("abortrescan", schema_for!(AbortRescan)),
+        ("bumpfee", schema_for!(BumpFee)),
+        ("createwallet", schema_for!(CreateWallet)),
+        ("createwalletdescriptor", schema_for!(CreateWalletDescriptor)),
+        ("encryptwallet", schema_for!(EncryptWallet)),
+        ("getaddressesbylabel", schema_for!(GetAddressesByLabel)),
+        ("getaddressinfo", schema_for!(GetAddressInfo)),
+        ("getbalance", schema_for!(GetBalance)),
+        ("getbalances", schema_for!(GetBalances)),
+        ("gethdkeys", schema_for!(GetHdKeys)),
+        ("getnewaddress", schema_for!(GetNewAddress)),
+        ("getrawchangeaddress", schema_for!(GetRawChangeAddress)),
+        ("getreceivedbyaddress", schema_for!(GetReceivedByAddress)),
+        ("getreceivedbylabel", schema_for!(GetReceivedByLabel)),
+        ("gettransaction", schema_for!(GetTransaction)),
+        ("getwalletinfo", schema_for!(GetWalletInfo)),
+        ("importdescriptors", schema_for!(ImportDescriptors)),
+        ("listaddressgroupings", schema_for!(ListAddressGroupings)),
+        ("listdescriptors", schema_for!(ListDescriptors)),
+        ("listlabels", schema_for!(ListLabels)),
+        ("listlockunspent", schema_for!(ListLockUnspent)),
+        ("migratewallet", schema_for!(MigrateWallet)),
+        ("psbtbumpfee", schema_for!(PsbtBumpFee)),
+        ("listreceivedbyaddress", schema_for!(ListReceivedByAddress)),
+        ("listreceivedbylabel", schema_for!(ListReceivedByLabel)),
+        ("listsinceblock", schema_for!(ListSinceBlock)),
+        ("listtransactions", schema_for!(ListTransactions)),
+        ("listunspent", schema_for!(ListUnspent)),
+        ("listwalletdir", schema_for!(ListWalletDir)),
+        ("listwallets", schema_for!(ListWallets)),
+        ("loadwallet", schema_for!(LoadWallet)),
+        ("lockunspent", schema_for!(LockUnspent)),
+        ("rescanblockchain", schema_for!(RescanBlockchain)),
+        ("restorewallet", schema_for!(RestoreWallet)),
+        ("send", schema_for!(Send)),
+        ("sendall", schema_for!(SendAll)),
+        ("sendmany", schema_for!(SendMany)),
+        ("sendtoaddress", schema_for!(SendToAddress)),
+        ("settxfee", schema_for!(SetTxFee)),
+        ("setwalletflag", schema_for!(SetWalletFlag)),
+        ("signmessage", schema_for!(SignMessage)),
+        ("signrawtransactionwithwallet", schema_for!(SignRawTransaction)),
+        ("simulaterawtransaction", schema_for!(SimulateRawTransaction)),
+        ("walletcreatefundedpsbt", schema_for!(WalletCreateFundedPsbt)),
+        ("walletdisplayaddress", schema_for!(WalletDisplayAddress)),
+        ("walletprocesspsbt", schema_for!(WalletProcessPsbt)),
+        ("getzmqnotifications", schema_for!(GetZmqNotifications)),
+    ]
+}
+
+/// Returns the JSON Schema of the type returned by each method of `v31`, by method name.
+pub fn v31() -> Vec<(&'static str, RootSchema)> {
+    use crate::v31::*;
+
+    vec![
+        ("dumptxoutset", schema_for!(DumpTxOutSet)),
+        ("getbestblockhash", schema_for!(GetBestBlockHash)),
+        ("getblock", schema_for!(GetBlockVerboseZero)),
+        ("getblockchaininfo", schema_for!(GetBlockchainInfo)),
+        ("getblockcount", schema_for!(GetBlockCount)),
+        ("getblockfilter", schema_for!(GetBlockFilter)),
+        ("getblockhash", schema_for!(GetBlockHash)),
+        ("getblockheader", schema_for!(GetBlockHeader)),
+        ("getblockstats", schema_for!(GetBlockStats)),
+        ("getchainstates", schema_for!(GetChainStates)),
+        ("getchaintips", schema_for!(GetChainTips)),
+        ("getchaintxstats", schema_for!(GetChainTxStats)),
+        ("getdeploymentinfo", schema_for!(GetDeploymentInfo)),
+        ("getdescriptoractivity", schema_for!(GetDescriptorActivity)),
+        ("getdifficulty", schema_for!(GetDifficulty)),
+        ("getmempoolancestors", schema_for!(GetMempoolAncestors)),
+        ("getmempoolcluster", schema_for!(GetMempoolCluster)),
+        ("getmempooldescendants", schema_for!(GetMempoolDescendants)),
+        ("getmempoolentry", schema_for!(GetMempoolEntry)),
+        ("getmempoolinfo", schema_for!(GetMempoolInfo)),
+        ("getrawmempool", schema_for!(GetRawMempool)),
+        ("gettxout", schema_for!(GetTxOut)),
+        ("gettxoutsetinfo", schema_for!(GetTxOutSetInfo)),
+        ("gettxspendingprevout", schema_for!(GetTxSpendingPrevout)),
+        ("loadtxoutset", schema_for!(LoadTxOutSet)),
+        ("pruneblockchain", schema_for!(PruneBlockchain)),
+        ("savemempool", schema_for!(SaveMempool)),
+        ("scanblocks", schema_for!(ScanBlocksStart)),
+        ("scantxoutset", schema_for!(ScanTxOutSetStart)),
+        ("verifychain", schema_for!(VerifyChain)),
+        ("verifytxoutproof", schema_for!(VerifyTxOutProof)),
+        ("waitforblock", schema_for!(WaitForBlock)),
+        ("waitforblockheight", schema_for!(WaitForBlockHeight)),
+        ("waitfornewblock", schema_for!(WaitForNewBlock)),
+        ("getrpcinfo", schema_for!(GetRpcInfo)),
+        ("getmemoryinfo", schema_for!(GetMemoryInfoStats)),
+        ("logging", schema_for!(Logging)),
+        ("getblocktemplate", schema_for!(GetBlockTemplate)),
+        ("getmininginfo", schema_for!(GetMiningInfo)),
+        ("getprioritisedtransactions", schema_for!(GetPrioritisedTransactions)),
+        ("getaddednodeinfo", schema_for!(GetAddedNodeInfo)),
+        ("getaddrmaninfo", schema_for!(GetAddrManInfo)),
+        ("getconnectioncount", schema_for!(GetConnectionCount)),
+        ("getnettotals", schema_for!(GetNetTotals)),
+        ("getnetworkinfo", schema_for!(GetNetworkInfo)),
+        ("getnodeaddresses", schema_for!(GetNodeAddresses)),
+        ("getpeerinfo", schema_for!(GetPeerInfo)),
+        ("listbanned", schema_for!(ListBanned)),
+        ("setnetworkactive", schema_for!(SetNetworkActive)),
+        ("abortprivatebroadcast", schema_for!(AbortPrivateBroadcast)),
+        ("analyzepsbt", schema_for!(AnalyzePsbt)),
+        ("combinepsbt", schema_for!(CombinePsbt)),
+        ("combinerawtransaction", schema_for!(CombineRawTransaction)),
+        ("converttopsbt", schema_for!(ConvertToPsbt)),
+        ("createpsbt", schema_for!(CreatePsbt)),
+        ("createrawtransaction", schema_for!(CreateRawTransaction)),
+        ("decodepsbt", schema_for!(DecodePsbt)),
+        ("decoderawtransaction", schema_for!(DecodeRawTransaction)),
+        ("decodescript", schema_for!(DecodeScript)),
+        ("descriptorprocesspsbt", schema_for!(DescriptorProcessPsbt)),
+        ("finalizepsbt", schema_for!(FinalizePsbt)),
+        ("fundrawtransaction", schema_for!(FundRawTransaction)),
+        ("getprivatebroadcastinfo", schema_for!(GetPrivateBroadcastInfo)),
+        ("getrawtransaction", schema_for!(GetRawTransaction)),
+        ("joinpsbts", schema_for!(JoinPsbts)),
+        ("sendrawtransaction", schema_for!(SendRawTransaction)),
+        ("signrawtransactionwithkey", schema_for!(SignRawTransaction)),
+        ("submitpackage", schema_for!(SubmitPackage)),
+        ("testmempoolaccept", schema_for!(TestMempoolAccept)),
+        ("utxoupdatepsbt", schema_for!(UtxoUpdatePsbt)),
+        ("createmultisig", schema_for!(CreateMultisig)),
+        ("deriveaddresses", schema_for!(DeriveAddresses)),
+        ("estimatesmartfee", schema_for!(EstimateSmartFee)),
+        ("getdescriptorinfo", schema_for!(GetDescriptorInfo)),
+        ("getindexinfo", schema_for!(GetIndexInfo)),
+        ("signmessagewithprivkey", schema_for!(SignMessageWithPrivKey)),
+        ("validateaddress", schema_for!(ValidateAddress)),
+        ("enumeratesigners", schema_for!(EnumerateSigners)),
+        ("abortrescan", schema_for!(AbortRescan)),
+        ("bumpfee", schema_for!(BumpFee)),
+        ("createwallet", schema_for!(CreateWallet)),
+        ("createwalletdescriptor", schema_for!(CreateWalletDescriptor)),
+        ("encryptwallet", schema_for!(EncryptWallet)),
+        ("getaddressesbylabel", schema_for!(GetAddressesByLabel)),
+        ("getaddressinfo", schema_for!(GetAddressInfo)),
+        ("getbalance", schema_for!(GetBalance)),
+        ("getbalances", schema_for!(GetBalances)),
+        ("gethdkeys", schema_for!(GetHdKeys)),
+        ("getnewaddress", schema_for!(GetNewAddress)),
+        ("getrawchangeaddress", schema_for!(GetRawChangeAddress)),
+        ("getreceivedbyaddress", schema_for!(GetReceivedByAddress)),
+        ("getreceivedbylabel", schema_for!(GetReceivedByLabel)),
+        ("gettransaction", schema_for!(GetTransaction)),
+        ("getwalletinfo", schema_for!(GetWalletInfo)),
+        ("importdescriptors", schema_for!(ImportDescriptors)),
+        ("listaddressgroupings", schema_for!(ListAddressGroupings)),
+        ("listdescriptors", schema_for!(ListDescriptors)),
+        ("listlabels", schema_for!(ListLabels)),
+        ("listlockunspent", schema_for!(ListLockUnspent)),
+        ("migratewallet", schema_for!(MigrateWallet)),
+        ("psbtbumpfee", schema_for!(PsbtBumpFee)),
+        ("listreceivedbyaddress", schema_for!(ListReceivedByAddress)),
+        ("listreceivedbylabel", schema_for!(ListReceivedByLabel)),
+        ("listsinceblock", schema_for!(ListSinceBlock)),
+        ("listtransactions", schema_for!(ListTransactions)),
+        ("listunspent", schema_for!(ListUnspent)),
+        ("listwalletdir", schema_for!(ListWalletDir)),
+        ("listwallets", schema_for!(ListWallets)),
+        ("loadwallet", schema_for!(LoadWallet)),
+        ("lockunspent", schema_for!(LockUnspent)),
+        ("rescanblockchain", schema_for!(RescanBlockchain)),
+        ("restorewallet", schema_for!(RestoreWallet)),
+        ("send", schema_for!(Send)),
+        ("sendall", schema_for!(SendAll)),
+        ("sendmany", schema_for!(SendMany)),
+        ("sendtoaddress", schema_for!(SendToAddress)),
+        ("setwalletflag", schema_for!(SetWalletFlag)),
+        ("signmessage", schema_for!(SignMessage)),
+        ("signrawtransactionwithwallet", schema_for!(SignRawTransaction)),
+        ("simulaterawtransaction", schema_for!(SimulateRawTransaction)),
+        ("walletcreatefundedpsbt", schema_for!(WalletCreateFundedPsbt)),
+        ("walletdisplayaddress", schema_for!(WalletDisplayAddress)),
+        ("walletprocesspsbt", schema_for!(WalletProcessPsbt)),
+        ("getzmqnotifications", schema_for!(GetZmqNotifications)),
+    ]
+}
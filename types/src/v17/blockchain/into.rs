@@ -9,6 +9,7 @@ use bitcoin::{
 
 // TODO: Use explicit imports?
 use super::*;
+use crate::prelude::*;
 
 impl GetBestBlockHash {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -8,6 +8,7 @@ use bitcoin::{hex, BlockHash};
 use serde::{Deserialize, Serialize};
 
 use crate::model;
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `generate`.
 ///
@@ -18,6 +19,7 @@ use crate::model;
 /// > Arguments:
 /// > 1. nblocks      (numeric, required) How many blocks are generated immediately.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct Generate(
     /// Hashes of blocks generated.
@@ -42,6 +44,7 @@ impl Generate {
 /// > 1. nblocks     (numeric, required) How many blocks are generated immediately.
 /// > 2. address     (string, required) The address to send the newly generated bitcoin to.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GenerateToAddress(
     /// Hashes of blocks generated.
@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 pub use self::error::{
     EstimateRawFeeError, WaitForBlockError, WaitForBlockHeightError, WaitForNewBlockError,
 };
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `estimaterawfee`.
 ///
@@ -30,6 +31,7 @@ pub use self::error::{
 /// > Arguments:
 /// > 1. conf_target (numeric) Confirmation target in blocks (1 - 1008)
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct EstimateRawFee {
     /// Estimate for short time horizon.
@@ -42,6 +44,7 @@ pub struct EstimateRawFee {
 
 /// Estimate for a time horizon. Part of `estimaterawfee`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct RawFeeDetail {
     /// Estimate fee rate in BTC/kB.
@@ -61,6 +64,7 @@ pub struct RawFeeDetail {
 
 /// Information about a feerate range. Part of `estimaterawfee`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct RawFeeRange {
     /// Start of feerate range.
@@ -94,6 +98,7 @@ pub struct RawFeeRange {
 /// > Arguments:
 /// > 1. "blockhash"  (string, required) Block hash to wait for.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct WaitForBlock {
     /// The blockhash.
@@ -112,6 +117,7 @@ pub struct WaitForBlock {
 /// > Arguments:
 /// > 1. "blockhash"  (string, required) Block hash to wait for
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct WaitForBlockHeight {
     /// The blockhash.
@@ -128,6 +134,7 @@ pub struct WaitForBlockHeight {
 /// >
 /// > Returns the current block on timeout or exit.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct WaitForNewBlock {
     /// The blockhash.
@@ -10,6 +10,7 @@ use super::{
     GetBlockTemplateError, GetMiningInfo,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl GetBlockTemplate {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -7,11 +7,12 @@
 mod error;
 mod into;
 
-use std::collections::BTreeMap;
+use alloc::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
 pub use self::error::{BlockTemplateTransactionError, GetBlockTemplateError};
+use crate::prelude::*;
 
 /// Result of the JSON-RPC method `getblocktemplate`.
 ///
@@ -42,6 +43,7 @@ pub use self::error::{BlockTemplateTransactionError, GetBlockTemplateError};
 /// >        "data": "hex",          (string, optional) proposed block data to check, encoded in hexadecimal; valid only for mode="proposal"
 /// >      }
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBlockTemplate {
     /// The preferred block version.
@@ -113,6 +115,7 @@ pub struct GetBlockTemplate {
 
 /// Transaction contents. Part of `getblocktemplate`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct BlockTemplateTransaction {
     /// Transaction data encoded in hexadecimal (byte-for-byte).
@@ -143,6 +146,7 @@ pub struct BlockTemplateTransaction {
 /// >
 /// > Returns a json object containing mining-related information.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetMiningInfo {
     /// The current block.
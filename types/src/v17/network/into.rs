@@ -1,8 +1,9 @@
 // SPDX-License-Identifier: CC0-1.0
 
 use super::error::GetNetworkInfoError;
-use super::{GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork};
+use super::{Banned, GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork, ListBanned};
 use crate::model;
+use crate::prelude::*;
 
 impl GetNetworkInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -52,3 +53,24 @@ impl GetNetworkInfoAddress {
         model::GetNetworkInfoAddress { address: self.address, port: self.port, score: self.score }
     }
 }
+
+impl ListBanned {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::ListBanned {
+        model::ListBanned(self.0.into_iter().map(|b| b.into_model()).collect())
+    }
+}
+
+impl Banned {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::Banned {
+        model::Banned {
+            subnet: model::Subnet(self.address),
+            ban_created: self.ban_created,
+            banned_until: self.banned_until,
+            ban_duration: self.banned_until.saturating_sub(self.ban_created),
+            time_remaining: None,
+            ban_reason: Some(self.ban_reason),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: CC0-1.0
 
-use std::collections::BTreeMap;
+use alloc::collections::BTreeMap;
 
 use bitcoin::psbt::{self, Psbt, PsbtParseError, PsbtSighashType};
 use bitcoin::{
@@ -17,6 +17,7 @@ use super::{
     SignFailError, SignRawTransaction, SignRawTransactionError, TestMempoolAccept,
 };
 use crate::model;
+use crate::prelude::*;
 use crate::psbt::RawTransactionError;
 
 impl CombinePsbt {
@@ -7,10 +7,11 @@
 mod error;
 mod into;
 
-use std::collections::HashMap;
+use alloc::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
 use crate::ScriptSig;
 
 #[rustfmt::skip]                // Keep public re-exports separate.
@@ -38,6 +39,7 @@ pub use crate::psbt::{
 /// >       ,...
 /// >     ]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct CombinePsbt(
     /// The base64-encoded partially signed transaction.
@@ -58,6 +60,7 @@ pub struct CombinePsbt(
 /// >       ,...
 /// >     ]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct CombineRawTransaction(
     /// The hex-encoded raw transaction with signature(s).
@@ -74,6 +77,7 @@ pub struct CombineRawTransaction(
 /// > Arguments:
 /// > 1. "hexstring"              (string, required) The hex string of a raw transaction
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ConvertToPsbt(
     /// The resulting raw transaction (base64-encoded string).
@@ -94,6 +98,7 @@ pub struct ConvertToPsbt(
 /// >          "txid":"id",      (string, required) The transaction id
 /// >          "vout":n,         (numeric, required) The output number
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct CreatePsbt(
     /// The resulting raw transaction (base64-encoded string).
@@ -117,6 +122,7 @@ pub struct CreatePsbt(
 /// >          "txid":"id",      (string, required) The transaction id
 /// >          "vout":n,         (numeric, required) The output number
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct CreateRawTransaction(
     /// hex string of the transaction.
@@ -132,12 +138,13 @@ pub struct CreateRawTransaction(
 /// > Arguments:
 /// > 1. "psbt"            (string, required) The PSBT base64 string
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DecodePsbt {
     /// The decoded network-serialized unsigned transaction.
     pub tx: RawTransaction,
     /// The unknown global fields.
-    pub unknown: Option<HashMap<String, String>>,
+    pub unknown: Option<BTreeMap<String, String>>,
     /// Array of transaction inputs.
     pub inputs: Vec<PsbtInput>,
     /// Array of transaction outputs.
@@ -148,6 +155,7 @@ pub struct DecodePsbt {
 
 /// An input in a partially signed Bitcoin transaction. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct PsbtInput {
     /// Decoded network transaction for non-witness UTXOs.
@@ -155,7 +163,7 @@ pub struct PsbtInput {
     /// Transaction output for witness UTXOs.
     pub witness_utxo: Option<WitnessUtxo>,
     /// The public key and signature that corresponds to it.
-    pub partial_signatures: Option<HashMap<String, String>>,
+    pub partial_signatures: Option<BTreeMap<String, String>>,
     /// The sighash type to be used.
     pub sighash: Option<String>,
     /// The redeem script.
@@ -163,7 +171,7 @@ pub struct PsbtInput {
     /// The witness script.
     pub witness_script: Option<PsbtScript>,
     /// The public key with the derivation path as the value.
-    pub bip32_derivs: Option<HashMap<String, InputKeySource>>,
+    pub bip32_derivs: Option<BTreeMap<String, InputKeySource>>,
     /// The final scriptsig.
     #[serde(rename = "final_scriptsig")]
     pub final_script_sig: Option<ScriptSig>,
@@ -172,11 +180,12 @@ pub struct PsbtInput {
     pub final_script_witness: Option<Vec<String>>,
     // `s/global/input`: this is a bug in the Core v0.17 docs.
     /// The unknown global fields.
-    pub unknown: Option<HashMap<String, String>>,
+    pub unknown: Option<BTreeMap<String, String>>,
 }
 
 /// An output in a partially signed Bitcoin transaction. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct PsbtOutput {
     /// The redeem script.
@@ -186,7 +195,7 @@ pub struct PsbtOutput {
     /// The public key with the derivation path as the value.
     pub bip32_derivs: Option<Vec<Bip32Deriv>>,
     /// The unknown global fields.
-    pub unknown: Option<HashMap<String, String>>,
+    pub unknown: Option<BTreeMap<String, String>>,
 }
 
 /// Result of JSON-RPC method `decoderawtransaction`.
@@ -198,6 +207,7 @@ pub struct PsbtOutput {
 /// > Arguments:
 /// > 1. "hexstring"      (string, required) The transaction hex string
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DecodeRawTransaction(pub RawTransaction);
 
@@ -211,6 +221,7 @@ pub struct DecodeRawTransaction(pub RawTransaction);
 /// > 1. "hexstring"     (string) the hex encoded script
 // The docs on Core v0.17 appear to be way off what is actually returned.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DecodeScript {
     /// Script public key.
@@ -239,6 +250,7 @@ pub struct DecodeScript {
 /// Seemingly undocumented data returned in the `segwit` field of `DecodeScript`.
 // This seems to be the same as `DecodeScript` except the `p2sh` field is called `p2sh-segwit`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DecodeScriptSegwit {
     /// Script public key.
@@ -269,6 +281,7 @@ pub struct DecodeScriptSegwit {
 /// > Arguments:
 /// > 1. "psbt"                 (string) A base64 string of a PSBT
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct FinalizePsbt {
     /// The base64-encoded partially signed transaction if not extracted.
@@ -297,6 +310,7 @@ pub struct FinalizePsbt {
 /// > Arguments:
 /// > 1. "hexstring"           (string, required) The hex string of the raw transaction
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct FundRawTransaction {
     /// The resulting raw transaction (hex-encoded string).
@@ -329,6 +343,7 @@ pub struct FundRawTransaction {
 /// > 2. verbose     (bool, optional, default=false) If false, return a string, otherwise return a json object
 /// > 3. "blockhash" (string, optional) The block in which to look for the transaction
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetRawTransaction(
     /// The serialized, hex-encoded data for 'txid'.
@@ -337,6 +352,7 @@ pub struct GetRawTransaction(
 
 /// Result of JSON-RPC method `getrawtransaction` with verbose set to `true`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetRawTransactionVerbose {
     /// Whether specified block is in the active chain or not (only present with explicit "blockhash" argument).
@@ -390,6 +406,7 @@ pub struct GetRawTransactionVerbose {
 /// > 1. hexstring        (string, required) The hex string of the raw transaction
 /// > 2. allowhighfees    (boolean, optional, default=false) Allow high fees
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct SendRawTransaction(
     /// The transaction hash in hex.
@@ -410,6 +427,7 @@ pub struct SendRawTransaction(
 /// > Arguments:
 /// > 1. "hexstring"     (string, required) The transaction hex string
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct SignRawTransaction {
     /// The hex-encoded raw transaction with signature(s).
@@ -440,6 +458,7 @@ pub type SignRawTransactionWithKey = SignRawTransaction;
 
 /// A script verification error. Part of `signrawtransaction`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct SignFail {
     /// The hash of the referenced, previous transaction.
@@ -470,11 +489,13 @@ pub struct SignFail {
 /// >                                         Length must be one for now.
 /// > 2. allowhighfees    (boolean, optional, default=false) Allow high fees
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct TestMempoolAccept(pub Vec<MempoolAcceptance>);
 
 /// A single mempool acceptance test result. Part of `testmempoolaccept`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct MempoolAcceptance {
     /// The transaction hash in hex.
@@ -8,6 +8,7 @@ use super::{
     ValidateAddressError,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl CreateMultisig {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -9,6 +9,8 @@ mod into;
 
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 #[rustfmt::skip]                // Keep public re-exports separate.
 pub use self::error::{CreateMultisigError, ValidateAddressError};
 
@@ -27,6 +29,7 @@ pub use self::error::{CreateMultisigError, ValidateAddressError};
 /// >        ,...
 /// >      ]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct CreateMultisig {
     /// The value of the new multisig address.
@@ -57,6 +60,7 @@ pub struct CreateMultisig {
 /// >        "ECONOMICAL"
 /// >        "CONSERVATIVE"
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct EstimateSmartFee {
     /// Estimate fee rate in BTC/kB.
@@ -78,6 +82,7 @@ pub struct EstimateSmartFee {
 /// > 1. "privkey"         (string, required) The private key to sign the message with.
 /// > 2. "message"         (string, required) The message to create a signature of.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct SignMessageWithPrivKey(pub String);
 
@@ -94,6 +99,7 @@ pub struct SignMessageWithPrivKey(pub String);
 /// > Arguments:
 /// > 1. "address"                    (string, required) The bitcoin address to validate
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ValidateAddress {
     /// If the address is valid or not. If not, this is the only property returned.
@@ -127,5 +133,6 @@ pub struct ValidateAddress {
 /// > 2. "signature"       (string, required) The signature provided by the signer in base 64 encoding (see signmessage).
 /// > 3. "message"         (string, required) The message that was signed.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct VerifyMessage(pub bool);
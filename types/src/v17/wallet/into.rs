@@ -14,6 +14,7 @@ use bitcoin::{
 
 // TODO: Use explicit imports?
 use super::*;
+use crate::prelude::*;
 use crate::{model, NumericError};
 
 impl AddressPurpose {
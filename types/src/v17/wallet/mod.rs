@@ -17,6 +17,7 @@ use serde::{Deserialize, Serialize};
 // TODO: Remove wildcard, use explicit types.
 pub use self::error::*;
 use super::SignRawTransaction;
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `abortrescan`.
 ///
@@ -24,6 +25,7 @@ use super::SignRawTransaction;
 /// >
 /// > Stops current wallet rescan triggered by an RPC call, e.g. by an importprivkey call.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct AbortRescan(pub bool);
 
@@ -41,6 +43,7 @@ pub struct AbortRescan(pub bool);
 /// > 1. nrequired                      (numeric, required) The number of required signatures out of the n keys or addresses.
 /// > 2. "keys"                         (string, required) A json array of bitcoin addresses or hex-encoded public keys
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct AddMultisigAddress {
     /// The value of the new multisig address.
@@ -69,6 +72,7 @@ pub struct AddMultisigAddress {
 /// > Arguments:
 /// > 1. txid                  (string, required) The txid to be bumped
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct BumpFee {
     /// The id of the new transaction.
@@ -91,6 +95,7 @@ pub struct BumpFee {
 /// > Arguments:
 /// > 1. "wallet_name"          (string, required) The name for the new wallet. If this is a path, the wallet will be created at the path location.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct CreateWallet {
     /// The wallet name if created successfully.
@@ -116,6 +121,7 @@ impl CreateWallet {
 /// > Arguments:
 /// > 1. "address"   (string, required) The bitcoin address for the private key
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DumpPrivKey(pub String); // The private key.
 
@@ -136,6 +142,7 @@ impl DumpPrivKey {
 /// > Arguments:
 /// > 1. "filename"    (string, required) The filename with path (either absolute or relative to bitcoind)
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DumpWallet {
     /// The filename with full absolute path.
@@ -156,6 +163,7 @@ pub struct DumpWallet {
 /// > Arguments:
 /// > 1. passphrase    (string, required) The pass phrase to encrypt the wallet with. It must be at least 1 character, but should be long.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct EncryptWallet(pub String);
 
@@ -168,11 +176,13 @@ pub struct EncryptWallet(pub String);
 /// > Arguments:
 /// > 1. "label"  (string, required) The label.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetAddressesByLabel(pub BTreeMap<String, AddressInformation>);
 
 /// Address information. Part of `getaddressesbylabel`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct AddressInformation {
     /// Purpose of address.
@@ -181,6 +191,7 @@ pub struct AddressInformation {
 
 /// The purpose of an address. Part of `getaddressesbylabel`.
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum AddressPurpose {
     /// A send-to address.
@@ -199,6 +210,7 @@ pub enum AddressPurpose {
 /// > Arguments:
 /// > 1. "address"                    (string, required) The bitcoin address to get the information of.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetAddressInfo {
     /// The bitcoin address validated.
@@ -259,6 +271,7 @@ pub struct GetAddressInfo {
 
 /// The script field. Part of `getaddressinfo` and `getaddressinfoembedded`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum ScriptType {
     /// Non-standard output script type.
     #[serde(rename = "nonstandard")]
@@ -295,6 +308,7 @@ pub enum ScriptType {
 /// ("timestamp", "hdkeypath", "hdseedid") and relation to the wallet ("ismine", "iswatchonly",
 /// "account").
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetAddressInfoEmbedded {
     /// The bitcoin address validated.
@@ -334,6 +348,7 @@ pub struct GetAddressInfoEmbedded {
 
 /// Address label field. Part of `getaddressinfo` and `getaddressinfoembedded`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetAddressInfoLabel {
     /// The label.
@@ -350,6 +365,7 @@ pub struct GetAddressInfoLabel {
 /// > The available balance is what the wallet considers currently spendable, and is
 /// > thus affected by options which limit spendability such as -spendzeroconfchange.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBalance(pub f64);
 
@@ -369,6 +385,7 @@ impl GetBalance {
 /// > If 'label' is specified, it is added to the address book
 /// > so payments received with the address will be associated with 'label'.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetNewAddress(pub String);
 
@@ -379,6 +396,7 @@ pub struct GetNewAddress(pub String);
 /// > Returns a new Bitcoin address, for receiving change.
 /// > This is for use with raw transactions, NOT normal use.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetRawChangeAddress(pub String);
 
@@ -391,6 +409,7 @@ pub struct GetRawChangeAddress(pub String);
 /// > Arguments:
 /// > 1. "address"         (string, required) The bitcoin address for transactions.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetReceivedByAddress(pub f64); // Amount in BTC.
 
@@ -403,6 +422,7 @@ pub struct GetReceivedByAddress(pub f64); // Amount in BTC.
 /// > Arguments:
 /// > 1. txid                 (string, required) The transaction id
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetTransaction {
     /// The transaction amount in BTC.
@@ -448,6 +468,7 @@ pub struct GetTransaction {
 
 /// Transaction detail. Part of the `gettransaction`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetTransactionDetail {
     /// DEPRECATED. The account name involved in the transaction, can be "" for the default account.
@@ -474,6 +495,7 @@ pub struct GetTransactionDetail {
 
 /// The category of a transaction. Part of `gettransaction`, `listsinceblock` and `listtransactions`.
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum TransactionCategory {
     /// Transactions sent.
@@ -491,6 +513,7 @@ pub enum TransactionCategory {
 /// Whether this transaction can be RBF'ed. Part of `gettransaction`, `listsinceblock` and
 /// `listtransactions`.
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(rename_all = "lowercase")]
 pub enum Bip125Replaceable {
     /// Yes, can be replaced due to BIP-125 (RBF).
@@ -506,6 +529,7 @@ pub enum Bip125Replaceable {
 /// > getunconfirmedbalance
 /// > Returns the server's total unconfirmed balance
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetUnconfirmedBalance(pub f64); // Core docs are missing so this is just a guess.
 
@@ -514,6 +538,7 @@ pub struct GetUnconfirmedBalance(pub f64); // Core docs are missing so this is j
 /// > getwalletinfo
 /// > Returns an object containing various wallet state info.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetWalletInfo {
     /// The wallet name.
@@ -572,10 +597,12 @@ pub struct GetWalletInfo {
 /// >   ...
 /// >   ]
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ImportMulti(pub Vec<ImportMultiEntry>);
 
 /// A single import multi entry. Part of `importmulti`.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ImportMultiEntry {
     /// The success.
     pub success: bool,
@@ -585,6 +612,7 @@ pub struct ImportMultiEntry {
 
 /// A JSON-RPC error response. Part of `importmulti`.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct JsonRpcError {
     /// The error code.
     pub code: i32,
@@ -602,6 +630,7 @@ pub struct JsonRpcError {
 /// > made public by common use as inputs or as the resulting change
 /// > in past transactions
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ListAddressGroupings(pub Vec<Vec<ListAddressGroupingsItem>>);
 
@@ -611,6 +640,7 @@ pub struct ListAddressGroupings(pub Vec<Vec<ListAddressGroupingsItem>>);
 /// label or 3 elements `[address, amount, label]` when a label is present. Represent this as an
 /// untagged enum of tuple variants so Serde can match either length without custom code.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum ListAddressGroupingsItem {
     /// Entry without label.
@@ -625,6 +655,7 @@ pub enum ListAddressGroupingsItem {
 /// >
 /// > Returns the list of all labels, or labels that are assigned to addresses with a specific purpose.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ListLabels(pub Vec<String>);
 
@@ -635,11 +666,13 @@ pub struct ListLabels(pub Vec<String>);
 /// > Returns list of temporarily unspendable outputs.
 /// > See the lockunspent call to lock and unlock transactions for spending.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ListLockUnspent(pub Vec<ListLockUnspentItem>);
 
 /// List lock unspent item. Part of of `listlockunspent`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ListLockUnspentItem {
     /// The transaction id locked.
@@ -654,11 +687,13 @@ pub struct ListLockUnspentItem {
 /// >
 /// > List balances by receiving address.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ListReceivedByAddress(pub Vec<ListReceivedByAddressItem>);
 
 /// List received by address item. Part of of `listreceivedbyaddress`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ListReceivedByAddressItem {
     /// Only returned if imported addresses were involved in transaction.
@@ -686,6 +721,7 @@ pub struct ListReceivedByAddressItem {
 /// > If "blockhash" is no longer a part of the main chain, transactions from the fork point onward are included.
 /// > Additionally, if include_removed is set, transactions affecting the wallet which were removed are returned in the "removed" array.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ListSinceBlock {
     /// All the transactions.
@@ -706,6 +742,7 @@ pub struct ListSinceBlock {
 
 /// Transaction item. Part of `listsinceblock` and `listtransactions`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct TransactionItem {
     /// DEPRECATED. The account name associated with the transaction. Will be "" for the default account.
@@ -788,6 +825,7 @@ pub struct TransactionItem {
 /// > Note that the "account" argument and "otheraccount" return value have been removed in V0.17. To use this RPC with an "account" argument, restart
 /// > bitcoind with -deprecatedrpc=accounts
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ListTransactions(pub Vec<TransactionItem>);
 
@@ -799,11 +837,13 @@ pub struct ListTransactions(pub Vec<TransactionItem>);
 /// > with between minconf and maxconf (inclusive) confirmations.
 /// > Optionally filter to only include txouts paid to specified addresses.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ListUnspent(pub Vec<ListUnspentItem>);
 
 /// Unspent transaction output. Part of `listunspent`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ListUnspentItem {
     /// The transaction id.
@@ -842,6 +882,7 @@ pub struct ListUnspentItem {
 /// > Returns a list of currently loaded wallets.
 /// > For full information on the wallet, use "getwalletinfo"
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ListWallets(pub Vec<String>);
 
@@ -856,6 +897,7 @@ pub struct ListWallets(pub Vec<String>);
 /// > Arguments:
 /// > 1. "filename"    (string, required) The wallet directory or .dat file.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct LoadWallet {
     /// The wallet name if loaded successfully.
@@ -876,6 +918,7 @@ impl LoadWallet {
 /// > Arguments:
 /// > 1. unlock                  (boolean, required) Whether to unlock (true) or lock (false) the specified transactions.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct LockUnspent(pub bool);
 
 /// Result of the JSON-RPC method `rescanblockchain`.
@@ -884,6 +927,7 @@ pub struct LockUnspent(pub bool);
 /// >
 /// > Rescan the local blockchain for wallet related transactions.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct RescanBlockchain {
     /// The block height where the rescan has started.
@@ -909,6 +953,7 @@ pub struct RescanBlockchain {
 /// >       ,...
 /// >     }
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct SendMany(
     /// The transaction id for the send.
@@ -927,6 +972,7 @@ pub struct SendMany(
 /// > 1. "address"            (string, required) The bitcoin address to send to.
 /// > 2. "amount"             (numeric or string, required) The amount in BTC to send. eg 0.1
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct SendToAddress(pub String);
 
@@ -942,6 +988,7 @@ impl SendToAddress {
 /// > Arguments:
 /// > 1. amount         (numeric or string, required) The transaction fee in BTC/kB
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct SetTxFee(pub bool);
 
 /// Result of the JSON-RPC method `signmessage`.
@@ -954,6 +1001,7 @@ pub struct SetTxFee(pub bool);
 /// > 1. "address"         (string, required) The bitcoin address to use for the private key.
 /// > 2. "message"         (string, required) The message to create a signature of.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct SignMessage(
     /// The signature of the message encoded in base 64.
@@ -1004,6 +1052,7 @@ pub type SignRawTransactionWithWallet = SignRawTransaction;
 /// >                              accepted as second parameter.
 /// >    ]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct WalletCreateFundedPsbt {
     /// The resulting raw transaction (base64-encoded string).
@@ -1026,6 +1075,7 @@ pub struct WalletCreateFundedPsbt {
 /// > Arguments:
 /// > 1. "psbt"                      (string, required) The transaction base64 string
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct WalletProcessPsbt {
     /// The base64-encoded partially signed transaction.
@@ -6,12 +6,15 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Result of JSON-RPC method `getzmqnotifications`.
 ///
 ///> getzmqnotifications
 ///>
 ///> Returns information about the active ZeroMQ notifications.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetZmqNotifications {
     /// Type of notification.
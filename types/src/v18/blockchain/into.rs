@@ -11,6 +11,7 @@ use super::{
     ScanTxOutSetUnspent,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl GetMempoolAncestors {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -6,12 +6,15 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Result of JSON-RPC method `getrpcinfo`.
 ///
 /// > getrpcinfo
 /// >
 /// > Returns details of the RPC server.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetRpcInfo {
     active_commands: Vec<ActiveCommand>,
@@ -19,6 +22,7 @@ pub struct GetRpcInfo {
 
 /// Information about an active command. Part of `getrpcinfo`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ActiveCommand {
     /// The name of the RPC command.
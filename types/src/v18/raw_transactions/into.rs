@@ -9,6 +9,7 @@ use super::{
     AnalyzePsbtInputMissingError, JoinPsbts, UtxoUpdatePsbt,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl AnalyzePsbt {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
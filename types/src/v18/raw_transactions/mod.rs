@@ -10,6 +10,7 @@ mod into;
 use serde::{Deserialize, Serialize};
 
 pub use self::error::{AnalyzePsbtError, AnalyzePsbtInputMissingError};
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `analyzepsbt`.
 ///
@@ -20,6 +21,7 @@ pub use self::error::{AnalyzePsbtError, AnalyzePsbtInputMissingError};
 /// Arguments:
 /// 1. psbt    (string, required) A base64 string of a PSBT
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct AnalyzePsbt {
     /// Array of input objects.
@@ -39,6 +41,7 @@ pub struct AnalyzePsbt {
 
 /// Represents an input in a PSBT operation. Part of `analyzepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct AnalyzePsbtInput {
     /// Whether a UTXO is provided.
@@ -53,6 +56,7 @@ pub struct AnalyzePsbtInput {
 
 /// Represents missing elements required to complete an input. Part of `analyzepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct AnalyzePsbtInputMissing {
     /// Public key ID, hash160 of the public key, of a public key whose BIP 32 derivation path is missing.
@@ -81,6 +85,7 @@ pub struct AnalyzePsbtInputMissing {
 /// >        ...
 /// >      ]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct JoinPsbts(
     /// The base64-encoded partially signed transaction.
@@ -96,6 +101,7 @@ pub struct JoinPsbts(
 /// > Arguments:
 /// > 1. psbt    (string, required) A base64 string of a PSBT
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct UtxoUpdatePsbt(
     /// The base64-encoded partially signed transaction with inputs updated.
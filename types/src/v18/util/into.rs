@@ -4,6 +4,7 @@ use bitcoin::address;
 
 use super::DeriveAddresses;
 use crate::model;
+use crate::prelude::*;
 
 impl DeriveAddresses {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -8,6 +8,8 @@ mod into;
 
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Result of JSON-RPC method `deriveaddresses`.
 ///
 /// > deriveaddresses "descriptor" ( range )
@@ -15,6 +17,7 @@ use serde::{Deserialize, Serialize};
 /// > Derives one or more addresses corresponding to an output descriptor.
 /// > Returns an array of derived addresses.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DeriveAddresses(pub Vec<String>);
 
@@ -25,6 +28,7 @@ pub struct DeriveAddresses(pub Vec<String>);
 /// > Analyses a descriptor.
 /// > Returns information about the descriptor.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetDescriptorInfo {
     /// The descriptor in canonical form, without private keys.
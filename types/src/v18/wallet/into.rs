@@ -14,6 +14,7 @@ use super::{
     ListUnspentItemError,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl GetAddressInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: CC0-1.0
 
-use std::collections::BTreeMap;
+use alloc::collections::BTreeMap;
 
 use bitcoin::hex::{self, FromHex as _};
 use bitcoin::{bip158, Amount, BlockHash, Network, Txid, Work, Wtxid};
@@ -16,6 +16,7 @@ use super::{
     MempoolEntry, MempoolEntryFees, ScanTxOutSetError, ScanTxOutSetStart,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl GetBlockchainInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
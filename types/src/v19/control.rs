@@ -7,6 +7,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::ActiveCommand;
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `getrpcinfo`.
 ///
@@ -14,6 +15,7 @@ use super::ActiveCommand;
 /// >
 /// > Returns details of the RPC server.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetRpcInfo {
     /// All active commands
@@ -2,6 +2,7 @@
 
 use super::{GetNetworkInfo, GetNetworkInfoError};
 use crate::model;
+use crate::prelude::*;
 
 impl GetNetworkInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
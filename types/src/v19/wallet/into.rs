@@ -9,6 +9,7 @@ use super::{
     GetTransactionError, GetWalletInfo, GetWalletInfoError, GetWalletInfoScanning,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl GetBalances {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
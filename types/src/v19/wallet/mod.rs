@@ -11,6 +11,7 @@ use serde::{Deserialize, Serialize};
 
 pub use self::error::GetBalancesError;
 use super::{Bip125Replaceable, GetTransactionDetail, GetTransactionError, GetWalletInfoError};
+use crate::prelude::*;
 
 /// Result of the JSON-RPC method `getbalances`.
 ///
@@ -18,6 +19,7 @@ use super::{Bip125Replaceable, GetTransactionDetail, GetTransactionError, GetWal
 /// >
 /// > Returns an object with all balances in BTC.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBalances {
     /// Balances from outputs that the wallet can sign.
@@ -28,6 +30,7 @@ pub struct GetBalances {
 
 /// Balances from outputs that the wallet can sign. Part of `getbalances`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBalancesMine {
     /// Trusted balance (outputs created by the wallet or confirmed outputs).
@@ -44,6 +47,7 @@ pub struct GetBalancesMine {
 
 /// Hash and height of the block this information was generated on. Part of `getbalances`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBalancesWatchOnly {
     /// Trusted balance (outputs created by the wallet or confirmed outputs).
@@ -63,6 +67,7 @@ pub struct GetBalancesWatchOnly {
 /// > Arguments:
 /// > 1. txid                 (string, required) The transaction id
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetTransaction {
     /// The transaction amount in BTC.
@@ -111,6 +116,7 @@ pub struct GetTransaction {
 /// > getwalletinfo
 /// > Returns an object containing various wallet state info.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetWalletInfo {
     /// The wallet name.
@@ -157,6 +163,7 @@ pub struct GetWalletInfo {
 
 /// Current scanning details. Part of `getwalletinfo`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum GetWalletInfoScanning {
     /// Scanning details.
@@ -175,6 +182,7 @@ pub enum GetWalletInfoScanning {
 /// > 1. flag     (string, required) The name of the flag to change. Current available flags: avoid_reuse
 /// > 2. value    (boolean, optional, default=true) The new state.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct SetWalletFlag {
     /// The name of the flag that was modified.
@@ -8,6 +8,7 @@ use bitcoin::hex;
 use serde::{Deserialize, Serialize};
 
 use crate::model;
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `generatetodescriptor`.
 ///
@@ -20,6 +21,7 @@ use crate::model;
 /// > 2. descriptor    (string, required) The descriptor to send the newly generated bitcoin to.
 /// > 3. maxtries      (numeric, optional, default=1000000) How many iterations to try.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GenerateToDescriptor(
     /// Hashes of blocks generated.
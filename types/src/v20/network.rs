@@ -6,17 +6,22 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::model;
+use crate::prelude::*;
+
 /// Result of JSON-RPC method `listbanned`.
 ///
 /// > listbanned
 ///
 /// > List all banned IPs/Subnets.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ListBanned(pub Vec<Banned>);
 
 /// An banned item. Part of `listbanned`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct Banned {
     /// The IP/Subnet of the banned node.
@@ -26,3 +31,24 @@ pub struct Banned {
     /// The UNIX epoch time the ban was created.
     pub ban_created: u32,
 }
+
+impl ListBanned {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::ListBanned {
+        model::ListBanned(self.0.into_iter().map(|b| b.into_model()).collect())
+    }
+}
+
+impl Banned {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::Banned {
+        model::Banned {
+            subnet: model::Subnet(self.address),
+            ban_created: self.ban_created,
+            banned_until: self.banned_until,
+            ban_duration: self.banned_until.saturating_sub(self.ban_created),
+            time_remaining: None,
+            ban_reason: None,
+        }
+    }
+}
@@ -9,6 +9,7 @@ mod into;
 use serde::{Deserialize, Serialize};
 
 pub use super::CreateMultisigError;
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `createmultisig`.
 ///
@@ -25,6 +26,7 @@ pub use super::CreateMultisigError;
 /// >        ,...
 /// >      ]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct CreateMultisig {
     /// The value of the new multisig address.
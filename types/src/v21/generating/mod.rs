@@ -8,6 +8,8 @@ mod into;
 
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Result of JSON-RPC method `generateblock`.
 ///
 /// > Mine a block with a set of ordered transactions immediately to a specified address or descriptor (before the RPC call returns)
@@ -22,6 +24,7 @@ use serde::{Deserialize, Serialize};
 /// >        ...
 /// >      ]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GenerateBlock {
     /// Hash of generated block
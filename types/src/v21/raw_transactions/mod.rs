@@ -10,6 +10,7 @@ mod into;
 use serde::{Deserialize, Serialize};
 
 pub use self::error::{MempoolAcceptanceError, TestMempoolAcceptError};
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `testmempoolaccept`.
 ///
@@ -26,11 +27,13 @@ pub use self::error::{MempoolAcceptanceError, TestMempoolAcceptError};
 /// >                                         Length must be one for now.
 /// > 2. allowhighfees    (boolean, optional, default=false) Allow high fees
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct TestMempoolAccept(pub Vec<MempoolAcceptance>);
 
 /// A single mempool acceptance test result. Part of `testmempoolaccept`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct MempoolAcceptance {
     /// The transaction hash in hex.
@@ -48,6 +51,7 @@ pub struct MempoolAcceptance {
 
 /// Wrapper for the fees field. Part of `testmempoolaccept`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct MempoolAcceptanceFees {
     /// Transaction fee in BTC.
@@ -4,19 +4,23 @@
 //!
 //! Types for methods found under the `== Util ==` section of the API docs.
 
-use std::collections::BTreeMap;
+use alloc::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Result of JSON-RPC method `getindexinfo`.
 ///
 /// > Returns the status of one or all available indices currently running in the node.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetIndexInfo(pub BTreeMap<String, GetIndexInfoName>);
 
 /// Index info details. Part of `getindexinfo`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetIndexInfoName {
     /// Whether the index is synced or not.
@@ -7,6 +7,7 @@ use super::{
     SendError, SendMany, SendManyVerbose, UnloadWallet,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl UnloadWallet {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
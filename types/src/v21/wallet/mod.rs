@@ -11,12 +11,14 @@ use serde::{Deserialize, Serialize};
 
 pub use self::error::{PsbtBumpFeeError, SendError};
 pub use super::GetWalletInfoError;
+use crate::prelude::*;
 
 /// Result of the JSON-RPC method `getwalletinfo`.
 ///
 /// > getwalletinfo
 /// > Returns an object containing various wallet state info.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetWalletInfo {
     /// The wallet name.
@@ -67,6 +69,7 @@ pub struct GetWalletInfo {
 
 /// Current scanning details. Part of `getwalletinfo`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(untagged)]
 pub enum GetWalletInfoScanning {
     /// Scanning details.
@@ -110,6 +113,7 @@ pub enum GetWalletInfoScanning {
 /// >      ...
 /// >    ]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ImportDescriptors(
     /// Response is an array with the same size as the input that has the execution result.
@@ -118,6 +122,7 @@ pub struct ImportDescriptors(
 
 /// Result object for each descriptor import. Part of `importdescriptors`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ImportDescriptorsResult {
     /// Whether the import was successful.
@@ -137,6 +142,7 @@ pub struct ImportDescriptorsResult {
 /// Arguments:
 /// 1. txid    (string, required) The txid to be bumped
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct PsbtBumpFee {
     /// The base64-encoded unsigned PSBT of the new transaction.
@@ -161,6 +167,7 @@ pub struct PsbtBumpFee {
 /// >    That is, each address can only appear once and there can only be one 'data' object.
 /// >    For convenience, a dictionary, which holds the key-value pairs directly, is also accepted.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct Send {
     /// If the transaction has a complete set of signatures.
@@ -187,6 +194,7 @@ pub struct Send {
 /// > 3. - 9. (optional arguments, see Bitcoin Core documentation)
 /// > 10. verbose (boolean, optional, default=false) If true, return extra infomration about the transaction.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct SendMany(
     /// The transaction id for the send.
@@ -195,6 +203,7 @@ pub struct SendMany(
 
 /// Result of JSON-RPC method `sendmany` when `verbose=true`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct SendManyVerbose {
     /// The transaction id for the send. Only 1 transaction is created regardless of the number of addresses.
@@ -210,6 +219,7 @@ pub struct SendManyVerbose {
 /// > Unloads the wallet referenced by the request endpoint, otherwise unloads the wallet specified in the argument.
 /// > Specifying the wallet name on a wallet endpoint is invalid.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct UnloadWallet {
     /// Warning messages, if any, related to unloading the wallet.
@@ -221,6 +231,7 @@ pub struct UnloadWallet {
 /// > Upgrade the wallet. Upgrades to the latest version if no version number is specified.
 /// > New keys may be generated and a new wallet backup will need to be made.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct UpgradeWallet {
     /// Name of wallet this operation was performed on
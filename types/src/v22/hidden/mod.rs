@@ -6,6 +6,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Result of JSON-RPC method `addconnection`.
 ///
 /// > addconnection "address" "connection_type"
@@ -13,6 +15,7 @@ use serde::{Deserialize, Serialize};
 /// > Open an outbound connection to a specified node.
 /// > This RPC is for testing only.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct AddConnection {
     /// The address of the newly added connection.
@@ -107,7 +107,7 @@
 //! | getnetworkinfo                     | version + model |                                        |
 //! | getnodeaddresses                   | version         |                                        |
 //! | getpeerinfo                        | version         |                                        |
-//! | listbanned                         | version         |                                        |
+//! | listbanned                         | version + model |                                        |
 //! | ping                               | returns nothing |                                        |
 //! | setban                             | returns nothing |                                        |
 //! | setnetworkactive                   | version         |                                        |
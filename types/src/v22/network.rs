@@ -8,6 +8,9 @@ use alloc::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::model;
+use crate::prelude::*;
+
 /// Result of JSON-RPC method `getnodeaddresses`.
 ///
 /// > getnodeaddresses ( count "network" )
@@ -16,11 +19,13 @@ use serde::{Deserialize, Serialize};
 /// > These can potentially be used to find new peers in the network.
 /// > The total number of addresses known to the node may be higher.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetNodeAddresses(pub Vec<NodeAddress>);
 
 /// An node address item. Part of `getnodeaddresses`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct NodeAddress {
     /// Timestamp in seconds since epoch (Jan 1 1970 GMT) when the node was last seen.
@@ -41,11 +46,13 @@ pub struct NodeAddress {
 /// >
 /// > Returns data about each connected network node as a json array of objects.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetPeerInfo(pub Vec<PeerInfo>);
 
 /// A peer info item. Part of `getpeerinfo`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct PeerInfo {
     /// Peer index.
@@ -150,11 +157,13 @@ pub struct PeerInfo {
 ///
 /// > List all banned IPs/Subnets.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ListBanned(pub Vec<Banned>);
 
 /// An banned item. Part of `listbanned`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct Banned {
     /// The IP/Subnet of the banned node.
@@ -168,3 +177,24 @@ pub struct Banned {
     /// The time remaining until the ban expires, in seconds.
     pub time_remaining: u32,
 }
+
+impl ListBanned {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::ListBanned {
+        model::ListBanned(self.0.into_iter().map(|b| b.into_model()).collect())
+    }
+}
+
+impl Banned {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::Banned {
+        model::Banned {
+            subnet: model::Subnet(self.address),
+            ban_created: self.ban_created,
+            banned_until: self.banned_until,
+            ban_duration: self.ban_duration,
+            time_remaining: Some(self.time_remaining),
+            ban_reason: None,
+        }
+    }
+}
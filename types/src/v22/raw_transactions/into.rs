@@ -7,6 +7,7 @@ use super::{
     TestMempoolAcceptError,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl DecodeScript {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
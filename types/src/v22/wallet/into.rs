@@ -10,6 +10,7 @@ use super::{
     WalletDisplayAddress,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl GetAddressInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
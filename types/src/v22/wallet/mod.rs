@@ -9,6 +9,7 @@ mod into;
 use serde::{Deserialize, Serialize};
 
 pub use super::{GetAddressInfoEmbeddedError, GetAddressInfoError, ScriptType};
+use crate::prelude::*;
 
 /// Result of the JSON-RPC method `getaddressinfo`.
 ///
@@ -20,6 +21,7 @@ pub use super::{GetAddressInfoEmbeddedError, GetAddressInfoError, ScriptType};
 /// > Arguments:
 /// > 1. "address"                    (string, required) The bitcoin address to get the information of.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetAddressInfo {
     /// The bitcoin address validated.
@@ -92,6 +94,7 @@ pub struct GetAddressInfo {
 /// It includes all getaddressinfo output fields for the embedded address, excluding metadata
 /// ("timestamp", "hdkeypath", "hdseedid") and relation to the wallet ("ismine", "iswatchonly").
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetAddressInfoEmbedded {
     /// The bitcoin address validated.
@@ -144,6 +147,7 @@ pub struct GetAddressInfoEmbedded {
 ///
 /// > List descriptors imported into a descriptor-enabled wallet.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ListDescriptors {
     /// Name of wallet this operation was performed on.
@@ -154,6 +158,7 @@ pub struct ListDescriptors {
 
 /// A descriptor object from `listdescriptors`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DescriptorInfo {
     /// Descriptor string representation.
@@ -175,6 +180,7 @@ pub struct DescriptorInfo {
 ///
 /// > Display address on an external signer for verification.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct WalletDisplayAddress {
     /// The address as confirmed by the signer
@@ -11,6 +11,7 @@ use super::{
     GetRawMempoolVerbose, MapMempoolEntryError, MempoolEntry, MempoolEntryError,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl GetBlockchainInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -7,10 +7,11 @@
 mod error;
 mod into;
 
-use std::collections::HashMap;
+use alloc::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
 use crate::ScriptSig;
 
 #[rustfmt::skip]                // Keep public re-exports separate.
@@ -30,6 +31,7 @@ pub use crate::psbt::{Bip32Deriv, PsbtScript, RawTransaction, WitnessUtxo};
 /// > Arguments:
 /// > 1. "psbt"            (string, required) The PSBT base64 string
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DecodePsbt {
     /// The decoded network-serialized unsigned transaction.
@@ -41,7 +43,7 @@ pub struct DecodePsbt {
     /// The global proprietary map.
     pub proprietary: Option<Vec<Proprietary>>,
     /// The unknown global fields.
-    pub unknown: Option<HashMap<String, String>>,
+    pub unknown: Option<BTreeMap<String, String>>,
     /// Array of transaction inputs.
     pub inputs: Vec<PsbtInput>,
     /// Array of transaction outputs.
@@ -52,6 +54,7 @@ pub struct DecodePsbt {
 
 /// An item from the global xpubs list. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GlobalXpub {
     /// The extended public key this path corresponds to.
@@ -64,6 +67,7 @@ pub struct GlobalXpub {
 
 /// An item from the global proprietary list. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct Proprietary {
     /// The hex string for the proprietary identifier.
@@ -78,6 +82,7 @@ pub struct Proprietary {
 
 /// An input in a partially signed Bitcoin transaction. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct PsbtInput {
     /// Decoded network transaction for non-witness UTXOs.
@@ -85,7 +90,7 @@ pub struct PsbtInput {
     /// Transaction output for witness UTXOs.
     pub witness_utxo: Option<WitnessUtxo>,
     /// The public key and signature that corresponds to it.
-    pub partial_signatures: Option<HashMap<String, String>>,
+    pub partial_signatures: Option<BTreeMap<String, String>>,
     /// The sighash type to be used.
     pub sighash: Option<String>,
     /// The redeem script.
@@ -101,21 +106,22 @@ pub struct PsbtInput {
     #[serde(rename = "final_scriptwitness")]
     pub final_script_witness: Option<Vec<String>>,
     /// The hash and preimage that corresponds to it.
-    pub ripemd160_preimages: Option<HashMap<String, String>>,
+    pub ripemd160_preimages: Option<BTreeMap<String, String>>,
     /// The hash and preimage that corresponds to it.
-    pub sha256_preimages: Option<HashMap<String, String>>,
+    pub sha256_preimages: Option<BTreeMap<String, String>>,
     /// The hash and preimage that corresponds to it.
-    pub hash160_preimages: Option<HashMap<String, String>>,
+    pub hash160_preimages: Option<BTreeMap<String, String>>,
     /// The hash and preimage that corresponds to it.
-    pub hash256_preimages: Option<HashMap<String, String>>,
+    pub hash256_preimages: Option<BTreeMap<String, String>>,
     /// The input proprietary map.
     pub proprietary: Option<Vec<Proprietary>>,
     /// The unknown input fields.
-    pub unknown: Option<HashMap<String, String>>,
+    pub unknown: Option<BTreeMap<String, String>>,
 }
 
 /// An output in a partially signed Bitcoin transaction. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct PsbtOutput {
     /// The redeem script.
@@ -127,7 +133,7 @@ pub struct PsbtOutput {
     /// The output proprietary map.
     pub proprietary: Option<Vec<Proprietary>>,
     /// The unknown global fields.
-    pub unknown: Option<HashMap<String, String>>,
+    pub unknown: Option<BTreeMap<String, String>>,
 }
 
 /// Result of JSON-RPC method `decodescript`.
@@ -140,6 +146,7 @@ pub struct PsbtOutput {
 /// > 1. "hexstring"     (string) the hex encoded script
 // The docs on Core v0.17 appear to be way off what is actually returned.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DecodeScript {
     /// Script public key.
@@ -168,6 +175,7 @@ pub struct DecodeScript {
 
 /// Segwit data. Part of `decodescript`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DecodeScriptSegwit {
     /// Script public key.
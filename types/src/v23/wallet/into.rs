@@ -9,6 +9,7 @@ use super::{
     ListTransactions, TransactionItem, TransactionItemError,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl AddMultisigAddress {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -11,6 +11,7 @@ use super::{
     GetTxSpendingPrevoutItem, MapMempoolEntryError, MempoolEntry, MempoolEntryError,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl GetMempoolAncestors {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: CC0-1.0
 
-use std::collections::BTreeMap;
+use alloc::collections::BTreeMap;
 
 use bitcoin::bip32::{DerivationPath, Fingerprint, KeySource, Xpub};
 use bitcoin::hashes::{hash160, ripemd160, sha256, sha256d};
@@ -18,6 +18,7 @@ use super::{
     TaprootScriptPathSig, TaprootScriptPathSigError,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl DecodePsbt {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
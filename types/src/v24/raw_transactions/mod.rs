@@ -7,10 +7,11 @@
 mod error;
 mod into;
 
-use std::collections::HashMap;
+use alloc::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
 use crate::ScriptSig;
 
 #[rustfmt::skip]                // Keep public re-exports separate.
@@ -31,6 +32,7 @@ pub use crate::psbt::{Bip32Deriv, PsbtScript, RawTransaction, WitnessUtxo};
 /// > Arguments:
 /// > 1. "psbt"            (string, required) The PSBT base64 string
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DecodePsbt {
     /// The decoded network-serialized unsigned transaction.
@@ -42,7 +44,7 @@ pub struct DecodePsbt {
     /// The global proprietary map.
     pub proprietary: Option<Vec<Proprietary>>,
     /// The unknown global fields.
-    pub unknown: Option<HashMap<String, String>>,
+    pub unknown: Option<BTreeMap<String, String>>,
     /// Array of transaction inputs.
     pub inputs: Vec<PsbtInput>,
     /// Array of transaction outputs.
@@ -53,6 +55,7 @@ pub struct DecodePsbt {
 
 /// An item from the global xpubs list. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GlobalXpub {
     /// The extended public key this path corresponds to.
@@ -65,6 +68,7 @@ pub struct GlobalXpub {
 
 /// An item from the global proprietary list. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct Proprietary {
     /// The hex string for the proprietary identifier.
@@ -79,6 +83,7 @@ pub struct Proprietary {
 
 /// An input in a partially signed Bitcoin transaction. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct PsbtInput {
     /// Decoded network transaction for non-witness UTXOs.
@@ -86,7 +91,7 @@ pub struct PsbtInput {
     /// Transaction output for witness UTXOs.
     pub witness_utxo: Option<WitnessUtxo>,
     /// The public key and signature that corresponds to it.
-    pub partial_signatures: Option<HashMap<String, String>>,
+    pub partial_signatures: Option<BTreeMap<String, String>>,
     /// The sighash type to be used.
     pub sighash: Option<String>,
     /// The redeem script.
@@ -102,13 +107,13 @@ pub struct PsbtInput {
     #[serde(rename = "final_scriptwitness")]
     pub final_script_witness: Option<Vec<String>>,
     /// The hash and preimage that corresponds to it.
-    pub ripemd160_preimages: Option<HashMap<String, String>>,
+    pub ripemd160_preimages: Option<BTreeMap<String, String>>,
     /// The hash and preimage that corresponds to it.
-    pub sha256_preimages: Option<HashMap<String, String>>,
+    pub sha256_preimages: Option<BTreeMap<String, String>>,
     /// The hash and preimage that corresponds to it.
-    pub hash160_preimages: Option<HashMap<String, String>>,
+    pub hash160_preimages: Option<BTreeMap<String, String>>,
     /// The hash and preimage that corresponds to it.
-    pub hash256_preimages: Option<HashMap<String, String>>,
+    pub hash256_preimages: Option<BTreeMap<String, String>>,
     /// Hex-encoded signature for the Taproot key path spend.
     pub taproot_key_path_sig: Option<String>,
     /// The signature for the pubkey and leaf hash combination.
@@ -124,11 +129,12 @@ pub struct PsbtInput {
     /// The input proprietary map.
     pub proprietary: Option<Vec<Proprietary>>,
     /// The unknown input fields.
-    pub unknown: Option<HashMap<String, String>>,
+    pub unknown: Option<BTreeMap<String, String>>,
 }
 
 /// An output in a partially signed Bitcoin transaction. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct PsbtOutput {
     /// The redeem script.
@@ -146,11 +152,12 @@ pub struct PsbtOutput {
     /// The output proprietary map.
     pub proprietary: Option<Vec<Proprietary>>,
     /// The unknown global fields.
-    pub unknown: Option<HashMap<String, String>>,
+    pub unknown: Option<BTreeMap<String, String>>,
 }
 
 /// An item from the `taproot_script_path_sigs` list. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct TaprootScriptPathSig {
     /// The x-only pubkey for this signature.
@@ -163,6 +170,7 @@ pub struct TaprootScriptPathSig {
 
 /// An item from the `taproot_scripts` list. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct TaprootScript {
     /// A leaf script.
@@ -176,6 +184,7 @@ pub struct TaprootScript {
 
 /// An item from the `taproot_bip32_derivs` list. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct TaprootBip32Deriv {
     /// The x-only public key this path corresponds to.
@@ -190,6 +199,7 @@ pub struct TaprootBip32Deriv {
 
 /// A Taproot leaf script at depth with version. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct TaprootLeaf {
     /// The depth of this element in the tree.
@@ -213,6 +223,7 @@ pub mod taproot {
     use bitcoin::{secp256k1, taproot, TapSighashType};
 
     use crate::error::write_err;
+    use crate::prelude::*;
 
     /// Parses a Taproot signature from a hex string.
     pub fn signature_from_str(sig: &str) -> Result<taproot::Signature, Error> {
@@ -11,6 +11,7 @@ use super::{
     TransactionItemError,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl GetTransaction {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -8,6 +8,7 @@ use super::{
     ScanTxOutSetUnspent,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl GetBlockStats {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -11,6 +11,7 @@ use serde::{Deserialize, Serialize};
 
 pub use self::error::ScanBlocksStartError;
 pub use super::{GetBlockStatsError, ScanTxOutSetError};
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `getblockstats`.
 ///
@@ -29,6 +30,7 @@ pub use super::{GetBlockStatsError, ScanTxOutSetError};
 /// >        ...
 /// >    ]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBlockStats {
     /// Average fee in the block.
@@ -125,6 +127,7 @@ pub struct GetBlockStats {
 /// >
 /// > Aborts the current scan and returns whether an abort was successful.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ScanBlocksAbort(pub bool);
 
 /// Result of JSON-RPC method `scanblocks` with action "start".
@@ -134,6 +137,7 @@ pub struct ScanBlocksAbort(pub bool);
 /// > Arguments:
 /// > 1. scanobjects                            (json array, required) Array of scan objects
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ScanBlocksStart {
     /// The height we started the scan from
     pub from_height: i64,
@@ -149,6 +153,7 @@ pub struct ScanBlocksStart {
 /// >
 /// > Returns progress report (in %) of the current scan.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct ScanBlocksStatus {
     /// Approximate percent complete
     pub progress: f64,
@@ -164,6 +169,7 @@ pub struct ScanBlocksStatus {
 /// > 1. action                        (string, required) The action to execute
 /// > 2. scanobjects                   (json array, required) Array of scan objects
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ScanTxOutSetStart {
     /// Whether the scan was completed.
@@ -184,6 +190,7 @@ pub struct ScanTxOutSetStart {
 
 /// Unspent outputs. Part of `scantxoutset`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ScanTxOutSetUnspent {
     /// The transaction id.
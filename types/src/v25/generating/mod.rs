@@ -10,6 +10,7 @@ mod into;
 use serde::{Deserialize, Serialize};
 
 pub use self::error::GenerateBlockError;
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `generateblock`.
 ///
@@ -25,6 +26,7 @@ pub use self::error::GenerateBlockError;
 /// >        ...
 /// >      ]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GenerateBlock {
     /// Hash of generated block
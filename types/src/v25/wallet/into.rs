@@ -2,6 +2,7 @@
 
 use super::{CreateWallet, LoadWallet, UnloadWallet};
 use crate::model;
+use crate::prelude::*;
 
 impl CreateWallet {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
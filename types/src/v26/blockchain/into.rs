@@ -8,6 +8,7 @@ use super::{
     GetTxOutSetInfoError, LoadTxOutSet, LoadTxOutSetError, ScanBlocksStart,
 };
 use crate::model;
+use crate::prelude::*;
 use crate::v25::ScanBlocksStartError;
 
 impl GetChainStates {
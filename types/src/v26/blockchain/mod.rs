@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 pub use self::error::{
     DumpTxOutSetError, GetChainStatesError, GetTxOutSetInfoError, LoadTxOutSetError,
 };
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `dumptxoutset`.
 ///
@@ -22,6 +23,7 @@ pub use self::error::{
 /// > Arguments:
 /// > 1. path    (string, required) Path to the output file. If relative, will be prefixed by datadir.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DumpTxOutSet {
     /// The number of coins written in the snapshot.
@@ -46,6 +48,7 @@ pub struct DumpTxOutSet {
 /// >
 /// > Return information about chainstates.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetChainStates {
     /// The number of headers seen so far.
@@ -57,6 +60,7 @@ pub struct GetChainStates {
 
 /// A single chainstate. Part of `getchainstates`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ChainState {
     /// Number of blocks in this chainstate.
@@ -92,6 +96,7 @@ pub struct ChainState {
 /// > 2. hash_or_height    (string or numeric, optional, default=the current best block) The block hash or height of the target height (only available with coinstatsindex).
 /// > 3. use_index         (boolean, optional, default=true) Use coinstatsindex, if available.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetTxOutSetInfo {
     /// The current block height (index).
@@ -123,6 +128,7 @@ pub struct GetTxOutSetInfo {
 
 /// Detailed block-level info returned by `gettxoutsetinfo` when coinstatsindex is enabled.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetTxOutSetInfoBlockInfo {
     /// Total amount of all prevouts spent in this block.
@@ -141,6 +147,7 @@ pub struct GetTxOutSetInfoBlockInfo {
 
 /// Categories of unspendable amounts returned inside `BlockInfo`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetTxOutSetInfoUnspendables {
     /// The unspendable amount of the Genesis block subsidy.
@@ -163,6 +170,7 @@ pub struct GetTxOutSetInfoUnspendables {
 /// > Arguments:
 /// > 1. path    (string, required) path to the snapshot file. If relative, will be prefixed by datadir.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct LoadTxOutSet {
     /// The number of coins loaded from the snapshot.
@@ -182,6 +190,7 @@ pub struct LoadTxOutSet {
 /// > Arguments:
 /// > 1. scanobjects                            (json array, required) Array of scan objects
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ScanBlocksStart {
     /// The height we started the scan from
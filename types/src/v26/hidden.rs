@@ -8,6 +8,8 @@ use alloc::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Result of JSON-RPC method `getrawaddrman`.
 ///
 /// > getrawaddrman
@@ -18,6 +20,7 @@ use serde::{Deserialize, Serialize};
 ///
 /// This is a hidden RPC, useful for testing and development.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetRawAddrMan {
     /// Addresses in the "new" table (potential peers discovered but not yet connected to).
@@ -33,6 +36,7 @@ pub struct GetRawAddrMan {
 ///
 /// Field order matches Bitcoin Core's RPC response definition.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct RawAddrManEntry {
     /// The address of the node.
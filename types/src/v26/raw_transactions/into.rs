@@ -4,6 +4,7 @@ use bitcoin::{consensus, Amount, Psbt, Txid, Wtxid};
 
 // TODO: Use explicit imports?
 use super::*;
+use crate::prelude::*;
 
 impl DescriptorProcessPsbt {
     /// Converts version specific type to a version in-specific, more strongly typed type.
@@ -14,6 +14,7 @@ use serde::{Deserialize, Serialize};
 // TODO: Remove wildcard, use explicit types.
 pub use self::error::*;
 use crate::model;
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `descriptorprocesspsbt`.
 ///
@@ -34,6 +35,7 @@ use crate::model;
 /// >        ...
 /// >      ]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DescriptorProcessPsbt {
     /// The base64-encoded partially signed transaction.
@@ -62,6 +64,7 @@ pub struct DescriptorProcessPsbt {
 /// >        ...
 /// >      ]
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct SubmitPackage {
     /// The transaction package result message.
@@ -78,6 +81,7 @@ pub struct SubmitPackage {
 
 /// The per-transaction result. Part of `submitpackage`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct SubmitPackageTxResult {
     /// The transaction id.
@@ -97,6 +101,7 @@ pub struct SubmitPackageTxResult {
 
 /// The fees included in the per-transaction result. Part of `submitpackage`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct SubmitPackageTxResultFees {
     /// Transaction fee.
@@ -9,6 +9,7 @@ use super::{
     LastProcessedBlockError, LoadWallet, UnloadWallet, WalletProcessPsbt, WalletProcessPsbtError,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl CreateWallet {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -4,12 +4,13 @@
 //!
 //! Types for methods found under the `== Mining ==` section of the API docs.
 
-use std::collections::BTreeMap;
+use alloc::collections::BTreeMap;
 
 use bitcoin::{hex, SignedAmount, Txid};
 use serde::{Deserialize, Serialize};
 
 use crate::model;
+use crate::prelude::*;
 
 /// Result of the JSON-RPC method `getprioritisedtransactions`.
 ///
@@ -17,6 +18,7 @@ use crate::model;
 /// >
 /// > Returns a map of all user-created (see prioritisetransaction) fee deltas by txid, and whether the tx is present in mempool.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetPrioritisedTransactions(
     /// prioritisation keyed by txid.
@@ -25,6 +27,7 @@ pub struct GetPrioritisedTransactions(
 
 /// An individual prioritised transaction. Part of `getprioritisedtransactions`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct PrioritisedTransaction {
     /// Transaction fee delta in satoshis.
@@ -9,6 +9,7 @@ use super::{
     ScanTxOutSetUnspent,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl GetBlockchainInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -11,6 +11,7 @@ use alloc::collections::BTreeMap;
 use serde::{Deserialize, Serialize};
 
 use super::{GetBlockchainInfoError, ScanTxOutSetError, Softfork};
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `getblockchaininfo`.
 ///
@@ -18,6 +19,7 @@ use super::{GetBlockchainInfoError, ScanTxOutSetError, Softfork};
 ///
 /// > Returns an object containing various state info regarding blockchain processing.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBlockchainInfo {
     /// Current network name as defined in BIP70 (main, test, signet, regtest).
@@ -71,6 +73,7 @@ pub struct GetBlockchainInfo {
 /// > 1. action                        (string, required) The action to execute
 /// > 2. scanobjects                   (json array, required) Array of scan objects
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ScanTxOutSetStart {
     /// Whether the scan was completed.
@@ -91,6 +94,7 @@ pub struct ScanTxOutSetStart {
 
 /// Unspent outputs. Part of `scantxoutset`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ScanTxOutSetUnspent {
     /// The transaction id.
@@ -8,6 +8,7 @@ use bitcoin::Weight;
 use serde::{Deserialize, Serialize};
 
 use crate::model;
+use crate::prelude::*;
 
 /// Result of the JSON-RPC method `getmininginfo`.
 ///
@@ -15,6 +16,7 @@ use crate::model;
 /// >
 /// > Returns a json object containing mining-related information.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetMiningInfo {
     /// The current block.
@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 
 use super::{GetNetworkInfoAddress, GetNetworkInfoError, GetNetworkInfoNetwork};
 use crate::model;
+use crate::prelude::*;
 
 /// Result of the JSON-RPC method `getnetworkinfo`.
 ///
@@ -14,6 +15,7 @@ use crate::model;
 ///
 /// > Returns an object containing various state info regarding P2P networking.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetNetworkInfo {
     /// The server version.
@@ -15,6 +15,7 @@ use super::{
     ListSinceBlockError, ListTransactions, TransactionItem, TransactionItemError,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl GetAddressInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -8,6 +8,7 @@ use bitcoin::hex::HexToBytesError;
 use bitcoin::{address, amount, hex, network};
 
 use crate::error::write_err;
+use crate::prelude::*;
 use crate::psbt::{RawTransactionInputError, RawTransactionOutputError};
 use crate::v17::GetRawTransactionVerboseError;
 use crate::{NumericError, ScriptPubKeyError};
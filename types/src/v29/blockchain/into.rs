@@ -11,6 +11,7 @@ use bitcoin::{
 
 // TODO: Use explicit imports?
 use super::*;
+use crate::prelude::*;
 
 impl GetBlockVerboseOne {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
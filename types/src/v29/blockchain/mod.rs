@@ -13,12 +13,14 @@ pub use self::error::{
     GetBlockVerboseThreeError, GetBlockVerboseTwoError, GetBlockchainInfoError,
     GetChainStatesError, GetDescriptorActivityError,
 };
+use crate::prelude::*;
 use crate::psbt::{RawTransactionInput, RawTransactionOutput};
 use crate::v17::GetRawTransactionVerbose;
 use crate::{model, ScriptPubKey};
 
 /// Result of JSON-RPC method `getblock` with verbosity set to 1.
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBlockVerboseOne {
     /// The block hash (same as provided) in RPC call.
@@ -73,6 +75,7 @@ pub struct GetBlockVerboseOne {
 
 /// Result of JSON-RPC method `getblock` with verbosity set to 2.
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBlockVerboseTwo {
     /// The block hash (same as provided).
@@ -127,6 +130,7 @@ pub struct GetBlockVerboseTwo {
 
 /// A transaction entry for `getblock` verbosity 2.
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBlockVerboseTwoTransaction {
     /// The transaction data (same as `getrawtransaction` verbose output).
@@ -138,6 +142,7 @@ pub struct GetBlockVerboseTwoTransaction {
 
 /// Result of JSON-RPC method `getblock` with verbosity set to 3.
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBlockVerboseThree {
     /// The block hash (same as provided) in RPC call.
@@ -192,6 +197,7 @@ pub struct GetBlockVerboseThree {
 
 /// A transaction entry for `getblock` verbosity 3.
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBlockVerboseThreeTransaction {
     /// The transaction data (same as `getrawtransaction` verbose output, plus prevout info).
@@ -203,6 +209,7 @@ pub struct GetBlockVerboseThreeTransaction {
 
 /// Result of JSON-RPC method `getrawtransaction` with extra prevout info in inputs.
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetRawTransactionVerboseWithPrevout {
     /// Whether specified block is in the active chain or not (only present with explicit "blockhash" argument).
@@ -245,6 +252,7 @@ pub struct GetRawTransactionVerboseWithPrevout {
 
 /// A transaction input with optional prevout data (verbosity 3 only).
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct RawTransactionInputWithPrevout {
     /// The input data.
@@ -256,6 +264,7 @@ pub struct RawTransactionInputWithPrevout {
 
 /// The prevout information for a transaction input.
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBlockVerboseThreePrevout {
     /// Coinbase or not.
@@ -275,6 +284,7 @@ pub struct GetBlockVerboseThreePrevout {
 /// >
 /// > Returns an object containing various state info regarding blockchain processing.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBlockchainInfo {
     /// Current network name as defined in BIP70 (main, test, signet, regtest).
@@ -328,6 +338,7 @@ pub struct GetBlockchainInfo {
 /// > Arguments:
 /// > 1. "hash"          (string, required) The block hash
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBlockHeader(pub String);
 
@@ -339,6 +350,7 @@ pub struct GetBlockHeader(pub String);
 /// > Arguments:
 /// > 1. "hash"          (string, required) The block hash
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBlockHeaderVerbose {
     /// The block hash.
@@ -388,6 +400,7 @@ pub struct GetBlockHeaderVerbose {
 /// >
 /// > Return information about chainstates.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetChainStates {
     /// The number of headers seen so far.
@@ -399,6 +412,7 @@ pub struct GetChainStates {
 
 /// A single chainstate. Part of `getchainstates`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ChainState {
     /// Number of blocks in this chainstate.
@@ -435,6 +449,7 @@ pub struct ChainState {
 /// > 2. scanobjects  (json array, optional) Array of scan objects. Required for "start" action
 /// > 3. include_mempool  (boolean, optional, default=true) Whether to include unconfirmed activitydata
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetDescriptorActivity {
     pub activity: Vec<ActivityEntry>,
@@ -442,6 +457,7 @@ pub struct GetDescriptorActivity {
 
 /// Enum representing either a spend or receive activity entry. Part of `getdescriptoractivity`.
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum ActivityEntry {
     /// The spend activity.
@@ -452,6 +468,7 @@ pub enum ActivityEntry {
 
 /// Represents a 'spend' activity event. Part of `getdescriptoractivity`.
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct SpendActivity {
     // Note: 'type' field is used for deserialization tag, not included here explicitly.
@@ -478,6 +495,7 @@ pub struct SpendActivity {
 
 /// Represents a 'receive' activity event. Part of `getdescriptoractivity`.
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct ReceiveActivity {
     // Note: 'type' field is used for deserialization tag, not included here explicitly.
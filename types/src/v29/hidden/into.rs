@@ -10,6 +10,7 @@ use super::{
     GetOrphanTxsVerboseTwoEntryError,
 };
 use crate::model;
+use crate::prelude::*;
 
 impl GetOrphanTxs {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 pub use self::error::{
     GetOrphanTxsError, GetOrphanTxsVerboseOneEntryError, GetOrphanTxsVerboseTwoEntryError,
 };
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `getorphantxs` verbosity 0.
 ///
@@ -19,6 +20,7 @@ pub use self::error::{
 /// >
 /// > Shows transactions in the tx orphanage.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetOrphanTxs(pub Vec<String>);
 
@@ -28,10 +30,12 @@ pub struct GetOrphanTxs(pub Vec<String>);
 /// >
 /// > Shows transactions in the tx orphanage.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetOrphanTxsVerboseOne(pub Vec<GetOrphanTxsVerboseOneEntry>);
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetOrphanTxsVerboseOneEntry {
     /// The transaction hash in hex.
@@ -60,10 +64,12 @@ pub struct GetOrphanTxsVerboseOneEntry {
 /// >
 /// > Shows transactions in the tx orphanage.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetOrphanTxsVerboseTwo(pub Vec<GetOrphanTxsVerboseTwoEntry>);
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetOrphanTxsVerboseTwoEntry {
     /// The transaction hash in hex.
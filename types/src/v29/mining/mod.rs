@@ -10,6 +10,7 @@ mod into;
 use serde::{Deserialize, Serialize};
 
 pub use self::error::{GetMiningInfoError, NextBlockInfoError};
+use crate::prelude::*;
 
 /// Result of the JSON-RPC method `getmininginfo`.
 ///
@@ -17,6 +18,7 @@ pub use self::error::{GetMiningInfoError, NextBlockInfoError};
 /// >
 /// > Returns a json object containing mining-related information.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetMiningInfo {
     /// The current block.
@@ -54,6 +56,7 @@ pub struct GetMiningInfo {
 
 /// Represents the `next` block information. Part of `getmininginfo`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct NextBlockInfo {
     /// The next height.
@@ -9,6 +9,7 @@ use serde::{Deserialize, Serialize};
 
 use super::DeriveAddresses;
 use crate::model;
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `deriveaddresses` for multipath descriptors.
 ///
@@ -17,6 +18,7 @@ use crate::model;
 /// > Derives one or more addresses corresponding to an output descriptor.
 /// > Returns an array of derived addresses.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DeriveAddressesMultipath(pub Vec<DeriveAddresses>);
 
@@ -39,6 +41,7 @@ impl DeriveAddressesMultipath {
 /// > Analyses a descriptor.
 /// > Returns information about the descriptor.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetDescriptorInfo {
     /// The descriptor in canonical form, without private keys. For a multipath descriptor, only the
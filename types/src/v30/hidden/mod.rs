@@ -11,6 +11,7 @@ use serde::{Deserialize, Serialize};
 pub use super::{
     GetOrphanTxsError, GetOrphanTxsVerboseOneEntryError, GetOrphanTxsVerboseTwoEntryError,
 };
+use crate::prelude::*;
 
 /// Result of JSON-RPC method `getorphantxs` verbosity 0.
 ///
@@ -18,6 +19,7 @@ pub use super::{
 /// >
 /// > Shows transactions in the tx orphanage.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetOrphanTxs(pub Vec<String>);
 
@@ -27,10 +29,12 @@ pub struct GetOrphanTxs(pub Vec<String>);
 /// >
 /// > Shows transactions in the tx orphanage.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetOrphanTxsVerboseOne(pub Vec<GetOrphanTxsVerboseOneEntry>);
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetOrphanTxsVerboseOneEntry {
     /// The transaction hash in hex
@@ -59,10 +63,12 @@ pub struct GetOrphanTxsVerboseOneEntry {
 /// >
 /// > Shows transactions in the tx orphanage.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetOrphanTxsVerboseTwo(pub Vec<GetOrphanTxsVerboseTwoEntry>);
 
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetOrphanTxsVerboseTwoEntry {
     /// The transaction hash in hex
@@ -11,6 +11,7 @@ use serde::{Deserialize, Serialize};
 
 pub use self::error::GetMiningInfoError;
 pub use super::{NextBlockInfo, NextBlockInfoError};
+use crate::prelude::*;
 
 /// Result of the JSON-RPC method `getmininginfo`.
 ///
@@ -18,6 +19,7 @@ pub use super::{NextBlockInfo, NextBlockInfoError};
 /// >
 /// > Returns a json object containing mining-related information.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetMiningInfo {
     /// The current block.
@@ -7,10 +7,11 @@
 mod error;
 mod into;
 
-use std::collections::HashMap;
+use alloc::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
 use crate::ScriptSig;
 
 #[rustfmt::skip]                // Keep public re-exports separate.
@@ -31,6 +32,7 @@ pub use crate::psbt::{Bip32Deriv, PsbtScript, RawTransaction, WitnessUtxo};
 /// > Arguments:
 /// > 1. "psbt"            (string, required) The PSBT base64 string
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct DecodePsbt {
     /// The decoded network-serialized unsigned transaction.
@@ -42,7 +44,7 @@ pub struct DecodePsbt {
     /// The global proprietary map.
     pub proprietary: Option<Vec<Proprietary>>,
     /// The unknown global fields.
-    pub unknown: Option<HashMap<String, String>>,
+    pub unknown: Option<BTreeMap<String, String>>,
     /// Array of transaction inputs.
     pub inputs: Vec<PsbtInput>,
     /// Array of transaction outputs.
@@ -53,6 +55,7 @@ pub struct DecodePsbt {
 
 /// An item from the global xpubs list. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GlobalXpub {
     /// The extended public key this path corresponds to.
@@ -65,6 +68,7 @@ pub struct GlobalXpub {
 
 /// An item from the global proprietary list. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct Proprietary {
     /// The hex string for the proprietary identifier.
@@ -81,6 +85,7 @@ pub struct Proprietary {
 ///
 /// TODO: Update model once Musig is supported in rust-bitcoin.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct PsbtInput {
     /// Decoded network transaction for non-witness UTXOs.
@@ -88,7 +93,7 @@ pub struct PsbtInput {
     /// Transaction output for witness UTXOs.
     pub witness_utxo: Option<WitnessUtxo>,
     /// The public key and signature that corresponds to it.
-    pub partial_signatures: Option<HashMap<String, String>>,
+    pub partial_signatures: Option<BTreeMap<String, String>>,
     /// The sighash type to be used.
     pub sighash: Option<String>,
     /// The redeem script.
@@ -104,13 +109,13 @@ pub struct PsbtInput {
     #[serde(rename = "final_scriptwitness")]
     pub final_script_witness: Option<Vec<String>>,
     /// The hash and preimage that corresponds to it.
-    pub ripemd160_preimages: Option<HashMap<String, String>>,
+    pub ripemd160_preimages: Option<BTreeMap<String, String>>,
     /// The hash and preimage that corresponds to it.
-    pub sha256_preimages: Option<HashMap<String, String>>,
+    pub sha256_preimages: Option<BTreeMap<String, String>>,
     /// The hash and preimage that corresponds to it.
-    pub hash160_preimages: Option<HashMap<String, String>>,
+    pub hash160_preimages: Option<BTreeMap<String, String>>,
     /// The hash and preimage that corresponds to it.
-    pub hash256_preimages: Option<HashMap<String, String>>,
+    pub hash256_preimages: Option<BTreeMap<String, String>>,
     /// Hex-encoded signature for the Taproot key path spend.
     pub taproot_key_path_sig: Option<String>,
     /// The signature for the pubkey and leaf hash combination.
@@ -132,11 +137,12 @@ pub struct PsbtInput {
     /// The input proprietary map.
     pub proprietary: Option<Vec<Proprietary>>,
     /// The unknown input fields.
-    pub unknown: Option<HashMap<String, String>>,
+    pub unknown: Option<BTreeMap<String, String>>,
 }
 
 /// An output in a partially signed Bitcoin transaction. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct PsbtOutput {
     /// The redeem script.
@@ -156,11 +162,12 @@ pub struct PsbtOutput {
     /// The output proprietary map.
     pub proprietary: Option<Vec<Proprietary>>,
     /// The unknown global fields.
-    pub unknown: Option<HashMap<String, String>>,
+    pub unknown: Option<BTreeMap<String, String>>,
 }
 
 /// An item from the `taproot_script_path_sigs` list. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct TaprootScriptPathSig {
     /// The x-only pubkey for this signature.
@@ -173,6 +180,7 @@ pub struct TaprootScriptPathSig {
 
 /// An item from the `taproot_scripts` list. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct TaprootScript {
     /// A leaf script.
@@ -186,6 +194,7 @@ pub struct TaprootScript {
 
 /// An item from the `taproot_bip32_derivs` list. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct TaprootBip32Deriv {
     /// The x-only public key this path corresponds to.
@@ -200,6 +209,7 @@ pub struct TaprootBip32Deriv {
 
 /// A Taproot leaf script at depth with version. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct TaprootLeaf {
     /// The depth of this element in the tree.
@@ -213,6 +223,7 @@ pub struct TaprootLeaf {
 
 /// MuSig2 participant public keys. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct Musig2ParticipantPubKeys {
     /// The compressed aggregate public key for which the participants create.
@@ -223,6 +234,7 @@ pub struct Musig2ParticipantPubKeys {
 
 /// MuSig2 public nonce. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct Musig2Pubnonce {
     /// The compressed public key of the participant that created this pubnonce.
@@ -237,6 +249,7 @@ pub struct Musig2Pubnonce {
 
 /// MuSig2 partial signature. Part of `decodepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct Musig2PartialSig {
     /// The compressed public key of the participant that created this partial signature.
@@ -261,6 +274,7 @@ pub mod taproot {
     use bitcoin::{secp256k1, taproot, TapSighashType};
 
     use crate::error::write_err;
+    use crate::prelude::*;
 
     /// Parses a Taproot signature from a hex string.
     pub fn signature_from_str(sig: &str) -> Result<taproot::Signature, Error> {
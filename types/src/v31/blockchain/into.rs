@@ -4,6 +4,7 @@ use bitcoin::{hex, Amount, Txid};
 
 use super::GetMempoolCluster;
 use crate::model;
+use crate::prelude::*;
 
 impl GetMempoolCluster {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
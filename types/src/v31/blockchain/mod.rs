@@ -8,12 +8,15 @@ mod into;
 
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Result of JSON-RPC method `getmempoolcluster`.
 ///
 /// > getmempoolcluster "txid"
 /// >
 /// > Returns mempool data for given cluster
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetMempoolCluster {
     /// Total sigops-adjusted weight (as defined in BIP 141 and modified by `-bytespersigop`).
@@ -27,6 +30,7 @@ pub struct GetMempoolCluster {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct Chunk {
     /// Fees of the transactions in this chunk.
     #[serde(rename = "chunkfee")]
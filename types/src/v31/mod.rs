@@ -111,7 +111,7 @@
 //! | getnetworkinfo                     | version + model | TODO: Fields have changed since v30    |
 //! | getnodeaddresses                   | version         |                                        |
 //! | getpeerinfo                        | version         | TODO: Fields have changed since v30    |
-//! | listbanned                         | version         |                                        |
+//! | listbanned                         | version + model |                                        |
 //! | ping                               | returns nothing |                                        |
 //! | setban                             | returns nothing |                                        |
 //! | setnetworkactive                   | version         |                                        |
@@ -5,6 +5,7 @@ use bitcoin::Transaction;
 
 use super::{AbortPrivateBroadcast, GetPrivateBroadcastInfo};
 use crate::model;
+use crate::prelude::*;
 
 impl AbortPrivateBroadcast {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
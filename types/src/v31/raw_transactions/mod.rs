@@ -8,6 +8,8 @@ mod into;
 
 use serde::{Deserialize, Serialize};
 
+use crate::prelude::*;
+
 /// Result of JSON-RPC method `abortprivatebroadcast`.
 ///
 /// > abortprivatebroadcast "id"
@@ -15,6 +17,7 @@ use serde::{Deserialize, Serialize};
 /// > Abort private broadcast attempts for a transaction currently being privately broadcast.
 /// > The transaction will be removed from the private broadcast queue.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct AbortPrivateBroadcast {
     /// The removed transactions.
@@ -22,6 +25,7 @@ pub struct AbortPrivateBroadcast {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct RemovedTransaction {
     /// The transaction hash in hex.
     pub txid: String,
@@ -37,6 +41,7 @@ pub struct RemovedTransaction {
 /// >
 /// > Returns information about transactions that are currently being privately broadcast.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetPrivateBroadcastInfo {
     /// The transactions currently being broadcast.
@@ -44,6 +49,7 @@ pub struct GetPrivateBroadcastInfo {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PrivateBroadcastTransaction {
     /// The transaction hash in hex.
     pub txid: String,
@@ -56,6 +62,7 @@ pub struct PrivateBroadcastTransaction {
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct PrivateBroadcastPeer {
     /// The address of the peer to which the transaction was sent.
     pub address: String,
@@ -0,0 +1,528 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Things related to the async client in `corepc-client` i.e., `client/src/client_async`.
+//!
+//! The version modules of the async client are generated from the ones of the blocking client in
+//! `client/src/client_sync`. Each macro implementing a method is copied with its functions made
+//! `async` and the calls to other methods of the client awaited, and each version module invokes
+//! the same macros as the blocking one.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use regex::Regex;
+
+use crate::Version;
+
+/// The functions of the blocking client left out of the async client.
+///
+/// These block the thread between calls, or return helpers that make calls on their own.
+const SYNC_ONLY: &[&str] = &[
+    // Polls `getpeerinfo` with `thread::sleep`.
+    "dial",
+    // Return `TxWindows`.
+    "tx_windows",
+    // Return `Transactions`, and the functions paging through it.
+    "transactions",
+    "detect_address_reuse",
+    "send_idempotent",
+    // Returns `UnlockGuard`.
+    "unlock_wallet",
+];
+
+/// The indentation of the functions in the macros.
+const FN_INDENT: &str = "            ";
+
+/// A file generated from the blocking client, the path is relative to `client/src/client_async`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct File {
+    /// Path of the file e.g., `v17/blockchain.rs`.
+    pub path: PathBuf,
+    /// The code of the file.
+    pub code: String,
+}
+
+/// Path to the directory of the async client.
+pub fn path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../client/src/client_async")
+}
+
+/// Path to the directory of the blocking client.
+fn sync_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../client/src/client_sync")
+}
+
+/// Returns the files of the version modules of the async client for `versions`.
+pub fn generate(versions: &[Version]) -> Result<Vec<File>> {
+    // The macros are invoked across versions, collect all of them first.
+    let mut macros = BTreeMap::new();
+    let mut sections = Vec::new();
+    for &version in versions {
+        for entry in fs::read_dir(sync_path().join(version.to_string()))? {
+            let path = entry?.path();
+            let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+            if name == "mod.rs" || !name.ends_with(".rs") {
+                continue;
+            }
+            let src = read(&path)?;
+            let section = Section::parse(&src)
+                .with_context(|| format!("Failed to parse {}", path.display()))?;
+            for m in &section.macros {
+                macros.insert(m.name.clone(), m.body.clone());
+            }
+            sections.push((version, name.to_owned(), section));
+        }
+    }
+
+    let mut files = Vec::new();
+    for (version, name, section) in &sections {
+        if let Some(code) = section.generate(&core_version(*version)?) {
+            files.push(File { path: Path::new(&version.to_string()).join(name), code });
+        }
+    }
+    for &version in versions {
+        let modules = files
+            .iter()
+            .filter(|file| file.path.starts_with(version.to_string()))
+            .filter_map(|file| file.path.file_stem()?.to_str())
+            .collect::<BTreeSet<_>>();
+        let code = generate_mod(version, &modules, &macros)
+            .with_context(|| format!("Failed to generate the {} module", version))?;
+        files.push(File { path: Path::new(&version.to_string()).join("mod.rs"), code });
+    }
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(files)
+}
+
+/// Checks that the version modules of the async client match the blocking client.
+pub fn check(versions: &[Version]) -> Result<()> {
+    let files = generate(versions)?;
+    for file in &files {
+        let path = path().join(&file.path);
+        let current = read(&path)?;
+        if current != file.code {
+            return Err(anyhow::anyhow!(
+                "{} is out of date, regenerate it with `cargo run -- async-client`",
+                path.display()
+            ));
+        }
+    }
+    if let Some(path) = stale(versions, &files)?.first() {
+        return Err(anyhow::anyhow!(
+            "{} is not generated, regenerate the async client with `cargo run -- async-client`",
+            path.display()
+        ));
+    }
+    Ok(())
+}
+
+/// Writes the version modules of the async client generated from the blocking client.
+pub fn write(versions: &[Version]) -> Result<()> {
+    let files = generate(versions)?;
+    for file in &files {
+        let path = path().join(&file.path);
+        fs::create_dir_all(path.parent().expect("file is in a version directory"))?;
+        fs::write(&path, &file.code)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+    for path in stale(versions, &files)? {
+        fs::remove_file(&path).with_context(|| format!("Failed to remove {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Returns the files in the version directories of the async client that are not in `files`.
+fn stale(versions: &[Version], files: &[File]) -> Result<Vec<PathBuf>> {
+    let mut stale = Vec::new();
+    for &version in versions {
+        let dir = path().join(version.to_string());
+        if !dir.exists() {
+            continue;
+        }
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let relative = Path::new(&version.to_string())
+                .join(path.file_name().expect("directory entries have a name"));
+            if !files.iter().any(|file| file.path == relative) {
+                stale.push(path);
+            }
+        }
+    }
+    stale.sort();
+    Ok(stale)
+}
+
+/// Returns the version of Bitcoin Core in the docs of the blocking version module e.g., `v0.17`.
+fn core_version(version: Version) -> Result<String> {
+    let src = read(&sync_path().join(version.to_string()).join("mod.rs"))?;
+    capture(r"Bitcoin Core `([^`]+)`", &src).context("missing the version")
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+/// The macros of a section of the blocking client e.g., `client_sync/v17/blockchain.rs`.
+#[derive(Debug)]
+struct Section {
+    /// The section of the API docs e.g., `Blockchain`.
+    name: String,
+    macros: Vec<Macro>,
+}
+
+/// A macro implementing a method, with its doc comment and attributes.
+#[derive(Debug)]
+struct Macro {
+    /// The name of the blocking macro e.g., `impl_client_v17__get_block`.
+    name: String,
+    /// The async macro, `None` if all of its functions are in [`SYNC_ONLY`].
+    body: Option<String>,
+}
+
+impl Section {
+    fn parse(src: &str) -> Result<Self> {
+        let name = capture(r"`== (\w+) ==`", src).context("missing the API docs section")?;
+        let macro_name = Regex::new(r"macro_rules! (\w+)").expect("valid regex");
+
+        let mut macros = Vec::new();
+        let mut lines = Vec::new();
+        let mut in_item = false;
+        for line in src.lines() {
+            if !in_item {
+                in_item = line.starts_with("///") || line.starts_with("#[");
+            }
+            if !in_item {
+                continue;
+            }
+            lines.push(line);
+            if line == "}" {
+                let item = lines.join("\n");
+                let name = macro_name
+                    .captures(&item)
+                    .with_context(|| format!("not a macro: {}", item))?[1]
+                    .to_owned();
+                macros.push(Macro { name, body: asyncify(&lines) });
+                lines.clear();
+                in_item = false;
+            }
+        }
+        Ok(Section { name, macros })
+    }
+
+    /// Returns the code of the async section, `None` if none of the macros are async.
+    ///
+    /// `core` is the version in the API docs e.g., `v0.17`.
+    fn generate(&self, core: &str) -> Option<String> {
+        let bodies = self.macros.iter().filter_map(|m| m.body.as_deref()).collect::<Vec<_>>();
+        if bodies.is_empty() {
+            return None;
+        }
+        let mut code = format!(
+            "// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing async JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== {} ==` section of the
+//! API docs of Bitcoin Core `{}`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_async_client!` macro to define a `Client`.
+//!
+//! Generated by the `verify` tool from the macros of the blocking client, do not edit. Regenerate
+//! by running `cargo run -- async-client` in the `verify` directory.
+",
+            self.name, core
+        );
+        for body in bodies {
+            code.push('\n');
+            code.push_str(body);
+            code.push('\n');
+        }
+        Some(code)
+    }
+}
+
+/// Returns the first capture of `re` in `s`.
+fn capture(re: &str, s: &str) -> Option<String> {
+    Some(Regex::new(re).expect("valid regex").captures(s)?[1].to_owned())
+}
+
+/// Returns the async version of the macro `lines`, `None` if there are no functions left.
+fn asyncify(lines: &[&str]) -> Option<String> {
+    let fn_start =
+        Regex::new(&format!(r"^{}(pub(\(crate\))? )?fn (\w+)", FN_INDENT)).expect("valid regex");
+
+    // Leave out the functions in `SYNC_ONLY`, with their doc comments and attributes.
+    let mut kept: Vec<String> = Vec::new();
+    let mut any_fn = false;
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        let Some(caps) = fn_start.captures(line) else {
+            kept.push(line.to_owned());
+            i += 1;
+            continue;
+        };
+        // Find the end of the function, on the same line for single line functions.
+        let end = if line.trim_end().ends_with('}') {
+            i
+        } else {
+            let close = format!("{}}}", FN_INDENT);
+            i + lines[i..].iter().position(|l| *l == close).expect("function is closed")
+        };
+        if SYNC_ONLY.contains(&&caps[3]) {
+            while kept.last().is_some_and(|l| {
+                let l = l.trim_start();
+                l.starts_with("///") || l.starts_with("#[")
+            }) {
+                kept.pop();
+            }
+            // Remove the blank line separating it from the previous function.
+            if kept.last().is_some_and(|l| l.is_empty()) {
+                kept.pop();
+            }
+        } else {
+            any_fn = true;
+            for (j, l) in lines[i..=end].iter().enumerate() {
+                match (j, l.strip_prefix(FN_INDENT)) {
+                    (0, Some(rest)) => {
+                        let rest = rest
+                            .replacen("pub(crate) fn ", "pub(crate) async fn ", 1)
+                            .replacen("pub fn ", "pub async fn ", 1);
+                        let rest =
+                            if rest.starts_with("fn ") { format!("async {}", rest) } else { rest };
+                        kept.push(format!("{}{}", FN_INDENT, rest));
+                    }
+                    _ => kept.push(l.to_string()),
+                }
+            }
+        }
+        i = end + 1;
+    }
+    // The block of the `impl` may now start with a blank line.
+    let kept = kept
+        .iter()
+        .enumerate()
+        .filter(|(j, l)| !(l.is_empty() && *j > 0 && kept[j - 1].ends_with("impl Client {")))
+        .map(|(_, l)| l.as_str())
+        .collect::<Vec<_>>();
+
+    if !any_fn {
+        return None;
+    }
+    let body = kept.join("\n").replace("impl_client_", "impl_async_client_");
+    Some(await_calls(&body))
+}
+
+/// Appends `.await` to the calls of methods on `self` in `code`, including nested calls.
+fn await_calls(code: &str) -> String {
+    let call = Regex::new(r"self\s*\.\w+(::<[^>]*>)?\(").expect("valid regex");
+    let mut closes = call.find_iter(code).map(|m| closing_paren(code, m.end())).collect::<Vec<_>>();
+    closes.sort_unstable();
+    let mut out = code.to_owned();
+    for close in closes.into_iter().rev() {
+        out.insert_str(close + 1, ".await");
+    }
+    out
+}
+
+/// Returns the index of the `)` closing the `(` just before `start`, skipping string literals.
+fn closing_paren(code: &str, start: usize) -> usize {
+    let bytes = code.as_bytes();
+    let mut depth = 1;
+    let mut in_str = false;
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if in_str => i += 1,
+            b'"' => in_str = !in_str,
+            b'(' if !in_str => depth += 1,
+            b')' if !in_str => {
+                depth -= 1;
+                if depth == 0 {
+                    return i;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    panic!("unbalanced parentheses in: {}", code)
+}
+
+/// Returns the code of the async version module.
+///
+/// # Parameters
+///
+/// - `modules`: The section modules of the async version module.
+/// - `macros`: The async macros by the name of their blocking macro.
+fn generate_mod(
+    version: Version,
+    modules: &BTreeSet<&str>,
+    macros: &BTreeMap<String, Option<String>>,
+) -> Result<String> {
+    let src = read(&sync_path().join(version.to_string()).join("mod.rs"))?;
+    let src = src.as_str();
+    let core = core_version(version)?;
+    let invoke = Regex::new(r"^crate::(impl_client_\w+)!\((.*)\);$").expect("valid regex");
+    let local_type = Regex::new(r"^pub (?:enum|struct) (\w+)").expect("valid regex");
+
+    // The invocations of the method macros, by section.
+    let mut sections: Vec<(&str, Vec<String>)> = Vec::new();
+    // The other invocations e.g., `impl_client_capabilities`.
+    let mut others = Vec::new();
+    let mut used = String::new();
+    for line in src.lines() {
+        if line.starts_with("// == ") {
+            sections.push((line, Vec::new()));
+        } else if let Some(caps) = invoke.captures(line) {
+            let name = &caps[1];
+            let asyncified = line.replace("impl_client_", "impl_async_client_");
+            match macros.get(name) {
+                Some(Some(body)) => {
+                    used.push_str(body);
+                    let (_, invocations) =
+                        sections.last_mut().context("method macro outside a section")?;
+                    invocations.push(asyncified);
+                }
+                Some(None) => {}
+                None if name.contains("__") => anyhow::bail!("unknown macro {}", name),
+                None => others.push(asyncified),
+            }
+        }
+    }
+
+    let mut code = format!(
+        "// SPDX-License-Identifier: CC0-1.0
+
+//! An async JSON-RPC client for Bitcoin Core `{}`.
+//!
+//! Generated by the `verify` tool from `client_sync::{}`, do not edit. Regenerate by running
+//! `cargo run -- async-client` in the `verify` directory.
+",
+        core, version
+    );
+    if !modules.is_empty() {
+        code.push('\n');
+        for module in modules {
+            code.push_str(&format!("pub mod {};\n", module));
+        }
+    }
+
+    // The imports used by the invoked macros, in the groups of the blocking module.
+    let mut group = Vec::new();
+    let mut statement = String::new();
+    for line in
+        src.lines().take_while(|line| !line.starts_with("#[") && !line.starts_with("crate::"))
+    {
+        if line.starts_with("use ") || !statement.is_empty() {
+            statement.push_str(line);
+            statement.push('\n');
+            if line.ends_with(';') {
+                if let Some(import) = filter_import(&statement, &used) {
+                    group.push(import);
+                }
+                statement.clear();
+            }
+        } else if line.is_empty() && !group.is_empty() {
+            code.push('\n');
+            code.push_str(&group.concat());
+            group.clear();
+        }
+    }
+    if !group.is_empty() {
+        code.push('\n');
+        code.push_str(&group.concat());
+    }
+
+    // The public re-exports, including the types defined in the blocking module.
+    let mut reexports = String::new();
+    let mut lines = src.lines().skip_while(|line| !line.starts_with("#[rustfmt::skip]"));
+    if let Some(skip) = lines.next() {
+        reexports.push_str(skip);
+        reexports.push('\n');
+        for line in lines.by_ref() {
+            reexports.push_str(line);
+            reexports.push('\n');
+            if line.ends_with(';') {
+                break;
+            }
+        }
+    }
+    let local = src
+        .lines()
+        .filter_map(|line| Some(local_type.captures(line)?[1].to_owned()))
+        .collect::<Vec<_>>();
+    if !local.is_empty() {
+        if reexports.is_empty() {
+            reexports
+                .push_str("#[rustfmt::skip]                // Keep public re-exports separate.\n");
+        }
+        reexports.push_str(&format!(
+            "pub use crate::client_sync::{}::{{{}}};\n",
+            version,
+            local.join(", ")
+        ));
+    }
+    if !reexports.is_empty() {
+        code.push('\n');
+        code.push_str(&reexports);
+    }
+
+    code.push_str("\n// This publicly re-exports `Client`.\n");
+    code.push_str(&format!("crate::define_jsonrpc_bitreq_async_client!(\"{}\");\n", version));
+    for other in others {
+        code.push_str(&other);
+        code.push('\n');
+    }
+    for (section, invocations) in sections {
+        if invocations.is_empty() {
+            continue;
+        }
+        code.push('\n');
+        code.push_str(section);
+        code.push('\n');
+        for invocation in invocations {
+            code.push_str(&invocation);
+            code.push('\n');
+        }
+    }
+    Ok(code)
+}
+
+/// Returns `statement` importing only the names used in `code`, `None` if none are.
+///
+/// Glob imports are kept as they are.
+fn filter_import(statement: &str, code: &str) -> Option<String> {
+    let used = |name: &str| {
+        let word = Regex::new(&format!(r"\b{}\b", regex::escape(name))).expect("valid regex");
+        word.is_match(code)
+    };
+    let trimmed = statement.trim_end().trim_end_matches(';');
+    if trimmed.ends_with('*') {
+        return Some(statement.to_owned());
+    }
+    match (trimmed.find('{'), trimmed.rfind('}')) {
+        (Some(open), Some(close)) => {
+            let prefix = &trimmed[..open];
+            let names = trimmed[open + 1..close]
+                .split(',')
+                .map(str::trim)
+                .filter(|name| !name.is_empty())
+                .filter(|name| used(name.rsplit("::").next().expect("split is not empty")))
+                .collect::<Vec<_>>();
+            match names.len() {
+                0 => None,
+                1 => Some(format!("{}{};\n", prefix, names[0])),
+                _ => Some(format!("{}{{{}}};\n", prefix, names.join(", "))),
+            }
+        }
+        _ => {
+            let name = trimmed.rsplit("::").next().expect("split is not empty");
+            used(name).then(|| statement.to_owned())
+        }
+    }
+}
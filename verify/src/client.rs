@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Things related to parsing the client files.
+//!
+//! The "client files" are the files in `client/src/client_sync/`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use regex::Regex;
+use walkdir::WalkDir;
+
+use crate::Version;
+
+/// Path to the `client_sync` module directory.
+fn dir() -> PathBuf { PathBuf::from("../client/src/client_sync") }
+
+/// Path to the version specific client module file.
+fn path(version: Version) -> PathBuf { dir().join(format!("{}/mod.rs", version)) }
+
+/// Returns the names of all functions implemented on the `Client` for `version`.
+///
+/// Collected from the bodies of the `impl_client_*` macros invoked in the version specific module.
+pub fn all_functions(version: Version) -> Result<BTreeSet<String>> {
+    let macros = macro_functions()?;
+
+    let path = path(version);
+    let module = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read client module {}", path.display()))?;
+    let invocation = Regex::new(r"crate::(impl_client_\w+)!")?;
+
+    let mut functions = BTreeSet::new();
+    for caps in invocation.captures_iter(&module) {
+        if let Some(fns) = macros.get(caps.get(1).unwrap().as_str()) {
+            functions.extend(fns.iter().cloned());
+        }
+    }
+    Ok(functions)
+}
+
+/// Returns `true` if the `Client` for `version` has a function for the method `function`.
+///
+/// Methods with several modes are implemented as several functions with a common prefix (e.g.
+/// `get_block_stats_by_height`) so these count as implementing `get_block_stats`.
+pub fn function_exists(functions: &BTreeSet<String>, function: &str) -> bool {
+    let prefix = format!("{}_", function);
+    functions.iter().any(|f| f == function || f.starts_with(&prefix))
+}
+
+/// Maps the name of each `impl_client_*` macro to the functions it defines.
+fn macro_functions() -> Result<BTreeMap<String, Vec<String>>> {
+    let definition = Regex::new(r"macro_rules!\s+(impl_client_\w+)")?;
+    let function = Regex::new(r"pub fn (\w+)")?;
+
+    let mut map = BTreeMap::new();
+    for entry in WalkDir::new(dir()).into_iter().filter_map(|e| e.ok()) {
+        if entry.path().extension() != Some("rs".as_ref()) {
+            continue;
+        }
+        let file = fs::read_to_string(entry.path())
+            .with_context(|| format!("Failed to read client file {}", entry.path().display()))?;
+
+        // Each macro body runs until the start of the next macro definition.
+        let starts = definition.captures_iter(&file).collect::<Vec<_>>();
+        for (i, caps) in starts.iter().enumerate() {
+            let whole = caps.get(0).unwrap();
+            let end = starts.get(i + 1).map_or(file.len(), |next| next.get(0).unwrap().start());
+            let body = &file[whole.end()..end];
+            let fns = function.captures_iter(body).map(|c| c[1].to_string()).collect();
+            map.insert(caps[1].to_string(), fns);
+        }
+    }
+    Ok(map)
+}
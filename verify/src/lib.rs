@@ -2,9 +2,13 @@
 
 //! Helper methods.
 
+pub mod async_client;
+pub mod client;
 pub mod method;
 pub mod model;
 pub mod reexports;
+pub mod registry;
+pub mod schema;
 pub mod ssot;
 pub mod versioned;
 
@@ -8,7 +8,14 @@
 //! - That an expected return type is provided if the method is supported.
 //! - That there is a `model` type if required.
 //! - That the method has an integration test.
+//! - That every method returning a `model` type has a client function.
 //! - That re-exports in `corepc-types` are complete.
+//! - That the method registry in `corepc-types` is up to date (when verifying all versions).
+//! - That the JSON Schema module in `corepc-types` is up to date (when verifying all versions).
+//! - That the async client in `corepc-client` is up to date (when verifying all versions).
+//!
+//! Run with `registry`, `schema` or `async-client` instead of a version to regenerate the method
+//! registry, the JSON Schema module or the async client.
 
 use std::process;
 
@@ -16,7 +23,7 @@ use anyhow::Result;
 use clap::{arg, Command};
 use verify::method::{Method, Return};
 use verify::versioned::{self, Status};
-use verify::{method, model, reexports, ssot, Version};
+use verify::{async_client, client, method, model, reexports, registry, schema, ssot, Version};
 
 // TODO: Enable running from any directory, currently errors if run from `src/`.
 // TODO: Add a --quiet option.
@@ -41,6 +48,8 @@ const VERSIONS: [Version; 15] = [
 
 fn main() -> Result<()> {
     let cmd = Command::new("verify").args([
+        // The version may also be "registry", "schema" or "async-client", to regenerate
+        // `types/src/methods.rs`, `types/src/schema.rs` or `client/src/client_async`.
         arg!([version] "Verify specific version of Core (use \"all\" for all versions)")
             .required(true),
         arg!(-t --tests <TEST_OUTPUT> "Optionally check claimed status of tests").required(false),
@@ -54,6 +63,12 @@ fn main() -> Result<()> {
 
     if version == "all" {
         verify_all_versions(test_output, quiet)?;
+    } else if version == "registry" {
+        registry::write(&VERSIONS)?;
+    } else if version == "schema" {
+        schema::write(&VERSIONS)?;
+    } else if version == "async-client" {
+        async_client::write(&VERSIONS)?;
     } else if let Ok(v) = version.parse::<Version>() {
         verify_version(v, test_output, quiet)?;
     } else {
@@ -73,6 +88,46 @@ fn verify_all_versions(test_output: Option<&String>, quiet: bool) -> Result<()>
             any_failed = true;
         }
     }
+
+    println!();
+    let msg = "Checking that the corepc-types method registry is up to date";
+    check(msg, quiet);
+    match registry::check(&VERSIONS) {
+        Ok(()) => close(true, quiet),
+        Err(e) => {
+            if !quiet {
+                eprintln!("{}", e);
+            }
+            close(false, quiet);
+            any_failed = true;
+        }
+    }
+
+    let msg = "Checking that the corepc-types JSON Schema module is up to date";
+    check(msg, quiet);
+    match schema::check(&VERSIONS) {
+        Ok(()) => close(true, quiet),
+        Err(e) => {
+            if !quiet {
+                eprintln!("{}", e);
+            }
+            close(false, quiet);
+            any_failed = true;
+        }
+    }
+    let msg = "Checking that the corepc-client async client is up to date";
+    check(msg, quiet);
+    match async_client::check(&VERSIONS) {
+        Ok(()) => close(true, quiet),
+        Err(e) => {
+            if !quiet {
+                eprintln!("{}", e);
+            }
+            close(false, quiet);
+            any_failed = true;
+        }
+    }
+
     if any_failed {
         return Err(anyhow::anyhow!("verification failed for one or more versions"));
     }
@@ -136,6 +191,19 @@ fn verify_version(version: Version, test_output: Option<&String>, quiet: bool) -
         }
     }
 
+    let msg = "Checking that every 'version + model' method has a client function";
+    check(msg, quiet);
+    match verify_client_functions(version) {
+        Ok(()) => close(true, quiet),
+        Err(e) => {
+            if !quiet {
+                eprintln!("{}", e);
+            }
+            close(false, quiet);
+            failures += 1;
+        }
+    }
+
     let msg = "Checking that corepc-types re-exports are complete";
     check(msg, quiet);
     match reexports::check_type_reexports(version) {
@@ -318,6 +386,31 @@ fn verify_returns_method(version: Version) -> Result<()> {
     Ok(())
 }
 
+/// Verifies that every method documented as 'version + model' can be called using the client.
+fn verify_client_functions(version: Version) -> Result<()> {
+    use verify::versioned::{returns_map, ReturnsDoc};
+
+    let functions = client::all_functions(version)?;
+    let mut failures = 0;
+
+    for (name, entry) in returns_map(version)?.into_iter() {
+        if entry != ReturnsDoc::VersionPlusModel {
+            continue;
+        }
+        let Some(method) = Method::from_name(version, &name) else { continue };
+
+        if !client::function_exists(&functions, method.function) {
+            eprintln!("missing client function: {} {}", method.name, method.function);
+            failures += 1;
+        }
+    }
+
+    if failures > 0 {
+        return Err(anyhow::anyhow!("client verification failed ({} issue(s))", failures));
+    }
+    Ok(())
+}
+
 // Use a module because a file with this name is confusing.
 mod check_integration_test_crate {
     //! Things related to parsing the `integration_test` crate.
@@ -59,7 +59,7 @@ pub const METHODS: &[Method] = &[
     Method::new_no_model("getnettotals", "GetNetTotals", "get_net_totals"),
     Method::new_modelled("getnetworkinfo", "GetNetworkInfo", "get_network_info"),
     Method::new_no_model("getpeerinfo", "GetPeerInfo", "get_peer_info"),
-    Method::new_no_model("listbanned", "ListBanned", "list_banned"),
+    Method::new_modelled("listbanned", "ListBanned", "list_banned"),
     Method::new_nothing("ping", "ping"),
     Method::new_nothing("setban", "set_ban"),
     Method::new_no_model("setnetworkactive", "SetNetworkActive", "set_network_active"),
@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Things related to the method registry in `corepc-types` i.e., `types/src/methods.rs`.
+//!
+//! The name, section and versions of each method come from the SSOT files. Whether a method
+//! changes the state of the node and whether it is called on a wallet is not in the SSOT, it is
+//! listed here.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+use crate::{ssot, Version};
+
+/// Marks the start of the generated code in the registry file.
+const START: &str = "// Start of generated code, see the module docs.\n";
+
+/// Marks the end of the generated code in the registry file.
+const END: &str = "// End of generated code.\n";
+
+/// The methods that change the state of the node or the wallet, or act on the network.
+const MUTATING: &[&str] = &[
+    "abandontransaction",
+    "abortprivatebroadcast",
+    "abortrescan",
+    "addmultisigaddress",
+    "addnode",
+    "backupwallet",
+    "bumpfee",
+    "clearbanned",
+    "createwallet",
+    "createwalletdescriptor",
+    "disconnectnode",
+    "dumptxoutset",
+    "dumpwallet",
+    "encryptwallet",
+    "fundrawtransaction",
+    "generate",
+    "generateblock",
+    "generatetoaddress",
+    "generatetodescriptor",
+    "getaccountaddress",
+    "getblockfrompeer",
+    "getnewaddress",
+    "getrawchangeaddress",
+    "importaddress",
+    "importdescriptors",
+    "importmempool",
+    "importmulti",
+    "importprivkey",
+    "importprunedfunds",
+    "importpubkey",
+    "importwallet",
+    "keypoolrefill",
+    "loadtxoutset",
+    "loadwallet",
+    "lockunspent",
+    "logging",
+    "migratewallet",
+    "move",
+    "newkeypool",
+    "ping",
+    "preciousblock",
+    "prioritisetransaction",
+    "pruneblockchain",
+    "psbtbumpfee",
+    "removeprunedfunds",
+    "rescanblockchain",
+    "restorewallet",
+    "savemempool",
+    "send",
+    "sendall",
+    "sendfrom",
+    "sendmany",
+    "sendrawtransaction",
+    "sendtoaddress",
+    "setaccount",
+    "setban",
+    "sethdseed",
+    "setlabel",
+    "setnetworkactive",
+    "settxfee",
+    "setwalletflag",
+    "stop",
+    "submitblock",
+    "submitheader",
+    "submitpackage",
+    "unloadwallet",
+    "upgradewallet",
+    "walletcreatefundedpsbt",
+    "walletlock",
+    "walletpassphrase",
+    "walletpassphrasechange",
+];
+
+/// The methods in the `Wallet` section that are not called on a wallet.
+const NODE_WALLET_METHODS: &[&str] =
+    &["createwallet", "listwalletdir", "listwallets", "loadwallet", "restorewallet"];
+
+/// The methods outside of the `Wallet` section that are called on a wallet.
+const OTHER_WALLET_METHODS: &[&str] = &["fundrawtransaction", "generate"];
+
+/// The methods Core still has after `bitcoin-cli help` stopped listing them, the generating
+/// methods are hidden from Core v23.
+const HIDDEN: &[&str] = &["generateblock", "generatetoaddress", "generatetodescriptor"];
+
+/// Path to the registry file.
+pub fn path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../types/src/methods.rs")
+}
+
+/// Returns the generated code of the registry for the SSOT files of `versions`, in order.
+pub fn generate(versions: &[Version]) -> Result<String> {
+    // The section, first and last version of each method.
+    let mut methods: BTreeMap<String, (String, u32, u32)> = BTreeMap::new();
+    for &version in versions {
+        let major = major(version);
+        for (name, section) in ssot::all_methods_by_section(version)? {
+            let entry = methods.entry(name).or_insert((section.clone(), major, major));
+            entry.0 = section;
+            entry.2 = major;
+        }
+    }
+    let latest = versions.last().map(|&version| major(version)).unwrap_or_default();
+
+    let mut code = String::from("pub const METHODS: &[MethodInfo] = &[\n");
+    for (name, (section, since, last)) in methods {
+        let wallet = if section == "Wallet" {
+            !NODE_WALLET_METHODS.contains(&name.as_str())
+        } else {
+            OTHER_WALLET_METHODS.contains(&name.as_str())
+        };
+        let last = if HIDDEN.contains(&name.as_str()) { latest } else { last };
+        let until = if last == latest { "None".to_string() } else { format!("Some({})", last + 1) };
+        let access = if MUTATING.contains(&name.as_str()) { "Write" } else { "Read" };
+        code.push_str(&format!(
+            "    {}(\"{}\", {}, {}, {}, {}),\n",
+            if wallet { "wallet" } else { "node" },
+            name,
+            section,
+            since,
+            until,
+            access
+        ));
+    }
+    code.push_str("];\n");
+    Ok(code)
+}
+
+/// Checks that the registry file matches the SSOT files of `versions`.
+pub fn check(versions: &[Version]) -> Result<()> {
+    let (_, current, _) = read()?;
+    if current != generate(versions)? {
+        return Err(anyhow::anyhow!(
+            "{} is out of date, regenerate it with `cargo run -- registry`",
+            path().display()
+        ));
+    }
+    Ok(())
+}
+
+/// Writes the code generated from the SSOT files of `versions` to the registry file.
+pub fn write(versions: &[Version]) -> Result<()> {
+    let (before, _, after) = read()?;
+    let code = format!("{}{}{}{}{}", before, START, generate(versions)?, END, after);
+    fs::write(path(), code).with_context(|| format!("Failed to write {}", path().display()))
+}
+
+/// Reads the registry file, returns the code before, within and after the generated code.
+fn read() -> Result<(String, String, String)> {
+    let path = path();
+    let code = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read registry {}", path.display()))?;
+    let (before, rest) = code
+        .split_once(START)
+        .with_context(|| format!("missing start of generated code in {}", path.display()))?;
+    let (generated, after) = rest
+        .split_once(END)
+        .with_context(|| format!("missing end of generated code in {}", path.display()))?;
+    Ok((before.to_string(), generated.to_string(), after.to_string()))
+}
+
+/// Returns the major version number of `version` e.g., `17` for v0.17.
+fn major(version: Version) -> u32 {
+    version.to_string().trim_start_matches('v').parse().expect("versions are formatted as vNN")
+}
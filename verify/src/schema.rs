@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Things related to the JSON Schema module in `corepc-types` i.e., `types/src/schema.rs`.
+//!
+//! The module is generated from the method data, each method returning a version specific type
+//! gets the schema of that type.
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+
+use crate::method::{self, Method, Return};
+use crate::{versioned, Version};
+
+/// The code before the generated functions.
+const HEADER: &str = r#"// SPDX-License-Identifier: CC0-1.0
+
+//! JSON Schema for the types returned by the JSON-RPC methods of each version of Bitcoin Core.
+//!
+//! This module is generated by the `verify` tool from its method data, do not edit it. Regenerate
+//! it by running `cargo run -- schema` in the `verify` directory.
+//!
+//! Methods returning nothing, a number, a boolean or a string, and the methods that are not yet
+//! supported, are not included.
+//!
+//! ```
+//! let schemas = corepc_types::schema::v29();
+//! let (_, schema) = schemas.iter().find(|(method, _)| *method == "getblockchaininfo").unwrap();
+//! println!("{}", serde_json::to_string_pretty(schema).unwrap());
+//! ```
+
+use schemars::schema::RootSchema;
+use schemars::schema_for;
+"#;
+
+/// Path to the schema module.
+pub fn path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../types/src/schema.rs")
+}
+
+/// Returns the code of the schema module for `versions`, in order.
+pub fn generate(versions: &[Version]) -> Result<String> {
+    let mut code = String::from(HEADER);
+    for &version in versions {
+        code.push_str("\n/// Returns the JSON Schema of the type returned by each method of ");
+        code.push_str(&format!("`{}`, by method name.\n", version));
+        code.push_str(&format!("pub fn {}() -> Vec<(&'static str, RootSchema)> {{\n", version));
+        code.push_str(&format!("    use crate::{}::*;\n\n", version));
+        code.push_str("    vec![\n");
+        for name in method::all_methods(version) {
+            let Some(Method { ret: Some(Return::Type(ty)), .. }) =
+                Method::from_name(version, &name)
+            else {
+                continue;
+            };
+            // Skip the types not re-exported by the version module.
+            if !versioned::type_exists(version, &name)? {
+                continue;
+            }
+            code.push_str(&format!("        (\"{}\", schema_for!({})),\n", name, ty));
+        }
+        code.push_str("    ]\n}\n");
+    }
+    Ok(code)
+}
+
+/// Checks that the schema module matches the method data of `versions`.
+pub fn check(versions: &[Version]) -> Result<()> {
+    let path = path();
+    let current = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read schema module {}", path.display()))?;
+    if current != generate(versions)? {
+        return Err(anyhow::anyhow!(
+            "{} is out of date, regenerate it with `cargo run -- schema`",
+            path.display()
+        ));
+    }
+    Ok(())
+}
+
+/// Writes the schema module generated from the method data of `versions`.
+pub fn write(versions: &[Version]) -> Result<()> {
+    let path = path();
+    fs::write(&path, generate(versions)?)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
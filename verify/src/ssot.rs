@@ -46,3 +46,39 @@ pub fn all_methods(version: Version) -> Result<Vec<String>> {
 
     Ok(methods)
 }
+
+/// Parses the Bitcoin Core docs (from SSOT file) and gets all the method names, each with the
+/// name of the section it is listed under e.g., `("getblock", "Blockchain")`.
+pub fn all_methods_by_section(version: Version) -> Result<Vec<(String, String)>> {
+    let path = path(version);
+    let file = File::open(&path)
+        .with_context(|| format!("Failed to grep for method sections in {}", path.display()))?;
+    let reader = io::BufReader::new(file);
+
+    let header_re = Regex::new(r"^== (\w+) ==$").unwrap();
+    let empty_re = Regex::new(r"^$").unwrap();
+
+    let mut methods = Vec::new();
+    let mut section = None;
+
+    for line in reader.lines() {
+        let line = line?;
+
+        if let Some(caps) = header_re.captures(&line) {
+            section = Some(caps[1].to_string());
+            continue;
+        }
+        if empty_re.is_match(&line) {
+            continue;
+        }
+
+        let section = section
+            .clone()
+            .with_context(|| format!("method listed before any section: {}", line))?;
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        // We know this is not empty because of `empty_re`.
+        methods.push((parts[0].to_string(), section));
+    }
+
+    Ok(methods)
+}